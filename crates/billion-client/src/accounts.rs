@@ -0,0 +1,392 @@
+use anchor_lang::prelude::*;
+use anchor_lang::{AccountDeserialize, Discriminator};
+use billion::state::{
+    AdminMintAllowance, AdminRoles, Attestation, BlockMap, BlockMapData, CharityRegistry, ClaimNonce, ClaimsPause, ClaimerStats, ConfigFreeze, ConfigTimelock, TokenTreasury, Distribution, EpochArchive, Fraction, FractionPosition, GridConfig, HarbergerDistrict, HookConfig, MapDigest, NameRecord, ParcelContent, ParcelIdPool, ParcelInfo, ParcelMask, ParcelPixels, ParcelValuation, Raffle, ReferralShare, ReferrerAccount, RenameFee, RewardDelegate, RewardDust, RewardExclusions, RingOccupancy, RingRewardWeights, RingUnlockSchedule, SolRewardCheckpoint, SolRewards, StakeAccount, Voucher,
+};
+
+use crate::pda::{find_emissions_vault, find_grid_config, find_quest_vault, find_referral_vault, find_reward_pool};
+
+/// Decode GridConfig account data (including the 8-byte discriminator)
+pub fn decode_grid_config(data: &[u8]) -> Result<GridConfig> {
+    GridConfig::try_deserialize(&mut &data[..])
+}
+
+/// Decode ParcelInfo account data (including the 8-byte discriminator)
+pub fn decode_parcel_info(data: &[u8]) -> Result<ParcelInfo> {
+    ParcelInfo::try_deserialize(&mut &data[..])
+}
+
+/// Decode ReferrerAccount account data (including the 8-byte discriminator)
+pub fn decode_referrer_account(data: &[u8]) -> Result<ReferrerAccount> {
+    ReferrerAccount::try_deserialize(&mut &data[..])
+}
+
+/// Decode StakeAccount account data (including the 8-byte discriminator)
+pub fn decode_stake_account(data: &[u8]) -> Result<StakeAccount> {
+    StakeAccount::try_deserialize(&mut &data[..])
+}
+
+/// Decode HarbergerDistrict account data (including the 8-byte discriminator)
+pub fn decode_harberger_district(data: &[u8]) -> Result<HarbergerDistrict> {
+    HarbergerDistrict::try_deserialize(&mut &data[..])
+}
+
+/// Decode Fraction account data (including the 8-byte discriminator)
+pub fn decode_fraction(data: &[u8]) -> Result<Fraction> {
+    Fraction::try_deserialize(&mut &data[..])
+}
+
+/// Decode FractionPosition account data (including the 8-byte discriminator)
+pub fn decode_fraction_position(data: &[u8]) -> Result<FractionPosition> {
+    FractionPosition::try_deserialize(&mut &data[..])
+}
+
+/// Decode Attestation account data (including the 8-byte discriminator)
+pub fn decode_attestation(data: &[u8]) -> Result<Attestation> {
+    Attestation::try_deserialize(&mut &data[..])
+}
+
+/// Decode CharityRegistry account data (including the 8-byte discriminator)
+pub fn decode_charity_registry(data: &[u8]) -> Result<CharityRegistry> {
+    CharityRegistry::try_deserialize(&mut &data[..])
+}
+
+/// Decode ClaimerStats account data (including the 8-byte discriminator)
+pub fn decode_claimer_stats(data: &[u8]) -> Result<ClaimerStats> {
+    ClaimerStats::try_deserialize(&mut &data[..])
+}
+
+/// Decode ClaimNonce account data (including the 8-byte discriminator)
+pub fn decode_claim_nonce(data: &[u8]) -> Result<ClaimNonce> {
+    ClaimNonce::try_deserialize(&mut &data[..])
+}
+
+/// Decode NameRecord account data (including the 8-byte discriminator)
+pub fn decode_name_record(data: &[u8]) -> Result<NameRecord> {
+    NameRecord::try_deserialize(&mut &data[..])
+}
+
+/// Decode Voucher account data (including the 8-byte discriminator)
+pub fn decode_voucher(data: &[u8]) -> Result<Voucher> {
+    Voucher::try_deserialize(&mut &data[..])
+}
+
+/// Decode Raffle account data (including the 8-byte discriminator)
+pub fn decode_raffle(data: &[u8]) -> Result<Raffle> {
+    Raffle::try_deserialize(&mut &data[..])
+}
+
+/// Decode HookConfig account data (including the 8-byte discriminator)
+pub fn decode_hook_config(data: &[u8]) -> Result<HookConfig> {
+    HookConfig::try_deserialize(&mut &data[..])
+}
+
+/// Decode ParcelValuation account data (including the 8-byte discriminator)
+pub fn decode_parcel_valuation(data: &[u8]) -> Result<ParcelValuation> {
+    ParcelValuation::try_deserialize(&mut &data[..])
+}
+
+/// Decode EpochArchive account data (including the 8-byte discriminator)
+pub fn decode_epoch_archive(data: &[u8]) -> Result<EpochArchive> {
+    EpochArchive::try_deserialize(&mut &data[..])
+}
+
+/// Decode Distribution account data (including the 8-byte discriminator)
+pub fn decode_distribution(data: &[u8]) -> Result<Distribution> {
+    Distribution::try_deserialize(&mut &data[..])
+}
+
+/// Decode ParcelIdPool account data (including the 8-byte discriminator)
+pub fn decode_parcel_id_pool(data: &[u8]) -> Result<ParcelIdPool> {
+    ParcelIdPool::try_deserialize(&mut &data[..])
+}
+
+/// Decode ParcelMask account data (including the 8-byte discriminator). The mask only
+/// describes the parcel while its ParcelInfo has a nonzero masked_block_count.
+pub fn decode_parcel_mask(data: &[u8]) -> Result<ParcelMask> {
+    ParcelMask::try_deserialize(&mut &data[..])
+}
+
+/// Decode AdminMintAllowance account data (including the 8-byte discriminator)
+pub fn decode_admin_mint_allowance(data: &[u8]) -> Result<AdminMintAllowance> {
+    AdminMintAllowance::try_deserialize(&mut &data[..])
+}
+
+/// Decode RewardExclusions account data (including the 8-byte discriminator)
+pub fn decode_reward_exclusions(data: &[u8]) -> Result<RewardExclusions> {
+    RewardExclusions::try_deserialize(&mut &data[..])
+}
+
+/// Decode RewardDelegate account data (including the 8-byte discriminator)
+pub fn decode_reward_delegate(data: &[u8]) -> Result<RewardDelegate> {
+    RewardDelegate::try_deserialize(&mut &data[..])
+}
+
+/// Decode SolRewards account data (including the 8-byte discriminator)
+pub fn decode_sol_rewards(data: &[u8]) -> Result<SolRewards> {
+    SolRewards::try_deserialize(&mut &data[..])
+}
+
+/// Decode SolRewardCheckpoint account data (including the 8-byte discriminator)
+pub fn decode_sol_reward_checkpoint(data: &[u8]) -> Result<SolRewardCheckpoint> {
+    SolRewardCheckpoint::try_deserialize(&mut &data[..])
+}
+
+/// Decode RewardDust account data (including the 8-byte discriminator)
+pub fn decode_reward_dust(data: &[u8]) -> Result<RewardDust> {
+    RewardDust::try_deserialize(&mut &data[..])
+}
+
+/// Decode RingRewardWeights account data (including the 8-byte discriminator)
+pub fn decode_ring_reward_weights(data: &[u8]) -> Result<RingRewardWeights> {
+    RingRewardWeights::try_deserialize(&mut &data[..])
+}
+
+/// Decode AdminRoles account data (including the 8-byte discriminator)
+pub fn decode_admin_roles(data: &[u8]) -> Result<AdminRoles> {
+    AdminRoles::try_deserialize(&mut &data[..])
+}
+
+/// Decode ConfigTimelock account data (including the 8-byte discriminator)
+pub fn decode_config_timelock(data: &[u8]) -> Result<ConfigTimelock> {
+    ConfigTimelock::try_deserialize(&mut &data[..])
+}
+
+/// Decode TokenTreasury account data (including the 8-byte discriminator)
+pub fn decode_token_treasury(data: &[u8]) -> Result<TokenTreasury> {
+    TokenTreasury::try_deserialize(&mut &data[..])
+}
+
+/// Decode ConfigFreeze account data (including the 8-byte discriminator)
+pub fn decode_config_freeze(data: &[u8]) -> Result<ConfigFreeze> {
+    ConfigFreeze::try_deserialize(&mut &data[..])
+}
+
+/// Decode ClaimsPause account data (including the 8-byte discriminator)
+pub fn decode_claims_pause(data: &[u8]) -> Result<ClaimsPause> {
+    ClaimsPause::try_deserialize(&mut &data[..])
+}
+
+/// Decode RingOccupancy account data (including the 8-byte discriminator)
+pub fn decode_ring_occupancy(data: &[u8]) -> Result<RingOccupancy> {
+    RingOccupancy::try_deserialize(&mut &data[..])
+}
+
+/// Decode MapDigest account data (including the 8-byte discriminator)
+pub fn decode_map_digest(data: &[u8]) -> Result<MapDigest> {
+    MapDigest::try_deserialize(&mut &data[..])
+}
+
+/// Decode RingUnlockSchedule account data (including the 8-byte discriminator)
+pub fn decode_ring_unlock_schedule(data: &[u8]) -> Result<RingUnlockSchedule> {
+    RingUnlockSchedule::try_deserialize(&mut &data[..])
+}
+
+/// Decode RenameFee account data (including the 8-byte discriminator)
+pub fn decode_rename_fee(data: &[u8]) -> Result<RenameFee> {
+    RenameFee::try_deserialize(&mut &data[..])
+}
+
+/// Decode ReferralShare account data (including the 8-byte discriminator)
+pub fn decode_referral_share(data: &[u8]) -> Result<ReferralShare> {
+    ReferralShare::try_deserialize(&mut &data[..])
+}
+
+/// Decode ParcelContent account data (including the 8-byte discriminator)
+pub fn decode_parcel_content(data: &[u8]) -> Result<ParcelContent> {
+    ParcelContent::try_deserialize(&mut &data[..])
+}
+
+/// Decode ParcelPixels account data (including the 8-byte discriminator)
+pub fn decode_parcel_pixels(data: &[u8]) -> Result<ParcelPixels> {
+    ParcelPixels::try_deserialize(&mut &data[..])
+}
+
+/// Referrers ranked by lifetime earnings, then by blocks referred
+pub fn referral_leaderboard(mut referrers: Vec<ReferrerAccount>) -> Vec<ReferrerAccount> {
+    referrers.sort_by(|a, b| {
+        b.total_earned
+            .cmp(&a.total_earned)
+            .then(b.referred_blocks.cmp(&a.referred_blocks))
+    });
+    referrers
+}
+
+/// Decode the parcel id of every block from BlockMap account data, row-major over a grid
+/// [`decode_block_map_grid_size`] blocks wide
+pub fn decode_block_map(data: &[u8]) -> Result<Vec<u16>> {
+    let grid_size = decode_block_map_grid_size(data)?;
+    Ok(data[8..8 + 2 * billion_core::total_blocks(grid_size)]
+        .chunks_exact(2)
+        .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+        .collect())
+}
+
+/// Width and height of the grid a BlockMap covers, checked against the account's length
+pub fn decode_block_map_grid_size(data: &[u8]) -> Result<u16> {
+    require!(data.len() >= 8, ErrorCode::AccountDidNotDeserialize);
+    require!(
+        data[..8] == BlockMap::DISCRIMINATOR,
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+    Ok(BlockMapData::new(data)?.grid_size())
+}
+
+/// Addresses every grid instruction needs, resolved once from the GridConfig account
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GridAccounts {
+    /// Grid these addresses belong to; every grid-scoped PDA is derived for it
+    pub grid_id: u64,
+    pub grid_config: Pubkey,
+    pub authority: Pubkey,
+    pub block_map: Pubkey,
+    pub token_mint: Pubkey,
+    pub collection: Pubkey,
+    pub land_buy_reward_pool: Pubkey,
+    pub referral_vault: Pubkey,
+    pub emissions_vault: Pubkey,
+    pub quest_vault: Pubkey,
+    /// Current epoch; per-parcel PDAs in the builders are derived for this epoch
+    pub epoch: u16,
+    /// Token account receiving the treasury share of claims, once set_token_treasury has run;
+    /// it lives in the TokenTreasury PDA, so from_config leaves it unset
+    pub treasury: Option<Pubkey>,
+}
+
+impl GridAccounts {
+    /// GridConfig doesn't record its own grid id, so the caller supplies the one it was fetched for
+    pub fn from_config(grid_id: u64, config: &GridConfig) -> Self {
+        Self {
+            grid_id,
+            grid_config: find_grid_config(grid_id),
+            authority: config.authority,
+            block_map: config.block_map,
+            token_mint: config.token_mint,
+            collection: config.collection,
+            land_buy_reward_pool: config.land_buy_reward_pool,
+            referral_vault: config.referral_vault,
+            emissions_vault: config.emissions_vault,
+            quest_vault: config.quest_vault,
+            epoch: config.epoch,
+            treasury: None,
+        }
+    }
+
+    /// Addresses for a grid that has not been initialized yet
+    pub fn new(grid_id: u64, authority: Pubkey, block_map: Pubkey, token_mint: Pubkey, collection: Pubkey) -> Self {
+        let grid_config = find_grid_config(grid_id);
+        Self {
+            grid_id,
+            grid_config,
+            authority,
+            block_map,
+            token_mint,
+            collection,
+            land_buy_reward_pool: find_reward_pool(&grid_config),
+            referral_vault: find_referral_vault(&grid_config),
+            emissions_vault: find_emissions_vault(&grid_config),
+            quest_vault: find_quest_vault(&grid_config),
+            epoch: 0,
+            treasury: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use billion::state::DEFAULT_GRID_SIZE;
+
+    fn block_map_data(grid_size: u16) -> Vec<u8> {
+        let mut data = vec![0u8; BlockMap::size(grid_size)];
+        data[..8].copy_from_slice(&BlockMap::DISCRIMINATOR);
+        let trailer = data.len() - BlockMap::TRAILER_LEN;
+        data[trailer + 2..trailer + 4].copy_from_slice(&grid_size.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn test_decode_block_map() {
+        for grid_size in [DEFAULT_GRID_SIZE, 50] {
+            let mut data = block_map_data(grid_size);
+            // Block (3, 1) holds parcel 258
+            let offset = 8 + 2 * billion_core::block_index(3, 1, grid_size);
+            data[offset..offset + 2].copy_from_slice(&258u16.to_le_bytes());
+
+            assert_eq!(decode_block_map_grid_size(&data).unwrap(), grid_size);
+            let blocks = decode_block_map(&data).unwrap();
+            assert_eq!(blocks.len(), billion_core::total_blocks(grid_size));
+            assert_eq!(blocks[billion_core::block_index(3, 1, grid_size)], 258);
+            assert_eq!(blocks.iter().filter(|&&b| b != 0).count(), 1);
+        }
+
+        // Maps created before sizes could vary leave the size unset
+        let mut legacy = vec![0u8; BlockMap::SIZE];
+        legacy[..8].copy_from_slice(&BlockMap::DISCRIMINATOR);
+        assert_eq!(decode_block_map(&legacy).unwrap().len(), billion_core::MAX_TOTAL_BLOCKS);
+    }
+
+    #[test]
+    fn test_decode_block_map_rejects_bad_data() {
+        assert!(decode_block_map(&[0u8; 16]).is_err());
+        assert!(decode_block_map(&vec![0u8; BlockMap::SIZE]).is_err());
+        // A 50x50 size recorded on a 100x100 account
+        let mut data = block_map_data(DEFAULT_GRID_SIZE);
+        let trailer = data.len() - BlockMap::TRAILER_LEN;
+        data[trailer + 2..trailer + 4].copy_from_slice(&50u16.to_le_bytes());
+        assert!(decode_block_map(&data).is_err());
+    }
+
+    #[test]
+    fn test_referral_leaderboard_order() {
+        let referrer = |total_earned, referred_blocks| ReferrerAccount {
+            referrer: Pubkey::new_unique(),
+            referred_by: Pubkey::default(),
+            accrued: 0,
+            total_earned,
+            referral_count: 1,
+            referred_blocks,
+            bump: 255,
+            _reserved: [0u8; 32],
+        };
+        let ranked = referral_leaderboard(vec![referrer(10, 1), referrer(50, 1), referrer(10, 9)]);
+        let ranking: Vec<_> = ranked.iter().map(|r| (r.total_earned, r.referred_blocks)).collect();
+        assert_eq!(ranking, vec![(50, 1), (10, 9), (10, 1)]);
+    }
+
+    #[test]
+    fn test_decode_parcel_info_round_trip() {
+        let parcel_info = ParcelInfo {
+            asset: Pubkey::new_unique(),
+            x: 4,
+            y: 5,
+            width: 2,
+            height: 3,
+            bump: 254,
+            last_claimed_land_buy_rewards_per_block: 42,
+            epoch: 0,
+            last_claimed_at: 1_700_000_000,
+            adjacency_score: 3,
+            locked_until: 0,
+            lock_rewards: false,
+            named: false,
+            premium_until: 0,
+            collection_generation: 0,
+            masked_block_count: 0,
+            reserved: false,
+            rewards_excluded: false,
+            paid_total: 0,
+            claimed_at: 1_700_000_000,
+            reward_weight: 0,
+            _reserved: [0u8; 0],
+        };
+        let mut data = Vec::new();
+        parcel_info.try_serialize(&mut data).unwrap();
+
+        let decoded = decode_parcel_info(&data).unwrap();
+        assert_eq!(decoded.asset, parcel_info.asset);
+        assert_eq!(decoded.block_count(), 6);
+        assert_eq!(decoded.last_claimed_land_buy_rewards_per_block, 42);
+        assert_eq!(decoded.last_claimed_at, 1_700_000_000);
+    }
+}
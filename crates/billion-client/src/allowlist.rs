@@ -0,0 +1,53 @@
+//! Off-chain Merkle tree for the claim allowlist, hashed exactly like `claim_parcel` checks.
+
+use anchor_lang::prelude::Pubkey;
+use billion::utils::allowlist_leaf;
+
+use crate::quest::QuestTree;
+
+/// Allowlist tree over `wallets`; a wallet's leaf index is its position
+#[derive(Clone, Debug)]
+pub struct AllowlistTree {
+    wallets: Vec<Pubkey>,
+    tree: QuestTree,
+}
+
+impl AllowlistTree {
+    /// Panics if `wallets` is empty
+    pub fn new(wallets: &[Pubkey]) -> Self {
+        assert!(!wallets.is_empty(), "allowlist tree needs at least one wallet");
+        let leaves = wallets.iter().map(allowlist_leaf).collect();
+        Self {
+            wallets: wallets.to_vec(),
+            tree: QuestTree::from_leaves(leaves),
+        }
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.tree.root()
+    }
+
+    /// Proof for `wallet`, or None if it isn't on the list
+    pub fn proof(&self, wallet: &Pubkey) -> Option<Vec<[u8; 32]>> {
+        let index = self.wallets.iter().position(|listed| listed == wallet)?;
+        Some(self.tree.proof(index as u32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use billion::utils::verify_allowlist_proof;
+
+    #[test]
+    fn test_every_listed_wallet_verifies() {
+        for size in 1..=5 {
+            let wallets = (0..size).map(|_| Pubkey::new_unique()).collect::<Vec<_>>();
+            let tree = AllowlistTree::new(&wallets);
+            for wallet in &wallets {
+                assert!(verify_allowlist_proof(&tree.proof(wallet).unwrap(), &tree.root(), wallet));
+            }
+            assert_eq!(tree.proof(&Pubkey::new_unique()), None);
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use anchor_lang::prelude::*;
+use billion::constants::RING_COUNT;
+use billion_core::{block_index, get_ring, total_blocks, Rect, RESERVED_BLOCK};
+use serde::{Deserialize, Serialize};
+
+use crate::accounts::{decode_block_map, decode_block_map_grid_size};
+use crate::pda::find_parcel_info_in_epoch;
+
+/// Read-only view over decoded BlockMap account data
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockMapView {
+    grid_size: u16,
+    blocks: Vec<u16>,
+}
+
+impl BlockMapView {
+    /// Decode raw BlockMap account data (including the 8-byte discriminator)
+    pub fn from_account_data(data: &[u8]) -> Result<Self> {
+        Ok(Self {
+            grid_size: decode_block_map_grid_size(data)?,
+            blocks: decode_block_map(data)?,
+        })
+    }
+
+    /// Width and height of the grid in blocks
+    pub fn grid_size(&self) -> u16 {
+        self.grid_size
+    }
+
+    /// Parcel id at (x, y), or None if the block is unclaimed, reserved, or outside the grid
+    pub fn get(&self, x: u8, y: u8) -> Option<u16> {
+        if (x as u16) >= self.grid_size || (y as u16) >= self.grid_size {
+            return None;
+        }
+        match self.blocks[block_index(x, y, self.grid_size)] {
+            0 | RESERVED_BLOCK => None,
+            parcel_id => Some(parcel_id),
+        }
+    }
+
+    /// ParcelInfo address of the parcel covering (x, y), this being grid `grid_id`'s BlockMap
+    /// for `epoch`. Its `asset` is the parcel's NFT, so a clicked block resolves in one more
+    /// account fetch. None if the block is unclaimed, reserved, or outside the grid.
+    pub fn parcel_info_at(&self, grid_id: u64, epoch: u16, x: u8, y: u8) -> Option<Pubkey> {
+        self.get(x, y).map(|parcel_id| find_parcel_info_in_epoch(grid_id, epoch, parcel_id))
+    }
+
+    /// Whether (x, y) was taken out of play by admin_reserve_blocks
+    pub fn is_reserved(&self, x: u8, y: u8) -> bool {
+        (x as u16) < self.grid_size
+            && (y as u16) < self.grid_size
+            && self.blocks[block_index(x, y, self.grid_size)] == RESERVED_BLOCK
+    }
+
+    /// Row-major (x, y, parcel id) for every block of `rect`, 0 meaning unclaimed and
+    /// RESERVED_BLOCK reserved. Blocks outside the grid are skipped.
+    pub fn iter_region(&self, rect: Rect) -> impl Iterator<Item = (u8, u8, u16)> + '_ {
+        let grid_size = self.grid_size;
+        rect.blocks()
+            .filter(move |&(x, y)| (x as u16) < grid_size && (y as u16) < grid_size)
+            .map(move |(x, y)| (x, y, self.blocks[block_index(x, y, grid_size)]))
+    }
+
+    /// Claimed block count per ring of a grid of `ring_count` rings (GridConfig::ring_count),
+    /// index 0 being ring 1 (outermost); reserved blocks don't count
+    pub fn occupancy_by_ring(&self, ring_count: u8) -> [u32; RING_COUNT as usize] {
+        let mut occupancy = [0u32; RING_COUNT as usize];
+        let side = self.grid_size as u8;
+        for (x, y, parcel_id) in self.iter_region(Rect::new(0, 0, side, side)) {
+            if parcel_id != 0 && parcel_id != RESERVED_BLOCK {
+                occupancy[(get_ring(x, y, self.grid_size, ring_count) - 1) as usize] += 1;
+            }
+        }
+        occupancy
+    }
+
+    /// Every `min_w` x `min_h` rectangle that is entirely unclaimed and unreserved, in row-major
+    /// order of its top-left corner. Candidates overlap; ring locks are not taken into account.
+    pub fn find_free_rects(&self, min_w: u8, min_h: u8) -> Vec<Rect> {
+        if !billion_core::rect_in_bounds(0, 0, min_w, min_h, self.grid_size) {
+            return Vec::new();
+        }
+
+        // claimed[y][x] = claimed blocks in the rectangle (0, 0)..(x, y), exclusive
+        let side = self.grid_size as usize;
+        let stride = side + 1;
+        let mut claimed = vec![0u32; stride * stride];
+        for y in 0..side {
+            for x in 0..side {
+                let occupied = (self.blocks[y * side + x] != 0) as u32;
+                claimed[(y + 1) * stride + x + 1] =
+                    occupied + claimed[y * stride + x + 1] + claimed[(y + 1) * stride + x] - claimed[y * stride + x];
+            }
+        }
+
+        let (w, h) = (min_w as usize, min_h as usize);
+        let mut rects = Vec::new();
+        for y in 0..=side - h {
+            for x in 0..=side - w {
+                let count = claimed[(y + h) * stride + x + w] + claimed[y * stride + x]
+                    - claimed[y * stride + x + w]
+                    - claimed[(y + h) * stride + x];
+                if count == 0 {
+                    rects.push(Rect::new(x as u8, y as u8, min_w, min_h));
+                }
+            }
+        }
+        rects
+    }
+
+    /// One bit per block, row-major, least significant bit first; set means claimed or reserved
+    pub fn claimed_bitmap(&self) -> Vec<u8> {
+        let mut bitmap = vec![0u8; self.blocks.len().div_ceil(8)];
+        for (i, &parcel_id) in self.blocks.iter().enumerate() {
+            if parcel_id != 0 {
+                bitmap[i / 8] |= 1 << (i % 8);
+            }
+        }
+        bitmap
+    }
+
+    /// Blocks held by parcels, as counted by GridConfig.total_claimed_blocks
+    pub fn claimed_blocks(&self) -> u32 {
+        self.blocks
+            .iter()
+            .filter(|&&parcel_id| parcel_id != 0 && parcel_id != RESERVED_BLOCK)
+            .count() as u32
+    }
+
+    pub fn reserved_blocks(&self) -> u32 {
+        self.blocks.iter().filter(|&&parcel_id| parcel_id == RESERVED_BLOCK).count() as u32
+    }
+
+    /// Run-length encoded export, typically a few hundred bytes of JSON for a busy grid
+    pub fn to_snapshot(&self) -> BlockMapSnapshot {
+        let mut runs: Vec<(u16, u32)> = Vec::new();
+        for &parcel_id in &self.blocks {
+            match runs.last_mut() {
+                Some((last, len)) if *last == parcel_id => *len += 1,
+                _ => runs.push((parcel_id, 1)),
+            }
+        }
+        BlockMapSnapshot {
+            grid_size: self.grid_size as u8,
+            runs,
+        }
+    }
+
+    /// Rebuild a view from a snapshot; fails if the runs do not cover the grid exactly
+    pub fn from_snapshot(snapshot: &BlockMapSnapshot) -> Result<Self> {
+        let grid_size = snapshot.grid_size as u16;
+        require!(
+            billion_core::grid_size_is_valid(grid_size),
+            ErrorCode::AccountDidNotDeserialize
+        );
+        let total = total_blocks(grid_size);
+        let mut blocks = Vec::with_capacity(total);
+        for &(parcel_id, len) in &snapshot.runs {
+            require!(
+                blocks.len() + len as usize <= total,
+                ErrorCode::AccountDidNotDeserialize
+            );
+            blocks.resize(blocks.len() + len as usize, parcel_id);
+        }
+        require!(blocks.len() == total, ErrorCode::AccountDidNotDeserialize);
+        Ok(Self { grid_size, blocks })
+    }
+}
+
+/// Serializable BlockMap contents: row-major runs of (parcel id, block count), 0 meaning unclaimed
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockMapSnapshot {
+    pub grid_size: u8,
+    pub runs: Vec<(u16, u32)>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+    use billion::state::{BlockMap, DEFAULT_GRID_SIZE};
+
+    fn view_on(grid_size: u16, parcels: &[(u16, Rect)]) -> BlockMapView {
+        let mut data = vec![0u8; BlockMap::size(grid_size)];
+        data[..8].copy_from_slice(&BlockMap::DISCRIMINATOR);
+        let trailer = data.len() - BlockMap::TRAILER_LEN;
+        data[trailer + 2..trailer + 4].copy_from_slice(&grid_size.to_le_bytes());
+        for &(parcel_id, rect) in parcels {
+            for (x, y) in rect.blocks() {
+                let offset = 8 + 2 * block_index(x, y, grid_size);
+                data[offset..offset + 2].copy_from_slice(&parcel_id.to_le_bytes());
+            }
+        }
+        BlockMapView::from_account_data(&data).unwrap()
+    }
+
+    fn view_with(parcels: &[(u16, Rect)]) -> BlockMapView {
+        view_on(DEFAULT_GRID_SIZE, parcels)
+    }
+
+    #[test]
+    fn test_get_and_iter_region() {
+        let view = view_with(&[(7, Rect::new(10, 20, 2, 2))]);
+        assert_eq!(view.get(10, 20), Some(7));
+        assert_eq!(view.get(11, 21), Some(7));
+        assert_eq!(view.get(12, 20), None);
+        assert_eq!(view.get(200, 0), None);
+
+        let region: Vec<_> = view.iter_region(Rect::new(11, 20, 2, 1)).collect();
+        assert_eq!(region, vec![(11, 20, 7), (12, 20, 0)]);
+        assert_eq!(view.iter_region(Rect::new(98, 98, 5, 5)).count(), 4);
+    }
+
+    #[test]
+    fn test_occupancy_by_ring() {
+        let view = view_with(&[(1, Rect::new(0, 0, 3, 2)), (2, Rect::new(50, 50, 1, 1))]);
+        let occupancy = view.occupancy_by_ring(RING_COUNT);
+        assert_eq!(occupancy[0], 6);
+        assert_eq!(occupancy[9], 1);
+        assert_eq!(occupancy.iter().sum::<u32>(), view.claimed_blocks());
+        // With 5 rings the center block is in ring 5 and nothing counts past it
+        assert_eq!(view.occupancy_by_ring(5), [6, 0, 0, 0, 1, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_find_free_rects() {
+        let view = view_with(&[]);
+        assert_eq!(view.find_free_rects(100, 100), vec![Rect::new(0, 0, 100, 100)]);
+        assert_eq!(view.find_free_rects(99, 100).len(), 2);
+        assert!(view.find_free_rects(0, 1).is_empty());
+        assert!(view.find_free_rects(101, 1).is_empty());
+
+        // A claimed row at y = 50 leaves 50 free rows above and 49 below
+        let view = view_with(&[(1, Rect::new(0, 50, 100, 1))]);
+        assert_eq!(view.find_free_rects(100, 50), vec![Rect::new(0, 0, 100, 50)]);
+        assert_eq!(view.find_free_rects(100, 49).len(), 3);
+    }
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let view = view_with(&[(1, Rect::new(0, 0, 3, 2)), (2, Rect::new(50, 50, 4, 4))]);
+        let snapshot = view.to_snapshot();
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let decoded: BlockMapSnapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(BlockMapView::from_snapshot(&decoded).unwrap(), view);
+
+        let mut truncated = snapshot.clone();
+        truncated.runs.pop();
+        assert!(BlockMapView::from_snapshot(&truncated).is_err());
+    }
+
+    #[test]
+    fn test_claimed_bitmap() {
+        let view = view_with(&[(1, Rect::new(0, 0, 3, 1)), (2, Rect::new(99, 99, 1, 1))]);
+        let bitmap = view.claimed_bitmap();
+        assert_eq!(bitmap.len(), 1250);
+        assert_eq!(bitmap[0], 0b111);
+        assert_eq!(bitmap[1249], 0b1000_0000);
+    }
+
+    #[test]
+    fn test_reserved_blocks_are_taken_but_not_claimed() {
+        let view = view_with(&[(1, Rect::new(0, 0, 2, 2)), (RESERVED_BLOCK, Rect::new(0, 50, 100, 1))]);
+        assert_eq!(view.get(10, 50), None);
+        assert!(view.is_reserved(10, 50));
+        assert!(!view.is_reserved(0, 0));
+        assert!(!view.is_reserved(100, 50));
+        assert_eq!((view.claimed_blocks(), view.reserved_blocks()), (4, 100));
+        assert_eq!(view.occupancy_by_ring(RING_COUNT).iter().sum::<u32>(), 4);
+        assert!(view.find_free_rects(100, 49).iter().all(|rect| !rect.contains(0, 50)));
+        assert_eq!(view.claimed_bitmap()[50 * 100 / 8], 0xff);
+    }
+
+    #[test]
+    fn test_small_grid() {
+        let view = view_on(50, &[(1, Rect::new(0, 0, 2, 1)), (2, Rect::new(25, 25, 1, 1))]);
+        assert_eq!(view.grid_size(), 50);
+        assert_eq!(view.get(49, 49), None);
+        assert_eq!(view.get(50, 0), None);
+        assert_eq!(view.iter_region(Rect::new(48, 48, 5, 5)).count(), 4);
+
+        let occupancy = view.occupancy_by_ring(RING_COUNT);
+        assert_eq!((occupancy[0], occupancy[9]), (2, 1));
+        assert_eq!(view.find_free_rects(50, 24), vec![Rect::new(0, 1, 50, 24), Rect::new(0, 26, 50, 24)]);
+        assert!(view.find_free_rects(51, 1).is_empty());
+        assert_eq!(view.claimed_bitmap().len(), 313);
+
+        let snapshot = view.to_snapshot();
+        assert_eq!(snapshot.grid_size, 50);
+        assert_eq!(BlockMapView::from_snapshot(&snapshot).unwrap(), view);
+    }
+}
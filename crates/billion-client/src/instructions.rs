@@ -0,0 +1,2743 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    ed25519_program, system_instruction, sysvar,
+};
+use anchor_lang::{system_program, InstructionData, ToAccountMetas};
+use anchor_spl::{associated_token, token_2022};
+use billion::constants::RING_COUNT;
+use billion::instructions::admin_mint_batch::AdminMintEntry;
+use billion::instructions::claim_parcel::MPL_CORE_ID;
+use billion::instructions::claim_parcels_batch::BatchRect;
+use billion::instructions::claim_parcel_signed::ClaimAuthorization;
+use billion::state::{BlockMap, Fraction, TimelockedConfig, DEFAULT_GRID_SIZE};
+use billion_core::Rect;
+
+use crate::accounts::GridAccounts;
+use crate::pda::{
+    find_admin_mint_allowance, find_admin_roles, find_allowlist, find_asset_in_epoch, find_attestation,
+    find_charity_registry, find_claim_nonce, find_claimer_stats, find_claims_pause, find_config_freeze,
+    find_config_timelock, find_distribution_vault, find_epoch_archive, find_fraction, find_fraction_escrow,
+    find_fraction_position, find_fraction_vault, find_grid_config, find_harberger_district, find_hook_config,
+    find_map_digest, find_name_record, find_parcel_content_in_epoch, find_parcel_id_pool, find_parcel_info_in_epoch,
+    find_parcel_mask_in_epoch, find_parcel_pixels_in_epoch, find_parcel_valuation_in_epoch, find_quest,
+    find_quest_claims, find_raffle, find_referral_share, find_referrer_account, find_rename_fee,
+    find_reward_delegate_in_epoch, find_reward_dust, find_reward_exclusions, find_ring_occupancy, find_ring_pricing,
+    find_ring_reward_weights, find_ring_unlock_schedule, find_sol_reward_checkpoint_in_epoch, find_sol_reward_vault,
+    find_sol_rewards, find_sol_treasury, find_stake_account_in_epoch, find_token_account, find_token_treasury,
+    find_voucher,
+};
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: billion::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// Two-step BlockMap setup: a top-level `create_account` (the account is too large to be
+/// created via CPI) followed by `create_block_map`. Both must be in the same transaction,
+/// signed by `payer` and the `block_map` keypair. `lamports` is the rent-exempt minimum
+/// for [`BlockMap::SIZE`] bytes.
+///
+/// ```
+/// use anchor_lang::prelude::Pubkey;
+/// use billion_client::create_block_map;
+///
+/// let payer = Pubkey::new_unique();
+/// let block_map = Pubkey::new_unique();
+/// let [create_account, init] = create_block_map(&payer, &block_map, 140_000_000);
+/// assert_eq!(init.program_id, billion::ID);
+/// assert_eq!(create_account.accounts[1].pubkey, block_map);
+/// ```
+pub fn create_block_map(payer: &Pubkey, block_map: &Pubkey, lamports: u64) -> [Instruction; 2] {
+    create_sized_block_map(payer, block_map, lamports, DEFAULT_GRID_SIZE)
+}
+
+/// [`create_block_map`] for a `grid_size` x `grid_size` grid; `lamports` is the rent-exempt
+/// minimum for `BlockMap::size(grid_size)` bytes
+pub fn create_sized_block_map(payer: &Pubkey, block_map: &Pubkey, lamports: u64, grid_size: u16) -> [Instruction; 2] {
+    let space = BlockMap::size(grid_size) as u64;
+    [
+        system_instruction::create_account(payer, block_map, lamports, space, &billion::ID),
+        build(
+            billion::accounts::CreateBlockMap {
+                payer: *payer,
+                block_map: *block_map,
+            },
+            billion::instruction::CreateBlockMap { grid_size },
+        ),
+    ]
+}
+
+/// Arguments for `initialize`
+#[derive(Clone, Debug, Default)]
+pub struct InitializeArgs {
+    pub price_per_block: u64,
+    pub ring_thresholds: Vec<u64>,
+    pub uri_base: String,
+    pub land_owners_reward_share_bps: u16,
+    /// Largest width * height a claim may cover (0 = unlimited)
+    pub max_parcel_blocks: u16,
+}
+
+pub fn initialize(grid: &GridAccounts, args: InitializeArgs) -> Instruction {
+    build(
+        billion::accounts::Initialize {
+            authority: grid.authority,
+            token_mint: grid.token_mint,
+            grid_config: grid.grid_config,
+            block_map: grid.block_map,
+            land_buy_reward_pool: grid.land_buy_reward_pool,
+            token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            admin_roles: find_admin_roles(grid.grid_id),
+            ring_occupancy: find_ring_occupancy(grid.grid_id),
+            map_digest: find_map_digest(grid.grid_id),
+        },
+        billion::instruction::Initialize {
+            grid_id: grid.grid_id,
+            price_per_block: args.price_per_block,
+            ring_thresholds: args.ring_thresholds,
+            uri_base: args.uri_base,
+            land_owners_reward_share_bps: args.land_owners_reward_share_bps,
+            max_parcel_blocks: args.max_parcel_blocks,
+        },
+    )
+}
+
+/// Arguments for `update_config`; `None` leaves a field unchanged
+///
+/// ```
+/// use anchor_lang::prelude::Pubkey;
+/// use billion_client::{update_config, UpdateConfigArgs};
+///
+/// let authority = Pubkey::new_unique();
+/// let ix = update_config(0, &authority, UpdateConfigArgs {
+///     price_per_block: Some(2_000_000),
+///     ..Default::default()
+/// });
+/// assert_eq!(ix.accounts[0].pubkey, authority);
+/// assert!(ix.accounts[0].is_signer);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct UpdateConfigArgs {
+    pub price_per_block: Option<u64>,
+    pub ring_thresholds: Option<Vec<u64>>,
+    pub uri_base: Option<String>,
+    pub seeding_enabled: Option<bool>,
+    pub collection: Option<Pubkey>,
+    pub land_owners_reward_share_bps: Option<u16>,
+    /// Must be None; rings are unlocked by hand with admin_advance_ring
+    pub total_burned: Option<u64>,
+    pub max_parcel_blocks: Option<u16>,
+    pub max_blocks_per_wallet: Option<u32>,
+    pub claims_enabled: Option<bool>,
+    /// One per ring of the grid, outermost first
+    pub ring_price_multipliers_bps: Option<Vec<u16>>,
+    pub curve_divisor: Option<u64>,
+    pub allowlist_root: Option<[u8; 32]>,
+    pub allowlist_only: Option<bool>,
+    /// Added to the admin mints allowed while seeding is disabled
+    pub admin_mint_allowance_top_up: Option<u32>,
+    /// Needed to replace the collection once parcels have been minted under it
+    pub force_collection: Option<bool>,
+    /// When each ring opens whatever has been burned; an empty schedule leaves it to the burns
+    pub ring_unlock_timestamps: Option<Vec<i64>>,
+}
+
+pub fn update_config(grid_id: u64, authority: &Pubkey, args: UpdateConfigArgs) -> Instruction {
+    build(
+        billion::accounts::UpdateConfig {
+            authority: *authority,
+            grid_config: find_grid_config(grid_id),
+            ring_pricing: find_ring_pricing(grid_id),
+            allowlist: find_allowlist(grid_id),
+            admin_mint_allowance: find_admin_mint_allowance(grid_id),
+            claims_pause: find_claims_pause(grid_id),
+            ring_unlock_schedule: find_ring_unlock_schedule(grid_id),
+            system_program: system_program::ID,
+            admin_roles: find_admin_roles(grid_id),
+            config_timelock: find_config_timelock(grid_id),
+            token_treasury: find_token_treasury(grid_id),
+            referral_share: find_referral_share(grid_id),
+            config_freeze: find_config_freeze(grid_id),
+        },
+        billion::instruction::UpdateConfig {
+            grid_id,
+            price_per_block: args.price_per_block,
+            ring_thresholds: args.ring_thresholds,
+            uri_base: args.uri_base,
+            seeding_enabled: args.seeding_enabled,
+            collection: args.collection,
+            land_owners_reward_share_bps: args.land_owners_reward_share_bps,
+            total_burned: args.total_burned,
+            max_parcel_blocks: args.max_parcel_blocks,
+            max_blocks_per_wallet: args.max_blocks_per_wallet,
+            claims_enabled: args.claims_enabled,
+            ring_price_multipliers_bps: args.ring_price_multipliers_bps,
+            curve_divisor: args.curve_divisor,
+            allowlist_root: args.allowlist_root,
+            allowlist_only: args.allowlist_only,
+            admin_mint_allowance_top_up: args.admin_mint_allowance_top_up,
+            force_collection: args.force_collection,
+            ring_unlock_timestamps: args.ring_unlock_timestamps,
+        },
+    )
+}
+
+/// Config-authority-only: create the Core collection in the `collection` keypair, which must
+/// also sign, and point the grid at it
+pub fn create_collection(grid_id: u64, authority: &Pubkey, collection: &Pubkey, name: String, uri: String) -> Instruction {
+    build(
+        billion::accounts::CreateCollection {
+            authority: *authority,
+            grid_config: find_grid_config(grid_id),
+            admin_roles: find_admin_roles(grid_id),
+            collection: *collection,
+            mpl_core_program: MPL_CORE_ID,
+            system_program: system_program::ID,
+            config_freeze: find_config_freeze(grid_id),
+        },
+        billion::instruction::CreateCollection { grid_id, name, uri },
+    )
+}
+
+/// Claim `rect` as parcel `parcel_id` (the last id in the ParcelIdPool, else the current
+/// `next_parcel_id`), minting into the `asset` keypair which must also sign. `referrer` is the wallet of a registered referrer.
+///
+/// ```
+/// use anchor_lang::prelude::Pubkey;
+/// use billion_client::{claim_parcel, find_parcel_info, GridAccounts, Rect};
+///
+/// let grid = GridAccounts::new(
+///     0,
+///     Pubkey::new_unique(),
+///     Pubkey::new_unique(),
+///     Pubkey::new_unique(),
+///     Pubkey::new_unique(),
+/// );
+/// let claimer = Pubkey::new_unique();
+/// let asset = Pubkey::new_unique();
+/// let ix = claim_parcel(&grid, &claimer, &asset, 1, Rect::new(0, 0, 2, 2), None);
+/// assert!(ix.accounts.iter().any(|meta| meta.pubkey == find_parcel_info(0, 1)));
+/// ```
+pub fn claim_parcel(
+    grid: &GridAccounts,
+    claimer: &Pubkey,
+    asset: &Pubkey,
+    parcel_id: u16,
+    rect: Rect,
+    referrer: Option<&Pubkey>,
+) -> Instruction {
+    claim_parcel_with_charity(grid, claimer, asset, parcel_id, rect, referrer, None)
+}
+
+/// A buyer's choice of registered charity for `claim_parcel_with_charity`
+#[derive(Clone, Copy, Debug)]
+pub struct CharityChoice {
+    /// Position in the CharityRegistry
+    pub index: u8,
+    /// Basis points of the cost routed to the charity instead of burned
+    pub bps: u16,
+    /// The registered token account at `index`
+    pub token_account: Pubkey,
+}
+
+/// `claim_parcel`, routing part of the burned portion to a registered charity
+pub fn claim_parcel_with_charity(
+    grid: &GridAccounts,
+    claimer: &Pubkey,
+    asset: &Pubkey,
+    parcel_id: u16,
+    rect: Rect,
+    referrer: Option<&Pubkey>,
+    charity: Option<CharityChoice>,
+) -> Instruction {
+    build(
+        claim_parcel_accounts(grid, claimer, asset, parcel_id, referrer, charity, false),
+        billion::instruction::ClaimParcel {
+            grid_id: grid.grid_id,
+            x: rect.x,
+            y: rect.y,
+            width: rect.width,
+            height: rect.height,
+            dry_run: false,
+            charity_index: charity.map(|charity| charity.index),
+            charity_bps: charity.map_or(0, |charity| charity.bps),
+            allowlist_proof: None,
+        },
+    )
+}
+
+/// `claim_parcel` with a Merkle proof of the claimer's allowlist leaf, the only way to claim
+/// while allowlist_only is set
+pub fn claim_parcel_allowlisted(
+    grid: &GridAccounts,
+    claimer: &Pubkey,
+    asset: &Pubkey,
+    parcel_id: u16,
+    rect: Rect,
+    proof: Vec<[u8; 32]>,
+) -> Instruction {
+    build(
+        claim_parcel_accounts(grid, claimer, asset, parcel_id, None, None, true),
+        billion::instruction::ClaimParcel {
+            grid_id: grid.grid_id,
+            x: rect.x,
+            y: rect.y,
+            width: rect.width,
+            height: rect.height,
+            dry_run: false,
+            charity_index: None,
+            charity_bps: 0,
+            allowlist_proof: Some(proof),
+        },
+    )
+}
+
+/// `claim_parcel` paid by `claimer` with the asset minted to `recipient`
+pub fn claim_parcel_gift(
+    grid: &GridAccounts,
+    claimer: &Pubkey,
+    recipient: &Pubkey,
+    asset: &Pubkey,
+    parcel_id: u16,
+    rect: Rect,
+) -> Instruction {
+    let mut accounts = claim_parcel_accounts(grid, claimer, asset, parcel_id, None, None, false);
+    accounts.recipient = Some(*recipient);
+    build(
+        accounts,
+        billion::instruction::ClaimParcel {
+            grid_id: grid.grid_id,
+            x: rect.x,
+            y: rect.y,
+            width: rect.width,
+            height: rect.height,
+            dry_run: false,
+            charity_index: None,
+            charity_bps: 0,
+            allowlist_proof: None,
+        },
+    )
+}
+
+fn claim_parcel_accounts(
+    grid: &GridAccounts,
+    claimer: &Pubkey,
+    asset: &Pubkey,
+    parcel_id: u16,
+    referrer: Option<&Pubkey>,
+    charity: Option<CharityChoice>,
+    allowlisted: bool,
+) -> billion::accounts::ClaimParcel {
+    billion::accounts::ClaimParcel {
+        claimer: *claimer,
+        grid_config: grid.grid_config,
+        block_map: grid.block_map,
+        token_mint: grid.token_mint,
+        claimer_token_account: find_token_account(claimer, &grid.token_mint),
+        land_buy_reward_pool: grid.land_buy_reward_pool,
+        claimer_stats: find_claimer_stats(grid.grid_id, claimer),
+        reward_dust: find_reward_dust(grid.grid_id),
+        parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+        asset: *asset,
+        collection: grid.collection,
+        mpl_core_program: MPL_CORE_ID,
+        token_program: token_2022::ID,
+        associated_token_program: associated_token::ID,
+        system_program: system_program::ID,
+        hook_config: find_hook_config(grid.grid_id),
+        ring_pricing: find_ring_pricing(grid.grid_id),
+        parcel_id_pool: find_parcel_id_pool(grid.grid_id),
+        referrer_account: referrer.map(|referrer| find_referrer_account(grid.grid_id, referrer)),
+        referral_vault: referrer.map(|_| grid.referral_vault),
+        charity_registry: charity.map(|_| find_charity_registry(grid.grid_id)),
+        charity_token_account: charity.map(|charity| charity.token_account),
+        allowlist: allowlisted.then(|| find_allowlist(grid.grid_id)),
+        recipient: None,
+        ring_reward_weights: find_ring_reward_weights(grid.grid_id),
+        ring_occupancy: find_ring_occupancy(grid.grid_id),
+        map_digest: find_map_digest(grid.grid_id),
+        ring_unlock_schedule: find_ring_unlock_schedule(grid.grid_id),
+        token_treasury: find_token_treasury(grid.grid_id),
+        treasury_token_account: grid.treasury,
+        referral_share: find_referral_share(grid.grid_id),
+    }
+}
+
+/// `claim_parcel` with `dry_run` set: simulate it to read a
+/// [`ClaimPreview`](billion::instructions::ClaimPreview) from return data. The transaction
+/// always fails with `DryRunSuccess` and no asset is created, so it can be simulated without
+/// signature verification instead of signing with a real asset keypair.
+pub fn claim_parcel_dry_run(
+    grid: &GridAccounts,
+    claimer: &Pubkey,
+    asset: &Pubkey,
+    parcel_id: u16,
+    rect: Rect,
+    referrer: Option<&Pubkey>,
+) -> Instruction {
+    let mut ix = claim_parcel(grid, claimer, asset, parcel_id, rect, referrer);
+    ix.data = billion::instruction::ClaimParcel {
+        grid_id: grid.grid_id,
+        x: rect.x,
+        y: rect.y,
+        width: rect.width,
+        height: rect.height,
+        dry_run: true,
+        charity_index: None,
+        charity_bps: 0,
+        allowlist_proof: None,
+    }
+    .data();
+    ix
+}
+
+/// Claim `rect` as parcel `parcel_id` with a program-derived asset address
+pub fn claim_parcel_pda(
+    grid: &GridAccounts,
+    claimer: &Pubkey,
+    parcel_id: u16,
+    rect: Rect,
+    referrer: Option<&Pubkey>,
+) -> Instruction {
+    build(
+        billion::accounts::ClaimParcelPda {
+            claimer: *claimer,
+            grid_config: grid.grid_config,
+            block_map: grid.block_map,
+            token_mint: grid.token_mint,
+            claimer_token_account: find_token_account(claimer, &grid.token_mint),
+            land_buy_reward_pool: grid.land_buy_reward_pool,
+            claimer_stats: find_claimer_stats(grid.grid_id, claimer),
+            reward_dust: find_reward_dust(grid.grid_id),
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            asset: find_asset_in_epoch(&grid.grid_config, grid.epoch, parcel_id),
+            collection: grid.collection,
+            mpl_core_program: MPL_CORE_ID,
+            token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            hook_config: find_hook_config(grid.grid_id),
+            ring_pricing: find_ring_pricing(grid.grid_id),
+            referrer_account: referrer.map(|referrer| find_referrer_account(grid.grid_id, referrer)),
+            referral_vault: referrer.map(|_| grid.referral_vault),
+            ring_reward_weights: find_ring_reward_weights(grid.grid_id),
+            ring_occupancy: find_ring_occupancy(grid.grid_id),
+            map_digest: find_map_digest(grid.grid_id),
+            ring_unlock_schedule: find_ring_unlock_schedule(grid.grid_id),
+            token_treasury: find_token_treasury(grid.grid_id),
+            treasury_token_account: grid.treasury,
+        },
+        billion::instruction::ClaimParcelPda {
+            grid_id: grid.grid_id,
+            x: rect.x,
+            y: rect.y,
+            width: rect.width,
+            height: rect.height,
+        },
+    )
+}
+
+pub fn admin_mint(grid: &GridAccounts, recipient: &Pubkey, asset: &Pubkey, parcel_id: u16, rect: Rect) -> Instruction {
+    admin_mint_with_lock(grid, recipient, asset, parcel_id, rect, None, false)
+}
+
+/// Admin mint under a permanent freeze only admin_thaw_parcel can lift
+pub fn admin_mint_soulbound(grid: &GridAccounts, recipient: &Pubkey, asset: &Pubkey, parcel_id: u16, rect: Rect) -> Instruction {
+    admin_mint_instruction(grid, recipient, asset, parcel_id, rect, None, false, true, false)
+}
+
+/// Admin mint left out of land-buy rewards until admin_set_rewards_excluded includes it
+pub fn admin_mint_rewards_excluded(grid: &GridAccounts, recipient: &Pubkey, asset: &Pubkey, parcel_id: u16, rect: Rect) -> Instruction {
+    admin_mint_instruction(grid, recipient, asset, parcel_id, rect, None, false, false, true)
+}
+
+/// Admin mint that freezes the asset until `locked_until`; `lock_rewards` also holds back
+/// its land-buy rewards until unlock_vested_parcel
+pub fn admin_mint_with_lock(
+    grid: &GridAccounts,
+    recipient: &Pubkey,
+    asset: &Pubkey,
+    parcel_id: u16,
+    rect: Rect,
+    locked_until: Option<i64>,
+    lock_rewards: bool,
+) -> Instruction {
+    admin_mint_instruction(grid, recipient, asset, parcel_id, rect, locked_until, lock_rewards, false, false)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn admin_mint_instruction(
+    grid: &GridAccounts,
+    recipient: &Pubkey,
+    asset: &Pubkey,
+    parcel_id: u16,
+    rect: Rect,
+    locked_until: Option<i64>,
+    lock_rewards: bool,
+    soulbound: bool,
+    rewards_excluded: bool,
+) -> Instruction {
+    build(
+        billion::accounts::AdminMint {
+            authority: grid.authority,
+            recipient: *recipient,
+            grid_config: grid.grid_config,
+            block_map: grid.block_map,
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            asset: *asset,
+            collection: grid.collection,
+            mpl_core_program: MPL_CORE_ID,
+            system_program: system_program::ID,
+            admin_mint_allowance: find_admin_mint_allowance(grid.grid_id),
+            reward_exclusions: find_reward_exclusions(grid.grid_id),
+            ring_reward_weights: find_ring_reward_weights(grid.grid_id),
+            ring_occupancy: find_ring_occupancy(grid.grid_id),
+            map_digest: find_map_digest(grid.grid_id),
+            config_freeze: find_config_freeze(grid.grid_id),
+        },
+        billion::instruction::AdminMint {
+            grid_id: grid.grid_id,
+            x: rect.x,
+            y: rect.y,
+            width: rect.width,
+            height: rect.height,
+            locked_until,
+            lock_rewards,
+            soulbound,
+            rewards_excluded,
+        },
+    )
+}
+
+pub fn update_parcel_metadata(
+    grid: &GridAccounts,
+    asset: &Pubkey,
+    parcel_id: u16,
+    new_name: Option<String>,
+    new_uri: Option<String>,
+) -> Instruction {
+    build(
+        billion::accounts::UpdateParcelMetadata {
+            authority: grid.authority,
+            grid_config: grid.grid_config,
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            asset: *asset,
+            collection: grid.collection,
+            mpl_core_program: MPL_CORE_ID,
+            system_program: system_program::ID,
+            admin_roles: find_admin_roles(grid.grid_id),
+        },
+        billion::instruction::UpdateParcelMetadata { grid_id: grid.grid_id, parcel_id, new_name, new_uri },
+    )
+}
+
+/// Metadata-authority-only: point each current-epoch parcel's uri at a uri base and its id.
+/// With `uri_base` only the assets are passed; without it the grid's uri_base is used and
+/// each asset is checked against its ParcelInfo.
+pub fn update_parcels_metadata_batch(
+    grid: &GridAccounts,
+    authority: &Pubkey,
+    parcels: &[(u16, Pubkey)],
+    uri_base: Option<&str>,
+) -> Instruction {
+    let mut ix = build(
+        billion::accounts::UpdateParcelsMetadataBatch {
+            authority: *authority,
+            grid_config: grid.grid_config,
+            admin_roles: find_admin_roles(grid.grid_id),
+            collection: grid.collection,
+            mpl_core_program: MPL_CORE_ID,
+            system_program: system_program::ID,
+        },
+        billion::instruction::UpdateParcelsMetadataBatch {
+            grid_id: grid.grid_id,
+            parcel_ids: parcels.iter().map(|&(parcel_id, _)| parcel_id).collect(),
+            uri_base: uri_base.map(str::to_string),
+        },
+    );
+    for &(parcel_id, asset) in parcels {
+        if uri_base.is_none() {
+            let parcel_info = find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id);
+            ix.accounts.push(AccountMeta::new_readonly(parcel_info, false));
+        }
+        ix.accounts.push(AccountMeta::new(asset, false));
+    }
+    ix
+}
+
+/// Metadata-authority-only: burn `fee` tokens for each owner_update_parcel; 0 disables renames
+pub fn set_rename_fee(grid_id: u64, authority: &Pubkey, fee: u64) -> Instruction {
+    build(
+        billion::accounts::SetRenameFee {
+            authority: *authority,
+            grid_config: find_grid_config(grid_id),
+            admin_roles: find_admin_roles(grid_id),
+            rename_fee: find_rename_fee(grid_id),
+            system_program: system_program::ID,
+        },
+        billion::instruction::SetRenameFee { grid_id, fee },
+    )
+}
+
+/// Config-authority-only: pay `share_bps`, at most MAX_REFERRAL_SHARE_BPS, of each claim_parcel
+/// cost to the referrer token account passed with it; 0 disables the share
+pub fn set_referral_share(grid_id: u64, authority: &Pubkey, share_bps: u16) -> Instruction {
+    build(
+        billion::accounts::SetReferralShare {
+            authority: *authority,
+            grid_config: find_grid_config(grid_id),
+            admin_roles: find_admin_roles(grid_id),
+            referral_share: find_referral_share(grid_id),
+            system_program: system_program::ID,
+            token_treasury: find_token_treasury(grid_id),
+            config_freeze: find_config_freeze(grid_id),
+        },
+        billion::instruction::SetReferralShare { grid_id, share_bps },
+    )
+}
+
+/// Rename current-epoch `parcel_id`, owned by `owner`, burning the rename fee from the
+/// owner's associated token account. A `new_uri_suffix` points the uri at uri_base, the
+/// parcel id and the suffix.
+pub fn owner_update_parcel(
+    grid: &GridAccounts,
+    owner: &Pubkey,
+    asset: &Pubkey,
+    parcel_id: u16,
+    new_name: &str,
+    new_uri_suffix: Option<&str>,
+) -> Instruction {
+    build(
+        billion::accounts::OwnerUpdateParcel {
+            owner: *owner,
+            grid_config: grid.grid_config,
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            rename_fee: find_rename_fee(grid.grid_id),
+            owner_token_account: find_token_account(owner, &grid.token_mint),
+            token_mint: grid.token_mint,
+            token_program: token_2022::ID,
+            ring_unlock_schedule: find_ring_unlock_schedule(grid.grid_id),
+            asset: *asset,
+            collection: grid.collection,
+            mpl_core_program: MPL_CORE_ID,
+            system_program: system_program::ID,
+        },
+        billion::instruction::OwnerUpdateParcel {
+            grid_id: grid.grid_id,
+            parcel_id,
+            new_name: new_name.to_string(),
+            new_uri_suffix: new_uri_suffix.map(str::to_string),
+        },
+    )
+}
+
+/// Record the hash of current-epoch `parcel_id`'s art, owned by `owner`; all zeros clears it
+pub fn set_parcel_content(
+    grid: &GridAccounts,
+    owner: &Pubkey,
+    asset: &Pubkey,
+    parcel_id: u16,
+    content_hash: [u8; 32],
+) -> Instruction {
+    build(
+        billion::accounts::SetParcelContent {
+            owner: *owner,
+            grid_config: grid.grid_config,
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            parcel_content: find_parcel_content_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            asset: *asset,
+            collection: grid.collection,
+            mpl_core_program: MPL_CORE_ID,
+            system_program: system_program::ID,
+        },
+        billion::instruction::SetParcelContent { grid_id: grid.grid_id, parcel_id, content_hash },
+    )
+}
+
+/// Store `rgb`, three bytes per block, as the colors of current-epoch `parcel_id`, owned by
+/// `owner`
+pub fn set_parcel_pixels(
+    grid: &GridAccounts,
+    owner: &Pubkey,
+    asset: &Pubkey,
+    parcel_id: u16,
+    rgb: &[u8],
+) -> Instruction {
+    build(
+        billion::accounts::SetParcelPixels {
+            owner: *owner,
+            grid_config: grid.grid_config,
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            asset: *asset,
+            parcel_pixels: find_parcel_pixels_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            system_program: system_program::ID,
+        },
+        billion::instruction::SetParcelPixels { grid_id: grid.grid_id, parcel_id, rgb: rgb.to_vec() },
+    )
+}
+
+/// Delete the pixels of `parcel_id` from `epoch`, refunding their rent to `owner`
+pub fn close_parcel_pixels(
+    grid: &GridAccounts,
+    owner: &Pubkey,
+    asset: &Pubkey,
+    epoch: u16,
+    parcel_id: u16,
+) -> Instruction {
+    build(
+        billion::accounts::CloseParcelPixels {
+            owner: *owner,
+            grid_config: grid.grid_config,
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, epoch, parcel_id),
+            asset: *asset,
+            parcel_pixels: find_parcel_pixels_in_epoch(grid.grid_id, epoch, parcel_id),
+        },
+        billion::instruction::CloseParcelPixels { grid_id: grid.grid_id, parcel_id },
+    )
+}
+
+/// Claim land buy rewards for `parcel_id` into the claimer's associated token account
+pub fn claim_land_buy_rewards(grid: &GridAccounts, claimer: &Pubkey, asset: &Pubkey, parcel_id: u16) -> Instruction {
+    claim_land_buy_rewards_in_epoch(grid, claimer, asset, grid.epoch, parcel_id)
+}
+
+/// Claim for a parcel from `epoch`, passing that epoch's archive when it has ended
+pub fn claim_land_buy_rewards_in_epoch(
+    grid: &GridAccounts,
+    claimer: &Pubkey,
+    asset: &Pubkey,
+    epoch: u16,
+    parcel_id: u16,
+) -> Instruction {
+    claim_land_buy_rewards_for_owner(grid, claimer, claimer, asset, epoch, parcel_id)
+}
+
+/// Claim as `owner`'s reward delegate; the rewards go to `owner`'s ATA
+pub fn claim_land_buy_rewards_for_owner(
+    grid: &GridAccounts,
+    claimer: &Pubkey,
+    owner: &Pubkey,
+    asset: &Pubkey,
+    epoch: u16,
+    parcel_id: u16,
+) -> Instruction {
+    let destination = find_token_account(owner, &grid.token_mint);
+    claim_land_buy_rewards_to(grid, claimer, owner, asset, epoch, parcel_id, &destination)
+}
+
+/// Claim into `destination`, any token account of `owner` for the grid's mint
+pub fn claim_land_buy_rewards_to(
+    grid: &GridAccounts,
+    claimer: &Pubkey,
+    owner: &Pubkey,
+    asset: &Pubkey,
+    epoch: u16,
+    parcel_id: u16,
+    destination: &Pubkey,
+) -> Instruction {
+    build(
+        billion::accounts::ClaimLandBuyRewards {
+            claimer: *claimer,
+            grid_config: grid.grid_config,
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, epoch, parcel_id),
+            asset: *asset,
+            land_buy_reward_pool: grid.land_buy_reward_pool,
+            owner: *owner,
+            owner_token_account: *destination,
+            token_mint: grid.token_mint,
+            claimer_stats: find_claimer_stats(grid.grid_id, owner),
+            token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            epoch_archive: (epoch != grid.epoch).then(|| find_epoch_archive(grid.grid_id, epoch)),
+            reward_delegate: (claimer != owner).then(|| find_reward_delegate_in_epoch(grid.grid_id, epoch, parcel_id)),
+        },
+        billion::instruction::ClaimLandBuyRewards { grid_id: grid.grid_id, parcel_id },
+    )
+}
+
+/// Pay out a current-epoch parcel's pending rewards to `owner` before transferring it
+pub fn settle_rewards_for_transfer(grid: &GridAccounts, owner: &Pubkey, asset: &Pubkey, parcel_id: u16) -> Instruction {
+    build(
+        billion::accounts::SettleRewardsForTransfer {
+            owner: *owner,
+            grid_config: grid.grid_config,
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            asset: *asset,
+            land_buy_reward_pool: grid.land_buy_reward_pool,
+            owner_token_account: find_token_account(owner, &grid.token_mint),
+            token_mint: grid.token_mint,
+            claimer_stats: find_claimer_stats(grid.grid_id, owner),
+            token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            epoch_archive: None,
+        },
+        billion::instruction::SettleRewardsForTransfer { grid_id: grid.grid_id, parcel_id },
+    )
+}
+
+/// Claim land buy rewards for several current-epoch parcels, given as (parcel_id, asset),
+/// in one transfer
+pub fn claim_land_buy_rewards_batch(grid: &GridAccounts, claimer: &Pubkey, parcels: &[(u16, Pubkey)]) -> Instruction {
+    let mut ix = build(
+        billion::accounts::ClaimLandBuyRewardsBatch {
+            claimer: *claimer,
+            grid_config: grid.grid_config,
+            land_buy_reward_pool: grid.land_buy_reward_pool,
+            claimer_token_account: find_token_account(claimer, &grid.token_mint),
+            token_mint: grid.token_mint,
+            claimer_stats: find_claimer_stats(grid.grid_id, claimer),
+            token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            epoch_archive: None,
+        },
+        billion::instruction::ClaimLandBuyRewardsBatch {
+            grid_id: grid.grid_id,
+            parcel_ids: parcels.iter().map(|&(parcel_id, _)| parcel_id).collect(),
+        },
+    );
+    for &(parcel_id, asset) in parcels {
+        ix.accounts.push(AccountMeta::new(find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id), false));
+        ix.accounts.push(AccountMeta::new_readonly(asset, false));
+    }
+    ix
+}
+
+/// Close a parcel's ParcelInfo; a current-epoch parcel's cells and id are freed for reuse
+pub fn admin_close_parcel_info(grid: &GridAccounts, epoch: u16, parcel_id: u16) -> Instruction {
+    build(
+        billion::accounts::AdminCloseParcelInfo {
+            authority: grid.authority,
+            grid_config: grid.grid_config,
+            block_map: grid.block_map,
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, epoch, parcel_id),
+            stake_account: find_stake_account_in_epoch(grid.grid_id, epoch, parcel_id),
+            fraction: find_fraction(grid.grid_id, epoch, parcel_id),
+            parcel_id_pool: find_parcel_id_pool(grid.grid_id),
+            reward_exclusions: find_reward_exclusions(grid.grid_id),
+            system_program: system_program::ID,
+            ring_reward_weights: find_ring_reward_weights(grid.grid_id),
+            ring_occupancy: find_ring_occupancy(grid.grid_id),
+            map_digest: find_map_digest(grid.grid_id),
+            config_freeze: find_config_freeze(grid.grid_id),
+        },
+        billion::instruction::AdminCloseParcelInfo { grid_id: grid.grid_id, parcel_id },
+    )
+}
+
+/// Drain the reward pool into the authority's associated token account, empty the SOL
+/// reward vault into the authority, and close the grid. Claims must have been paused for
+/// PURGE_PAUSE_SLOTS; `force` is needed while landowners are still owed rewards.
+pub fn admin_purge(grid: &GridAccounts, force: bool) -> Instruction {
+    build(
+        billion::accounts::AdminPurge {
+            authority: grid.authority,
+            grid_config: grid.grid_config,
+            block_map: grid.block_map,
+            token_mint: grid.token_mint,
+            land_buy_reward_pool: grid.land_buy_reward_pool,
+            authority_token_account: find_token_account(&grid.authority, &grid.token_mint),
+            sol_reward_vault: find_sol_reward_vault(&grid.grid_config),
+            token_program: token_2022::ID,
+            system_program: system_program::ID,
+            admin_roles: find_admin_roles(grid.grid_id),
+            config_freeze: find_config_freeze(grid.grid_id),
+            claims_pause: find_claims_pause(grid.grid_id),
+        },
+        billion::instruction::AdminPurge { grid_id: grid.grid_id, force },
+    )
+}
+
+/// Close the ParcelInfo of each (epoch, parcel_id) ahead of admin_purge, at most
+/// MAX_PURGE_PARCEL_BATCH at a time
+pub fn admin_purge_parcels(grid: &GridAccounts, parcels: &[(u16, u16)], force: bool) -> Instruction {
+    let mut ix = build(
+        billion::accounts::AdminPurgeParcels {
+            authority: grid.authority,
+            grid_config: grid.grid_config,
+            admin_roles: find_admin_roles(grid.grid_id),
+            claims_pause: find_claims_pause(grid.grid_id),
+            config_freeze: find_config_freeze(grid.grid_id),
+        },
+        billion::instruction::AdminPurgeParcels {
+            grid_id: grid.grid_id,
+            parcel_ids: parcels.iter().map(|&(_, parcel_id)| parcel_id).collect(),
+            force,
+        },
+    );
+    for &(epoch, parcel_id) in parcels {
+        ix.accounts.push(AccountMeta::new(find_parcel_info_in_epoch(grid.grid_id, epoch, parcel_id), false));
+    }
+    ix
+}
+
+fn reserve_blocks_accounts(grid: &GridAccounts) -> billion::accounts::AdminReserveBlocks {
+    billion::accounts::AdminReserveBlocks {
+        authority: grid.authority,
+        grid_config: grid.grid_config,
+        block_map: grid.block_map,
+        admin_roles: find_admin_roles(grid.grid_id),
+        config_freeze: find_config_freeze(grid.grid_id),
+        map_digest: find_map_digest(grid.grid_id),
+    }
+}
+
+/// Reserve the unclaimed blocks of `rect` so no parcel can ever take them
+pub fn admin_reserve_blocks(grid: &GridAccounts, rect: Rect) -> Instruction {
+    build(
+        reserve_blocks_accounts(grid),
+        billion::instruction::AdminReserveBlocks {
+            grid_id: grid.grid_id,
+            x: rect.x,
+            y: rect.y,
+            width: rect.width,
+            height: rect.height,
+        },
+    )
+}
+
+/// Return the reserved blocks of `rect` to unclaimed
+pub fn admin_unreserve_blocks(grid: &GridAccounts, rect: Rect) -> Instruction {
+    build(
+        reserve_blocks_accounts(grid),
+        billion::instruction::AdminUnreserveBlocks {
+            grid_id: grid.grid_id,
+            x: rect.x,
+            y: rect.y,
+            width: rect.width,
+            height: rect.height,
+        },
+    )
+}
+
+pub fn admin_transfer_nft_collection_authority(grid: &GridAccounts, new_collection_authority: &Pubkey) -> Instruction {
+    build(
+        billion::accounts::AdminTransferNftCollectionAuthority {
+            authority: grid.authority,
+            grid_config: grid.grid_config,
+            collection: grid.collection,
+            new_collection_authority: *new_collection_authority,
+            mpl_core_program: MPL_CORE_ID,
+            system_program: system_program::ID,
+        },
+        billion::instruction::AdminTransferNftCollectionAuthority { grid_id: grid.grid_id },
+    )
+}
+
+/// Pay `royalty_bps` of every parcel's secondary sales to `royalty_recipient` (0 removes the
+/// collection's Royalties plugin)
+pub fn admin_set_collection_royalties(grid: &GridAccounts, royalty_bps: u16, royalty_recipient: &Pubkey) -> Instruction {
+    build(
+        billion::accounts::AdminSetCollectionRoyalties {
+            authority: grid.authority,
+            grid_config: grid.grid_config,
+            collection: grid.collection,
+            mpl_core_program: MPL_CORE_ID,
+            system_program: system_program::ID,
+        },
+        billion::instruction::AdminSetCollectionRoyalties {
+            grid_id: grid.grid_id,
+            royalty_bps,
+            royalty_recipient: *royalty_recipient,
+        },
+    )
+}
+
+/// View: simulate and decode `GridState` from the return data
+pub fn get_grid_state(grid_id: u64) -> Instruction {
+    build(
+        billion::accounts::GetGridState {
+            grid_config: find_grid_config(grid_id),
+            ring_unlock_schedule: find_ring_unlock_schedule(grid_id),
+        },
+        billion::instruction::GetGridState { grid_id },
+    )
+}
+
+/// Count the next MAX_OCCUPANCY_SCAN_BLOCKS blocks into the grid's RingOccupancy; repeat until
+/// it's complete. `restart` starts the count over from the first block.
+pub fn admin_backfill_ring_occupancy(grid: &GridAccounts, restart: bool) -> Instruction {
+    build(
+        billion::accounts::AdminBackfillRingOccupancy {
+            authority: grid.authority,
+            grid_config: grid.grid_config,
+            block_map: grid.block_map,
+            ring_occupancy: find_ring_occupancy(grid.grid_id),
+            admin_roles: find_admin_roles(grid.grid_id),
+            system_program: system_program::ID,
+        },
+        billion::instruction::AdminBackfillRingOccupancy { grid_id: grid.grid_id, restart },
+    )
+}
+
+/// Create the MapDigest of a grid initialized before it existed
+pub fn init_map_digest(grid: &GridAccounts) -> Instruction {
+    build(
+        billion::accounts::InitMapDigest {
+            authority: grid.authority,
+            grid_config: grid.grid_config,
+            map_digest: find_map_digest(grid.grid_id),
+            system_program: system_program::ID,
+        },
+        billion::instruction::InitMapDigest { grid_id: grid.grid_id },
+    )
+}
+
+/// View: simulate and decode `RegionView` from the return data; `rect` is at most
+/// MAX_REGION_SIDE blocks on a side
+pub fn get_region(grid: &GridAccounts, rect: Rect) -> Instruction {
+    build(
+        billion::accounts::GetRegion {
+            grid_config: grid.grid_config,
+            block_map: grid.block_map,
+            ring_unlock_schedule: find_ring_unlock_schedule(grid.grid_id),
+        },
+        billion::instruction::GetRegion {
+            grid_id: grid.grid_id,
+            x: rect.x,
+            y: rect.y,
+            width: rect.width,
+            height: rect.height,
+        },
+    )
+}
+
+/// View: simulate and decode `ParcelView` from the return data
+pub fn get_parcel(grid_id: u64, epoch: u16, parcel_id: u16, asset: &Pubkey) -> Instruction {
+    build(
+        billion::accounts::GetParcel {
+            parcel_info: find_parcel_info_in_epoch(grid_id, epoch, parcel_id),
+            asset: *asset,
+        },
+        billion::instruction::GetParcel { grid_id, parcel_id },
+    )
+}
+
+/// View: simulate and decode the `u64` of land-buy rewards pending for a parcel from `epoch`
+pub fn get_pending_rewards(grid: &GridAccounts, epoch: u16, parcel_id: u16) -> Instruction {
+    build(
+        billion::accounts::GetPendingRewards {
+            grid_config: grid.grid_config,
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, epoch, parcel_id),
+            epoch_archive: (epoch != grid.epoch).then(|| find_epoch_archive(grid.grid_id, epoch)),
+        },
+        billion::instruction::GetPendingRewards { grid_id: grid.grid_id, parcel_id },
+    )
+}
+
+/// View: simulate and decode a `Vec<u64>` of pending rewards for current-epoch parcels
+pub fn get_pending_rewards_many(grid: &GridAccounts, parcel_ids: &[u16]) -> Instruction {
+    let mut ix = build(
+        billion::accounts::GetPendingRewardsMany { grid_config: grid.grid_config, epoch_archive: None },
+        billion::instruction::GetPendingRewardsMany { grid_id: grid.grid_id, parcel_ids: parcel_ids.to_vec() },
+    );
+    for &parcel_id in parcel_ids {
+        ix.accounts.push(AccountMeta::new_readonly(find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id), false));
+    }
+    ix
+}
+
+/// View: simulate and decode a `SolvencyReport` for current-epoch `parcel_ids`, adding
+/// their pending rewards to `running_total` from the previous batch
+pub fn verify_reward_solvency(grid: &GridAccounts, parcel_ids: &[u16], running_total: u64) -> Instruction {
+    let mut ix = build(
+        billion::accounts::VerifyRewardSolvency {
+            grid_config: grid.grid_config,
+            land_buy_reward_pool: grid.land_buy_reward_pool,
+            epoch_archive: None,
+        },
+        billion::instruction::VerifyRewardSolvency { grid_id: grid.grid_id, parcel_ids: parcel_ids.to_vec(), running_total },
+    );
+    for &parcel_id in parcel_ids {
+        ix.accounts.push(AccountMeta::new_readonly(find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id), false));
+    }
+    ix
+}
+
+/// View: simulate and decode `ClaimerStats` from the return data
+pub fn get_claimer_stats(grid_id: u64, wallet: &Pubkey) -> Instruction {
+    build(
+        billion::accounts::GetClaimerStats {
+            claimer_stats: find_claimer_stats(grid_id, wallet),
+        },
+        billion::instruction::GetClaimerStats { grid_id, wallet: *wallet },
+    )
+}
+
+/// Close `wallet`'s stats account, refunding its rent
+pub fn close_claimer_stats(grid_id: u64, wallet: &Pubkey) -> Instruction {
+    build(
+        billion::accounts::CloseClaimerStats {
+            wallet: *wallet,
+            claimer_stats: find_claimer_stats(grid_id, wallet),
+            grid_config: find_grid_config(grid_id),
+        },
+        billion::instruction::CloseClaimerStats { grid_id },
+    )
+}
+
+/// View: simulate and decode `ProgramInfo` from the return data
+pub fn get_program_info(grid_id: u64) -> Instruction {
+    build(
+        billion::accounts::GetProgramInfo {
+            grid_config: find_grid_config(grid_id),
+        },
+        billion::instruction::GetProgramInfo { grid_id },
+    )
+}
+
+/// Register `referrer`, optionally recording the registered referrer who introduced them
+pub fn register_referrer(grid_id: u64, referrer: &Pubkey, referred_by: Option<&Pubkey>) -> Instruction {
+    build(
+        billion::accounts::RegisterReferrer {
+            referrer: *referrer,
+            referrer_account: find_referrer_account(grid_id, referrer),
+            referred_by: referred_by.map(|referred_by| find_referrer_account(grid_id, referred_by)),
+            system_program: system_program::ID,
+        },
+        billion::instruction::RegisterReferrer { grid_id },
+    )
+}
+
+/// Create the referral vault (authority only)
+pub fn init_referral_vault(grid: &GridAccounts) -> Instruction {
+    build(
+        billion::accounts::InitReferralVault {
+            authority: grid.authority,
+            grid_config: grid.grid_config,
+            token_mint: grid.token_mint,
+            referral_vault: grid.referral_vault,
+            token_program: token_2022::ID,
+            system_program: system_program::ID,
+        },
+        billion::instruction::InitReferralVault { grid_id: grid.grid_id },
+    )
+}
+
+/// Set the share of referred claims credited to referrers (authority only)
+pub fn set_referral_bps(grid_id: u64, authority: &Pubkey, referral_bps: u16) -> Instruction {
+    build(
+        billion::accounts::SetReferralBps {
+            authority: *authority,
+            grid_config: find_grid_config(grid_id),
+            token_treasury: find_token_treasury(grid_id),
+            referral_share: find_referral_share(grid_id),
+            config_freeze: find_config_freeze(grid_id),
+        },
+        billion::instruction::SetReferralBps { grid_id, referral_bps },
+    )
+}
+
+/// Withdraw `referrer`'s accrued earnings to their token account
+pub fn claim_referral_earnings(grid: &GridAccounts, referrer: &Pubkey) -> Instruction {
+    build(
+        billion::accounts::ClaimReferralEarnings {
+            referrer: *referrer,
+            grid_config: grid.grid_config,
+            referrer_account: find_referrer_account(grid.grid_id, referrer),
+            referral_vault: grid.referral_vault,
+            referrer_token_account: find_token_account(referrer, &grid.token_mint),
+            token_mint: grid.token_mint,
+            token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+        },
+        billion::instruction::ClaimReferralEarnings { grid_id: grid.grid_id },
+    )
+}
+
+/// Create the emissions vault (authority only)
+pub fn init_emissions_vault(grid: &GridAccounts) -> Instruction {
+    build(
+        billion::accounts::InitEmissionsVault {
+            authority: grid.authority,
+            grid_config: grid.grid_config,
+            token_mint: grid.token_mint,
+            emissions_vault: grid.emissions_vault,
+            token_program: token_2022::ID,
+            system_program: system_program::ID,
+        },
+        billion::instruction::InitEmissionsVault { grid_id: grid.grid_id },
+    )
+}
+
+/// Set the staking emission per block per second (authority only)
+pub fn set_emission_rate(grid_id: u64, authority: &Pubkey, emission_rate_per_block_per_sec: u64) -> Instruction {
+    build(
+        billion::accounts::SetEmissionRate {
+            authority: *authority,
+            grid_config: find_grid_config(grid_id),
+        },
+        billion::instruction::SetEmissionRate {
+            grid_id,
+            emission_rate_per_block_per_sec,
+        },
+    )
+}
+
+/// Freeze `staker`'s parcel `asset` and start earning emissions
+pub fn stake_parcel(grid: &GridAccounts, staker: &Pubkey, asset: &Pubkey, parcel_id: u16) -> Instruction {
+    build(
+        billion::accounts::StakeParcel {
+            staker: *staker,
+            grid_config: grid.grid_config,
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            stake_account: find_stake_account_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            asset: *asset,
+            collection: grid.collection,
+            mpl_core_program: MPL_CORE_ID,
+            system_program: system_program::ID,
+        },
+        billion::instruction::StakeParcel { grid_id: grid.grid_id, parcel_id },
+    )
+}
+
+/// Let `delegate` claim a current-epoch parcel's land-buy rewards for `owner`; None clears it
+pub fn set_reward_delegate(
+    grid: &GridAccounts,
+    owner: &Pubkey,
+    asset: &Pubkey,
+    parcel_id: u16,
+    delegate: Option<Pubkey>,
+) -> Instruction {
+    build(
+        billion::accounts::SetRewardDelegate {
+            owner: *owner,
+            grid_config: grid.grid_config,
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            asset: *asset,
+            reward_delegate: find_reward_delegate_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            system_program: system_program::ID,
+        },
+        billion::instruction::SetRewardDelegate { grid_id: grid.grid_id, parcel_id, delegate },
+    )
+}
+
+/// Permissionless: thaw a vested admin-minted parcel once its lock date has passed
+pub fn unlock_vested_parcel(grid: &GridAccounts, payer: &Pubkey, asset: &Pubkey, parcel_id: u16) -> Instruction {
+    build(
+        billion::accounts::UnlockVestedParcel {
+            payer: *payer,
+            grid_config: grid.grid_config,
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            asset: *asset,
+            collection: grid.collection,
+            mpl_core_program: MPL_CORE_ID,
+            system_program: system_program::ID,
+        },
+        billion::instruction::UnlockVestedParcel { grid_id: grid.grid_id, parcel_id },
+    )
+}
+
+/// Authority-only: lift a soulbound parcel's permanent freeze
+pub fn admin_thaw_parcel(grid: &GridAccounts, asset: &Pubkey, parcel_id: u16) -> Instruction {
+    build(
+        billion::accounts::AdminThawParcel {
+            authority: grid.authority,
+            grid_config: grid.grid_config,
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            asset: *asset,
+            collection: grid.collection,
+            mpl_core_program: MPL_CORE_ID,
+            system_program: system_program::ID,
+        },
+        billion::instruction::AdminThawParcel { grid_id: grid.grid_id, parcel_id },
+    )
+}
+
+/// Mint `rect` as parcel `parcel_id` to the GridConfig PDA, held until
+/// [`admin_release_reserved_parcel`]
+pub fn admin_reserve_parcel(grid: &GridAccounts, asset: &Pubkey, parcel_id: u16, rect: Rect) -> Instruction {
+    build(
+        billion::accounts::AdminMint {
+            authority: grid.authority,
+            recipient: grid.grid_config,
+            grid_config: grid.grid_config,
+            block_map: grid.block_map,
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            asset: *asset,
+            collection: grid.collection,
+            mpl_core_program: MPL_CORE_ID,
+            system_program: system_program::ID,
+            admin_mint_allowance: find_admin_mint_allowance(grid.grid_id),
+            reward_exclusions: find_reward_exclusions(grid.grid_id),
+            ring_reward_weights: find_ring_reward_weights(grid.grid_id),
+            ring_occupancy: find_ring_occupancy(grid.grid_id),
+            map_digest: find_map_digest(grid.grid_id),
+            config_freeze: find_config_freeze(grid.grid_id),
+        },
+        billion::instruction::AdminReserveParcel {
+            grid_id: grid.grid_id,
+            x: rect.x,
+            y: rect.y,
+            width: rect.width,
+            height: rect.height,
+        },
+    )
+}
+
+/// Hand a reserved parcel to `recipient`
+pub fn admin_release_reserved_parcel(grid: &GridAccounts, asset: &Pubkey, parcel_id: u16, recipient: &Pubkey) -> Instruction {
+    build(
+        billion::accounts::AdminReleaseReservedParcel {
+            authority: grid.authority,
+            grid_config: grid.grid_config,
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            asset: *asset,
+            collection: grid.collection,
+            recipient: *recipient,
+            mpl_core_program: MPL_CORE_ID,
+            system_program: system_program::ID,
+        },
+        billion::instruction::AdminReleaseReservedParcel { grid_id: grid.grid_id, parcel_id },
+    )
+}
+
+/// Exclude a current-epoch parcel from land-buy rewards, or include it again
+pub fn admin_set_rewards_excluded(grid: &GridAccounts, parcel_id: u16, excluded: bool) -> Instruction {
+    build(
+        billion::accounts::AdminSetRewardsExcluded {
+            authority: grid.authority,
+            grid_config: grid.grid_config,
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            reward_exclusions: find_reward_exclusions(grid.grid_id),
+            system_program: system_program::ID,
+            ring_reward_weights: find_ring_reward_weights(grid.grid_id),
+        },
+        billion::instruction::AdminSetRewardsExcluded { grid_id: grid.grid_id, parcel_id, excluded },
+    )
+}
+
+/// Pay out emissions accrued by a staked parcel
+pub fn claim_staking_rewards(grid: &GridAccounts, staker: &Pubkey, parcel_id: u16) -> Instruction {
+    build(
+        billion::accounts::ClaimStakingRewards {
+            staker: *staker,
+            grid_config: grid.grid_config,
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            stake_account: find_stake_account_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            emissions_vault: grid.emissions_vault,
+            staker_token_account: find_token_account(staker, &grid.token_mint),
+            token_mint: grid.token_mint,
+            token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+        },
+        billion::instruction::ClaimStakingRewards { grid_id: grid.grid_id, parcel_id },
+    )
+}
+
+/// Pay out remaining emissions and thaw the parcel `asset`
+pub fn unstake_parcel(grid: &GridAccounts, staker: &Pubkey, asset: &Pubkey, parcel_id: u16) -> Instruction {
+    build(
+        billion::accounts::UnstakeParcel {
+            staker: *staker,
+            grid_config: grid.grid_config,
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            stake_account: find_stake_account_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            asset: *asset,
+            collection: grid.collection,
+            emissions_vault: grid.emissions_vault,
+            staker_token_account: find_token_account(staker, &grid.token_mint),
+            token_mint: grid.token_mint,
+            mpl_core_program: MPL_CORE_ID,
+            token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        billion::instruction::UnstakeParcel { grid_id: grid.grid_id, parcel_id },
+    )
+}
+
+/// Arguments for `configure_harberger_district`
+#[derive(Clone, Copy, Debug)]
+pub struct HarbergerDistrictArgs {
+    pub rect: Rect,
+    pub tax_bps: u16,
+    pub tax_period_secs: i64,
+    pub enabled: bool,
+}
+
+pub fn configure_harberger_district(grid_id: u64, authority: &Pubkey, args: HarbergerDistrictArgs) -> Instruction {
+    build(
+        billion::accounts::ConfigureHarbergerDistrict {
+            authority: *authority,
+            grid_config: find_grid_config(grid_id),
+            harberger_district: find_harberger_district(grid_id),
+            system_program: system_program::ID,
+        },
+        billion::instruction::ConfigureHarbergerDistrict {
+            grid_id,
+            x: args.rect.x,
+            y: args.rect.y,
+            width: args.rect.width,
+            height: args.rect.height,
+            tax_bps: args.tax_bps,
+            tax_period_secs: args.tax_period_secs,
+            enabled: args.enabled,
+        },
+    )
+}
+
+pub fn set_valuation(grid: &GridAccounts, owner: &Pubkey, asset: &Pubkey, parcel_id: u16, value: u64) -> Instruction {
+    build(
+        billion::accounts::SetValuation {
+            owner: *owner,
+            grid_config: grid.grid_config,
+            harberger_district: find_harberger_district(grid.grid_id),
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            parcel_valuation: find_parcel_valuation_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            asset: *asset,
+            collection: grid.collection,
+            mpl_core_program: MPL_CORE_ID,
+            system_program: system_program::ID,
+        },
+        billion::instruction::SetValuation { grid_id: grid.grid_id, parcel_id, value },
+    )
+}
+
+/// `owner` is the owner recorded on the valuation, whose token account pays the tax
+pub fn pay_tax(
+    grid: &GridAccounts,
+    payer: &Pubkey,
+    owner: &Pubkey,
+    asset: &Pubkey,
+    parcel_id: u16,
+) -> Instruction {
+    build(
+        billion::accounts::PayTax {
+            payer: *payer,
+            grid_config: grid.grid_config,
+            harberger_district: find_harberger_district(grid.grid_id),
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            parcel_valuation: find_parcel_valuation_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            asset: *asset,
+            owner_token_account: find_token_account(owner, &grid.token_mint),
+            land_buy_reward_pool: grid.land_buy_reward_pool,
+            token_mint: grid.token_mint,
+            token_program: token_2022::ID,
+            ring_reward_weights: find_ring_reward_weights(grid.grid_id),
+            reward_dust: find_reward_dust(grid.grid_id),
+            system_program: system_program::ID,
+        },
+        billion::instruction::PayTax { grid_id: grid.grid_id, parcel_id },
+    )
+}
+
+pub fn force_buy(
+    grid: &GridAccounts,
+    buyer: &Pubkey,
+    previous_owner: &Pubkey,
+    asset: &Pubkey,
+    parcel_id: u16,
+) -> Instruction {
+    build(
+        billion::accounts::ForceBuy {
+            buyer: *buyer,
+            grid_config: grid.grid_config,
+            harberger_district: find_harberger_district(grid.grid_id),
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            parcel_valuation: find_parcel_valuation_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            stake_account: find_stake_account_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            asset: *asset,
+            collection: grid.collection,
+            previous_owner: *previous_owner,
+            buyer_token_account: find_token_account(buyer, &grid.token_mint),
+            previous_owner_token_account: find_token_account(previous_owner, &grid.token_mint),
+            land_buy_reward_pool: grid.land_buy_reward_pool,
+            token_mint: grid.token_mint,
+            mpl_core_program: MPL_CORE_ID,
+            token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        billion::instruction::ForceBuy { grid_id: grid.grid_id, parcel_id },
+    )
+}
+
+pub fn init_quest_vault(grid: &GridAccounts) -> Instruction {
+    build(
+        billion::accounts::InitQuestVault {
+            authority: grid.authority,
+            grid_config: grid.grid_config,
+            token_mint: grid.token_mint,
+            quest_vault: grid.quest_vault,
+            token_program: token_2022::ID,
+            system_program: system_program::ID,
+        },
+        billion::instruction::InitQuestVault { grid_id: grid.grid_id },
+    )
+}
+
+pub fn set_quest_root(grid_id: u64, authority: &Pubkey, root: [u8; 32], total_allocation: u64) -> Instruction {
+    build(
+        billion::accounts::SetQuestRoot {
+            authority: *authority,
+            grid_config: find_grid_config(grid_id),
+            quest: find_quest(grid_id, &root),
+            system_program: system_program::ID,
+        },
+        billion::instruction::SetQuestRoot { grid_id, root, total_allocation },
+    )
+}
+
+/// `proof` as returned by [`crate::QuestTree::proof`]
+pub fn claim_quest_reward(
+    grid: &GridAccounts,
+    claimer: &Pubkey,
+    root: &[u8; 32],
+    amount: u64,
+    index: u32,
+    proof: Vec<[u8; 32]>,
+) -> Instruction {
+    let quest = find_quest(grid.grid_id, root);
+    build(
+        billion::accounts::ClaimQuestReward {
+            claimer: *claimer,
+            grid_config: grid.grid_config,
+            quest,
+            quest_claims: find_quest_claims(&quest, index),
+            quest_vault: grid.quest_vault,
+            claimer_token_account: find_token_account(claimer, &grid.token_mint),
+            token_mint: grid.token_mint,
+            token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        billion::instruction::ClaimQuestReward { grid_id: grid.grid_id, amount, index, proof },
+    )
+}
+
+/// `token_program` owns `mint`, which may be any SPL or Token-2022 mint. `distribution` is a
+/// fresh keypair that must sign.
+pub fn create_distribution(
+    grid_id: u64,
+    distributor: &Pubkey,
+    distribution: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    amount: u64,
+    expires_at: i64,
+) -> Instruction {
+    build(
+        billion::accounts::CreateDistribution {
+            distributor: *distributor,
+            grid_config: find_grid_config(grid_id),
+            distribution: *distribution,
+            mint: *mint,
+            distributor_token_account: associated_token::get_associated_token_address_with_program_id(
+                distributor,
+                mint,
+                token_program,
+            ),
+            vault: find_distribution_vault(distribution),
+            token_program: *token_program,
+            system_program: system_program::ID,
+        },
+        billion::instruction::CreateDistribution { grid_id, amount, expires_at },
+    )
+}
+
+/// Pays parcel `parcel_id`'s share to `owner`, creating their token account if needed.
+/// `epoch` is the distribution's epoch, which the parcel must belong to.
+#[allow(clippy::too_many_arguments)]
+pub fn claim_distribution(
+    grid_id: u64,
+    payer: &Pubkey,
+    distribution: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    owner: &Pubkey,
+    asset: &Pubkey,
+    epoch: u16,
+    parcel_id: u16,
+) -> Instruction {
+    build(
+        billion::accounts::ClaimDistribution {
+            payer: *payer,
+            grid_config: find_grid_config(grid_id),
+            distribution: *distribution,
+            parcel_info: find_parcel_info_in_epoch(grid_id, epoch, parcel_id),
+            asset: *asset,
+            owner: *owner,
+            owner_token_account: associated_token::get_associated_token_address_with_program_id(
+                owner,
+                mint,
+                token_program,
+            ),
+            vault: find_distribution_vault(distribution),
+            mint: *mint,
+            token_program: *token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        billion::instruction::ClaimDistribution { grid_id, parcel_id },
+    )
+}
+
+pub fn sweep_distribution(grid_id: u64, distributor: &Pubkey, distribution: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Instruction {
+    build(
+        billion::accounts::SweepDistribution {
+            distributor: *distributor,
+            grid_config: find_grid_config(grid_id),
+            distribution: *distribution,
+            vault: find_distribution_vault(distribution),
+            distributor_token_account: associated_token::get_associated_token_address_with_program_id(
+                distributor,
+                mint,
+                token_program,
+            ),
+            mint: *mint,
+            token_program: *token_program,
+        },
+        billion::instruction::SweepDistribution { grid_id },
+    )
+}
+
+/// Archive the current epoch and switch to `new_block_map`, which must already be created
+/// and initialized with [`create_block_map`] (same or an earlier transaction)
+pub fn start_new_epoch(grid: &GridAccounts, new_block_map: &Pubkey) -> Instruction {
+    build(
+        billion::accounts::StartNewEpoch {
+            authority: grid.authority,
+            grid_config: grid.grid_config,
+            block_map: grid.block_map,
+            new_block_map: *new_block_map,
+            epoch_archive: find_epoch_archive(grid.grid_id, grid.epoch),
+            system_program: system_program::ID,
+        },
+        billion::instruction::StartNewEpoch { grid_id: grid.grid_id },
+    )
+}
+
+/// Arguments for `set_hook`
+#[derive(Clone, Copy, Debug)]
+pub struct HookArgs {
+    pub program_id: Pubkey,
+    /// May disable the hook alongside the authority
+    pub guardian: Pubkey,
+    /// Claims overlapping this rectangle invoke the hook
+    pub district: Rect,
+    pub enabled: bool,
+}
+
+pub fn set_hook(grid_id: u64, authority: &Pubkey, args: HookArgs) -> Instruction {
+    build(
+        billion::accounts::SetHook {
+            authority: *authority,
+            grid_config: find_grid_config(grid_id),
+            hook_config: find_hook_config(grid_id),
+            system_program: system_program::ID,
+            config_freeze: find_config_freeze(grid_id),
+        },
+        billion::instruction::SetHook {
+            grid_id,
+            program_id: args.program_id,
+            guardian: args.guardian,
+            x: args.district.x,
+            y: args.district.y,
+            width: args.district.width,
+            height: args.district.height,
+            enabled: args.enabled,
+        },
+    )
+}
+
+/// Replace the charity registry and the cap on the share a buyer may route to it
+pub fn admin_set_charities(grid_id: u64, authority: &Pubkey, charities: Vec<Pubkey>, max_charity_bps: u16) -> Instruction {
+    build(
+        billion::accounts::AdminSetCharities {
+            authority: *authority,
+            grid_config: find_grid_config(grid_id),
+            charity_registry: find_charity_registry(grid_id),
+            system_program: system_program::ID,
+            token_treasury: find_token_treasury(grid_id),
+            referral_share: find_referral_share(grid_id),
+            config_freeze: find_config_freeze(grid_id),
+        },
+        billion::instruction::AdminSetCharities { grid_id, charities, max_charity_bps },
+    )
+}
+
+/// `signer` is the authority or the hook guardian
+pub fn disable_hook(grid_id: u64, signer: &Pubkey) -> Instruction {
+    build(
+        billion::accounts::DisableHook {
+            signer: *signer,
+            grid_config: find_grid_config(grid_id),
+            hook_config: find_hook_config(grid_id),
+        },
+        billion::instruction::DisableHook { grid_id },
+    )
+}
+
+/// Appends the adjacency proof - one of the claimer's current-epoch parcels bordering the
+/// claim, and its asset - to a `claim_parcel` or `claim_parcel_pda` instruction. Required for
+/// claims touching a newly unlocked ring during its expansion window. Must be added before
+/// [`with_hook_accounts`].
+pub fn with_adjacency_proof(mut ix: Instruction, grid_id: u64, epoch: u16, parcel_id: u16, asset: &Pubkey) -> Instruction {
+    ix.accounts.push(AccountMeta::new_readonly(find_parcel_info_in_epoch(grid_id, epoch, parcel_id), false));
+    ix.accounts.push(AccountMeta::new_readonly(*asset, false));
+    ix
+}
+
+/// Appends the token account paid the direct referral share to a `claim_parcel` instruction.
+/// Ignored while no share is set; must be added after [`with_adjacency_proof`] and before
+/// [`with_hook_accounts`].
+pub fn with_referrer_token_account(mut ix: Instruction, referrer_token_account: &Pubkey) -> Instruction {
+    ix.accounts.push(AccountMeta::new(*referrer_token_account, false));
+    ix
+}
+
+/// Appends the hook program and the accounts passed through to it to a `claim_parcel` or
+/// `claim_parcel_pda` instruction. Required whenever the enabled hook overlaps the claim.
+///
+/// ```
+/// use anchor_lang::prelude::Pubkey;
+/// use anchor_lang::solana_program::instruction::AccountMeta;
+/// use billion_client::{claim_parcel_pda, with_hook_accounts, GridAccounts, Rect};
+///
+/// let grid = GridAccounts::new(
+///     0,
+///     Pubkey::new_unique(),
+///     Pubkey::new_unique(),
+///     Pubkey::new_unique(),
+///     Pubkey::new_unique(),
+/// );
+/// let hook_program = Pubkey::new_unique();
+/// let item_mint = AccountMeta::new(Pubkey::new_unique(), false);
+/// let ix = claim_parcel_pda(&grid, &Pubkey::new_unique(), 1, Rect::new(0, 0, 1, 1), None);
+/// let base = ix.accounts.len();
+/// let ix = with_hook_accounts(ix, &hook_program, &[item_mint.clone()]);
+/// assert_eq!(ix.accounts[base].pubkey, hook_program);
+/// assert_eq!(ix.accounts[base + 1], item_mint);
+/// ```
+pub fn with_hook_accounts(mut ix: Instruction, hook_program: &Pubkey, accounts: &[AccountMeta]) -> Instruction {
+    ix.accounts.push(AccountMeta::new_readonly(*hook_program, false));
+    ix.accounts.extend_from_slice(accounts);
+    ix
+}
+
+/// Fractionalize `parcel_id` into `share_supply` shares of the `share_mint` keypair, which
+/// must also sign
+pub fn fractionalize_parcel(
+    grid: &GridAccounts,
+    owner: &Pubkey,
+    share_mint: &Pubkey,
+    asset: &Pubkey,
+    parcel_id: u16,
+    share_supply: u64,
+) -> Instruction {
+    build(
+        billion::accounts::FractionalizeParcel {
+            owner: *owner,
+            grid_config: grid.grid_config,
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            stake_account: find_stake_account_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            fraction: find_fraction(grid.grid_id, grid.epoch, parcel_id),
+            share_mint: *share_mint,
+            owner_share_account: find_token_account(owner, share_mint),
+            share_escrow: find_fraction_escrow(share_mint),
+            reward_vault: find_fraction_vault(share_mint),
+            land_buy_reward_pool: grid.land_buy_reward_pool,
+            owner_token_account: find_token_account(owner, &grid.token_mint),
+            token_mint: grid.token_mint,
+            asset: *asset,
+            collection: grid.collection,
+            mpl_core_program: MPL_CORE_ID,
+            token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        billion::instruction::FractionalizeParcel { grid_id: grid.grid_id, parcel_id, share_supply },
+    )
+}
+
+fn fraction_holder(grid: &GridAccounts, holder: &Pubkey, fraction: &Fraction) -> billion::accounts::FractionHolder {
+    billion::accounts::FractionHolder {
+        holder: *holder,
+        grid_config: grid.grid_config,
+        parcel_info: find_parcel_info_in_epoch(grid.grid_id, fraction.epoch, fraction.parcel_id),
+        fraction: find_fraction(grid.grid_id, fraction.epoch, fraction.parcel_id),
+        position: find_fraction_position(&fraction.share_mint, holder),
+        share_mint: fraction.share_mint,
+        holder_share_account: find_token_account(holder, &fraction.share_mint),
+        share_escrow: find_fraction_escrow(&fraction.share_mint),
+        reward_vault: find_fraction_vault(&fraction.share_mint),
+        holder_token_account: find_token_account(holder, &grid.token_mint),
+        land_buy_reward_pool: grid.land_buy_reward_pool,
+        token_mint: grid.token_mint,
+        token_program: token_2022::ID,
+        associated_token_program: associated_token::ID,
+        system_program: system_program::ID,
+        epoch_archive: (fraction.epoch != grid.epoch).then(|| find_epoch_archive(grid.grid_id, fraction.epoch)),
+    }
+}
+
+/// Deposit `amount` of `holder`'s shares so they earn the parcel's land-buy rewards
+pub fn deposit_fraction_shares(grid: &GridAccounts, holder: &Pubkey, fraction: &Fraction, amount: u64) -> Instruction {
+    build(
+        fraction_holder(grid, holder, fraction),
+        billion::instruction::DepositFractionShares { grid_id: grid.grid_id, parcel_id: fraction.parcel_id, amount },
+    )
+}
+
+pub fn withdraw_fraction_shares(grid: &GridAccounts, holder: &Pubkey, fraction: &Fraction, amount: u64) -> Instruction {
+    build(
+        fraction_holder(grid, holder, fraction),
+        billion::instruction::WithdrawFractionShares { grid_id: grid.grid_id, parcel_id: fraction.parcel_id, amount },
+    )
+}
+
+pub fn claim_fraction_rewards(grid: &GridAccounts, holder: &Pubkey, fraction: &Fraction) -> Instruction {
+    build(
+        fraction_holder(grid, holder, fraction),
+        billion::instruction::ClaimFractionRewards { grid_id: grid.grid_id, parcel_id: fraction.parcel_id },
+    )
+}
+
+/// Burn every share held by `redeemer` and take the asset back
+pub fn redeem_parcel(grid: &GridAccounts, redeemer: &Pubkey, fraction: &Fraction) -> Instruction {
+    build(
+        billion::accounts::RedeemParcel {
+            redeemer: *redeemer,
+            grid_config: grid.grid_config,
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, fraction.epoch, fraction.parcel_id),
+            fraction: find_fraction(grid.grid_id, fraction.epoch, fraction.parcel_id),
+            share_mint: fraction.share_mint,
+            redeemer_share_account: find_token_account(redeemer, &fraction.share_mint),
+            share_escrow: find_fraction_escrow(&fraction.share_mint),
+            reward_vault: find_fraction_vault(&fraction.share_mint),
+            redeemer_token_account: find_token_account(redeemer, &grid.token_mint),
+            token_mint: grid.token_mint,
+            asset: fraction.asset,
+            collection: grid.collection,
+            mpl_core_program: MPL_CORE_ID,
+            token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        billion::instruction::RedeemParcel { grid_id: grid.grid_id, parcel_id: fraction.parcel_id },
+    )
+}
+
+/// Set the exit share paid by `burn_for_exit` (authority only). `reward_liability`, when set,
+/// re-seeds the tracked liability of pending land-buy rewards.
+pub fn set_exit_share(grid_id: u64, authority: &Pubkey, exit_share_bps: u16, reward_liability: Option<u64>) -> Instruction {
+    build(
+        billion::accounts::SetExitShare {
+            authority: *authority,
+            grid_config: find_grid_config(grid_id),
+            config_freeze: find_config_freeze(grid_id),
+        },
+        billion::instruction::SetExitShare { grid_id, exit_share_bps, reward_liability },
+    )
+}
+
+/// Burn `owner`'s parcel for its pending rewards plus the exit share
+pub fn burn_for_exit(grid: &GridAccounts, owner: &Pubkey, asset: &Pubkey, parcel_id: u16) -> Instruction {
+    build(
+        billion::accounts::BurnForExit {
+            owner: *owner,
+            grid_config: grid.grid_config,
+            block_map: grid.block_map,
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            stake_account: find_stake_account_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            land_buy_reward_pool: grid.land_buy_reward_pool,
+            owner_token_account: find_token_account(owner, &grid.token_mint),
+            token_mint: grid.token_mint,
+            asset: *asset,
+            collection: grid.collection,
+            mpl_core_program: MPL_CORE_ID,
+            token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            reward_exclusions: find_reward_exclusions(grid.grid_id),
+            ring_reward_weights: find_ring_reward_weights(grid.grid_id),
+            ring_occupancy: find_ring_occupancy(grid.grid_id),
+            map_digest: find_map_digest(grid.grid_id),
+            parcel_id_pool: find_parcel_id_pool(grid.grid_id),
+        },
+        billion::instruction::BurnForExit { grid_id: grid.grid_id, parcel_id },
+    )
+}
+
+/// Set the reward expiry window (0 = never) and the cranker's bounty (authority only)
+pub fn set_reward_expiry(grid_id: u64, authority: &Pubkey, reward_expiry_seconds: i64, expiry_bounty_bps: u16) -> Instruction {
+    build(
+        billion::accounts::SetRewardExpiry {
+            authority: *authority,
+            grid_config: find_grid_config(grid_id),
+            config_freeze: find_config_freeze(grid_id),
+        },
+        billion::instruction::SetRewardExpiry { grid_id, reward_expiry_seconds, expiry_bounty_bps },
+    )
+}
+
+/// Lapse the pending rewards of a dormant current-epoch parcel, paying `cranker` the bounty
+pub fn expire_parcel_rewards(grid: &GridAccounts, cranker: &Pubkey, parcel_id: u16) -> Instruction {
+    build(
+        billion::accounts::ExpireParcelRewards {
+            cranker: *cranker,
+            grid_config: grid.grid_config,
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            land_buy_reward_pool: grid.land_buy_reward_pool,
+            cranker_token_account: find_token_account(cranker, &grid.token_mint),
+            token_mint: grid.token_mint,
+            token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            ring_reward_weights: find_ring_reward_weights(grid.grid_id),
+            reward_dust: find_reward_dust(grid.grid_id),
+        },
+        billion::instruction::ExpireParcelRewards { grid_id: grid.grid_id, parcel_id },
+    )
+}
+
+/// Set the adjacency bonus paid on land-buy reward claims (authority only)
+pub fn set_adjacency_bonus(grid_id: u64, authority: &Pubkey, adjacency_bonus_bps: u16) -> Instruction {
+    build(
+        billion::accounts::SetAdjacencyBonus {
+            authority: *authority,
+            grid_config: find_grid_config(grid_id),
+            config_freeze: find_config_freeze(grid_id),
+        },
+        billion::instruction::SetAdjacencyBonus { grid_id, adjacency_bonus_bps },
+    )
+}
+
+/// Recount the parcels bordering a current-epoch parcel. Needs no signer beyond the fee payer.
+pub fn refresh_adjacency(grid: &GridAccounts, parcel_id: u16) -> Instruction {
+    build(
+        billion::accounts::RefreshAdjacency {
+            grid_config: grid.grid_config,
+            block_map: grid.block_map,
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+        },
+        billion::instruction::RefreshAdjacency { grid_id: grid.grid_id, parcel_id },
+    )
+}
+
+/// Attest that `owner` currently owns `parcel_id` of `epoch`; refreshes an existing attestation
+pub fn mint_ownership_attestation(grid_id: u64, owner: &Pubkey, asset: &Pubkey, epoch: u16, parcel_id: u16) -> Instruction {
+    build(
+        billion::accounts::MintOwnershipAttestation {
+            owner: *owner,
+            parcel_info: find_parcel_info_in_epoch(grid_id, epoch, parcel_id),
+            asset: *asset,
+            attestation: find_attestation(grid_id, epoch, parcel_id, owner),
+            system_program: system_program::ID,
+        },
+        billion::instruction::MintOwnershipAttestation { grid_id, parcel_id },
+    )
+}
+
+/// Register `name` for a parcel `owner` holds and write it to the asset's Core metadata
+pub fn register_parcel_name(
+    grid: &GridAccounts,
+    owner: &Pubkey,
+    asset: &Pubkey,
+    epoch: u16,
+    parcel_id: u16,
+    name: &str,
+) -> Instruction {
+    build(
+        billion::accounts::RegisterParcelName {
+            owner: *owner,
+            grid_config: grid.grid_config,
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, epoch, parcel_id),
+            name_record: find_name_record(grid.grid_id, name),
+            asset: *asset,
+            collection: grid.collection,
+            mpl_core_program: MPL_CORE_ID,
+            system_program: system_program::ID,
+        },
+        billion::instruction::RegisterParcelName { grid_id: grid.grid_id, parcel_id, name: name.to_string() },
+    )
+}
+
+/// Release a registered name. `signer` is the registrant, or anyone once the registrant no
+/// longer owns the asset; the record's rent goes back to `registrant`.
+pub fn release_parcel_name(
+    grid: &GridAccounts,
+    signer: &Pubkey,
+    registrant: &Pubkey,
+    asset: &Pubkey,
+    epoch: u16,
+    parcel_id: u16,
+    name: &str,
+) -> Instruction {
+    build(
+        billion::accounts::ReleaseParcelName {
+            signer: *signer,
+            grid_config: grid.grid_config,
+            name_record: find_name_record(grid.grid_id, name),
+            registrant: *registrant,
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, epoch, parcel_id),
+            asset: *asset,
+            collection: grid.collection,
+            mpl_core_program: MPL_CORE_ID,
+            system_program: system_program::ID,
+        },
+        billion::instruction::ReleaseParcelName { grid_id: grid.grid_id, name: name.to_string() },
+    )
+}
+
+/// Issue voucher `voucher_id` to `beneficiary` for one free parcel of at most
+/// `max_width` x `max_height` within rings `ring_min..=ring_max`, redeemable until `expires_at`
+#[allow(clippy::too_many_arguments)]
+pub fn mint_voucher(
+    grid: &GridAccounts,
+    voucher_id: u64,
+    beneficiary: &Pubkey,
+    max_width: u8,
+    max_height: u8,
+    ring_min: u8,
+    ring_max: u8,
+    expires_at: i64,
+) -> Instruction {
+    build(
+        billion::accounts::MintVoucher {
+            authority: grid.authority,
+            grid_config: grid.grid_config,
+            voucher: find_voucher(grid.grid_id, beneficiary, voucher_id),
+            system_program: system_program::ID,
+            config_freeze: find_config_freeze(grid.grid_id),
+        },
+        billion::instruction::MintVoucher {
+            grid_id: grid.grid_id,
+            voucher_id,
+            beneficiary: *beneficiary,
+            max_width,
+            max_height,
+            allowed_ring_min: ring_min,
+            allowed_ring_max: ring_max,
+            expires_at,
+        },
+    )
+}
+
+/// Redeem `beneficiary`'s voucher `voucher_id` for the parcel at `rect`, minted to `asset`.
+/// During an expansion priority window, wrap it in [`with_adjacency_proof`].
+pub fn redeem_voucher(
+    grid: &GridAccounts,
+    beneficiary: &Pubkey,
+    voucher_id: u64,
+    asset: &Pubkey,
+    parcel_id: u16,
+    rect: Rect,
+) -> Instruction {
+    build(
+        billion::accounts::RedeemVoucher {
+            beneficiary: *beneficiary,
+            authority: grid.authority,
+            grid_config: grid.grid_config,
+            block_map: grid.block_map,
+            voucher: find_voucher(grid.grid_id, beneficiary, voucher_id),
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            asset: *asset,
+            collection: grid.collection,
+            mpl_core_program: MPL_CORE_ID,
+            system_program: system_program::ID,
+            ring_reward_weights: find_ring_reward_weights(grid.grid_id),
+            ring_occupancy: find_ring_occupancy(grid.grid_id),
+            map_digest: find_map_digest(grid.grid_id),
+            ring_unlock_schedule: find_ring_unlock_schedule(grid.grid_id),
+        },
+        billion::instruction::RedeemVoucher { grid_id: grid.grid_id, x: rect.x, y: rect.y, width: rect.width, height: rect.height },
+    )
+}
+
+/// Close `beneficiary`'s expired voucher `voucher_id`, refunding its rent to the authority
+pub fn close_expired_voucher(grid: &GridAccounts, beneficiary: &Pubkey, voucher_id: u64) -> Instruction {
+    build(
+        billion::accounts::CloseExpiredVoucher {
+            authority: grid.authority,
+            grid_config: grid.grid_config,
+            voucher: find_voucher(grid.grid_id, beneficiary, voucher_id),
+        },
+        billion::instruction::CloseExpiredVoucher { grid_id: grid.grid_id },
+    )
+}
+
+pub fn set_premium_price(grid_id: u64, authority: &Pubkey, premium_price_per_period: u64, premium_period_secs: i64) -> Instruction {
+    build(
+        billion::accounts::SetPremiumPrice {
+            authority: *authority,
+            grid_config: find_grid_config(grid_id),
+        },
+        billion::instruction::SetPremiumPrice { grid_id, premium_price_per_period, premium_period_secs },
+    )
+}
+
+/// Buy `periods` of the premium badge for current-epoch `parcel_id`, paid by `payer`
+pub fn purchase_premium(grid: &GridAccounts, payer: &Pubkey, parcel_id: u16, periods: u32) -> Instruction {
+    build(
+        billion::accounts::PurchasePremium {
+            payer: *payer,
+            grid_config: grid.grid_config,
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            payer_token_account: find_token_account(payer, &grid.token_mint),
+            land_buy_reward_pool: grid.land_buy_reward_pool,
+            token_mint: grid.token_mint,
+            token_program: token_2022::ID,
+            ring_reward_weights: find_ring_reward_weights(grid.grid_id),
+            ring_unlock_schedule: find_ring_unlock_schedule(grid.grid_id),
+            reward_dust: find_reward_dust(grid.grid_id),
+            system_program: system_program::ID,
+        },
+        billion::instruction::PurchasePremium { grid_id: grid.grid_id, parcel_id, periods },
+    )
+}
+
+/// Deposit `amount` of `depositor`'s tokens for every landowner to claim pro-rata
+pub fn fund_land_buy_rewards(grid: &GridAccounts, depositor: &Pubkey, amount: u64) -> Instruction {
+    build(
+        billion::accounts::FundLandBuyRewards {
+            depositor: *depositor,
+            grid_config: grid.grid_config,
+            depositor_token_account: find_token_account(depositor, &grid.token_mint),
+            land_buy_reward_pool: grid.land_buy_reward_pool,
+            token_mint: grid.token_mint,
+            token_program: token_2022::ID,
+            ring_reward_weights: find_ring_reward_weights(grid.grid_id),
+            reward_dust: find_reward_dust(grid.grid_id),
+            system_program: system_program::ID,
+        },
+        billion::instruction::FundLandBuyRewards { grid_id: grid.grid_id, amount },
+    )
+}
+
+/// Authority-only: move `amount` tokens of tracked accumulator rounding out of the reward
+/// pool into the authority's associated token account
+pub fn admin_sweep_dust(grid: &GridAccounts, amount: u64) -> Instruction {
+    build(
+        billion::accounts::AdminSweepDust {
+            authority: grid.authority,
+            grid_config: grid.grid_config,
+            reward_dust: find_reward_dust(grid.grid_id),
+            token_mint: grid.token_mint,
+            land_buy_reward_pool: grid.land_buy_reward_pool,
+            authority_token_account: find_token_account(&grid.authority, &grid.token_mint),
+            token_program: token_2022::ID,
+            admin_roles: find_admin_roles(grid.grid_id),
+        },
+        billion::instruction::AdminSweepDust { grid_id: grid.grid_id, amount },
+    )
+}
+
+/// Authority-only: weight each ring's blocks in land-buy rewards for parcels minted from now
+/// on, in basis points (index 0 is the outermost ring)
+pub fn set_ring_reward_weights(grid_id: u64, authority: &Pubkey, weights_bps: [u16; RING_COUNT as usize]) -> Instruction {
+    build(
+        billion::accounts::SetRingRewardWeights {
+            authority: *authority,
+            grid_config: find_grid_config(grid_id),
+            ring_reward_weights: find_ring_reward_weights(grid_id),
+            system_program: system_program::ID,
+            config_freeze: find_config_freeze(grid_id),
+        },
+        billion::instruction::SetRingRewardWeights { grid_id, weights_bps },
+    )
+}
+
+/// Authority-only: move `amount` tokens the reward pool holds beyond the tracked reward
+/// liability into the authority's associated token account
+pub fn admin_withdraw_pool_excess(grid: &GridAccounts, amount: u64) -> Instruction {
+    build(
+        billion::accounts::AdminWithdrawPoolExcess {
+            authority: grid.authority,
+            grid_config: grid.grid_config,
+            token_mint: grid.token_mint,
+            land_buy_reward_pool: grid.land_buy_reward_pool,
+            authority_token_account: find_token_account(&grid.authority, &grid.token_mint),
+            token_program: token_2022::ID,
+            admin_roles: find_admin_roles(grid.grid_id),
+        },
+        billion::instruction::AdminWithdrawPoolExcess { grid_id: grid.grid_id, amount },
+    )
+}
+
+/// Treasury-authority-only: send `share_bps` of every token claim to `treasury_token_account`
+pub fn set_token_treasury(grid: &GridAccounts, treasury_token_account: &Pubkey, share_bps: u16) -> Instruction {
+    build(
+        billion::accounts::SetTokenTreasury {
+            authority: grid.authority,
+            grid_config: grid.grid_config,
+            admin_roles: find_admin_roles(grid.grid_id),
+            token_treasury: find_token_treasury(grid.grid_id),
+            referral_share: find_referral_share(grid.grid_id),
+            treasury_token_account: *treasury_token_account,
+            system_program: system_program::ID,
+            config_freeze: find_config_freeze(grid.grid_id),
+        },
+        billion::instruction::SetTokenTreasury { grid_id: grid.grid_id, share_bps },
+    )
+}
+
+/// Main-authority-only: hand each given role to a new key; None keeps the current holder
+pub fn set_admin_roles(
+    grid_id: u64,
+    authority: &Pubkey,
+    config_authority: Option<Pubkey>,
+    metadata_authority: Option<Pubkey>,
+    treasury_authority: Option<Pubkey>,
+) -> Instruction {
+    build(
+        billion::accounts::SetAdminRoles {
+            authority: *authority,
+            grid_config: find_grid_config(grid_id),
+            admin_roles: find_admin_roles(grid_id),
+            system_program: system_program::ID,
+            config_freeze: find_config_freeze(grid_id),
+        },
+        billion::instruction::SetAdminRoles { grid_id, config_authority, metadata_authority, treasury_authority },
+    )
+}
+
+/// Config-authority-only: unlock every ring up to `ring` by raising total_burned
+pub fn admin_advance_ring(grid_id: u64, authority: &Pubkey, ring: u8) -> Instruction {
+    build(
+        billion::accounts::AdminAdvanceRing {
+            authority: *authority,
+            grid_config: find_grid_config(grid_id),
+            admin_roles: find_admin_roles(grid_id),
+            ring_unlock_schedule: find_ring_unlock_schedule(grid_id),
+            config_freeze: find_config_freeze(grid_id),
+        },
+        billion::instruction::AdminAdvanceRing { grid_id, ring },
+    )
+}
+
+/// Config-authority-only: queue a timelocked config change
+pub fn queue_config_update(grid_id: u64, authority: &Pubkey, change: TimelockedConfig) -> Instruction {
+    build(
+        billion::accounts::QueueConfigUpdate {
+            authority: *authority,
+            grid_config: find_grid_config(grid_id),
+            admin_roles: find_admin_roles(grid_id),
+            config_timelock: find_config_timelock(grid_id),
+            system_program: system_program::ID,
+            token_treasury: find_token_treasury(grid_id),
+            referral_share: find_referral_share(grid_id),
+            config_freeze: find_config_freeze(grid_id),
+        },
+        billion::instruction::QueueConfigUpdate { grid_id, change },
+    )
+}
+
+/// Config-authority-only: apply the queued change, which must be passed again in full
+pub fn execute_config_update(grid_id: u64, authority: &Pubkey, change: TimelockedConfig) -> Instruction {
+    build(
+        billion::accounts::ExecuteConfigUpdate {
+            authority: *authority,
+            grid_config: find_grid_config(grid_id),
+            admin_roles: find_admin_roles(grid_id),
+            config_timelock: find_config_timelock(grid_id),
+            token_treasury: find_token_treasury(grid_id),
+            referral_share: find_referral_share(grid_id),
+            ring_pricing: find_ring_pricing(grid_id),
+            system_program: system_program::ID,
+            ring_unlock_schedule: find_ring_unlock_schedule(grid_id),
+            config_freeze: find_config_freeze(grid_id),
+        },
+        billion::instruction::ExecuteConfigUpdate { grid_id, change },
+    )
+}
+
+/// Config-authority-only: drop the queued change
+pub fn cancel_config_update(grid_id: u64, authority: &Pubkey) -> Instruction {
+    build(
+        billion::accounts::CancelConfigUpdate {
+            authority: *authority,
+            grid_config: find_grid_config(grid_id),
+            admin_roles: find_admin_roles(grid_id),
+            config_timelock: find_config_timelock(grid_id),
+        },
+        billion::instruction::CancelConfigUpdate,
+    )
+}
+
+pub fn set_raffle_interval(grid_id: u64, authority: &Pubkey, raffle_interval_secs: i64) -> Instruction {
+    build(
+        billion::accounts::SetRaffleInterval {
+            authority: *authority,
+            grid_config: find_grid_config(grid_id),
+        },
+        billion::instruction::SetRaffleInterval { grid_id, raffle_interval_secs },
+    )
+}
+
+/// Draw a raffle prize; `caller` pays for the Raffle account on the first draw
+pub fn run_raffle(grid: &GridAccounts, caller: &Pubkey) -> Instruction {
+    build(
+        billion::accounts::RunRaffle {
+            caller: *caller,
+            grid_config: grid.grid_config,
+            block_map: grid.block_map,
+            raffle: find_raffle(grid.grid_id),
+            land_buy_reward_pool: grid.land_buy_reward_pool,
+            ring_unlock_schedule: find_ring_unlock_schedule(grid.grid_id),
+            slot_hashes: sysvar::slot_hashes::ID,
+            system_program: system_program::ID,
+        },
+        billion::instruction::RunRaffle { grid_id: grid.grid_id },
+    )
+}
+
+/// Claim the open raffle prize as parcel `parcel_id`, minted to `asset`
+pub fn claim_raffle_block(grid: &GridAccounts, claimer: &Pubkey, asset: &Pubkey, parcel_id: u16) -> Instruction {
+    build(
+        billion::accounts::ClaimRaffleBlock {
+            claimer: *claimer,
+            grid_config: grid.grid_config,
+            block_map: grid.block_map,
+            raffle: find_raffle(grid.grid_id),
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            land_buy_reward_pool: grid.land_buy_reward_pool,
+            token_mint: grid.token_mint,
+            asset: *asset,
+            collection: grid.collection,
+            mpl_core_program: MPL_CORE_ID,
+            token_program: token_2022::ID,
+            system_program: system_program::ID,
+            ring_reward_weights: find_ring_reward_weights(grid.grid_id),
+            ring_occupancy: find_ring_occupancy(grid.grid_id),
+            map_digest: find_map_digest(grid.grid_id),
+            ring_unlock_schedule: find_ring_unlock_schedule(grid.grid_id),
+        },
+        billion::instruction::ClaimRaffleBlock { grid_id: grid.grid_id },
+    )
+}
+
+/// ed25519 program instruction checking `signature` by `signer` over `message`, with every
+/// input inline in its own data as claim_parcel_signed requires
+pub fn ed25519_verify(signer: &Pubkey, signature: &[u8; 64], message: &[u8]) -> Instruction {
+    const HEADER_LEN: u16 = 16;
+    let public_key_offset = HEADER_LEN;
+    let signature_offset = public_key_offset + 32;
+    let message_offset = signature_offset + 64;
+    let offsets = [
+        signature_offset,
+        u16::MAX,
+        public_key_offset,
+        u16::MAX,
+        message_offset,
+        message.len() as u16,
+        u16::MAX,
+    ];
+
+    let mut data = vec![1u8, 0];
+    data.extend(offsets.iter().flat_map(|offset| offset.to_le_bytes()));
+    data.extend_from_slice(signer.as_ref());
+    data.extend_from_slice(signature);
+    data.extend_from_slice(message);
+    Instruction { program_id: ed25519_program::ID, accounts: vec![], data }
+}
+
+/// Submit a user-signed `authorization` as `relayer`, minting to `asset` as `parcel_id`.
+/// Must directly follow [`ed25519_verify`] over `authorization.message()`, and the user must
+/// have approved the GridConfig PDA as delegate on their token account.
+pub fn claim_parcel_signed(
+    grid: &GridAccounts,
+    relayer: &Pubkey,
+    authorization: &ClaimAuthorization,
+    asset: &Pubkey,
+    parcel_id: u16,
+) -> Instruction {
+    let user = authorization.user;
+    build(
+        billion::accounts::ClaimParcelSigned {
+            relayer: *relayer,
+            user,
+            grid_config: grid.grid_config,
+            block_map: grid.block_map,
+            token_mint: grid.token_mint,
+            user_token_account: find_token_account(&user, &grid.token_mint),
+            land_buy_reward_pool: grid.land_buy_reward_pool,
+            claimer_stats: find_claimer_stats(grid.grid_id, &user),
+            reward_dust: find_reward_dust(grid.grid_id),
+            claim_nonce: find_claim_nonce(grid.grid_id, &user),
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            asset: *asset,
+            collection: grid.collection,
+            mpl_core_program: MPL_CORE_ID,
+            token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            hook_config: find_hook_config(grid.grid_id),
+            ring_pricing: find_ring_pricing(grid.grid_id),
+            instructions: sysvar::instructions::ID,
+            ring_reward_weights: find_ring_reward_weights(grid.grid_id),
+            ring_occupancy: find_ring_occupancy(grid.grid_id),
+            map_digest: find_map_digest(grid.grid_id),
+            ring_unlock_schedule: find_ring_unlock_schedule(grid.grid_id),
+            token_treasury: find_token_treasury(grid.grid_id),
+            treasury_token_account: grid.treasury,
+        },
+        billion::instruction::ClaimParcelSigned {
+            grid_id: grid.grid_id,
+            x: authorization.x,
+            y: authorization.y,
+            width: authorization.width,
+            height: authorization.height,
+            max_cost: authorization.max_cost,
+            nonce: authorization.nonce,
+            expires_at: authorization.expires_at,
+        },
+    )
+}
+
+/// Close an expired attestation, refunding its rent to `owner`. Any fee payer may send it.
+pub fn close_attestation(grid_id: u64, owner: &Pubkey, epoch: u16, parcel_id: u16) -> Instruction {
+    build(
+        billion::accounts::CloseAttestation {
+            owner: *owner,
+            attestation: find_attestation(grid_id, epoch, parcel_id, owner),
+        },
+        billion::instruction::CloseAttestation { grid_id },
+    )
+}
+
+/// Claim each of `parcels` (asset signer and rectangle) for one combined payment. The parcels
+/// get consecutive ids starting at `first_parcel_id`, the grid's current next_parcel_id.
+pub fn claim_parcels_batch(
+    grid: &GridAccounts,
+    claimer: &Pubkey,
+    first_parcel_id: u16,
+    parcels: &[(Pubkey, Rect)],
+) -> Instruction {
+    let mut ix = build(
+        billion::accounts::ClaimParcelsBatch {
+            claimer: *claimer,
+            grid_config: grid.grid_config,
+            block_map: grid.block_map,
+            token_mint: grid.token_mint,
+            claimer_token_account: find_token_account(claimer, &grid.token_mint),
+            land_buy_reward_pool: grid.land_buy_reward_pool,
+            claimer_stats: find_claimer_stats(grid.grid_id, claimer),
+            reward_dust: find_reward_dust(grid.grid_id),
+            collection: grid.collection,
+            mpl_core_program: MPL_CORE_ID,
+            token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            hook_config: find_hook_config(grid.grid_id),
+            ring_pricing: find_ring_pricing(grid.grid_id),
+            ring_reward_weights: find_ring_reward_weights(grid.grid_id),
+            ring_occupancy: find_ring_occupancy(grid.grid_id),
+            map_digest: find_map_digest(grid.grid_id),
+            ring_unlock_schedule: find_ring_unlock_schedule(grid.grid_id),
+            token_treasury: find_token_treasury(grid.grid_id),
+            treasury_token_account: grid.treasury,
+        },
+        billion::instruction::ClaimParcelsBatch {
+            grid_id: grid.grid_id,
+            rects: parcels
+                .iter()
+                .map(|(_, rect)| BatchRect { x: rect.x, y: rect.y, width: rect.width, height: rect.height })
+                .collect(),
+        },
+    );
+    for (parcel_id, (asset, _)) in (first_parcel_id..).zip(parcels) {
+        ix.accounts.push(AccountMeta::new(*asset, true));
+        ix.accounts.push(AccountMeta::new(find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id), false));
+    }
+    ix
+}
+
+/// Seed each of `parcels` (asset signer, recipient, and rectangle) in one instruction. The
+/// parcels get consecutive ids starting at `first_parcel_id`, the grid's current next_parcel_id.
+pub fn admin_mint_batch(grid: &GridAccounts, first_parcel_id: u16, parcels: &[(Pubkey, Pubkey, Rect)]) -> Instruction {
+    // Each distinct recipient is passed once, in order of first appearance
+    let mut recipients: Vec<Pubkey> = Vec::new();
+    let mut entries = Vec::with_capacity(parcels.len());
+    for (_, recipient, rect) in parcels {
+        let recipient_index = match recipients.iter().position(|known| known == recipient) {
+            Some(index) => index,
+            None => {
+                recipients.push(*recipient);
+                recipients.len() - 1
+            }
+        };
+        entries.push(AdminMintEntry {
+            x: rect.x,
+            y: rect.y,
+            width: rect.width,
+            height: rect.height,
+            recipient_index: recipient_index as u8,
+        });
+    }
+
+    let mut ix = build(
+        billion::accounts::AdminMintBatch {
+            authority: grid.authority,
+            grid_config: grid.grid_config,
+            block_map: grid.block_map,
+            collection: grid.collection,
+            mpl_core_program: MPL_CORE_ID,
+            system_program: system_program::ID,
+            admin_mint_allowance: find_admin_mint_allowance(grid.grid_id),
+            ring_reward_weights: find_ring_reward_weights(grid.grid_id),
+            ring_occupancy: find_ring_occupancy(grid.grid_id),
+            map_digest: find_map_digest(grid.grid_id),
+            config_freeze: find_config_freeze(grid.grid_id),
+        },
+        billion::instruction::AdminMintBatch { grid_id: grid.grid_id, entries },
+    );
+    for (parcel_id, (asset, _, _)) in (first_parcel_id..).zip(parcels) {
+        ix.accounts.push(AccountMeta::new(*asset, true));
+        ix.accounts.push(AccountMeta::new(find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id), false));
+    }
+    ix.accounts.extend(recipients.into_iter().map(|recipient| AccountMeta::new_readonly(recipient, false)));
+    ix
+}
+
+/// Claim `rect` as parcel `parcel_id`, paying price_per_block_lamports per block in SOL
+pub fn claim_parcel_sol(
+    grid: &GridAccounts,
+    claimer: &Pubkey,
+    asset: &Pubkey,
+    parcel_id: u16,
+    rect: Rect,
+) -> Instruction {
+    build(
+        billion::accounts::ClaimParcelSol {
+            claimer: *claimer,
+            grid_config: grid.grid_config,
+            block_map: grid.block_map,
+            sol_treasury: find_sol_treasury(&grid.grid_config),
+            claimer_stats: find_claimer_stats(grid.grid_id, claimer),
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            asset: *asset,
+            collection: grid.collection,
+            mpl_core_program: MPL_CORE_ID,
+            system_program: system_program::ID,
+            hook_config: find_hook_config(grid.grid_id),
+            ring_reward_weights: find_ring_reward_weights(grid.grid_id),
+            ring_occupancy: find_ring_occupancy(grid.grid_id),
+            map_digest: find_map_digest(grid.grid_id),
+            ring_unlock_schedule: find_ring_unlock_schedule(grid.grid_id),
+        },
+        billion::instruction::ClaimParcelSol {
+            grid_id: grid.grid_id,
+            x: rect.x,
+            y: rect.y,
+            width: rect.width,
+            height: rect.height,
+        },
+    )
+}
+
+/// Claim the cells of `rect` set in `mask` (row-major, see
+/// [`mask_contains`](billion_core::mask_contains)) as parcel `parcel_id`
+pub fn claim_parcel_masked(
+    grid: &GridAccounts,
+    claimer: &Pubkey,
+    asset: &Pubkey,
+    parcel_id: u16,
+    rect: Rect,
+    mask: Vec<u8>,
+) -> Instruction {
+    build(
+        billion::accounts::ClaimParcelMasked {
+            claimer: *claimer,
+            grid_config: grid.grid_config,
+            block_map: grid.block_map,
+            token_mint: grid.token_mint,
+            claimer_token_account: find_token_account(claimer, &grid.token_mint),
+            land_buy_reward_pool: grid.land_buy_reward_pool,
+            claimer_stats: find_claimer_stats(grid.grid_id, claimer),
+            reward_dust: find_reward_dust(grid.grid_id),
+            parcel_id_pool: find_parcel_id_pool(grid.grid_id),
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            parcel_mask: find_parcel_mask_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            asset: *asset,
+            collection: grid.collection,
+            mpl_core_program: MPL_CORE_ID,
+            token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            hook_config: find_hook_config(grid.grid_id),
+            ring_pricing: find_ring_pricing(grid.grid_id),
+            referrer_account: None,
+            referral_vault: None,
+            ring_reward_weights: find_ring_reward_weights(grid.grid_id),
+            ring_occupancy: find_ring_occupancy(grid.grid_id),
+            map_digest: find_map_digest(grid.grid_id),
+            ring_unlock_schedule: find_ring_unlock_schedule(grid.grid_id),
+            token_treasury: find_token_treasury(grid.grid_id),
+            treasury_token_account: grid.treasury,
+        },
+        billion::instruction::ClaimParcelMasked {
+            grid_id: grid.grid_id,
+            x: rect.x,
+            y: rect.y,
+            width: rect.width,
+            height: rect.height,
+            mask,
+        },
+    )
+}
+
+/// Claim `rect` as parcel `parcel_id`, paid first out of the land-buy rewards of the
+/// claimer's current-epoch parcel `reward_parcel_id`
+pub fn claim_parcel_with_rewards(
+    grid: &GridAccounts,
+    claimer: &Pubkey,
+    asset: &Pubkey,
+    parcel_id: u16,
+    rect: Rect,
+    reward_parcel_id: u16,
+    reward_asset: &Pubkey,
+) -> Instruction {
+    build(
+        billion::accounts::ClaimParcelWithRewards {
+            claimer: *claimer,
+            grid_config: grid.grid_config,
+            block_map: grid.block_map,
+            token_mint: grid.token_mint,
+            claimer_token_account: find_token_account(claimer, &grid.token_mint),
+            land_buy_reward_pool: grid.land_buy_reward_pool,
+            claimer_stats: find_claimer_stats(grid.grid_id, claimer),
+            reward_dust: find_reward_dust(grid.grid_id),
+            parcel_id_pool: find_parcel_id_pool(grid.grid_id),
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            asset: *asset,
+            collection: grid.collection,
+            mpl_core_program: MPL_CORE_ID,
+            token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            hook_config: find_hook_config(grid.grid_id),
+            ring_pricing: find_ring_pricing(grid.grid_id),
+            referrer_account: None,
+            referral_vault: None,
+            reward_parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, reward_parcel_id),
+            reward_asset: *reward_asset,
+            epoch_archive: None,
+            ring_reward_weights: find_ring_reward_weights(grid.grid_id),
+            ring_occupancy: find_ring_occupancy(grid.grid_id),
+            map_digest: find_map_digest(grid.grid_id),
+            ring_unlock_schedule: find_ring_unlock_schedule(grid.grid_id),
+            token_treasury: find_token_treasury(grid.grid_id),
+            treasury_token_account: grid.treasury,
+        },
+        billion::instruction::ClaimParcelWithRewards {
+            grid_id: grid.grid_id,
+            x: rect.x,
+            y: rect.y,
+            width: rect.width,
+            height: rect.height,
+            reward_parcel_id,
+        },
+    )
+}
+
+pub fn set_sol_price(grid_id: u64, authority: &Pubkey, price_per_block_lamports: u64, sol_ring_credit_bps: u16) -> Instruction {
+    build(
+        billion::accounts::SetSolPrice {
+            authority: *authority,
+            grid_config: find_grid_config(grid_id),
+            config_freeze: find_config_freeze(grid_id),
+        },
+        billion::instruction::SetSolPrice { grid_id, price_per_block_lamports, sol_ring_credit_bps },
+    )
+}
+
+/// Send `amount` lamports from the SOL treasury to `recipient`
+pub fn withdraw_sol_treasury(grid_id: u64, authority: &Pubkey, recipient: &Pubkey, amount: u64) -> Instruction {
+    let grid_config = find_grid_config(grid_id);
+    build(
+        billion::accounts::WithdrawSolTreasury {
+            authority: *authority,
+            grid_config,
+            sol_treasury: find_sol_treasury(&grid_config),
+            recipient: *recipient,
+            system_program: system_program::ID,
+            admin_roles: find_admin_roles(grid_id),
+        },
+        billion::instruction::WithdrawSolTreasury { grid_id, amount },
+    )
+}
+
+/// Deposit `lamports` for every parcel enrolled in SOL rewards to claim pro-rata
+pub fn fund_sol_rewards(grid: &GridAccounts, depositor: &Pubkey, lamports: u64) -> Instruction {
+    build(
+        billion::accounts::FundSolRewards {
+            depositor: *depositor,
+            grid_config: grid.grid_config,
+            sol_rewards: find_sol_rewards(grid.grid_id),
+            sol_reward_vault: find_sol_reward_vault(&grid.grid_config),
+            system_program: system_program::ID,
+        },
+        billion::instruction::FundSolRewards { grid_id: grid.grid_id, lamports },
+    )
+}
+
+/// Claim a current-epoch parcel's SOL rewards, enrolling it on the first call
+pub fn claim_sol_rewards(grid: &GridAccounts, claimer: &Pubkey, asset: &Pubkey, parcel_id: u16) -> Instruction {
+    build(
+        billion::accounts::ClaimSolRewards {
+            claimer: *claimer,
+            grid_config: grid.grid_config,
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            asset: *asset,
+            sol_rewards: find_sol_rewards(grid.grid_id),
+            sol_reward_checkpoint: find_sol_reward_checkpoint_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            sol_reward_vault: find_sol_reward_vault(&grid.grid_config),
+            system_program: system_program::ID,
+        },
+        billion::instruction::ClaimSolRewards { grid_id: grid.grid_id, parcel_id },
+    )
+}
+
+/// Permissionless: drop the SOL reward enrollment of a parcel that left the grid
+pub fn close_sol_reward_checkpoint(grid_id: u64, payer: &Pubkey, epoch: u16, parcel_id: u16) -> Instruction {
+    build(
+        billion::accounts::CloseSolRewardCheckpoint {
+            payer: *payer,
+            sol_rewards: find_sol_rewards(grid_id),
+            sol_reward_checkpoint: find_sol_reward_checkpoint_in_epoch(grid_id, epoch, parcel_id),
+            parcel_info: find_parcel_info_in_epoch(grid_id, epoch, parcel_id),
+        },
+        billion::instruction::CloseSolRewardCheckpoint { grid_id, epoch, parcel_id },
+    )
+}
+
+/// Permissionless: rewrite `parcel_id`'s Attributes plugin from its ParcelInfo
+pub fn refresh_parcel_attributes(grid: &GridAccounts, payer: &Pubkey, asset: &Pubkey, parcel_id: u16) -> Instruction {
+    build(
+        billion::accounts::RefreshParcelAttributes {
+            payer: *payer,
+            grid_config: grid.grid_config,
+            parcel_info: find_parcel_info_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            parcel_content: find_parcel_content_in_epoch(grid.grid_id, grid.epoch, parcel_id),
+            asset: *asset,
+            collection: grid.collection,
+            mpl_core_program: MPL_CORE_ID,
+            system_program: system_program::ID,
+        },
+        billion::instruction::RefreshParcelAttributes { grid_id: grid.grid_id, parcel_id },
+    )
+}
+
+/// Authority-only and irreversible: freeze the config for good. `confirmation` must be
+/// billion::constants::FREEZE_CONFIG_CONFIRMATION.
+pub fn freeze_config(grid_id: u64, authority: &Pubkey, confirmation: u64) -> Instruction {
+    build(
+        billion::accounts::FreezeConfig {
+            authority: *authority,
+            grid_config: find_grid_config(grid_id),
+            config_freeze: find_config_freeze(grid_id),
+            system_program: system_program::ID,
+        },
+        billion::instruction::FreezeConfig { grid_id, confirmation },
+    )
+}
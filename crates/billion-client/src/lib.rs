@@ -0,0 +1,30 @@
+//! Client helpers for the billion program.
+//!
+//! Everything here works on plain [`Pubkey`]s, [`Instruction`]s, and raw account bytes, so it
+//! can be used with any RPC client.
+//!
+//! ```
+//! use billion_client::{find_grid_config, find_parcel_info, find_reward_pool};
+//!
+//! let grid_config = find_grid_config(0);
+//! let reward_pool = find_reward_pool(&grid_config);
+//! let parcel_info = find_parcel_info(0, 1);
+//! assert_ne!(reward_pool, parcel_info);
+//! ```
+
+pub mod accounts;
+pub mod allowlist;
+pub mod block_map;
+pub mod instructions;
+pub mod pda;
+pub mod quest;
+
+pub use accounts::*;
+pub use allowlist::*;
+pub use block_map::*;
+pub use billion_core::Rect;
+pub use billion::instructions::claim_parcel_signed::ClaimAuthorization;
+pub use billion::state::TimelockedConfig;
+pub use instructions::*;
+pub use pda::*;
+pub use quest::*;
@@ -0,0 +1,412 @@
+use anchor_lang::prelude::Pubkey;
+use billion::constants::{
+    ADMIN_MINT_ALLOWANCE_SEED, ADMIN_ROLES_SEED, ALLOWLIST_SEED, ASSET_SEED, ATTESTATION_SEED,
+    CHARITY_REGISTRY_SEED, CLAIMER_STATS_SEED, CLAIMS_PAUSE_SEED, CLAIM_NONCE_SEED, CONFIG_FREEZE_SEED,
+    CONFIG_TIMELOCK_SEED, DISTRIBUTION_VAULT_SEED, EMISSIONS_VAULT_SEED, EPOCH_ARCHIVE_SEED,
+    FRACTION_ESCROW_SEED, FRACTION_POSITION_SEED, FRACTION_SEED, FRACTION_VAULT_SEED, GRID_CONFIG_SEED,
+    HARBERGER_DISTRICT_SEED, HOOK_CONFIG_SEED, LAND_BUY_REWARD_POOL_SEED, MAP_DIGEST_SEED, NAME_RECORD_SEED,
+    PARCEL_CONTENT_SEED, PARCEL_ID_POOL_SEED, PARCEL_INFO_SEED, PARCEL_MASK_SEED, PARCEL_PIXELS_SEED,
+    QUEST_CLAIMS_SEED, QUEST_SEED, QUEST_VAULT_SEED, RAFFLE_SEED, REFERRAL_SHARE_SEED, REFERRAL_VAULT_SEED,
+    REFERRER_SEED, RENAME_FEE_SEED, REWARD_DELEGATE_SEED, REWARD_DUST_SEED, REWARD_EXCLUSIONS_SEED, RING_OCCUPANCY_SEED,
+    RING_PRICING_SEED, RING_REWARD_WEIGHTS_SEED, RING_UNLOCK_SCHEDULE_SEED, SOL_REWARDS_SEED,
+    SOL_REWARD_CHECKPOINT_SEED, SOL_REWARD_VAULT_SEED, SOL_TREASURY_SEED, STAKE_SEED, TOKEN_TREASURY_SEED,
+    VALUATION_SEED, VOUCHER_SEED,
+};
+use billion::state::{epoch_seed, grid_seed, NameRecord};
+
+/// GridConfig PDA of grid `grid_id`
+pub fn find_grid_config(grid_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[GRID_CONFIG_SEED, &grid_seed(grid_id)], &billion::ID).0
+}
+
+/// ParcelInfo PDA for `parcel_id` in epoch 0
+pub fn find_parcel_info(grid_id: u64, parcel_id: u16) -> Pubkey {
+    find_parcel_info_in_epoch(grid_id, 0, parcel_id)
+}
+
+/// ParcelInfo PDA for `parcel_id` in `epoch`
+pub fn find_parcel_info_in_epoch(grid_id: u64, epoch: u16, parcel_id: u16) -> Pubkey {
+    Pubkey::find_program_address(
+        &[PARCEL_INFO_SEED, &grid_seed(grid_id), &epoch_seed(epoch), &parcel_id.to_le_bytes()],
+        &billion::ID,
+    )
+    .0
+}
+
+/// EpochArchive PDA written when `epoch` ended
+pub fn find_epoch_archive(grid_id: u64, epoch: u16) -> Pubkey {
+    Pubkey::find_program_address(&[EPOCH_ARCHIVE_SEED, &grid_seed(grid_id), &epoch.to_le_bytes()], &billion::ID).0
+}
+
+/// Land buy reward pool token account owned by `grid_config`
+pub fn find_reward_pool(grid_config: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[LAND_BUY_REWARD_POOL_SEED, grid_config.as_ref()], &billion::ID).0
+}
+
+/// Referral vault token account holding accrued referral earnings
+pub fn find_referral_vault(grid_config: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[REFERRAL_VAULT_SEED, grid_config.as_ref()], &billion::ID).0
+}
+
+/// ReferrerAccount PDA of `referrer`
+pub fn find_referrer_account(grid_id: u64, referrer: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[REFERRER_SEED, &grid_seed(grid_id), referrer.as_ref()], &billion::ID).0
+}
+
+/// Emissions vault token account paying staking rewards
+pub fn find_emissions_vault(grid_config: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[EMISSIONS_VAULT_SEED, grid_config.as_ref()], &billion::ID).0
+}
+
+/// StakeAccount PDA for `parcel_id` in epoch 0
+pub fn find_stake_account(grid_id: u64, parcel_id: u16) -> Pubkey {
+    find_stake_account_in_epoch(grid_id, 0, parcel_id)
+}
+
+/// StakeAccount PDA for `parcel_id` in `epoch`
+pub fn find_stake_account_in_epoch(grid_id: u64, epoch: u16, parcel_id: u16) -> Pubkey {
+    Pubkey::find_program_address(
+        &[STAKE_SEED, &grid_seed(grid_id), &epoch_seed(epoch), &parcel_id.to_le_bytes()],
+        &billion::ID,
+    )
+    .0
+}
+
+/// Quest vault token account paying quest rewards
+pub fn find_quest_vault(grid_config: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[QUEST_VAULT_SEED, grid_config.as_ref()], &billion::ID).0
+}
+
+/// Quest PDA for a Merkle `root`
+pub fn find_quest(grid_id: u64, root: &[u8; 32]) -> Pubkey {
+    Pubkey::find_program_address(&[QUEST_SEED, &grid_seed(grid_id), root.as_ref()], &billion::ID).0
+}
+
+/// QuestClaims bitmap chunk of `quest` holding leaf `index`
+pub fn find_quest_claims(quest: &Pubkey, index: u32) -> Pubkey {
+    let chunk = billion::state::QuestClaims::chunk_of(index);
+    Pubkey::find_program_address(&[QUEST_CLAIMS_SEED, quest.as_ref(), &chunk.to_le_bytes()], &billion::ID).0
+}
+
+/// Vault token account of a `distribution` keypair account
+pub fn find_distribution_vault(distribution: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[DISTRIBUTION_VAULT_SEED, distribution.as_ref()], &billion::ID).0
+}
+
+/// HarbergerDistrict PDA of grid `grid_id`
+pub fn find_harberger_district(grid_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[HARBERGER_DISTRICT_SEED, &grid_seed(grid_id)], &billion::ID).0
+}
+
+/// ParcelValuation PDA for `parcel_id` in epoch 0
+pub fn find_parcel_valuation(grid_id: u64, parcel_id: u16) -> Pubkey {
+    find_parcel_valuation_in_epoch(grid_id, 0, parcel_id)
+}
+
+/// ParcelValuation PDA for `parcel_id` in `epoch`
+pub fn find_parcel_valuation_in_epoch(grid_id: u64, epoch: u16, parcel_id: u16) -> Pubkey {
+    Pubkey::find_program_address(
+        &[VALUATION_SEED, &grid_seed(grid_id), &epoch_seed(epoch), &parcel_id.to_le_bytes()],
+        &billion::ID,
+    )
+    .0
+}
+
+/// Core asset PDA used by `claim_parcel_pda` for `parcel_id` in epoch 0
+pub fn find_asset(grid_config: &Pubkey, parcel_id: u16) -> Pubkey {
+    find_asset_in_epoch(grid_config, 0, parcel_id)
+}
+
+/// Core asset PDA used by `claim_parcel_pda` for `parcel_id` in `epoch`
+pub fn find_asset_in_epoch(grid_config: &Pubkey, epoch: u16, parcel_id: u16) -> Pubkey {
+    Pubkey::find_program_address(
+        &[ASSET_SEED, grid_config.as_ref(), &epoch_seed(epoch), &parcel_id.to_le_bytes()],
+        &billion::ID,
+    )
+    .0
+}
+
+/// HookConfig PDA of grid `grid_id`; also the signer of every on-claim hook CPI
+pub fn find_hook_config(grid_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[HOOK_CONFIG_SEED, &grid_seed(grid_id)], &billion::ID).0
+}
+
+/// CharityRegistry PDA of grid `grid_id`
+pub fn find_charity_registry(grid_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[CHARITY_REGISTRY_SEED, &grid_seed(grid_id)], &billion::ID).0
+}
+
+/// Lifetime ClaimerStats PDA for `wallet`
+pub fn find_claimer_stats(grid_id: u64, wallet: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[CLAIMER_STATS_SEED, &grid_seed(grid_id), wallet.as_ref()], &billion::ID).0
+}
+
+/// Fraction PDA for `parcel_id` in `epoch`; owns the escrowed asset while fractionalized
+pub fn find_fraction(grid_id: u64, epoch: u16, parcel_id: u16) -> Pubkey {
+    Pubkey::find_program_address(
+        &[FRACTION_SEED, &grid_seed(grid_id), &epoch_seed(epoch), &parcel_id.to_le_bytes()],
+        &billion::ID,
+    )
+    .0
+}
+
+/// Share escrow token account holding a fraction's deposited shares
+pub fn find_fraction_escrow(share_mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[FRACTION_ESCROW_SEED, share_mint.as_ref()], &billion::ID).0
+}
+
+/// Vault token account holding a fraction's harvested land-buy rewards
+pub fn find_fraction_vault(share_mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[FRACTION_VAULT_SEED, share_mint.as_ref()], &billion::ID).0
+}
+
+/// `holder`'s FractionPosition PDA for the fraction minting `share_mint`
+pub fn find_fraction_position(share_mint: &Pubkey, holder: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[FRACTION_POSITION_SEED, share_mint.as_ref(), holder.as_ref()], &billion::ID).0
+}
+
+/// Ownership Attestation PDA of `owner` for `parcel_id` in `epoch`
+pub fn find_attestation(grid_id: u64, epoch: u16, parcel_id: u16, owner: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[ATTESTATION_SEED, &grid_seed(grid_id), &epoch_seed(epoch), &parcel_id.to_le_bytes(), owner.as_ref()],
+        &billion::ID,
+    )
+    .0
+}
+
+/// NameRecord PDA for `name`; spellings that normalize to the same name share it
+pub fn find_name_record(grid_id: u64, name: &str) -> Pubkey {
+    Pubkey::find_program_address(&[NAME_RECORD_SEED, &grid_seed(grid_id), &NameRecord::name_hash(name)], &billion::ID).0
+}
+
+/// Voucher PDA `voucher_id` issued to `beneficiary`
+pub fn find_voucher(grid_id: u64, beneficiary: &Pubkey, voucher_id: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[VOUCHER_SEED, &grid_seed(grid_id), beneficiary.as_ref(), &voucher_id.to_le_bytes()],
+        &billion::ID,
+    )
+    .0
+}
+
+/// Raffle PDA of grid `grid_id`
+pub fn find_raffle(grid_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[RAFFLE_SEED, &grid_seed(grid_id)], &billion::ID).0
+}
+
+/// `user`'s ClaimNonce PDA guarding their signed claims against replay
+pub fn find_claim_nonce(grid_id: u64, user: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[CLAIM_NONCE_SEED, &grid_seed(grid_id), user.as_ref()], &billion::ID).0
+}
+
+/// System account holding lamports paid through claim_parcel_sol
+pub fn find_sol_treasury(grid_config: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[SOL_TREASURY_SEED, grid_config.as_ref()], &billion::ID).0
+}
+
+/// RingPricing PDA of grid `grid_id`
+pub fn find_ring_pricing(grid_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[RING_PRICING_SEED, &grid_seed(grid_id)], &billion::ID).0
+}
+
+/// Allowlist PDA of grid `grid_id`
+pub fn find_allowlist(grid_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[ALLOWLIST_SEED, &grid_seed(grid_id)], &billion::ID).0
+}
+
+/// ParcelIdPool PDA of grid `grid_id`
+pub fn find_parcel_id_pool(grid_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[PARCEL_ID_POOL_SEED, &grid_seed(grid_id)], &billion::ID).0
+}
+
+/// AdminMintAllowance PDA of grid `grid_id`
+pub fn find_admin_mint_allowance(grid_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[ADMIN_MINT_ALLOWANCE_SEED, &grid_seed(grid_id)], &billion::ID).0
+}
+
+/// RewardExclusions PDA of grid `grid_id`
+pub fn find_reward_exclusions(grid_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[REWARD_EXCLUSIONS_SEED, &grid_seed(grid_id)], &billion::ID).0
+}
+
+/// ParcelMask PDA for a masked `parcel_id` in `epoch`
+pub fn find_parcel_mask_in_epoch(grid_id: u64, epoch: u16, parcel_id: u16) -> Pubkey {
+    Pubkey::find_program_address(
+        &[PARCEL_MASK_SEED, &grid_seed(grid_id), &epoch_seed(epoch), &parcel_id.to_le_bytes()],
+        &billion::ID,
+    )
+    .0
+}
+
+/// RewardDelegate PDA of `parcel_id` in `epoch`
+pub fn find_reward_delegate_in_epoch(grid_id: u64, epoch: u16, parcel_id: u16) -> Pubkey {
+    Pubkey::find_program_address(
+        &[REWARD_DELEGATE_SEED, &grid_seed(grid_id), &epoch_seed(epoch), &parcel_id.to_le_bytes()],
+        &billion::ID,
+    )
+    .0
+}
+
+/// SolRewards PDA of grid `grid_id`
+pub fn find_sol_rewards(grid_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[SOL_REWARDS_SEED, &grid_seed(grid_id)], &billion::ID).0
+}
+
+/// System account holding lamports deposited through fund_sol_rewards
+pub fn find_sol_reward_vault(grid_config: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[SOL_REWARD_VAULT_SEED, grid_config.as_ref()], &billion::ID).0
+}
+
+/// SolRewardCheckpoint PDA of `parcel_id` in `epoch`
+pub fn find_sol_reward_checkpoint_in_epoch(grid_id: u64, epoch: u16, parcel_id: u16) -> Pubkey {
+    Pubkey::find_program_address(
+        &[SOL_REWARD_CHECKPOINT_SEED, &grid_seed(grid_id), &epoch_seed(epoch), &parcel_id.to_le_bytes()],
+        &billion::ID,
+    )
+    .0
+}
+
+/// RewardDust PDA of grid `grid_id`
+pub fn find_reward_dust(grid_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[REWARD_DUST_SEED, &grid_seed(grid_id)], &billion::ID).0
+}
+
+/// RingRewardWeights PDA of grid `grid_id`
+pub fn find_ring_reward_weights(grid_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[RING_REWARD_WEIGHTS_SEED, &grid_seed(grid_id)], &billion::ID).0
+}
+
+/// AdminRoles PDA of grid `grid_id`
+pub fn find_admin_roles(grid_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[ADMIN_ROLES_SEED, &grid_seed(grid_id)], &billion::ID).0
+}
+
+/// ConfigTimelock PDA of grid `grid_id`
+pub fn find_config_timelock(grid_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[CONFIG_TIMELOCK_SEED, &grid_seed(grid_id)], &billion::ID).0
+}
+
+/// TokenTreasury PDA of grid `grid_id`
+pub fn find_token_treasury(grid_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[TOKEN_TREASURY_SEED, &grid_seed(grid_id)], &billion::ID).0
+}
+
+/// ConfigFreeze PDA of grid `grid_id`; exists once freeze_config has run
+pub fn find_config_freeze(grid_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[CONFIG_FREEZE_SEED, &grid_seed(grid_id)], &billion::ID).0
+}
+
+/// ClaimsPause PDA of grid `grid_id`
+pub fn find_claims_pause(grid_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[CLAIMS_PAUSE_SEED, &grid_seed(grid_id)], &billion::ID).0
+}
+
+/// RingOccupancy PDA of grid `grid_id`
+pub fn find_ring_occupancy(grid_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[RING_OCCUPANCY_SEED, &grid_seed(grid_id)], &billion::ID).0
+}
+
+/// MapDigest PDA of grid `grid_id`
+pub fn find_map_digest(grid_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[MAP_DIGEST_SEED, &grid_seed(grid_id)], &billion::ID).0
+}
+
+/// RingUnlockSchedule PDA of grid `grid_id`
+pub fn find_ring_unlock_schedule(grid_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[RING_UNLOCK_SCHEDULE_SEED, &grid_seed(grid_id)], &billion::ID).0
+}
+
+/// RenameFee PDA of grid `grid_id`
+pub fn find_rename_fee(grid_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[RENAME_FEE_SEED, &grid_seed(grid_id)], &billion::ID).0
+}
+
+/// ReferralShare PDA of grid `grid_id`
+pub fn find_referral_share(grid_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[REFERRAL_SHARE_SEED, &grid_seed(grid_id)], &billion::ID).0
+}
+
+/// ParcelContent PDA of `parcel_id` in `epoch`
+pub fn find_parcel_content_in_epoch(grid_id: u64, epoch: u16, parcel_id: u16) -> Pubkey {
+    Pubkey::find_program_address(
+        &[PARCEL_CONTENT_SEED, &grid_seed(grid_id), &epoch_seed(epoch), &parcel_id.to_le_bytes()],
+        &billion::ID,
+    )
+    .0
+}
+
+/// ParcelPixels PDA of `parcel_id` in `epoch`
+pub fn find_parcel_pixels_in_epoch(grid_id: u64, epoch: u16, parcel_id: u16) -> Pubkey {
+    Pubkey::find_program_address(
+        &[PARCEL_PIXELS_SEED, &grid_seed(grid_id), &epoch_seed(epoch), &parcel_id.to_le_bytes()],
+        &billion::ID,
+    )
+    .0
+}
+
+/// Token-2022 associated token account of `wallet` for `mint`
+pub fn find_token_account(wallet: &Pubkey, mint: &Pubkey) -> Pubkey {
+    anchor_spl::associated_token::get_associated_token_address_with_program_id(
+        wallet,
+        mint,
+        &anchor_spl::token_2022::ID,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use billion::state::{Allowlist, GridConfig, HookConfig, ParcelIdPool, ParcelInfo, ReferrerAccount, RingPricing};
+
+    /// Grid 0 derives every PDA exactly as the single-grid layout did
+    #[test]
+    fn test_pdas_match_program_seeds() {
+        let (grid_config, _) = Pubkey::find_program_address(&[GridConfig::SEED], &billion::ID);
+        assert_eq!(find_grid_config(0), grid_config);
+
+        let (parcel_info, _) = Pubkey::find_program_address(&[ParcelInfo::SEED, &7u16.to_le_bytes()], &billion::ID);
+        assert_eq!(find_parcel_info(0, 7), parcel_info);
+        assert_ne!(find_parcel_info(0, 7), find_parcel_info(0, 8));
+        assert_ne!(find_asset(&grid_config, 7), find_asset(&grid_config, 8));
+
+        let referrer = Pubkey::new_unique();
+        let (referrer_account, _) =
+            Pubkey::find_program_address(&[ReferrerAccount::SEED, referrer.as_ref()], &billion::ID);
+        assert_eq!(find_referrer_account(0, &referrer), referrer_account);
+
+        assert_eq!(find_parcel_info_in_epoch(0, 0, 7), find_parcel_info(0, 7));
+        assert_ne!(find_parcel_info_in_epoch(0, 1, 7), find_parcel_info(0, 7));
+        assert_ne!(find_stake_account_in_epoch(0, 1, 7), find_stake_account(0, 7));
+
+        let (hook_config, _) = Pubkey::find_program_address(&[HookConfig::SEED], &billion::ID);
+        assert_eq!(find_hook_config(0), hook_config);
+        let (ring_pricing, _) = Pubkey::find_program_address(&[RingPricing::SEED], &billion::ID);
+        assert_eq!(find_ring_pricing(0), ring_pricing);
+        let (allowlist, _) = Pubkey::find_program_address(&[Allowlist::SEED], &billion::ID);
+        assert_eq!(find_allowlist(0), allowlist);
+        let (parcel_id_pool, _) = Pubkey::find_program_address(&[ParcelIdPool::SEED], &billion::ID);
+        assert_eq!(find_parcel_id_pool(0), parcel_id_pool);
+
+        assert_ne!(find_fraction(0, 0, 7), find_fraction(0, 1, 7));
+        let share_mint = Pubkey::new_unique();
+        assert_ne!(find_fraction_vault(&share_mint), find_fraction_escrow(&share_mint));
+    }
+
+    #[test]
+    fn test_grids_have_disjoint_pdas() {
+        assert_ne!(find_grid_config(0), find_grid_config(1));
+        assert_ne!(find_grid_config(1), find_grid_config(2));
+        assert_ne!(find_parcel_info(0, 7), find_parcel_info(1, 7));
+        assert_ne!(find_parcel_info_in_epoch(1, 1, 7), find_parcel_info_in_epoch(0, 1, 7));
+        assert_ne!(find_admin_roles(0), find_admin_roles(1));
+        assert_ne!(find_ring_occupancy(0), find_ring_occupancy(1));
+        assert_ne!(find_map_digest(0), find_map_digest(1));
+        assert_ne!(find_ring_unlock_schedule(0), find_ring_unlock_schedule(1));
+        assert_ne!(find_rename_fee(0), find_rename_fee(1));
+        assert_ne!(find_referral_share(0), find_referral_share(1));
+        assert_ne!(find_parcel_content_in_epoch(0, 1, 7), find_parcel_content_in_epoch(1, 1, 7));
+        assert_ne!(find_parcel_pixels_in_epoch(0, 1, 7), find_parcel_pixels_in_epoch(1, 1, 7));
+        assert_ne!(find_reward_pool(&find_grid_config(0)), find_reward_pool(&find_grid_config(1)));
+        let referrer = Pubkey::new_unique();
+        assert_ne!(find_referrer_account(0, &referrer), find_referrer_account(1, &referrer));
+    }
+}
@@ -0,0 +1,95 @@
+//! Off-chain Merkle trees for quest payouts, hashed exactly like `claim_quest_reward` checks.
+
+use anchor_lang::prelude::Pubkey;
+use billion::state::{quest_leaf, quest_node};
+
+/// Payout tree over `(wallet, amount)` winners; a winner's leaf index is its position
+#[derive(Clone, Debug)]
+pub struct QuestTree {
+    /// Level 0 is the leaves, the last level is the root
+    levels: Vec<Vec<[u8; 32]>>,
+}
+
+impl QuestTree {
+    /// Panics if `winners` is empty
+    pub fn new(winners: &[(Pubkey, u64)]) -> Self {
+        assert!(!winners.is_empty(), "quest tree needs at least one winner");
+        let leaves = winners
+            .iter()
+            .enumerate()
+            .map(|(index, (wallet, amount))| quest_leaf(index as u32, wallet, *amount))
+            .collect::<Vec<_>>();
+        Self::from_leaves(leaves)
+    }
+
+    /// Tree over already hashed leaves, for other sorted-pair trees like the allowlist
+    pub(crate) fn from_leaves(leaves: Vec<[u8; 32]>) -> Self {
+        let mut levels = vec![leaves];
+        while levels.last().unwrap().len() > 1 {
+            // An odd node out is carried up unchanged
+            let next = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => quest_node(a, b),
+                    [a] => *a,
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next);
+        }
+        Self { levels }
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.levels.last().unwrap()[0]
+    }
+
+    pub fn len(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Sibling hashes from leaf `index` up to the root
+    pub fn proof(&self, index: u32) -> Vec<[u8; 32]> {
+        let mut position = index as usize;
+        let mut proof = Vec::new();
+        for level in &self.levels[..self.levels.len() - 1] {
+            if let Some(sibling) = level.get(position ^ 1) {
+                proof.push(*sibling);
+            }
+            position /= 2;
+        }
+        proof
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use billion::state::verify_quest_proof;
+
+    #[test]
+    fn test_every_proof_verifies() {
+        for size in 1..=9 {
+            let winners = (0..size).map(|i| (Pubkey::new_unique(), 100 * i as u64)).collect::<Vec<_>>();
+            let tree = QuestTree::new(&winners);
+            for (index, (wallet, amount)) in winners.iter().enumerate() {
+                let leaf = quest_leaf(index as u32, wallet, *amount);
+                assert!(verify_quest_proof(&tree.proof(index as u32), &tree.root(), leaf));
+            }
+        }
+    }
+
+    #[test]
+    fn test_proof_rejects_other_wallet() {
+        let winners = vec![(Pubkey::new_unique(), 5), (Pubkey::new_unique(), 7)];
+        let tree = QuestTree::new(&winners);
+        let leaf = quest_leaf(0, &winners[1].0, 5);
+        assert!(!verify_quest_proof(&tree.proof(0), &tree.root(), leaf));
+    }
+}
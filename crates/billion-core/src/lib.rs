@@ -0,0 +1,476 @@
+//! Pure grid math shared by the on-chain program, Rust clients, and the web frontend (via WASM).
+//! No anchor/solana dependencies and `no_std` so it compiles to wasm32-unknown-unknown.
+#![cfg_attr(not(test), no_std)]
+
+/// Side of a grid whose BlockMap doesn't record one, and of every grid created before sizes
+/// could vary
+pub const DEFAULT_GRID_SIZE: u16 = 100;
+/// Rings of a grid whose config doesn't pick another count
+pub const DEFAULT_RING_COUNT: u8 = 10;
+/// Smallest grid side: each of DEFAULT_RING_COUNT rings needs at least one band of blocks
+pub const MIN_GRID_SIZE: u16 = 20;
+/// Largest grid side
+pub const MAX_GRID_SIZE: u16 = 100;
+/// Blocks in a MAX_GRID_SIZE grid
+pub const MAX_TOTAL_BLOCKS: usize = (MAX_GRID_SIZE as usize) * (MAX_GRID_SIZE as usize);
+/// Largest width or height of a masked parcel's bounding box
+pub const MAX_MASK_SIDE: u8 = 16;
+/// BlockMap value of a block reserved by the admin (roads, plazas); never a parcel id
+pub const RESERVED_BLOCK: u16 = u16::MAX;
+
+/// Whether `grid_size` is a side a grid can be created with
+pub fn grid_size_is_valid(grid_size: u16) -> bool {
+    (MIN_GRID_SIZE..=MAX_GRID_SIZE).contains(&grid_size)
+}
+
+/// Number of blocks in a `grid_size` x `grid_size` grid
+pub fn total_blocks(grid_size: u16) -> usize {
+    (grid_size as usize) * (grid_size as usize)
+}
+
+/// Whether a `grid_size` grid can be split into `ring_count` rings, each at least one band of
+/// blocks wide
+pub fn ring_count_is_valid(ring_count: u8, grid_size: u16) -> bool {
+    ring_count >= 1 && ring_count as u16 <= grid_size / 2
+}
+
+/// Calculate which ring (1-`ring_count`) a block of a `grid_size` grid belongs to
+/// Ring 1 is outermost (corners), Ring `ring_count` is center
+/// Outer rings unlock first, center unlocks last
+pub fn get_ring(x: u8, y: u8, grid_size: u16, ring_count: u8) -> u8 {
+    // The middle block of an odd grid; on an even grid the block just past the middle, so the
+    // far edge there sits one block further out than the near one
+    let center = (grid_size / 2) as i16; // 50 on the default grid
+    let dx = ((x as i16) - center).unsigned_abs();
+    let dy = ((y as i16) - center).unsigned_abs();
+    let distance = dx.max(dy) as u32;
+
+    // Bands are center / ring_count wide, kept as a fraction so a width that isn't whole
+    // doesn't push the remainder into the outer ring: on the default grid Ring 10 = center
+    // (0-4), Ring 1 = corners (45-50). The far edge of an even grid would start a band of its
+    // own and is folded into Ring 1.
+    let ring_count = ring_count.max(1) as u32;
+    let band = distance * ring_count / (center.max(1) as u32);
+    (ring_count - band.min(ring_count - 1)) as u8
+}
+
+/// Calculate which ring is unlocked based on total burned, never past `ring_count`.
+/// `thresholds` must be non-decreasing, as initialize and update_config enforce.
+pub fn get_unlocked_ring(total_burned: u64, thresholds: &[u64], ring_count: u8) -> u8 {
+    // The thresholds met are a prefix, one ring each
+    let met = thresholds.partition_point(|&threshold| threshold <= total_burned);
+    (met.min(ring_count as usize) as u8).max(1) // Ring 1 always unlocked
+}
+
+/// Ring opened by an unlock schedule at `now`, where `unlock_times[i]` is when ring i + 1
+/// opens; never past `ring_count`
+pub fn get_scheduled_ring(now: i64, unlock_times: &[i64], ring_count: u8) -> u8 {
+    for (i, &unlock_time) in unlock_times.iter().enumerate().rev() {
+        if now >= unlock_time {
+            return ((i + 1) as u8).min(ring_count).max(1);
+        }
+    }
+    1
+}
+
+/// A rectangle of blocks, top-left corner plus dimensions
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Rect {
+    pub x: u8,
+    pub y: u8,
+    pub width: u8,
+    pub height: u8,
+}
+
+impl Rect {
+    pub const fn new(x: u8, y: u8, width: u8, height: u8) -> Self {
+        Self { x, y, width, height }
+    }
+
+    pub fn in_bounds(&self, grid_size: u16) -> bool {
+        rect_in_bounds(self.x, self.y, self.width, self.height, grid_size)
+    }
+
+    pub fn block_count(&self) -> u32 {
+        rect_block_count(self.width, self.height)
+    }
+
+    pub fn contains(&self, x: u8, y: u8) -> bool {
+        x >= self.x
+            && y >= self.y
+            && (x as u16) < (self.x as u16) + (self.width as u16)
+            && (y as u16) < (self.y as u16) + (self.height as u16)
+    }
+
+    /// Whether the two rectangles share at least one block
+    pub fn intersects(&self, other: &Rect) -> bool {
+        let (ax, ay, bx, by) = (self.x as u16, self.y as u16, other.x as u16, other.y as u16);
+        self.block_count() > 0
+            && other.block_count() > 0
+            && ax < bx + other.width as u16
+            && bx < ax + self.width as u16
+            && ay < by + other.height as u16
+            && by < ay + self.height as u16
+    }
+
+    /// Row-major iterator over the (x, y) coordinates covered by the rectangle
+    pub fn blocks(&self) -> impl Iterator<Item = (u8, u8)> {
+        let Rect { x, y, width, height } = *self;
+        (0..height).flat_map(move |dy| (0..width).map(move |dx| (x + dx, y + dy)))
+    }
+
+    /// Blocks outside the rectangle that share an edge with it, clipped to a `grid_size` grid:
+    /// the row above, the row below, then the left and right columns
+    pub fn edge_neighbors(&self, grid_size: u16) -> impl Iterator<Item = (u8, u8)> {
+        let (x, y, width, height) = (self.x as i16, self.y as i16, self.width as i16, self.height as i16);
+        let rows = [y - 1, y + height]
+            .into_iter()
+            .flat_map(move |ny| (x..x + width).map(move |nx| (nx, ny)));
+        let columns = [x - 1, x + width]
+            .into_iter()
+            .flat_map(move |nx| (y..y + height).map(move |ny| (nx, ny)));
+        rows.chain(columns)
+            .filter(move |&(nx, ny)| (0..grid_size as i16).contains(&nx) && (0..grid_size as i16).contains(&ny))
+            .map(|(nx, ny)| (nx as u8, ny as u8))
+    }
+
+    /// Row-major iterator over the blocks whose bit is set in `mask` (see `mask_contains`)
+    pub fn masked_blocks<'a>(&self, mask: &'a [u8]) -> impl Iterator<Item = (u8, u8)> + 'a {
+        let Rect { x, y, width, .. } = *self;
+        self.blocks()
+            .filter(move |&(bx, by)| mask_contains(mask, width, bx - x, by - y))
+    }
+}
+
+/// Bytes in the bitmask of a `width` x `height` bounding box
+pub fn mask_len(width: u8, height: u8) -> usize {
+    (rect_block_count(width, height) as usize).div_ceil(8)
+}
+
+/// Whether cell (dx, dy) of a `width`-wide bounding box is set in its bitmask. Cells are
+/// numbered row-major; cell i is bit i % 8 (least significant first) of byte i / 8.
+pub fn mask_contains(mask: &[u8], width: u8, dx: u8, dy: u8) -> bool {
+    let i = (dy as usize) * (width as usize) + (dx as usize);
+    mask.get(i / 8).is_some_and(|byte| byte & (1 << (i % 8)) != 0)
+}
+
+/// Number of cells set in a bitmask
+pub fn mask_block_count(mask: &[u8]) -> u32 {
+    mask.iter().map(|byte| byte.count_ones()).sum()
+}
+
+/// Whether `mask` describes a parcel inside a `width` x `height` bounding box: both sides
+/// at most MAX_MASK_SIDE, exactly `mask_len` bytes, no bits past the last cell, and at
+/// least one cell set
+pub fn mask_is_valid(mask: &[u8], width: u8, height: u8) -> bool {
+    if width == 0 || height == 0 || width > MAX_MASK_SIDE || height > MAX_MASK_SIDE {
+        return false;
+    }
+    if mask.len() != mask_len(width, height) {
+        return false;
+    }
+    let used_bits = rect_block_count(width, height) % 8;
+    let padding_clear = used_bits == 0 || mask[mask.len() - 1] >> used_bits == 0;
+    padding_clear && mask_block_count(mask) > 0
+}
+
+/// Whether a rectangle has non-zero dimensions and lies fully inside a `grid_size` grid
+pub fn rect_in_bounds(x: u8, y: u8, width: u8, height: u8, grid_size: u16) -> bool {
+    width > 0
+        && height > 0
+        && (x as u16) + (width as u16) <= grid_size
+        && (y as u16) + (height as u16) <= grid_size
+}
+
+/// Number of blocks covered by a rectangle
+pub fn rect_block_count(width: u8, height: u8) -> u32 {
+    (width as u32) * (height as u32)
+}
+
+/// Index of a block in the row-major BlockMap array of a `grid_size` grid
+pub fn block_index(x: u8, y: u8, grid_size: u16) -> usize {
+    (y as usize) * (grid_size as usize) + (x as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL: u16 = 50;
+
+    #[test]
+    fn test_get_ring_center() {
+        // Center area (distance 0-4 from center) = Ring 10 (unlocks last)
+        assert_eq!(get_ring(50, 50, DEFAULT_GRID_SIZE, DEFAULT_RING_COUNT), 10);
+        assert_eq!(get_ring(48, 52, DEFAULT_GRID_SIZE, DEFAULT_RING_COUNT), 10);
+        assert_eq!(get_ring(54, 46, DEFAULT_GRID_SIZE, DEFAULT_RING_COUNT), 10);
+        // Half the grid, half the band: distance 0-2
+        assert_eq!(get_ring(25, 25, SMALL, DEFAULT_RING_COUNT), 10);
+        assert_eq!(get_ring(27, 23, SMALL, DEFAULT_RING_COUNT), 10);
+        assert_eq!(get_ring(28, 25, SMALL, DEFAULT_RING_COUNT), 9);
+    }
+
+    #[test]
+    fn test_get_ring_edges() {
+        // Corners/edges (distance 45-50 from center) = Ring 1 (unlocks first)
+        for grid_size in [DEFAULT_GRID_SIZE, SMALL, MIN_GRID_SIZE] {
+            let last = (grid_size - 1) as u8;
+            assert_eq!(get_ring(0, 0, grid_size, DEFAULT_RING_COUNT), 1);
+            assert_eq!(get_ring(last, last, grid_size, DEFAULT_RING_COUNT), 1);
+            assert_eq!(get_ring(0, last, grid_size, DEFAULT_RING_COUNT), 1);
+            assert_eq!(get_ring(last, 0, grid_size, DEFAULT_RING_COUNT), 1);
+        }
+    }
+
+    #[test]
+    fn test_get_ring_boundaries() {
+        // Ring boundaries based on distance from center
+        assert_eq!(get_ring(50, 54, DEFAULT_GRID_SIZE, DEFAULT_RING_COUNT), 10); // distance 4 → Ring 10
+        assert_eq!(get_ring(50, 55, DEFAULT_GRID_SIZE, DEFAULT_RING_COUNT), 9);  // distance 5 → Ring 9
+        assert_eq!(get_ring(50, 59, DEFAULT_GRID_SIZE, DEFAULT_RING_COUNT), 9);  // distance 9 → Ring 9
+        assert_eq!(get_ring(50, 60, DEFAULT_GRID_SIZE, DEFAULT_RING_COUNT), 8);  // distance 10 → Ring 8
+        assert_eq!(get_ring(25, 27, SMALL, DEFAULT_RING_COUNT), 10); // distance 2 → Ring 10
+        assert_eq!(get_ring(25, 30, SMALL, DEFAULT_RING_COUNT), 8);  // distance 5 → Ring 8
+        assert_eq!(get_ring(25, 47, SMALL, DEFAULT_RING_COUNT), 2);  // distance 22 → Ring 2
+        assert_eq!(get_ring(25, 48, SMALL, DEFAULT_RING_COUNT), 1);  // distance 23 → Ring 1
+    }
+
+    #[test]
+    fn test_get_ring_covers_every_block() {
+        // Every block of every valid grid maps to a ring in 1..=ring_count and every ring is
+        // used, up to a 20-ring design
+        for grid_size in MIN_GRID_SIZE..=MAX_GRID_SIZE {
+            for ring_count in (1..=20).filter(|&n| ring_count_is_valid(n, grid_size)) {
+                let mut seen = [false; 20];
+                for y in 0..grid_size as u8 {
+                    for x in 0..grid_size as u8 {
+                        let ring = get_ring(x, y, grid_size, ring_count);
+                        assert!((1..=ring_count).contains(&ring));
+                        seen[(ring - 1) as usize] = true;
+                    }
+                }
+                assert!(seen[..ring_count as usize].iter().all(|&s| s), "grid {grid_size}, {ring_count} rings");
+            }
+        }
+    }
+
+    #[test]
+    fn test_get_ring_five_rings_on_small_grid() {
+        // Center 25, bands 5 wide, the far edge (distance 25) folded into Ring 1
+        let mut blocks_per_ring = [0u32; 5];
+        for y in 0..SMALL as u8 {
+            for x in 0..SMALL as u8 {
+                let distance = (x as i16 - 25).unsigned_abs().max((y as i16 - 25).unsigned_abs());
+                let expected = 5 - (distance / 5).min(4) as u8;
+                assert_eq!(get_ring(x, y, SMALL, 5), expected, "({x}, {y})");
+                blocks_per_ring[(expected - 1) as usize] += 1;
+            }
+        }
+        assert_eq!(blocks_per_ring, [979, 680, 480, 280, 81]);
+    }
+
+    #[test]
+    fn test_get_ring_rises_toward_center() {
+        // Stepping one block toward the center never lands in a ring that unlocks earlier
+        for grid_size in MIN_GRID_SIZE..=MAX_GRID_SIZE {
+            let center = (grid_size / 2) as u8;
+            for ring_count in (1..=20).filter(|&n| ring_count_is_valid(n, grid_size)) {
+                for y in 0..grid_size as u8 {
+                    for x in 0..grid_size as u8 {
+                        let (nx, ny) = (step_toward(x, center), step_toward(y, center));
+                        assert!(
+                            get_ring(nx, ny, grid_size, ring_count) >= get_ring(x, y, grid_size, ring_count),
+                            "grid {grid_size}, {ring_count} rings, ({x}, {y})"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn step_toward(from: u8, to: u8) -> u8 {
+        match from.cmp(&to) {
+            core::cmp::Ordering::Less => from + 1,
+            core::cmp::Ordering::Equal => from,
+            core::cmp::Ordering::Greater => from - 1,
+        }
+    }
+
+    #[test]
+    fn test_get_ring_odd_grid_is_symmetric() {
+        // An odd grid has a middle block, so opposite edges land in the same ring
+        for ring_count in [1, 5, 10] {
+            for offset in 0..=20u8 {
+                assert_eq!(get_ring(20 - offset, 20, 41, ring_count), get_ring(20 + offset, 20, 41, ring_count));
+            }
+        }
+        assert_eq!(get_ring(0, 0, 41, 10), 1);
+        assert_eq!(get_ring(20, 20, 41, 10), 10);
+    }
+
+    #[test]
+    fn test_ring_count_is_valid() {
+        assert!(ring_count_is_valid(DEFAULT_RING_COUNT, MIN_GRID_SIZE));
+        assert!(ring_count_is_valid(20, 40));
+        assert!(!ring_count_is_valid(20, 39));
+        assert!(!ring_count_is_valid(0, DEFAULT_GRID_SIZE));
+    }
+
+    #[test]
+    fn test_grid_size_is_valid() {
+        assert!(grid_size_is_valid(DEFAULT_GRID_SIZE));
+        assert!(grid_size_is_valid(SMALL));
+        assert!(grid_size_is_valid(MIN_GRID_SIZE));
+        assert!(!grid_size_is_valid(MIN_GRID_SIZE - 1));
+        assert!(!grid_size_is_valid(MAX_GRID_SIZE + 1));
+        assert!(!grid_size_is_valid(0));
+        assert_eq!(total_blocks(SMALL), 2500);
+        assert_eq!(total_blocks(MAX_GRID_SIZE), MAX_TOTAL_BLOCKS);
+    }
+
+    #[test]
+    fn test_get_unlocked_ring() {
+        let thresholds = vec![0, 100, 200, 300, 400, 500, 600, 700, 800, 900];
+        assert_eq!(get_unlocked_ring(0, &thresholds, DEFAULT_RING_COUNT), 1);
+        assert_eq!(get_unlocked_ring(99, &thresholds, DEFAULT_RING_COUNT), 1);
+        assert_eq!(get_unlocked_ring(100, &thresholds, DEFAULT_RING_COUNT), 2);
+        assert_eq!(get_unlocked_ring(500, &thresholds, DEFAULT_RING_COUNT), 6);
+        assert_eq!(get_unlocked_ring(1000, &thresholds, DEFAULT_RING_COUNT), 10);
+        // Thresholds past the grid's ring count never unlock a ring it doesn't have
+        assert_eq!(get_unlocked_ring(1000, &thresholds, 5), 5);
+        assert_eq!(get_unlocked_ring(300, &thresholds, 5), 4);
+    }
+
+    #[test]
+    fn test_get_unlocked_ring_equal_thresholds() {
+        // Rings sharing a threshold unlock together
+        let thresholds = [0, 100, 100, 100, 200];
+        assert_eq!(get_unlocked_ring(99, &thresholds, 5), 1);
+        assert_eq!(get_unlocked_ring(100, &thresholds, 5), 4);
+        assert_eq!(get_unlocked_ring(199, &thresholds, 5), 4);
+        assert_eq!(get_unlocked_ring(200, &thresholds, 5), 5);
+        assert_eq!(get_unlocked_ring(0, &[0; 10], DEFAULT_RING_COUNT), 10);
+    }
+
+    #[test]
+    fn test_get_unlocked_ring_empty_thresholds() {
+        // Ring 1 is always unlocked, even with no thresholds configured
+        assert_eq!(get_unlocked_ring(u64::MAX, &[], DEFAULT_RING_COUNT), 1);
+    }
+
+    #[test]
+    fn test_get_scheduled_ring() {
+        let unlock_times = [1_000, 2_000, 2_000, 5_000];
+        assert_eq!(get_scheduled_ring(0, &unlock_times, DEFAULT_RING_COUNT), 1);
+        assert_eq!(get_scheduled_ring(1_999, &unlock_times, DEFAULT_RING_COUNT), 1);
+        // Rings sharing a time open together
+        assert_eq!(get_scheduled_ring(2_000, &unlock_times, DEFAULT_RING_COUNT), 3);
+        // Rings past the schedule wait for the burns
+        assert_eq!(get_scheduled_ring(i64::MAX, &unlock_times, DEFAULT_RING_COUNT), 4);
+        assert_eq!(get_scheduled_ring(i64::MAX, &unlock_times, 2), 2);
+        assert_eq!(get_scheduled_ring(i64::MAX, &[], DEFAULT_RING_COUNT), 1);
+    }
+
+    #[test]
+    fn test_rect_in_bounds() {
+        assert!(rect_in_bounds(0, 0, 1, 1, DEFAULT_GRID_SIZE));
+        assert!(rect_in_bounds(99, 99, 1, 1, DEFAULT_GRID_SIZE));
+        assert!(rect_in_bounds(0, 0, 100, 100, DEFAULT_GRID_SIZE));
+        assert!(!rect_in_bounds(99, 50, 2, 1, DEFAULT_GRID_SIZE));
+        assert!(!rect_in_bounds(50, 99, 1, 2, DEFAULT_GRID_SIZE));
+        assert!(!rect_in_bounds(50, 50, 0, 1, DEFAULT_GRID_SIZE));
+        assert!(!rect_in_bounds(50, 50, 1, 0, DEFAULT_GRID_SIZE));
+        assert!(!rect_in_bounds(255, 255, 255, 255, DEFAULT_GRID_SIZE));
+        assert!(rect_in_bounds(0, 0, 50, 50, SMALL));
+        assert!(!rect_in_bounds(49, 49, 2, 1, SMALL));
+        assert!(!rect_in_bounds(50, 0, 1, 1, SMALL));
+    }
+
+    #[test]
+    fn test_rect_block_count() {
+        assert_eq!(rect_block_count(1, 1), 1);
+        assert_eq!(rect_block_count(3, 2), 6);
+        assert_eq!(rect_block_count(255, 255), 65025);
+    }
+
+    #[test]
+    fn test_rect_intersects() {
+        let district = Rect::new(10, 10, 5, 5);
+        assert!(district.intersects(&Rect::new(10, 10, 1, 1)));
+        assert!(district.intersects(&Rect::new(14, 14, 3, 3)));
+        assert!(district.intersects(&Rect::new(0, 0, 11, 11)));
+        assert!(!district.intersects(&Rect::new(15, 10, 1, 1)));
+        assert!(!district.intersects(&Rect::new(9, 9, 1, 1)));
+        assert!(!district.intersects(&Rect::new(10, 15, 5, 1)));
+        assert!(!district.intersects(&Rect::new(12, 12, 0, 0)));
+    }
+
+    #[test]
+    fn test_rect_edge_neighbors() {
+        let neighbors: Vec<_> = Rect::new(10, 10, 2, 1).edge_neighbors(DEFAULT_GRID_SIZE).collect();
+        assert_eq!(neighbors, vec![(10, 9), (11, 9), (10, 11), (11, 11), (9, 10), (12, 10)]);
+
+        // Corners are excluded and the grid edge clips the outside rows and columns
+        let neighbors: Vec<_> = Rect::new(0, 0, 1, 1).edge_neighbors(DEFAULT_GRID_SIZE).collect();
+        assert_eq!(neighbors, vec![(0, 1), (1, 0)]);
+        assert_eq!(Rect::new(0, 0, 100, 100).edge_neighbors(DEFAULT_GRID_SIZE).count(), 0);
+        let neighbors: Vec<_> = Rect::new(49, 49, 1, 1).edge_neighbors(SMALL).collect();
+        assert_eq!(neighbors, vec![(49, 48), (48, 49)]);
+    }
+
+    #[test]
+    fn test_rect_helpers() {
+        let rect = Rect::new(95, 0, 2, 3);
+        assert!(rect.in_bounds(DEFAULT_GRID_SIZE));
+        assert!(!rect.in_bounds(SMALL));
+        assert_eq!(rect.block_count(), 6);
+        assert!(rect.contains(95, 0));
+        assert!(rect.contains(96, 2));
+        assert!(!rect.contains(97, 0));
+        assert!(!rect.contains(95, 3));
+        let blocks: Vec<_> = rect.blocks().collect();
+        assert_eq!(blocks, vec![(95, 0), (96, 0), (95, 1), (96, 1), (95, 2), (96, 2)]);
+        assert!(!Rect::new(99, 0, 2, 1).in_bounds(DEFAULT_GRID_SIZE));
+    }
+
+    #[test]
+    fn test_block_index() {
+        assert_eq!(block_index(0, 0, DEFAULT_GRID_SIZE), 0);
+        assert_eq!(block_index(4, 4, DEFAULT_GRID_SIZE), 404);
+        assert_eq!(block_index(99, 99, DEFAULT_GRID_SIZE), MAX_TOTAL_BLOCKS - 1);
+        assert_eq!(block_index(4, 4, SMALL), 204);
+        assert_eq!(block_index(49, 49, SMALL), total_blocks(SMALL) - 1);
+    }
+
+    #[test]
+    fn test_mask_l_shape() {
+        // 3x2 box: X . .
+        //          X X X
+        let mask = [0b0011_1001];
+        assert!(mask_is_valid(&mask, 3, 2));
+        assert_eq!(mask_block_count(&mask), 4);
+        assert!(mask_contains(&mask, 3, 0, 0));
+        assert!(!mask_contains(&mask, 3, 1, 0));
+        assert!(mask_contains(&mask, 3, 2, 1));
+        let blocks: Vec<_> = Rect::new(10, 20, 3, 2).masked_blocks(&mask).collect();
+        assert_eq!(blocks, vec![(10, 20), (10, 21), (11, 21), (12, 21)]);
+    }
+
+    #[test]
+    fn test_mask_is_valid_rejects_bad_masks() {
+        assert_eq!(mask_len(3, 3), 2);
+        assert_eq!(mask_len(16, 16), 32);
+        // Empty
+        assert!(!mask_is_valid(&[0, 0], 3, 3));
+        // Wrong length
+        assert!(!mask_is_valid(&[1], 3, 3));
+        assert!(!mask_is_valid(&[1, 0, 0], 3, 3));
+        // Bit past the 9th cell
+        assert!(!mask_is_valid(&[1, 0b10], 3, 3));
+        assert!(mask_is_valid(&[1, 0b01], 3, 3));
+        // Box too large
+        assert!(!mask_is_valid(&[0xff; 34], 17, 16));
+        assert!(mask_is_valid(&[0xff; 32], 16, 16));
+        assert!(!mask_is_valid(&[], 0, 1));
+    }
+}
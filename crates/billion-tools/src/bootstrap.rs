@@ -0,0 +1,238 @@
+//! Full localnet grid setup: mint, funded ATA, BlockMap, GridConfig, Core collection,
+//! and the collection registered via update_config.
+//!
+//! Mint, BlockMap, and collection keypairs are derived from the authority keypair, so a fresh
+//! validator always ends up with the same addresses for the same wallet.
+
+use anchor_spl::associated_token::spl_associated_token_account;
+use anchor_spl::token_2022::spl_token_2022;
+use billion::state::BlockMap;
+use billion_client::{self as client, GridAccounts, InitializeArgs};
+use serde::Serialize;
+use solana_sdk::{
+    hash::hashv,
+    instruction::Instruction,
+    program_pack::Pack,
+    signature::{keypair_from_seed, Keypair, Signer},
+    system_instruction,
+    transaction::Transaction,
+};
+
+use crate::rpc::{Result, RpcClient};
+
+pub struct BootstrapOptions {
+    /// Grid to set up; each grid gets its own mint, BlockMap, and collection
+    pub grid_id: u64,
+    pub price_per_block: u64,
+    pub land_owners_reward_share_bps: u16,
+    pub uri_base: String,
+    pub decimals: u8,
+    /// Tokens minted to the authority's ATA, in base units
+    pub mint_amount: u64,
+}
+
+impl Default for BootstrapOptions {
+    fn default() -> Self {
+        Self {
+            grid_id: 0,
+            price_per_block: 1_000_000,
+            land_owners_reward_share_bps: 2000,
+            uri_base: "http://localhost:3000/parcel/".to_string(),
+            decimals: 6,
+            mint_amount: 1_000_000_000_000,
+        }
+    }
+}
+
+/// Addresses created by the bootstrap, printed as JSON
+#[derive(Debug, Serialize)]
+pub struct BootstrapOutput {
+    pub program_id: String,
+    pub authority: String,
+    pub grid_id: u64,
+    pub grid_config: String,
+    pub block_map: String,
+    pub token_mint: String,
+    pub authority_token_account: String,
+    pub collection: String,
+    pub land_buy_reward_pool: String,
+}
+
+/// Ring thresholds spread evenly so every ring can be unlocked with the default mint amount
+fn default_ring_thresholds(price_per_block: u64) -> Vec<u64> {
+    (0..billion::constants::RING_COUNT as u64)
+        .map(|ring| ring * 100 * price_per_block)
+        .collect()
+}
+
+/// Grid 0 keeps the labels from before there could be several grids, so its addresses don't move
+fn derived_keypair(authority: &Keypair, grid_id: u64, label: &str) -> Keypair {
+    let label = match grid_id {
+        0 => label.to_string(),
+        grid_id => format!("{}-{}", label, grid_id),
+    };
+    let seed = hashv(&[&authority.to_bytes(), b"billion-tools", label.as_bytes()]);
+    keypair_from_seed(seed.as_ref()).expect("32-byte seed")
+}
+
+fn send(rpc: &RpcClient, authority: &Keypair, instructions: &[Instruction], signers: &[&Keypair]) -> Result<()> {
+    let mut all_signers = vec![authority];
+    all_signers.extend_from_slice(signers);
+    let tx = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&authority.pubkey()),
+        &all_signers,
+        rpc.get_latest_blockhash()?,
+    );
+    rpc.send_and_confirm(&tx).map(|_| ())
+}
+
+pub fn bootstrap(rpc: &RpcClient, authority: &Keypair, options: &BootstrapOptions) -> Result<BootstrapOutput> {
+    let mint = derived_keypair(authority, options.grid_id, "mint");
+    let block_map = derived_keypair(authority, options.grid_id, "block_map");
+    let collection = derived_keypair(authority, options.grid_id, "collection");
+    let grid = planned_accounts(authority, options.grid_id);
+
+    if rpc.account_exists(&grid.grid_config)? {
+        return Err(format!(
+            "grid config {} already exists; restart the validator with --reset",
+            grid.grid_config
+        ));
+    }
+
+    // Plenty of SOL for rent (the BlockMap alone needs ~0.14 SOL) and fees
+    if rpc.get_balance(&authority.pubkey())? < 10_000_000_000 {
+        eprintln!("airdropping 100 SOL to {}", authority.pubkey());
+        rpc.request_airdrop(&authority.pubkey(), 100_000_000_000)?;
+    }
+
+    eprintln!("1/4 creating Token-2022 mint {}", mint.pubkey());
+    let authority_token_account = client::find_token_account(&authority.pubkey(), &mint.pubkey());
+    let mint_space = spl_token_2022::state::Mint::LEN;
+    send(
+        rpc,
+        authority,
+        &[
+            system_instruction::create_account(
+                &authority.pubkey(),
+                &mint.pubkey(),
+                rpc.get_minimum_balance_for_rent_exemption(mint_space)?,
+                mint_space as u64,
+                &spl_token_2022::id(),
+            ),
+            spl_token_2022::instruction::initialize_mint2(
+                &spl_token_2022::id(),
+                &mint.pubkey(),
+                &authority.pubkey(),
+                None,
+                options.decimals,
+            )
+            .map_err(|e| e.to_string())?,
+            spl_associated_token_account::instruction::create_associated_token_account(
+                &authority.pubkey(),
+                &authority.pubkey(),
+                &mint.pubkey(),
+                &spl_token_2022::id(),
+            ),
+            spl_token_2022::instruction::mint_to(
+                &spl_token_2022::id(),
+                &mint.pubkey(),
+                &authority_token_account,
+                &authority.pubkey(),
+                &[],
+                options.mint_amount,
+            )
+            .map_err(|e| e.to_string())?,
+        ],
+        &[&mint],
+    )?;
+
+    eprintln!("2/4 creating BlockMap {}", block_map.pubkey());
+    let block_map_lamports = rpc.get_minimum_balance_for_rent_exemption(BlockMap::SIZE)?;
+    send(
+        rpc,
+        authority,
+        &client::create_block_map(&authority.pubkey(), &block_map.pubkey(), block_map_lamports),
+        &[&block_map],
+    )?;
+
+    eprintln!("3/4 initializing grid config {}", grid.grid_config);
+    let initialize = client::initialize(
+        &grid,
+        InitializeArgs {
+            price_per_block: options.price_per_block,
+            ring_thresholds: default_ring_thresholds(options.price_per_block),
+            uri_base: options.uri_base.clone(),
+            land_owners_reward_share_bps: options.land_owners_reward_share_bps,
+            max_parcel_blocks: 0,
+        },
+    );
+    send(rpc, authority, &[initialize], &[])?;
+
+    eprintln!("4/4 creating Core collection {}", collection.pubkey());
+    let create_collection = client::create_collection(
+        grid.grid_id,
+        &authority.pubkey(),
+        &collection.pubkey(),
+        "Localnet Parcels".to_string(),
+        format!("{}collection.json", options.uri_base),
+    );
+    send(rpc, authority, &[create_collection], &[&collection])?;
+
+    Ok(BootstrapOutput {
+        program_id: billion::ID.to_string(),
+        authority: authority.pubkey().to_string(),
+        grid_id: grid.grid_id,
+        grid_config: grid.grid_config.to_string(),
+        block_map: grid.block_map.to_string(),
+        token_mint: grid.token_mint.to_string(),
+        authority_token_account: authority_token_account.to_string(),
+        collection: grid.collection.to_string(),
+        land_buy_reward_pool: grid.land_buy_reward_pool.to_string(),
+    })
+}
+
+/// Addresses `bootstrap` would create for `authority`'s grid `grid_id`, without touching the network
+pub fn planned_accounts(authority: &Keypair, grid_id: u64) -> GridAccounts {
+    GridAccounts::new(
+        grid_id,
+        authority.pubkey(),
+        derived_keypair(authority, grid_id, "block_map").pubkey(),
+        derived_keypair(authority, grid_id, "mint").pubkey(),
+        derived_keypair(authority, grid_id, "collection").pubkey(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_derived_addresses_are_deterministic() {
+        let authority = Keypair::new();
+        assert_eq!(planned_accounts(&authority, 0), planned_accounts(&authority, 0));
+
+        let other = Keypair::new();
+        assert_ne!(planned_accounts(&authority, 0).token_mint, planned_accounts(&other, 0).token_mint);
+        // Grid config is a PDA of the grid id, independent of the wallet
+        assert_eq!(planned_accounts(&authority, 0).grid_config, planned_accounts(&other, 0).grid_config);
+    }
+
+    #[test]
+    fn test_each_grid_gets_its_own_accounts() {
+        let authority = Keypair::new();
+        let (first, second) = (planned_accounts(&authority, 0), planned_accounts(&authority, 1));
+        assert_ne!(first.grid_config, second.grid_config);
+        assert_ne!(first.block_map, second.block_map);
+        assert_ne!(first.token_mint, second.token_mint);
+        assert_ne!(first.collection, second.collection);
+    }
+
+    #[test]
+    fn test_default_ring_thresholds() {
+        let thresholds = default_ring_thresholds(1_000_000);
+        assert_eq!(thresholds.len(), 10);
+        assert_eq!(thresholds[0], 0);
+        assert!(thresholds.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
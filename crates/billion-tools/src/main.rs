@@ -0,0 +1,108 @@
+//! Developer tooling for the billion program.
+//!
+//! ```text
+//! cargo run -p billion-tools -- bootstrap [--url http://127.0.0.1:8899] [--keypair ~/.config/solana/id.json]
+//!     [--grid-id 0] [--price-per-block 1000000] [--reward-share-bps 2000] [--uri-base URL] [--mint-amount N]
+//! ```
+//!
+//! `bootstrap` sets up a complete grid on a local validator (the program and Metaplex Core must
+//! already be deployed, e.g. via `anchor localnet`) and prints the resulting addresses as JSON.
+
+mod bootstrap;
+mod rpc;
+
+use std::process::ExitCode;
+
+use solana_sdk::signature::read_keypair_file;
+
+use crate::bootstrap::{bootstrap, BootstrapOptions};
+use crate::rpc::RpcClient;
+
+const DEFAULT_URL: &str = "http://127.0.0.1:8899";
+
+struct Args {
+    url: String,
+    keypair: String,
+    options: BootstrapOptions,
+}
+
+fn default_keypair_path() -> String {
+    let home = std::env::var("HOME").unwrap_or_else(|_| ".".to_string());
+    format!("{}/.config/solana/id.json", home)
+}
+
+fn parse_args(args: &[String]) -> Result<Args, String> {
+    let mut parsed = Args {
+        url: DEFAULT_URL.to_string(),
+        keypair: default_keypair_path(),
+        options: BootstrapOptions::default(),
+    };
+
+    let mut iter = args.iter();
+    while let Some(flag) = iter.next() {
+        let value = iter.next().ok_or_else(|| format!("missing value for {}", flag))?;
+        let number = || value.parse::<u64>().map_err(|_| format!("invalid number for {}: {}", flag, value));
+        match flag.as_str() {
+            "--url" => parsed.url = value.clone(),
+            "--keypair" => parsed.keypair = value.clone(),
+            "--grid-id" => parsed.options.grid_id = number()?,
+            "--price-per-block" => parsed.options.price_per_block = number()?,
+            "--reward-share-bps" => {
+                parsed.options.land_owners_reward_share_bps =
+                    u16::try_from(number()?).map_err(|_| format!("{} out of range", flag))?
+            }
+            "--uri-base" => parsed.options.uri_base = value.clone(),
+            "--mint-amount" => parsed.options.mint_amount = number()?,
+            _ => return Err(format!("unknown flag {}", flag)),
+        }
+    }
+    Ok(parsed)
+}
+
+fn run(argv: &[String]) -> Result<(), String> {
+    match argv.first().map(String::as_str) {
+        Some("bootstrap") => {
+            let args = parse_args(&argv[1..])?;
+            let authority = read_keypair_file(&args.keypair).map_err(|e| format!("{}: {}", args.keypair, e))?;
+            let rpc = RpcClient::new(&args.url)?;
+            let output = bootstrap(&rpc, &authority, &args.options)?;
+            println!("{}", serde_json::to_string_pretty(&output).map_err(|e| e.to_string())?);
+            Ok(())
+        }
+        _ => Err("usage: billion-tools bootstrap [--url URL] [--keypair PATH] [options]".to_string()),
+    }
+}
+
+fn main() -> ExitCode {
+    let argv: Vec<String> = std::env::args().skip(1).collect();
+    match run(&argv) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let parsed = parse_args(&args(&["--url", "http://localhost:9000", "--price-per-block", "5"])).unwrap();
+        assert_eq!(parsed.url, "http://localhost:9000");
+        assert_eq!(parsed.options.price_per_block, 5);
+        assert_eq!(parsed.options.land_owners_reward_share_bps, 2000);
+        assert_eq!(parsed.options.grid_id, 0);
+        assert_eq!(parse_args(&args(&["--grid-id", "3"])).unwrap().options.grid_id, 3);
+
+        assert!(parse_args(&args(&["--url"])).is_err());
+        assert!(parse_args(&args(&["--reward-share-bps", "70000"])).is_err());
+        assert!(parse_args(&args(&["--bogus", "1"])).is_err());
+    }
+}
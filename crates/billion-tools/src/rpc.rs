@@ -0,0 +1,160 @@
+//! Minimal blocking JSON-RPC client over plain HTTP.
+//!
+//! Only what the bootstrap needs, and only `http://` endpoints (local validators, surfnet).
+
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use serde_json::{json, Value};
+use solana_sdk::{hash::Hash, pubkey::Pubkey, signature::Signature, transaction::Transaction};
+
+pub type Result<T> = std::result::Result<T, String>;
+
+pub struct RpcClient {
+    host: String,
+    port: u16,
+    path: String,
+}
+
+impl RpcClient {
+    pub fn new(url: &str) -> Result<Self> {
+        let (host, port, path) = parse_http_url(url)?;
+        Ok(Self { host, port, path })
+    }
+
+    fn call(&self, method: &str, params: Value) -> Result<Value> {
+        let body = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params }).to_string();
+        // HTTP/1.0 so the response is never chunked and the server closes the connection
+        let request = format!(
+            "POST {} HTTP/1.0\r\nHost: {}:{}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+            self.path,
+            self.host,
+            self.port,
+            body.len(),
+            body
+        );
+
+        let mut stream = TcpStream::connect((self.host.as_str(), self.port))
+            .map_err(|e| format!("connect to {}:{}: {}", self.host, self.port, e))?;
+        stream.write_all(request.as_bytes()).map_err(|e| e.to_string())?;
+        let mut response = String::new();
+        stream.read_to_string(&mut response).map_err(|e| e.to_string())?;
+
+        let (_, body) = response
+            .split_once("\r\n\r\n")
+            .ok_or_else(|| format!("malformed HTTP response to {}", method))?;
+        let mut value: Value = serde_json::from_str(body).map_err(|e| format!("{}: {}", method, e))?;
+        if let Some(error) = value.get("error") {
+            return Err(format!("{}: {}", method, error));
+        }
+        Ok(value["result"].take())
+    }
+
+    pub fn get_latest_blockhash(&self) -> Result<Hash> {
+        let result = self.call("getLatestBlockhash", json!([{ "commitment": "confirmed" }]))?;
+        result["value"]["blockhash"]
+            .as_str()
+            .ok_or("getLatestBlockhash: missing blockhash")?
+            .parse()
+            .map_err(|e| format!("getLatestBlockhash: {:?}", e))
+    }
+
+    pub fn get_minimum_balance_for_rent_exemption(&self, space: usize) -> Result<u64> {
+        self.call("getMinimumBalanceForRentExemption", json!([space]))?
+            .as_u64()
+            .ok_or_else(|| "getMinimumBalanceForRentExemption: not a number".to_string())
+    }
+
+    pub fn get_balance(&self, address: &Pubkey) -> Result<u64> {
+        self.call("getBalance", json!([address.to_string(), { "commitment": "confirmed" }]))?["value"]
+            .as_u64()
+            .ok_or_else(|| "getBalance: not a number".to_string())
+    }
+
+    /// Whether an account exists at `address`
+    pub fn account_exists(&self, address: &Pubkey) -> Result<bool> {
+        let result = self.call(
+            "getAccountInfo",
+            json!([address.to_string(), { "encoding": "base64", "commitment": "confirmed" }]),
+        )?;
+        Ok(!result["value"].is_null())
+    }
+
+    pub fn request_airdrop(&self, address: &Pubkey, lamports: u64) -> Result<()> {
+        let signature = self.call("requestAirdrop", json!([address.to_string(), lamports]))?;
+        let signature = signature
+            .as_str()
+            .ok_or("requestAirdrop: missing signature")?
+            .parse()
+            .map_err(|e| format!("requestAirdrop: {:?}", e))?;
+        self.confirm(&signature)
+    }
+
+    /// Send a signed transaction and wait until it is confirmed
+    pub fn send_and_confirm(&self, tx: &Transaction) -> Result<Signature> {
+        let wire = bincode::serialize(tx).map_err(|e| e.to_string())?;
+        self.call(
+            "sendTransaction",
+            json!([BASE64.encode(wire), { "encoding": "base64", "preflightCommitment": "confirmed" }]),
+        )?;
+        let signature = tx.signatures[0];
+        self.confirm(&signature)?;
+        Ok(signature)
+    }
+
+    fn confirm(&self, signature: &Signature) -> Result<()> {
+        let deadline = Instant::now() + Duration::from_secs(30);
+        while Instant::now() < deadline {
+            let result = self.call("getSignatureStatuses", json!([[signature.to_string()]]))?;
+            let status = &result["value"][0];
+            if !status.is_null() {
+                if !status["err"].is_null() {
+                    return Err(format!("transaction {} failed: {}", signature, status["err"]));
+                }
+                if matches!(status["confirmationStatus"].as_str(), Some("confirmed" | "finalized")) {
+                    return Ok(());
+                }
+            }
+            sleep(Duration::from_millis(250));
+        }
+        Err(format!("transaction {} not confirmed after 30s", signature))
+    }
+}
+
+/// Split `http://host[:port][/path]` into its parts; port defaults to 80
+fn parse_http_url(url: &str) -> Result<(String, u16, String)> {
+    let rest = url
+        .strip_prefix("http://")
+        .ok_or_else(|| format!("only http:// RPC endpoints are supported, got {}", url))?;
+    let (authority, path) = match rest.find('/') {
+        Some(i) => (&rest[..i], &rest[i..]),
+        None => (rest, "/"),
+    };
+    let (host, port) = match authority.rsplit_once(':') {
+        Some((host, port)) => (host, port.parse().map_err(|_| format!("invalid port in {}", url))?),
+        None => (authority, 80),
+    };
+    Ok((host.to_string(), port, path.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_http_url() {
+        assert_eq!(
+            parse_http_url("http://127.0.0.1:8899").unwrap(),
+            ("127.0.0.1".to_string(), 8899, "/".to_string())
+        );
+        assert_eq!(
+            parse_http_url("http://localhost/rpc").unwrap(),
+            ("localhost".to_string(), 80, "/rpc".to_string())
+        );
+        assert!(parse_http_url("https://api.devnet.solana.com").is_err());
+        assert!(parse_http_url("http://localhost:port").is_err());
+    }
+}
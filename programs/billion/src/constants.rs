@@ -0,0 +1,309 @@
+use anchor_lang::prelude::*;
+
+// Constants emitted into the IDL so client SDKs don't hardcode them
+
+#[constant]
+pub const GRID_CONFIG_SEED: &[u8] = b"grid_config";
+
+#[constant]
+pub const PARCEL_INFO_SEED: &[u8] = b"parcel";
+
+#[constant]
+pub const LAND_BUY_REWARD_POOL_SEED: &[u8] = b"land_buy_reward_pool";
+
+#[constant]
+pub const ASSET_SEED: &[u8] = b"asset";
+
+#[constant]
+pub const REFERRER_SEED: &[u8] = b"referrer";
+
+#[constant]
+pub const REFERRAL_VAULT_SEED: &[u8] = b"referral_vault";
+
+#[constant]
+pub const STAKE_SEED: &[u8] = b"stake";
+
+#[constant]
+pub const EMISSIONS_VAULT_SEED: &[u8] = b"emissions_vault";
+
+#[constant]
+pub const HARBERGER_DISTRICT_SEED: &[u8] = b"harberger_district";
+
+#[constant]
+pub const VALUATION_SEED: &[u8] = b"valuation";
+
+#[constant]
+pub const QUEST_SEED: &[u8] = b"quest";
+
+#[constant]
+pub const QUEST_CLAIMS_SEED: &[u8] = b"quest_claims";
+
+#[constant]
+pub const QUEST_VAULT_SEED: &[u8] = b"quest_vault";
+
+#[constant]
+pub const DISTRIBUTION_VAULT_SEED: &[u8] = b"distribution_vault";
+
+#[constant]
+pub const EPOCH_ARCHIVE_SEED: &[u8] = b"epoch_archive";
+
+#[constant]
+pub const HOOK_CONFIG_SEED: &[u8] = b"hook_config";
+
+#[constant]
+pub const FRACTION_SEED: &[u8] = b"fraction";
+
+#[constant]
+pub const FRACTION_POSITION_SEED: &[u8] = b"fraction_position";
+
+#[constant]
+pub const FRACTION_VAULT_SEED: &[u8] = b"fraction_vault";
+
+#[constant]
+pub const FRACTION_ESCROW_SEED: &[u8] = b"fraction_escrow";
+
+#[constant]
+pub const ATTESTATION_SEED: &[u8] = b"attestation";
+
+#[constant]
+pub const CHARITY_REGISTRY_SEED: &[u8] = b"charity_registry";
+
+#[constant]
+pub const CLAIMER_STATS_SEED: &[u8] = b"claimer_stats";
+
+#[constant]
+pub const NAME_RECORD_SEED: &[u8] = b"name_record";
+
+#[constant]
+pub const VOUCHER_SEED: &[u8] = b"voucher";
+
+#[constant]
+pub const RAFFLE_SEED: &[u8] = b"raffle";
+
+#[constant]
+pub const CLAIM_NONCE_SEED: &[u8] = b"claim_nonce";
+
+#[constant]
+pub const SOL_TREASURY_SEED: &[u8] = b"sol_treasury";
+
+#[constant]
+pub const RING_PRICING_SEED: &[u8] = b"ring_pricing";
+
+#[constant]
+pub const ALLOWLIST_SEED: &[u8] = b"allowlist";
+
+#[constant]
+pub const PARCEL_ID_POOL_SEED: &[u8] = b"parcel_id_pool";
+
+#[constant]
+pub const PARCEL_MASK_SEED: &[u8] = b"parcel_mask";
+
+#[constant]
+pub const ADMIN_MINT_ALLOWANCE_SEED: &[u8] = b"admin_mint_allowance";
+
+#[constant]
+pub const REWARD_EXCLUSIONS_SEED: &[u8] = b"reward_exclusions";
+
+#[constant]
+pub const REWARD_DELEGATE_SEED: &[u8] = b"reward_delegate";
+
+#[constant]
+pub const SOL_REWARDS_SEED: &[u8] = b"sol_rewards";
+
+#[constant]
+pub const SOL_REWARD_VAULT_SEED: &[u8] = b"sol_reward_vault";
+
+#[constant]
+pub const SOL_REWARD_CHECKPOINT_SEED: &[u8] = b"sol_reward_checkpoint";
+
+#[constant]
+pub const REWARD_DUST_SEED: &[u8] = b"reward_dust";
+
+#[constant]
+pub const RING_REWARD_WEIGHTS_SEED: &[u8] = b"ring_reward_weights";
+
+#[constant]
+pub const ADMIN_ROLES_SEED: &[u8] = b"admin_roles";
+
+#[constant]
+pub const CONFIG_TIMELOCK_SEED: &[u8] = b"config_timelock";
+
+#[constant]
+pub const TOKEN_TREASURY_SEED: &[u8] = b"token_treasury";
+
+#[constant]
+pub const CONFIG_FREEZE_SEED: &[u8] = b"config_freeze";
+
+#[constant]
+pub const CLAIMS_PAUSE_SEED: &[u8] = b"claims_pause";
+
+#[constant]
+pub const RING_OCCUPANCY_SEED: &[u8] = b"ring_occupancy";
+
+#[constant]
+pub const MAP_DIGEST_SEED: &[u8] = b"map_digest";
+
+#[constant]
+pub const RING_UNLOCK_SCHEDULE_SEED: &[u8] = b"ring_unlock_schedule";
+
+#[constant]
+pub const RENAME_FEE_SEED: &[u8] = b"rename_fee";
+
+#[constant]
+pub const PARCEL_CONTENT_SEED: &[u8] = b"parcel_content";
+
+#[constant]
+pub const PARCEL_PIXELS_SEED: &[u8] = b"pixels";
+
+#[constant]
+pub const REFERRAL_SHARE_SEED: &[u8] = b"referral_share";
+
+/// Width and height of the grid in blocks, unless create_block_map was given another size
+#[constant]
+pub const GRID_WIDTH: u16 = billion_core::DEFAULT_GRID_SIZE;
+
+/// Most unlock rings a grid can have, and the length of the per-ring arrays; a grid's own
+/// count is its number of ring thresholds
+#[constant]
+pub const RING_COUNT: u8 = 10;
+
+/// Scale applied to land_buy_rewards_per_block for precision
+#[constant]
+pub const REWARD_SCALE: u128 = 1_000_000_000;
+
+/// Reward weight of an unweighted block, in the tenths ParcelInfo.reward_weight is stored in
+#[constant]
+pub const REWARD_WEIGHT_UNIT: u8 = 10;
+
+/// Largest parcel the largest grid can hold, in blocks
+#[constant]
+pub const MAX_PARCEL_BLOCKS: u32 = billion_core::MAX_TOTAL_BLOCKS as u32;
+
+/// Highest parcel id; the next one up marks reserved blocks in the BlockMap
+#[constant]
+pub const MAX_PARCEL_ID: u16 = billion_core::RESERVED_BLOCK - 1;
+
+/// Maximum length of GridConfig.uri_base
+#[constant]
+pub const MAX_URI_BASE_LEN: u32 = 128;
+
+/// Maximum length of a parcel NFT name
+#[constant]
+pub const MAX_PARCEL_NAME_LEN: u32 = 32;
+
+/// Minimum length of a registered parcel name
+#[constant]
+pub const MIN_PARCEL_NAME_LEN: u32 = 3;
+
+/// Maximum length of a parcel NFT uri
+#[constant]
+pub const MAX_PARCEL_URI_LEN: u32 = 200;
+
+/// Layout version carried as the first field of every event
+#[constant]
+pub const EVENT_SCHEMA_VERSION: u8 = 1;
+
+/// Most remaining_accounts passed through to the on-claim hook program
+#[constant]
+pub const MAX_HOOK_ACCOUNTS: u8 = 8;
+
+/// Decimals of every fraction share mint
+#[constant]
+pub const FRACTION_SHARE_DECIMALS: u8 = 0;
+
+/// Largest share of lapsed rewards paid to the expire_parcel_rewards cranker
+#[constant]
+pub const MAX_EXPIRY_BOUNTY_BPS: u16 = 1_000;
+
+/// Largest share of a claim paid straight to the referrer's token account. Any wallet can be
+/// passed as the referrer, so this is also the largest discount a claimer can give themselves.
+#[constant]
+pub const MAX_REFERRAL_SHARE_BPS: u16 = 1_000;
+
+/// Neighboring parcels a parcel needs before it earns the adjacency bonus
+#[constant]
+pub const ADJACENCY_BONUS_MIN_NEIGHBORS: u8 = 3;
+
+/// Largest adjacency bonus, in basis points of the rewards claimed
+#[constant]
+pub const MAX_ADJACENCY_BONUS_BPS: u16 = 10_000;
+
+/// How long after a ring unlocks its cells can only be claimed by owners of an adjacent parcel
+#[constant]
+pub const EXPANSION_PRIORITY_WINDOW_SECS: i64 = 3_600;
+
+/// Age after which anyone may close an ownership attestation and refund its rent
+#[constant]
+pub const ATTESTATION_TTL_SECS: i64 = 86_400;
+
+/// Maximum number of approved charity token accounts
+#[constant]
+pub const MAX_CHARITIES: u8 = 8;
+
+/// How long a raffle prize stays claimable after it is drawn
+#[constant]
+pub const RAFFLE_CLAIM_WINDOW_SECS: i64 = 86_400;
+
+/// Random blocks run_raffle tries before giving up on finding an eligible one
+#[constant]
+pub const MAX_RAFFLE_DRAWS: u8 = 32;
+
+/// Most rectangles claim_parcels_batch accepts in one instruction
+#[constant]
+pub const MAX_BATCH_RECTS: u8 = 4;
+
+/// Most parcels admin_mint_batch seeds in one instruction. Each needs an asset signature and
+/// two accounts, so five already come close to the transaction size limit.
+#[constant]
+pub const MAX_ADMIN_MINT_BATCH: u8 = 5;
+
+/// Most parcels claim_land_buy_rewards_batch settles in one instruction. Each adds two
+/// accounts, so a full batch needs an address lookup table.
+#[constant]
+pub const MAX_REWARD_CLAIM_BATCH: u8 = 30;
+
+/// Most ParcelInfo accounts admin_purge_parcels closes in one instruction. Each adds one
+/// account, so a full batch still fits in a transaction without a lookup table.
+#[constant]
+pub const MAX_PURGE_PARCEL_BATCH: u8 = 24;
+
+/// Most assets update_parcels_metadata_batch rewrites in one instruction, bounded by the
+/// compute of one UpdateV1 CPI per asset. Derived-mode batches add two accounts per parcel
+/// and need an address lookup table to fill up.
+#[constant]
+pub const MAX_METADATA_UPDATE_BATCH: u8 = 20;
+
+/// Largest width or height get_region returns: 22x22 parcel ids and the rest of the view stay
+/// under the 1024-byte return data limit
+#[constant]
+pub const MAX_REGION_SIDE: u8 = 22;
+
+/// Most BlockMap blocks one admin_backfill_ring_occupancy call counts, a fifth of the
+/// largest grid per transaction
+#[constant]
+pub const MAX_OCCUPANCY_SCAN_BLOCKS: u32 = 2_000;
+
+/// Most freed parcel ids the ParcelIdPool holds for reuse
+#[constant]
+pub const MAX_FREE_PARCEL_IDS: u32 = 64;
+
+/// Largest width or height of a parcel claimed with claim_parcel_masked
+#[constant]
+pub const MAX_MASK_SIDE: u8 = billion_core::MAX_MASK_SIDE;
+
+/// Bytes in the largest parcel mask
+#[constant]
+pub const MAX_MASK_BYTES: u32 = (MAX_MASK_SIDE as u32 * MAX_MASK_SIDE as u32).div_ceil(8);
+
+/// Slots claims must stay paused before admin_purge may run (about a day), so owners see the
+/// purge coming and can claim their rewards first
+#[constant]
+pub const PURGE_PAUSE_SLOTS: u64 = 216_000;
+
+/// Argument freeze_config must be passed, so a mistyped call can't freeze the config
+#[constant]
+pub const FREEZE_CONFIG_CONFIRMATION: u64 = u64::from_le_bytes(*b"FREEZE!!");
+
+/// Most RGB bytes a ParcelPixels holds: three per block of an 8x8 parcel
+#[constant]
+pub const MAX_PARCEL_PIXELS_LEN: u32 = 3 * 8 * 8;
@@ -1,49 +1,563 @@
 use anchor_lang::prelude::*;
 
+/// Error codes are part of the client API (6000 + discriminant).
+/// Never reorder or reuse a discriminant; append new variants at the end.
 #[error_code]
 pub enum BillionError {
     #[msg("Block is already claimed")]
-    BlockAlreadyClaimed,
+    BlockAlreadyClaimed = 0,
 
     #[msg("Block is in a locked ring")]
-    RingLocked,
+    RingLocked = 1,
 
     #[msg("Block coordinates out of bounds")]
-    OutOfBounds,
+    OutOfBounds = 2,
 
     #[msg("Invalid parcel dimensions")]
-    InvalidDimensions,
+    InvalidDimensions = 3,
 
     #[msg("Insufficient token balance")]
-    InsufficientBalance,
+    InsufficientBalance = 4,
 
     #[msg("Unauthorized")]
-    Unauthorized,
+    Unauthorized = 5,
 
     #[msg("Seeding is disabled")]
-    SeedingDisabled,
+    SeedingDisabled = 6,
 
     #[msg("Arithmetic overflow")]
-    Overflow,
+    Overflow = 7,
 
     #[msg("Collection not set")]
-    CollectionNotSet,
+    CollectionNotSet = 8,
 
     #[msg("Invalid collection")]
-    InvalidCollection,
+    InvalidCollection = 9,
 
     #[msg("Asset does not match parcel")]
-    AssetMismatch,
+    AssetMismatch = 10,
 
     #[msg("Invalid reward pool")]
-    InvalidRewardPool,
+    InvalidRewardPool = 11,
 
     #[msg("Caller does not own this parcel")]
-    NotOwner,
+    NotOwner = 12,
 
     #[msg("Nothing to claim")]
-    NothingToClaim,
+    NothingToClaim = 13,
 
     #[msg("Invalid Core asset data")]
-    InvalidCoreAsset,
+    InvalidCoreAsset = 14,
+
+    #[msg("Parcel name is too long")]
+    NameTooLong = 15,
+
+    #[msg("Parcel uri is too long")]
+    UriTooLong = 16,
+
+    #[msg("Dry run completed, no state was changed")]
+    DryRunSuccess = 17,
+
+    #[msg("Parcel exceeds the maximum allowed blocks")]
+    ParcelTooLarge = 18,
+
+    #[msg("Claims are paused")]
+    ClaimsPaused = 19,
+
+    #[msg("Claimer is not on the allowlist")]
+    NotAllowlisted = 20,
+
+    #[msg("No parcel ids left to assign")]
+    ParcelIdExhausted = 21,
+
+    #[msg("Parcel name is already registered")]
+    NameTaken = 22,
+
+    #[msg("Config is frozen")]
+    ConfigFrozen = 23,
+
+    #[msg("Landowners still have unclaimed rewards")]
+    OutstandingRewards = 24,
+
+    #[msg("Referrer cannot be the claimer")]
+    SelfReferral = 25,
+
+    #[msg("Referrer was referred by the claimer")]
+    CircularReferral = 26,
+
+    #[msg("Referral vault is not configured")]
+    ReferralsDisabled = 27,
+
+    #[msg("Referral and landowner shares exceed 100%")]
+    InvalidReferralShare = 28,
+
+    #[msg("Emissions vault is not configured")]
+    EmissionsDisabled = 29,
+
+    #[msg("Harberger district is not enabled")]
+    HarbergerDisabled = 30,
+
+    #[msg("Parcel is not inside the Harberger district")]
+    OutsideDistrict = 31,
+
+    #[msg("Harberger tax is not paid up")]
+    TaxNotCurrent = 32,
+
+    #[msg("Valuation must be greater than zero")]
+    InvalidValuation = 33,
+
+    #[msg("Current owner has not declared a valuation")]
+    ValuationNotSet = 34,
+
+    #[msg("Parcel is staked")]
+    ParcelStaked = 35,
+
+    #[msg("Tax rate must be at most 100% per non-zero period")]
+    InvalidTaxRate = 36,
+
+    #[msg("Quest vault is not configured")]
+    QuestsDisabled = 37,
+
+    #[msg("Quest proof does not match the root")]
+    InvalidQuestProof = 38,
+
+    #[msg("Quest reward already claimed")]
+    QuestAlreadyClaimed = 39,
+
+    #[msg("Quest allocation exhausted")]
+    QuestAllocationExceeded = 40,
+
+    #[msg("Distribution has expired")]
+    DistributionExpired = 41,
+
+    #[msg("Distribution has not expired yet")]
+    DistributionNotExpired = 42,
+
+    #[msg("Parcel already claimed this distribution")]
+    DistributionAlreadyClaimed = 43,
+
+    #[msg("Parcel was created after the distribution snapshot")]
+    ParcelNotInSnapshot = 44,
+
+    #[msg("This epoch's grid is frozen")]
+    EpochFrozen = 45,
+
+    #[msg("Parcel belongs to an archived epoch")]
+    ParcelArchived = 46,
+
+    #[msg("Archived parcel requires its epoch archive")]
+    EpochArchiveRequired = 47,
+
+    #[msg("Hook program account missing or does not match hook_config")]
+    HookProgramMismatch = 48,
+
+    #[msg("Too many accounts passed to the claim hook")]
+    TooManyHookAccounts = 49,
+
+    #[msg("Share amount must be greater than zero")]
+    InvalidShareAmount = 50,
+
+    #[msg("Parcel is fractionalized")]
+    ParcelFractionalized = 51,
+
+    #[msg("Exit share must be at most 100%")]
+    InvalidExitShare = 52,
+
+    #[msg("Reward expiry is disabled")]
+    RewardExpiryDisabled = 53,
+
+    #[msg("Parcel rewards have not expired")]
+    RewardsNotExpired = 54,
+
+    #[msg("Reward expiry must not be negative and its bounty must not exceed the maximum")]
+    InvalidRewardExpiry = 55,
+
+    #[msg("Adjacency bonus exceeds the maximum")]
+    InvalidAdjacencyBonus = 56,
+
+    #[msg("Claims of newly unlocked cells need an adjacent parcel until the expansion window closes")]
+    AdjacencyProofRequired = 57,
+
+    #[msg("Adjacency proof must be a current parcel owned by the claimer that borders the claim")]
+    InvalidAdjacencyProof = 58,
+
+    #[msg("Attestation can't be closed before it is 24 hours old")]
+    AttestationNotExpired = 59,
+
+    #[msg("Vesting date must be in the future")]
+    InvalidVestingDate = 60,
+
+    #[msg("Parcel rewards are locked until its vesting date")]
+    RewardsLocked = 61,
+
+    #[msg("Parcel has no vesting lock")]
+    NotVested = 62,
+
+    #[msg("Vesting lock has not ended")]
+    VestingNotEnded = 63,
+
+    #[msg("Charity index is not in the registry or its token account does not match")]
+    InvalidCharity = 64,
+
+    #[msg("Charity share exceeds the configured maximum")]
+    InvalidCharityShare = 65,
+
+    #[msg("Too many charities")]
+    TooManyCharities = 66,
+
+    #[msg("Names must be 3-32 characters of a-z, 0-9, '-' or '_'")]
+    InvalidName = 67,
+
+    #[msg("Parcel already has a registered name")]
+    ParcelAlreadyNamed = 68,
+
+    #[msg("Only the registrant can release a name while they still own the parcel")]
+    NameStillHeld = 69,
+
+    #[msg("Reward pool cannot cover the parcel's pending rewards")]
+    RewardPoolInsufficient = 70,
+
+    #[msg("Voucher constraints are inconsistent or already expired")]
+    InvalidVoucher = 71,
+
+    #[msg("Voucher has expired")]
+    VoucherExpired = 72,
+
+    #[msg("Voucher has not expired yet")]
+    VoucherNotExpired = 73,
+
+    #[msg("Parcel is outside the voucher's size or ring constraints")]
+    VoucherConstraintViolated = 74,
+
+    #[msg("Premium badges are not enabled")]
+    PremiumDisabled = 75,
+
+    #[msg("Premium period must be positive")]
+    InvalidPremiumConfig = 76,
+
+    #[msg("Must purchase at least one premium period")]
+    InvalidPremiumPeriods = 77,
+
+    #[msg("Raffles are not enabled")]
+    RaffleDisabled = 78,
+
+    #[msg("Minimum interval since the last raffle has not passed")]
+    RaffleTooSoon = 79,
+
+    #[msg("The current raffle prize is still claimable")]
+    RafflePrizeActive = 80,
+
+    #[msg("No eligible block was drawn; try again later")]
+    NoRaffleBlock = 81,
+
+    #[msg("There is no claimable raffle prize")]
+    NoRafflePrize = 82,
+
+    #[msg("Raffle interval cannot be negative")]
+    InvalidRaffleInterval = 83,
+
+    #[msg("Missing or mismatched ed25519 signature for the claim authorization")]
+    InvalidClaimSignature = 84,
+
+    #[msg("Claim authorization has expired")]
+    ClaimAuthorizationExpired = 85,
+
+    #[msg("Claim authorization nonce was already used")]
+    NonceAlreadyUsed = 86,
+
+    #[msg("Claim cost exceeds the signed maximum")]
+    MaxCostExceeded = 87,
+
+    #[msg("Batch must hold 1 to MAX_BATCH_RECTS rectangles, each with its asset and ParcelInfo account")]
+    InvalidBatch = 88,
+
+    #[msg("Claim would take the wallet past max_blocks_per_wallet")]
+    WalletBlockLimitExceeded = 89,
+
+    #[msg("ClaimerStats can't be closed while a per-wallet block limit is set")]
+    WalletLimitActive = 90,
+
+    #[msg("Paying in SOL is disabled")]
+    SolPaymentsDisabled = 91,
+
+    #[msg("SOL ring credit must be at most 10000 basis points")]
+    InvalidSolConfig = 92,
+
+    #[msg("Ring price multipliers need one nonzero entry per ring")]
+    InvalidRingMultipliers = 93,
+
+    #[msg("Royalty basis points must be at most 10000")]
+    InvalidRoyalties = 94,
+
+    #[msg("Token mints with a transfer fee are not supported")]
+    TransferFeeMintUnsupported = 95,
+
+    #[msg("Mask must be 1 to MAX_MASK_SIDE cells on a side, match its bounding box, and set at least one cell")]
+    InvalidMask = 96,
+
+    #[msg("Parcel is reserved until admin_release_reserved_parcel assigns its owner")]
+    ParcelReserved = 97,
+
+    #[msg("Parcel is not reserved")]
+    ParcelNotReserved = 98,
+
+    #[msg("Signer is neither the parcel owner nor its current reward delegate")]
+    NotRewardDelegate = 99,
+
+    #[msg("Reward destination must be a token account of the parcel owner for the grid's mint")]
+    InvalidRewardDestination = 100,
+
+    #[msg("No claimed blocks to credit rewards to")]
+    NoRewardRecipients = 101,
+
+    #[msg("Reward funding must be greater than zero")]
+    InvalidRewardFunding = 102,
+
+    #[msg("Parcel is still on the grid")]
+    ParcelStillOpen = 103,
+
+    #[msg("Ring reward weights must be between 1000 and 25500 bps")]
+    InvalidRingRewardWeights = 104,
+
+    #[msg("An admin role can't be held by the default key")]
+    InvalidAdminRole = 105,
+
+    #[msg("Price, ring thresholds and reward share changes must be queued")]
+    ConfigTimelocked = 106,
+
+    #[msg("A config update is already queued")]
+    ConfigUpdatePending = 107,
+
+    #[msg("No config update is queued")]
+    NoPendingConfigUpdate = 108,
+
+    #[msg("The queued config update's timelock hasn't passed")]
+    ConfigUpdateNotReady = 109,
+
+    #[msg("Config update doesn't match the queued one")]
+    ConfigUpdateMismatch = 110,
+
+    #[msg("Config timelock can't be negative")]
+    InvalidConfigTimelock = 111,
+
+    #[msg("Landowner and treasury shares can't exceed 10000 bps together")]
+    InvalidRewardShare = 112,
+
+    #[msg("Ring thresholds must be one per ring, for a ring count the grid fits")]
+    InvalidRingThresholds = 113,
+
+    #[msg("URI base must be at most 128 bytes and end in '/' or '='")]
+    InvalidUriBase = 114,
+
+    #[msg("total_burned is only raised through admin_advance_ring")]
+    TotalBurnedOverrideRemoved = 115,
+
+    #[msg("Ring is already unlocked")]
+    RingAlreadyUnlocked = 116,
+
+    #[msg("Ring must be one of the grid's rings")]
+    InvalidRing = 117,
+
+    #[msg("Parcels were minted under the current collection; changing it needs force_collection")]
+    CollectionLocked = 118,
+
+    #[msg("Treasury token account doesn't match the configured treasury")]
+    InvalidTreasury = 119,
+
+    #[msg("Treasury and landowner shares can't exceed 10000 bps together")]
+    InvalidTreasuryShare = 120,
+
+    #[msg("freeze_config needs FREEZE_CONFIG_CONFIRMATION to proceed")]
+    InvalidFreezeConfirmation = 121,
+
+    #[msg("Claims must be paused for PURGE_PAUSE_SLOTS before admin_purge")]
+    PurgeWindowOpen = 122,
+
+    #[msg("BlockMap size doesn't match its grid size, or the grid size is out of range")]
+    InvalidGridSize = 123,
+
+    #[msg("BlockMap is already bound to a grid")]
+    BlockMapInUse = 124,
+
+    #[msg("Every block in the rectangle must be reserved")]
+    BlocksNotReserved = 125,
+
+    #[msg("Regions are at most MAX_REGION_SIDE blocks wide and high")]
+    RegionTooLarge = 126,
+
+    #[msg("Ring unlock timestamps must be non-decreasing, at most one per ring")]
+    InvalidRingUnlockSchedule = 127,
+
+    #[msg("Ring thresholds must not decrease from one ring to the next")]
+    RingThresholdsNotAscending = 128,
+
+    #[msg("The first ring threshold must be 0; ring 1 is always unlocked")]
+    FirstRingThresholdNotZero = 129,
+
+    #[msg("Owners can't rename parcels while the rename fee is 0")]
+    RenameDisabled = 130,
+
+    #[msg("Display names must be 1-32 printable ASCII characters")]
+    InvalidDisplayName = 131,
+
+    #[msg("Uri suffixes are URL-safe characters that don't start with a digit")]
+    InvalidUriSuffix = 132,
+
+    #[msg("Only parcels of up to 64 blocks can store pixels")]
+    ParcelTooLargeForPixels = 133,
+
+    #[msg("Pixel data must be 3 RGB bytes per block of the parcel")]
+    InvalidPixelData = 134,
+
+    #[msg("Tax is paid up; only the owner can pay ahead")]
+    TaxNotDue = 135,
+
+    #[msg("Referrer token account must be a Token-2022 account of the grid's mint")]
+    InvalidReferrerTokenAccount = 136,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_codes_are_stable() {
+        assert_eq!(u32::from(BillionError::BlockAlreadyClaimed), 6000);
+        assert_eq!(u32::from(BillionError::RingLocked), 6001);
+        assert_eq!(u32::from(BillionError::OutOfBounds), 6002);
+        assert_eq!(u32::from(BillionError::InvalidDimensions), 6003);
+        assert_eq!(u32::from(BillionError::InsufficientBalance), 6004);
+        assert_eq!(u32::from(BillionError::Unauthorized), 6005);
+        assert_eq!(u32::from(BillionError::SeedingDisabled), 6006);
+        assert_eq!(u32::from(BillionError::Overflow), 6007);
+        assert_eq!(u32::from(BillionError::CollectionNotSet), 6008);
+        assert_eq!(u32::from(BillionError::InvalidCollection), 6009);
+        assert_eq!(u32::from(BillionError::AssetMismatch), 6010);
+        assert_eq!(u32::from(BillionError::InvalidRewardPool), 6011);
+        assert_eq!(u32::from(BillionError::NotOwner), 6012);
+        assert_eq!(u32::from(BillionError::NothingToClaim), 6013);
+        assert_eq!(u32::from(BillionError::InvalidCoreAsset), 6014);
+        assert_eq!(u32::from(BillionError::NameTooLong), 6015);
+        assert_eq!(u32::from(BillionError::UriTooLong), 6016);
+        assert_eq!(u32::from(BillionError::DryRunSuccess), 6017);
+        assert_eq!(u32::from(BillionError::ParcelTooLarge), 6018);
+        assert_eq!(u32::from(BillionError::ClaimsPaused), 6019);
+        assert_eq!(u32::from(BillionError::NotAllowlisted), 6020);
+        assert_eq!(u32::from(BillionError::ParcelIdExhausted), 6021);
+        assert_eq!(u32::from(BillionError::NameTaken), 6022);
+        assert_eq!(u32::from(BillionError::ConfigFrozen), 6023);
+        assert_eq!(u32::from(BillionError::OutstandingRewards), 6024);
+        assert_eq!(u32::from(BillionError::SelfReferral), 6025);
+        assert_eq!(u32::from(BillionError::CircularReferral), 6026);
+        assert_eq!(u32::from(BillionError::ReferralsDisabled), 6027);
+        assert_eq!(u32::from(BillionError::InvalidReferralShare), 6028);
+        assert_eq!(u32::from(BillionError::EmissionsDisabled), 6029);
+        assert_eq!(u32::from(BillionError::HarbergerDisabled), 6030);
+        assert_eq!(u32::from(BillionError::OutsideDistrict), 6031);
+        assert_eq!(u32::from(BillionError::TaxNotCurrent), 6032);
+        assert_eq!(u32::from(BillionError::InvalidValuation), 6033);
+        assert_eq!(u32::from(BillionError::ValuationNotSet), 6034);
+        assert_eq!(u32::from(BillionError::ParcelStaked), 6035);
+        assert_eq!(u32::from(BillionError::InvalidTaxRate), 6036);
+        assert_eq!(u32::from(BillionError::QuestsDisabled), 6037);
+        assert_eq!(u32::from(BillionError::InvalidQuestProof), 6038);
+        assert_eq!(u32::from(BillionError::QuestAlreadyClaimed), 6039);
+        assert_eq!(u32::from(BillionError::QuestAllocationExceeded), 6040);
+        assert_eq!(u32::from(BillionError::DistributionExpired), 6041);
+        assert_eq!(u32::from(BillionError::DistributionNotExpired), 6042);
+        assert_eq!(u32::from(BillionError::DistributionAlreadyClaimed), 6043);
+        assert_eq!(u32::from(BillionError::ParcelNotInSnapshot), 6044);
+        assert_eq!(u32::from(BillionError::EpochFrozen), 6045);
+        assert_eq!(u32::from(BillionError::ParcelArchived), 6046);
+        assert_eq!(u32::from(BillionError::EpochArchiveRequired), 6047);
+        assert_eq!(u32::from(BillionError::HookProgramMismatch), 6048);
+        assert_eq!(u32::from(BillionError::TooManyHookAccounts), 6049);
+        assert_eq!(u32::from(BillionError::InvalidShareAmount), 6050);
+        assert_eq!(u32::from(BillionError::ParcelFractionalized), 6051);
+        assert_eq!(u32::from(BillionError::InvalidExitShare), 6052);
+        assert_eq!(u32::from(BillionError::RewardExpiryDisabled), 6053);
+        assert_eq!(u32::from(BillionError::RewardsNotExpired), 6054);
+        assert_eq!(u32::from(BillionError::InvalidRewardExpiry), 6055);
+        assert_eq!(u32::from(BillionError::InvalidAdjacencyBonus), 6056);
+        assert_eq!(u32::from(BillionError::AdjacencyProofRequired), 6057);
+        assert_eq!(u32::from(BillionError::InvalidAdjacencyProof), 6058);
+        assert_eq!(u32::from(BillionError::AttestationNotExpired), 6059);
+        assert_eq!(u32::from(BillionError::InvalidVestingDate), 6060);
+        assert_eq!(u32::from(BillionError::RewardsLocked), 6061);
+        assert_eq!(u32::from(BillionError::NotVested), 6062);
+        assert_eq!(u32::from(BillionError::VestingNotEnded), 6063);
+        assert_eq!(u32::from(BillionError::InvalidCharity), 6064);
+        assert_eq!(u32::from(BillionError::InvalidCharityShare), 6065);
+        assert_eq!(u32::from(BillionError::TooManyCharities), 6066);
+        assert_eq!(u32::from(BillionError::InvalidName), 6067);
+        assert_eq!(u32::from(BillionError::ParcelAlreadyNamed), 6068);
+        assert_eq!(u32::from(BillionError::NameStillHeld), 6069);
+        assert_eq!(u32::from(BillionError::RewardPoolInsufficient), 6070);
+        assert_eq!(u32::from(BillionError::InvalidVoucher), 6071);
+        assert_eq!(u32::from(BillionError::VoucherExpired), 6072);
+        assert_eq!(u32::from(BillionError::VoucherNotExpired), 6073);
+        assert_eq!(u32::from(BillionError::VoucherConstraintViolated), 6074);
+        assert_eq!(u32::from(BillionError::PremiumDisabled), 6075);
+        assert_eq!(u32::from(BillionError::InvalidPremiumConfig), 6076);
+        assert_eq!(u32::from(BillionError::InvalidPremiumPeriods), 6077);
+        assert_eq!(u32::from(BillionError::RaffleDisabled), 6078);
+        assert_eq!(u32::from(BillionError::RaffleTooSoon), 6079);
+        assert_eq!(u32::from(BillionError::RafflePrizeActive), 6080);
+        assert_eq!(u32::from(BillionError::NoRaffleBlock), 6081);
+        assert_eq!(u32::from(BillionError::NoRafflePrize), 6082);
+        assert_eq!(u32::from(BillionError::InvalidRaffleInterval), 6083);
+        assert_eq!(u32::from(BillionError::InvalidClaimSignature), 6084);
+        assert_eq!(u32::from(BillionError::ClaimAuthorizationExpired), 6085);
+        assert_eq!(u32::from(BillionError::NonceAlreadyUsed), 6086);
+        assert_eq!(u32::from(BillionError::MaxCostExceeded), 6087);
+        assert_eq!(u32::from(BillionError::InvalidBatch), 6088);
+        assert_eq!(u32::from(BillionError::WalletBlockLimitExceeded), 6089);
+        assert_eq!(u32::from(BillionError::WalletLimitActive), 6090);
+        assert_eq!(u32::from(BillionError::SolPaymentsDisabled), 6091);
+        assert_eq!(u32::from(BillionError::InvalidSolConfig), 6092);
+        assert_eq!(u32::from(BillionError::InvalidRingMultipliers), 6093);
+        assert_eq!(u32::from(BillionError::InvalidRoyalties), 6094);
+        assert_eq!(u32::from(BillionError::TransferFeeMintUnsupported), 6095);
+        assert_eq!(u32::from(BillionError::InvalidMask), 6096);
+        assert_eq!(u32::from(BillionError::ParcelReserved), 6097);
+        assert_eq!(u32::from(BillionError::ParcelNotReserved), 6098);
+        assert_eq!(u32::from(BillionError::NotRewardDelegate), 6099);
+        assert_eq!(u32::from(BillionError::InvalidRewardDestination), 6100);
+        assert_eq!(u32::from(BillionError::NoRewardRecipients), 6101);
+        assert_eq!(u32::from(BillionError::InvalidRewardFunding), 6102);
+        assert_eq!(u32::from(BillionError::ParcelStillOpen), 6103);
+        assert_eq!(u32::from(BillionError::InvalidRingRewardWeights), 6104);
+        assert_eq!(u32::from(BillionError::InvalidAdminRole), 6105);
+        assert_eq!(u32::from(BillionError::ConfigTimelocked), 6106);
+        assert_eq!(u32::from(BillionError::ConfigUpdatePending), 6107);
+        assert_eq!(u32::from(BillionError::NoPendingConfigUpdate), 6108);
+        assert_eq!(u32::from(BillionError::ConfigUpdateNotReady), 6109);
+        assert_eq!(u32::from(BillionError::ConfigUpdateMismatch), 6110);
+        assert_eq!(u32::from(BillionError::InvalidConfigTimelock), 6111);
+        assert_eq!(u32::from(BillionError::InvalidRewardShare), 6112);
+        assert_eq!(u32::from(BillionError::InvalidRingThresholds), 6113);
+        assert_eq!(u32::from(BillionError::InvalidUriBase), 6114);
+        assert_eq!(u32::from(BillionError::TotalBurnedOverrideRemoved), 6115);
+        assert_eq!(u32::from(BillionError::RingAlreadyUnlocked), 6116);
+        assert_eq!(u32::from(BillionError::InvalidRing), 6117);
+        assert_eq!(u32::from(BillionError::CollectionLocked), 6118);
+        assert_eq!(u32::from(BillionError::InvalidTreasury), 6119);
+        assert_eq!(u32::from(BillionError::InvalidTreasuryShare), 6120);
+        assert_eq!(u32::from(BillionError::InvalidFreezeConfirmation), 6121);
+        assert_eq!(u32::from(BillionError::PurgeWindowOpen), 6122);
+        assert_eq!(u32::from(BillionError::InvalidGridSize), 6123);
+        assert_eq!(u32::from(BillionError::BlockMapInUse), 6124);
+        assert_eq!(u32::from(BillionError::BlocksNotReserved), 6125);
+        assert_eq!(u32::from(BillionError::RegionTooLarge), 6126);
+        assert_eq!(u32::from(BillionError::InvalidRingUnlockSchedule), 6127);
+        assert_eq!(u32::from(BillionError::RingThresholdsNotAscending), 6128);
+        assert_eq!(u32::from(BillionError::FirstRingThresholdNotZero), 6129);
+        assert_eq!(u32::from(BillionError::RenameDisabled), 6130);
+        assert_eq!(u32::from(BillionError::InvalidDisplayName), 6131);
+        assert_eq!(u32::from(BillionError::InvalidUriSuffix), 6132);
+        assert_eq!(u32::from(BillionError::ParcelTooLargeForPixels), 6133);
+        assert_eq!(u32::from(BillionError::InvalidPixelData), 6134);
+        assert_eq!(u32::from(BillionError::TaxNotDue), 6135);
+        assert_eq!(u32::from(BillionError::InvalidReferrerTokenAccount), 6136);
+    }
 }
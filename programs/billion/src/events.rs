@@ -0,0 +1,876 @@
+//! Every `#[event]` the program emits.
+//!
+//! Event layouts are part of the client API. Each event starts with `schema_version`
+//! (EVENT_SCHEMA_VERSION at emit time) so indexers can branch on it; bump the constant and
+//! regenerate the golden fixtures in `fixtures/events/` only when a layout genuinely changes.
+
+use anchor_lang::prelude::*;
+
+pub use crate::constants::EVENT_SCHEMA_VERSION;
+
+#[event]
+pub struct GridInitialized {
+    pub schema_version: u8,
+    pub grid_config: Pubkey,
+    pub authority: Pubkey,
+    pub token_mint: Pubkey,
+    pub price_per_block: u64,
+    pub land_owners_reward_share_bps: u16,
+    pub program_version: String,
+    pub config_schema_version: u8,
+    pub feature_flags: u64,
+}
+
+/// The full starting config, alongside GridInitialized (whose layout is pinned). Later
+/// changes arrive as ConfigUpdated.
+#[event]
+pub struct ConfigInitialized {
+    pub schema_version: u8,
+    pub price_per_block: u64,
+    pub ring_thresholds: Vec<u64>,
+    pub uri_base: String,
+    pub seeding_enabled: bool,
+    pub collection: Pubkey,
+    pub land_owners_reward_share_bps: u16,
+    pub max_parcel_blocks: u16,
+}
+
+/// ConfigUpdated.changed_fields bits, one per update_config argument plus the timelock and the
+/// direct referral share
+pub const CONFIG_FIELD_PRICE_PER_BLOCK: u32 = 1 << 0;
+pub const CONFIG_FIELD_RING_THRESHOLDS: u32 = 1 << 1;
+pub const CONFIG_FIELD_URI_BASE: u32 = 1 << 2;
+pub const CONFIG_FIELD_SEEDING_ENABLED: u32 = 1 << 3;
+pub const CONFIG_FIELD_COLLECTION: u32 = 1 << 4;
+pub const CONFIG_FIELD_LAND_OWNERS_REWARD_SHARE: u32 = 1 << 5;
+pub const CONFIG_FIELD_MAX_PARCEL_BLOCKS: u32 = 1 << 6;
+pub const CONFIG_FIELD_MAX_BLOCKS_PER_WALLET: u32 = 1 << 7;
+pub const CONFIG_FIELD_CLAIMS_ENABLED: u32 = 1 << 8;
+pub const CONFIG_FIELD_RING_PRICE_MULTIPLIERS: u32 = 1 << 9;
+pub const CONFIG_FIELD_CURVE_DIVISOR: u32 = 1 << 10;
+pub const CONFIG_FIELD_ALLOWLIST_ROOT: u32 = 1 << 11;
+pub const CONFIG_FIELD_ALLOWLIST_ONLY: u32 = 1 << 12;
+pub const CONFIG_FIELD_ADMIN_MINT_ALLOWANCE: u32 = 1 << 13;
+pub const CONFIG_FIELD_CONFIG_TIMELOCK: u32 = 1 << 14;
+pub const CONFIG_FIELD_RING_UNLOCK_TIMESTAMPS: u32 = 1 << 15;
+pub const CONFIG_FIELD_REFERRAL_SHARE: u32 = 1 << 16;
+
+/// One per update_config, execute_config_update or set_referral_share call. `changed_fields`
+/// has a bit for every field the call set, whether or not its value moved; the fields indexers
+/// chart carry their values either side, with ring thresholds as GridConfig::ring_thresholds_hash.
+#[event]
+pub struct ConfigUpdated {
+    pub schema_version: u8,
+    pub authority: Pubkey,
+    pub changed_fields: u32,
+    pub old_price_per_block: u64,
+    pub new_price_per_block: u64,
+    pub old_land_owners_reward_share_bps: u16,
+    pub new_land_owners_reward_share_bps: u16,
+    pub old_seeding_enabled: bool,
+    pub new_seeding_enabled: bool,
+    pub old_collection: Pubkey,
+    pub new_collection: Pubkey,
+    pub old_uri_base: String,
+    pub new_uri_base: String,
+    pub old_ring_thresholds_hash: [u8; 32],
+    pub new_ring_thresholds_hash: [u8; 32],
+}
+
+/// An admin-minted parcel frozen until `locked_until`
+#[event]
+pub struct ParcelVested {
+    pub schema_version: u8,
+    pub parcel_id: u16,
+    pub epoch: u16,
+    pub asset: Pubkey,
+    pub recipient: Pubkey,
+    pub locked_until: i64,
+    /// Land-buy rewards are unclaimable until locked_until as well
+    pub lock_rewards: bool,
+}
+
+#[event]
+pub struct VestedParcelUnlocked {
+    pub schema_version: u8,
+    pub parcel_id: u16,
+    pub epoch: u16,
+    pub asset: Pubkey,
+    pub locked_until: i64,
+    pub unlocked_at: i64,
+}
+
+/// A parcel bought through any claim_parcel variant. Emitted after the block map, GridConfig
+/// and ParcelInfo are written.
+#[event]
+pub struct ParcelClaimed {
+    pub schema_version: u8,
+    pub parcel_id: u16,
+    pub epoch: u16,
+    /// Paid for the parcel
+    pub claimer: Pubkey,
+    pub asset: Pubkey,
+    pub x: u8,
+    pub y: u8,
+    pub width: u8,
+    pub height: u8,
+    /// For claim_parcels_batch, the whole batch's amounts are reported on its first parcel
+    /// and 0 on the others; always 0 for claim_parcel_sol
+    pub burn_amount: u64,
+    pub reward_amount: u64,
+    /// GridConfig.total_burned after the claim
+    pub total_burned: u64,
+    /// Token price per block after the bonding curve, before ring multipliers; 0 for
+    /// claim_parcel_sol
+    pub price_per_block: u64,
+    /// Received the asset; differs from claimer only for gift claims
+    pub owner: Pubkey,
+}
+
+/// A parcel minted by the authority through admin_mint. No tokens move, so total_burned is
+/// unchanged by it.
+#[event]
+pub struct ParcelSeeded {
+    pub schema_version: u8,
+    pub parcel_id: u16,
+    pub epoch: u16,
+    pub recipient: Pubkey,
+    pub asset: Pubkey,
+    pub x: u8,
+    pub y: u8,
+    pub width: u8,
+    pub height: u8,
+    pub total_burned: u64,
+}
+
+/// How a claim's cost was split. The four amounts sum to total_cost, less the amounts of any
+/// TreasuryPaid and ReferralSharePaid emitted with it.
+#[event]
+pub struct PaymentSettled {
+    pub schema_version: u8,
+    /// First parcel of the batch for claim_parcels_batch
+    pub parcel_id: u16,
+    pub epoch: u16,
+    pub claimer: Pubkey,
+    pub total_cost: u64,
+    pub reward_amount: u64,
+    pub referral_amount: u64,
+    /// Routed to `charity` out of what would otherwise be burned
+    pub charity_amount: u64,
+    /// Charity token account, or the default pubkey when none was chosen
+    pub charity: Pubkey,
+    pub burn_amount: u64,
+}
+
+/// Premium badge bought for a parcel. `fee` = reward_amount + burn_amount.
+#[event]
+pub struct PremiumPurchased {
+    pub schema_version: u8,
+    pub parcel_id: u16,
+    pub epoch: u16,
+    pub payer: Pubkey,
+    pub periods: u32,
+    pub fee: u64,
+    pub reward_amount: u64,
+    pub burn_amount: u64,
+    /// Badge expiry after this purchase
+    pub premium_until: i64,
+}
+
+/// One parcel's share of a LandBuyRewardsClaimed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParcelRewardPayout {
+    pub parcel_id: u16,
+    pub epoch: u16,
+    pub owed: u64,
+    pub adjacency_bonus: u64,
+}
+
+/// Land-buy rewards paid by claim_land_buy_rewards_batch in one transfer. Parcels with
+/// nothing to claim are left out of `payouts`.
+#[event]
+pub struct LandBuyRewardsClaimed {
+    pub schema_version: u8,
+    pub claimer: Pubkey,
+    pub payouts: Vec<ParcelRewardPayout>,
+    /// Sum of every payout's owed and adjacency_bonus
+    pub total: u64,
+}
+
+/// Tokens deposited by fund_land_buy_rewards and credited to every rewarded block
+#[event]
+pub struct LandBuyRewardsFunded {
+    pub schema_version: u8,
+    pub depositor: Pubkey,
+    pub amount: u64,
+    /// Accumulator after the deposit
+    pub land_buy_rewards_per_block: u128,
+}
+
+/// Lamports deposited by fund_sol_rewards and credited to every enrolled block
+#[event]
+pub struct SolRewardsFunded {
+    pub schema_version: u8,
+    pub depositor: Pubkey,
+    pub lamports: u64,
+    /// SolRewards accumulator after the deposit
+    pub sol_rewards_per_block: u128,
+}
+
+/// verify_reward_solvency summed the rewards pending on a batch of parcels
+#[event]
+pub struct RewardSolvencyVerified {
+    pub schema_version: u8,
+    /// Parcels in this batch
+    pub parcels: u16,
+    /// Pending rewards of this batch and every earlier one
+    pub outstanding: u64,
+    pub pool_balance: u64,
+}
+
+/// The rewards pending on the parcels verified so far exceed the reward pool
+#[event]
+pub struct SolvencyWarning {
+    pub schema_version: u8,
+    pub outstanding: u64,
+    pub pool_balance: u64,
+    pub deficit: u64,
+}
+
+/// A timelocked config change was queued; the change itself is only published as its hash,
+/// which execute_config_update checks the submitted change against
+#[event]
+pub struct ConfigUpdateQueued {
+    pub schema_version: u8,
+    pub change_hash: [u8; 32],
+    pub ready_at: i64,
+}
+
+/// Rings unlocked by admin_advance_ring rather than by burns. total_burned is raised to the
+/// target ring's threshold, so everything after it counts toward the rings past it.
+#[event]
+pub struct RingManuallyUnlocked {
+    pub schema_version: u8,
+    pub authority: Pubkey,
+    pub previous_ring: u8,
+    pub ring: u8,
+    pub total_burned_before: u64,
+    pub total_burned_after: u64,
+}
+
+/// The protocol treasury's share of a claim, settled alongside PaymentSettled and taken out
+/// of its burn_amount
+#[event]
+pub struct TreasuryPaid {
+    pub schema_version: u8,
+    /// First parcel of the payment
+    pub parcel_id: u16,
+    pub epoch: u16,
+    pub claimer: Pubkey,
+    pub treasury: Pubkey,
+    pub amount: u64,
+}
+
+/// freeze_config ran; update_config, admin_mint and the other frozen admin paths fail from
+/// here on
+#[event]
+pub struct ConfigFrozen {
+    pub schema_version: u8,
+    pub authority: Pubkey,
+    pub frozen_at: i64,
+}
+
+/// admin_purge was forced while landowners were still owed land-buy rewards. `amount` is the
+/// part of `reward_liability` the drained pool could have paid.
+#[event]
+pub struct RewardsConfiscated {
+    pub schema_version: u8,
+    pub authority: Pubkey,
+    pub reward_liability: u64,
+    pub amount: u64,
+}
+
+/// admin_reserve_blocks took a rectangle of the current epoch's BlockMap out of play
+#[event]
+pub struct BlocksReserved {
+    pub schema_version: u8,
+    pub authority: Pubkey,
+    pub epoch: u16,
+    pub x: u8,
+    pub y: u8,
+    pub width: u8,
+    pub height: u8,
+}
+
+/// admin_unreserve_blocks returned a reserved rectangle to unclaimed
+#[event]
+pub struct BlocksUnreserved {
+    pub schema_version: u8,
+    pub authority: Pubkey,
+    pub epoch: u16,
+    pub x: u8,
+    pub y: u8,
+    pub width: u8,
+    pub height: u8,
+}
+
+/// New claimed-block counts per ring (index 0 is the outermost ring), emitted alongside
+/// ParcelClaimed (whose layout is pinned), on freeing a parcel's blocks, and after each
+/// admin_backfill_ring_occupancy call. Counts of an existing grid are partial until
+/// `complete`, once the backfill has scanned its whole BlockMap.
+#[event]
+pub struct RingOccupancyUpdated {
+    pub schema_version: u8,
+    pub epoch: u16,
+    pub claimed_blocks_per_ring: [u32; 10],
+    pub complete: bool,
+}
+
+/// A write to the BlockMap, emitted alongside ParcelClaimed (whose layout is pinned) and by
+/// every other instruction that changes the map. `parcel_id` is 0 for freed blocks and
+/// RESERVED_BLOCK for reserved ones; a masked parcel writes its bounding rectangle.
+/// `map_hash` is MapDigest's hash with the write chained in.
+#[event]
+pub struct MapUpdated {
+    pub schema_version: u8,
+    pub epoch: u16,
+    pub parcel_id: u16,
+    pub x: u8,
+    pub y: u8,
+    pub width: u8,
+    pub height: u8,
+    pub map_version: u64,
+    pub map_hash: [u8; 32],
+}
+
+/// A claim's burn took the unlocked ring past one or more thresholds. A claim crossing several
+/// at once emits a single event for the ring it reached.
+#[event]
+pub struct RingUnlocked {
+    pub schema_version: u8,
+    pub epoch: u16,
+    /// Unlocked ring after the claim
+    pub new_ring: u8,
+    /// Rings the claim opened, ending with `new_ring`
+    pub rings_crossed: u8,
+    /// GridConfig.total_burned after the claim
+    pub total_burned: u64,
+    /// The claimed parcel; the first parcel of a claim_parcels_batch
+    pub triggering_parcel_id: u16,
+}
+
+/// owner_update_parcel renamed a parcel's asset, burning `fee`
+#[event]
+pub struct ParcelRenamed {
+    pub schema_version: u8,
+    pub parcel_id: u16,
+    pub epoch: u16,
+    pub owner: Pubkey,
+    /// Display name as written to the asset, after trimming
+    pub name: String,
+    /// New asset uri; empty when the uri was left as it was
+    pub uri: String,
+    pub fee: u64,
+}
+
+/// set_parcel_content recorded the hash of a parcel's art
+#[event]
+pub struct ParcelContentUpdated {
+    pub schema_version: u8,
+    pub parcel_id: u16,
+    pub epoch: u16,
+    pub owner: Pubkey,
+    /// All zeros when the owner cleared it
+    pub content_hash: [u8; 32],
+}
+
+/// A parcel's ParcelPixels were written or closed; indexers refetch the account
+#[event]
+pub struct ParcelPixelsUpdated {
+    pub schema_version: u8,
+    pub parcel_id: u16,
+    pub epoch: u16,
+    pub owner: Pubkey,
+    /// Whether close_parcel_pixels removed them
+    pub cleared: bool,
+}
+
+/// claim_parcel paid ReferralShare.share_bps of its total_cost straight to a referrer's token
+/// account, before the rest was split
+#[event]
+pub struct ReferralSharePaid {
+    pub schema_version: u8,
+    pub parcel_id: u16,
+    pub epoch: u16,
+    pub claimer: Pubkey,
+    /// Owner of `referrer_token_account`
+    pub referrer: Pubkey,
+    pub referrer_token_account: Pubkey,
+    pub amount: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Event;
+
+    /// Compare `event.data()` (discriminator + borsh) against a checked-in hex fixture.
+    /// Run with UPDATE_EVENT_FIXTURES=1 to rewrite the fixture after an intentional change.
+    fn assert_golden<E: Event>(name: &str, event: &E) {
+        let path = format!("{}/fixtures/events/{}.hex", env!("CARGO_MANIFEST_DIR"), name);
+        let actual: String = event.data().iter().map(|b| format!("{:02x}", b)).collect();
+        if std::env::var_os("UPDATE_EVENT_FIXTURES").is_some() {
+            std::fs::write(&path, format!("{}\n", actual)).unwrap();
+            return;
+        }
+        let expected = std::fs::read_to_string(&path).unwrap_or_else(|_| panic!("missing fixture {}", path));
+        assert_eq!(
+            expected.trim(),
+            actual,
+            "{} layout changed; bump EVENT_SCHEMA_VERSION and regenerate fixtures if intended",
+            name
+        );
+    }
+
+    fn sample_pubkey(seed: u8) -> Pubkey {
+        Pubkey::new_from_array([seed; 32])
+    }
+
+    #[test]
+    fn test_grid_initialized_layout() {
+        assert_golden(
+            "grid_initialized",
+            &GridInitialized {
+                schema_version: EVENT_SCHEMA_VERSION,
+                grid_config: sample_pubkey(1),
+                authority: sample_pubkey(2),
+                token_mint: sample_pubkey(3),
+                price_per_block: 1_000_000,
+                land_owners_reward_share_bps: 2000,
+                program_version: "0.1.0".to_string(),
+                config_schema_version: 1,
+                feature_flags: 0b111,
+            },
+        );
+    }
+
+    #[test]
+    fn test_parcel_vested_layout() {
+        assert_golden(
+            "parcel_vested",
+            &ParcelVested {
+                schema_version: EVENT_SCHEMA_VERSION,
+                parcel_id: 7,
+                epoch: 1,
+                asset: sample_pubkey(4),
+                recipient: sample_pubkey(5),
+                locked_until: 1_735_689_600,
+                lock_rewards: true,
+            },
+        );
+    }
+
+    #[test]
+    fn test_vested_parcel_unlocked_layout() {
+        assert_golden(
+            "vested_parcel_unlocked",
+            &VestedParcelUnlocked {
+                schema_version: EVENT_SCHEMA_VERSION,
+                parcel_id: 7,
+                epoch: 1,
+                asset: sample_pubkey(4),
+                locked_until: 1_735_689_600,
+                unlocked_at: 1_735_700_000,
+            },
+        );
+    }
+
+    #[test]
+    fn test_payment_settled_layout() {
+        assert_golden(
+            "payment_settled",
+            &PaymentSettled {
+                schema_version: EVENT_SCHEMA_VERSION,
+                parcel_id: 7,
+                epoch: 1,
+                claimer: sample_pubkey(5),
+                total_cost: 4_000_000,
+                reward_amount: 800_000,
+                referral_amount: 200_000,
+                charity_amount: 400_000,
+                charity: sample_pubkey(6),
+                burn_amount: 2_600_000,
+            },
+        );
+    }
+
+    #[test]
+    fn test_premium_purchased_layout() {
+        assert_golden(
+            "premium_purchased",
+            &PremiumPurchased {
+                schema_version: EVENT_SCHEMA_VERSION,
+                parcel_id: 7,
+                epoch: 1,
+                payer: sample_pubkey(5),
+                periods: 3,
+                fee: 3_000_000,
+                reward_amount: 600_000,
+                burn_amount: 2_400_000,
+                premium_until: 1_743_465_600,
+            },
+        );
+    }
+
+    #[test]
+    fn test_parcel_claimed_layout() {
+        assert_golden(
+            "parcel_claimed",
+            &ParcelClaimed {
+                schema_version: EVENT_SCHEMA_VERSION,
+                parcel_id: 7,
+                epoch: 1,
+                claimer: sample_pubkey(5),
+                asset: sample_pubkey(4),
+                x: 10,
+                y: 20,
+                width: 2,
+                height: 2,
+                burn_amount: 3_200_000,
+                reward_amount: 800_000,
+                total_burned: 53_200_000,
+                price_per_block: 1_000_000,
+                owner: sample_pubkey(3),
+            },
+        );
+    }
+
+    #[test]
+    fn test_land_buy_rewards_claimed_layout() {
+        assert_golden(
+            "land_buy_rewards_claimed",
+            &LandBuyRewardsClaimed {
+                schema_version: EVENT_SCHEMA_VERSION,
+                claimer: sample_pubkey(5),
+                payouts: vec![
+                    ParcelRewardPayout { parcel_id: 7, epoch: 1, owed: 400_000, adjacency_bonus: 0 },
+                    ParcelRewardPayout { parcel_id: 9, epoch: 1, owed: 100_000, adjacency_bonus: 5_000 },
+                ],
+                total: 505_000,
+            },
+        );
+    }
+
+    #[test]
+    fn test_land_buy_rewards_funded_layout() {
+        assert_golden(
+            "land_buy_rewards_funded",
+            &LandBuyRewardsFunded {
+                schema_version: EVENT_SCHEMA_VERSION,
+                depositor: sample_pubkey(5),
+                amount: 10_000_000,
+                land_buy_rewards_per_block: 2_500_000_000_000_000,
+            },
+        );
+    }
+
+    #[test]
+    fn test_sol_rewards_funded_layout() {
+        assert_golden(
+            "sol_rewards_funded",
+            &SolRewardsFunded {
+                schema_version: EVENT_SCHEMA_VERSION,
+                depositor: sample_pubkey(5),
+                lamports: 2_000_000_000,
+                sol_rewards_per_block: 500_000_000_000_000_000,
+            },
+        );
+    }
+
+    #[test]
+    fn test_reward_solvency_verified_layout() {
+        assert_golden(
+            "reward_solvency_verified",
+            &RewardSolvencyVerified {
+                schema_version: EVENT_SCHEMA_VERSION,
+                parcels: 12,
+                outstanding: 4_200_000,
+                pool_balance: 5_000_000,
+            },
+        );
+    }
+
+    #[test]
+    fn test_solvency_warning_layout() {
+        assert_golden(
+            "solvency_warning",
+            &SolvencyWarning {
+                schema_version: EVENT_SCHEMA_VERSION,
+                outstanding: 5_000_001,
+                pool_balance: 5_000_000,
+                deficit: 1,
+            },
+        );
+    }
+
+    #[test]
+    fn test_ring_manually_unlocked_layout() {
+        assert_golden(
+            "ring_manually_unlocked",
+            &RingManuallyUnlocked {
+                schema_version: EVENT_SCHEMA_VERSION,
+                authority: Pubkey::new_from_array([1u8; 32]),
+                previous_ring: 2,
+                ring: 4,
+                total_burned_before: 12_000_000,
+                total_burned_after: 100_000_000,
+            },
+        );
+    }
+
+    #[test]
+    fn test_config_initialized_layout() {
+        assert_golden(
+            "config_initialized",
+            &ConfigInitialized {
+                schema_version: EVENT_SCHEMA_VERSION,
+                price_per_block: 1_000_000,
+                ring_thresholds: vec![0, 10_000_000],
+                uri_base: "https://example.com/parcel/".to_string(),
+                seeding_enabled: true,
+                collection: sample_pubkey(1),
+                land_owners_reward_share_bps: 2_000,
+                max_parcel_blocks: 64,
+            },
+        );
+    }
+
+    #[test]
+    fn test_config_updated_layout() {
+        assert_golden(
+            "config_updated",
+            &ConfigUpdated {
+                schema_version: EVENT_SCHEMA_VERSION,
+                authority: sample_pubkey(1),
+                changed_fields: CONFIG_FIELD_PRICE_PER_BLOCK | CONFIG_FIELD_URI_BASE,
+                old_price_per_block: 1_000_000,
+                new_price_per_block: 2_000_000,
+                old_land_owners_reward_share_bps: 2_000,
+                new_land_owners_reward_share_bps: 2_000,
+                old_seeding_enabled: true,
+                new_seeding_enabled: true,
+                old_collection: sample_pubkey(2),
+                new_collection: sample_pubkey(2),
+                old_uri_base: "https://example.com/parcel/".to_string(),
+                new_uri_base: "https://example.org/parcel/".to_string(),
+                old_ring_thresholds_hash: [3u8; 32],
+                new_ring_thresholds_hash: [3u8; 32],
+            },
+        );
+    }
+
+    #[test]
+    fn test_treasury_paid_layout() {
+        assert_golden(
+            "treasury_paid",
+            &TreasuryPaid {
+                schema_version: EVENT_SCHEMA_VERSION,
+                parcel_id: 42,
+                epoch: 1,
+                claimer: sample_pubkey(1),
+                treasury: sample_pubkey(2),
+                amount: 150_000,
+            },
+        );
+    }
+
+    #[test]
+    fn test_config_update_queued_layout() {
+        assert_golden(
+            "config_update_queued",
+            &ConfigUpdateQueued { schema_version: EVENT_SCHEMA_VERSION, change_hash: [7u8; 32], ready_at: 1_700_086_400 },
+        );
+    }
+
+    #[test]
+    fn test_parcel_seeded_layout() {
+        assert_golden(
+            "parcel_seeded",
+            &ParcelSeeded {
+                schema_version: EVENT_SCHEMA_VERSION,
+                parcel_id: 7,
+                epoch: 1,
+                recipient: sample_pubkey(5),
+                asset: sample_pubkey(4),
+                x: 10,
+                y: 20,
+                width: 2,
+                height: 2,
+                total_burned: 50_000_000,
+            },
+        );
+    }
+
+    #[test]
+    fn test_config_frozen_layout() {
+        assert_golden(
+            "config_frozen",
+            &ConfigFrozen {
+                schema_version: EVENT_SCHEMA_VERSION,
+                authority: sample_pubkey(1),
+                frozen_at: 1_700_000_000,
+            },
+        );
+    }
+
+    #[test]
+    fn test_rewards_confiscated_layout() {
+        assert_golden(
+            "rewards_confiscated",
+            &RewardsConfiscated {
+                schema_version: EVENT_SCHEMA_VERSION,
+                authority: sample_pubkey(1),
+                reward_liability: 1_200_000,
+                amount: 1_150_000,
+            },
+        );
+    }
+
+    #[test]
+    fn test_blocks_reserved_layout() {
+        assert_golden(
+            "blocks_reserved",
+            &BlocksReserved {
+                schema_version: EVENT_SCHEMA_VERSION,
+                authority: sample_pubkey(1),
+                epoch: 1,
+                x: 45,
+                y: 0,
+                width: 10,
+                height: 100,
+            },
+        );
+    }
+
+    #[test]
+    fn test_blocks_unreserved_layout() {
+        assert_golden(
+            "blocks_unreserved",
+            &BlocksUnreserved {
+                schema_version: EVENT_SCHEMA_VERSION,
+                authority: sample_pubkey(1),
+                epoch: 1,
+                x: 45,
+                y: 0,
+                width: 10,
+                height: 100,
+            },
+        );
+    }
+
+    #[test]
+    fn test_ring_occupancy_updated_layout() {
+        assert_golden(
+            "ring_occupancy_updated",
+            &RingOccupancyUpdated {
+                schema_version: EVENT_SCHEMA_VERSION,
+                epoch: 1,
+                claimed_blocks_per_ring: [400, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+                complete: true,
+            },
+        );
+    }
+
+    #[test]
+    fn test_map_updated_layout() {
+        assert_golden(
+            "map_updated",
+            &MapUpdated {
+                schema_version: EVENT_SCHEMA_VERSION,
+                epoch: 1,
+                parcel_id: 7,
+                x: 45,
+                y: 0,
+                width: 10,
+                height: 2,
+                map_version: 3,
+                map_hash: [9; 32],
+            },
+        );
+    }
+
+    #[test]
+    fn test_referral_share_paid_layout() {
+        assert_golden(
+            "referral_share_paid",
+            &ReferralSharePaid {
+                schema_version: EVENT_SCHEMA_VERSION,
+                parcel_id: 42,
+                epoch: 1,
+                claimer: sample_pubkey(1),
+                referrer: sample_pubkey(2),
+                referrer_token_account: sample_pubkey(3),
+                amount: 100_000,
+            },
+        );
+    }
+
+    #[test]
+    fn test_ring_unlocked_layout() {
+        assert_golden(
+            "ring_unlocked",
+            &RingUnlocked {
+                schema_version: EVENT_SCHEMA_VERSION,
+                epoch: 1,
+                new_ring: 3,
+                rings_crossed: 2,
+                total_burned: 53_200_000,
+                triggering_parcel_id: 7,
+            },
+        );
+    }
+
+    #[test]
+    fn test_parcel_renamed_layout() {
+        assert_golden(
+            "parcel_renamed",
+            &ParcelRenamed {
+                schema_version: EVENT_SCHEMA_VERSION,
+                parcel_id: 7,
+                epoch: 1,
+                owner: sample_pubkey(3),
+                name: "Harbor View".to_string(),
+                uri: "https://example.com/parcels/7-harbor".to_string(),
+                fee: 1_000_000,
+            },
+        );
+    }
+
+    #[test]
+    fn test_parcel_content_updated_layout() {
+        assert_golden(
+            "parcel_content_updated",
+            &ParcelContentUpdated {
+                schema_version: EVENT_SCHEMA_VERSION,
+                parcel_id: 7,
+                epoch: 1,
+                owner: sample_pubkey(3),
+                content_hash: [0xab; 32],
+            },
+        );
+    }
+
+    #[test]
+    fn test_parcel_pixels_updated_layout() {
+        assert_golden(
+            "parcel_pixels_updated",
+            &ParcelPixelsUpdated {
+                schema_version: EVENT_SCHEMA_VERSION,
+                parcel_id: 7,
+                epoch: 1,
+                owner: sample_pubkey(3),
+                cleared: false,
+            },
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::state::{grid_seed, AdminRoles, GridConfig, ConfigFreeze, RingUnlockSchedule};
+use crate::errors::BillionError;
+use crate::events::{RingManuallyUnlocked, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct AdminAdvanceRing<'info> {
+    /// The config authority
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// Names the config authority; the main authority holds every role until it's created
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [AdminRoles::SEED, &grid_seed(grid_id)], bump)]
+    pub admin_roles: UncheckedAccount<'info>,
+
+    /// Times rings open whatever has been burned; rings unlock by burns alone while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [RingUnlockSchedule::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_unlock_schedule: UncheckedAccount<'info>,
+
+    /// Must still be empty: freeze_config creating it disables this instruction for good
+    /// CHECK: Seeds are verified here, only whether it exists is read
+    #[account(
+        seeds = [ConfigFreeze::SEED, &grid_seed(grid_id)],
+        bump,
+        constraint = !ConfigFreeze::is_frozen(&config_freeze) @ BillionError::ConfigFrozen
+    )]
+    pub config_freeze: UncheckedAccount<'info>,
+}
+
+/// Unlocks every ring up to `ring` without the burns, by raising total_burned to its
+/// threshold. The only way to move total_burned other than burning; every use is logged
+/// through RingManuallyUnlocked.
+pub fn handler(ctx: Context<AdminAdvanceRing>, _grid_id: u64, ring: u8) -> Result<()> {
+    let roles = AdminRoles::load(&ctx.accounts.admin_roles, &ctx.accounts.grid_config)?;
+    require_keys_eq!(roles.config_authority, ctx.accounts.authority.key(), BillionError::Unauthorized);
+
+    let now = Clock::get()?.unix_timestamp;
+    let schedule = RingUnlockSchedule::load(&ctx.accounts.ring_unlock_schedule)?;
+    let config = &mut ctx.accounts.grid_config;
+    require!((1..=config.ring_count()).contains(&ring), BillionError::InvalidRing);
+    let previous_ring = config.unlocked_ring_at(&schedule, now);
+    require!(ring > previous_ring, BillionError::RingAlreadyUnlocked);
+
+    let total_burned_before = config.total_burned;
+    config.total_burned = config.ring_thresholds[(ring - 1) as usize];
+    // Rings sharing the target's threshold unlock with it
+    let unlocked = config.unlocked_ring_at(&schedule, now);
+    config.record_ring_unlock(previous_ring, unlocked, now);
+
+    emit!(RingManuallyUnlocked {
+        schema_version: EVENT_SCHEMA_VERSION,
+        authority: ctx.accounts.authority.key(),
+        previous_ring,
+        ring: unlocked,
+        total_burned_before,
+        total_burned_after: config.total_burned,
+    });
+    msg!(
+        "Manually unlocked ring {} (was {}), total_burned {} -> {}",
+        unlocked,
+        previous_ring,
+        total_burned_before,
+        config.total_burned
+    );
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use crate::constants::MAX_OCCUPANCY_SCAN_BLOCKS;
+use crate::state::{grid_seed, AdminRoles, BlockMap, GridConfig, RingOccupancy};
+use crate::errors::BillionError;
+use crate::events::{RingOccupancyUpdated, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct AdminBackfillRingOccupancy<'info> {
+    /// The config authority, paying for the counts' account on the first call
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// BlockMap address must match the one stored in grid_config
+    #[account(
+        constraint = block_map.key() == grid_config.block_map @ BillionError::Unauthorized
+    )]
+    pub block_map: AccountLoader<'info, BlockMap>,
+
+    /// Created on the first call for a grid initialized before it existed
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + RingOccupancy::INIT_SPACE,
+        seeds = [RingOccupancy::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub ring_occupancy: Account<'info, RingOccupancy>,
+
+    /// Names the config authority; the main authority holds every role until it's created
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [AdminRoles::SEED, &grid_seed(grid_id)], bump)]
+    pub admin_roles: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Counts the next MAX_OCCUPANCY_SCAN_BLOCKS blocks of the BlockMap into RingOccupancy, for
+/// grids initialized before the counts existed. Call it until RingOccupancyUpdated reports
+/// `complete`; claims in between count their blocks only where the scan has already been.
+/// `restart` drops the counts and scans again from the first block.
+pub fn handler(ctx: Context<AdminBackfillRingOccupancy>, _grid_id: u64, restart: bool) -> Result<()> {
+    let roles = AdminRoles::load(&ctx.accounts.admin_roles, &ctx.accounts.grid_config)?;
+    require_keys_eq!(roles.config_authority, ctx.accounts.authority.key(), BillionError::Unauthorized);
+
+    // A just-created account is all zeros, which only a fresh scan can follow
+    let epoch = ctx.accounts.grid_config.epoch;
+    let occupancy = &mut ctx.accounts.ring_occupancy;
+    if restart || occupancy.bump == 0 {
+        **occupancy = RingOccupancy::new(epoch, 0, ctx.bumps.ring_occupancy);
+    }
+
+    let ring_count = ctx.accounts.grid_config.ring_count();
+    let block_map = BlockMap::load(&ctx.accounts.block_map)?;
+    let parcel_id_at = |x, y| block_map.get_block(x, y);
+    occupancy
+        .scan(epoch, parcel_id_at, block_map.grid_size(), ring_count, MAX_OCCUPANCY_SCAN_BLOCKS)
+        .ok_or(BillionError::Overflow)?;
+
+    emit!(RingOccupancyUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        epoch,
+        claimed_blocks_per_ring: occupancy.claimed_blocks_per_ring,
+        complete: occupancy.is_complete(),
+    });
+    msg!("Ring occupancy counted to block {}: {:?}", occupancy.counted_to, occupancy.claimed_blocks_per_ring);
+    Ok(())
+}
+
+/// Counts `blocks` of a `grid_size` grid of `ring_count` rings as claimed (or freed) in
+/// `account` and publishes the new counts. A grid whose RingOccupancy hasn't been created
+/// has nothing to keep current.
+pub(crate) fn record_ring_occupancy(
+    account: &AccountInfo,
+    epoch: u16,
+    blocks: impl Iterator<Item = (u8, u8)>,
+    grid_size: u16,
+    ring_count: u8,
+    claimed: bool,
+) -> Result<()> {
+    if account.data_is_empty() {
+        return Ok(());
+    }
+    let mut occupancy = RingOccupancy::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    occupancy.record(epoch, blocks, grid_size, ring_count, claimed).ok_or(BillionError::Overflow)?;
+    occupancy.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+    emit!(RingOccupancyUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        epoch,
+        claimed_blocks_per_ring: occupancy.claimed_blocks_per_ring,
+        complete: occupancy.is_complete(),
+    });
+    Ok(())
+}
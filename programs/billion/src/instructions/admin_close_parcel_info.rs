@@ -1,32 +1,159 @@
 use anchor_lang::prelude::*;
+use billion_core::Rect;
 
 use crate::errors::BillionError;
-use crate::state::{GridConfig, ParcelInfo};
+use crate::instructions::admin_backfill_ring_occupancy::record_ring_occupancy;
+use crate::instructions::claim_land_buy_rewards::pending_land_buy_rewards;
+use crate::instructions::init_map_digest::record_map_write;
+use crate::state::{
+    epoch_seed, grid_seed, BlockMap, GridConfig, ParcelIdPool, ParcelInfo, RewardExclusions, StakeAccount, RingRewardWeights, RingOccupancy, MapDigest,
+    ConfigFreeze, Fraction,
+};
 
 #[derive(Accounts)]
-#[instruction(parcel_id: u16)]
+#[instruction(grid_id: u64, parcel_id: u16)]
 pub struct AdminCloseParcelInfo<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
     #[account(
-        seeds = [GridConfig::SEED],
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
         bump = grid_config.bump,
         has_one = authority @ BillionError::Unauthorized,
     )]
     pub grid_config: Account<'info, GridConfig>,
 
+    /// Current BlockMap - a current-epoch parcel's cells are freed
+    #[account(
+        mut,
+        constraint = block_map.key() == grid_config.block_map @ BillionError::Unauthorized
+    )]
+    pub block_map: AccountLoader<'info, BlockMap>,
+
     #[account(
         mut,
-        seeds = [ParcelInfo::SEED, &parcel_id.to_le_bytes()],
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
         bump = parcel_info.bump,
         close = authority,
     )]
     pub parcel_info: Account<'info, ParcelInfo>,
+
+    /// Must be empty: a staked parcel's id can't be handed to a new parcel
+    /// CHECK: Only its emptiness is read
+    #[account(
+        seeds = [StakeAccount::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump
+    )]
+    pub stake_account: UncheckedAccount<'info>,
+
+    /// Must be empty: a fraction keyed by the id would harvest the rewards of whoever reuses it
+    /// CHECK: Only its emptiness is read
+    #[account(
+        seeds = [Fraction::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump
+    )]
+    pub fraction: UncheckedAccount<'info>,
+
+    /// Receives the freed id
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ParcelIdPool::INIT_SPACE,
+        seeds = [ParcelIdPool::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub parcel_id_pool: Account<'info, ParcelIdPool>,
+
+    /// Counts the parcel's blocks while it's excluded from land-buy rewards
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler for excluded parcels
+    #[account(mut, seeds = [RewardExclusions::SEED, &grid_seed(grid_id)], bump)]
+    pub reward_exclusions: UncheckedAccount<'info>,
+
+    /// Per-ring land-buy reward weights, which stop counting the parcel's extra weight
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(mut, seeds = [RingRewardWeights::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_reward_weights: UncheckedAccount<'info>,
+
+    /// Claimed blocks per ring, which stop counting the parcel's freed blocks
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(mut, seeds = [RingOccupancy::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_occupancy: UncheckedAccount<'info>,
+
+    /// Rolling digest of BlockMap writes, which chains in the freed blocks
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(mut, seeds = [MapDigest::SEED, &grid_seed(grid_id)], bump)]
+    pub map_digest: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Must still be empty: freeze_config creating it disables this instruction for good
+    /// CHECK: Seeds are verified here, only whether it exists is read
+    #[account(
+        seeds = [ConfigFreeze::SEED, &grid_seed(grid_id)],
+        bump,
+        constraint = !ConfigFreeze::is_frozen(&config_freeze) @ BillionError::ConfigFrozen
+    )]
+    pub config_freeze: UncheckedAccount<'info>,
 }
 
-pub fn handler(_ctx: Context<AdminCloseParcelInfo>, _parcel_id: u16) -> Result<()> {
-    // Account is closed automatically by the `close = authority` constraint
-    msg!("Closed ParcelInfo for parcel_id: {}", _parcel_id);
+/// Closes a parcel's ParcelInfo. A parcel from the current epoch also gives up its cells,
+/// and its id goes into the ParcelIdPool for claim_parcel to reuse; its asset, if it still
+/// exists, no longer maps to anything on the grid. Its unclaimed land-buy rewards are
+/// forfeited to the pool's surplus.
+pub fn handler(ctx: Context<AdminCloseParcelInfo>, _grid_id: u64, parcel_id: u16) -> Result<()> {
+    let parcel_info = &ctx.accounts.parcel_info;
+    let grid_config = &mut ctx.accounts.grid_config;
+    if parcel_info.epoch == grid_config.epoch {
+        let forfeited = pending_land_buy_rewards(parcel_info, grid_config.land_buy_rewards_per_block)?;
+        grid_config.pay_land_buy_rewards(forfeited);
+    }
+    let mut block_map = BlockMap::load_mut(&ctx.accounts.block_map)?;
+    if parcel_info.epoch != grid_config.epoch || block_map.is_frozen() {
+        // Account is closed automatically by the `close = authority` constraint
+        msg!("Closed ParcelInfo for parcel_id: {}", parcel_id);
+        return Ok(());
+    }
+    require!(ctx.accounts.stake_account.data_is_empty(), BillionError::ParcelStaked);
+    require!(ctx.accounts.fraction.data_is_empty(), BillionError::ParcelFractionalized);
+
+    let mut freed = Vec::new();
+    for dy in 0..parcel_info.height {
+        for dx in 0..parcel_info.width {
+            let (x, y) = (parcel_info.x + dx, parcel_info.y + dy);
+            if block_map.get_block(x, y) == parcel_id {
+                block_map.set_block(x, y, 0);
+                freed.push((x, y));
+            }
+        }
+    }
+    let freed_blocks = freed.len() as u32;
+    let grid_size = block_map.grid_size();
+    let ring_count = grid_config.ring_count();
+    let epoch = grid_config.epoch;
+    record_ring_occupancy(&ctx.accounts.ring_occupancy, epoch, freed.into_iter(), grid_size, ring_count, false)?;
+    let rect = Rect::new(parcel_info.x, parcel_info.y, parcel_info.width, parcel_info.height);
+    record_map_write(&ctx.accounts.map_digest, epoch, 0, rect)?;
+    if parcel_info.rewards_excluded {
+        RewardExclusions::release(&ctx.accounts.reward_exclusions, grid_config.epoch, freed_blocks)?;
+    } else {
+        grid_config.total_claimed_blocks = grid_config
+            .total_claimed_blocks
+            .checked_sub(freed_blocks)
+            .ok_or(BillionError::Overflow)?;
+        RingRewardWeights::record_extra(
+            &ctx.accounts.ring_reward_weights,
+            grid_config.epoch,
+            -parcel_info.extra_weight(freed_blocks),
+        )?;
+    }
+
+    let pool = &mut ctx.accounts.parcel_id_pool;
+    pool.bump = ctx.bumps.parcel_id_pool;
+    if pool.release(grid_config.epoch, parcel_id) {
+        msg!("Closed ParcelInfo for parcel_id: {}, freed {} blocks and the id", parcel_id, freed_blocks);
+    } else {
+        msg!("Closed ParcelInfo for parcel_id: {}, freed {} blocks; id pool is full", parcel_id, freed_blocks);
+    }
     Ok(())
 }
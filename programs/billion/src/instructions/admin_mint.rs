@@ -1,11 +1,22 @@
 use anchor_lang::prelude::*;
+use std::ops::Deref;
+use billion_core::Rect;
 use mpl_core::instructions::CreateV2CpiBuilder;
-use crate::state::{GridConfig, BlockMap, ParcelInfo, GRID_SIZE};
+use mpl_core::types::{FreezeDelegate, PermanentFreezeDelegate, Plugin, PluginAuthority, PluginAuthorityPair};
+use crate::state::{
+    epoch_seed, grid_seed, AdminMintAllowance, GridConfig, BlockMap, ParcelInfo, RewardExclusions, RingRewardWeights, RingOccupancy, MapDigest,
+    BlockMapData, ConfigFreeze,
+};
 use crate::errors::BillionError;
+use crate::events::{ParcelSeeded, ParcelVested, EVENT_SCHEMA_VERSION};
+use crate::instructions::refresh_parcel_attributes::parcel_attributes_plugin;
+use crate::instructions::admin_backfill_ring_occupancy::record_ring_occupancy;
+use crate::instructions::init_map_digest::record_map_write;
+#[cfg(not(feature = "localnet"))]
 use crate::instructions::claim_parcel::MPL_CORE_ID;
 
 #[derive(Accounts)]
-#[instruction(x: u8, y: u8, width: u8, height: u8)]
+#[instruction(grid_id: u64, x: u8, y: u8, width: u8, height: u8)]
 pub struct AdminMint<'info> {
     #[account(
         mut,
@@ -18,7 +29,7 @@ pub struct AdminMint<'info> {
 
     #[account(
         mut,
-        seeds = [GridConfig::SEED],
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
         bump = grid_config.bump
     )]
     pub grid_config: Account<'info, GridConfig>,
@@ -35,7 +46,7 @@ pub struct AdminMint<'info> {
         init,
         payer = authority,
         space = 8 + ParcelInfo::INIT_SPACE,
-        seeds = [ParcelInfo::SEED, &grid_config.next_parcel_id.to_le_bytes()],
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(grid_config.epoch), &grid_config.next_parcel_id.to_le_bytes()],
         bump
     )]
     pub parcel_info: Account<'info, ParcelInfo>,
@@ -52,31 +63,74 @@ pub struct AdminMint<'info> {
     )]
     pub collection: UncheckedAccount<'info>,
 
-    /// CHECK: Metaplex Core program
-    #[account(address = MPL_CORE_ID)]
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
     pub mpl_core_program: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Mints left after seeding is disabled. Uninitialized until the authority first calls
+    /// update_config, in which case there are none.
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(mut, seeds = [AdminMintAllowance::SEED, &grid_seed(grid_id)], bump)]
+    pub admin_mint_allowance: UncheckedAccount<'info>,
+
+    /// Counts the blocks of parcels minted with `rewards_excluded`
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + RewardExclusions::INIT_SPACE,
+        seeds = [RewardExclusions::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub reward_exclusions: Box<Account<'info, RewardExclusions>>,
+
+    /// Per-ring land-buy reward weights, which count the parcel's extra weight; every block
+    /// is unweighted while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(mut, seeds = [RingRewardWeights::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_reward_weights: UncheckedAccount<'info>,
+
+    /// Claimed blocks per ring, which count the parcel's blocks; nothing is counted until
+    /// it's created
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(mut, seeds = [RingOccupancy::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_occupancy: UncheckedAccount<'info>,
+
+    /// Rolling digest of BlockMap writes, which chains in the parcel's blocks
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(mut, seeds = [MapDigest::SEED, &grid_seed(grid_id)], bump)]
+    pub map_digest: UncheckedAccount<'info>,
+
+    /// Must still be empty: freeze_config creating it disables this instruction for good
+    /// CHECK: Seeds are verified here, only whether it exists is read
+    #[account(
+        seeds = [ConfigFreeze::SEED, &grid_seed(grid_id)],
+        bump,
+        constraint = !ConfigFreeze::is_frozen(&config_freeze) @ BillionError::ConfigFrozen
+    )]
+    pub config_freeze: UncheckedAccount<'info>,
 }
 
 /// Validates that the admin mint is valid (no ring check, just bounds and unclaimed)
-fn validate_admin_mint(
+pub(crate) fn validate_admin_mint(
     x: u8,
     y: u8,
     width: u8,
     height: u8,
-    block_map: &BlockMap,
+    block_map: &BlockMapData<impl Deref<Target = [u8]>>,
 ) -> Result<()> {
     // Check dimensions are valid
     require!(width > 0 && height > 0, BillionError::InvalidDimensions);
 
     // Check bounds
     require!(
-        (x as usize) + (width as usize) <= GRID_SIZE,
+        (x as u16) + (width as u16) <= block_map.grid_size(),
         BillionError::OutOfBounds
     );
     require!(
-        (y as usize) + (height as usize) <= GRID_SIZE,
+        (y as u16) + (height as u16) <= block_map.grid_size(),
         BillionError::OutOfBounds
     );
 
@@ -95,12 +149,43 @@ fn validate_admin_mint(
     Ok(())
 }
 
+/// With `locked_until` set, the asset is minted frozen under a FreezeDelegate owned by the
+/// GridConfig PDA until unlock_vested_parcel thaws it after that date. `lock_rewards`
+/// additionally holds back land-buy reward claims until then. A `soulbound` asset is minted
+/// under a frozen PermanentFreezeDelegate instead, which only admin_thaw_parcel lifts. A
+/// `rewards_excluded` parcel stays out of total_claimed_blocks and accrues no land-buy
+/// rewards until admin_set_rewards_excluded includes it.
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<AdminMint>,
+    grid_id: u64,
+    x: u8,
+    y: u8,
+    width: u8,
+    height: u8,
+    locked_until: Option<i64>,
+    lock_rewards: bool,
+    soulbound: bool,
+    rewards_excluded: bool,
+) -> Result<()> {
+    mint_parcel(ctx, grid_id, x, y, width, height, locked_until, lock_rewards, soulbound, false, rewards_excluded)
+}
+
+/// admin_mint, or with `reserved` set admin_reserve_parcel: the asset goes to the GridConfig
+/// PDA instead of the recipient account, and the ParcelInfo is flagged until released
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn mint_parcel(
+    ctx: Context<AdminMint>,
+    grid_id: u64,
     x: u8,
     y: u8,
     width: u8,
     height: u8,
+    locked_until: Option<i64>,
+    lock_rewards: bool,
+    soulbound: bool,
+    reserved: bool,
+    rewards_excluded: bool,
 ) -> Result<()> {
     // Validate collection is set
     require!(
@@ -109,15 +194,26 @@ pub fn handler(
     );
 
     let grid_config = &ctx.accounts.grid_config;
+    let ring_count = grid_config.ring_count();
 
-    // Check seeding is enabled
-    require!(grid_config.seeding_enabled, BillionError::SeedingDisabled);
+    // After seeding is disabled, only the promotional allowance can mint
+    let allowance_left = AdminMintAllowance::charge(&ctx.accounts.admin_mint_allowance, grid_config.seeding_enabled, 1)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    if let Some(locked_until) = locked_until {
+        require!(locked_until > now, BillionError::InvalidVestingDate);
+    }
+    require!(!lock_rewards || locked_until.is_some(), BillionError::InvalidVestingDate);
 
     // Validate the admin mint (bounds and unclaimed only, no ring check)
-    {
-        let block_map = ctx.accounts.block_map.load()?;
+    let grid_size = {
+        let block_map = BlockMap::load(&ctx.accounts.block_map)?;
+        require!(!block_map.is_frozen(), BillionError::EpochFrozen);
         validate_admin_mint(x, y, width, height, &block_map)?;
-    }
+        block_map.grid_size()
+    };
+
+    require!(ctx.accounts.grid_config.has_parcel_ids(1), BillionError::ParcelIdExhausted);
 
     // Calculate number of blocks
     let num_blocks = (width as u32).checked_mul(height as u32).ok_or(BillionError::Overflow)?;
@@ -129,10 +225,16 @@ pub fn handler(
     let grid_config = &mut ctx.accounts.grid_config;
 
     // Update total claimed blocks (no reward distribution since no burn)
-    grid_config.total_claimed_blocks = grid_config
-        .total_claimed_blocks
-        .checked_add(num_blocks)
-        .ok_or(BillionError::Overflow)?;
+    if rewards_excluded {
+        let exclusions = &mut ctx.accounts.reward_exclusions;
+        exclusions.bump = ctx.bumps.reward_exclusions;
+        exclusions.add(grid_config.epoch, num_blocks).ok_or(BillionError::Overflow)?;
+    } else {
+        grid_config.total_claimed_blocks = grid_config
+            .total_claimed_blocks
+            .checked_add(num_blocks)
+            .ok_or(BillionError::Overflow)?;
+    }
 
     grid_config.next_parcel_id = grid_config
         .next_parcel_id
@@ -142,10 +244,12 @@ pub fn handler(
     // Store values needed for CPI and ParcelInfo
     let uri_base = grid_config.uri_base.clone();
     let current_rewards_per_block = grid_config.land_buy_rewards_per_block;
+    let epoch = grid_config.epoch;
+    let collection_generation = grid_config.collection_generation;
 
     // Assign parcel_id to all blocks
     {
-        let mut block_map = ctx.accounts.block_map.load_mut()?;
+        let mut block_map = BlockMap::load_mut(&ctx.accounts.block_map)?;
         for dy in 0..height {
             for dx in 0..width {
                 block_map.set_block(x + dx, y + dy, parcel_id);
@@ -159,21 +263,47 @@ pub fn handler(
 
     // Get the grid_config bump for PDA signing (collection authority is the GridConfig PDA)
     let bump = ctx.accounts.grid_config.bump;
-    let seeds: &[&[u8]] = &[GridConfig::SEED, &[bump]];
+    let grid_bytes = grid_seed(grid_id);
+    let seeds: &[&[u8]] = &[GridConfig::SEED, &grid_bytes, &[bump]];
     let signer_seeds: &[&[&[u8]]] = &[seeds];
 
-    CreateV2CpiBuilder::new(&ctx.accounts.mpl_core_program.to_account_info())
-        .asset(&ctx.accounts.asset.to_account_info())
-        .collection(Some(&ctx.accounts.collection.to_account_info()))
-        .authority(Some(&ctx.accounts.grid_config.to_account_info()))
-        .payer(&ctx.accounts.authority.to_account_info())
-        .owner(Some(&ctx.accounts.recipient.to_account_info()))
-        .system_program(&ctx.accounts.system_program.to_account_info())
+    let mpl_core_program = ctx.accounts.mpl_core_program.to_account_info();
+    let asset = ctx.accounts.asset.to_account_info();
+    let collection = ctx.accounts.collection.to_account_info();
+    let grid_config = ctx.accounts.grid_config.to_account_info();
+    let authority = ctx.accounts.authority.to_account_info();
+    let recipient = if reserved { grid_config.clone() } else { ctx.accounts.recipient.to_account_info() };
+    let system_program = ctx.accounts.system_program.to_account_info();
+
+    let mut create = CreateV2CpiBuilder::new(&mpl_core_program);
+    create
+        .asset(&asset)
+        .collection(Some(&collection))
+        .authority(Some(&grid_config))
+        .payer(&authority)
+        .owner(Some(&recipient))
+        .system_program(&system_program)
         .name(name.clone())
-        .uri(uri.clone())
-        .invoke_signed(signer_seeds)?;
+        .uri(uri.clone());
+    let mut plugins = vec![parcel_attributes_plugin(grid_config.key(), parcel_id, x, y, width, height)];
+    // Frozen from birth under the GridConfig PDA, so the recipient can't move it before vesting
+    if locked_until.is_some() {
+        plugins.push(PluginAuthorityPair {
+            plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: true }),
+            authority: Some(PluginAuthority::Address { address: grid_config.key() }),
+        });
+    }
+    if soulbound {
+        plugins.push(PluginAuthorityPair {
+            plugin: Plugin::PermanentFreezeDelegate(PermanentFreezeDelegate { frozen: true }),
+            authority: Some(PluginAuthority::Address { address: grid_config.key() }),
+        });
+    }
+    create.plugins(plugins);
+    create.invoke_signed(signer_seeds)?;
 
     // Initialize ParcelInfo
+    let weights = RingRewardWeights::load(&ctx.accounts.ring_reward_weights)?;
     let parcel_info = &mut ctx.accounts.parcel_info;
     parcel_info.asset = ctx.accounts.asset.key();
     parcel_info.x = x;
@@ -182,17 +312,70 @@ pub fn handler(
     parcel_info.height = height;
     parcel_info.bump = ctx.bumps.parcel_info;
     parcel_info.last_claimed_land_buy_rewards_per_block = current_rewards_per_block;
-    parcel_info._reserved = [0u8; 48];
+    parcel_info.epoch = epoch;
+    parcel_info.last_claimed_at = now;
+    parcel_info.adjacency_score = 0;
+    parcel_info.locked_until = locked_until.unwrap_or(0);
+    parcel_info.lock_rewards = lock_rewards;
+    parcel_info.named = false;
+    parcel_info.premium_until = 0;
+    parcel_info.collection_generation = collection_generation;
+    parcel_info.masked_block_count = 0;
+    parcel_info.reserved = reserved;
+    parcel_info.rewards_excluded = rewards_excluded;
+    parcel_info.paid_total = 0;
+    parcel_info.claimed_at = ParcelInfo::claim_timestamp(now);
+    parcel_info.reward_weight = weights
+        .parcel_weight(Rect::new(x, y, width, height).blocks(), grid_size, ring_count)
+        .ok_or(BillionError::Overflow)?;
+    parcel_info._reserved = [0u8; 0];
+    // An excluded parcel's weight is counted once admin_set_rewards_excluded includes it
+    if !rewards_excluded {
+        let extra_weight = parcel_info.extra_weight(num_blocks);
+        RingRewardWeights::record_extra(&ctx.accounts.ring_reward_weights, epoch, extra_weight)?;
+    }
+    // Excluded or not, the blocks are claimed
+    let blocks = Rect::new(x, y, width, height).blocks();
+    record_ring_occupancy(&ctx.accounts.ring_occupancy, epoch, blocks, grid_size, ring_count, true)?;
+    record_map_write(&ctx.accounts.map_digest, epoch, parcel_id, Rect::new(x, y, width, height))?;
 
+    if let Some(remaining) = allowance_left {
+        msg!("Admin mint allowance: {} remaining", remaining);
+    }
     msg!(
         "Admin minted parcel {} to {} at ({}, {}) with dimensions {}x{}",
         parcel_id,
-        ctx.accounts.recipient.key(),
+        recipient.key(),
         x,
         y,
         width,
         height
     );
 
+    emit!(ParcelSeeded {
+        schema_version: EVENT_SCHEMA_VERSION,
+        parcel_id,
+        epoch,
+        recipient: recipient.key(),
+        asset: ctx.accounts.asset.key(),
+        x,
+        y,
+        width,
+        height,
+        total_burned: ctx.accounts.grid_config.total_burned,
+    });
+
+    if let Some(locked_until) = locked_until {
+        emit!(ParcelVested {
+            schema_version: EVENT_SCHEMA_VERSION,
+            parcel_id,
+            epoch,
+            asset: ctx.accounts.asset.key(),
+            recipient: recipient.key(),
+            locked_until,
+            lock_rewards,
+        });
+    }
+
     Ok(())
 }
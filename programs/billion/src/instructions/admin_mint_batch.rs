@@ -0,0 +1,266 @@
+use anchor_lang::prelude::*;
+use mpl_core::instructions::CreateV2CpiBuilder;
+use billion_core::Rect;
+use crate::state::{epoch_seed, grid_seed, AdminMintAllowance, GridConfig, BlockMap, ParcelInfo, RingRewardWeights, RingOccupancy, MapDigest, ConfigFreeze};
+use crate::constants::{MAX_ADMIN_MINT_BATCH, REWARD_WEIGHT_UNIT};
+use crate::errors::BillionError;
+use crate::events::{ParcelSeeded, EVENT_SCHEMA_VERSION};
+use crate::instructions::admin_mint::validate_admin_mint;
+use crate::instructions::claim_parcels_batch::create_parcel_info;
+use crate::instructions::refresh_parcel_attributes::parcel_attributes_plugin;
+use crate::instructions::admin_backfill_ring_occupancy::record_ring_occupancy;
+use crate::instructions::init_map_digest::record_map_write;
+#[cfg(not(feature = "localnet"))]
+use crate::instructions::claim_parcel::MPL_CORE_ID;
+
+/// One parcel of an admin_mint_batch. The recipient is an index into the recipient accounts
+/// rather than a pubkey, which keeps a full batch inside the transaction size limit.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AdminMintEntry {
+    pub x: u8,
+    pub y: u8,
+    pub width: u8,
+    pub height: u8,
+    pub recipient_index: u8,
+}
+
+/// Same accounts as AdminMint minus the per-parcel ones, which come in remaining_accounts
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct AdminMintBatch<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == grid_config.authority @ BillionError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Box<Account<'info, GridConfig>>,
+
+    /// BlockMap address must match the one stored in grid_config
+    #[account(
+        mut,
+        constraint = block_map.key() == grid_config.block_map @ BillionError::Unauthorized
+    )]
+    pub block_map: AccountLoader<'info, BlockMap>,
+
+    /// Core collection - must match grid_config.collection
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        mut,
+        constraint = collection.key() == grid_config.collection @ BillionError::InvalidCollection
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Mints left after seeding is disabled, as in AdminMint
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(mut, seeds = [AdminMintAllowance::SEED, &grid_seed(grid_id)], bump)]
+    pub admin_mint_allowance: UncheckedAccount<'info>,
+
+    /// Per-ring land-buy reward weights, which count the parcels' extra weight; every block
+    /// is unweighted while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(mut, seeds = [RingRewardWeights::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_reward_weights: UncheckedAccount<'info>,
+
+    /// Claimed blocks per ring, which count the parcels' blocks; nothing is counted until
+    /// it's created
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(mut, seeds = [RingOccupancy::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_occupancy: UncheckedAccount<'info>,
+
+    /// Rolling digest of BlockMap writes, which chains in each parcel's blocks
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(mut, seeds = [MapDigest::SEED, &grid_seed(grid_id)], bump)]
+    pub map_digest: UncheckedAccount<'info>,
+
+    /// Must still be empty: freeze_config creating it disables this instruction for good
+    /// CHECK: Seeds are verified here, only whether it exists is read
+    #[account(
+        seeds = [ConfigFreeze::SEED, &grid_seed(grid_id)],
+        bump,
+        constraint = !ConfigFreeze::is_frozen(&config_freeze) @ BillionError::ConfigFrozen
+    )]
+    pub config_freeze: UncheckedAccount<'info>,
+}
+
+/// Seeds every entry as admin_mint would without a lock, taking consecutive parcel ids.
+/// remaining_accounts are, per entry in order, its Core asset (a signer) and its ParcelInfo
+/// PDA, followed by the recipients the entries index into. Like admin_mint there is no ring check;
+/// an entry overlapping a claimed block, or an earlier entry, reverts the whole batch.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, AdminMintBatch<'info>>,
+    grid_id: u64,
+    entries: Vec<AdminMintEntry>,
+) -> Result<()> {
+    require!(
+        !entries.is_empty() && entries.len() <= MAX_ADMIN_MINT_BATCH as usize,
+        BillionError::InvalidBatch
+    );
+    require!(
+        ctx.remaining_accounts.len() >= 2 * entries.len(),
+        BillionError::InvalidBatch
+    );
+    let (parcel_accounts, recipients) = ctx.remaining_accounts.split_at(2 * entries.len());
+
+    let grid_config = &mut ctx.accounts.grid_config;
+    let ring_count = grid_config.ring_count();
+    require!(
+        grid_config.collection != Pubkey::default(),
+        BillionError::CollectionNotSet
+    );
+    // After seeding is disabled, the allowance has to cover every entry
+    let allowance_left = AdminMintAllowance::charge(
+        &ctx.accounts.admin_mint_allowance,
+        grid_config.seeding_enabled,
+        entries.len() as u32,
+    )?;
+
+    // The last id handed out must not pass MAX_PARCEL_ID, as in admin_mint
+    let first_parcel_id = grid_config.next_parcel_id;
+    require!(grid_config.has_parcel_ids(entries.len()), BillionError::ParcelIdExhausted);
+
+    let mut block_count: u32 = 0;
+    let grid_size = {
+        let mut block_map = BlockMap::load_mut(&ctx.accounts.block_map)?;
+        require!(!block_map.is_frozen(), BillionError::EpochFrozen);
+        for (parcel_id, entry) in (first_parcel_id..).zip(&entries) {
+            let AdminMintEntry { x, y, width, height, .. } = *entry;
+            validate_admin_mint(x, y, width, height, &block_map)?;
+            for dy in 0..height {
+                for dx in 0..width {
+                    block_map.set_block(x + dx, y + dy, parcel_id);
+                }
+            }
+            block_count = block_count
+                .checked_add((width as u32) * (height as u32))
+                .ok_or(BillionError::Overflow)?;
+        }
+        block_map.grid_size()
+    };
+
+    // No burn, so no reward distribution; the blocks just start counting
+    grid_config.total_claimed_blocks = grid_config
+        .total_claimed_blocks
+        .checked_add(block_count)
+        .ok_or(BillionError::Overflow)?;
+    grid_config.next_parcel_id = first_parcel_id + entries.len() as u16;
+
+    let now = Clock::get()?.unix_timestamp;
+    let epoch = grid_config.epoch;
+    let collection_generation = grid_config.collection_generation;
+    let grid_bytes = grid_seed(grid_id);
+    let epoch_bytes = epoch_seed(epoch);
+    let grid_config_info = grid_config.to_account_info();
+    let grid_config_seeds: &[&[u8]] = &[GridConfig::SEED, &grid_bytes, &[grid_config.bump]];
+    let authority = ctx.accounts.authority.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let weights = RingRewardWeights::load(&ctx.accounts.ring_reward_weights)?;
+    let mut extra_weight = 0i64;
+
+    for ((parcel_id, entry), accounts) in (first_parcel_id..).zip(&entries).zip(parcel_accounts.chunks(2)) {
+        let (asset, parcel_info) = (&accounts[0], &accounts[1]);
+        require!(asset.is_signer, BillionError::InvalidBatch);
+        let recipient = recipients
+            .get(entry.recipient_index as usize)
+            .ok_or(BillionError::InvalidBatch)?;
+
+        let parcel_id_bytes = parcel_id.to_le_bytes();
+        let (expected, parcel_info_bump) =
+            Pubkey::find_program_address(&[ParcelInfo::SEED, &grid_bytes, &epoch_bytes, &parcel_id_bytes], &crate::ID);
+        require!(parcel_info.key() == expected, BillionError::InvalidBatch);
+
+        let AdminMintEntry { x, y, width, height, .. } = *entry;
+        let reward_weight = weights
+            .parcel_weight(Rect::new(x, y, width, height).blocks(), grid_size, ring_count)
+            .ok_or(BillionError::Overflow)?;
+        CreateV2CpiBuilder::new(&ctx.accounts.mpl_core_program.to_account_info())
+            .asset(asset)
+            .collection(Some(&ctx.accounts.collection.to_account_info()))
+            .authority(Some(&grid_config_info))
+            .payer(&authority)
+            .owner(Some(recipient))
+            .system_program(&system_program)
+            .name(format!("Parcel #{}", parcel_id))
+            .uri(format!("{}{}", grid_config.uri_base, parcel_id))
+            .plugins(vec![parcel_attributes_plugin(grid_config_info.key(), parcel_id, x, y, width, height)])
+            .invoke_signed(&[grid_config_seeds])?;
+
+        create_parcel_info(
+            &authority,
+            parcel_info,
+            &system_program,
+            &[ParcelInfo::SEED, &grid_bytes, &epoch_bytes, &parcel_id_bytes, &[parcel_info_bump]],
+            &ParcelInfo {
+                asset: asset.key(),
+                x,
+                y,
+                width,
+                height,
+                bump: parcel_info_bump,
+                last_claimed_land_buy_rewards_per_block: grid_config.land_buy_rewards_per_block,
+                epoch,
+                last_claimed_at: now,
+                adjacency_score: 0,
+                locked_until: 0,
+                lock_rewards: false,
+                named: false,
+                premium_until: 0,
+                collection_generation,
+                masked_block_count: 0,
+                reserved: false,
+                rewards_excluded: false,
+                paid_total: 0,
+                claimed_at: ParcelInfo::claim_timestamp(now),
+                reward_weight,
+                _reserved: [0u8; 0],
+            },
+        )?;
+        let weight_delta = reward_weight as i64 - REWARD_WEIGHT_UNIT as i64;
+        extra_weight = extra_weight
+            .checked_add((width as i64) * (height as i64) * weight_delta)
+            .ok_or(BillionError::Overflow)?;
+
+        emit!(ParcelSeeded {
+            schema_version: EVENT_SCHEMA_VERSION,
+            parcel_id,
+            epoch,
+            recipient: recipient.key(),
+            asset: asset.key(),
+            x,
+            y,
+            width,
+            height,
+            total_burned: grid_config.total_burned,
+        });
+    }
+    RingRewardWeights::record_extra(&ctx.accounts.ring_reward_weights, epoch, extra_weight)?;
+    let blocks = entries.iter().flat_map(|entry| Rect::new(entry.x, entry.y, entry.width, entry.height).blocks());
+    record_ring_occupancy(&ctx.accounts.ring_occupancy, epoch, blocks, grid_size, ring_count, true)?;
+    for (parcel_id, entry) in (first_parcel_id..).zip(&entries) {
+        let rect = Rect::new(entry.x, entry.y, entry.width, entry.height);
+        record_map_write(&ctx.accounts.map_digest, epoch, parcel_id, rect)?;
+    }
+
+    if let Some(remaining) = allowance_left {
+        msg!("Admin mint allowance: {} remaining", remaining);
+    }
+    msg!(
+        "Admin minted parcels {}..={} in one batch, {} blocks",
+        first_parcel_id,
+        first_parcel_id + entries.len() as u16 - 1,
+        block_count
+    );
+    Ok(())
+}
@@ -4,19 +4,23 @@ use anchor_spl::token_interface::{
     TransferChecked, CloseAccount,
 };
 
+use anchor_lang::system_program;
+
+use crate::constants::{PURGE_PAUSE_SLOTS, SOL_REWARD_VAULT_SEED};
 use crate::errors::BillionError;
-use crate::state::{GridConfig, LAND_BUY_REWARD_POOL_SEED};
+use crate::events::{RewardsConfiscated, EVENT_SCHEMA_VERSION};
+use crate::state::{grid_seed, GridConfig, AdminRoles, LAND_BUY_REWARD_POOL_SEED, ClaimsPause, ConfigFreeze};
 
 #[derive(Accounts)]
+#[instruction(grid_id: u64)]
 pub struct AdminPurge<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [GridConfig::SEED],
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
         bump = grid_config.bump,
-        has_one = authority @ BillionError::Unauthorized,
         close = authority,
     )]
     pub grid_config: Account<'info, GridConfig>,
@@ -50,21 +54,81 @@ pub struct AdminPurge<'info> {
     )]
     pub authority_token_account: InterfaceAccount<'info, TokenAccount>,
 
+    /// SOL reward vault to drain - a system account, so it's gone once empty
+    #[account(
+        mut,
+        seeds = [SOL_REWARD_VAULT_SEED, grid_config.key().as_ref()],
+        bump
+    )]
+    pub sol_reward_vault: SystemAccount<'info>,
+
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
+
+    /// Names the treasury authority; the main authority holds every role until it's created
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [AdminRoles::SEED, &grid_seed(grid_id)], bump)]
+    pub admin_roles: UncheckedAccount<'info>,
+
+    /// Must still be empty: freeze_config creating it disables this instruction for good
+    /// CHECK: Seeds are verified here, only whether it exists is read
+    #[account(
+        seeds = [ConfigFreeze::SEED, &grid_seed(grid_id)],
+        bump,
+        constraint = !ConfigFreeze::is_frozen(&config_freeze) @ BillionError::ConfigFrozen
+    )]
+    pub config_freeze: UncheckedAccount<'info>,
+
+    /// When claims were paused; the purge waits PURGE_PAUSE_SLOTS past it
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [ClaimsPause::SEED, &grid_seed(grid_id)], bump)]
+    pub claims_pause: UncheckedAccount<'info>,
+}
+
+/// What admin_purge and admin_purge_parcels both need: claims paused for PURGE_PAUSE_SLOTS and,
+/// while landowners are still owed land-buy rewards, `force`
+pub(crate) fn require_purge_allowed(grid_config: &GridConfig, claims_pause: &AccountInfo, force: bool) -> Result<()> {
+    let claims_pause = ClaimsPause::load(claims_pause)?;
+    require!(
+        grid_config.claims_paused && claims_pause.paused_for(PURGE_PAUSE_SLOTS, Clock::get()?.slot),
+        BillionError::PurgeWindowOpen
+    );
+    require!(force || grid_config.reward_liability == 0, BillionError::OutstandingRewards);
+    Ok(())
 }
 
-pub fn handler(ctx: Context<AdminPurge>) -> Result<()> {
+/// Closes the grid and sends everything left in its vaults to the authority; the final step
+/// of a purge, after admin_purge_parcels has closed the ParcelInfo accounts. Claims must have
+/// been paused for PURGE_PAUSE_SLOTS first, and while landowners are still owed land-buy
+/// rewards it only runs with `force`, logging what they lose through RewardsConfiscated.
+pub fn handler(ctx: Context<AdminPurge>, grid_id: u64, force: bool) -> Result<()> {
+    let roles = AdminRoles::load(&ctx.accounts.admin_roles, &ctx.accounts.grid_config)?;
+    require_keys_eq!(roles.treasury_authority, ctx.accounts.authority.key(), BillionError::Unauthorized);
+
     let grid_config = &ctx.accounts.grid_config;
     let reward_pool = &ctx.accounts.land_buy_reward_pool;
 
+    require_purge_allowed(grid_config, &ctx.accounts.claims_pause, force)?;
+
     // Get the amount of tokens in the reward pool
     let amount = reward_pool.amount;
     let decimals = ctx.accounts.token_mint.decimals;
 
+    if grid_config.reward_liability > 0 {
+        emit!(RewardsConfiscated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            authority: ctx.accounts.authority.key(),
+            reward_liability: grid_config.reward_liability,
+            amount: grid_config.reward_liability.min(amount),
+        });
+        msg!("Purging with {} tokens of rewards still owed", grid_config.reward_liability);
+    }
+
     // Signer seeds for grid_config PDA
+    let grid_bytes = grid_seed(grid_id);
     let signer_seeds: &[&[&[u8]]] = &[&[
         GridConfig::SEED,
+        &grid_bytes,
         &[grid_config.bump],
     ]];
 
@@ -100,7 +164,26 @@ pub fn handler(ctx: Context<AdminPurge>) -> Result<()> {
         signer_seeds,
     ))?;
 
-    // Step 3: Close the BlockMap account (manually since it's zero_copy)
+    // Step 3: Drain the SOL reward vault to authority
+    let vault_lamports = ctx.accounts.sol_reward_vault.lamports();
+    if vault_lamports > 0 {
+        msg!("Draining {} lamports from SOL reward vault", vault_lamports);
+        let grid_config_key = grid_config.key();
+        let vault_seeds: &[&[u8]] = &[SOL_REWARD_VAULT_SEED, grid_config_key.as_ref(), &[ctx.bumps.sol_reward_vault]];
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.sol_reward_vault.to_account_info(),
+                    to: ctx.accounts.authority.to_account_info(),
+                },
+                &[vault_seeds],
+            ),
+            vault_lamports,
+        )?;
+    }
+
+    // Step 4: Close the BlockMap account (manually since it's zero_copy)
     let block_map = &ctx.accounts.block_map;
     let block_map_lamports = block_map.lamports();
 
@@ -115,9 +198,12 @@ pub fn handler(ctx: Context<AdminPurge>) -> Result<()> {
         .checked_add(block_map_lamports)
         .ok_or(BillionError::Overflow)?;
 
-    // Zero out the data
-    let mut data = block_map.try_borrow_mut_data()?;
-    data.fill(0);
+    // Zero out the data and hand the account back to the system program, as `close` does.
+    // Left program-owned and zeroed it would pass create_block_map's `zero` constraint again
+    // if topped up later in the same transaction.
+    block_map.try_borrow_mut_data()?.fill(0);
+    block_map.assign(&system_program::ID);
+    block_map.realloc(0, false)?;
 
     // Note: GridConfig is closed automatically by the `close = authority` constraint
 
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::MAX_PURGE_PARCEL_BATCH;
+use crate::errors::BillionError;
+use crate::instructions::admin_purge::require_purge_allowed;
+use crate::instructions::claim_land_buy_rewards_batch::load_parcel_info;
+use crate::state::{grid_seed, AdminRoles, ClaimsPause, ConfigFreeze, GridConfig};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct AdminPurgeParcels<'info> {
+    /// The treasury authority; receives the closed accounts' rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// Names the treasury authority; the main authority holds every role until it's created
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [AdminRoles::SEED, &grid_seed(grid_id)], bump)]
+    pub admin_roles: UncheckedAccount<'info>,
+
+    /// When claims were paused; as with admin_purge, nothing closes before PURGE_PAUSE_SLOTS
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [ClaimsPause::SEED, &grid_seed(grid_id)], bump)]
+    pub claims_pause: UncheckedAccount<'info>,
+
+    /// Must still be empty: freeze_config creating it disables this instruction for good
+    /// CHECK: Seeds are verified here, only whether it exists is read
+    #[account(
+        seeds = [ConfigFreeze::SEED, &grid_seed(grid_id)],
+        bump,
+        constraint = !ConfigFreeze::is_frozen(&config_freeze) @ BillionError::ConfigFrozen
+    )]
+    pub config_freeze: UncheckedAccount<'info>,
+}
+
+/// Closes the ParcelInfo of each of `parcel_ids`, passed writable in remaining_accounts in the
+/// same order, returning their rent to the authority. Run it as many times as it takes before
+/// admin_purge, which closes GridConfig and so has to come last. Each account is closed the
+/// way `close = authority` closes one: handed back to the system program with its data
+/// dropped, so it can't be revived within the transaction. Takes the same pause and `force`
+/// as admin_purge.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, AdminPurgeParcels<'info>>,
+    grid_id: u64,
+    parcel_ids: Vec<u16>,
+    force: bool,
+) -> Result<()> {
+    let roles = AdminRoles::load(&ctx.accounts.admin_roles, &ctx.accounts.grid_config)?;
+    require_keys_eq!(roles.treasury_authority, ctx.accounts.authority.key(), BillionError::Unauthorized);
+    require!(
+        !parcel_ids.is_empty() && parcel_ids.len() <= MAX_PURGE_PARCEL_BATCH as usize,
+        BillionError::InvalidBatch
+    );
+    require!(ctx.remaining_accounts.len() == parcel_ids.len(), BillionError::InvalidBatch);
+
+    let grid_config = &ctx.accounts.grid_config;
+    require_purge_allowed(grid_config, &ctx.accounts.claims_pause, force)?;
+
+    let authority = ctx.accounts.authority.to_account_info();
+    for (&parcel_id, parcel_account) in parcel_ids.iter().zip(ctx.remaining_accounts) {
+        require!(
+            parcel_id != 0 && parcel_id < grid_config.next_parcel_id,
+            BillionError::InvalidBatch
+        );
+        require!(parcel_account.is_writable, BillionError::InvalidBatch);
+        load_parcel_info(parcel_account, grid_id, parcel_id)?;
+
+        let rent = parcel_account.lamports();
+        **authority.try_borrow_mut_lamports()? = authority.lamports().checked_add(rent).ok_or(BillionError::Overflow)?;
+        **parcel_account.try_borrow_mut_lamports()? = 0;
+        parcel_account.assign(&system_program::ID);
+        parcel_account.realloc(0, false)?;
+    }
+
+    msg!("Closed {} ParcelInfo accounts", parcel_ids.len());
+    Ok(())
+}
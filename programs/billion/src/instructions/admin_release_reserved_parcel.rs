@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use mpl_core::instructions::TransferV1CpiBuilder;
+use crate::state::{epoch_seed, grid_seed, GridConfig, ParcelInfo};
+use crate::errors::BillionError;
+#[cfg(not(feature = "localnet"))]
+use crate::instructions::claim_parcel::MPL_CORE_ID;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16)]
+pub struct AdminReleaseReservedParcel<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == grid_config.authority @ BillionError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        mut,
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+
+    /// The Metaplex Core asset - must match parcel_info.asset
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        mut,
+        constraint = asset.key() == parcel_info.asset @ BillionError::AssetMismatch
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    /// Core collection - must match grid_config.collection
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        mut,
+        constraint = collection.key() == grid_config.collection @ BillionError::InvalidCollection
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    /// CHECK: Final owner of the parcel (not signer)
+    pub recipient: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Transfers a parcel reserved by admin_reserve_parcel from the GridConfig PDA to `recipient`
+/// and clears the flag, so the new owner can claim its land-buy rewards
+pub fn handler(ctx: Context<AdminReleaseReservedParcel>, grid_id: u64, parcel_id: u16) -> Result<()> {
+    require!(ctx.accounts.parcel_info.reserved, BillionError::ParcelNotReserved);
+
+    let bump = ctx.accounts.grid_config.bump;
+    TransferV1CpiBuilder::new(&ctx.accounts.mpl_core_program.to_account_info())
+        .asset(&ctx.accounts.asset.to_account_info())
+        .collection(Some(&ctx.accounts.collection.to_account_info()))
+        .payer(&ctx.accounts.authority.to_account_info())
+        .authority(Some(&ctx.accounts.grid_config.to_account_info()))
+        .new_owner(&ctx.accounts.recipient.to_account_info())
+        .system_program(Some(&ctx.accounts.system_program.to_account_info()))
+        .invoke_signed(&[&[GridConfig::SEED, &grid_seed(grid_id), &[bump]]])?;
+
+    ctx.accounts.parcel_info.reserved = false;
+    msg!("Released reserved parcel {} to {}", parcel_id, ctx.accounts.recipient.key());
+    Ok(())
+}
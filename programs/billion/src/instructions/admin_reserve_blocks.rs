@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use billion_core::Rect;
+use crate::state::{grid_seed, AdminRoles, BlockMap, GridConfig, ConfigFreeze, MapDigest, RESERVED_BLOCK};
+use crate::errors::BillionError;
+use crate::events::{BlocksReserved, EVENT_SCHEMA_VERSION};
+use crate::instructions::admin_mint::validate_admin_mint;
+use crate::instructions::init_map_digest::record_map_write;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct AdminReserveBlocks<'info> {
+    /// The config authority
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// BlockMap address must match the one stored in grid_config
+    #[account(
+        mut,
+        constraint = block_map.key() == grid_config.block_map @ BillionError::Unauthorized
+    )]
+    pub block_map: AccountLoader<'info, BlockMap>,
+
+    /// Names the config authority; the main authority holds every role until it's created
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [AdminRoles::SEED, &grid_seed(grid_id)], bump)]
+    pub admin_roles: UncheckedAccount<'info>,
+
+    /// Must still be empty: freeze_config creating it disables this instruction for good
+    /// CHECK: Seeds are verified here, only whether it exists is read
+    #[account(
+        seeds = [ConfigFreeze::SEED, &grid_seed(grid_id)],
+        bump,
+        constraint = !ConfigFreeze::is_frozen(&config_freeze) @ BillionError::ConfigFrozen
+    )]
+    pub config_freeze: UncheckedAccount<'info>,
+
+    /// Rolling digest of BlockMap writes, which chains in the rectangle
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(mut, seeds = [MapDigest::SEED, &grid_seed(grid_id)], bump)]
+    pub map_digest: UncheckedAccount<'info>,
+}
+
+/// Takes an unclaimed rectangle out of play for roads and plazas by marking its blocks
+/// RESERVED_BLOCK. Claims and admin mints see them as taken; they count toward neither
+/// total_claimed_blocks nor any reward weight, so landowners' shares are unchanged.
+pub fn handler(ctx: Context<AdminReserveBlocks>, _grid_id: u64, x: u8, y: u8, width: u8, height: u8) -> Result<()> {
+    let roles = AdminRoles::load(&ctx.accounts.admin_roles, &ctx.accounts.grid_config)?;
+    require_keys_eq!(roles.config_authority, ctx.accounts.authority.key(), BillionError::Unauthorized);
+
+    let mut block_map = BlockMap::load_mut(&ctx.accounts.block_map)?;
+    require!(!block_map.is_frozen(), BillionError::EpochFrozen);
+    validate_admin_mint(x, y, width, height, &block_map)?;
+    let rect = Rect::new(x, y, width, height);
+    for (block_x, block_y) in rect.blocks() {
+        block_map.set_block(block_x, block_y, RESERVED_BLOCK);
+    }
+    record_map_write(&ctx.accounts.map_digest, ctx.accounts.grid_config.epoch, RESERVED_BLOCK, rect)?;
+
+    emit!(BlocksReserved {
+        schema_version: EVENT_SCHEMA_VERSION,
+        authority: ctx.accounts.authority.key(),
+        epoch: ctx.accounts.grid_config.epoch,
+        x,
+        y,
+        width,
+        height,
+    });
+    msg!("Reserved {}x{} blocks at ({}, {})", width, height, x, y);
+    Ok(())
+}
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+use crate::instructions::admin_mint::{mint_parcel, AdminMint};
+
+/// Mints a parcel like admin_mint, but to the GridConfig PDA, flagging its ParcelInfo as
+/// reserved until admin_release_reserved_parcel hands it to an owner. The recipient account
+/// is unused; clients pass the GridConfig PDA. The parcel accrues land-buy rewards from the
+/// start, which its eventual owner can claim.
+pub fn handler(ctx: Context<AdminMint>, grid_id: u64, x: u8, y: u8, width: u8, height: u8) -> Result<()> {
+    mint_parcel(ctx, grid_id, x, y, width, height, None, false, false, true, false)
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_CHARITIES;
+use crate::errors::BillionError;
+use crate::state::{grid_seed, CharityRegistry, ConfigFreeze, GridConfig, ReferralShare, TokenTreasury};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct AdminSetCharities<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump,
+        has_one = authority @ BillionError::Unauthorized,
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// Created on first configuration, replaced in place afterwards
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + CharityRegistry::INIT_SPACE,
+        seeds = [CharityRegistry::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub charity_registry: Account<'info, CharityRegistry>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Treasury share the charity share must leave room for
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [TokenTreasury::SEED, &grid_seed(grid_id)], bump)]
+    pub token_treasury: UncheckedAccount<'info>,
+
+    /// Direct referral share the charity share must leave room for
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [ReferralShare::SEED, &grid_seed(grid_id)], bump)]
+    pub referral_share: UncheckedAccount<'info>,
+
+    /// Must still be empty: freeze_config creating it disables this instruction for good
+    /// CHECK: Seeds are verified here, only whether it exists is read
+    #[account(
+        seeds = [ConfigFreeze::SEED, &grid_seed(grid_id)],
+        bump,
+        constraint = !ConfigFreeze::is_frozen(&config_freeze) @ BillionError::ConfigFrozen
+    )]
+    pub config_freeze: UncheckedAccount<'info>,
+}
+
+/// Replaces the charity registry. Indices chosen by buyers refer to positions in
+/// `charities`, so reordering changes where in-flight claims route.
+pub fn handler(ctx: Context<AdminSetCharities>, _grid_id: u64, charities: Vec<Pubkey>, max_charity_bps: u16) -> Result<()> {
+    require!(charities.len() <= MAX_CHARITIES as usize, BillionError::TooManyCharities);
+    require!(
+        charities.iter().all(|charity| *charity != Pubkey::default()),
+        BillionError::InvalidCharity
+    );
+    let config = &mut ctx.accounts.grid_config;
+    // Charity share comes out of the burned portion, alongside the referral and treasury shares
+    let treasury_bps = TokenTreasury::load(&ctx.accounts.token_treasury)?.share_bps;
+    let referral_share = ReferralShare::load(&ctx.accounts.referral_share)?;
+    require!(
+        GridConfig::valid_split_bps(
+            config.land_owners_reward_share_bps,
+            treasury_bps,
+            referral_share.with_referral_bps(config.referral_bps),
+            max_charity_bps,
+        ),
+        BillionError::InvalidCharityShare
+    );
+    config.max_charity_bps = max_charity_bps;
+
+    let registry = &mut ctx.accounts.charity_registry;
+    registry.charities = charities;
+    registry.bump = ctx.bumps.charity_registry;
+
+    msg!(
+        "Registered {} charities, max share {} bps",
+        registry.charities.len(),
+        max_charity_bps
+    );
+    Ok(())
+}
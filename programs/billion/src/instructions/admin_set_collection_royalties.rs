@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use mpl_core::accounts::BaseCollectionV1;
+use mpl_core::fetch_plugin;
+use mpl_core::instructions::{
+    AddCollectionPluginV1CpiBuilder, RemoveCollectionPluginV1CpiBuilder, UpdateCollectionPluginV1CpiBuilder,
+};
+use mpl_core::types::{Creator, Plugin, PluginType, Royalties, RuleSet};
+use crate::state::{grid_seed, GridConfig};
+use crate::errors::BillionError;
+#[cfg(not(feature = "localnet"))]
+use crate::instructions::claim_parcel::MPL_CORE_ID;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct AdminSetCollectionRoyalties<'info> {
+    /// Only the grid authority can set royalties
+    #[account(
+        mut,
+        constraint = authority.key() == grid_config.authority @ BillionError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// Core collection - must match grid_config.collection
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        mut,
+        constraint = collection.key() == grid_config.collection @ BillionError::InvalidCollection
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Sets the collection's Royalties plugin, paying `royalty_bps` of secondary sales to
+/// `royalty_recipient`. Core applies collection plugins to every asset in the collection,
+/// so this covers parcels already minted as well as new ones. A `royalty_bps` of 0 removes
+/// the plugin.
+pub fn handler(ctx: Context<AdminSetCollectionRoyalties>, grid_id: u64, royalty_bps: u16, royalty_recipient: Pubkey) -> Result<()> {
+    require!(royalty_bps <= 10_000, BillionError::InvalidRoyalties);
+    require!(
+        ctx.accounts.grid_config.collection != Pubkey::default(),
+        BillionError::CollectionNotSet
+    );
+
+    let mpl_core_program = ctx.accounts.mpl_core_program.to_account_info();
+    let collection = ctx.accounts.collection.to_account_info();
+    let grid_config = ctx.accounts.grid_config.to_account_info();
+    let authority = ctx.accounts.authority.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+
+    // The GridConfig PDA is the collection's update authority
+    let bump = ctx.accounts.grid_config.bump;
+    let grid_bytes = grid_seed(grid_id);
+    let seeds: &[&[u8]] = &[GridConfig::SEED, &grid_bytes, &[bump]];
+    let signer_seeds: &[&[&[u8]]] = &[seeds];
+
+    let existing = fetch_plugin::<BaseCollectionV1, Royalties>(&collection, PluginType::Royalties).is_ok();
+    if royalty_bps == 0 {
+        if existing {
+            RemoveCollectionPluginV1CpiBuilder::new(&mpl_core_program)
+                .collection(&collection)
+                .payer(&authority)
+                .authority(Some(&grid_config))
+                .system_program(&system_program)
+                .plugin_type(PluginType::Royalties)
+                .invoke_signed(signer_seeds)?;
+        }
+        msg!("Collection royalties disabled");
+        return Ok(());
+    }
+
+    let plugin = Plugin::Royalties(Royalties {
+        basis_points: royalty_bps,
+        creators: vec![Creator {
+            address: royalty_recipient,
+            percentage: 100,
+        }],
+        rule_set: RuleSet::None,
+    });
+    if existing {
+        UpdateCollectionPluginV1CpiBuilder::new(&mpl_core_program)
+            .collection(&collection)
+            .payer(&authority)
+            .authority(Some(&grid_config))
+            .system_program(&system_program)
+            .plugin(plugin)
+            .invoke_signed(signer_seeds)?;
+    } else {
+        // Defaults to the collection's update authority, the GridConfig PDA
+        AddCollectionPluginV1CpiBuilder::new(&mpl_core_program)
+            .collection(&collection)
+            .payer(&authority)
+            .authority(Some(&grid_config))
+            .system_program(&system_program)
+            .plugin(plugin)
+            .invoke_signed(signer_seeds)?;
+    }
+
+    msg!("Collection royalties set to {} bps for {}", royalty_bps, royalty_recipient);
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use crate::state::{epoch_seed, grid_seed, GridConfig, ParcelInfo, RewardExclusions, RingRewardWeights};
+use crate::errors::BillionError;
+use crate::instructions::claim_land_buy_rewards::pending_land_buy_rewards;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16)]
+pub struct AdminSetRewardsExcluded<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == grid_config.authority @ BillionError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        mut,
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + RewardExclusions::INIT_SPACE,
+        seeds = [RewardExclusions::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub reward_exclusions: Account<'info, RewardExclusions>,
+
+    /// Per-ring land-buy reward weights, which count the parcel's extra weight while it's
+    /// included
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(mut, seeds = [RingRewardWeights::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_reward_weights: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Moves a current-epoch parcel's blocks out of or back into total_claimed_blocks. Rewards
+/// pending when a parcel is excluded lapse into the pool surplus rather than staying owed,
+/// and a parcel included again only accrues from the current accumulator on, so the pool
+/// never owes for the time it was excluded.
+pub fn handler(ctx: Context<AdminSetRewardsExcluded>, _grid_id: u64, parcel_id: u16, excluded: bool) -> Result<()> {
+    let grid_config = &mut ctx.accounts.grid_config;
+    let parcel_info = &mut ctx.accounts.parcel_info;
+    require!(parcel_info.epoch == grid_config.epoch, BillionError::ParcelArchived);
+    if parcel_info.rewards_excluded == excluded {
+        msg!("Parcel {} rewards_excluded is already {}", parcel_id, excluded);
+        return Ok(());
+    }
+
+    let block_count = parcel_info.block_count();
+    let extra_weight = parcel_info.extra_weight(block_count);
+    let exclusions = &mut ctx.accounts.reward_exclusions;
+    exclusions.bump = ctx.bumps.reward_exclusions;
+    if excluded {
+        let lapsed = pending_land_buy_rewards(parcel_info, grid_config.land_buy_rewards_per_block)?;
+        grid_config.pay_land_buy_rewards(lapsed);
+        grid_config.total_claimed_blocks = grid_config
+            .total_claimed_blocks
+            .checked_sub(block_count)
+            .ok_or(BillionError::Overflow)?;
+        exclusions.add(grid_config.epoch, block_count).ok_or(BillionError::Overflow)?;
+        RingRewardWeights::record_extra(&ctx.accounts.ring_reward_weights, grid_config.epoch, -extra_weight)?;
+        msg!("Excluded parcel {} ({} blocks) from rewards, {} pending rewards lapsed", parcel_id, block_count, lapsed);
+    } else {
+        grid_config.total_claimed_blocks = grid_config
+            .total_claimed_blocks
+            .checked_add(block_count)
+            .ok_or(BillionError::Overflow)?;
+        exclusions.remove(grid_config.epoch, block_count).ok_or(BillionError::Overflow)?;
+        RingRewardWeights::record_extra(&ctx.accounts.ring_reward_weights, grid_config.epoch, extra_weight)?;
+        msg!("Included parcel {} ({} blocks) in rewards", parcel_id, block_count);
+    }
+
+    parcel_info.rewards_excluded = excluded;
+    parcel_info.last_claimed_land_buy_rewards_per_block = grid_config.land_buy_rewards_per_block;
+    parcel_info.last_claimed_at = Clock::get()?.unix_timestamp;
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022,
+    token_interface::{Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface},
+};
+use crate::errors::BillionError;
+use crate::state::{grid_seed, GridConfig, RewardDust, AdminRoles, LAND_BUY_REWARD_POOL_SEED};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct AdminSweepDust<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump,
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        mut,
+        seeds = [RewardDust::SEED, &grid_seed(grid_id)],
+        bump = reward_dust.bump
+    )]
+    pub reward_dust: Account<'info, RewardDust>,
+
+    #[account(
+        constraint = token_mint.key() == grid_config.token_mint @ BillionError::Unauthorized
+    )]
+    pub token_mint: InterfaceAccount<'info, InterfaceMint>,
+
+    #[account(
+        mut,
+        seeds = [LAND_BUY_REWARD_POOL_SEED, grid_config.key().as_ref()],
+        bump,
+        constraint = land_buy_reward_pool.key() == grid_config.land_buy_reward_pool @ BillionError::InvalidRewardPool
+    )]
+    pub land_buy_reward_pool: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = authority,
+        token::token_program = token_program,
+    )]
+    pub authority_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Names the treasury authority; the main authority holds every role until it's created
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [AdminRoles::SEED, &grid_seed(grid_id)], bump)]
+    pub admin_roles: UncheckedAccount<'info>,
+}
+
+/// Moves `amount` tokens of accumulator rounding out of the land-buy reward pool to the
+/// authority, at most the whole tokens RewardDust has tracked. The dust was counted in
+/// reward_liability when credited, so the liability drops with it and every parcel's
+/// pending rewards stay covered.
+pub fn handler(ctx: Context<AdminSweepDust>, grid_id: u64, amount: u64) -> Result<()> {
+    let roles = AdminRoles::load(&ctx.accounts.admin_roles, &ctx.accounts.grid_config)?;
+    require_keys_eq!(roles.treasury_authority, ctx.accounts.authority.key(), BillionError::Unauthorized);
+
+    require!(
+        amount > 0 && ctx.accounts.reward_dust.sweep(amount).is_some(),
+        BillionError::InsufficientBalance
+    );
+    let grid_config = &mut ctx.accounts.grid_config;
+    grid_config.pay_land_buy_rewards(amount);
+
+    let bump = grid_config.bump;
+    token_2022::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_2022::TransferChecked {
+                from: ctx.accounts.land_buy_reward_pool.to_account_info(),
+                to: ctx.accounts.authority_token_account.to_account_info(),
+                authority: grid_config.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+            },
+            &[&[GridConfig::SEED, &grid_seed(grid_id), &[bump]]],
+        ),
+        amount,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    msg!("Swept {} tokens of reward dust, {} left", amount, ctx.accounts.reward_dust.sweepable());
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use mpl_core::instructions::UpdatePluginV1CpiBuilder;
+use mpl_core::types::{PermanentFreezeDelegate, Plugin};
+use crate::state::{epoch_seed, grid_seed, GridConfig, ParcelInfo};
+use crate::errors::BillionError;
+#[cfg(not(feature = "localnet"))]
+use crate::instructions::claim_parcel::MPL_CORE_ID;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16)]
+pub struct AdminThawParcel<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == grid_config.authority @ BillionError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+
+    /// The Metaplex Core asset - must match parcel_info.asset
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        mut,
+        constraint = asset.key() == parcel_info.asset @ BillionError::AssetMismatch
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    /// Core collection - must match grid_config.collection
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        mut,
+        constraint = collection.key() == grid_config.collection @ BillionError::InvalidCollection
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Lifts the soulbound freeze admin_mint put on a parcel. The PermanentFreezeDelegate can't
+/// be removed, so it stays on the asset thawed; Core fails the CPI for assets without one.
+pub fn handler(ctx: Context<AdminThawParcel>, grid_id: u64, parcel_id: u16) -> Result<()> {
+    let bump = ctx.accounts.grid_config.bump;
+    UpdatePluginV1CpiBuilder::new(&ctx.accounts.mpl_core_program.to_account_info())
+        .asset(&ctx.accounts.asset.to_account_info())
+        .collection(Some(&ctx.accounts.collection.to_account_info()))
+        .payer(&ctx.accounts.authority.to_account_info())
+        .authority(Some(&ctx.accounts.grid_config.to_account_info()))
+        .system_program(&ctx.accounts.system_program.to_account_info())
+        .plugin(Plugin::PermanentFreezeDelegate(PermanentFreezeDelegate { frozen: false }))
+        .invoke_signed(&[&[GridConfig::SEED, &grid_seed(grid_id), &[bump]]])?;
+
+    msg!("Thawed soulbound parcel {}", parcel_id);
+    Ok(())
+}
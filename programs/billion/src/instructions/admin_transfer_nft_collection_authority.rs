@@ -1,10 +1,12 @@
 use anchor_lang::prelude::*;
 use mpl_core::instructions::UpdateCollectionV1CpiBuilder;
-use crate::state::GridConfig;
+use crate::state::{grid_seed, GridConfig};
 use crate::errors::BillionError;
+#[cfg(not(feature = "localnet"))]
 use crate::instructions::claim_parcel::MPL_CORE_ID;
 
 #[derive(Accounts)]
+#[instruction(grid_id: u64)]
 pub struct AdminTransferNftCollectionAuthority<'info> {
     /// Only the grid authority can transfer collection authority
     #[account(
@@ -13,7 +15,7 @@ pub struct AdminTransferNftCollectionAuthority<'info> {
     pub authority: Signer<'info>,
 
     #[account(
-        seeds = [GridConfig::SEED],
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
         bump = grid_config.bump
     )]
     pub grid_config: Account<'info, GridConfig>,
@@ -30,14 +32,15 @@ pub struct AdminTransferNftCollectionAuthority<'info> {
     /// CHECK: Can be any valid pubkey
     pub new_collection_authority: UncheckedAccount<'info>,
 
-    /// CHECK: Metaplex Core program
-    #[account(address = MPL_CORE_ID)]
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
     pub mpl_core_program: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<AdminTransferNftCollectionAuthority>) -> Result<()> {
+pub fn handler(ctx: Context<AdminTransferNftCollectionAuthority>, grid_id: u64) -> Result<()> {
     // Validate collection is set
     require!(
         ctx.accounts.grid_config.collection != Pubkey::default(),
@@ -53,7 +56,8 @@ pub fn handler(ctx: Context<AdminTransferNftCollectionAuthority>) -> Result<()>
 
     // Get the grid_config bump for PDA signing (collection authority is the GridConfig PDA)
     let bump = ctx.accounts.grid_config.bump;
-    let seeds: &[&[u8]] = &[GridConfig::SEED, &[bump]];
+    let grid_bytes = grid_seed(grid_id);
+    let seeds: &[&[u8]] = &[GridConfig::SEED, &grid_bytes, &[bump]];
     let signer_seeds: &[&[&[u8]]] = &[seeds];
 
     // Update the collection's update authority to the new authority
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use billion_core::Rect;
+use crate::state::{AdminRoles, BlockMap, RESERVED_BLOCK};
+use crate::errors::BillionError;
+use crate::events::{BlocksUnreserved, EVENT_SCHEMA_VERSION};
+use crate::instructions::admin_reserve_blocks::AdminReserveBlocks;
+use crate::instructions::init_map_digest::record_map_write;
+
+/// Returns a reserved rectangle to the market: every block in it must be RESERVED_BLOCK,
+/// and each goes back to unclaimed.
+pub fn handler(ctx: Context<AdminReserveBlocks>, _grid_id: u64, x: u8, y: u8, width: u8, height: u8) -> Result<()> {
+    let roles = AdminRoles::load(&ctx.accounts.admin_roles, &ctx.accounts.grid_config)?;
+    require_keys_eq!(roles.config_authority, ctx.accounts.authority.key(), BillionError::Unauthorized);
+
+    let rect = Rect::new(x, y, width, height);
+    let mut block_map = BlockMap::load_mut(&ctx.accounts.block_map)?;
+    require!(!block_map.is_frozen(), BillionError::EpochFrozen);
+    require!(width > 0 && height > 0, BillionError::InvalidDimensions);
+    require!(rect.in_bounds(block_map.grid_size()), BillionError::OutOfBounds);
+    require!(
+        rect.blocks().all(|(block_x, block_y)| block_map.get_block(block_x, block_y) == RESERVED_BLOCK),
+        BillionError::BlocksNotReserved
+    );
+    for (block_x, block_y) in rect.blocks() {
+        block_map.set_block(block_x, block_y, 0);
+    }
+    record_map_write(&ctx.accounts.map_digest, ctx.accounts.grid_config.epoch, 0, rect)?;
+
+    emit!(BlocksUnreserved {
+        schema_version: EVENT_SCHEMA_VERSION,
+        authority: ctx.accounts.authority.key(),
+        epoch: ctx.accounts.grid_config.epoch,
+        x,
+        y,
+        width,
+        height,
+    });
+    msg!("Unreserved {}x{} blocks at ({}, {})", width, height, x, y);
+    Ok(())
+}
@@ -0,0 +1,252 @@
+use anchor_lang::prelude::*;
+use billion_core::Rect;
+use anchor_spl::{
+    token_2022,
+    token_interface::{Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface},
+    associated_token::AssociatedToken,
+};
+use mpl_core::instructions::{AddPluginV1CpiBuilder, BurnV1CpiBuilder};
+use mpl_core::types::{BurnDelegate, Plugin, PluginAuthority};
+use crate::state::{
+    epoch_seed, grid_seed, BlockMap, GridConfig, ParcelInfo, RewardExclusions, StakeAccount, RingRewardWeights, RingOccupancy, MapDigest,
+    ParcelIdPool, LAND_BUY_REWARD_POOL_SEED,
+};
+use crate::errors::BillionError;
+use crate::instructions::admin_backfill_ring_occupancy::record_ring_occupancy;
+use crate::instructions::init_map_digest::record_map_write;
+use crate::instructions::claim_land_buy_rewards::{get_core_asset_owner, pending_land_buy_rewards};
+#[cfg(not(feature = "localnet"))]
+use crate::instructions::claim_parcel::MPL_CORE_ID;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16)]
+pub struct BurnForExit<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Box<Account<'info, GridConfig>>,
+
+    /// Current BlockMap - the parcel's cells are freed
+    #[account(
+        mut,
+        constraint = block_map.key() == grid_config.block_map @ BillionError::Unauthorized
+    )]
+    pub block_map: AccountLoader<'info, BlockMap>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Box<Account<'info, ParcelInfo>>,
+
+    /// Must be empty: staked parcels are frozen and can't be burned
+    /// CHECK: Only its emptiness is read
+    #[account(
+        seeds = [StakeAccount::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump
+    )]
+    pub stake_account: UncheckedAccount<'info>,
+
+    /// Land buy reward pool - pays pending rewards and the exit share
+    #[account(
+        mut,
+        seeds = [LAND_BUY_REWARD_POOL_SEED, grid_config.key().as_ref()],
+        bump,
+        constraint = land_buy_reward_pool.key() == grid_config.land_buy_reward_pool @ BillionError::InvalidRewardPool
+    )]
+    pub land_buy_reward_pool: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = token_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_token_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    #[account(
+        constraint = token_mint.key() == grid_config.token_mint @ BillionError::Unauthorized
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, InterfaceMint>>,
+
+    /// The Metaplex Core asset - must match parcel_info.asset
+    /// CHECK: Validated by constraint, ownership checked in handler
+    #[account(
+        mut,
+        constraint = asset.key() == parcel_info.asset @ BillionError::AssetMismatch
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    /// Core collection - must match grid_config.collection
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        mut,
+        constraint = collection.key() == grid_config.collection @ BillionError::InvalidCollection
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    /// Counts the parcel's blocks while it's excluded from land-buy rewards
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler for excluded parcels
+    #[account(mut, seeds = [RewardExclusions::SEED, &grid_seed(grid_id)], bump)]
+    pub reward_exclusions: UncheckedAccount<'info>,
+
+    /// Per-ring land-buy reward weights, which stop counting the parcel's extra weight
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(mut, seeds = [RingRewardWeights::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_reward_weights: UncheckedAccount<'info>,
+
+    /// Claimed blocks per ring, which stop counting the parcel's freed blocks
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(mut, seeds = [RingOccupancy::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_occupancy: UncheckedAccount<'info>,
+
+    /// Rolling digest of BlockMap writes, which chains in the freed blocks
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(mut, seeds = [MapDigest::SEED, &grid_seed(grid_id)], bump)]
+    pub map_digest: UncheckedAccount<'info>,
+
+    /// Receives the freed id
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + ParcelIdPool::INIT_SPACE,
+        seeds = [ParcelIdPool::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub parcel_id_pool: Box<Account<'info, ParcelIdPool>>,
+}
+
+/// Burns the parcel and returns its blocks and id to the grid. The owner receives their pending
+/// land-buy rewards plus `exit_share_bps` of the parcel's pro-rata share of the pool surplus,
+/// i.e. what's left after every parcel's pending rewards are covered.
+pub fn handler(ctx: Context<BurnForExit>, grid_id: u64, parcel_id: u16) -> Result<()> {
+    let owner = get_core_asset_owner(&ctx.accounts.asset.to_account_info())?;
+    require!(owner == ctx.accounts.owner.key(), BillionError::NotOwner);
+    require!(
+        ctx.accounts.parcel_info.epoch == ctx.accounts.grid_config.epoch,
+        BillionError::ParcelArchived
+    );
+    require!(ctx.accounts.stake_account.data_is_empty(), BillionError::ParcelStaked);
+
+    let parcel_info = &ctx.accounts.parcel_info;
+    let block_count = parcel_info.block_count();
+    let grid_config = &mut ctx.accounts.grid_config;
+
+    // Settle pending rewards first so they leave the liability before the surplus is measured
+    let owed = pending_land_buy_rewards(parcel_info, grid_config.land_buy_rewards_per_block)?;
+    grid_config.pay_land_buy_rewards(owed);
+    let pool_after_owed = ctx.accounts.land_buy_reward_pool.amount.saturating_sub(owed);
+    let surplus = grid_config.reward_surplus(pool_after_owed);
+    // A parcel excluded from rewards has no share of the surplus either
+    let rewarded_blocks = parcel_info.rewarded_block_count();
+    let exit_amount = grid_config.exit_payout(surplus, rewarded_blocks).ok_or(BillionError::Overflow)?;
+
+    grid_config.total_claimed_blocks = grid_config
+        .total_claimed_blocks
+        .checked_sub(rewarded_blocks)
+        .ok_or(BillionError::Overflow)?;
+    if parcel_info.rewards_excluded {
+        RewardExclusions::release(&ctx.accounts.reward_exclusions, grid_config.epoch, block_count)?;
+    } else {
+        RingRewardWeights::record_extra(
+            &ctx.accounts.ring_reward_weights,
+            grid_config.epoch,
+            -parcel_info.extra_weight(block_count),
+        )?;
+    }
+
+    {
+        let mut block_map = BlockMap::load_mut(&ctx.accounts.block_map)?;
+        require!(!block_map.is_frozen(), BillionError::EpochFrozen);
+        // A masked parcel's bounding box can hold other parcels' cells
+        let mut freed = Vec::new();
+        for dy in 0..parcel_info.height {
+            for dx in 0..parcel_info.width {
+                let (x, y) = (parcel_info.x + dx, parcel_info.y + dy);
+                if block_map.get_block(x, y) == parcel_id {
+                    block_map.set_block(x, y, 0);
+                    freed.push((x, y));
+                }
+            }
+        }
+        let grid_size = block_map.grid_size();
+        let ring_count = grid_config.ring_count();
+        let epoch = grid_config.epoch;
+        record_ring_occupancy(&ctx.accounts.ring_occupancy, epoch, freed.into_iter(), grid_size, ring_count, false)?;
+        let rect = Rect::new(parcel_info.x, parcel_info.y, parcel_info.width, parcel_info.height);
+        record_map_write(&ctx.accounts.map_digest, epoch, 0, rect)?;
+    }
+
+    let pool = &mut ctx.accounts.parcel_id_pool;
+    pool.bump = ctx.bumps.parcel_id_pool;
+    pool.release(grid_config.epoch, parcel_id);
+
+    let bump = grid_config.bump;
+    let signer_seeds: &[&[&[u8]]] = &[&[GridConfig::SEED, &grid_seed(grid_id), &[bump]]];
+
+    let payout = owed.checked_add(exit_amount).ok_or(BillionError::Overflow)?;
+    if payout > 0 {
+        token_2022::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_2022::TransferChecked {
+                    from: ctx.accounts.land_buy_reward_pool.to_account_info(),
+                    to: ctx.accounts.owner_token_account.to_account_info(),
+                    authority: ctx.accounts.grid_config.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            payout,
+            ctx.accounts.token_mint.decimals,
+        )?;
+    }
+
+    // The owner delegates the burn to the GridConfig PDA, which burns it in the same
+    // transaction, so the asset can only leave through this settlement
+    AddPluginV1CpiBuilder::new(&ctx.accounts.mpl_core_program.to_account_info())
+        .asset(&ctx.accounts.asset.to_account_info())
+        .collection(Some(&ctx.accounts.collection.to_account_info()))
+        .payer(&ctx.accounts.owner.to_account_info())
+        .authority(Some(&ctx.accounts.owner.to_account_info()))
+        .system_program(&ctx.accounts.system_program.to_account_info())
+        .plugin(Plugin::BurnDelegate(BurnDelegate {}))
+        .init_authority(PluginAuthority::Address {
+            address: ctx.accounts.grid_config.key(),
+        })
+        .invoke()?;
+
+    BurnV1CpiBuilder::new(&ctx.accounts.mpl_core_program.to_account_info())
+        .asset(&ctx.accounts.asset.to_account_info())
+        .collection(Some(&ctx.accounts.collection.to_account_info()))
+        .payer(&ctx.accounts.owner.to_account_info())
+        .authority(Some(&ctx.accounts.grid_config.to_account_info()))
+        .system_program(Some(&ctx.accounts.system_program.to_account_info()))
+        .invoke_signed(signer_seeds)?;
+
+    msg!(
+        "Parcel {} burned for exit: {} blocks freed, {} rewards + {} exit share paid",
+        parcel_id,
+        block_count,
+        owed,
+        exit_amount
+    );
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::{grid_seed, AdminRoles, ConfigTimelock, GridConfig};
+use crate::errors::BillionError;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct CancelConfigUpdate<'info> {
+    /// The config authority
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// Names the config authority; the main authority holds every role until it's created
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [AdminRoles::SEED, &grid_seed(grid_id)], bump)]
+    pub admin_roles: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [ConfigTimelock::SEED, &grid_seed(grid_id)],
+        bump = config_timelock.bump
+    )]
+    pub config_timelock: Account<'info, ConfigTimelock>,
+}
+
+pub fn handler(ctx: Context<CancelConfigUpdate>, _grid_id: u64) -> Result<()> {
+    let roles = AdminRoles::load(&ctx.accounts.admin_roles, &ctx.accounts.grid_config)?;
+    require_keys_eq!(roles.config_authority, ctx.accounts.authority.key(), BillionError::Unauthorized);
+
+    ctx.accounts.config_timelock.cancel()?;
+    msg!("Cancelled the queued config update");
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022,
+    token_interface::{Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface},
+    associated_token::AssociatedToken,
+};
+use crate::state::{epoch_seed, grid_seed, Distribution, GridConfig, ParcelInfo};
+use crate::errors::BillionError;
+use crate::instructions::claim_land_buy_rewards::get_core_asset_owner;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16)]
+pub struct ClaimDistribution<'info> {
+    /// Anyone can crank a claim; the payout always goes to the asset owner
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(mut)]
+    pub distribution: Account<'info, Distribution>,
+
+    #[account(
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+
+    /// The Metaplex Core asset - must match parcel_info.asset
+    /// CHECK: Validated by constraint, ownership checked in handler
+    #[account(
+        constraint = asset.key() == parcel_info.asset @ BillionError::AssetMismatch
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    /// Current asset owner receiving the payout
+    /// CHECK: Checked against the asset in the handler
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [Distribution::VAULT_SEED, distribution.key().as_ref()],
+        bump = distribution.vault_bump
+    )]
+    pub vault: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    #[account(
+        constraint = mint.key() == distribution.mint @ BillionError::Unauthorized
+    )]
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimDistribution>, grid_id: u64, parcel_id: u16) -> Result<()> {
+    let owner = get_core_asset_owner(&ctx.accounts.asset.to_account_info())?;
+    require!(owner == ctx.accounts.owner.key(), BillionError::NotOwner);
+    // A Fraction PDA can't move tokens out of its own token account
+    require!(*ctx.accounts.owner.owner != crate::ID, BillionError::ParcelFractionalized);
+
+    let distribution = &mut ctx.accounts.distribution;
+    require!(
+        Clock::get()?.unix_timestamp < distribution.expires_at,
+        BillionError::DistributionExpired
+    );
+    require!(
+        ctx.accounts.parcel_info.epoch == distribution.epoch
+            && parcel_id < distribution.snapshot_next_parcel_id,
+        BillionError::ParcelNotInSnapshot
+    );
+    require!(!distribution.is_claimed(parcel_id), BillionError::DistributionAlreadyClaimed);
+
+    let owed = distribution
+        .payout(ctx.accounts.parcel_info.rewarded_block_count())
+        .ok_or(BillionError::Overflow)?;
+    require!(owed > 0, BillionError::NothingToClaim);
+
+    distribution.set_claimed(parcel_id);
+    distribution.claimed_amount = distribution
+        .claimed_amount
+        .checked_add(owed)
+        .ok_or(BillionError::Overflow)?;
+    distribution.claim_count = distribution.claim_count.checked_add(1).ok_or(BillionError::Overflow)?;
+
+    let bump = ctx.accounts.grid_config.bump;
+    let grid_bytes = grid_seed(grid_id);
+    let seeds: &[&[u8]] = &[GridConfig::SEED, &grid_bytes, &[bump]];
+    let signer_seeds: &[&[&[u8]]] = &[seeds];
+
+    token_2022::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_2022::TransferChecked {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.owner_token_account.to_account_info(),
+                authority: ctx.accounts.grid_config.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        owed,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    msg!("Paid {} to owner of parcel {}", owed, parcel_id);
+    Ok(())
+}
@@ -0,0 +1,213 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022,
+    token_interface::{Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface},
+    associated_token::AssociatedToken,
+};
+use crate::state::{epoch_seed, grid_seed, EpochArchive, Fraction, FractionPosition, GridConfig, ParcelInfo, LAND_BUY_REWARD_POOL_SEED};
+use crate::errors::BillionError;
+use crate::instructions::claim_land_buy_rewards::settle_rewards_to;
+
+/// Accounts shared by claim_fraction_rewards, deposit_fraction_shares and
+/// withdraw_fraction_shares. Each harvests the parcel's pending land-buy rewards into the
+/// fraction vault and settles the holder's position before doing anything else.
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16)]
+pub struct FractionHolder<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Box<Account<'info, GridConfig>>,
+
+    #[account(
+        mut,
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Box<Account<'info, ParcelInfo>>,
+
+    #[account(
+        mut,
+        seeds = [Fraction::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = fraction.bump,
+        has_one = share_mint @ BillionError::Unauthorized,
+        constraint = fraction.asset == parcel_info.asset @ BillionError::AssetMismatch,
+    )]
+    pub fraction: Box<Account<'info, Fraction>>,
+
+    /// Created on first use, settled on every call
+    #[account(
+        init_if_needed,
+        payer = holder,
+        space = 8 + FractionPosition::INIT_SPACE,
+        seeds = [FractionPosition::SEED, share_mint.key().as_ref(), holder.key().as_ref()],
+        bump
+    )]
+    pub position: Box<Account<'info, FractionPosition>>,
+
+    pub share_mint: Box<InterfaceAccount<'info, InterfaceMint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = share_mint,
+        associated_token::authority = holder,
+        associated_token::token_program = token_program,
+    )]
+    pub holder_share_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [Fraction::ESCROW_SEED, share_mint.key().as_ref()],
+        bump
+    )]
+    pub share_escrow: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [Fraction::VAULT_SEED, share_mint.key().as_ref()],
+        bump
+    )]
+    pub reward_vault: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    /// Receives the holder's rewards
+    #[account(
+        init_if_needed,
+        payer = holder,
+        associated_token::mint = token_mint,
+        associated_token::authority = holder,
+        associated_token::token_program = token_program,
+    )]
+    pub holder_token_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [LAND_BUY_REWARD_POOL_SEED, grid_config.key().as_ref()],
+        bump,
+        constraint = land_buy_reward_pool.key() == grid_config.land_buy_reward_pool @ BillionError::InvalidRewardPool
+    )]
+    pub land_buy_reward_pool: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    #[account(
+        constraint = token_mint.key() == grid_config.token_mint @ BillionError::Unauthorized
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, InterfaceMint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    /// Archive of the parcel's epoch - required when the parcel is from an earlier epoch
+    #[account(
+        seeds = [EpochArchive::SEED, &grid_seed(grid_id), &epoch_archive.epoch.to_le_bytes()],
+        bump = epoch_archive.bump
+    )]
+    pub epoch_archive: Option<Account<'info, EpochArchive>>,
+}
+
+impl<'info> FractionHolder<'info> {
+    /// Signer seeds of the Fraction PDA, which owns the escrow, the vault and the asset
+    fn with_fraction_seeds<T>(&self, grid_id: u64, f: impl FnOnce(&[&[&[u8]]]) -> T) -> T {
+        let grid_bytes = grid_seed(grid_id);
+        let epoch_bytes = epoch_seed(self.fraction.epoch);
+        let parcel_id_bytes = self.fraction.parcel_id.to_le_bytes();
+        f(&[&[Fraction::SEED, &grid_bytes, &epoch_bytes, &parcel_id_bytes, &[self.fraction.bump]]])
+    }
+
+    /// Moves the parcel's pending land-buy rewards from the pool into the vault and credits
+    /// them to deposited shares
+    fn harvest(&mut self, grid_id: u64) -> Result<()> {
+        let owed = settle_rewards_to(
+            &mut self.parcel_info,
+            grid_id,
+            &mut self.grid_config,
+            self.epoch_archive.as_deref(),
+            &self.land_buy_reward_pool,
+            &self.reward_vault,
+            &self.token_mint,
+            &self.token_program,
+        )?;
+        self.fraction.distribute(owed).ok_or(BillionError::Overflow)?;
+        Ok(())
+    }
+
+    /// Harvests, then pays the position everything owed at the new rewards_per_share.
+    /// Returns the amount paid.
+    pub(crate) fn harvest_and_settle(&mut self, grid_id: u64, position_bump: u8) -> Result<u64> {
+        self.harvest(grid_id)?;
+
+        let rewards_per_share = self.fraction.rewards_per_share;
+        let position = &mut self.position;
+        if position.holder == Pubkey::default() {
+            position.share_mint = self.share_mint.key();
+            position.holder = self.holder.key();
+            position.deposited = 0;
+            position.rewards_per_share_paid = rewards_per_share;
+            position.total_claimed = 0;
+            position.bump = position_bump;
+            position._reserved = [0u8; 16];
+        }
+
+        let owed = position.owed(rewards_per_share).ok_or(BillionError::Overflow)?;
+        position.rewards_per_share_paid = rewards_per_share;
+        position.total_claimed = position.total_claimed.checked_add(owed).ok_or(BillionError::Overflow)?;
+
+        if owed > 0 {
+            self.with_fraction_seeds(grid_id, |signer_seeds| {
+                token_2022::transfer_checked(
+                    CpiContext::new_with_signer(
+                        self.token_program.to_account_info(),
+                        token_2022::TransferChecked {
+                            from: self.reward_vault.to_account_info(),
+                            to: self.holder_token_account.to_account_info(),
+                            authority: self.fraction.to_account_info(),
+                            mint: self.token_mint.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    owed,
+                    self.token_mint.decimals,
+                )
+            })?;
+        }
+        Ok(owed)
+    }
+
+    /// Moves `amount` shares from the escrow back to the holder
+    pub(crate) fn release_shares(&self, grid_id: u64, amount: u64) -> Result<()> {
+        self.with_fraction_seeds(grid_id, |signer_seeds| {
+            token_2022::transfer_checked(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    token_2022::TransferChecked {
+                        from: self.share_escrow.to_account_info(),
+                        to: self.holder_share_account.to_account_info(),
+                        authority: self.fraction.to_account_info(),
+                        mint: self.share_mint.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+                self.share_mint.decimals,
+            )
+        })
+    }
+}
+
+pub fn handler(ctx: Context<FractionHolder>, grid_id: u64, parcel_id: u16) -> Result<()> {
+    let paid = ctx.accounts.harvest_and_settle(grid_id, ctx.bumps.position)?;
+    require!(paid > 0, BillionError::NothingToClaim);
+
+    msg!(
+        "Paid {} to {} for {} deposited shares of parcel {}",
+        paid,
+        ctx.accounts.holder.key(),
+        ctx.accounts.position.deposited,
+        parcel_id
+    );
+    Ok(())
+}
@@ -4,17 +4,25 @@ use anchor_spl::{
     token_interface::{Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface},
     associated_token::AssociatedToken,
 };
-use crate::state::{GridConfig, ParcelInfo, LAND_BUY_REWARD_POOL_SEED};
+use mpl_core::accounts::BaseAssetV1;
+use mpl_core::types::{Key as CoreKey, UpdateAuthority};
+use crate::state::{
+    epoch_seed, grid_seed, ClaimerStats, EpochArchive, GridConfig, ParcelInfo, RewardDelegate, LAND_BUY_REWARD_POOL_SEED,
+};
+use crate::constants::{REWARD_SCALE, REWARD_WEIGHT_UNIT};
 use crate::errors::BillionError;
+#[cfg(not(feature = "localnet"))]
+use crate::instructions::claim_parcel::MPL_CORE_ID;
 
 #[derive(Accounts)]
-#[instruction(parcel_id: u16)]
+#[instruction(grid_id: u64, parcel_id: u16)]
 pub struct ClaimLandBuyRewards<'info> {
     #[account(mut)]
     pub claimer: Signer<'info>,
 
     #[account(
-        seeds = [GridConfig::SEED],
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
         bump = grid_config.bump
     )]
     pub grid_config: Account<'info, GridConfig>,
@@ -22,7 +30,7 @@ pub struct ClaimLandBuyRewards<'info> {
     /// ParcelInfo PDA - derived from parcel_id
     #[account(
         mut,
-        seeds = [ParcelInfo::SEED, &parcel_id.to_le_bytes()],
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
         bump = parcel_info.bump
     )]
     pub parcel_info: Account<'info, ParcelInfo>,
@@ -43,85 +51,257 @@ pub struct ClaimLandBuyRewards<'info> {
     )]
     pub land_buy_reward_pool: InterfaceAccount<'info, InterfaceTokenAccount>,
 
-    /// Claimer's token account to receive rewards
-    #[account(
-        mut,
-        associated_token::mint = token_mint,
-        associated_token::authority = claimer,
-        associated_token::token_program = token_program,
-    )]
-    pub claimer_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    /// Owner of the asset, validated against it by the handler; the claimer unless a
+    /// delegate signs
+    /// CHECK: Compared with the Core asset's owner in handler
+    pub owner: UncheckedAccount<'info>,
+
+    /// Any of the owner's token accounts for the mint, ATA or not, to receive rewards
+    /// (checked in handler)
+    #[account(mut)]
+    pub owner_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
 
     #[account(
         constraint = token_mint.key() == grid_config.token_mint @ BillionError::Unauthorized
     )]
     pub token_mint: InterfaceAccount<'info, InterfaceMint>,
 
+    /// The owner's lifetime stats, created on first touch
+    #[account(
+        init_if_needed,
+        payer = claimer,
+        space = 8 + ClaimerStats::INIT_SPACE,
+        seeds = [ClaimerStats::SEED, &grid_seed(grid_id), owner.key().as_ref()],
+        bump
+    )]
+    pub claimer_stats: Box<Account<'info, ClaimerStats>>,
+
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    /// Archive of the parcel's epoch - required when the parcel is from an earlier epoch
+    #[account(
+        seeds = [EpochArchive::SEED, &grid_seed(grid_id), &epoch_archive.epoch.to_le_bytes()],
+        bump = epoch_archive.bump
+    )]
+    pub epoch_archive: Option<Account<'info, EpochArchive>>,
+
+    /// The parcel's reward delegate - required when the claimer isn't the owner
+    #[account(
+        seeds = [RewardDelegate::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = reward_delegate.bump
+    )]
+    pub reward_delegate: Option<Account<'info, RewardDelegate>>,
+}
+
+/// Parse a Metaplex Core asset account to extract the owner. Accounts that aren't an AssetV1
+/// of the Core program, such as a collection, fail with InvalidCoreAsset.
+pub(crate) fn get_core_asset_owner(asset_info: &AccountInfo) -> Result<Pubkey> {
+    #[cfg(not(feature = "localnet"))]
+    require_keys_eq!(*asset_info.owner, MPL_CORE_ID, BillionError::InvalidCoreAsset);
+    let asset = BaseAssetV1::from_bytes(&asset_info.try_borrow_data()?).map_err(|_| BillionError::InvalidCoreAsset)?;
+    require!(asset.key == CoreKey::AssetV1, BillionError::InvalidCoreAsset);
+    Ok(asset.owner)
 }
 
-/// Parse a Metaplex Core asset account to extract the owner
-fn get_core_asset_owner(asset_info: &AccountInfo) -> Result<Pubkey> {
-    // Deserialize the Core asset using mpl-core
-    let asset_data = asset_info.try_borrow_data()?;
-
-    // BaseAssetV1 layout:
-    // - key: 1 byte (discriminator)
-    // - owner: 32 bytes
-    // - update_authority: varies
-    // The owner is at offset 1
-    if asset_data.len() < 33 {
-        return Err(BillionError::InvalidCoreAsset.into());
+/// Collection a Core asset's own UpdateAuthority puts it in, if any, rather than whichever
+/// collection account was passed
+pub(crate) fn get_core_asset_collection(asset_info: &AccountInfo) -> Result<Option<Pubkey>> {
+    let asset = BaseAssetV1::from_bytes(&asset_info.try_borrow_data()?).map_err(|_| BillionError::InvalidCoreAsset)?;
+    require!(asset.key == CoreKey::AssetV1, BillionError::InvalidCoreAsset);
+    match asset.update_authority {
+        UpdateAuthority::Collection(collection) => Ok(Some(collection)),
+        _ => Ok(None),
     }
+}
 
-    // Read owner from bytes 1-33
-    let owner_bytes: [u8; 32] = asset_data[1..33]
-        .try_into()
-        .map_err(|_| BillionError::InvalidCoreAsset)?;
+/// Errors unless the Core asset belongs to `collection`
+pub(crate) fn require_asset_in_collection(asset_info: &AccountInfo, collection: &Pubkey) -> Result<()> {
+    require!(
+        get_core_asset_collection(asset_info)? == Some(*collection),
+        BillionError::InvalidCollection
+    );
+    Ok(())
+}
 
-    Ok(Pubkey::new_from_array(owner_bytes))
+/// Errors unless `owner` holds the parcel's asset, checked as for a land-buy reward claim. An
+/// asset outside the grid's collection is refused as InvalidCoreAsset even when the owner
+/// matches, so a ParcelInfo pointing at the wrong asset can't be claimed through.
+pub(crate) fn require_parcel_owner(
+    parcel_info: &ParcelInfo,
+    asset: &AccountInfo,
+    owner: &Pubkey,
+    grid_config: &GridConfig,
+) -> Result<()> {
+    require!(get_core_asset_owner(asset)? == *owner, BillionError::NotOwner);
+    // Parcels minted before a collection change stay in the collection of their generation,
+    // which isn't recorded, but every parcel was minted into some collection
+    let collection = get_core_asset_collection(asset)?.ok_or(BillionError::InvalidCoreAsset)?;
+    require!(
+        parcel_info.collection_generation != grid_config.collection_generation || collection == grid_config.collection,
+        BillionError::InvalidCoreAsset
+    );
+    Ok(())
 }
 
-pub fn handler(ctx: Context<ClaimLandBuyRewards>, parcel_id: u16) -> Result<()> {
-    // Verify claimer owns the NFT
-    let owner = get_core_asset_owner(&ctx.accounts.asset.to_account_info())?;
-    require!(owner == ctx.accounts.claimer.key(), BillionError::NotOwner);
+/// Errors unless `claimer` owns the parcel's asset and its land-buy rewards can be claimed
+/// at `now`
+pub(crate) fn require_rewards_claimable(
+    parcel_info: &ParcelInfo,
+    asset: &AccountInfo,
+    claimer: &Pubkey,
+    grid_config: &GridConfig,
+    now: i64,
+) -> Result<()> {
+    require_parcel_owner(parcel_info, asset, claimer, grid_config)?;
+    require!(!parcel_info.rewards_locked(now), BillionError::RewardsLocked);
+    require!(!parcel_info.reserved, BillionError::ParcelReserved);
+    Ok(())
+}
 
-    let parcel_info = &mut ctx.accounts.parcel_info;
-    let grid_config = &ctx.accounts.grid_config;
+/// Accumulator a parcel accrues against: the live one, or the final value in its epoch's
+/// archive once the parcel's epoch has ended
+pub(crate) fn parcel_rewards_per_block(
+    parcel_info: &ParcelInfo,
+    grid_config: &GridConfig,
+    epoch_archive: Option<&EpochArchive>,
+) -> Result<u128> {
+    if parcel_info.epoch == grid_config.epoch {
+        return Ok(grid_config.land_buy_rewards_per_block);
+    }
+    let archive = epoch_archive.ok_or(BillionError::EpochArchiveRequired)?;
+    require!(archive.epoch == parcel_info.epoch, BillionError::EpochArchiveRequired);
+    Ok(archive.land_buy_rewards_per_block)
+}
 
-    // Calculate owed rewards using the accumulator pattern
-    // Both values are u128 now, so subtraction stays in u128
-    let rewards_delta = grid_config
-        .land_buy_rewards_per_block
+/// Land-buy rewards owed to a parcel at `land_buy_rewards_per_block`, using the
+/// accumulator pattern: weighted tenths * (accumulator - checkpoint) / (1e9 * 10), which for
+/// an unweighted parcel is block_count * (accumulator - checkpoint) / 1e9. Parcels excluded
+/// from rewards are never owed anything.
+pub(crate) fn pending_land_buy_rewards(parcel_info: &ParcelInfo, land_buy_rewards_per_block: u128) -> Result<u64> {
+    // Both values are u128, so subtraction stays in u128
+    let rewards_delta = land_buy_rewards_per_block
         .checked_sub(parcel_info.last_claimed_land_buy_rewards_per_block)
         .ok_or(BillionError::Overflow)?;
 
-    // owed = block_count * rewards_delta / 1e9 (unscale)
-    let owed_u128 = (parcel_info.block_count() as u128)
+    let owed_u128 = (parcel_info.rewarded_weight() as u128)
         .checked_mul(rewards_delta)
         .ok_or(BillionError::Overflow)?
-        .checked_div(1_000_000_000)
+        .checked_div(REWARD_SCALE * REWARD_WEIGHT_UNIT as u128)
         .ok_or(BillionError::Overflow)?;
 
     // Convert to u64 for token transfer (final amount should fit in u64)
-    let owed = u64::try_from(owed_u128)
-        .map_err(|_| BillionError::Overflow)?;
+    Ok(u64::try_from(owed_u128).map_err(|_| BillionError::Overflow)?)
+}
+
+/// Advances the parcel's checkpoint and expiry clock past everything owed at
+/// `land_buy_rewards_per_block` and returns the amount. Fails without touching the parcel
+/// when `pool_balance` can't cover it, so an owner is never settled short.
+pub(crate) fn settle_pending_rewards(
+    parcel_info: &mut ParcelInfo,
+    land_buy_rewards_per_block: u128,
+    pool_balance: u64,
+    now: i64,
+) -> Result<u64> {
+    let owed = pending_land_buy_rewards(parcel_info, land_buy_rewards_per_block)?;
+    require!(owed <= pool_balance, BillionError::RewardPoolInsufficient);
+    parcel_info.last_claimed_land_buy_rewards_per_block = land_buy_rewards_per_block;
+    parcel_info.last_claimed_at = now;
+    Ok(owed)
+}
+
+/// Pays a parcel's pending land-buy rewards from the pool to `recipient` before the parcel
+/// changes hands, so they stay with the outgoing owner. Every instruction that moves a
+/// parcel settles through here. Returns the amount paid.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn settle_rewards_to<'info>(
+    parcel_info: &mut ParcelInfo,
+    grid_id: u64,
+    grid_config: &mut Account<'info, GridConfig>,
+    epoch_archive: Option<&EpochArchive>,
+    land_buy_reward_pool: &InterfaceAccount<'info, InterfaceTokenAccount>,
+    recipient: &InterfaceAccount<'info, InterfaceTokenAccount>,
+    token_mint: &InterfaceAccount<'info, InterfaceMint>,
+    token_program: &Interface<'info, TokenInterface>,
+) -> Result<u64> {
+    let land_buy_rewards_per_block = parcel_rewards_per_block(parcel_info, grid_config, epoch_archive)?;
+    let owed = settle_pending_rewards(
+        parcel_info,
+        land_buy_rewards_per_block,
+        land_buy_reward_pool.amount,
+        Clock::get()?.unix_timestamp,
+    )?;
+    grid_config.pay_land_buy_rewards(owed);
 
+    if owed > 0 {
+        let bump = grid_config.bump;
+        token_2022::transfer_checked(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token_2022::TransferChecked {
+                    from: land_buy_reward_pool.to_account_info(),
+                    to: recipient.to_account_info(),
+                    authority: grid_config.to_account_info(),
+                    mint: token_mint.to_account_info(),
+                },
+                &[&[GridConfig::SEED, &grid_seed(grid_id), &[bump]]],
+            ),
+            owed,
+            token_mint.decimals,
+        )?;
+    }
+    Ok(owed)
+}
+
+/// Pays a parcel's land-buy rewards to any of its owner's token accounts for the mint. The
+/// owner signs, or the RewardDelegate they set while they still hold the asset.
+pub fn handler(ctx: Context<ClaimLandBuyRewards>, grid_id: u64, parcel_id: u16) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let owner = ctx.accounts.owner.key();
+    require_rewards_claimable(
+        &ctx.accounts.parcel_info,
+        &ctx.accounts.asset.to_account_info(),
+        &owner,
+        &ctx.accounts.grid_config,
+        now,
+    )?;
+    let claimer = ctx.accounts.claimer.key();
+    require!(
+        claimer == owner
+            || ctx.accounts.reward_delegate.as_ref().is_some_and(|delegate| delegate.authorizes(&claimer, &owner)),
+        BillionError::NotRewardDelegate
+    );
+    let destination = &ctx.accounts.owner_token_account;
+    require!(
+        destination.owner == owner && destination.mint == ctx.accounts.grid_config.token_mint,
+        BillionError::InvalidRewardDestination
+    );
+
+    let parcel_info = &mut ctx.accounts.parcel_info;
+    let grid_config = &mut ctx.accounts.grid_config;
+
+    let land_buy_rewards_per_block =
+        parcel_rewards_per_block(parcel_info, grid_config, ctx.accounts.epoch_archive.as_deref())?;
+    let pool_balance = ctx.accounts.land_buy_reward_pool.amount;
+    let owed = settle_pending_rewards(parcel_info, land_buy_rewards_per_block, pool_balance, now)?;
     require!(owed > 0, BillionError::NothingToClaim);
+    grid_config.pay_land_buy_rewards(owed);
 
-    // Update last claimed checkpoint
-    parcel_info.last_claimed_land_buy_rewards_per_block = grid_config.land_buy_rewards_per_block;
+    // The adjacency bonus comes out of surplus, never out of rewards owed to other parcels
+    let surplus = grid_config.reward_surplus(ctx.accounts.land_buy_reward_pool.amount.saturating_sub(owed));
+    let bonus = grid_config.adjacency_bonus(owed, parcel_info.adjacency_score, surplus);
+    let payout = owed.checked_add(bonus).ok_or(BillionError::Overflow)?;
 
     // Transfer from pool to claimer (signed by GridConfig PDA)
     let bump = grid_config.bump;
-    let seeds: &[&[u8]] = &[GridConfig::SEED, &[bump]];
+    let grid_bytes = grid_seed(grid_id);
+    let seeds: &[&[u8]] = &[GridConfig::SEED, &grid_bytes, &[bump]];
     let signer_seeds: &[&[&[u8]]] = &[seeds];
 
     let cpi_accounts = token_2022::TransferChecked {
         from: ctx.accounts.land_buy_reward_pool.to_account_info(),
-        to: ctx.accounts.claimer_token_account.to_account_info(),
+        to: ctx.accounts.owner_token_account.to_account_info(),
         authority: ctx.accounts.grid_config.to_account_info(),
         mint: ctx.accounts.token_mint.to_account_info(),
     };
@@ -131,16 +311,143 @@ pub fn handler(ctx: Context<ClaimLandBuyRewards>, parcel_id: u16) -> Result<()>
             cpi_accounts,
             signer_seeds,
         ),
-        owed,
+        payout,
         ctx.accounts.token_mint.decimals,
     )?;
 
+    let stats = &mut ctx.accounts.claimer_stats;
+    stats.touch(owner, ctx.bumps.claimer_stats);
+    stats.record_rewards(payout).ok_or(BillionError::Overflow)?;
+
     msg!(
-        "Claimed {} tokens for parcel {} ({} blocks)",
+        "Claimed {} tokens (+{} adjacency bonus) for parcel {} ({} blocks)",
         owed,
+        bonus,
         parcel_id,
         parcel_info.block_count()
     );
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mpl_core::accounts::BaseCollectionV1;
+
+    fn parcel(block_count: u8, checkpoint: u128) -> ParcelInfo {
+        ParcelInfo {
+            asset: Pubkey::default(),
+            x: 0,
+            y: 0,
+            width: block_count,
+            height: 1,
+            bump: 255,
+            last_claimed_land_buy_rewards_per_block: checkpoint,
+            epoch: 0,
+            last_claimed_at: 100,
+            adjacency_score: 0,
+            locked_until: 0,
+            lock_rewards: false,
+            named: false,
+            premium_until: 0,
+            collection_generation: 0,
+            masked_block_count: 0,
+            reserved: false,
+            rewards_excluded: false,
+            paid_total: 0,
+            claimed_at: 0,
+            reward_weight: 0,
+            _reserved: [0u8; 0],
+        }
+    }
+
+    #[test]
+    fn test_settlement_advances_checkpoint_and_clock() {
+        let mut parcel_info = parcel(4, REWARD_SCALE);
+        let owed = settle_pending_rewards(&mut parcel_info, 3 * REWARD_SCALE, 1_000, 500).unwrap();
+        assert_eq!(owed, 8);
+        assert_eq!(parcel_info.last_claimed_land_buy_rewards_per_block, 3 * REWARD_SCALE);
+        assert_eq!(parcel_info.last_claimed_at, 500);
+    }
+
+    #[test]
+    fn test_zero_owed_settles_against_an_empty_pool() {
+        let mut parcel_info = parcel(4, REWARD_SCALE);
+        assert_eq!(settle_pending_rewards(&mut parcel_info, REWARD_SCALE, 0, 500).unwrap(), 0);
+        assert_eq!(parcel_info.last_claimed_at, 500);
+    }
+
+    #[test]
+    fn test_excluded_parcel_is_owed_nothing() {
+        let mut parcel_info = parcel(4, 0);
+        parcel_info.rewards_excluded = true;
+        assert_eq!(pending_land_buy_rewards(&parcel_info, 3 * REWARD_SCALE).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_weighted_parcel_is_owed_its_weight() {
+        let mut parcel_info = parcel(4, 0);
+        assert_eq!(pending_land_buy_rewards(&parcel_info, 3 * REWARD_SCALE).unwrap(), 12);
+        parcel_info.reward_weight = REWARD_WEIGHT_UNIT;
+        assert_eq!(pending_land_buy_rewards(&parcel_info, 3 * REWARD_SCALE).unwrap(), 12);
+        parcel_info.reward_weight = 2 * REWARD_WEIGHT_UNIT;
+        assert_eq!(pending_land_buy_rewards(&parcel_info, 3 * REWARD_SCALE).unwrap(), 24);
+    }
+
+    #[test]
+    fn test_insufficient_pool_leaves_parcel_untouched() {
+        let mut parcel_info = parcel(4, 0);
+        let result = settle_pending_rewards(&mut parcel_info, REWARD_SCALE, 3, 500);
+        assert_eq!(result.unwrap_err(), BillionError::RewardPoolInsufficient.into());
+        assert_eq!(parcel_info.last_claimed_land_buy_rewards_per_block, 0);
+        assert_eq!(parcel_info.last_claimed_at, 100);
+    }
+
+    fn core_account_owner(data: &mut [u8], program: Pubkey) -> Result<Pubkey> {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let account = AccountInfo::new(&key, false, false, &mut lamports, data, &program, false, 0);
+        get_core_asset_owner(&account)
+    }
+
+    fn asset_data(owner: Pubkey) -> Vec<u8> {
+        let asset = BaseAssetV1 {
+            key: CoreKey::AssetV1,
+            owner,
+            update_authority: UpdateAuthority::Collection(Pubkey::new_unique()),
+            name: "Parcel".to_string(),
+            uri: "https://example.com/1.json".to_string(),
+            seq: None,
+        };
+        asset.try_to_vec().unwrap()
+    }
+
+    #[test]
+    fn test_core_asset_owner_is_read_from_an_asset() {
+        let owner = Pubkey::new_unique();
+        assert_eq!(core_account_owner(&mut asset_data(owner), MPL_CORE_ID).unwrap(), owner);
+    }
+
+    #[test]
+    fn test_non_asset_accounts_have_no_owner() {
+        let invalid: Error = BillionError::InvalidCoreAsset.into();
+        let collection = BaseCollectionV1 {
+            key: CoreKey::CollectionV1,
+            update_authority: Pubkey::new_unique(),
+            name: "Billion".to_string(),
+            uri: "https://example.com/collection.json".to_string(),
+            num_minted: 1,
+            current_size: 1,
+        };
+        let mut data = collection.try_to_vec().unwrap();
+        assert_eq!(core_account_owner(&mut data, MPL_CORE_ID).unwrap_err(), invalid);
+
+        // The old offset parsing accepted any 33 bytes
+        let mut truncated = asset_data(Pubkey::new_unique())[..33].to_vec();
+        assert_eq!(core_account_owner(&mut truncated, MPL_CORE_ID).unwrap_err(), invalid);
+
+        let mut foreign = asset_data(Pubkey::new_unique());
+        assert_eq!(core_account_owner(&mut foreign, Pubkey::new_unique()).unwrap_err(), invalid);
+    }
+}
@@ -0,0 +1,171 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022,
+    token_interface::{Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface},
+    associated_token::AssociatedToken,
+};
+use crate::state::{epoch_seed, grid_seed, ClaimerStats, EpochArchive, GridConfig, ParcelInfo, LAND_BUY_REWARD_POOL_SEED};
+use crate::constants::MAX_REWARD_CLAIM_BATCH;
+use crate::errors::BillionError;
+use crate::events::{LandBuyRewardsClaimed, ParcelRewardPayout, EVENT_SCHEMA_VERSION};
+use crate::instructions::claim_land_buy_rewards::{
+    parcel_rewards_per_block, require_parcel_owner, settle_pending_rewards,
+};
+
+/// Same accounts as ClaimLandBuyRewards minus the per-parcel ones, which come in
+/// remaining_accounts. There is no delegate: the claimer must own every parcel.
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct ClaimLandBuyRewardsBatch<'info> {
+    #[account(mut)]
+    pub claimer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Box<Account<'info, GridConfig>>,
+
+    /// Land buy reward pool holding the tokens
+    #[account(
+        mut,
+        seeds = [LAND_BUY_REWARD_POOL_SEED, grid_config.key().as_ref()],
+        bump,
+        constraint = land_buy_reward_pool.key() == grid_config.land_buy_reward_pool @ BillionError::InvalidRewardPool
+    )]
+    pub land_buy_reward_pool: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    /// Claimer's token account to receive rewards
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = claimer,
+        associated_token::token_program = token_program,
+    )]
+    pub claimer_token_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    #[account(
+        constraint = token_mint.key() == grid_config.token_mint @ BillionError::Unauthorized
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, InterfaceMint>>,
+
+    /// The claimer's lifetime stats, created on first touch
+    #[account(
+        init_if_needed,
+        payer = claimer,
+        space = 8 + ClaimerStats::INIT_SPACE,
+        seeds = [ClaimerStats::SEED, &grid_seed(grid_id), claimer.key().as_ref()],
+        bump
+    )]
+    pub claimer_stats: Box<Account<'info, ClaimerStats>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    /// Archive of an earlier epoch - required for parcels from that epoch
+    #[account(
+        seeds = [EpochArchive::SEED, &grid_seed(grid_id), &epoch_archive.epoch.to_le_bytes()],
+        bump = epoch_archive.bump
+    )]
+    pub epoch_archive: Option<Account<'info, EpochArchive>>,
+}
+
+/// Reads the ParcelInfo for `parcel_id` out of remaining_accounts, checking it is the
+/// program's PDA for the epoch it records
+pub(crate) fn load_parcel_info(account: &AccountInfo, grid_id: u64, parcel_id: u16) -> Result<ParcelInfo> {
+    require!(account.owner == &crate::ID, BillionError::InvalidBatch);
+    let parcel_info = ParcelInfo::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    let expected = Pubkey::create_program_address(
+        &[ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes(), &[parcel_info.bump]],
+        &crate::ID,
+    )
+    .map_err(|_| BillionError::InvalidBatch)?;
+    require!(account.key() == expected, BillionError::InvalidBatch);
+    Ok(parcel_info)
+}
+
+/// claim_land_buy_rewards for several parcels, paid in one transfer. remaining_accounts
+/// hold each parcel's ParcelInfo and Core asset, in `parcel_ids` order. Every asset must
+/// belong to the claimer or the whole batch fails; parcels with nothing to claim, including
+/// vesting ones whose rewards are still locked, are skipped.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimLandBuyRewardsBatch<'info>>,
+    grid_id: u64,
+    parcel_ids: Vec<u16>,
+) -> Result<()> {
+    require!(
+        !parcel_ids.is_empty() && parcel_ids.len() <= MAX_REWARD_CLAIM_BATCH as usize,
+        BillionError::InvalidBatch
+    );
+    require!(
+        ctx.remaining_accounts.len() == 2 * parcel_ids.len(),
+        BillionError::InvalidBatch
+    );
+
+    let claimer = ctx.accounts.claimer.key();
+    let grid_config = &mut ctx.accounts.grid_config;
+    let epoch_archive = ctx.accounts.epoch_archive.as_deref();
+    let now = Clock::get()?.unix_timestamp;
+    let mut pool_left = ctx.accounts.land_buy_reward_pool.amount;
+    let mut payouts = Vec::with_capacity(parcel_ids.len());
+
+    for (&parcel_id, accounts) in parcel_ids.iter().zip(ctx.remaining_accounts.chunks(2)) {
+        let (parcel_account, asset) = (&accounts[0], &accounts[1]);
+        require!(parcel_account.is_writable, BillionError::InvalidBatch);
+        let mut parcel_info = load_parcel_info(parcel_account, grid_id, parcel_id)?;
+        require!(asset.key() == parcel_info.asset, BillionError::AssetMismatch);
+        require_parcel_owner(&parcel_info, asset, &claimer, grid_config)?;
+        if parcel_info.rewards_locked(now) {
+            continue;
+        }
+
+        let land_buy_rewards_per_block = parcel_rewards_per_block(&parcel_info, grid_config, epoch_archive)?;
+        let owed = settle_pending_rewards(&mut parcel_info, land_buy_rewards_per_block, pool_left, now)?;
+        if owed == 0 {
+            continue;
+        }
+        grid_config.pay_land_buy_rewards(owed);
+        pool_left -= owed;
+
+        // As in claim_land_buy_rewards, the bonus only comes out of what's left as surplus
+        let bonus = grid_config.adjacency_bonus(owed, parcel_info.adjacency_score, grid_config.reward_surplus(pool_left));
+        pool_left -= bonus;
+
+        parcel_info.try_serialize(&mut &mut parcel_account.try_borrow_mut_data()?[..])?;
+        payouts.push(ParcelRewardPayout { parcel_id, epoch: parcel_info.epoch, owed, adjacency_bonus: bonus });
+    }
+
+    let total = ctx.accounts.land_buy_reward_pool.amount - pool_left;
+    require!(total > 0, BillionError::NothingToClaim);
+
+    let bump = grid_config.bump;
+    token_2022::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_2022::TransferChecked {
+                from: ctx.accounts.land_buy_reward_pool.to_account_info(),
+                to: ctx.accounts.claimer_token_account.to_account_info(),
+                authority: ctx.accounts.grid_config.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+            },
+            &[&[GridConfig::SEED, &grid_seed(grid_id), &[bump]]],
+        ),
+        total,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    let stats = &mut ctx.accounts.claimer_stats;
+    stats.touch(claimer, ctx.bumps.claimer_stats);
+    stats.record_rewards(total).ok_or(BillionError::Overflow)?;
+
+    msg!("Claimed {} tokens for {} of {} parcels", total, payouts.len(), parcel_ids.len());
+    emit!(LandBuyRewardsClaimed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        claimer,
+        payouts,
+        total,
+    });
+    Ok(())
+}
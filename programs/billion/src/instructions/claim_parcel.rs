@@ -1,26 +1,43 @@
 use anchor_lang::prelude::*;
+use std::ops::Deref;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::{invoke_signed, set_return_data},
+};
 use anchor_spl::{
     token_2022,
     token_interface::{Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface},
     associated_token::AssociatedToken,
 };
 use mpl_core::instructions::CreateV2CpiBuilder;
-use crate::state::{GridConfig, BlockMap, ParcelInfo, GRID_SIZE, LAND_BUY_REWARD_POOL_SEED};
+use billion_core::{mask_block_count, mask_is_valid, Rect};
+use crate::state::{
+    epoch_seed, grid_seed, Allowlist, GridConfig, BlockMap, CharityRegistry, ClaimerStats, HookConfig, OnParcelClaimed,
+    ParcelIdPool, ParcelInfo, ReferralShare, ReferrerAccount, RewardDust, RingPricing, RingRewardWeights, RingOccupancy,
+    MapDigest, RingUnlockSchedule, TokenTreasury, BlockMapData, LAND_BUY_REWARD_POOL_SEED,
+};
+use crate::constants::MAX_HOOK_ACCOUNTS;
 use crate::errors::BillionError;
-use crate::utils::{get_ring, get_unlocked_ring};
+use crate::events::{ParcelClaimed, PaymentSettled, ReferralSharePaid, RingUnlocked, TreasuryPaid, EVENT_SCHEMA_VERSION};
+use crate::instructions::claim_land_buy_rewards::get_core_asset_owner;
+use crate::instructions::refresh_parcel_attributes::parcel_attributes_plugin;
+use crate::instructions::admin_backfill_ring_occupancy::record_ring_occupancy;
+use crate::instructions::init_map_digest::record_map_write;
+use crate::utils::{first_locked_of, get_ring, get_unlocked_ring, verify_allowlist_proof};
 
 // Metaplex Core program ID
+// Pinned in every account constraint unless built with the `localnet` feature
 pub const MPL_CORE_ID: Pubkey = pubkey!("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d");
 
 #[derive(Accounts)]
-#[instruction(x: u8, y: u8, width: u8, height: u8)]
+#[instruction(grid_id: u64, x: u8, y: u8, width: u8, height: u8, dry_run: bool)]
 pub struct ClaimParcel<'info> {
     #[account(mut)]
     pub claimer: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [GridConfig::SEED],
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
         bump = grid_config.bump
     )]
     pub grid_config: Account<'info, GridConfig>,
@@ -57,12 +74,43 @@ pub struct ClaimParcel<'info> {
     )]
     pub land_buy_reward_pool: InterfaceAccount<'info, InterfaceTokenAccount>,
 
+    /// The claimer's lifetime stats, created on first touch
+    #[account(
+        init_if_needed,
+        payer = claimer,
+        space = 8 + ClaimerStats::INIT_SPACE,
+        seeds = [ClaimerStats::SEED, &grid_seed(grid_id), claimer.key().as_ref()],
+        bump
+    )]
+    pub claimer_stats: Box<Account<'info, ClaimerStats>>,
+
+    /// Rounding left in the reward pool by the accumulator, created on first touch
+    #[account(
+        init_if_needed,
+        payer = claimer,
+        space = 8 + RewardDust::INIT_SPACE,
+        seeds = [RewardDust::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub reward_dust: Box<Account<'info, RewardDust>>,
+
+    /// Freed parcel ids, reused before next_parcel_id. Uninitialized until the authority first
+    /// frees one with admin_close_parcel_info.
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(mut, seeds = [ParcelIdPool::SEED, &grid_seed(grid_id)], bump)]
+    pub parcel_id_pool: UncheckedAccount<'info>,
+
     /// Parcel info PDA - stores asset address for lookups
     #[account(
         init,
         payer = claimer,
         space = 8 + ParcelInfo::INIT_SPACE,
-        seeds = [ParcelInfo::SEED, &grid_config.next_parcel_id.to_le_bytes()],
+        seeds = [
+            ParcelInfo::SEED,
+            &grid_seed(grid_id),
+            &epoch_seed(grid_config.epoch),
+            &ParcelIdPool::next_parcel_id(&parcel_id_pool, grid_config.epoch, grid_config.next_parcel_id)?.to_le_bytes(),
+        ],
         bump
     )]
     pub parcel_info: Account<'info, ParcelInfo>,
@@ -79,173 +127,874 @@ pub struct ClaimParcel<'info> {
     )]
     pub collection: UncheckedAccount<'info>,
 
-    /// CHECK: Metaplex Core program
-    #[account(address = MPL_CORE_ID)]
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
     pub mpl_core_program: UncheckedAccount<'info>,
 
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
+
+    /// On-claim hook config, always required so a claim can't skip an enabled hook.
+    /// Uninitialized until the authority first calls set_hook.
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(seeds = [HookConfig::SEED, &grid_seed(grid_id)], bump)]
+    pub hook_config: UncheckedAccount<'info>,
+
+    /// Per-ring price multipliers. Uninitialized until the authority first calls update_config,
+    /// in which case every ring costs price_per_block.
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(seeds = [RingPricing::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_pricing: UncheckedAccount<'info>,
+
+    /// Per-ring land-buy reward weights, which count the parcel's extra weight; every block
+    /// is unweighted while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(mut, seeds = [RingRewardWeights::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_reward_weights: UncheckedAccount<'info>,
+
+    /// Claimed blocks per ring, which count the parcel's blocks; nothing is counted until
+    /// it's created
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(mut, seeds = [RingOccupancy::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_occupancy: UncheckedAccount<'info>,
+
+    /// Rolling digest of BlockMap writes, which chains in the parcel's blocks
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(mut, seeds = [MapDigest::SEED, &grid_seed(grid_id)], bump)]
+    pub map_digest: UncheckedAccount<'info>,
+
+    /// Times rings open whatever has been burned; rings unlock by burns alone while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(seeds = [RingUnlockSchedule::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_unlock_schedule: UncheckedAccount<'info>,
+
+    /// Protocol treasury share of the cost; nothing is owed while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(seeds = [TokenTreasury::SEED, &grid_seed(grid_id)], bump)]
+    pub token_treasury: UncheckedAccount<'info>,
+
+    /// The configured treasury token account - required while the treasury share is nonzero
+    #[account(mut)]
+    pub treasury_token_account: Option<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    /// Referrer credited with referral_bps of the cost, if any
+    #[account(
+        mut,
+        seeds = [ReferrerAccount::SEED, &grid_seed(grid_id), referrer_account.referrer.as_ref()],
+        bump = referrer_account.bump
+    )]
+    pub referrer_account: Option<Account<'info, ReferrerAccount>>,
+
+    /// Referral vault - required when a referrer is passed and referral_bps > 0
+    #[account(
+        mut,
+        constraint = referral_vault.key() == grid_config.referral_vault @ BillionError::ReferralsDisabled
+    )]
+    pub referral_vault: Option<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    /// Charity registry - required when a charity_index is passed
+    #[account(
+        seeds = [CharityRegistry::SEED, &grid_seed(grid_id)],
+        bump = charity_registry.bump
+    )]
+    pub charity_registry: Option<Account<'info, CharityRegistry>>,
+
+    /// The registered charity token account at charity_index
+    #[account(mut)]
+    pub charity_token_account: Option<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    /// Allowlist root - required while grid_config.allowlist_only is set
+    #[account(
+        seeds = [Allowlist::SEED, &grid_seed(grid_id)],
+        bump = allowlist.bump
+    )]
+    pub allowlist: Option<Account<'info, Allowlist>>,
+
+    /// Owner of the new asset when the parcel is a gift; the claimer when omitted
+    /// CHECK: Any wallet can own a Core asset, and nothing is read from or paid by it
+    pub recipient: Option<UncheckedAccount<'info>>,
+
+    /// Share of the cost paid to a referrer token account passed in remaining_accounts;
+    /// nothing is paid while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(seeds = [ReferralShare::SEED, &grid_seed(grid_id)], bump)]
+    pub referral_share: UncheckedAccount<'info>,
 }
 
-/// Validates that the claim is valid
-fn validate_claim(
+/// Accounts used by every public claim path, borrowed from the instruction context
+pub struct ClaimAccounts<'a, 'info> {
+    /// Receives the asset and is charged the cost
+    pub claimer: AccountInfo<'info>,
+    /// Pays rent for the asset; the claimer except in signed claims, where the relayer pays
+    pub payer: AccountInfo<'info>,
+    /// Receives the asset; the claimer unless claim_parcel names a gift recipient
+    pub owner: AccountInfo<'info>,
+    /// Whether the GridConfig PDA moves the claimer's tokens as their approved delegate
+    /// instead of the claimer signing
+    pub delegated: bool,
+    /// Whether the claimer proved they're on the allowlist; only claim_parcel takes a proof
+    pub allowlisted: bool,
+    /// Grid the GridConfig PDA was derived for, to sign as it
+    pub grid_id: u64,
+    pub grid_config: &'a mut Account<'info, GridConfig>,
+    pub block_map: &'a AccountLoader<'info, BlockMap>,
+    pub token_mint: &'a InterfaceAccount<'info, InterfaceMint>,
+    pub claimer_token_account: &'a InterfaceAccount<'info, InterfaceTokenAccount>,
+    pub land_buy_reward_pool: &'a InterfaceAccount<'info, InterfaceTokenAccount>,
+    pub claimer_stats: &'a mut Account<'info, ClaimerStats>,
+    pub claimer_stats_bump: u8,
+    pub parcel_info: &'a mut Account<'info, ParcelInfo>,
+    pub parcel_info_bump: u8,
+    pub asset: AccountInfo<'info>,
+    pub collection: &'a UncheckedAccount<'info>,
+    pub mpl_core_program: &'a UncheckedAccount<'info>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+    pub system_program: &'a Program<'info, System>,
+    pub hook_config: &'a UncheckedAccount<'info>,
+    pub ring_pricing: &'a UncheckedAccount<'info>,
+    pub ring_reward_weights: &'a UncheckedAccount<'info>,
+    pub ring_occupancy: &'a UncheckedAccount<'info>,
+    pub map_digest: &'a UncheckedAccount<'info>,
+    pub ring_unlock_schedule: &'a UncheckedAccount<'info>,
+    pub token_treasury: &'a UncheckedAccount<'info>,
+    pub treasury_token_account: Option<&'a InterfaceAccount<'info, InterfaceTokenAccount>>,
+    /// Freed ids to draw the parcel id from; only claim_parcel passes it, since the other
+    /// paths derive addresses from next_parcel_id
+    pub parcel_id_pool: Option<&'a UncheckedAccount<'info>>,
+    /// Direct referral share; only claim_parcel passes it
+    pub referral_share: Option<&'a UncheckedAccount<'info>>,
+    pub reward_dust: &'a mut Account<'info, RewardDust>,
+    pub reward_dust_bump: u8,
+    /// The instruction's remaining_accounts: the adjacency proof when one is required, the
+    /// referrer token account while a direct referral share is set, then the hook program and
+    /// the accounts passed through to it
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+    pub referrer_account: Option<&'a mut Account<'info, ReferrerAccount>>,
+    pub referral_vault: Option<&'a InterfaceAccount<'info, InterfaceTokenAccount>>,
+    /// Charity token account and the basis points of the cost routed to it, as resolved by
+    /// select_charity
+    pub charity: Option<(&'a InterfaceAccount<'info, InterfaceTokenAccount>, u16)>,
+}
+
+/// Resolves the buyer's charity choice against the registry. Runs before any token moves,
+/// so an unknown index or mismatched account fails the claim with nothing transferred.
+pub(crate) fn select_charity<'a, 'info>(
+    grid_config: &GridConfig,
+    charity_registry: Option<&CharityRegistry>,
+    charity_token_account: Option<&'a InterfaceAccount<'info, InterfaceTokenAccount>>,
+    charity_index: Option<u8>,
+    charity_bps: u16,
+) -> Result<Option<(&'a InterfaceAccount<'info, InterfaceTokenAccount>, u16)>> {
+    let Some(index) = charity_index else {
+        require!(charity_bps == 0, BillionError::InvalidCharity);
+        return Ok(None);
+    };
+    let charity = charity_registry
+        .and_then(|registry| registry.get(index))
+        .ok_or(BillionError::InvalidCharity)?;
+    let token_account = charity_token_account.ok_or(BillionError::InvalidCharity)?;
+    require!(
+        token_account.key() == charity && token_account.mint == grid_config.token_mint,
+        BillionError::InvalidCharity
+    );
+    require!(
+        charity_bps > 0 && charity_bps <= grid_config.max_charity_bps,
+        BillionError::InvalidCharityShare
+    );
+    Ok(Some((token_account, charity_bps)))
+}
+
+/// Validates that the claim is valid. Shared by every public claim path, so it also enforces
+/// the claims pause; admin_mint has its own validation. `unlocked_ring` is the grid's at the
+/// time of the claim, from GridConfig::unlocked_ring_at.
+pub(crate) fn validate_claim(
     x: u8,
     y: u8,
     width: u8,
     height: u8,
-    block_map: &BlockMap,
+    block_map: &BlockMapData<impl Deref<Target = [u8]>>,
+    grid_config: &GridConfig,
+    unlocked_ring: u8,
+) -> Result<()> {
+    validate_claim_blocks(Rect::new(x, y, width, height), None, block_map, grid_config, unlocked_ring)
+}
+
+/// validate_claim for a parcel covering `rect`, or only the cells of it set in `mask`. The
+/// bounding box must still lie on the grid, whose size is the BlockMap's; the size cap, ring,
+/// and availability checks apply to the covered blocks alone.
+pub(crate) fn validate_claim_blocks(
+    rect: Rect,
+    mask: Option<&[u8]>,
+    block_map: &BlockMapData<impl Deref<Target = [u8]>>,
     grid_config: &GridConfig,
+    unlocked_ring: u8,
 ) -> Result<()> {
+    require!(!grid_config.claims_paused, BillionError::ClaimsPaused);
+
     // Check dimensions are valid
-    require!(width > 0 && height > 0, BillionError::InvalidDimensions);
+    require!(rect.width > 0 && rect.height > 0, BillionError::InvalidDimensions);
+    if let Some(mask) = mask {
+        require!(mask_is_valid(mask, rect.width, rect.height), BillionError::InvalidMask);
+    }
+    let block_count = mask.map_or(rect.block_count(), mask_block_count);
+    require!(grid_config.allows_parcel_blocks(block_count), BillionError::ParcelTooLarge);
 
     // Check bounds
+    let grid_size = block_map.grid_size();
     require!(
-        (x as usize) + (width as usize) <= GRID_SIZE,
+        (rect.x as u16) + (rect.width as u16) <= grid_size,
         BillionError::OutOfBounds
     );
     require!(
-        (y as usize) + (height as usize) <= GRID_SIZE,
+        (rect.y as u16) + (rect.height as u16) <= grid_size,
         BillionError::OutOfBounds
     );
 
-    // Get the currently unlocked ring
-    let unlocked_ring = get_unlocked_ring(grid_config.total_burned, &grid_config.ring_thresholds);
+    // Name the first block outside the unlocked rings, so a claim straddling a ring boundary
+    // says where it failed
+    let locked = first_locked_of(claim_blocks(rect, mask), unlocked_ring, grid_size, grid_config.ring_count());
+    if let Some((block_x, block_y)) = locked {
+        msg!(
+            "ClaimRejected: block ({}, {}) is in ring {}, unlocked ring is {}",
+            block_x,
+            block_y,
+            get_ring(block_x, block_y, grid_size, grid_config.ring_count()),
+            unlocked_ring
+        );
+        return err!(BillionError::RingLocked);
+    }
 
-    // Check each block in the parcel
-    for dy in 0..height {
-        for dx in 0..width {
-            let block_x = x + dx;
-            let block_y = y + dy;
+    // Check each block in the parcel is unclaimed (value == 0)
+    for (block_x, block_y) in claim_blocks(rect, mask) {
+        let block_value = block_map.get_block(block_x, block_y);
+        require!(block_value == 0, BillionError::BlockAlreadyClaimed);
+    }
 
-            // Check if block is in unlocked ring
-            let block_ring = get_ring(block_x, block_y);
-            require!(block_ring <= unlocked_ring, BillionError::RingLocked);
+    Ok(())
+}
 
-            // Check if block is unclaimed (value == 0)
-            let block_value = block_map.get_block(block_x, block_y);
-            require!(block_value == 0, BillionError::BlockAlreadyClaimed);
-        }
+/// The blocks a claim covers: all of `rect`, or the cells set in `mask`
+pub(crate) fn claim_blocks<'a>(rect: Rect, mask: Option<&'a [u8]>) -> Box<dyn Iterator<Item = (u8, u8)> + 'a> {
+    match mask {
+        Some(mask) => Box::new(rect.masked_blocks(mask)),
+        None => Box::new(rect.blocks()),
     }
+}
 
-    Ok(())
+/// Outcome of a claim, computed before anything is transferred or written.
+/// Returned via return data by `claim_parcel` with `dry_run` set.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ClaimPreview {
+    pub parcel_id: u16,
+    pub block_count: u32,
+    pub total_cost: u64,
+    pub burn_amount: u64,
+    pub reward_amount: u64,
+    /// GridConfig values after the claim
+    pub total_burned: u64,
+    pub total_claimed_blocks: u32,
+    pub land_buy_rewards_per_block: u128,
+    pub unlocked_ring: u8,
+    /// Portion of total_cost credited to the referrer (taken from the burn)
+    pub referral_amount: u64,
+    /// Portion of total_cost routed to the chosen charity (taken from the burn)
+    pub charity_amount: u64,
+    /// Per-block price after the bonding curve, before ring multipliers
+    pub price_per_block: u64,
+    /// Portion of total_cost sent to the protocol treasury (taken from the burn)
+    pub treasury_amount: u64,
+    /// Portion of total_cost paid straight to the referrer's token account; the other
+    /// amounts split what's left
+    pub referral_share_amount: u64,
+}
+
+/// Cost split and post-claim grid state for a parcel covering `rect`, or only the cells of it
+/// set in `mask`. Every block is priced at the curve price from before the claim, then by its
+/// ring's multiplier.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn preview_claim(
+    rect: Rect,
+    mask: Option<&[u8]>,
+    grid_size: u16,
+    referred: bool,
+    referral_share_bps: u16,
+    charity_bps: u16,
+    grid_config: &GridConfig,
+    ring_pricing: &RingPricing,
+    ring_reward_weights: &RingRewardWeights,
+    token_treasury: &TokenTreasury,
+) -> Result<ClaimPreview> {
+    let num_blocks = match mask {
+        Some(mask) => mask_block_count(mask),
+        None => (rect.width as u32).checked_mul(rect.height as u32).ok_or(BillionError::Overflow)?,
+    };
+    let price_per_block = ring_pricing
+        .curve_price(grid_config.price_per_block, grid_config.total_claimed_blocks)
+        .ok_or(BillionError::Overflow)?;
+    let total_cost = ring_pricing
+        .blocks_cost(claim_blocks(rect, mask), price_per_block, grid_size, grid_config.ring_count())
+        .ok_or(BillionError::Overflow)?;
+    preview_claim_cost(
+        num_blocks,
+        price_per_block,
+        total_cost,
+        referred,
+        referral_share_bps,
+        charity_bps,
+        grid_config,
+        ring_reward_weights,
+        token_treasury,
+    )
+}
+
+/// Cost split and post-claim grid state for `num_blocks` blocks bought for `total_cost` in
+/// one payment, `price_per_block` being the curve price they were charged at
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn preview_claim_cost(
+    num_blocks: u32,
+    price_per_block: u64,
+    total_cost: u64,
+    referred: bool,
+    referral_share_bps: u16,
+    charity_bps: u16,
+    grid_config: &GridConfig,
+    ring_reward_weights: &RingRewardWeights,
+    token_treasury: &TokenTreasury,
+) -> Result<ClaimPreview> {
+    // A direct referral share comes off the top; the rest of the split is of what's left
+    let referral_share_amount = total_cost
+        .checked_mul(referral_share_bps as u64)
+        .ok_or(BillionError::Overflow)?
+        .checked_div(10_000)
+        .ok_or(BillionError::Overflow)?;
+    let split_cost = total_cost.checked_sub(referral_share_amount).ok_or(BillionError::Overflow)?;
+
+    // Calculate reward/burn split
+    let reward_amount = split_cost
+        .checked_mul(grid_config.land_owners_reward_share_bps as u64)
+        .ok_or(BillionError::Overflow)?
+        .checked_div(10_000)
+        .ok_or(BillionError::Overflow)?;
+    let referral_amount = if referred {
+        split_cost
+            .checked_mul(grid_config.referral_bps as u64)
+            .ok_or(BillionError::Overflow)?
+            .checked_div(10_000)
+            .ok_or(BillionError::Overflow)?
+    } else {
+        0
+    };
+    let charity_amount = split_cost
+        .checked_mul(charity_bps as u64)
+        .ok_or(BillionError::Overflow)?
+        .checked_div(10_000)
+        .ok_or(BillionError::Overflow)?;
+    let treasury_amount = token_treasury.share(split_cost).ok_or(BillionError::Overflow)?;
+    // Only what is actually burned counts toward total_burned and ring unlocks
+    let burn_amount = split_cost
+        .checked_sub(reward_amount)
+        .ok_or(BillionError::Overflow)?
+        .checked_sub(referral_amount)
+        .ok_or(BillionError::Overflow)?
+        .checked_sub(charity_amount)
+        .ok_or(BillionError::Overflow)?
+        .checked_sub(treasury_amount)
+        .ok_or(BillionError::Overflow)?;
+
+    // Distribute rewards to existing landowners BEFORE adding new blocks
+    let mut land_buy_rewards_per_block = grid_config.land_buy_rewards_per_block;
+    if grid_config.total_claimed_blocks > 0 && reward_amount > 0 {
+        let reward_units = ring_reward_weights
+            .reward_units(grid_config.epoch, grid_config.total_claimed_blocks)
+            .ok_or(BillionError::Overflow)?;
+        let reward_increase =
+            RingRewardWeights::reward_increase(reward_amount, reward_units).ok_or(BillionError::Overflow)?;
+
+        land_buy_rewards_per_block = land_buy_rewards_per_block
+            .checked_add(reward_increase)
+            .ok_or(BillionError::Overflow)?;
+    }
+
+    // Update total claimed blocks (include new parcel)
+    let total_claimed_blocks = grid_config
+        .total_claimed_blocks
+        .checked_add(num_blocks)
+        .ok_or(BillionError::Overflow)?;
+    let total_burned = grid_config
+        .total_burned
+        .checked_add(burn_amount)
+        .ok_or(BillionError::Overflow)?;
+
+    Ok(ClaimPreview {
+        parcel_id: grid_config.next_parcel_id,
+        block_count: num_blocks,
+        total_cost,
+        burn_amount,
+        reward_amount,
+        total_burned,
+        total_claimed_blocks,
+        land_buy_rewards_per_block,
+        unlocked_ring: get_unlocked_ring(total_burned, &grid_config.ring_thresholds, grid_config.ring_count()),
+        referral_amount,
+        charity_amount,
+        price_per_block,
+        treasury_amount,
+        referral_share_amount,
+    })
 }
 
-pub fn handler(
-    ctx: Context<ClaimParcel>,
+#[allow(clippy::too_many_arguments)]
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimParcel<'info>>,
+    grid_id: u64,
     x: u8,
     y: u8,
     width: u8,
     height: u8,
+    dry_run: bool,
+    charity_index: Option<u8>,
+    charity_bps: u16,
+    allowlist_proof: Option<Vec<[u8; 32]>>,
 ) -> Result<()> {
+    let allowlisted = match (ctx.accounts.allowlist.as_ref(), allowlist_proof) {
+        (Some(allowlist), Some(proof)) if ctx.accounts.grid_config.allowlist_only => {
+            verify_allowlist_proof(&proof, &allowlist.root, &ctx.accounts.claimer.key())
+        }
+        _ => false,
+    };
+    let charity = select_charity(
+        &ctx.accounts.grid_config,
+        ctx.accounts.charity_registry.as_deref(),
+        ctx.accounts.charity_token_account.as_ref(),
+        charity_index,
+        charity_bps,
+    )?;
+
+    // A gift recipient only receives the asset; everything else is the claimer's
+    let owner = match &ctx.accounts.recipient {
+        Some(recipient) => recipient.to_account_info(),
+        None => ctx.accounts.claimer.to_account_info(),
+    };
+    let accounts = ClaimAccounts {
+        claimer: ctx.accounts.claimer.to_account_info(),
+        payer: ctx.accounts.claimer.to_account_info(),
+        owner,
+        delegated: false,
+        allowlisted,
+        grid_id,
+        grid_config: &mut ctx.accounts.grid_config,
+        block_map: &ctx.accounts.block_map,
+        token_mint: &ctx.accounts.token_mint,
+        claimer_token_account: &ctx.accounts.claimer_token_account,
+        land_buy_reward_pool: &ctx.accounts.land_buy_reward_pool,
+        claimer_stats: &mut ctx.accounts.claimer_stats,
+        claimer_stats_bump: ctx.bumps.claimer_stats,
+        parcel_info: &mut ctx.accounts.parcel_info,
+        parcel_info_bump: ctx.bumps.parcel_info,
+        asset: ctx.accounts.asset.to_account_info(),
+        collection: &ctx.accounts.collection,
+        mpl_core_program: &ctx.accounts.mpl_core_program,
+        token_program: &ctx.accounts.token_program,
+        system_program: &ctx.accounts.system_program,
+        hook_config: &ctx.accounts.hook_config,
+        ring_pricing: &ctx.accounts.ring_pricing,
+        ring_reward_weights: &ctx.accounts.ring_reward_weights,
+        ring_occupancy: &ctx.accounts.ring_occupancy,
+        map_digest: &ctx.accounts.map_digest,
+        ring_unlock_schedule: &ctx.accounts.ring_unlock_schedule,
+        token_treasury: &ctx.accounts.token_treasury,
+        treasury_token_account: ctx.accounts.treasury_token_account.as_ref(),
+        parcel_id_pool: Some(&ctx.accounts.parcel_id_pool),
+        referral_share: Some(&ctx.accounts.referral_share),
+        reward_dust: &mut ctx.accounts.reward_dust,
+        reward_dust_bump: ctx.bumps.reward_dust,
+        remaining_accounts: ctx.remaining_accounts,
+        referrer_account: ctx.accounts.referrer_account.as_mut(),
+        referral_vault: ctx.accounts.referral_vault.as_ref(),
+        charity,
+    };
+
+    // Asset is a client keypair and already signed the transaction
+    process_claim(accounts, x, y, width, height, None, None, dry_run)
+}
+
+/// Validates, charges, and mints a parcel.
+/// `mask` is set for a masked parcel, which covers only those cells of the rectangle.
+/// `asset_seeds` is set when the asset is a program-derived address signed for by the program.
+/// With `dry_run` set, stops after validation and returns the ClaimPreview via return data.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn process_claim(
+    accounts: ClaimAccounts,
+    x: u8,
+    y: u8,
+    width: u8,
+    height: u8,
+    mask: Option<&[u8]>,
+    asset_seeds: Option<&[&[u8]]>,
+    dry_run: bool,
+) -> Result<()> {
+    let ClaimAccounts {
+        claimer,
+        payer,
+        owner,
+        delegated,
+        allowlisted,
+        grid_id,
+        grid_config,
+        block_map,
+        token_mint,
+        claimer_token_account,
+        land_buy_reward_pool,
+        claimer_stats,
+        claimer_stats_bump,
+        parcel_info,
+        parcel_info_bump,
+        asset,
+        collection,
+        mpl_core_program,
+        token_program,
+        system_program,
+        hook_config,
+        ring_pricing,
+        ring_reward_weights,
+        ring_occupancy,
+        map_digest,
+        ring_unlock_schedule,
+        token_treasury,
+        treasury_token_account,
+        parcel_id_pool,
+        referral_share,
+        reward_dust,
+        reward_dust_bump,
+        remaining_accounts,
+        referrer_account,
+        referral_vault,
+        charity,
+    } = accounts;
+
     // Validate collection is set
     require!(
-        ctx.accounts.grid_config.collection != Pubkey::default(),
+        grid_config.collection != Pubkey::default(),
         BillionError::CollectionNotSet
     );
 
+    require!(
+        !grid_config.allowlist_only || allowlisted,
+        BillionError::NotAllowlisted
+    );
+
     // Validate the claim
-    {
-        let block_map = ctx.accounts.block_map.load()?;
-        validate_claim(x, y, width, height, &block_map, &ctx.accounts.grid_config)?;
+    let rect = Rect::new(x, y, width, height);
+    let now = Clock::get()?.unix_timestamp;
+    let schedule = RingUnlockSchedule::load(ring_unlock_schedule)?;
+    let previously_unlocked = grid_config.unlocked_ring_at(&schedule, now);
+    let grid_size = {
+        let block_map = BlockMap::load(block_map)?;
+        require!(!block_map.is_frozen(), BillionError::EpochFrozen);
+        validate_claim_blocks(rect, mask, &block_map, grid_config, previously_unlocked)?;
+        block_map.grid_size()
+    };
+
+    // Right after a ring unlocks, its cells are reserved for owners of adjacent parcels
+    let hook_accounts = if grid_config.requires_adjacency_proof(rect, now, grid_size) {
+        let proof = remaining_accounts.get(..2).ok_or(BillionError::AdjacencyProofRequired)?;
+        verify_adjacency_proof(proof, claimer.key(), rect, grid_config.epoch, grid_size)?;
+        &remaining_accounts[2..]
+    } else {
+        remaining_accounts
+    };
+
+    // While a direct referral share is set, a non-executable account ahead of the hook program
+    // is the referrer's token account
+    let share_bps = match referral_share {
+        Some(account) => ReferralShare::load(account)?.share_bps,
+        None => 0,
+    };
+    let (referrer_token_account, hook_accounts) = match hook_accounts.split_first() {
+        Some((account, rest)) if share_bps > 0 && !account.executable => (Some(account), rest),
+        _ => (None, hook_accounts),
+    };
+    let referrer_token_account = referrer_token_account
+        .map(|account| -> Result<_> {
+            require_keys_eq!(*account.owner, token_program.key(), BillionError::InvalidReferrerTokenAccount);
+            let token_account = InterfaceAccount::<InterfaceTokenAccount>::try_from(account)?;
+            require_keys_eq!(token_account.mint, token_mint.key(), BillionError::InvalidReferrerTokenAccount);
+            require!(token_account.owner != claimer.key(), BillionError::SelfReferral);
+            Ok(token_account)
+        })
+        .transpose()?;
+    let referral_share_bps = if referrer_token_account.is_some() { share_bps } else { 0 };
+
+    // Self and circular referrals earn nothing
+    if let Some(referrer) = referrer_account.as_deref() {
+        require!(referrer.referrer != claimer.key(), BillionError::SelfReferral);
+        require!(referrer.referred_by != claimer.key(), BillionError::CircularReferral);
     }
 
-    // Calculate total cost
-    let num_blocks = (width as u32).checked_mul(height as u32).ok_or(BillionError::Overflow)?;
-    let total_cost = (num_blocks as u64)
-        .checked_mul(ctx.accounts.grid_config.price_per_block)
-        .ok_or(BillionError::Overflow)?;
+    // Calculate cost, reward/referral/charity/burn split, and the resulting grid state
+    let charity_bps = charity.map_or(0, |(_, bps)| bps);
+    let ring_pricing = RingPricing::load(ring_pricing)?;
+    let weights = RingRewardWeights::load(ring_reward_weights)?;
+    let treasury = TokenTreasury::load(token_treasury)?;
+    let referred = referrer_account.is_some();
+    let mut preview = preview_claim(
+        rect,
+        mask,
+        grid_size,
+        referred,
+        referral_share_bps,
+        charity_bps,
+        grid_config,
+        &ring_pricing,
+        &weights,
+        &treasury,
+    )?;
+    // The preview counts burns only; rings the schedule opened stay open
+    preview.unlocked_ring = schedule.unlocked_ring(preview.unlocked_ring, now, grid_config.ring_count());
 
-    // Calculate reward/burn split
-    let reward_amount = total_cost
-        .checked_mul(ctx.accounts.grid_config.land_owners_reward_share_bps as u64)
-        .ok_or(BillionError::Overflow)?
-        .checked_div(10_000)
-        .ok_or(BillionError::Overflow)?;
-    let burn_amount = total_cost.checked_sub(reward_amount).ok_or(BillionError::Overflow)?;
+    // A freed id is reused before the counter advances
+    let mut id_pool = parcel_id_pool.map(|pool| ParcelIdPool::load(pool)).transpose()?;
+    let recycled_id = id_pool.as_mut().and_then(|pool| pool.take(grid_config.epoch));
+    match recycled_id {
+        Some(id) => preview.parcel_id = id,
+        None => require!(grid_config.has_parcel_ids(1), BillionError::ParcelIdExhausted),
+    }
+    let ClaimPreview {
+        parcel_id,
+        block_count,
+        price_per_block,
+        total_cost,
+        burn_amount,
+        reward_amount,
+        referral_amount,
+        charity_amount,
+        treasury_amount,
+        referral_share_amount,
+        ..
+    } = preview;
+
+    // Fair-launch cap on what one wallet can claim through the program
+    require!(
+        grid_config.allows_wallet_blocks(claimer_stats.blocks_claimed, block_count),
+        BillionError::WalletBlockLimitExceeded
+    );
+
+    // The treasury account is checked before anything moves
+    let treasury_token_account = if treasury_amount > 0 {
+        let account = treasury_token_account.ok_or(BillionError::InvalidTreasury)?;
+        require_keys_eq!(account.key(), treasury.treasury, BillionError::InvalidTreasury);
+        Some(account)
+    } else {
+        None
+    };
 
     // Verify claimer has sufficient balance
     require!(
-        ctx.accounts.claimer_token_account.amount >= total_cost,
+        claimer_token_account.amount >= total_cost,
         BillionError::InsufficientBalance
     );
 
+    // Nothing has been transferred or written yet, so failing here persists nothing
+    if dry_run {
+        set_return_data(&preview.try_to_vec()?);
+        return err!(BillionError::DryRunSuccess);
+    }
+
+    // Signed claims spend through the GridConfig PDA's delegation on the claimer's account
+    let grid_bytes = grid_seed(grid_id);
+    let grid_config_bump = [grid_config.bump];
+    let grid_config_seeds: &[&[u8]] = &[GridConfig::SEED, &grid_bytes, &grid_config_bump];
+    let (token_authority, token_signer_seeds): (AccountInfo, &[&[&[u8]]]) = if delegated {
+        (grid_config.to_account_info(), &[grid_config_seeds])
+    } else {
+        (claimer.clone(), &[])
+    };
+
+    // Pay the direct referral share before the rest is split
+    if let Some(referrer_token_account) = referrer_token_account.as_ref() {
+        if referral_share_amount > 0 {
+            let cpi_accounts = token_2022::TransferChecked {
+                from: claimer_token_account.to_account_info(),
+                to: referrer_token_account.to_account_info(),
+                authority: token_authority.clone(),
+                mint: token_mint.to_account_info(),
+            };
+            token_2022::transfer_checked(
+                CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, token_signer_seeds),
+                referral_share_amount,
+                token_mint.decimals,
+            )?;
+        }
+        emit!(ReferralSharePaid {
+            schema_version: EVENT_SCHEMA_VERSION,
+            parcel_id,
+            epoch: grid_config.epoch,
+            claimer: claimer.key(),
+            referrer: referrer_token_account.owner,
+            referrer_token_account: referrer_token_account.key(),
+            amount: referral_share_amount,
+        });
+        msg!("Referrer {} paid a {} token share", referrer_token_account.owner, referral_share_amount);
+    }
+
     // Transfer reward portion to pool (if any)
     if reward_amount > 0 {
         let cpi_accounts = token_2022::TransferChecked {
-            from: ctx.accounts.claimer_token_account.to_account_info(),
-            to: ctx.accounts.land_buy_reward_pool.to_account_info(),
-            authority: ctx.accounts.claimer.to_account_info(),
-            mint: ctx.accounts.token_mint.to_account_info(),
+            from: claimer_token_account.to_account_info(),
+            to: land_buy_reward_pool.to_account_info(),
+            authority: token_authority.clone(),
+            mint: token_mint.to_account_info(),
         };
         token_2022::transfer_checked(
-            CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
+            CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, token_signer_seeds),
             reward_amount,
-            ctx.accounts.token_mint.decimals,
+            token_mint.decimals,
+        )?;
+    }
+
+    // Transfer referral portion to the vault and credit the referrer
+    if let Some(referrer) = referrer_account {
+        if referral_amount > 0 {
+            let referral_vault = referral_vault.ok_or(BillionError::ReferralsDisabled)?;
+            let cpi_accounts = token_2022::TransferChecked {
+                from: claimer_token_account.to_account_info(),
+                to: referral_vault.to_account_info(),
+                authority: token_authority.clone(),
+                mint: token_mint.to_account_info(),
+            };
+            token_2022::transfer_checked(
+                CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, token_signer_seeds),
+                referral_amount,
+                token_mint.decimals,
+            )?;
+        }
+
+        referrer.accrued = referrer.accrued.checked_add(referral_amount).ok_or(BillionError::Overflow)?;
+        referrer.total_earned = referrer
+            .total_earned
+            .checked_add(referral_amount)
+            .ok_or(BillionError::Overflow)?;
+        referrer.referral_count = referrer.referral_count.checked_add(1).ok_or(BillionError::Overflow)?;
+        referrer.referred_blocks = referrer
+            .referred_blocks
+            .checked_add(block_count)
+            .ok_or(BillionError::Overflow)?;
+        msg!("Referrer {} credited {} tokens", referrer.referrer, referral_amount);
+    }
+
+    // Transfer the charity portion to the buyer's chosen charity
+    if let Some((charity_token_account, _)) = charity {
+        if charity_amount > 0 {
+            let cpi_accounts = token_2022::TransferChecked {
+                from: claimer_token_account.to_account_info(),
+                to: charity_token_account.to_account_info(),
+                authority: token_authority.clone(),
+                mint: token_mint.to_account_info(),
+            };
+            token_2022::transfer_checked(
+                CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, token_signer_seeds),
+                charity_amount,
+                token_mint.decimals,
+            )?;
+        }
+    }
+
+    // Transfer the treasury portion to the protocol treasury
+    if let Some(treasury_token_account) = treasury_token_account {
+        let cpi_accounts = token_2022::TransferChecked {
+            from: claimer_token_account.to_account_info(),
+            to: treasury_token_account.to_account_info(),
+            authority: token_authority.clone(),
+            mint: token_mint.to_account_info(),
+        };
+        token_2022::transfer_checked(
+            CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, token_signer_seeds),
+            treasury_amount,
+            token_mint.decimals,
         )?;
+        emit!(TreasuryPaid {
+            schema_version: EVENT_SCHEMA_VERSION,
+            parcel_id,
+            epoch: grid_config.epoch,
+            claimer: claimer.key(),
+            treasury: treasury_token_account.key(),
+            amount: treasury_amount,
+        });
     }
 
     // Burn the burn portion
     if burn_amount > 0 {
         let cpi_accounts = token_2022::Burn {
-            mint: ctx.accounts.token_mint.to_account_info(),
-            from: ctx.accounts.claimer_token_account.to_account_info(),
-            authority: ctx.accounts.claimer.to_account_info(),
+            mint: token_mint.to_account_info(),
+            from: claimer_token_account.to_account_info(),
+            authority: token_authority.clone(),
         };
         token_2022::burn(
-            CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
+            CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, token_signer_seeds),
             burn_amount,
         )?;
     }
 
-    // Get the parcel_id before mutating
-    let parcel_id = ctx.accounts.grid_config.next_parcel_id;
+    emit!(PaymentSettled {
+        schema_version: EVENT_SCHEMA_VERSION,
+        parcel_id,
+        epoch: grid_config.epoch,
+        claimer: claimer.key(),
+        total_cost,
+        reward_amount,
+        referral_amount,
+        charity_amount,
+        charity: charity.map_or(Pubkey::default(), |(account, _)| account.key()),
+        burn_amount,
+    });
 
-    // Update grid_config
-    let grid_config = &mut ctx.accounts.grid_config;
+    claimer_stats.touch(claimer.key(), claimer_stats_bump);
+    claimer_stats
+        .record_claim(total_cost, block_count, now)
+        .ok_or(BillionError::Overflow)?;
 
-    // Distribute rewards to existing landowners BEFORE adding new blocks
+    // Rewards only reach the accumulator when there are landowners to credit; otherwise
+    // they sit in the pool as surplus
     if grid_config.total_claimed_blocks > 0 && reward_amount > 0 {
-        // Scale by 1e9 for precision
-        let reward_increase = (reward_amount as u128)
-            .checked_mul(1_000_000_000)
-            .ok_or(BillionError::Overflow)?
-            .checked_div(grid_config.total_claimed_blocks as u128)
+        grid_config.credit_land_buy_rewards(reward_amount).ok_or(BillionError::Overflow)?;
+        let reward_increase = preview.land_buy_rewards_per_block - grid_config.land_buy_rewards_per_block;
+        let reward_units = weights
+            .reward_units(grid_config.epoch, grid_config.total_claimed_blocks)
             .ok_or(BillionError::Overflow)?;
+        reward_dust.bump = reward_dust_bump;
+        reward_dust.record(reward_amount, reward_increase, reward_units).ok_or(BillionError::Overflow)?;
+    }
 
-        grid_config.land_buy_rewards_per_block = grid_config
-            .land_buy_rewards_per_block
-            .checked_add(reward_increase)
+    // Apply the previewed accumulator and counters
+    grid_config.record_ring_unlock(previously_unlocked, preview.unlocked_ring, now);
+    emit_ring_unlock(grid_config.epoch, previously_unlocked, preview.unlocked_ring, preview.total_burned, parcel_id);
+    grid_config.land_buy_rewards_per_block = preview.land_buy_rewards_per_block;
+    grid_config.total_claimed_blocks = preview.total_claimed_blocks;
+    grid_config.total_burned = preview.total_burned;
+    // A reused id leaves the pool instead of advancing the counter
+    if let (Some(pool), Some(account), Some(_)) = (id_pool, parcel_id_pool, recycled_id) {
+        pool.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+    } else {
+        grid_config.next_parcel_id = grid_config
+            .next_parcel_id
+            .checked_add(1)
             .ok_or(BillionError::Overflow)?;
     }
 
-    // Update total claimed blocks (include new parcel)
-    grid_config.total_claimed_blocks = grid_config
-        .total_claimed_blocks
-        .checked_add(num_blocks)
-        .ok_or(BillionError::Overflow)?;
-
-    grid_config.total_burned = grid_config
-        .total_burned
-        .checked_add(burn_amount)
-        .ok_or(BillionError::Overflow)?;
-    grid_config.next_parcel_id = grid_config
-        .next_parcel_id
-        .checked_add(1)
-        .ok_or(BillionError::Overflow)?;
-
     // Store values needed for CPI and ParcelInfo
     let uri_base = grid_config.uri_base.clone();
     let current_rewards_per_block = grid_config.land_buy_rewards_per_block;
+    let epoch = grid_config.epoch;
+    let ring_count = grid_config.ring_count();
+    let collection_generation = grid_config.collection_generation;
 
     // Assign parcel_id to all blocks
     {
-        let mut block_map = ctx.accounts.block_map.load_mut()?;
-        for dy in 0..height {
-            for dx in 0..width {
-                block_map.set_block(x + dx, y + dy, parcel_id);
-            }
+        let mut block_map = BlockMap::load_mut(block_map)?;
+        for (block_x, block_y) in claim_blocks(rect, mask) {
+            block_map.set_block(block_x, block_y, parcel_id);
         }
     }
 
@@ -254,42 +1003,202 @@ pub fn handler(
     let uri = format!("{}{}", uri_base, parcel_id);
 
     // Get the grid_config bump for PDA signing
-    let bump = ctx.accounts.grid_config.bump;
-    let seeds: &[&[u8]] = &[GridConfig::SEED, &[bump]];
-    let signer_seeds: &[&[&[u8]]] = &[seeds];
-
-    CreateV2CpiBuilder::new(&ctx.accounts.mpl_core_program.to_account_info())
-        .asset(&ctx.accounts.asset.to_account_info())
-        .collection(Some(&ctx.accounts.collection.to_account_info()))
-        .authority(Some(&ctx.accounts.grid_config.to_account_info()))
-        .payer(&ctx.accounts.claimer.to_account_info())
-        .owner(Some(&ctx.accounts.claimer.to_account_info()))
-        .system_program(&ctx.accounts.system_program.to_account_info())
+    let bump = grid_config.bump;
+    let seeds: &[&[u8]] = &[GridConfig::SEED, &grid_bytes, &[bump]];
+    let signer_seeds: &[&[&[u8]]] = match asset_seeds {
+        Some(asset_seeds) => &[seeds, asset_seeds],
+        None => &[seeds],
+    };
+
+    CreateV2CpiBuilder::new(&mpl_core_program.to_account_info())
+        .asset(&asset)
+        .collection(Some(&collection.to_account_info()))
+        .authority(Some(&grid_config.to_account_info()))
+        .payer(&payer)
+        .owner(Some(&owner))
+        .system_program(&system_program.to_account_info())
         .name(name.clone())
         .uri(uri.clone())
+        .plugins(vec![parcel_attributes_plugin(grid_config.key(), parcel_id, x, y, width, height)])
         .invoke_signed(signer_seeds)?;
 
     // Initialize ParcelInfo
-    let parcel_info = &mut ctx.accounts.parcel_info;
-    parcel_info.asset = ctx.accounts.asset.key();
+    parcel_info.asset = asset.key();
     parcel_info.x = x;
     parcel_info.y = y;
     parcel_info.width = width;
     parcel_info.height = height;
-    parcel_info.bump = ctx.bumps.parcel_info;
+    parcel_info.bump = parcel_info_bump;
     parcel_info.last_claimed_land_buy_rewards_per_block = current_rewards_per_block;
-    parcel_info._reserved = [0u8; 48];
+    parcel_info.epoch = epoch;
+    parcel_info.last_claimed_at = Clock::get()?.unix_timestamp;
+    parcel_info.adjacency_score = 0;
+    parcel_info.locked_until = 0;
+    parcel_info.lock_rewards = false;
+    parcel_info.named = false;
+    parcel_info.premium_until = 0;
+    parcel_info.collection_generation = collection_generation;
+    // Masks are at most MAX_MASK_SIDE squared cells
+    parcel_info.masked_block_count = if mask.is_some() { block_count as u16 } else { 0 };
+    parcel_info.reserved = false;
+    parcel_info.rewards_excluded = false;
+    parcel_info.paid_total = total_cost;
+    parcel_info.claimed_at = ParcelInfo::claim_timestamp(now);
+    parcel_info.reward_weight =
+        weights.parcel_weight(claim_blocks(rect, mask), grid_size, ring_count).ok_or(BillionError::Overflow)?;
+    parcel_info._reserved = [0u8; 0];
+    RingRewardWeights::record_extra(ring_reward_weights, epoch, parcel_info.extra_weight(block_count))?;
+    record_ring_occupancy(ring_occupancy, epoch, claim_blocks(rect, mask), grid_size, ring_count, true)?;
+    record_map_write(map_digest, epoch, parcel_id, rect)?;
+
+    emit!(ParcelClaimed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        parcel_id,
+        epoch,
+        claimer: claimer.key(),
+        owner: owner.key(),
+        asset: asset.key(),
+        x,
+        y,
+        width,
+        height,
+        price_per_block,
+        burn_amount,
+        reward_amount,
+        total_burned: grid_config.total_burned,
+    });
+
+    // Notify the hook last so it sees the finished claim; its failure aborts the whole claim
+    invoke_claim_hook(
+        grid_id,
+        hook_config,
+        hook_accounts,
+        &claimer,
+        &asset,
+        OnParcelClaimed {
+            parcel_id,
+            claimer: claimer.key(),
+            x,
+            y,
+            width,
+            height,
+        },
+    )?;
 
     msg!(
-        "Parcel {} claimed at ({}, {}) with dimensions {}x{}, burned {} tokens, {} to rewards pool",
+        "Parcel {} claimed at ({}, {}) with dimensions {}x{} at {} per block, burned {} tokens, {} to rewards pool",
         parcel_id,
         x,
         y,
         width,
         height,
+        price_per_block,
         burn_amount,
         reward_amount
     );
+    msg!("Paid by {}, owned by {}", claimer.key(), owner.key());
+
+    Ok(())
+}
+
+/// Emits RingUnlocked if the claim of `parcel_id` raised the unlocked ring from
+/// `previously_unlocked` to `unlocked`
+pub(crate) fn emit_ring_unlock(epoch: u16, previously_unlocked: u8, unlocked: u8, total_burned: u64, parcel_id: u16) {
+    if unlocked <= previously_unlocked {
+        return;
+    }
+    emit!(RingUnlocked {
+        schema_version: EVENT_SCHEMA_VERSION,
+        epoch,
+        new_ring: unlocked,
+        rings_crossed: unlocked - previously_unlocked,
+        total_burned,
+        triggering_parcel_id: parcel_id,
+    });
+}
+
+/// `proof` is a ParcelInfo followed by its Core asset. The parcel must be from the current
+/// epoch, owned by `claimer`, and share an edge with `rect`.
+pub(crate) fn verify_adjacency_proof(
+    proof: &[AccountInfo],
+    claimer: Pubkey,
+    rect: Rect,
+    epoch: u16,
+    grid_size: u16,
+) -> Result<()> {
+    let (parcel_info, asset) = (&proof[0], &proof[1]);
+    require!(parcel_info.owner == &crate::ID, BillionError::InvalidAdjacencyProof);
+    let parcel_info = ParcelInfo::try_deserialize(&mut &parcel_info.try_borrow_data()?[..])
+        .map_err(|_| BillionError::InvalidAdjacencyProof)?;
+    require!(
+        parcel_info.epoch == epoch && asset.key() == parcel_info.asset,
+        BillionError::InvalidAdjacencyProof
+    );
+    require!(get_core_asset_owner(asset)? == claimer, BillionError::InvalidAdjacencyProof);
+
+    let parcel = Rect::new(parcel_info.x, parcel_info.y, parcel_info.width, parcel_info.height);
+    require!(
+        parcel.edge_neighbors(grid_size).any(|(x, y)| rect.contains(x, y)),
+        BillionError::InvalidAdjacencyProof
+    );
+    Ok(())
+}
+
+/// CPIs the hook program if the hook is initialized, enabled, and overlaps the claim.
+/// `hook_accounts[0]` must be the hook program; at most MAX_HOOK_ACCOUNTS more are passed
+/// through with their writable flag but never as signers. The call is signed by the
+/// HookConfig PDA, which has no authority over anything else, so the hook can verify the
+/// caller without being handed the GridConfig signature.
+pub(crate) fn invoke_claim_hook<'info>(
+    grid_id: u64,
+    hook_config: &UncheckedAccount<'info>,
+    hook_accounts: &[AccountInfo<'info>],
+    claimer: &AccountInfo<'info>,
+    asset: &AccountInfo<'info>,
+    args: OnParcelClaimed,
+) -> Result<()> {
+    if hook_config.data_is_empty() {
+        return Ok(());
+    }
+    let hook = HookConfig::try_deserialize(&mut &hook_config.try_borrow_data()?[..])?;
+    if !hook.applies_to(Rect::new(args.x, args.y, args.width, args.height)) {
+        return Ok(());
+    }
+
+    let (hook_program, passthrough) = hook_accounts
+        .split_first()
+        .ok_or(BillionError::HookProgramMismatch)?;
+    require!(
+        hook_program.key() == hook.program_id && hook_program.executable,
+        BillionError::HookProgramMismatch
+    );
+    require!(
+        passthrough.len() <= MAX_HOOK_ACCOUNTS as usize,
+        BillionError::TooManyHookAccounts
+    );
+
+    let mut metas = vec![
+        AccountMeta::new_readonly(hook_config.key(), true),
+        AccountMeta::new_readonly(claimer.key(), false),
+        AccountMeta::new_readonly(asset.key(), false),
+    ];
+    metas.extend(passthrough.iter().map(|account| {
+        if account.is_writable {
+            AccountMeta::new(account.key(), false)
+        } else {
+            AccountMeta::new_readonly(account.key(), false)
+        }
+    }));
+    let ix = Instruction {
+        program_id: hook.program_id,
+        accounts: metas,
+        data: args.instruction_data()?,
+    };
+
+    let mut infos = vec![hook_config.to_account_info(), claimer.clone(), asset.clone()];
+    infos.extend_from_slice(passthrough);
+    infos.push(hook_program.clone());
 
+    invoke_signed(&ix, &infos, &[&[HookConfig::SEED, &grid_seed(grid_id), &[hook.bump]]])?;
     Ok(())
 }
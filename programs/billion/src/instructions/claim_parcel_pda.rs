@@ -0,0 +1,232 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_interface::{Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface},
+    associated_token::AssociatedToken,
+};
+use crate::state::{
+    epoch_seed, grid_seed, GridConfig, BlockMap, ClaimerStats, RewardDust, HookConfig, ParcelInfo, RingPricing,
+    RingRewardWeights, RingOccupancy, MapDigest, RingUnlockSchedule, ReferrerAccount, TokenTreasury, ASSET_SEED,
+    LAND_BUY_REWARD_POOL_SEED,
+};
+use crate::errors::BillionError;
+use crate::instructions::claim_parcel::{process_claim, ClaimAccounts};
+#[cfg(not(feature = "localnet"))]
+use crate::instructions::claim_parcel::MPL_CORE_ID;
+
+/// Same as ClaimParcel, but the Core asset address is a PDA signed for by the program,
+/// so wallets and CPI callers don't need to generate an asset keypair.
+#[derive(Accounts)]
+#[instruction(grid_id: u64, x: u8, y: u8, width: u8, height: u8)]
+pub struct ClaimParcelPda<'info> {
+    #[account(mut)]
+    pub claimer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// BlockMap address must match the one stored in grid_config
+    #[account(
+        mut,
+        constraint = block_map.key() == grid_config.block_map @ BillionError::Unauthorized
+    )]
+    pub block_map: AccountLoader<'info, BlockMap>,
+
+    /// Token mint must match the one in grid_config (Token-2022)
+    #[account(
+        mut,
+        constraint = token_mint.key() == grid_config.token_mint @ BillionError::Unauthorized
+    )]
+    pub token_mint: InterfaceAccount<'info, InterfaceMint>,
+
+    /// Claimer's token account for burning (Token-2022)
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = claimer,
+        associated_token::token_program = token_program,
+    )]
+    pub claimer_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    /// Land buy reward pool - receives the landowner share
+    #[account(
+        mut,
+        seeds = [LAND_BUY_REWARD_POOL_SEED, grid_config.key().as_ref()],
+        bump,
+        constraint = land_buy_reward_pool.key() == grid_config.land_buy_reward_pool @ BillionError::InvalidRewardPool
+    )]
+    pub land_buy_reward_pool: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    /// The claimer's lifetime stats, created on first touch
+    #[account(
+        init_if_needed,
+        payer = claimer,
+        space = 8 + ClaimerStats::INIT_SPACE,
+        seeds = [ClaimerStats::SEED, &grid_seed(grid_id), claimer.key().as_ref()],
+        bump
+    )]
+    pub claimer_stats: Box<Account<'info, ClaimerStats>>,
+
+    /// Rounding left in the reward pool by the accumulator, created on first touch
+    #[account(
+        init_if_needed,
+        payer = claimer,
+        space = 8 + RewardDust::INIT_SPACE,
+        seeds = [RewardDust::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub reward_dust: Box<Account<'info, RewardDust>>,
+
+    /// Parcel info PDA - stores asset address for lookups
+    #[account(
+        init,
+        payer = claimer,
+        space = 8 + ParcelInfo::INIT_SPACE,
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(grid_config.epoch), &grid_config.next_parcel_id.to_le_bytes()],
+        bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+
+    /// New Core asset - PDA derived from the grid config and the parcel id it will hold
+    /// CHECK: Seeds are verified here, account is created by the Metaplex Core program
+    #[account(
+        mut,
+        seeds = [ASSET_SEED, grid_config.key().as_ref(), &epoch_seed(grid_config.epoch), &grid_config.next_parcel_id.to_le_bytes()],
+        bump
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    /// Core collection - must match grid_config.collection
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        mut,
+        constraint = collection.key() == grid_config.collection @ BillionError::InvalidCollection
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    /// On-claim hook config, always required so a claim can't skip an enabled hook.
+    /// Uninitialized until the authority first calls set_hook.
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(seeds = [HookConfig::SEED, &grid_seed(grid_id)], bump)]
+    pub hook_config: UncheckedAccount<'info>,
+
+    /// Per-ring price multipliers, flat pricing while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(seeds = [RingPricing::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_pricing: UncheckedAccount<'info>,
+
+    /// Per-ring land-buy reward weights, which count the parcel's extra weight; every block
+    /// is unweighted while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(mut, seeds = [RingRewardWeights::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_reward_weights: UncheckedAccount<'info>,
+
+    /// Claimed blocks per ring, which count the parcel's blocks; nothing is counted until
+    /// it's created
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(mut, seeds = [RingOccupancy::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_occupancy: UncheckedAccount<'info>,
+
+    /// Rolling digest of BlockMap writes, which chains in the parcel's blocks
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(mut, seeds = [MapDigest::SEED, &grid_seed(grid_id)], bump)]
+    pub map_digest: UncheckedAccount<'info>,
+
+    /// Times rings open whatever has been burned; rings unlock by burns alone while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(seeds = [RingUnlockSchedule::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_unlock_schedule: UncheckedAccount<'info>,
+
+    /// Protocol treasury share of the cost; nothing is owed while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(seeds = [TokenTreasury::SEED, &grid_seed(grid_id)], bump)]
+    pub token_treasury: UncheckedAccount<'info>,
+
+    /// The configured treasury token account - required while the treasury share is nonzero
+    #[account(mut)]
+    pub treasury_token_account: Option<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    /// Referrer credited with referral_bps of the cost, if any
+    #[account(
+        mut,
+        seeds = [ReferrerAccount::SEED, &grid_seed(grid_id), referrer_account.referrer.as_ref()],
+        bump = referrer_account.bump
+    )]
+    pub referrer_account: Option<Account<'info, ReferrerAccount>>,
+
+    /// Referral vault - required when a referrer is passed and referral_bps > 0
+    #[account(
+        mut,
+        constraint = referral_vault.key() == grid_config.referral_vault @ BillionError::ReferralsDisabled
+    )]
+    pub referral_vault: Option<InterfaceAccount<'info, InterfaceTokenAccount>>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimParcelPda<'info>>,
+    grid_id: u64,
+    x: u8,
+    y: u8,
+    width: u8,
+    height: u8,
+) -> Result<()> {
+    // Asset seeds must be captured before next_parcel_id is incremented
+    let grid_config_key = ctx.accounts.grid_config.key();
+    let epoch_bytes = epoch_seed(ctx.accounts.grid_config.epoch);
+    let parcel_id_bytes = ctx.accounts.grid_config.next_parcel_id.to_le_bytes();
+    let asset_bump = [ctx.bumps.asset];
+    let asset_seeds: &[&[u8]] = &[ASSET_SEED, grid_config_key.as_ref(), &epoch_bytes, &parcel_id_bytes, &asset_bump];
+
+    let accounts = ClaimAccounts {
+        claimer: ctx.accounts.claimer.to_account_info(),
+        payer: ctx.accounts.claimer.to_account_info(),
+        owner: ctx.accounts.claimer.to_account_info(),
+        delegated: false,
+        allowlisted: false,
+        grid_id,
+        grid_config: &mut ctx.accounts.grid_config,
+        block_map: &ctx.accounts.block_map,
+        token_mint: &ctx.accounts.token_mint,
+        claimer_token_account: &ctx.accounts.claimer_token_account,
+        land_buy_reward_pool: &ctx.accounts.land_buy_reward_pool,
+        claimer_stats: &mut ctx.accounts.claimer_stats,
+        claimer_stats_bump: ctx.bumps.claimer_stats,
+        parcel_info: &mut ctx.accounts.parcel_info,
+        parcel_info_bump: ctx.bumps.parcel_info,
+        asset: ctx.accounts.asset.to_account_info(),
+        collection: &ctx.accounts.collection,
+        mpl_core_program: &ctx.accounts.mpl_core_program,
+        token_program: &ctx.accounts.token_program,
+        system_program: &ctx.accounts.system_program,
+        hook_config: &ctx.accounts.hook_config,
+        ring_pricing: &ctx.accounts.ring_pricing,
+        ring_reward_weights: &ctx.accounts.ring_reward_weights,
+        ring_occupancy: &ctx.accounts.ring_occupancy,
+        map_digest: &ctx.accounts.map_digest,
+        ring_unlock_schedule: &ctx.accounts.ring_unlock_schedule,
+        token_treasury: &ctx.accounts.token_treasury,
+        treasury_token_account: ctx.accounts.treasury_token_account.as_ref(),
+        parcel_id_pool: None,
+        referral_share: None,
+        reward_dust: &mut ctx.accounts.reward_dust,
+        reward_dust_bump: ctx.bumps.reward_dust,
+        remaining_accounts: ctx.remaining_accounts,
+        referrer_account: ctx.accounts.referrer_account.as_mut(),
+        referral_vault: ctx.accounts.referral_vault.as_ref(),
+        charity: None,
+    };
+
+    process_claim(accounts, x, y, width, height, None, Some(asset_seeds), false)
+}
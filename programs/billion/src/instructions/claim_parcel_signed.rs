@@ -0,0 +1,374 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    ed25519_program,
+    sysvar::instructions::{self as instructions_sysvar, load_current_index_checked, load_instruction_at_checked},
+};
+use anchor_spl::{
+    token_interface::{Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface},
+    associated_token::AssociatedToken,
+};
+use billion_core::Rect;
+use crate::state::{
+    epoch_seed, grid_seed, GridConfig, BlockMap, ClaimNonce, ClaimerStats, RewardDust, HookConfig, ParcelInfo,
+    RingPricing, RingRewardWeights, RingOccupancy, MapDigest, RingUnlockSchedule, TokenTreasury,
+    LAND_BUY_REWARD_POOL_SEED,
+};
+use crate::errors::BillionError;
+use crate::instructions::claim_parcel::{preview_claim, process_claim, ClaimAccounts};
+#[cfg(not(feature = "localnet"))]
+use crate::instructions::claim_parcel::MPL_CORE_ID;
+
+/// What a user signs off-chain to let a relayer claim on their behalf. The signed bytes are
+/// [`ClaimAuthorization::message`]; binding the GridConfig address ties them to one
+/// grid of one deployment of the program.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ClaimAuthorization {
+    pub grid_config: Pubkey,
+    pub user: Pubkey,
+    pub x: u8,
+    pub y: u8,
+    pub width: u8,
+    pub height: u8,
+    /// Most the user agrees to be charged
+    pub max_cost: u64,
+    /// Must be at least the user's ClaimNonce.next_nonce
+    pub nonce: u64,
+    /// Unix timestamp after which the authorization is void
+    pub expires_at: i64,
+}
+
+impl ClaimAuthorization {
+    pub const DOMAIN: &'static [u8] = b"billion:claim_parcel_signed:v1";
+
+    /// Canonical signed message: the domain tag followed by the borsh-encoded authorization
+    pub fn message(&self) -> Vec<u8> {
+        let mut message = Self::DOMAIN.to_vec();
+        message.extend(self.try_to_vec().expect("borsh into a Vec cannot fail"));
+        message
+    }
+}
+
+/// Same as ClaimParcel, but submitted by a relayer who pays fees and rent. The user's consent
+/// comes from an ed25519 signature verified by the preceding instruction, and their tokens
+/// move through the GridConfig PDA, which they must have approved as delegate.
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct ClaimParcelSigned<'info> {
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    /// Signer of the authorization; receives the asset
+    /// CHECK: Authenticated by the ed25519 signature over the authorization
+    pub user: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Box<Account<'info, GridConfig>>,
+
+    /// BlockMap address must match the one stored in grid_config
+    #[account(
+        mut,
+        constraint = block_map.key() == grid_config.block_map @ BillionError::Unauthorized
+    )]
+    pub block_map: AccountLoader<'info, BlockMap>,
+
+    /// Token mint must match the one in grid_config (Token-2022)
+    #[account(
+        mut,
+        constraint = token_mint.key() == grid_config.token_mint @ BillionError::Unauthorized
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, InterfaceMint>>,
+
+    /// User's token account, with the GridConfig PDA approved as delegate
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    /// Land buy reward pool - receives the landowner share
+    #[account(
+        mut,
+        seeds = [LAND_BUY_REWARD_POOL_SEED, grid_config.key().as_ref()],
+        bump,
+        constraint = land_buy_reward_pool.key() == grid_config.land_buy_reward_pool @ BillionError::InvalidRewardPool
+    )]
+    pub land_buy_reward_pool: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    /// The user's lifetime stats, created on first touch
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        space = 8 + ClaimerStats::INIT_SPACE,
+        seeds = [ClaimerStats::SEED, &grid_seed(grid_id), user.key().as_ref()],
+        bump
+    )]
+    pub claimer_stats: Box<Account<'info, ClaimerStats>>,
+
+    /// Rounding left in the reward pool by the accumulator, created on first touch
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        space = 8 + RewardDust::INIT_SPACE,
+        seeds = [RewardDust::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub reward_dust: Box<Account<'info, RewardDust>>,
+
+    /// The user's replay protection, created on their first signed claim
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        space = 8 + ClaimNonce::INIT_SPACE,
+        seeds = [ClaimNonce::SEED, &grid_seed(grid_id), user.key().as_ref()],
+        bump
+    )]
+    pub claim_nonce: Box<Account<'info, ClaimNonce>>,
+
+    /// Parcel info PDA - stores asset address for lookups
+    #[account(
+        init,
+        payer = relayer,
+        space = 8 + ParcelInfo::INIT_SPACE,
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(grid_config.epoch), &grid_config.next_parcel_id.to_le_bytes()],
+        bump
+    )]
+    pub parcel_info: Box<Account<'info, ParcelInfo>>,
+
+    /// New Core asset - must be a signer (keypair generated client-side)
+    #[account(mut)]
+    pub asset: Signer<'info>,
+
+    /// Core collection - must match grid_config.collection
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        mut,
+        constraint = collection.key() == grid_config.collection @ BillionError::InvalidCollection
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    /// On-claim hook config, always required so a claim can't skip an enabled hook
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(seeds = [HookConfig::SEED, &grid_seed(grid_id)], bump)]
+    pub hook_config: UncheckedAccount<'info>,
+
+    /// Per-ring price multipliers, flat pricing while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(seeds = [RingPricing::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_pricing: UncheckedAccount<'info>,
+
+    /// Per-ring land-buy reward weights, which count the parcel's extra weight; every block
+    /// is unweighted while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(mut, seeds = [RingRewardWeights::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_reward_weights: UncheckedAccount<'info>,
+
+    /// Claimed blocks per ring, which count the parcel's blocks; nothing is counted until
+    /// it's created
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(mut, seeds = [RingOccupancy::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_occupancy: UncheckedAccount<'info>,
+
+    /// Rolling digest of BlockMap writes, which chains in the parcel's blocks
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(mut, seeds = [MapDigest::SEED, &grid_seed(grid_id)], bump)]
+    pub map_digest: UncheckedAccount<'info>,
+
+    /// Times rings open whatever has been burned; rings unlock by burns alone while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(seeds = [RingUnlockSchedule::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_unlock_schedule: UncheckedAccount<'info>,
+
+    /// Protocol treasury share of the cost; nothing is owed while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(seeds = [TokenTreasury::SEED, &grid_seed(grid_id)], bump)]
+    pub token_treasury: UncheckedAccount<'info>,
+
+    /// The configured treasury token account - required while the treasury share is nonzero
+    #[account(mut)]
+    pub treasury_token_account: Option<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    /// CHECK: Instructions sysvar, read to find the ed25519 verification
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+/// Checks that the instruction right before this one is an ed25519 program instruction
+/// verifying exactly one signature by `signer` over `message`. The ed25519 program fails the
+/// transaction if the signature itself is invalid, so matching its inputs is enough.
+fn verify_ed25519_signature(instructions: &AccountInfo, signer: &Pubkey, message: &[u8]) -> Result<()> {
+    let current = load_current_index_checked(instructions)?;
+    let previous = current.checked_sub(1).ok_or(BillionError::InvalidClaimSignature)?;
+    let ix = load_instruction_at_checked(previous as usize, instructions)?;
+    require!(
+        ix.program_id == ed25519_program::ID && ix.accounts.is_empty(),
+        BillionError::InvalidClaimSignature
+    );
+
+    // Layout: u8 signature count, u8 padding, then per signature seven u16 offsets:
+    // signature, signature ix, public key, public key ix, message, message size, message ix
+    let data = &ix.data;
+    require!(data.len() >= 16 && data[0] == 1, BillionError::InvalidClaimSignature);
+    let offset = |i: usize| u16::from_le_bytes([data[2 + 2 * i], data[3 + 2 * i]]);
+    let (public_key_offset, message_offset, message_size) = (offset(2) as usize, offset(4) as usize, offset(5) as usize);
+    // u16::MAX points at the ed25519 instruction's own data rather than another instruction's
+    require!(
+        offset(1) == u16::MAX && offset(3) == u16::MAX && offset(6) == u16::MAX,
+        BillionError::InvalidClaimSignature
+    );
+
+    let public_key = data.get(public_key_offset..public_key_offset + 32);
+    let signed_message = data.get(message_offset..message_offset + message_size);
+    require!(
+        public_key == Some(signer.as_ref()) && signed_message == Some(message),
+        BillionError::InvalidClaimSignature
+    );
+    Ok(())
+}
+
+/// Claims the parcel described by a user-signed ClaimAuthorization. Signature, expiry, nonce
+/// and max cost are all checked before any token moves; referrals and charity routing aren't
+/// available on this path.
+#[allow(clippy::too_many_arguments)]
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimParcelSigned<'info>>,
+    grid_id: u64,
+    x: u8,
+    y: u8,
+    width: u8,
+    height: u8,
+    max_cost: u64,
+    nonce: u64,
+    expires_at: i64,
+) -> Result<()> {
+    let authorization = ClaimAuthorization {
+        grid_config: ctx.accounts.grid_config.key(),
+        user: ctx.accounts.user.key(),
+        x,
+        y,
+        width,
+        height,
+        max_cost,
+        nonce,
+        expires_at,
+    };
+    verify_ed25519_signature(
+        &ctx.accounts.instructions.to_account_info(),
+        &authorization.user,
+        &authorization.message(),
+    )?;
+    require!(
+        Clock::get()?.unix_timestamp < expires_at,
+        BillionError::ClaimAuthorizationExpired
+    );
+
+    let claim_nonce = &mut ctx.accounts.claim_nonce;
+    if claim_nonce.user == Pubkey::default() {
+        claim_nonce.user = authorization.user;
+        claim_nonce.next_nonce = 0;
+        claim_nonce.bump = ctx.bumps.claim_nonce;
+    }
+    require!(claim_nonce.consume(nonce), BillionError::NonceAlreadyUsed);
+
+    let ring_pricing = RingPricing::load(&ctx.accounts.ring_pricing)?;
+    let weights = RingRewardWeights::load(&ctx.accounts.ring_reward_weights)?;
+    let rect = Rect::new(x, y, width, height);
+    let treasury = TokenTreasury::load(&ctx.accounts.token_treasury)?;
+    let grid_size = BlockMap::load(&ctx.accounts.block_map)?.grid_size();
+    let preview = preview_claim(
+        rect,
+        None,
+        grid_size,
+        false,
+        0,
+        0,
+        &ctx.accounts.grid_config,
+        &ring_pricing,
+        &weights,
+        &treasury,
+    )?;
+    require!(preview.total_cost <= max_cost, BillionError::MaxCostExceeded);
+
+    let accounts = ClaimAccounts {
+        claimer: ctx.accounts.user.to_account_info(),
+        payer: ctx.accounts.relayer.to_account_info(),
+        owner: ctx.accounts.user.to_account_info(),
+        delegated: true,
+        allowlisted: false,
+        grid_id,
+        grid_config: &mut ctx.accounts.grid_config,
+        block_map: &ctx.accounts.block_map,
+        token_mint: &ctx.accounts.token_mint,
+        claimer_token_account: &ctx.accounts.user_token_account,
+        land_buy_reward_pool: &ctx.accounts.land_buy_reward_pool,
+        claimer_stats: &mut ctx.accounts.claimer_stats,
+        claimer_stats_bump: ctx.bumps.claimer_stats,
+        parcel_info: &mut ctx.accounts.parcel_info,
+        parcel_info_bump: ctx.bumps.parcel_info,
+        asset: ctx.accounts.asset.to_account_info(),
+        collection: &ctx.accounts.collection,
+        mpl_core_program: &ctx.accounts.mpl_core_program,
+        token_program: &ctx.accounts.token_program,
+        system_program: &ctx.accounts.system_program,
+        hook_config: &ctx.accounts.hook_config,
+        ring_pricing: &ctx.accounts.ring_pricing,
+        ring_reward_weights: &ctx.accounts.ring_reward_weights,
+        ring_occupancy: &ctx.accounts.ring_occupancy,
+        map_digest: &ctx.accounts.map_digest,
+        ring_unlock_schedule: &ctx.accounts.ring_unlock_schedule,
+        token_treasury: &ctx.accounts.token_treasury,
+        treasury_token_account: ctx.accounts.treasury_token_account.as_ref(),
+        parcel_id_pool: None,
+        referral_share: None,
+        reward_dust: &mut ctx.accounts.reward_dust,
+        reward_dust_bump: ctx.bumps.reward_dust,
+        remaining_accounts: ctx.remaining_accounts,
+        referrer_account: None,
+        referral_vault: None,
+        charity: None,
+    };
+
+    process_claim(accounts, x, y, width, height, None, None, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_authorization_message_is_domain_tagged_and_binds_every_field() {
+        let authorization = ClaimAuthorization {
+            grid_config: Pubkey::new_unique(),
+            user: Pubkey::new_unique(),
+            x: 1,
+            y: 2,
+            width: 3,
+            height: 4,
+            max_cost: 12_000_000,
+            nonce: 7,
+            expires_at: 1_735_689_600,
+        };
+        let message = authorization.message();
+        assert!(message.starts_with(ClaimAuthorization::DOMAIN));
+        assert_eq!(message.len(), ClaimAuthorization::DOMAIN.len() + 32 + 32 + 4 + 8 + 8 + 8);
+
+        let cheaper = ClaimAuthorization { max_cost: 1, ..authorization.clone() };
+        assert_ne!(cheaper.message(), message);
+        let moved = ClaimAuthorization { x: 9, ..authorization };
+        assert_ne!(moved.message(), message);
+    }
+}
@@ -0,0 +1,313 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use mpl_core::instructions::CreateV2CpiBuilder;
+use billion_core::Rect;
+use crate::state::{
+    epoch_seed, grid_seed, GridConfig, BlockMap, ClaimerStats, HookConfig, OnParcelClaimed, ParcelInfo,
+    RingRewardWeights, RingOccupancy, MapDigest, RingUnlockSchedule, SOL_TREASURY_SEED,
+};
+use crate::errors::BillionError;
+use crate::events::{ParcelClaimed, EVENT_SCHEMA_VERSION};
+use crate::instructions::claim_parcel::{emit_ring_unlock, invoke_claim_hook, validate_claim, verify_adjacency_proof};
+use crate::instructions::refresh_parcel_attributes::parcel_attributes_plugin;
+use crate::instructions::admin_backfill_ring_occupancy::record_ring_occupancy;
+use crate::instructions::init_map_digest::record_map_write;
+#[cfg(not(feature = "localnet"))]
+use crate::instructions::claim_parcel::MPL_CORE_ID;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct ClaimParcelSol<'info> {
+    #[account(mut)]
+    pub claimer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Box<Account<'info, GridConfig>>,
+
+    /// BlockMap address must match the one stored in grid_config
+    #[account(
+        mut,
+        constraint = block_map.key() == grid_config.block_map @ BillionError::Unauthorized
+    )]
+    pub block_map: AccountLoader<'info, BlockMap>,
+
+    /// Receives the lamports; a system account owned by no one but the program's seeds
+    #[account(
+        mut,
+        seeds = [SOL_TREASURY_SEED, grid_config.key().as_ref()],
+        bump
+    )]
+    pub sol_treasury: SystemAccount<'info>,
+
+    /// The claimer's lifetime stats, created on first touch
+    #[account(
+        init_if_needed,
+        payer = claimer,
+        space = 8 + ClaimerStats::INIT_SPACE,
+        seeds = [ClaimerStats::SEED, &grid_seed(grid_id), claimer.key().as_ref()],
+        bump
+    )]
+    pub claimer_stats: Box<Account<'info, ClaimerStats>>,
+
+    /// Parcel info PDA - stores asset address for lookups
+    #[account(
+        init,
+        payer = claimer,
+        space = 8 + ParcelInfo::INIT_SPACE,
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(grid_config.epoch), &grid_config.next_parcel_id.to_le_bytes()],
+        bump
+    )]
+    pub parcel_info: Box<Account<'info, ParcelInfo>>,
+
+    /// New Core asset - must be a signer (keypair generated client-side)
+    #[account(mut)]
+    pub asset: Signer<'info>,
+
+    /// Core collection - must match grid_config.collection
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        mut,
+        constraint = collection.key() == grid_config.collection @ BillionError::InvalidCollection
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// On-claim hook config, always required so a claim can't skip an enabled hook
+    /// CHECK: Seeds are verified here, contents are deserialized by invoke_claim_hook when initialized
+    #[account(seeds = [HookConfig::SEED, &grid_seed(grid_id)], bump)]
+    pub hook_config: UncheckedAccount<'info>,
+
+    /// Per-ring land-buy reward weights, which count the parcel's extra weight; every block
+    /// is unweighted while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(mut, seeds = [RingRewardWeights::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_reward_weights: UncheckedAccount<'info>,
+
+    /// Claimed blocks per ring, which count the parcel's blocks; nothing is counted until
+    /// it's created
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(mut, seeds = [RingOccupancy::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_occupancy: UncheckedAccount<'info>,
+
+    /// Rolling digest of BlockMap writes, which chains in the parcel's blocks
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(mut, seeds = [MapDigest::SEED, &grid_seed(grid_id)], bump)]
+    pub map_digest: UncheckedAccount<'info>,
+
+    /// Times rings open whatever has been burned; rings unlock by burns alone while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [RingUnlockSchedule::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_unlock_schedule: UncheckedAccount<'info>,
+}
+
+/// Claims a parcel paying price_per_block_lamports per block into the SOL treasury. Nothing
+/// is burned and landowners get no reward share: the whole payment stays in the treasury,
+/// and rewards keep accruing only from token purchases. Each block instead adds
+/// sol_ring_credit_bps of the token price to total_burned, so SOL claims can move rings.
+/// remaining_accounts work as in claim_parcel.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimParcelSol<'info>>,
+    grid_id: u64,
+    x: u8,
+    y: u8,
+    width: u8,
+    height: u8,
+) -> Result<()> {
+    let grid_config = &ctx.accounts.grid_config;
+    let ring_count = grid_config.ring_count();
+    require!(
+        grid_config.price_per_block_lamports > 0,
+        BillionError::SolPaymentsDisabled
+    );
+    require!(
+        grid_config.collection != Pubkey::default(),
+        BillionError::CollectionNotSet
+    );
+    // Allowlist proofs are only taken by claim_parcel
+    require!(!grid_config.allowlist_only, BillionError::NotAllowlisted);
+
+    let now = Clock::get()?.unix_timestamp;
+    let schedule = RingUnlockSchedule::load(&ctx.accounts.ring_unlock_schedule)?;
+    let previously_unlocked = grid_config.unlocked_ring_at(&schedule, now);
+    let grid_size = {
+        let block_map = BlockMap::load(&ctx.accounts.block_map)?;
+        require!(!block_map.is_frozen(), BillionError::EpochFrozen);
+        validate_claim(x, y, width, height, &block_map, grid_config, previously_unlocked)?;
+        block_map.grid_size()
+    };
+
+    let claimer = ctx.accounts.claimer.to_account_info();
+    let rect = Rect::new(x, y, width, height);
+    let hook_accounts = if grid_config.requires_adjacency_proof(rect, now, grid_size) {
+        let proof = ctx.remaining_accounts.get(..2).ok_or(BillionError::AdjacencyProofRequired)?;
+        verify_adjacency_proof(proof, claimer.key(), rect, grid_config.epoch, grid_size)?;
+        &ctx.remaining_accounts[2..]
+    } else {
+        ctx.remaining_accounts
+    };
+
+    require!(grid_config.has_parcel_ids(1), BillionError::ParcelIdExhausted);
+    let block_count = (width as u32) * (height as u32);
+    require!(
+        grid_config.allows_wallet_blocks(ctx.accounts.claimer_stats.blocks_claimed, block_count),
+        BillionError::WalletBlockLimitExceeded
+    );
+    let total_lamports = (block_count as u64)
+        .checked_mul(grid_config.price_per_block_lamports)
+        .ok_or(BillionError::Overflow)?;
+    let ring_credit = grid_config.sol_ring_credit(block_count).ok_or(BillionError::Overflow)?;
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: claimer.clone(),
+                to: ctx.accounts.sol_treasury.to_account_info(),
+            },
+        ),
+        total_lamports,
+    )?;
+
+    // Tokens spent stay 0; the stats only count the blocks
+    let claimer_stats = &mut ctx.accounts.claimer_stats;
+    claimer_stats.touch(claimer.key(), ctx.bumps.claimer_stats);
+    claimer_stats.record_claim(0, block_count, now).ok_or(BillionError::Overflow)?;
+
+    let parcel_id = ctx.accounts.grid_config.next_parcel_id;
+    let grid_config = &mut ctx.accounts.grid_config;
+    grid_config.total_burned = grid_config.total_burned.checked_add(ring_credit).ok_or(BillionError::Overflow)?;
+    let unlocked = grid_config.unlocked_ring_at(&schedule, now);
+    grid_config.record_ring_unlock(previously_unlocked, unlocked, now);
+    emit_ring_unlock(grid_config.epoch, previously_unlocked, unlocked, grid_config.total_burned, parcel_id);
+    grid_config.total_sol_collected = grid_config
+        .total_sol_collected
+        .checked_add(total_lamports)
+        .ok_or(BillionError::Overflow)?;
+    grid_config.total_claimed_blocks = grid_config
+        .total_claimed_blocks
+        .checked_add(block_count)
+        .ok_or(BillionError::Overflow)?;
+    grid_config.next_parcel_id = grid_config
+        .next_parcel_id
+        .checked_add(1)
+        .ok_or(BillionError::Overflow)?;
+
+    let uri_base = grid_config.uri_base.clone();
+    let current_rewards_per_block = grid_config.land_buy_rewards_per_block;
+    let epoch = grid_config.epoch;
+    let collection_generation = grid_config.collection_generation;
+    let total_burned = grid_config.total_burned;
+
+    {
+        let mut block_map = BlockMap::load_mut(&ctx.accounts.block_map)?;
+        for (block_x, block_y) in rect.blocks() {
+            block_map.set_block(block_x, block_y, parcel_id);
+        }
+    }
+
+    let bump = ctx.accounts.grid_config.bump;
+    let grid_bytes = grid_seed(grid_id);
+    let seeds: &[&[u8]] = &[GridConfig::SEED, &grid_bytes, &[bump]];
+    let asset = ctx.accounts.asset.to_account_info();
+    CreateV2CpiBuilder::new(&ctx.accounts.mpl_core_program.to_account_info())
+        .asset(&asset)
+        .collection(Some(&ctx.accounts.collection.to_account_info()))
+        .authority(Some(&ctx.accounts.grid_config.to_account_info()))
+        .payer(&claimer)
+        .owner(Some(&claimer))
+        .system_program(&ctx.accounts.system_program.to_account_info())
+        .name(format!("Parcel #{}", parcel_id))
+        .uri(format!("{}{}", uri_base, parcel_id))
+        .plugins(vec![parcel_attributes_plugin(
+            ctx.accounts.grid_config.key(),
+            parcel_id,
+            x,
+            y,
+            width,
+            height,
+        )])
+        .invoke_signed(&[seeds])?;
+
+    let weights = RingRewardWeights::load(&ctx.accounts.ring_reward_weights)?;
+    let parcel_info = &mut ctx.accounts.parcel_info;
+    parcel_info.asset = asset.key();
+    parcel_info.x = x;
+    parcel_info.y = y;
+    parcel_info.width = width;
+    parcel_info.height = height;
+    parcel_info.bump = ctx.bumps.parcel_info;
+    parcel_info.last_claimed_land_buy_rewards_per_block = current_rewards_per_block;
+    parcel_info.epoch = epoch;
+    parcel_info.last_claimed_at = now;
+    parcel_info.adjacency_score = 0;
+    parcel_info.locked_until = 0;
+    parcel_info.lock_rewards = false;
+    parcel_info.named = false;
+    parcel_info.premium_until = 0;
+    parcel_info.collection_generation = collection_generation;
+    parcel_info.masked_block_count = 0;
+    parcel_info.reserved = false;
+    parcel_info.rewards_excluded = false;
+    parcel_info.paid_total = 0;
+    parcel_info.claimed_at = ParcelInfo::claim_timestamp(now);
+    parcel_info.reward_weight =
+        weights.parcel_weight(rect.blocks(), grid_size, ring_count).ok_or(BillionError::Overflow)?;
+    parcel_info._reserved = [0u8; 0];
+    RingRewardWeights::record_extra(&ctx.accounts.ring_reward_weights, epoch, parcel_info.extra_weight(block_count))?;
+    record_ring_occupancy(&ctx.accounts.ring_occupancy, epoch, rect.blocks(), grid_size, ring_count, true)?;
+    record_map_write(&ctx.accounts.map_digest, epoch, parcel_id, rect)?;
+
+    emit!(ParcelClaimed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        parcel_id,
+        epoch,
+        claimer: claimer.key(),
+        owner: claimer.key(),
+        asset: asset.key(),
+        x,
+        y,
+        width,
+        height,
+        price_per_block: 0,
+        burn_amount: 0,
+        reward_amount: 0,
+        total_burned,
+    });
+
+    invoke_claim_hook(
+        grid_id,
+        &ctx.accounts.hook_config,
+        hook_accounts,
+        &claimer,
+        &asset,
+        OnParcelClaimed {
+            parcel_id,
+            claimer: claimer.key(),
+            x,
+            y,
+            width,
+            height,
+        },
+    )?;
+
+    msg!(
+        "Parcel {} claimed at ({}, {}) with dimensions {}x{} for {} lamports",
+        parcel_id,
+        x,
+        y,
+        width,
+        height,
+        total_lamports
+    );
+    Ok(())
+}
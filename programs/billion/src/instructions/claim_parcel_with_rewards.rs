@@ -0,0 +1,284 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_interface::{Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface},
+    associated_token::AssociatedToken,
+};
+use crate::state::{
+    epoch_seed, grid_seed, GridConfig, BlockMap, ClaimerStats, RewardDust, EpochArchive, HookConfig, ParcelIdPool,
+    ParcelInfo, ReferrerAccount, RingPricing, RingRewardWeights, RingOccupancy, MapDigest, RingUnlockSchedule,
+    TokenTreasury, LAND_BUY_REWARD_POOL_SEED,
+};
+use crate::errors::BillionError;
+use crate::instructions::claim_land_buy_rewards::{require_rewards_claimable, settle_rewards_to};
+use crate::instructions::claim_parcel::{process_claim, ClaimAccounts};
+#[cfg(not(feature = "localnet"))]
+use crate::instructions::claim_parcel::MPL_CORE_ID;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, x: u8, y: u8, width: u8, height: u8, reward_parcel_id: u16)]
+pub struct ClaimParcelWithRewards<'info> {
+    #[account(mut)]
+    pub claimer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// BlockMap address must match the one stored in grid_config
+    #[account(
+        mut,
+        constraint = block_map.key() == grid_config.block_map @ BillionError::Unauthorized
+    )]
+    pub block_map: AccountLoader<'info, BlockMap>,
+
+    /// Token mint must match the one in grid_config (Token-2022)
+    #[account(
+        mut,
+        constraint = token_mint.key() == grid_config.token_mint @ BillionError::Unauthorized
+    )]
+    pub token_mint: InterfaceAccount<'info, InterfaceMint>,
+
+    /// Claimer's token account - receives the rewards, then pays the claim (Token-2022)
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = claimer,
+        associated_token::token_program = token_program,
+    )]
+    pub claimer_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    /// Land buy reward pool - pays the rewards and receives the landowner share
+    #[account(
+        mut,
+        seeds = [LAND_BUY_REWARD_POOL_SEED, grid_config.key().as_ref()],
+        bump,
+        constraint = land_buy_reward_pool.key() == grid_config.land_buy_reward_pool @ BillionError::InvalidRewardPool
+    )]
+    pub land_buy_reward_pool: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    /// The claimer's lifetime stats, created on first touch
+    #[account(
+        init_if_needed,
+        payer = claimer,
+        space = 8 + ClaimerStats::INIT_SPACE,
+        seeds = [ClaimerStats::SEED, &grid_seed(grid_id), claimer.key().as_ref()],
+        bump
+    )]
+    pub claimer_stats: Box<Account<'info, ClaimerStats>>,
+
+    /// Rounding left in the reward pool by the accumulator, created on first touch
+    #[account(
+        init_if_needed,
+        payer = claimer,
+        space = 8 + RewardDust::INIT_SPACE,
+        seeds = [RewardDust::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub reward_dust: Box<Account<'info, RewardDust>>,
+
+    /// Freed parcel ids, reused before next_parcel_id
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(mut, seeds = [ParcelIdPool::SEED, &grid_seed(grid_id)], bump)]
+    pub parcel_id_pool: UncheckedAccount<'info>,
+
+    /// Parcel info PDA - stores asset address for lookups
+    #[account(
+        init,
+        payer = claimer,
+        space = 8 + ParcelInfo::INIT_SPACE,
+        seeds = [
+            ParcelInfo::SEED,
+            &grid_seed(grid_id),
+            &epoch_seed(grid_config.epoch),
+            &ParcelIdPool::next_parcel_id(&parcel_id_pool, grid_config.epoch, grid_config.next_parcel_id)?.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub parcel_info: Box<Account<'info, ParcelInfo>>,
+
+    /// New Core asset - must be a signer (keypair generated client-side)
+    #[account(mut)]
+    pub asset: Signer<'info>,
+
+    /// Core collection - must match grid_config.collection
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        mut,
+        constraint = collection.key() == grid_config.collection @ BillionError::InvalidCollection
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    /// On-claim hook config, always required so a claim can't skip an enabled hook
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(seeds = [HookConfig::SEED, &grid_seed(grid_id)], bump)]
+    pub hook_config: UncheckedAccount<'info>,
+
+    /// Per-ring price multipliers
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(seeds = [RingPricing::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_pricing: UncheckedAccount<'info>,
+
+    /// Per-ring land-buy reward weights, which count the parcel's extra weight; every block
+    /// is unweighted while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(mut, seeds = [RingRewardWeights::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_reward_weights: UncheckedAccount<'info>,
+
+    /// Claimed blocks per ring, which count the parcel's blocks; nothing is counted until
+    /// it's created
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(mut, seeds = [RingOccupancy::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_occupancy: UncheckedAccount<'info>,
+
+    /// Rolling digest of BlockMap writes, which chains in the parcel's blocks
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(mut, seeds = [MapDigest::SEED, &grid_seed(grid_id)], bump)]
+    pub map_digest: UncheckedAccount<'info>,
+
+    /// Times rings open whatever has been burned; rings unlock by burns alone while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(seeds = [RingUnlockSchedule::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_unlock_schedule: UncheckedAccount<'info>,
+
+    /// Protocol treasury share of the cost; nothing is owed while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by process_claim when initialized
+    #[account(seeds = [TokenTreasury::SEED, &grid_seed(grid_id)], bump)]
+    pub token_treasury: UncheckedAccount<'info>,
+
+    /// The configured treasury token account - required while the treasury share is nonzero
+    #[account(mut)]
+    pub treasury_token_account: Option<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    /// Referrer credited with referral_bps of the cost, if any
+    #[account(
+        mut,
+        seeds = [ReferrerAccount::SEED, &grid_seed(grid_id), referrer_account.referrer.as_ref()],
+        bump = referrer_account.bump
+    )]
+    pub referrer_account: Option<Account<'info, ReferrerAccount>>,
+
+    /// Referral vault - required when a referrer is passed and referral_bps > 0
+    #[account(
+        mut,
+        constraint = referral_vault.key() == grid_config.referral_vault @ BillionError::ReferralsDisabled
+    )]
+    pub referral_vault: Option<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    /// ParcelInfo of the parcel whose rewards pay for the claim
+    #[account(
+        mut,
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(reward_parcel_info.epoch), &reward_parcel_id.to_le_bytes()],
+        bump = reward_parcel_info.bump
+    )]
+    pub reward_parcel_info: Box<Account<'info, ParcelInfo>>,
+
+    /// The reward parcel's Core asset - must match reward_parcel_info.asset
+    /// CHECK: Validated by constraint, ownership checked in handler
+    #[account(
+        constraint = reward_asset.key() == reward_parcel_info.asset @ BillionError::AssetMismatch
+    )]
+    pub reward_asset: UncheckedAccount<'info>,
+
+    /// Archive of the reward parcel's epoch - required when it is from an earlier epoch
+    #[account(
+        seeds = [EpochArchive::SEED, &grid_seed(grid_id), &epoch_archive.epoch.to_le_bytes()],
+        bump = epoch_archive.bump
+    )]
+    pub epoch_archive: Option<Account<'info, EpochArchive>>,
+}
+
+/// Claims the `width` x `height` box at (x, y) like claim_parcel, paid first out of the
+/// land-buy rewards owed to `reward_parcel_id`, which the claimer must own as for
+/// claim_land_buy_rewards. The rewards are settled before the claim, so the new parcel's
+/// blocks aren't counted yet when the claim credits the accumulator and it earns nothing from
+/// its own purchase. The reward share and burn are computed on the full cost; the claimer's
+/// ATA only covers the shortfall, and rewards above the cost stay in it. No adjacency bonus
+/// is paid. remaining_accounts work as in claim_parcel; charity routing and allowlist proofs
+/// are only taken by claim_parcel.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimParcelWithRewards<'info>>,
+    grid_id: u64,
+    x: u8,
+    y: u8,
+    width: u8,
+    height: u8,
+    reward_parcel_id: u16,
+) -> Result<()> {
+    require_rewards_claimable(
+        &ctx.accounts.reward_parcel_info,
+        &ctx.accounts.reward_asset.to_account_info(),
+        &ctx.accounts.claimer.key(),
+        &ctx.accounts.grid_config,
+        Clock::get()?.unix_timestamp,
+    )?;
+    let owed = settle_rewards_to(
+        &mut ctx.accounts.reward_parcel_info,
+        grid_id,
+        &mut ctx.accounts.grid_config,
+        ctx.accounts.epoch_archive.as_deref(),
+        &ctx.accounts.land_buy_reward_pool,
+        &ctx.accounts.claimer_token_account,
+        &ctx.accounts.token_mint,
+        &ctx.accounts.token_program,
+    )?;
+    require!(owed > 0, BillionError::NothingToClaim);
+    // The claim checks and splits against the balances the rewards just moved
+    ctx.accounts.claimer_token_account.reload()?;
+    ctx.accounts.land_buy_reward_pool.reload()?;
+
+    let accounts = ClaimAccounts {
+        claimer: ctx.accounts.claimer.to_account_info(),
+        payer: ctx.accounts.claimer.to_account_info(),
+        owner: ctx.accounts.claimer.to_account_info(),
+        delegated: false,
+        allowlisted: false,
+        grid_id,
+        grid_config: &mut ctx.accounts.grid_config,
+        block_map: &ctx.accounts.block_map,
+        token_mint: &ctx.accounts.token_mint,
+        claimer_token_account: &ctx.accounts.claimer_token_account,
+        land_buy_reward_pool: &ctx.accounts.land_buy_reward_pool,
+        claimer_stats: &mut ctx.accounts.claimer_stats,
+        claimer_stats_bump: ctx.bumps.claimer_stats,
+        parcel_info: &mut ctx.accounts.parcel_info,
+        parcel_info_bump: ctx.bumps.parcel_info,
+        asset: ctx.accounts.asset.to_account_info(),
+        collection: &ctx.accounts.collection,
+        mpl_core_program: &ctx.accounts.mpl_core_program,
+        token_program: &ctx.accounts.token_program,
+        system_program: &ctx.accounts.system_program,
+        hook_config: &ctx.accounts.hook_config,
+        ring_pricing: &ctx.accounts.ring_pricing,
+        ring_reward_weights: &ctx.accounts.ring_reward_weights,
+        ring_occupancy: &ctx.accounts.ring_occupancy,
+        map_digest: &ctx.accounts.map_digest,
+        ring_unlock_schedule: &ctx.accounts.ring_unlock_schedule,
+        token_treasury: &ctx.accounts.token_treasury,
+        treasury_token_account: ctx.accounts.treasury_token_account.as_ref(),
+        parcel_id_pool: Some(&ctx.accounts.parcel_id_pool),
+        referral_share: None,
+        reward_dust: &mut ctx.accounts.reward_dust,
+        reward_dust_bump: ctx.bumps.reward_dust,
+        remaining_accounts: ctx.remaining_accounts,
+        referrer_account: ctx.accounts.referrer_account.as_mut(),
+        referral_vault: ctx.accounts.referral_vault.as_ref(),
+        charity: None,
+    };
+    process_claim(accounts, x, y, width, height, None, None, false)?;
+    ctx.accounts.claimer_stats.record_rewards(owed).ok_or(BillionError::Overflow)?;
+
+    msg!("Applied {} reward tokens from parcel {} to the claim", owed, reward_parcel_id);
+    Ok(())
+}
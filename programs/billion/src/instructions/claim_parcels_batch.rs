@@ -0,0 +1,542 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::{
+    token_2022,
+    token_interface::{Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface},
+    associated_token::AssociatedToken,
+};
+use mpl_core::instructions::CreateV2CpiBuilder;
+use billion_core::Rect;
+use crate::state::{
+    epoch_seed, grid_seed, GridConfig, BlockMap, ClaimerStats, HookConfig, OnParcelClaimed, ParcelInfo, RewardDust,
+    RingPricing, RingRewardWeights, RingOccupancy, MapDigest, RingUnlockSchedule, TokenTreasury, LAND_BUY_REWARD_POOL_SEED,
+};
+use crate::constants::{MAX_BATCH_RECTS, REWARD_WEIGHT_UNIT};
+use crate::errors::BillionError;
+use crate::events::{ParcelClaimed, PaymentSettled, TreasuryPaid, EVENT_SCHEMA_VERSION};
+use crate::instructions::claim_parcel::{
+    emit_ring_unlock, invoke_claim_hook, preview_claim_cost, validate_claim, ClaimPreview,
+};
+use crate::instructions::refresh_parcel_attributes::parcel_attributes_plugin;
+use crate::instructions::admin_backfill_ring_occupancy::record_ring_occupancy;
+use crate::instructions::init_map_digest::record_map_write;
+#[cfg(not(feature = "localnet"))]
+use crate::instructions::claim_parcel::MPL_CORE_ID;
+
+/// One rectangle of a claim_parcels_batch
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BatchRect {
+    pub x: u8,
+    pub y: u8,
+    pub width: u8,
+    pub height: u8,
+}
+
+impl BatchRect {
+    pub fn rect(&self) -> Rect {
+        Rect::new(self.x, self.y, self.width, self.height)
+    }
+
+    pub fn block_count(&self) -> u32 {
+        (self.width as u32) * (self.height as u32)
+    }
+}
+
+/// Same accounts as ClaimParcel minus the per-parcel ones, which come in remaining_accounts,
+/// and minus referrals and charity routing
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct ClaimParcelsBatch<'info> {
+    #[account(mut)]
+    pub claimer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Box<Account<'info, GridConfig>>,
+
+    /// BlockMap address must match the one stored in grid_config
+    #[account(
+        mut,
+        constraint = block_map.key() == grid_config.block_map @ BillionError::Unauthorized
+    )]
+    pub block_map: AccountLoader<'info, BlockMap>,
+
+    /// Token mint must match the one in grid_config (Token-2022)
+    #[account(
+        mut,
+        constraint = token_mint.key() == grid_config.token_mint @ BillionError::Unauthorized
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, InterfaceMint>>,
+
+    /// Claimer's token account for burning (Token-2022)
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = claimer,
+        associated_token::token_program = token_program,
+    )]
+    pub claimer_token_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    /// Land buy reward pool - receives the landowner share
+    #[account(
+        mut,
+        seeds = [LAND_BUY_REWARD_POOL_SEED, grid_config.key().as_ref()],
+        bump,
+        constraint = land_buy_reward_pool.key() == grid_config.land_buy_reward_pool @ BillionError::InvalidRewardPool
+    )]
+    pub land_buy_reward_pool: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    /// The claimer's lifetime stats, created on first touch
+    #[account(
+        init_if_needed,
+        payer = claimer,
+        space = 8 + ClaimerStats::INIT_SPACE,
+        seeds = [ClaimerStats::SEED, &grid_seed(grid_id), claimer.key().as_ref()],
+        bump
+    )]
+    pub claimer_stats: Box<Account<'info, ClaimerStats>>,
+
+    /// Rounding left in the reward pool by the accumulator, created on first touch
+    #[account(
+        init_if_needed,
+        payer = claimer,
+        space = 8 + RewardDust::INIT_SPACE,
+        seeds = [RewardDust::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub reward_dust: Box<Account<'info, RewardDust>>,
+
+    /// Core collection - must match grid_config.collection
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        mut,
+        constraint = collection.key() == grid_config.collection @ BillionError::InvalidCollection
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    /// On-claim hook config, always required so a claim can't skip an enabled hook
+    /// CHECK: Seeds are verified here, contents are deserialized by invoke_claim_hook when initialized
+    #[account(seeds = [HookConfig::SEED, &grid_seed(grid_id)], bump)]
+    pub hook_config: UncheckedAccount<'info>,
+
+    /// Per-ring price multipliers, flat pricing while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [RingPricing::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_pricing: UncheckedAccount<'info>,
+
+    /// Per-ring land-buy reward weights, which count the parcels' extra weight; every block
+    /// is unweighted while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(mut, seeds = [RingRewardWeights::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_reward_weights: UncheckedAccount<'info>,
+
+    /// Claimed blocks per ring, which count the parcels' blocks; nothing is counted until
+    /// it's created
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(mut, seeds = [RingOccupancy::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_occupancy: UncheckedAccount<'info>,
+
+    /// Rolling digest of BlockMap writes, which chains in each parcel's blocks
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(mut, seeds = [MapDigest::SEED, &grid_seed(grid_id)], bump)]
+    pub map_digest: UncheckedAccount<'info>,
+
+    /// Times rings open whatever has been burned; rings unlock by burns alone while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [RingUnlockSchedule::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_unlock_schedule: UncheckedAccount<'info>,
+
+    /// Protocol treasury share of the cost; nothing is owed while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [TokenTreasury::SEED, &grid_seed(grid_id)], bump)]
+    pub token_treasury: UncheckedAccount<'info>,
+
+    /// The configured treasury token account - required while the treasury share is nonzero
+    #[account(mut)]
+    pub treasury_token_account: Option<InterfaceAccount<'info, InterfaceTokenAccount>>,
+}
+
+/// Creates the ParcelInfo PDA at `parcel_info` and writes `data` into it. Mirrors Anchor's
+/// `init`, including for an address someone pre-funded to block it.
+pub(crate) fn create_parcel_info<'info>(
+    payer: &AccountInfo<'info>,
+    parcel_info: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    seeds: &[&[u8]],
+    data: &ParcelInfo,
+) -> Result<()> {
+    let space = 8 + ParcelInfo::INIT_SPACE;
+    let rent = Rent::get()?.minimum_balance(space);
+    let current_lamports = parcel_info.lamports();
+    if current_lamports == 0 {
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::CreateAccount { from: payer.clone(), to: parcel_info.clone() },
+                &[seeds],
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        )?;
+    } else {
+        require!(
+            parcel_info.owner == &system_program::ID && parcel_info.data_is_empty(),
+            BillionError::InvalidBatch
+        );
+        if current_lamports < rent {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    system_program::Transfer { from: payer.clone(), to: parcel_info.clone() },
+                ),
+                rent - current_lamports,
+            )?;
+        }
+        system_program::allocate(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::Allocate { account_to_allocate: parcel_info.clone() },
+                &[seeds],
+            ),
+            space as u64,
+        )?;
+        system_program::assign(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::Assign { account_to_assign: parcel_info.clone() },
+                &[seeds],
+            ),
+            &crate::ID,
+        )?;
+    }
+    data.try_serialize(&mut &mut parcel_info.try_borrow_mut_data()?[..])
+}
+
+/// Claims every rectangle in `rects` for one combined payment with a single reward/burn
+/// split. remaining_accounts are, per rectangle in order, its Core asset (a signer) and its
+/// ParcelInfo PDA, followed by the hook program and its accounts when the hook applies.
+/// Rectangles needing an adjacency proof aren't accepted; claim those with claim_parcel.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimParcelsBatch<'info>>,
+    grid_id: u64,
+    rects: Vec<BatchRect>,
+) -> Result<()> {
+    require!(
+        !rects.is_empty() && rects.len() <= MAX_BATCH_RECTS as usize,
+        BillionError::InvalidBatch
+    );
+    require!(
+        ctx.remaining_accounts.len() >= 2 * rects.len(),
+        BillionError::InvalidBatch
+    );
+    let (parcel_accounts, hook_accounts) = ctx.remaining_accounts.split_at(2 * rects.len());
+
+    let grid_config = &mut ctx.accounts.grid_config;
+    let ring_count = grid_config.ring_count();
+    require!(
+        grid_config.collection != Pubkey::default(),
+        BillionError::CollectionNotSet
+    );
+    // Allowlist proofs are only taken by claim_parcel
+    require!(!grid_config.allowlist_only, BillionError::NotAllowlisted);
+
+    // Each rectangle is written to the BlockMap as soon as it validates, so one overlapping
+    // an earlier rectangle of the batch fails as already claimed. Any failure reverts the
+    // whole instruction, writes included.
+    let now = Clock::get()?.unix_timestamp;
+    let schedule = RingUnlockSchedule::load(&ctx.accounts.ring_unlock_schedule)?;
+    let previously_unlocked = grid_config.unlocked_ring_at(&schedule, now);
+    let first_parcel_id = grid_config.next_parcel_id;
+    require!(grid_config.has_parcel_ids(rects.len()), BillionError::ParcelIdExhausted);
+    // The whole batch is priced at the curve price from before its first block
+    let ring_pricing = RingPricing::load(&ctx.accounts.ring_pricing)?;
+    let weights = RingRewardWeights::load(&ctx.accounts.ring_reward_weights)?;
+    let treasury = TokenTreasury::load(&ctx.accounts.token_treasury)?;
+    let price_per_block = ring_pricing
+        .curve_price(grid_config.price_per_block, grid_config.total_claimed_blocks)
+        .ok_or(BillionError::Overflow)?;
+    let mut parcel_ids = Vec::with_capacity(rects.len());
+    let mut rect_costs = Vec::with_capacity(rects.len());
+    let mut block_count: u32 = 0;
+    let mut total_cost: u64 = 0;
+    let grid_size = {
+        let mut block_map = BlockMap::load_mut(&ctx.accounts.block_map)?;
+        require!(!block_map.is_frozen(), BillionError::EpochFrozen);
+        let grid_size = block_map.grid_size();
+        for (i, batch_rect) in rects.iter().enumerate() {
+            let BatchRect { x, y, width, height } = *batch_rect;
+            validate_claim(x, y, width, height, &block_map, grid_config, previously_unlocked)?;
+            require!(
+                !grid_config.requires_adjacency_proof(batch_rect.rect(), now, grid_size),
+                BillionError::AdjacencyProofRequired
+            );
+
+            let parcel_id = first_parcel_id.checked_add(i as u16).ok_or(BillionError::Overflow)?;
+            for (block_x, block_y) in batch_rect.rect().blocks() {
+                block_map.set_block(block_x, block_y, parcel_id);
+            }
+            parcel_ids.push(parcel_id);
+            block_count = block_count
+                .checked_add(batch_rect.block_count())
+                .ok_or(BillionError::Overflow)?;
+            let rect_cost = ring_pricing
+                .rect_cost(batch_rect.rect(), price_per_block, grid_size, ring_count)
+                .ok_or(BillionError::Overflow)?;
+            total_cost = total_cost.checked_add(rect_cost).ok_or(BillionError::Overflow)?;
+            rect_costs.push(rect_cost);
+        }
+        grid_size
+    };
+
+    // One payment for all blocks; rewards are spread over the landowners from before the batch
+    let preview =
+        preview_claim_cost(block_count, price_per_block, total_cost, false, 0, 0, grid_config, &weights, &treasury)?;
+    let ClaimPreview {
+        burn_amount,
+        reward_amount,
+        treasury_amount,
+        ..
+    } = preview;
+    require!(
+        grid_config.allows_wallet_blocks(ctx.accounts.claimer_stats.blocks_claimed, block_count),
+        BillionError::WalletBlockLimitExceeded
+    );
+    require!(
+        ctx.accounts.claimer_token_account.amount >= total_cost,
+        BillionError::InsufficientBalance
+    );
+
+    let token_program = ctx.accounts.token_program.to_account_info();
+    if reward_amount > 0 {
+        token_2022::transfer_checked(
+            CpiContext::new(
+                token_program.clone(),
+                token_2022::TransferChecked {
+                    from: ctx.accounts.claimer_token_account.to_account_info(),
+                    to: ctx.accounts.land_buy_reward_pool.to_account_info(),
+                    authority: ctx.accounts.claimer.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                },
+            ),
+            reward_amount,
+            ctx.accounts.token_mint.decimals,
+        )?;
+    }
+    if treasury_amount > 0 {
+        let treasury_token_account =
+            ctx.accounts.treasury_token_account.as_ref().ok_or(BillionError::InvalidTreasury)?;
+        require_keys_eq!(treasury_token_account.key(), treasury.treasury, BillionError::InvalidTreasury);
+        token_2022::transfer_checked(
+            CpiContext::new(
+                token_program.clone(),
+                token_2022::TransferChecked {
+                    from: ctx.accounts.claimer_token_account.to_account_info(),
+                    to: treasury_token_account.to_account_info(),
+                    authority: ctx.accounts.claimer.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                },
+            ),
+            treasury_amount,
+            ctx.accounts.token_mint.decimals,
+        )?;
+        emit!(TreasuryPaid {
+            schema_version: EVENT_SCHEMA_VERSION,
+            parcel_id: first_parcel_id,
+            epoch: grid_config.epoch,
+            claimer: ctx.accounts.claimer.key(),
+            treasury: treasury_token_account.key(),
+            amount: treasury_amount,
+        });
+    }
+    if burn_amount > 0 {
+        token_2022::burn(
+            CpiContext::new(
+                token_program,
+                token_2022::Burn {
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    from: ctx.accounts.claimer_token_account.to_account_info(),
+                    authority: ctx.accounts.claimer.to_account_info(),
+                },
+            ),
+            burn_amount,
+        )?;
+    }
+
+    let claimer = ctx.accounts.claimer.to_account_info();
+    emit!(PaymentSettled {
+        schema_version: EVENT_SCHEMA_VERSION,
+        parcel_id: first_parcel_id,
+        epoch: grid_config.epoch,
+        claimer: claimer.key(),
+        total_cost,
+        reward_amount,
+        referral_amount: 0,
+        charity_amount: 0,
+        charity: Pubkey::default(),
+        burn_amount,
+    });
+
+    let claimer_stats = &mut ctx.accounts.claimer_stats;
+    claimer_stats.touch(claimer.key(), ctx.bumps.claimer_stats);
+    for (batch_rect, &cost) in rects.iter().zip(&rect_costs) {
+        claimer_stats
+            .record_claim(cost, batch_rect.block_count(), now)
+            .ok_or(BillionError::Overflow)?;
+    }
+
+    // The aggregate reward reaches the accumulator before the batch's blocks are counted
+    if grid_config.total_claimed_blocks > 0 && reward_amount > 0 {
+        grid_config.credit_land_buy_rewards(reward_amount).ok_or(BillionError::Overflow)?;
+        let reward_increase = preview.land_buy_rewards_per_block - grid_config.land_buy_rewards_per_block;
+        let reward_units = weights
+            .reward_units(grid_config.epoch, grid_config.total_claimed_blocks)
+            .ok_or(BillionError::Overflow)?;
+        let reward_dust = &mut ctx.accounts.reward_dust;
+        reward_dust.bump = ctx.bumps.reward_dust;
+        reward_dust.record(reward_amount, reward_increase, reward_units).ok_or(BillionError::Overflow)?;
+    }
+    let unlocked = schedule.unlocked_ring(preview.unlocked_ring, now, ring_count);
+    grid_config.record_ring_unlock(previously_unlocked, unlocked, now);
+    emit_ring_unlock(grid_config.epoch, previously_unlocked, unlocked, preview.total_burned, first_parcel_id);
+    grid_config.land_buy_rewards_per_block = preview.land_buy_rewards_per_block;
+    grid_config.total_claimed_blocks = preview.total_claimed_blocks;
+    grid_config.total_burned = preview.total_burned;
+    grid_config.next_parcel_id = first_parcel_id
+        .checked_add(rects.len() as u16)
+        .ok_or(BillionError::Overflow)?;
+
+    let epoch = grid_config.epoch;
+    let collection_generation = grid_config.collection_generation;
+    let grid_bytes = grid_seed(grid_id);
+    let epoch_bytes = epoch_seed(epoch);
+    let grid_config_info = grid_config.to_account_info();
+    let grid_config_seeds: &[&[u8]] = &[GridConfig::SEED, &grid_bytes, &[grid_config.bump]];
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let mut extra_weight = 0i64;
+
+    for (((batch_rect, cost), parcel_id), accounts) in
+        rects.iter().zip(rect_costs).zip(parcel_ids).zip(parcel_accounts.chunks(2))
+    {
+        let (asset, parcel_info) = (&accounts[0], &accounts[1]);
+        require!(asset.is_signer, BillionError::InvalidBatch);
+
+        let parcel_id_bytes = parcel_id.to_le_bytes();
+        let (expected, parcel_info_bump) =
+            Pubkey::find_program_address(&[ParcelInfo::SEED, &grid_bytes, &epoch_bytes, &parcel_id_bytes], &crate::ID);
+        require!(parcel_info.key() == expected, BillionError::InvalidBatch);
+
+        let BatchRect { x, y, width, height } = *batch_rect;
+        let reward_weight =
+            weights.parcel_weight(batch_rect.rect().blocks(), grid_size, ring_count).ok_or(BillionError::Overflow)?;
+        CreateV2CpiBuilder::new(&ctx.accounts.mpl_core_program.to_account_info())
+            .asset(asset)
+            .collection(Some(&ctx.accounts.collection.to_account_info()))
+            .authority(Some(&grid_config_info))
+            .payer(&claimer)
+            .owner(Some(&claimer))
+            .system_program(&system_program)
+            .name(format!("Parcel #{}", parcel_id))
+            .uri(format!("{}{}", grid_config.uri_base, parcel_id))
+            .plugins(vec![parcel_attributes_plugin(grid_config_info.key(), parcel_id, x, y, width, height)])
+            .invoke_signed(&[grid_config_seeds])?;
+
+        create_parcel_info(
+            &claimer,
+            parcel_info,
+            &system_program,
+            &[ParcelInfo::SEED, &grid_bytes, &epoch_bytes, &parcel_id_bytes, &[parcel_info_bump]],
+            &ParcelInfo {
+                asset: asset.key(),
+                x,
+                y,
+                width,
+                height,
+                bump: parcel_info_bump,
+                last_claimed_land_buy_rewards_per_block: grid_config.land_buy_rewards_per_block,
+                epoch,
+                last_claimed_at: now,
+                adjacency_score: 0,
+                locked_until: 0,
+                lock_rewards: false,
+                named: false,
+                premium_until: 0,
+                collection_generation,
+                masked_block_count: 0,
+                reserved: false,
+                rewards_excluded: false,
+                paid_total: cost,
+                claimed_at: ParcelInfo::claim_timestamp(now),
+                reward_weight,
+                _reserved: [0u8; 0],
+            },
+        )?;
+        let weight_delta = reward_weight as i64 - REWARD_WEIGHT_UNIT as i64;
+        extra_weight = extra_weight
+            .checked_add(batch_rect.block_count() as i64 * weight_delta)
+            .ok_or(BillionError::Overflow)?;
+
+        let first = parcel_id == first_parcel_id;
+        emit!(ParcelClaimed {
+            schema_version: EVENT_SCHEMA_VERSION,
+            parcel_id,
+            epoch,
+            claimer: claimer.key(),
+            owner: claimer.key(),
+            asset: asset.key(),
+            x,
+            y,
+            width,
+            height,
+            price_per_block,
+            burn_amount: if first { burn_amount } else { 0 },
+            reward_amount: if first { reward_amount } else { 0 },
+            total_burned: grid_config.total_burned,
+        });
+
+        invoke_claim_hook(
+            grid_id,
+            &ctx.accounts.hook_config,
+            hook_accounts,
+            &claimer,
+            asset,
+            OnParcelClaimed {
+                parcel_id,
+                claimer: claimer.key(),
+                x,
+                y,
+                width,
+                height,
+            },
+        )?;
+    }
+    RingRewardWeights::record_extra(&ctx.accounts.ring_reward_weights, epoch, extra_weight)?;
+    let blocks = rects.iter().flat_map(|batch_rect| batch_rect.rect().blocks());
+    record_ring_occupancy(&ctx.accounts.ring_occupancy, epoch, blocks, grid_size, ring_count, true)?;
+    for (parcel_id, batch_rect) in (first_parcel_id..).zip(&rects) {
+        record_map_write(&ctx.accounts.map_digest, epoch, parcel_id, batch_rect.rect())?;
+    }
+
+    msg!(
+        "Parcels {}..={} claimed in one batch, {} blocks at {} per block, burned {} tokens, {} to rewards pool",
+        first_parcel_id,
+        first_parcel_id + rects.len() as u16 - 1,
+        block_count,
+        price_per_block,
+        burn_amount,
+        reward_amount
+    );
+    Ok(())
+}
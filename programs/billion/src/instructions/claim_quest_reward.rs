@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022,
+    token_interface::{Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface},
+    associated_token::AssociatedToken,
+};
+use crate::state::{grid_seed, quest_leaf, verify_quest_proof, GridConfig, Quest, QuestClaims, QUEST_VAULT_SEED};
+use crate::errors::BillionError;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, amount: u64, index: u32)]
+pub struct ClaimQuestReward<'info> {
+    #[account(mut)]
+    pub claimer: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        mut,
+        seeds = [Quest::SEED, &grid_seed(grid_id), quest.root.as_ref()],
+        bump = quest.bump
+    )]
+    pub quest: Account<'info, Quest>,
+
+    /// Bitmap chunk holding `index`, created by the first claimer in the chunk
+    #[account(
+        init_if_needed,
+        payer = claimer,
+        space = 8 + QuestClaims::INIT_SPACE,
+        seeds = [QuestClaims::SEED, quest.key().as_ref(), &QuestClaims::chunk_of(index).to_le_bytes()],
+        bump
+    )]
+    pub quest_claims: Account<'info, QuestClaims>,
+
+    /// Quest vault paying the reward
+    #[account(
+        mut,
+        seeds = [QUEST_VAULT_SEED, grid_config.key().as_ref()],
+        bump,
+        constraint = quest_vault.key() == grid_config.quest_vault @ BillionError::QuestsDisabled
+    )]
+    pub quest_vault: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    /// Claimer's token account to receive the reward
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = claimer,
+        associated_token::token_program = token_program,
+    )]
+    pub claimer_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    #[account(
+        constraint = token_mint.key() == grid_config.token_mint @ BillionError::Unauthorized
+    )]
+    pub token_mint: InterfaceAccount<'info, InterfaceMint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimQuestReward>, grid_id: u64, amount: u64, index: u32, proof: Vec<[u8; 32]>) -> Result<()> {
+    let quest = &mut ctx.accounts.quest;
+    let leaf = quest_leaf(index, &ctx.accounts.claimer.key(), amount);
+    require!(verify_quest_proof(&proof, &quest.root, leaf), BillionError::InvalidQuestProof);
+
+    let claims = &mut ctx.accounts.quest_claims;
+    if claims.quest == Pubkey::default() {
+        claims.quest = quest.key();
+        claims.chunk = QuestClaims::chunk_of(index);
+        claims.bump = ctx.bumps.quest_claims;
+    }
+    require!(!claims.is_claimed(index), BillionError::QuestAlreadyClaimed);
+    claims.set_claimed(index);
+
+    // Caps a bad tree at the published allocation
+    quest.claimed_amount = quest
+        .claimed_amount
+        .checked_add(amount)
+        .ok_or(BillionError::Overflow)?;
+    require!(
+        quest.claimed_amount <= quest.total_allocation,
+        BillionError::QuestAllocationExceeded
+    );
+    quest.claim_count = quest.claim_count.checked_add(1).ok_or(BillionError::Overflow)?;
+
+    let bump = ctx.accounts.grid_config.bump;
+    let grid_bytes = grid_seed(grid_id);
+    let seeds: &[&[u8]] = &[GridConfig::SEED, &grid_bytes, &[bump]];
+    let signer_seeds: &[&[&[u8]]] = &[seeds];
+
+    let cpi_accounts = token_2022::TransferChecked {
+        from: ctx.accounts.quest_vault.to_account_info(),
+        to: ctx.accounts.claimer_token_account.to_account_info(),
+        authority: ctx.accounts.grid_config.to_account_info(),
+        mint: ctx.accounts.token_mint.to_account_info(),
+    };
+    token_2022::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        ),
+        amount,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    msg!("Claimed quest reward {} for leaf {}", amount, index);
+    Ok(())
+}
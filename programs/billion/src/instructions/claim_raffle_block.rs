@@ -0,0 +1,243 @@
+use anchor_lang::prelude::*;
+use billion_core::Rect;
+use anchor_spl::{
+    token_2022,
+    token_interface::{Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface},
+};
+use mpl_core::instructions::CreateV2CpiBuilder;
+use crate::state::{
+    epoch_seed, grid_seed, GridConfig, BlockMap, ParcelInfo, Raffle, RingRewardWeights, RingOccupancy, MapDigest,
+    RingUnlockSchedule, LAND_BUY_REWARD_POOL_SEED,
+};
+use crate::errors::BillionError;
+use crate::instructions::claim_parcel::{emit_ring_unlock, validate_claim};
+use crate::instructions::refresh_parcel_attributes::parcel_attributes_plugin;
+use crate::instructions::admin_backfill_ring_occupancy::record_ring_occupancy;
+use crate::instructions::init_map_digest::record_map_write;
+#[cfg(not(feature = "localnet"))]
+use crate::instructions::claim_parcel::MPL_CORE_ID;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct ClaimRaffleBlock<'info> {
+    #[account(mut)]
+    pub claimer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Box<Account<'info, GridConfig>>,
+
+    /// BlockMap address must match the one stored in grid_config
+    #[account(
+        mut,
+        constraint = block_map.key() == grid_config.block_map @ BillionError::Unauthorized
+    )]
+    pub block_map: AccountLoader<'info, BlockMap>,
+
+    #[account(
+        mut,
+        seeds = [Raffle::SEED, &grid_seed(grid_id)],
+        bump = raffle.bump
+    )]
+    pub raffle: Box<Account<'info, Raffle>>,
+
+    /// Parcel info PDA - stores asset address for lookups
+    #[account(
+        init,
+        payer = claimer,
+        space = 8 + ParcelInfo::INIT_SPACE,
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(grid_config.epoch), &grid_config.next_parcel_id.to_le_bytes()],
+        bump
+    )]
+    pub parcel_info: Box<Account<'info, ParcelInfo>>,
+
+    /// Land buy reward pool - its surplus pays the prize block's burn
+    #[account(
+        mut,
+        seeds = [LAND_BUY_REWARD_POOL_SEED, grid_config.key().as_ref()],
+        bump,
+        constraint = land_buy_reward_pool.key() == grid_config.land_buy_reward_pool @ BillionError::InvalidRewardPool
+    )]
+    pub land_buy_reward_pool: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = token_mint.key() == grid_config.token_mint @ BillionError::Unauthorized
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, InterfaceMint>>,
+
+    /// New Core asset - must be a signer (keypair generated client-side)
+    #[account(mut)]
+    pub asset: Signer<'info>,
+
+    /// Core collection - must match grid_config.collection
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        mut,
+        constraint = collection.key() == grid_config.collection @ BillionError::InvalidCollection
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+
+    /// Per-ring land-buy reward weights, which count the parcel's extra weight; every block
+    /// is unweighted while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(mut, seeds = [RingRewardWeights::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_reward_weights: UncheckedAccount<'info>,
+
+    /// Claimed blocks per ring, which count the parcel's blocks; nothing is counted until
+    /// it's created
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(mut, seeds = [RingOccupancy::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_occupancy: UncheckedAccount<'info>,
+
+    /// Rolling digest of BlockMap writes, which chains in the parcel's blocks
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(mut, seeds = [MapDigest::SEED, &grid_seed(grid_id)], bump)]
+    pub map_digest: UncheckedAccount<'info>,
+
+    /// Times rings open whatever has been burned; rings unlock by burns alone while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [RingUnlockSchedule::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_unlock_schedule: UncheckedAccount<'info>,
+}
+
+/// First caller wins the open raffle prize as a 1x1 parcel. The claimer pays only rent; the
+/// block's price is burned out of the reward pool surplus, so it still counts toward ring
+/// unlocks.
+pub fn handler(ctx: Context<ClaimRaffleBlock>, grid_id: u64) -> Result<()> {
+    let grid_config = &ctx.accounts.grid_config;
+    let ring_count = grid_config.ring_count();
+    require!(
+        grid_config.collection != Pubkey::default(),
+        BillionError::CollectionNotSet
+    );
+    require!(grid_config.has_parcel_ids(1), BillionError::ParcelIdExhausted);
+
+    let now = Clock::get()?.unix_timestamp;
+    let raffle = &ctx.accounts.raffle;
+    require!(raffle.prize_open(now), BillionError::NoRafflePrize);
+    let (x, y) = (raffle.prize_x, raffle.prize_y);
+
+    // Anyone may have bought the block since the draw
+    let schedule = RingUnlockSchedule::load(&ctx.accounts.ring_unlock_schedule)?;
+    let previously_unlocked = grid_config.unlocked_ring_at(&schedule, now);
+    let grid_size = {
+        let block_map = BlockMap::load(&ctx.accounts.block_map)?;
+        require!(!block_map.is_frozen(), BillionError::EpochFrozen);
+        validate_claim(x, y, 1, 1, &block_map, grid_config, previously_unlocked)?;
+        block_map.grid_size()
+    };
+
+    let price = grid_config.price_per_block;
+    require!(
+        grid_config.reward_surplus(ctx.accounts.land_buy_reward_pool.amount) >= price,
+        BillionError::RewardPoolInsufficient
+    );
+
+    let bump = grid_config.bump;
+    let grid_bytes = grid_seed(grid_id);
+    let seeds: &[&[u8]] = &[GridConfig::SEED, &grid_bytes, &[bump]];
+    let signer_seeds: &[&[&[u8]]] = &[seeds];
+    if price > 0 {
+        token_2022::burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_2022::Burn {
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    from: ctx.accounts.land_buy_reward_pool.to_account_info(),
+                    authority: ctx.accounts.grid_config.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            price,
+        )?;
+    }
+
+    let parcel_id = ctx.accounts.grid_config.next_parcel_id;
+    let grid_config = &mut ctx.accounts.grid_config;
+    grid_config.total_burned = grid_config.total_burned.checked_add(price).ok_or(BillionError::Overflow)?;
+    let unlocked = grid_config.unlocked_ring_at(&schedule, now);
+    grid_config.record_ring_unlock(previously_unlocked, unlocked, now);
+    emit_ring_unlock(grid_config.epoch, previously_unlocked, unlocked, grid_config.total_burned, parcel_id);
+    grid_config.total_claimed_blocks = grid_config
+        .total_claimed_blocks
+        .checked_add(1)
+        .ok_or(BillionError::Overflow)?;
+    grid_config.next_parcel_id = grid_config
+        .next_parcel_id
+        .checked_add(1)
+        .ok_or(BillionError::Overflow)?;
+
+    let uri_base = grid_config.uri_base.clone();
+    let current_rewards_per_block = grid_config.land_buy_rewards_per_block;
+    let epoch = grid_config.epoch;
+    let collection_generation = grid_config.collection_generation;
+
+    BlockMap::load_mut(&ctx.accounts.block_map)?.set_block(x, y, parcel_id);
+
+    CreateV2CpiBuilder::new(&ctx.accounts.mpl_core_program.to_account_info())
+        .asset(&ctx.accounts.asset.to_account_info())
+        .collection(Some(&ctx.accounts.collection.to_account_info()))
+        .authority(Some(&ctx.accounts.grid_config.to_account_info()))
+        .payer(&ctx.accounts.claimer.to_account_info())
+        .owner(Some(&ctx.accounts.claimer.to_account_info()))
+        .system_program(&ctx.accounts.system_program.to_account_info())
+        .name(format!("Parcel #{}", parcel_id))
+        .uri(format!("{}{}", uri_base, parcel_id))
+        .plugins(vec![parcel_attributes_plugin(ctx.accounts.grid_config.key(), parcel_id, x, y, 1, 1)])
+        .invoke_signed(signer_seeds)?;
+
+    let weights = RingRewardWeights::load(&ctx.accounts.ring_reward_weights)?;
+    let parcel_info = &mut ctx.accounts.parcel_info;
+    parcel_info.asset = ctx.accounts.asset.key();
+    parcel_info.x = x;
+    parcel_info.y = y;
+    parcel_info.width = 1;
+    parcel_info.height = 1;
+    parcel_info.bump = ctx.bumps.parcel_info;
+    parcel_info.last_claimed_land_buy_rewards_per_block = current_rewards_per_block;
+    parcel_info.epoch = epoch;
+    parcel_info.last_claimed_at = now;
+    parcel_info.adjacency_score = 0;
+    parcel_info.locked_until = 0;
+    parcel_info.lock_rewards = false;
+    parcel_info.named = false;
+    parcel_info.premium_until = 0;
+    parcel_info.collection_generation = collection_generation;
+    parcel_info.masked_block_count = 0;
+    parcel_info.reserved = false;
+    parcel_info.rewards_excluded = false;
+    parcel_info.paid_total = 0;
+    parcel_info.claimed_at = ParcelInfo::claim_timestamp(now);
+    parcel_info.reward_weight =
+        weights.parcel_weight(std::iter::once((x, y)), grid_size, ring_count).ok_or(BillionError::Overflow)?;
+    parcel_info._reserved = [0u8; 0];
+    RingRewardWeights::record_extra(&ctx.accounts.ring_reward_weights, epoch, parcel_info.extra_weight(1))?;
+    record_ring_occupancy(&ctx.accounts.ring_occupancy, epoch, std::iter::once((x, y)), grid_size, ring_count, true)?;
+    record_map_write(&ctx.accounts.map_digest, epoch, parcel_id, Rect::new(x, y, 1, 1))?;
+
+    let raffle = &mut ctx.accounts.raffle;
+    raffle.active = false;
+    raffle.winner = ctx.accounts.claimer.key();
+
+    msg!(
+        "Raffle round {} won by {}: parcel {} at ({}, {})",
+        raffle.round,
+        raffle.winner,
+        parcel_id,
+        x,
+        y
+    );
+    Ok(())
+}
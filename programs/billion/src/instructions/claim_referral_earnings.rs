@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022,
+    token_interface::{Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface},
+    associated_token::AssociatedToken,
+};
+use crate::state::{grid_seed, GridConfig, ReferrerAccount, REFERRAL_VAULT_SEED};
+use crate::errors::BillionError;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct ClaimReferralEarnings<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        mut,
+        seeds = [ReferrerAccount::SEED, &grid_seed(grid_id), referrer.key().as_ref()],
+        bump = referrer_account.bump,
+        has_one = referrer @ BillionError::Unauthorized,
+    )]
+    pub referrer_account: Account<'info, ReferrerAccount>,
+
+    /// Referral vault holding the accrued earnings
+    #[account(
+        mut,
+        seeds = [REFERRAL_VAULT_SEED, grid_config.key().as_ref()],
+        bump,
+        constraint = referral_vault.key() == grid_config.referral_vault @ BillionError::ReferralsDisabled
+    )]
+    pub referral_vault: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    /// Referrer's token account to receive earnings
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = referrer,
+        associated_token::token_program = token_program,
+    )]
+    pub referrer_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    #[account(
+        constraint = token_mint.key() == grid_config.token_mint @ BillionError::Unauthorized
+    )]
+    pub token_mint: InterfaceAccount<'info, InterfaceMint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handler(ctx: Context<ClaimReferralEarnings>, grid_id: u64) -> Result<()> {
+    let owed = ctx.accounts.referrer_account.accrued;
+    require!(owed > 0, BillionError::NothingToClaim);
+
+    ctx.accounts.referrer_account.accrued = 0;
+
+    // Transfer from vault to referrer (signed by GridConfig PDA)
+    let bump = ctx.accounts.grid_config.bump;
+    let grid_bytes = grid_seed(grid_id);
+    let seeds: &[&[u8]] = &[GridConfig::SEED, &grid_bytes, &[bump]];
+    let signer_seeds: &[&[&[u8]]] = &[seeds];
+
+    let cpi_accounts = token_2022::TransferChecked {
+        from: ctx.accounts.referral_vault.to_account_info(),
+        to: ctx.accounts.referrer_token_account.to_account_info(),
+        authority: ctx.accounts.grid_config.to_account_info(),
+        mint: ctx.accounts.token_mint.to_account_info(),
+    };
+    token_2022::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        ),
+        owed,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    msg!("Claimed {} referral tokens for {}", owed, ctx.accounts.referrer.key());
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{epoch_seed, grid_seed, GridConfig, ParcelInfo, SolRewardCheckpoint, SolRewards};
+use crate::constants::SOL_REWARD_VAULT_SEED;
+use crate::errors::BillionError;
+use crate::instructions::claim_land_buy_rewards::require_rewards_claimable;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16)]
+pub struct ClaimSolRewards<'info> {
+    #[account(mut)]
+    pub claimer: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+
+    /// The Metaplex Core asset - must match parcel_info.asset
+    /// CHECK: Validated by constraint, ownership checked in handler
+    #[account(
+        constraint = asset.key() == parcel_info.asset @ BillionError::AssetMismatch
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = claimer,
+        space = 8 + SolRewards::INIT_SPACE,
+        seeds = [SolRewards::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub sol_rewards: Box<Account<'info, SolRewards>>,
+
+    /// The parcel's enrollment, created by its first claim
+    #[account(
+        init_if_needed,
+        payer = claimer,
+        space = 8 + SolRewardCheckpoint::INIT_SPACE,
+        seeds = [SolRewardCheckpoint::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump
+    )]
+    pub sol_reward_checkpoint: Box<Account<'info, SolRewardCheckpoint>>,
+
+    /// System-owned vault paying the rewards
+    #[account(
+        mut,
+        seeds = [SOL_REWARD_VAULT_SEED, grid_config.key().as_ref()],
+        bump
+    )]
+    pub sol_reward_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Pays the lamports a parcel has earned from fund_sol_rewards deposits, checked like
+/// claim_land_buy_rewards. A parcel's first call enrolls it instead: its rewarded blocks join
+/// SolRewards.enrolled_blocks and it earns from later deposits only. The block count is fixed
+/// at enrollment, and the stream ignores epochs, so enrolled parcels keep earning after their
+/// epoch ends.
+pub fn handler(ctx: Context<ClaimSolRewards>, _grid_id: u64, parcel_id: u16) -> Result<()> {
+    let parcel_info = &ctx.accounts.parcel_info;
+    require_rewards_claimable(
+        parcel_info,
+        &ctx.accounts.asset.to_account_info(),
+        &ctx.accounts.claimer.key(),
+        &ctx.accounts.grid_config,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    let sol_rewards = &mut ctx.accounts.sol_rewards;
+    sol_rewards.bump = ctx.bumps.sol_rewards;
+    let checkpoint = &mut ctx.accounts.sol_reward_checkpoint;
+    if checkpoint.asset != parcel_info.asset {
+        // A new checkpoint, or one left by a closed parcel whose id was reused
+        let block_count = parcel_info.rewarded_block_count();
+        sol_rewards.enrolled_blocks = sol_rewards
+            .enrolled_blocks
+            .checked_sub(checkpoint.block_count)
+            .and_then(|blocks| blocks.checked_add(block_count))
+            .ok_or(BillionError::Overflow)?;
+        checkpoint.asset = parcel_info.asset;
+        checkpoint.block_count = block_count;
+        checkpoint.last_claimed_sol_rewards_per_block = sol_rewards.sol_rewards_per_block;
+        checkpoint.bump = ctx.bumps.sol_reward_checkpoint;
+        msg!("Enrolled parcel {} ({} blocks) in SOL rewards", parcel_id, block_count);
+        return Ok(());
+    }
+
+    let owed = checkpoint.pending(sol_rewards.sol_rewards_per_block).ok_or(BillionError::Overflow)?;
+    require!(owed > 0, BillionError::NothingToClaim);
+    checkpoint.last_claimed_sol_rewards_per_block = sol_rewards.sol_rewards_per_block;
+
+    let grid_config_key = ctx.accounts.grid_config.key();
+    let seeds: &[&[u8]] = &[SOL_REWARD_VAULT_SEED, grid_config_key.as_ref(), &[ctx.bumps.sol_reward_vault]];
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.sol_reward_vault.to_account_info(),
+                to: ctx.accounts.claimer.to_account_info(),
+            },
+            &[seeds],
+        ),
+        owed,
+    )?;
+
+    msg!("Claimed {} lamports for parcel {}", owed, parcel_id);
+    Ok(())
+}
@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022,
+    token_interface::{Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface},
+    associated_token::AssociatedToken,
+};
+use crate::state::{epoch_seed, grid_seed, GridConfig, ParcelInfo, StakeAccount, EMISSIONS_VAULT_SEED};
+use crate::errors::BillionError;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16)]
+pub struct ClaimStakingRewards<'info> {
+    #[account(mut)]
+    pub staker: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+
+    #[account(
+        mut,
+        seeds = [StakeAccount::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = stake_account.bump,
+        has_one = staker @ BillionError::NotOwner,
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    /// Emissions vault paying the rewards
+    #[account(
+        mut,
+        seeds = [EMISSIONS_VAULT_SEED, grid_config.key().as_ref()],
+        bump,
+        constraint = emissions_vault.key() == grid_config.emissions_vault @ BillionError::EmissionsDisabled
+    )]
+    pub emissions_vault: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    /// Staker's token account to receive emissions
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = staker,
+        associated_token::token_program = token_program,
+    )]
+    pub staker_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    #[account(
+        constraint = token_mint.key() == grid_config.token_mint @ BillionError::Unauthorized
+    )]
+    pub token_mint: InterfaceAccount<'info, InterfaceMint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Accounts needed to pay out emissions, shared by claim_staking_rewards and unstake_parcel
+pub struct EmissionAccounts<'a, 'info> {
+    /// Grid the GridConfig PDA was derived for, to sign as it
+    pub grid_id: u64,
+    pub grid_config: &'a Account<'info, GridConfig>,
+    pub stake_account: &'a mut Account<'info, StakeAccount>,
+    pub emissions_vault: &'a InterfaceAccount<'info, InterfaceTokenAccount>,
+    pub staker_token_account: &'a InterfaceAccount<'info, InterfaceTokenAccount>,
+    pub token_mint: &'a InterfaceAccount<'info, InterfaceMint>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+}
+
+/// Emissions earned by `block_count` blocks over `elapsed` seconds.
+/// Saturates at u64::MAX, which no vault could pay anyway.
+pub fn staking_rewards_owed(block_count: u32, rate_per_block_per_sec: u64, elapsed: i64) -> u64 {
+    let owed = (block_count as u128)
+        .saturating_mul(rate_per_block_per_sec as u128)
+        .saturating_mul(elapsed.max(0) as u128);
+    u64::try_from(owed).unwrap_or(u64::MAX)
+}
+
+/// Pay emissions accrued since the last claim, capped at the vault balance.
+/// The checkpoint always advances, so an empty vault stops emissions instead of building debt.
+pub(crate) fn settle_staking_rewards(accounts: EmissionAccounts, block_count: u32) -> Result<u64> {
+    let EmissionAccounts {
+        grid_id,
+        grid_config,
+        stake_account,
+        emissions_vault,
+        staker_token_account,
+        token_mint,
+        token_program,
+    } = accounts;
+
+    let now = Clock::get()?.unix_timestamp;
+    let owed = staking_rewards_owed(
+        block_count,
+        grid_config.emission_rate_per_block_per_sec,
+        now - stake_account.last_claimed_at,
+    );
+    let paid = owed.min(emissions_vault.amount);
+
+    stake_account.last_claimed_at = now;
+    stake_account.total_claimed = stake_account
+        .total_claimed
+        .checked_add(paid)
+        .ok_or(BillionError::Overflow)?;
+
+    if paid > 0 {
+        // Transfer from vault to staker (signed by GridConfig PDA)
+        let bump = grid_config.bump;
+        let grid_bytes = grid_seed(grid_id);
+        let seeds: &[&[u8]] = &[GridConfig::SEED, &grid_bytes, &[bump]];
+        let signer_seeds: &[&[&[u8]]] = &[seeds];
+
+        let cpi_accounts = token_2022::TransferChecked {
+            from: emissions_vault.to_account_info(),
+            to: staker_token_account.to_account_info(),
+            authority: grid_config.to_account_info(),
+            mint: token_mint.to_account_info(),
+        };
+        token_2022::transfer_checked(
+            CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer_seeds),
+            paid,
+            token_mint.decimals,
+        )?;
+    }
+
+    if paid < owed {
+        msg!("Emissions vault short: paid {} of {} owed", paid, owed);
+    }
+
+    Ok(paid)
+}
+
+pub fn handler(ctx: Context<ClaimStakingRewards>, grid_id: u64, parcel_id: u16) -> Result<()> {
+    let block_count = ctx.accounts.parcel_info.block_count();
+    let paid = settle_staking_rewards(
+        EmissionAccounts {
+            grid_id,
+            grid_config: &ctx.accounts.grid_config,
+            stake_account: &mut ctx.accounts.stake_account,
+            emissions_vault: &ctx.accounts.emissions_vault,
+            staker_token_account: &ctx.accounts.staker_token_account,
+            token_mint: &ctx.accounts.token_mint,
+            token_program: &ctx.accounts.token_program,
+        },
+        block_count,
+    )?;
+
+    msg!("Claimed {} staking tokens for parcel {}", paid, parcel_id);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_staking_rewards_owed() {
+        assert_eq!(staking_rewards_owed(4, 10, 60), 2_400);
+        assert_eq!(staking_rewards_owed(4, 0, 60), 0);
+        // Clock going backwards pays nothing rather than failing
+        assert_eq!(staking_rewards_owed(4, 10, -5), 0);
+        assert_eq!(staking_rewards_owed(10_000, u64::MAX, i64::MAX), u64::MAX);
+    }
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::{epoch_seed, grid_seed, Attestation};
+use crate::errors::BillionError;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct CloseAttestation<'info> {
+    /// Owner recorded in the attestation; receives the rent
+    /// CHECK: Only credited, and must match attestation.owner
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            Attestation::SEED,
+            &grid_seed(grid_id),
+            &epoch_seed(attestation.epoch),
+            &attestation.parcel_id.to_le_bytes(),
+            owner.key().as_ref(),
+        ],
+        bump = attestation.bump,
+        has_one = owner @ BillionError::Unauthorized,
+    )]
+    pub attestation: Account<'info, Attestation>,
+}
+
+/// Permissionless once the attestation is ATTESTATION_TTL_SECS old; the rent goes back to
+/// the attested owner
+pub fn handler(ctx: Context<CloseAttestation>, _grid_id: u64) -> Result<()> {
+    let attestation = &ctx.accounts.attestation;
+    require!(
+        attestation.is_expired(Clock::get()?.unix_timestamp),
+        BillionError::AttestationNotExpired
+    );
+
+    msg!("Closed attestation of parcel {} for {}", attestation.parcel_id, attestation.owner);
+    Ok(())
+}
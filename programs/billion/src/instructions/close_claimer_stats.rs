@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::errors::BillionError;
+use crate::state::{grid_seed, ClaimerStats, GridConfig};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct CloseClaimerStats<'info> {
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    #[account(
+        mut,
+        close = wallet,
+        seeds = [ClaimerStats::SEED, &grid_seed(grid_id), wallet.key().as_ref()],
+        bump = claimer_stats.bump,
+        has_one = wallet @ BillionError::Unauthorized,
+    )]
+    pub claimer_stats: Account<'info, ClaimerStats>,
+
+    /// Closing is blocked while max_blocks_per_wallet is set, so the count can't be reset
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump,
+        constraint = grid_config.max_blocks_per_wallet == 0 @ BillionError::WalletLimitActive
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+}
+
+/// Refunds the stats account's rent to its wallet, typically once it has sold every parcel.
+/// The program can't see secondary sales, so that call is the wallet's; a later claim or
+/// reward claim starts a fresh account.
+pub fn handler(ctx: Context<CloseClaimerStats>, _grid_id: u64) -> Result<()> {
+    let stats = &ctx.accounts.claimer_stats;
+    msg!(
+        "Closed stats for {}: {} parcels, {} spent",
+        stats.wallet,
+        stats.parcels_claimed,
+        stats.lifetime_spent
+    );
+    Ok(())
+}
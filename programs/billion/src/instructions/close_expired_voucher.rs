@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::{grid_seed, GridConfig, Voucher};
+use crate::errors::BillionError;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct CloseExpiredVoucher<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == grid_config.authority @ BillionError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [Voucher::SEED, &grid_seed(grid_id), voucher.beneficiary.as_ref(), &voucher.voucher_id.to_le_bytes()],
+        bump = voucher.bump
+    )]
+    pub voucher: Account<'info, Voucher>,
+}
+
+/// Returns an unredeemed voucher's rent to the authority once it has expired
+pub fn handler(ctx: Context<CloseExpiredVoucher>, _grid_id: u64) -> Result<()> {
+    let voucher = &ctx.accounts.voucher;
+    require!(
+        voucher.is_expired(Clock::get()?.unix_timestamp),
+        BillionError::VoucherNotExpired
+    );
+
+    msg!("Closed expired voucher {} for {}", voucher.voucher_id, voucher.beneficiary);
+    Ok(())
+}
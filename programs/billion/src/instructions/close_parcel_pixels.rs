@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::state::{epoch_seed, grid_seed, GridConfig, ParcelInfo, ParcelPixels};
+use crate::errors::BillionError;
+use crate::events::{ParcelPixelsUpdated, EVENT_SCHEMA_VERSION};
+use crate::instructions::claim_land_buy_rewards::require_parcel_owner;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16)]
+pub struct CloseParcelPixels<'info> {
+    /// Must own the parcel's asset; receives the rent
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+
+    /// The Metaplex Core asset - must match parcel_info.asset
+    /// CHECK: Validated by constraint, ownership checked in handler
+    #[account(
+        constraint = asset.key() == parcel_info.asset @ BillionError::AssetMismatch
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [ParcelPixels::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_pixels.bump
+    )]
+    pub parcel_pixels: Account<'info, ParcelPixels>,
+}
+
+/// Deletes a parcel's pixels and refunds the rent to its current owner, whoever paid it.
+/// Works for archived parcels too, so their rent isn't stranded.
+pub fn handler(ctx: Context<CloseParcelPixels>, _grid_id: u64, parcel_id: u16) -> Result<()> {
+    let parcel_info = &ctx.accounts.parcel_info;
+    let owner = ctx.accounts.owner.key();
+    require_parcel_owner(parcel_info, &ctx.accounts.asset.to_account_info(), &owner, &ctx.accounts.grid_config)?;
+
+    emit!(ParcelPixelsUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        parcel_id,
+        epoch: parcel_info.epoch,
+        owner,
+        cleared: true,
+    });
+
+    msg!("Parcel {} pixels cleared", parcel_id);
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::state::{epoch_seed, grid_seed, ParcelInfo, SolRewardCheckpoint, SolRewards};
+use crate::errors::BillionError;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, epoch: u16, parcel_id: u16)]
+pub struct CloseSolRewardCheckpoint<'info> {
+    /// Anyone can crank; receives the checkpoint's rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SolRewards::SEED, &grid_seed(grid_id)],
+        bump = sol_rewards.bump
+    )]
+    pub sol_rewards: Account<'info, SolRewards>,
+
+    #[account(
+        mut,
+        seeds = [SolRewardCheckpoint::SEED, &grid_seed(grid_id), &epoch_seed(epoch), &parcel_id.to_le_bytes()],
+        bump = sol_reward_checkpoint.bump,
+        close = payer,
+    )]
+    pub sol_reward_checkpoint: Account<'info, SolRewardCheckpoint>,
+
+    /// Must be closed, or hold a different parcel that reused the id
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(epoch), &parcel_id.to_le_bytes()], bump)]
+    pub parcel_info: UncheckedAccount<'info>,
+}
+
+/// Drops the SOL reward enrollment of a parcel that has left the grid (burned or closed), so
+/// later deposits are no longer split over its blocks. Lamports it hadn't claimed stay in the
+/// vault.
+pub fn handler(ctx: Context<CloseSolRewardCheckpoint>, _grid_id: u64, epoch: u16, parcel_id: u16) -> Result<()> {
+    let checkpoint = &ctx.accounts.sol_reward_checkpoint;
+    let parcel_info = &ctx.accounts.parcel_info;
+    if !parcel_info.data_is_empty() {
+        let parcel_info = ParcelInfo::try_deserialize(&mut &parcel_info.try_borrow_data()?[..])?;
+        require!(parcel_info.asset != checkpoint.asset, BillionError::ParcelStillOpen);
+    }
+
+    let sol_rewards = &mut ctx.accounts.sol_rewards;
+    sol_rewards.enrolled_blocks = sol_rewards
+        .enrolled_blocks
+        .checked_sub(checkpoint.block_count)
+        .ok_or(BillionError::Overflow)?;
+    msg!("Closed SOL reward checkpoint of parcel {} in epoch {}", parcel_id, epoch);
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use billion_core::{Rect, MAX_GRID_SIZE};
+
+use crate::errors::BillionError;
+use crate::state::{grid_seed, GridConfig, HarbergerDistrict};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct ConfigureHarbergerDistrict<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump,
+        has_one = authority @ BillionError::Unauthorized,
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// Created on first configuration, updated in place afterwards
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + HarbergerDistrict::INIT_SPACE,
+        seeds = [HarbergerDistrict::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub harberger_district: Account<'info, HarbergerDistrict>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Moving or shrinking the district leaves existing valuations in place, but parcels no
+/// longer fully inside it can't pay tax or be force-bought
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
+    ctx: Context<ConfigureHarbergerDistrict>,
+    _grid_id: u64,
+    x: u8,
+    y: u8,
+    width: u8,
+    height: u8,
+    tax_bps: u16,
+    tax_period_secs: i64,
+    enabled: bool,
+) -> Result<()> {
+    // No BlockMap is passed, so the region is bounded by the largest grid; claims inside it
+    // are still bounds-checked against their own grid
+    require!(Rect::new(x, y, width, height).in_bounds(MAX_GRID_SIZE), BillionError::InvalidDimensions);
+    require!(
+        tax_bps <= 10_000 && tax_period_secs > 0,
+        BillionError::InvalidTaxRate
+    );
+
+    let district = &mut ctx.accounts.harberger_district;
+    district.x = x;
+    district.y = y;
+    district.width = width;
+    district.height = height;
+    district.enabled = enabled;
+    district.tax_bps = tax_bps;
+    district.tax_period_secs = tax_period_secs;
+    district.bump = ctx.bumps.harberger_district;
+
+    msg!(
+        "Harberger district {}x{} at ({}, {}): {} bps per {}s, enabled={}",
+        width, height, x, y, tax_bps, tax_period_secs, enabled
+    );
+    Ok(())
+}
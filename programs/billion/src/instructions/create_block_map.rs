@@ -1,12 +1,17 @@
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::errors::BillionError;
 use crate::state::BlockMap;
 
 /// CreateBlockMap uses the `zero` constraint because BlockMap (~20KB) exceeds
 /// Solana's 10KB limit for account creation in CPI (inner instructions).
 ///
 /// The client must pre-create the account with:
-/// 1. SystemProgram.createAccount (with program as owner, correct size)
-/// 2. Then call this instruction to initialize it
+/// 1. SystemProgram.createAccount (with program as owner, BlockMap::size(grid_size) bytes)
+/// 2. Then call this instruction with the same grid_size to initialize it
+///
+/// A BlockMap closed by admin_purge belongs to the system program again, so it can't be
+/// initialized here a second time.
 #[derive(Accounts)]
 pub struct CreateBlockMap<'info> {
     #[account(mut)]
@@ -14,17 +19,29 @@ pub struct CreateBlockMap<'info> {
 
     /// The BlockMap account must be pre-created by the client with:
     /// - owner = program ID
-    /// - space = BlockMap::SIZE (20016 bytes)
+    /// - space = BlockMap::size(grid_size) (BlockMap::SIZE, 20016 bytes, for the default 100x100)
     /// - data = all zeros
     #[account(zero)]
     pub block_map: AccountLoader<'info, BlockMap>,
 }
 
-pub fn handler(ctx: Context<CreateBlockMap>) -> Result<()> {
-    // Initialize the account (this marks the discriminator)
-    let _block_map = ctx.accounts.block_map.load_init()?;
+/// Initializes a `grid_size` x `grid_size` BlockMap. The size is recorded in the trailer and
+/// is the grid size every instruction reads for this epoch. The map belongs to no grid until
+/// initialize or start_new_epoch binds it.
+pub fn handler(ctx: Context<CreateBlockMap>, grid_size: u16) -> Result<()> {
+    require!(billion_core::grid_size_is_valid(grid_size), BillionError::InvalidGridSize);
+    let account = ctx.accounts.block_map.as_ref();
+    let mut data = account.try_borrow_mut_data()?;
+    require!(data.len() == BlockMap::size(grid_size), BillionError::InvalidGridSize);
+
+    // load_init would expect a full 100x100 map, so mark the discriminator and size directly.
     // blocks array is already zeroed from account creation
     // bump is not needed since this is not a PDA
-    msg!("BlockMap initialized at {}", ctx.accounts.block_map.key());
+    data[..8].copy_from_slice(&BlockMap::DISCRIMINATOR);
+    let trailer = data.len() - BlockMap::TRAILER_LEN;
+    data[trailer + 2..trailer + 4].copy_from_slice(&grid_size.to_le_bytes());
+    // Unbound until a grid takes it in initialize or start_new_epoch
+    data[trailer + 4] = 1;
+    msg!("BlockMap initialized at {} for a {}x{} grid", account.key(), grid_size, grid_size);
     Ok(())
 }
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use mpl_core::instructions::CreateCollectionV2CpiBuilder;
+use crate::state::{grid_seed, AdminRoles, GridConfig, ConfigFreeze};
+use crate::errors::BillionError;
+use crate::events::CONFIG_FIELD_COLLECTION;
+use crate::instructions::update_config::ConfigSnapshot;
+#[cfg(not(feature = "localnet"))]
+use crate::instructions::claim_parcel::MPL_CORE_ID;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct CreateCollection<'info> {
+    /// The config authority, paying for the collection
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// Names the config authority; the main authority holds every role until it's created
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [AdminRoles::SEED, &grid_seed(grid_id)], bump)]
+    pub admin_roles: UncheckedAccount<'info>,
+
+    /// New collection keypair
+    #[account(mut)]
+    pub collection: Signer<'info>,
+
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Must still be empty: freeze_config creating it disables this instruction for good
+    /// CHECK: Seeds are verified here, only whether it exists is read
+    #[account(
+        seeds = [ConfigFreeze::SEED, &grid_seed(grid_id)],
+        bump,
+        constraint = !ConfigFreeze::is_frozen(&config_freeze) @ BillionError::ConfigFrozen
+    )]
+    pub config_freeze: UncheckedAccount<'info>,
+}
+
+/// Creates the Core collection with the GridConfig PDA as its update authority and points
+/// the grid at it in the same instruction, so claims can never be wired to a collection the
+/// program can't mint into. Once parcels are minted the collection only changes through
+/// update_config with force_collection.
+pub fn handler(ctx: Context<CreateCollection>, _grid_id: u64, name: String, uri: String) -> Result<()> {
+    let roles = AdminRoles::load(&ctx.accounts.admin_roles, &ctx.accounts.grid_config)?;
+    require_keys_eq!(roles.config_authority, ctx.accounts.authority.key(), BillionError::Unauthorized);
+    require!(!ctx.accounts.grid_config.collection_locked(), BillionError::CollectionLocked);
+
+    let mpl_core_program = ctx.accounts.mpl_core_program.to_account_info();
+    let collection = ctx.accounts.collection.to_account_info();
+    let grid_config_info = ctx.accounts.grid_config.to_account_info();
+    let authority = ctx.accounts.authority.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    CreateCollectionV2CpiBuilder::new(&mpl_core_program)
+        .collection(&collection)
+        .update_authority(Some(&grid_config_info))
+        .payer(&authority)
+        .system_program(&system_program)
+        .name(name)
+        .uri(uri)
+        .invoke()?;
+
+    let config = &mut ctx.accounts.grid_config;
+    let before = ConfigSnapshot::of(config);
+    config.set_collection(collection.key()).ok_or(BillionError::Overflow)?;
+    emit!(before.updated(config, authority.key(), CONFIG_FIELD_COLLECTION));
+
+    msg!("Created collection {} (generation {})", collection.key(), config.collection_generation);
+    Ok(())
+}
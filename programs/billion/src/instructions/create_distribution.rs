@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022,
+    token_interface::{Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface},
+};
+use crate::state::{grid_seed, Distribution, GridConfig};
+use crate::errors::BillionError;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct CreateDistribution<'info> {
+    #[account(mut)]
+    pub distributor: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// New distribution - must be a signer (keypair generated client-side)
+    #[account(
+        init,
+        payer = distributor,
+        space = Distribution::space(grid_config.next_parcel_id),
+    )]
+    pub distribution: Account<'info, Distribution>,
+
+    /// Token being airdropped
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = distributor,
+        token::token_program = token_program,
+    )]
+    pub distributor_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    /// Vault holding the airdrop until claimed or swept
+    #[account(
+        init,
+        payer = distributor,
+        seeds = [Distribution::VAULT_SEED, distribution.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = grid_config,
+        token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Snapshots the grid as it is now: parcels claimed later get nothing
+pub fn handler(ctx: Context<CreateDistribution>, _grid_id: u64, amount: u64, expires_at: i64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(expires_at > now, BillionError::DistributionExpired);
+    require!(amount > 0, BillionError::NothingToClaim);
+
+    let grid_config = &ctx.accounts.grid_config;
+    require!(grid_config.total_claimed_blocks > 0, BillionError::NothingToClaim);
+    let per_block = Distribution::per_block_for(amount, grid_config.total_claimed_blocks)
+        .ok_or(BillionError::Overflow)?;
+
+    token_2022::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_2022::TransferChecked {
+                from: ctx.accounts.distributor_token_account.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                authority: ctx.accounts.distributor.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    let next_parcel_id = grid_config.next_parcel_id;
+    let distribution = &mut ctx.accounts.distribution;
+    distribution.distributor = ctx.accounts.distributor.key();
+    distribution.mint = ctx.accounts.mint.key();
+    distribution.amount = amount;
+    distribution.per_block = per_block;
+    distribution.epoch = ctx.accounts.grid_config.epoch;
+    distribution.snapshot_next_parcel_id = next_parcel_id;
+    distribution.created_at = now;
+    distribution.expires_at = expires_at;
+    distribution.claimed_amount = 0;
+    distribution.claim_count = 0;
+    distribution.vault_bump = ctx.bumps.vault;
+    distribution.claimed = vec![0u8; Distribution::bitmap_len(next_parcel_id)];
+
+    msg!(
+        "Distribution {} of {} across {} blocks, expires at {}",
+        distribution.key(), amount, ctx.accounts.grid_config.total_claimed_blocks, expires_at
+    );
+    Ok(())
+}
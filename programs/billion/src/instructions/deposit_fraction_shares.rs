@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022;
+use crate::errors::BillionError;
+use crate::instructions::claim_fraction_rewards::FractionHolder;
+
+/// Escrows `amount` shares so they earn the parcel's land-buy rewards from now on
+pub fn handler(ctx: Context<FractionHolder>, grid_id: u64, parcel_id: u16, amount: u64) -> Result<()> {
+    require!(amount > 0, BillionError::InvalidShareAmount);
+    ctx.accounts.harvest_and_settle(grid_id, ctx.bumps.position)?;
+
+    token_2022::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_2022::TransferChecked {
+                from: ctx.accounts.holder_share_account.to_account_info(),
+                to: ctx.accounts.share_escrow.to_account_info(),
+                authority: ctx.accounts.holder.to_account_info(),
+                mint: ctx.accounts.share_mint.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.share_mint.decimals,
+    )?;
+
+    let position = &mut ctx.accounts.position;
+    position.deposited = position.deposited.checked_add(amount).ok_or(BillionError::Overflow)?;
+    let fraction = &mut ctx.accounts.fraction;
+    fraction.total_deposited = fraction.total_deposited.checked_add(amount).ok_or(BillionError::Overflow)?;
+
+    msg!("Deposited {} shares of parcel {}, {} total", amount, parcel_id, position.deposited);
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BillionError;
+use crate::state::{grid_seed, GridConfig, HookConfig};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct DisableHook<'info> {
+    /// Authority or hook guardian
+    #[account(
+        constraint = signer.key() == grid_config.authority
+            || signer.key() == hook_config.guardian @ BillionError::Unauthorized
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump,
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        mut,
+        seeds = [HookConfig::SEED, &grid_seed(grid_id)],
+        bump = hook_config.bump,
+    )]
+    pub hook_config: Account<'info, HookConfig>,
+}
+
+/// Kill switch: stops claims from invoking the hook, effective from the next claim.
+/// Only the authority can re-enable it via set_hook.
+pub fn handler(ctx: Context<DisableHook>, _grid_id: u64) -> Result<()> {
+    ctx.accounts.hook_config.enabled = false;
+    msg!("Claim hook {} disabled by {}", ctx.accounts.hook_config.program_id, ctx.accounts.signer.key());
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    grid_seed, AdminRoles, ConfigTimelock, GridConfig, TimelockedConfig, TokenTreasury, ConfigFreeze, ReferralShare,
+    RingPricing, RingUnlockSchedule,
+};
+use crate::errors::BillionError;
+use crate::events::{
+    CONFIG_FIELD_CONFIG_TIMELOCK, CONFIG_FIELD_CURVE_DIVISOR, CONFIG_FIELD_LAND_OWNERS_REWARD_SHARE,
+    CONFIG_FIELD_PRICE_PER_BLOCK, CONFIG_FIELD_RING_PRICE_MULTIPLIERS, CONFIG_FIELD_RING_THRESHOLDS,
+};
+use crate::instructions::update_config::ConfigSnapshot;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct ExecuteConfigUpdate<'info> {
+    /// The config authority; pays for RingPricing if no update_config has created it
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// Names the config authority; the main authority holds every role until it's created
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [AdminRoles::SEED, &grid_seed(grid_id)], bump)]
+    pub admin_roles: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [ConfigTimelock::SEED, &grid_seed(grid_id)],
+        bump = config_timelock.bump
+    )]
+    pub config_timelock: Account<'info, ConfigTimelock>,
+
+    /// Treasury share the landowner share must leave room for, with the referral and charity shares
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [TokenTreasury::SEED, &grid_seed(grid_id)], bump)]
+    pub token_treasury: UncheckedAccount<'info>,
+
+    /// Direct referral share the landowner share must leave room for
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [ReferralShare::SEED, &grid_seed(grid_id)], bump)]
+    pub referral_share: UncheckedAccount<'info>,
+
+    /// Created with flat multipliers if no update_config has created it
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + RingPricing::INIT_SPACE,
+        seeds = [RingPricing::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub ring_pricing: Account<'info, RingPricing>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Times rings open whatever has been burned; rings unlock by burns alone while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [RingUnlockSchedule::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_unlock_schedule: UncheckedAccount<'info>,
+
+    /// Must still be empty: freeze_config creating it disables this instruction for good
+    /// CHECK: Seeds are verified here, only whether it exists is read
+    #[account(
+        seeds = [ConfigFreeze::SEED, &grid_seed(grid_id)],
+        bump,
+        constraint = !ConfigFreeze::is_frozen(&config_freeze) @ BillionError::ConfigFrozen
+    )]
+    pub config_freeze: UncheckedAccount<'info>,
+}
+
+/// Applies the queued change, resubmitted in full, once its timelock has passed
+pub fn handler(ctx: Context<ExecuteConfigUpdate>, _grid_id: u64, change: TimelockedConfig) -> Result<()> {
+    let roles = AdminRoles::load(&ctx.accounts.admin_roles, &ctx.accounts.grid_config)?;
+    require_keys_eq!(roles.config_authority, ctx.accounts.authority.key(), BillionError::Unauthorized);
+
+    let now = Clock::get()?.unix_timestamp;
+    let timelock = &mut ctx.accounts.config_timelock;
+    timelock.take(&change, now)?;
+
+    let authority = ctx.accounts.authority.key();
+    let schedule = RingUnlockSchedule::load(&ctx.accounts.ring_unlock_schedule)?;
+    let config = &mut ctx.accounts.grid_config;
+    let previously_unlocked = config.unlocked_ring_at(&schedule, now);
+    let before = ConfigSnapshot::of(config);
+    let mut changed_fields = 0;
+    if let Some(price) = change.price_per_block {
+        changed_fields |= CONFIG_FIELD_PRICE_PER_BLOCK;
+        config.price_per_block = price;
+        msg!("Updated price_per_block to {}", price);
+    }
+    if let Some(thresholds) = change.ring_thresholds {
+        changed_fields |= CONFIG_FIELD_RING_THRESHOLDS;
+        config.ring_thresholds = thresholds;
+        msg!("Updated ring_thresholds");
+    }
+    if let Some(bps) = change.land_owners_reward_share_bps {
+        changed_fields |= CONFIG_FIELD_LAND_OWNERS_REWARD_SHARE;
+        // The other shares may have grown since the change was queued
+        let treasury_bps = TokenTreasury::load(&ctx.accounts.token_treasury)?.share_bps;
+        let referral_bps = ReferralShare::load(&ctx.accounts.referral_share)?.with_referral_bps(config.referral_bps);
+        require!(
+            GridConfig::valid_split_bps(bps, treasury_bps, referral_bps, config.max_charity_bps),
+            BillionError::InvalidRewardShare
+        );
+        config.land_owners_reward_share_bps = bps;
+        msg!("Updated land_owners_reward_share_bps to {}", bps);
+    }
+    if let Some(secs) = change.config_timelock_secs {
+        changed_fields |= CONFIG_FIELD_CONFIG_TIMELOCK;
+        timelock.timelock_secs = secs;
+        msg!("Updated config_timelock_secs to {}", secs);
+    }
+
+    // A just-created account is all zeros, which no valid configuration is
+    let ring_pricing = &mut ctx.accounts.ring_pricing;
+    if ring_pricing.multipliers_bps.iter().all(|&bps| bps == 0) {
+        **ring_pricing = RingPricing::flat(ctx.bumps.ring_pricing);
+    }
+    if let Some(multipliers) = change.ring_price_multipliers_bps {
+        changed_fields |= CONFIG_FIELD_RING_PRICE_MULTIPLIERS;
+        require!(
+            RingPricing::valid_multipliers(&multipliers, config.ring_count()),
+            BillionError::InvalidRingMultipliers
+        );
+        ring_pricing.set_multipliers(&multipliers);
+        msg!("Updated ring_price_multipliers_bps");
+    }
+    if let Some(divisor) = change.curve_divisor {
+        changed_fields |= CONFIG_FIELD_CURVE_DIVISOR;
+        ring_pricing.curve_divisor = divisor;
+        msg!("Updated curve_divisor to {}", divisor);
+    }
+
+    // Lowered thresholds can unlock rings, as in update_config
+    let unlocked = config.unlocked_ring_at(&schedule, now);
+    config.record_ring_unlock(previously_unlocked, unlocked, now);
+
+    emit!(before.updated(config, authority, changed_fields));
+    Ok(())
+}
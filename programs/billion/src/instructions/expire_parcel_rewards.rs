@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022,
+    token_interface::{Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface},
+    associated_token::AssociatedToken,
+};
+use crate::state::{
+    epoch_seed, grid_seed, GridConfig, ParcelInfo, RewardDust, RingRewardWeights, LAND_BUY_REWARD_POOL_SEED,
+};
+use crate::errors::BillionError;
+use crate::instructions::claim_land_buy_rewards::pending_land_buy_rewards;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16)]
+pub struct ExpireParcelRewards<'info> {
+    /// Anyone; receives the bounty
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Box<Account<'info, GridConfig>>,
+
+    #[account(
+        mut,
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Box<Account<'info, ParcelInfo>>,
+
+    /// Land buy reward pool - pays the bounty, keeps the lapsed rewards
+    #[account(
+        mut,
+        seeds = [LAND_BUY_REWARD_POOL_SEED, grid_config.key().as_ref()],
+        bump,
+        constraint = land_buy_reward_pool.key() == grid_config.land_buy_reward_pool @ BillionError::InvalidRewardPool
+    )]
+    pub land_buy_reward_pool: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        associated_token::mint = token_mint,
+        associated_token::authority = cranker,
+        associated_token::token_program = token_program,
+    )]
+    pub cranker_token_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    #[account(
+        constraint = token_mint.key() == grid_config.token_mint @ BillionError::Unauthorized
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, InterfaceMint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    /// Per-ring land-buy reward weights the lapsed rewards are spread by; every block is unweighted while
+    /// uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [RingRewardWeights::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_reward_weights: UncheckedAccount<'info>,
+
+    /// Rounding the redistribution leaves behind, created on first touch
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        space = 8 + RewardDust::INIT_SPACE,
+        seeds = [RewardDust::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub reward_dust: Box<Account<'info, RewardDust>>,
+}
+
+/// Lapses the pending land-buy rewards of a parcel that hasn't claimed within
+/// reward_expiry_seconds. The cranker gets expiry_bounty_bps of them and the rest is spread
+/// over every other claimed block through the accumulator. Only a claim restarts the
+/// parcel's clock, so rewards keep lapsing until its owner claims again.
+pub fn handler(ctx: Context<ExpireParcelRewards>, grid_id: u64, parcel_id: u16) -> Result<()> {
+    let weights = RingRewardWeights::load(&ctx.accounts.ring_reward_weights)?;
+    let grid_config = &mut ctx.accounts.grid_config;
+    let parcel_info = &mut ctx.accounts.parcel_info;
+    require!(grid_config.reward_expiry_seconds > 0, BillionError::RewardExpiryDisabled);
+    require!(parcel_info.epoch == grid_config.epoch, BillionError::ParcelArchived);
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        grid_config.rewards_expired(parcel_info.expiry_clock_start(), now),
+        BillionError::RewardsNotExpired
+    );
+
+    let owed = pending_land_buy_rewards(parcel_info, grid_config.land_buy_rewards_per_block)?;
+    require!(owed > 0, BillionError::NothingToClaim);
+    let bounty = u64::try_from((owed as u128) * (grid_config.expiry_bounty_bps as u128) / 10_000)
+        .map_err(|_| BillionError::Overflow)?;
+    let lapsed = owed - bounty;
+
+    grid_config.pay_land_buy_rewards(owed);
+    let other_units = weights
+        .reward_units(grid_config.epoch, grid_config.total_claimed_blocks)
+        .and_then(|units| units.checked_sub(parcel_info.rewarded_weight() as u128))
+        .ok_or(BillionError::Overflow)?;
+    // With no other landowners the lapsed rewards simply stay in the pool as surplus
+    if other_units > 0 && lapsed > 0 {
+        let increment = RingRewardWeights::reward_increase(lapsed, other_units).ok_or(BillionError::Overflow)?;
+        grid_config.land_buy_rewards_per_block = grid_config
+            .land_buy_rewards_per_block
+            .checked_add(increment)
+            .ok_or(BillionError::Overflow)?;
+        grid_config.credit_land_buy_rewards(lapsed).ok_or(BillionError::Overflow)?;
+        let reward_dust = &mut ctx.accounts.reward_dust;
+        reward_dust.bump = ctx.bumps.reward_dust;
+        reward_dust.record(lapsed, increment, other_units).ok_or(BillionError::Overflow)?;
+    }
+    // Skip past the redistribution so the parcel doesn't earn back its own lapsed rewards
+    parcel_info.last_claimed_land_buy_rewards_per_block = grid_config.land_buy_rewards_per_block;
+
+    if bounty > 0 {
+        let bump = grid_config.bump;
+        token_2022::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_2022::TransferChecked {
+                    from: ctx.accounts.land_buy_reward_pool.to_account_info(),
+                    to: ctx.accounts.cranker_token_account.to_account_info(),
+                    authority: ctx.accounts.grid_config.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                },
+                &[&[GridConfig::SEED, &grid_seed(grid_id), &[bump]]],
+            ),
+            bounty,
+            ctx.accounts.token_mint.decimals,
+        )?;
+    }
+
+    msg!(
+        "Expired {} rewards of parcel {}: {} redistributed, {} bounty",
+        owed,
+        parcel_id,
+        lapsed,
+        bounty
+    );
+    Ok(())
+}
@@ -0,0 +1,207 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022,
+    token_interface::{Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface},
+    associated_token::AssociatedToken,
+};
+use billion_core::Rect;
+use mpl_core::instructions::TransferV1CpiBuilder;
+use crate::state::{
+    epoch_seed, grid_seed, GridConfig, HarbergerDistrict, ParcelInfo, ParcelValuation, StakeAccount, LAND_BUY_REWARD_POOL_SEED,
+};
+use crate::errors::BillionError;
+use crate::instructions::claim_land_buy_rewards::{get_core_asset_owner, settle_rewards_to};
+#[cfg(not(feature = "localnet"))]
+use crate::instructions::claim_parcel::MPL_CORE_ID;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16)]
+pub struct ForceBuy<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        seeds = [HarbergerDistrict::SEED, &grid_seed(grid_id)],
+        bump = harberger_district.bump
+    )]
+    pub harberger_district: Account<'info, HarbergerDistrict>,
+
+    #[account(
+        mut,
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+
+    #[account(
+        mut,
+        seeds = [ParcelValuation::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_valuation.bump
+    )]
+    pub parcel_valuation: Account<'info, ParcelValuation>,
+
+    /// Stake PDA for the parcel - must not exist, a staked asset is frozen
+    /// CHECK: Only checked for emptiness
+    #[account(
+        seeds = [StakeAccount::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump
+    )]
+    pub stake_account: UncheckedAccount<'info>,
+
+    /// The Metaplex Core asset - must match parcel_info.asset
+    /// CHECK: Validated by constraint, ownership checked in handler
+    #[account(
+        mut,
+        constraint = asset.key() == parcel_info.asset @ BillionError::AssetMismatch
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    /// Core collection - must match grid_config.collection
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        mut,
+        constraint = collection.key() == grid_config.collection @ BillionError::InvalidCollection
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    /// Owner who declared the valuation
+    /// CHECK: Validated by constraint
+    #[account(
+        constraint = previous_owner.key() == parcel_valuation.owner @ BillionError::ValuationNotSet
+    )]
+    pub previous_owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = buyer,
+        associated_token::token_program = token_program,
+    )]
+    pub buyer_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = previous_owner,
+        associated_token::token_program = token_program,
+    )]
+    pub previous_owner_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    /// Pays the previous owner's pending land-buy rewards
+    #[account(
+        mut,
+        seeds = [LAND_BUY_REWARD_POOL_SEED, grid_config.key().as_ref()],
+        bump,
+        constraint = land_buy_reward_pool.key() == grid_config.land_buy_reward_pool @ BillionError::InvalidRewardPool
+    )]
+    pub land_buy_reward_pool: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    #[account(
+        constraint = token_mint.key() == grid_config.token_mint @ BillionError::Unauthorized
+    )]
+    pub token_mint: InterfaceAccount<'info, InterfaceMint>,
+
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ForceBuy>, grid_id: u64, parcel_id: u16) -> Result<()> {
+    let district = &ctx.accounts.harberger_district;
+    let parcel = &ctx.accounts.parcel_info;
+    require!(district.enabled, BillionError::HarbergerDisabled);
+    require!(parcel.epoch == ctx.accounts.grid_config.epoch, BillionError::ParcelArchived);
+    require!(
+        district.contains(Rect::new(parcel.x, parcel.y, parcel.width, parcel.height)),
+        BillionError::OutsideDistrict
+    );
+
+    // The valuation and delegate must both belong to whoever holds the asset right now
+    let owner = get_core_asset_owner(&ctx.accounts.asset.to_account_info())?;
+    let valuation = &ctx.accounts.parcel_valuation;
+    require!(
+        owner == valuation.owner
+            && valuation.delegate_approved_for == owner
+            && valuation.valuation > 0,
+        BillionError::ValuationNotSet
+    );
+    require!(ctx.accounts.buyer.key() != owner, BillionError::Unauthorized);
+    require!(ctx.accounts.stake_account.data_is_empty(), BillionError::ParcelStaked);
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(valuation.tax_paid_until > now, BillionError::TaxNotCurrent);
+
+    let price = valuation.valuation;
+    require!(
+        ctx.accounts.buyer_token_account.amount >= price,
+        BillionError::InsufficientBalance
+    );
+
+    token_2022::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_2022::TransferChecked {
+                from: ctx.accounts.buyer_token_account.to_account_info(),
+                to: ctx.accounts.previous_owner_token_account.to_account_info(),
+                authority: ctx.accounts.buyer.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+            },
+        ),
+        price,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    // Rewards accrued while the previous owner held the parcel stay with them
+    let settled = settle_rewards_to(
+        &mut ctx.accounts.parcel_info,
+        grid_id,
+        &mut ctx.accounts.grid_config,
+        None,
+        &ctx.accounts.land_buy_reward_pool,
+        &ctx.accounts.previous_owner_token_account,
+        &ctx.accounts.token_mint,
+        &ctx.accounts.token_program,
+    )?;
+
+    // Move the asset as the TransferDelegate authority (GridConfig PDA)
+    let bump = ctx.accounts.grid_config.bump;
+    let grid_bytes = grid_seed(grid_id);
+    let seeds: &[&[u8]] = &[GridConfig::SEED, &grid_bytes, &[bump]];
+    let signer_seeds: &[&[&[u8]]] = &[seeds];
+
+    TransferV1CpiBuilder::new(&ctx.accounts.mpl_core_program.to_account_info())
+        .asset(&ctx.accounts.asset.to_account_info())
+        .collection(Some(&ctx.accounts.collection.to_account_info()))
+        .payer(&ctx.accounts.buyer.to_account_info())
+        .authority(Some(&ctx.accounts.grid_config.to_account_info()))
+        .new_owner(&ctx.accounts.buyer.to_account_info())
+        .system_program(Some(&ctx.accounts.system_program.to_account_info()))
+        .invoke_signed(signer_seeds)?;
+
+    // Core returned the delegate to the new owner; they must set_valuation to re-enter the market
+    let valuation = &mut ctx.accounts.parcel_valuation;
+    valuation.owner = ctx.accounts.buyer.key();
+    valuation.valuation = 0;
+    valuation.delegate_approved_for = Pubkey::default();
+    valuation.tax_paid_until = now;
+
+    msg!(
+        "Parcel {} force-bought for {}, {} rewards settled to the previous owner",
+        parcel_id,
+        price,
+        settled
+    );
+    Ok(())
+}
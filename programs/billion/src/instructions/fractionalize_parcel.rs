@@ -0,0 +1,233 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::{self, spl_token_2022::instruction::AuthorityType},
+    token_interface::{Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface},
+    associated_token::AssociatedToken,
+};
+use mpl_core::instructions::TransferV1CpiBuilder;
+use crate::constants::FRACTION_SHARE_DECIMALS;
+use crate::state::{epoch_seed, grid_seed, Fraction, GridConfig, ParcelInfo, StakeAccount, LAND_BUY_REWARD_POOL_SEED};
+use crate::errors::BillionError;
+use crate::instructions::claim_land_buy_rewards::{get_core_asset_owner, settle_rewards_to};
+#[cfg(not(feature = "localnet"))]
+use crate::instructions::claim_parcel::MPL_CORE_ID;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16)]
+pub struct FractionalizeParcel<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        mut,
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+
+    /// Must be empty: staked parcels are frozen and can't be escrowed
+    /// CHECK: Only its emptiness is read
+    #[account(
+        seeds = [StakeAccount::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump
+    )]
+    pub stake_account: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + Fraction::INIT_SPACE,
+        seeds = [Fraction::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump
+    )]
+    pub fraction: Box<Account<'info, Fraction>>,
+
+    /// Share mint - a fresh keypair generated client-side
+    #[account(
+        init,
+        payer = owner,
+        mint::decimals = FRACTION_SHARE_DECIMALS,
+        mint::authority = fraction,
+        mint::token_program = token_program,
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, InterfaceMint>>,
+
+    /// Receives the entire share supply
+    #[account(
+        init,
+        payer = owner,
+        associated_token::mint = share_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_share_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    /// Holds shares deposited into positions
+    #[account(
+        init,
+        payer = owner,
+        seeds = [Fraction::ESCROW_SEED, share_mint.key().as_ref()],
+        bump,
+        token::mint = share_mint,
+        token::authority = fraction,
+        token::token_program = token_program,
+    )]
+    pub share_escrow: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    /// Receives the parcel's land-buy rewards for share holders
+    #[account(
+        init,
+        payer = owner,
+        seeds = [Fraction::VAULT_SEED, share_mint.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = fraction,
+        token::token_program = token_program,
+    )]
+    pub reward_vault: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    /// Land buy reward pool - pays out rewards accrued before fractionalization
+    #[account(
+        mut,
+        seeds = [LAND_BUY_REWARD_POOL_SEED, grid_config.key().as_ref()],
+        bump,
+        constraint = land_buy_reward_pool.key() == grid_config.land_buy_reward_pool @ BillionError::InvalidRewardPool
+    )]
+    pub land_buy_reward_pool: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_token_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    #[account(
+        constraint = token_mint.key() == grid_config.token_mint @ BillionError::Unauthorized
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, InterfaceMint>>,
+
+    /// The Metaplex Core asset - must match parcel_info.asset
+    /// CHECK: Validated by constraint, ownership checked in handler
+    #[account(
+        mut,
+        constraint = asset.key() == parcel_info.asset @ BillionError::AssetMismatch
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    /// Core collection - must match grid_config.collection
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        mut,
+        constraint = collection.key() == grid_config.collection @ BillionError::InvalidCollection
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Escrows the asset under the Fraction PDA and mints `share_supply` shares to the owner.
+/// Rewards accrued so far are paid to the owner; later ones go to deposited shares.
+pub fn handler(ctx: Context<FractionalizeParcel>, grid_id: u64, parcel_id: u16, share_supply: u64) -> Result<()> {
+    let owner = get_core_asset_owner(&ctx.accounts.asset.to_account_info())?;
+    require!(owner == ctx.accounts.owner.key(), BillionError::NotOwner);
+    require!(
+        ctx.accounts.parcel_info.epoch == ctx.accounts.grid_config.epoch,
+        BillionError::ParcelArchived
+    );
+    require!(ctx.accounts.stake_account.data_is_empty(), BillionError::ParcelStaked);
+    require!(share_supply > 0, BillionError::InvalidShareAmount);
+
+    // Settle the owner's pending land-buy rewards so the fraction starts from a clean checkpoint
+    settle_rewards_to(
+        &mut ctx.accounts.parcel_info,
+        grid_id,
+        &mut ctx.accounts.grid_config,
+        None,
+        &ctx.accounts.land_buy_reward_pool,
+        &ctx.accounts.owner_token_account,
+        &ctx.accounts.token_mint,
+        &ctx.accounts.token_program,
+    )?;
+
+    let epoch = ctx.accounts.parcel_info.epoch;
+    let fraction = &mut ctx.accounts.fraction;
+    fraction.parcel_id = parcel_id;
+    fraction.epoch = epoch;
+    fraction.asset = ctx.accounts.asset.key();
+    fraction.share_mint = ctx.accounts.share_mint.key();
+    fraction.share_supply = share_supply;
+    fraction.fractionalized_by = ctx.accounts.owner.key();
+    fraction.fractionalized_at = Clock::get()?.unix_timestamp;
+    fraction.total_deposited = 0;
+    fraction.rewards_per_share = 0;
+    fraction.undistributed = 0;
+    fraction.total_harvested = 0;
+    fraction.bump = ctx.bumps.fraction;
+    fraction._reserved = [0u8; 32];
+
+    let grid_bytes = grid_seed(grid_id);
+
+    let epoch_bytes = epoch_seed(epoch);
+    let parcel_id_bytes = parcel_id.to_le_bytes();
+    let fraction_seeds: &[&[u8]] = &[Fraction::SEED, &grid_bytes, &epoch_bytes, &parcel_id_bytes, &[ctx.bumps.fraction]];
+
+    // Mint the whole supply, then revoke the mint authority so it can never change
+    token_2022::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_2022::MintTo {
+                mint: ctx.accounts.share_mint.to_account_info(),
+                to: ctx.accounts.owner_share_account.to_account_info(),
+                authority: ctx.accounts.fraction.to_account_info(),
+            },
+            &[fraction_seeds],
+        ),
+        share_supply,
+    )?;
+    token_2022::set_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_2022::SetAuthority {
+                current_authority: ctx.accounts.fraction.to_account_info(),
+                account_or_mint: ctx.accounts.share_mint.to_account_info(),
+            },
+            &[fraction_seeds],
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+
+    // Escrow the asset; while the Fraction PDA owns it, it can't be listed, staked, or sold
+    TransferV1CpiBuilder::new(&ctx.accounts.mpl_core_program.to_account_info())
+        .asset(&ctx.accounts.asset.to_account_info())
+        .collection(Some(&ctx.accounts.collection.to_account_info()))
+        .payer(&ctx.accounts.owner.to_account_info())
+        .authority(Some(&ctx.accounts.owner.to_account_info()))
+        .new_owner(&ctx.accounts.fraction.to_account_info())
+        .system_program(Some(&ctx.accounts.system_program.to_account_info()))
+        .invoke()?;
+
+    msg!(
+        "Parcel {} fractionalized into {} shares of {}",
+        parcel_id,
+        share_supply,
+        ctx.accounts.share_mint.key()
+    );
+    Ok(())
+}
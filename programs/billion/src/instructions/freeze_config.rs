@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::state::{grid_seed, ConfigFreeze, GridConfig};
+use crate::constants::FREEZE_CONFIG_CONFIRMATION;
+use crate::errors::BillionError;
+use crate::events::{ConfigFrozen, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct FreezeConfig<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == grid_config.authority @ BillionError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// Created here and never closed; `init` fails if the config is already frozen
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ConfigFreeze::INIT_SPACE,
+        seeds = [ConfigFreeze::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub config_freeze: Account<'info, ConfigFreeze>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Freezes the config for good. Afterwards these fail with ConfigFrozen:
+/// - config: update_config, queue_config_update, execute_config_update, create_collection,
+///   set_admin_roles
+/// - claim economics: set_token_treasury, set_referral_bps, set_referral_share,
+///   admin_set_charities, set_ring_reward_weights, set_reward_expiry, set_exit_share,
+///   set_adjacency_bonus, set_sol_price, set_hook
+/// - grid and minting: admin_advance_ring, admin_reserve_blocks, admin_mint, admin_mint_batch,
+///   mint_voucher, admin_purge, admin_purge_parcels, admin_close_parcel_info
+///
+/// Claims, reward claims and metadata updates keep working, and vouchers minted before the
+/// freeze can still be redeemed until they expire. `confirmation` must be
+/// FREEZE_CONFIG_CONFIRMATION.
+pub fn handler(ctx: Context<FreezeConfig>, _grid_id: u64, confirmation: u64) -> Result<()> {
+    require!(confirmation == FREEZE_CONFIG_CONFIRMATION, BillionError::InvalidFreezeConfirmation);
+
+    let frozen_at = Clock::get()?.unix_timestamp;
+    let freeze = &mut ctx.accounts.config_freeze;
+    freeze.authority = ctx.accounts.authority.key();
+    freeze.frozen_at = frozen_at;
+    freeze.bump = ctx.bumps.config_freeze;
+
+    emit!(ConfigFrozen { schema_version: EVENT_SCHEMA_VERSION, authority: freeze.authority, frozen_at });
+    msg!("Config frozen by {}", freeze.authority);
+    Ok(())
+}
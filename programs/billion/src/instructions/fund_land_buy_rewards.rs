@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022,
+    token_interface::{Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface},
+};
+use crate::state::{grid_seed, GridConfig, RewardDust, RingRewardWeights, LAND_BUY_REWARD_POOL_SEED};
+use crate::errors::BillionError;
+use crate::events::{LandBuyRewardsFunded, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct FundLandBuyRewards<'info> {
+    /// Anyone can fund; pays for the RewardDust on first touch
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = depositor,
+        token::token_program = token_program,
+    )]
+    pub depositor_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    /// Land buy reward pool receiving the deposit
+    #[account(
+        mut,
+        seeds = [LAND_BUY_REWARD_POOL_SEED, grid_config.key().as_ref()],
+        bump,
+        constraint = land_buy_reward_pool.key() == grid_config.land_buy_reward_pool @ BillionError::InvalidRewardPool
+    )]
+    pub land_buy_reward_pool: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    #[account(
+        constraint = token_mint.key() == grid_config.token_mint @ BillionError::Unauthorized
+    )]
+    pub token_mint: InterfaceAccount<'info, InterfaceMint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Per-ring land-buy reward weights the deposit is spread by; every block is unweighted while
+    /// uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [RingRewardWeights::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_reward_weights: UncheckedAccount<'info>,
+
+    /// Rounding the accumulator credit leaves behind, created on first touch
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        space = 8 + RewardDust::INIT_SPACE,
+        seeds = [RewardDust::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub reward_dust: Box<Account<'info, RewardDust>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Deposits `amount` into the land buy reward pool and credits it pro-rata to every block in
+/// total_claimed_blocks, by ring weight, claimable like a claim's reward share. Rounding dust stays in the
+/// pool and is tracked in RewardDust.
+pub fn handler(ctx: Context<FundLandBuyRewards>, _grid_id: u64, amount: u64) -> Result<()> {
+    require!(amount > 0, BillionError::InvalidRewardFunding);
+    let total_claimed_blocks = ctx.accounts.grid_config.total_claimed_blocks;
+    require!(total_claimed_blocks > 0, BillionError::NoRewardRecipients);
+
+    token_2022::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_2022::TransferChecked {
+                from: ctx.accounts.depositor_token_account.to_account_info(),
+                to: ctx.accounts.land_buy_reward_pool.to_account_info(),
+                authority: ctx.accounts.depositor.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    let weights = RingRewardWeights::load(&ctx.accounts.ring_reward_weights)?;
+    let grid_config = &mut ctx.accounts.grid_config;
+    let reward_units = weights
+        .reward_units(grid_config.epoch, total_claimed_blocks)
+        .ok_or(BillionError::Overflow)?;
+    let increment = RingRewardWeights::reward_increase(amount, reward_units).ok_or(BillionError::Overflow)?;
+    grid_config.land_buy_rewards_per_block = grid_config
+        .land_buy_rewards_per_block
+        .checked_add(increment)
+        .ok_or(BillionError::Overflow)?;
+    grid_config.credit_land_buy_rewards(amount).ok_or(BillionError::Overflow)?;
+    let reward_dust = &mut ctx.accounts.reward_dust;
+    reward_dust.bump = ctx.bumps.reward_dust;
+    reward_dust.record(amount, increment, reward_units).ok_or(BillionError::Overflow)?;
+
+    emit!(LandBuyRewardsFunded {
+        schema_version: EVENT_SCHEMA_VERSION,
+        depositor: ctx.accounts.depositor.key(),
+        amount,
+        land_buy_rewards_per_block: grid_config.land_buy_rewards_per_block,
+    });
+    msg!("Funded {} land-buy reward tokens over {} blocks", amount, total_claimed_blocks);
+    Ok(())
+}
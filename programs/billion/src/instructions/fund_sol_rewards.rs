@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{grid_seed, GridConfig, SolRewards};
+use crate::constants::SOL_REWARD_VAULT_SEED;
+use crate::errors::BillionError;
+use crate::events::{SolRewardsFunded, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct FundSolRewards<'info> {
+    /// Anyone can fund
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        space = 8 + SolRewards::INIT_SPACE,
+        seeds = [SolRewards::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub sol_rewards: Account<'info, SolRewards>,
+
+    /// System-owned vault holding the deposited lamports
+    #[account(
+        mut,
+        seeds = [SOL_REWARD_VAULT_SEED, grid_config.key().as_ref()],
+        bump
+    )]
+    pub sol_reward_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Deposits `lamports` into the SOL reward vault and credits them pro-rata to every block
+/// enrolled through claim_sol_rewards. The first deposit also pays the vault's rent-exempt
+/// minimum, which isn't credited, so claims never leave it below rent exemption.
+pub fn handler(ctx: Context<FundSolRewards>, _grid_id: u64, lamports: u64) -> Result<()> {
+    require!(lamports > 0, BillionError::InvalidRewardFunding);
+    let sol_rewards = &mut ctx.accounts.sol_rewards;
+    sol_rewards.bump = ctx.bumps.sol_rewards;
+    require!(sol_rewards.enrolled_blocks > 0, BillionError::NoRewardRecipients);
+
+    let vault = &ctx.accounts.sol_reward_vault;
+    let rent = if vault.lamports() == 0 { Rent::get()?.minimum_balance(0) } else { 0 };
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.depositor.to_account_info(),
+                to: vault.to_account_info(),
+            },
+        ),
+        lamports.checked_add(rent).ok_or(BillionError::Overflow)?,
+    )?;
+    sol_rewards.credit(lamports).ok_or(BillionError::Overflow)?;
+
+    emit!(SolRewardsFunded {
+        schema_version: EVENT_SCHEMA_VERSION,
+        depositor: ctx.accounts.depositor.key(),
+        lamports,
+        sol_rewards_per_block: sol_rewards.sol_rewards_per_block,
+    });
+    msg!("Funded {} lamports over {} enrolled blocks", lamports, sol_rewards.enrolled_blocks);
+    Ok(())
+}
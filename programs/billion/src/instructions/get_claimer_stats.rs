@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+use crate::state::{grid_seed, ClaimerStats};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, wallet: Pubkey)]
+pub struct GetClaimerStats<'info> {
+    #[account(
+        seeds = [ClaimerStats::SEED, &grid_seed(grid_id), wallet.as_ref()],
+        bump = claimer_stats.bump
+    )]
+    pub claimer_stats: Account<'info, ClaimerStats>,
+}
+
+/// Lifetime stats for `wallet`, returned via return data
+pub fn handler(ctx: Context<GetClaimerStats>, _grid_id: u64, _wallet: Pubkey) -> Result<ClaimerStats> {
+    Ok(ctx.accounts.claimer_stats.clone().into_inner())
+}
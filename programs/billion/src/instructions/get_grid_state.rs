@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::state::{grid_seed, GridConfig, RingUnlockSchedule};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct GetGridState<'info> {
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// Times rings open whatever has been burned; rings unlock by burns alone while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [RingUnlockSchedule::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_unlock_schedule: UncheckedAccount<'info>,
+}
+
+/// Snapshot of the grid returned via return data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GridState {
+    pub price_per_block: u64,
+    pub unlocked_ring: u8,
+    pub total_claimed_blocks: u32,
+    pub total_burned: u64,
+    pub next_parcel_id: u16,
+    pub seeding_enabled: bool,
+}
+
+pub fn handler(ctx: Context<GetGridState>, _grid_id: u64) -> Result<GridState> {
+    let config = &ctx.accounts.grid_config;
+    let schedule = RingUnlockSchedule::load(&ctx.accounts.ring_unlock_schedule)?;
+
+    Ok(GridState {
+        price_per_block: config.price_per_block,
+        unlocked_ring: config.unlocked_ring_at(&schedule, Clock::get()?.unix_timestamp),
+        total_claimed_blocks: config.total_claimed_blocks,
+        total_burned: config.total_burned,
+        next_parcel_id: config.next_parcel_id,
+        seeding_enabled: config.seeding_enabled,
+    })
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{epoch_seed, grid_seed, ParcelInfo};
+use crate::errors::BillionError;
+use crate::instructions::claim_land_buy_rewards::get_core_asset_owner;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16)]
+pub struct GetParcel<'info> {
+    #[account(
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+
+    /// The Metaplex Core asset - must match parcel_info.asset
+    /// CHECK: Validated by constraint, owner parsed in handler
+    #[account(
+        constraint = asset.key() == parcel_info.asset @ BillionError::AssetMismatch
+    )]
+    pub asset: UncheckedAccount<'info>,
+}
+
+/// ParcelInfo fields plus the current asset owner, returned via return data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ParcelView {
+    pub parcel_id: u16,
+    pub asset: Pubkey,
+    pub owner: Pubkey,
+    pub x: u8,
+    pub y: u8,
+    pub width: u8,
+    pub height: u8,
+    pub block_count: u32,
+    pub last_claimed_land_buy_rewards_per_block: u128,
+    pub paid_total: u64,
+    pub claimed_at: u32,
+}
+
+pub fn handler(ctx: Context<GetParcel>, _grid_id: u64, parcel_id: u16) -> Result<ParcelView> {
+    let parcel_info = &ctx.accounts.parcel_info;
+    let owner = get_core_asset_owner(&ctx.accounts.asset.to_account_info())?;
+
+    Ok(ParcelView {
+        parcel_id,
+        asset: parcel_info.asset,
+        owner,
+        x: parcel_info.x,
+        y: parcel_info.y,
+        width: parcel_info.width,
+        height: parcel_info.height,
+        block_count: parcel_info.block_count(),
+        last_claimed_land_buy_rewards_per_block: parcel_info.last_claimed_land_buy_rewards_per_block,
+        paid_total: parcel_info.paid_total,
+        claimed_at: parcel_info.claimed_at,
+    })
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::{epoch_seed, grid_seed, EpochArchive, GridConfig, ParcelInfo};
+use crate::instructions::claim_land_buy_rewards::{parcel_rewards_per_block, pending_land_buy_rewards};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16)]
+pub struct GetPendingRewards<'info> {
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+
+    /// Archive of the parcel's epoch - required when the parcel is from an earlier epoch
+    #[account(
+        seeds = [EpochArchive::SEED, &grid_seed(grid_id), &epoch_archive.epoch.to_le_bytes()],
+        bump = epoch_archive.bump
+    )]
+    pub epoch_archive: Option<Account<'info, EpochArchive>>,
+}
+
+/// Land-buy rewards claim_land_buy_rewards would settle for `parcel_id` right now, returned
+/// via return data. Ignores vesting locks and the adjacency bonus.
+pub fn handler(ctx: Context<GetPendingRewards>, _grid_id: u64, _parcel_id: u16) -> Result<u64> {
+    let parcel_info = &ctx.accounts.parcel_info;
+    let land_buy_rewards_per_block =
+        parcel_rewards_per_block(parcel_info, &ctx.accounts.grid_config, ctx.accounts.epoch_archive.as_deref())?;
+    pending_land_buy_rewards(parcel_info, land_buy_rewards_per_block)
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::{grid_seed, EpochArchive, GridConfig};
+use crate::constants::MAX_REWARD_CLAIM_BATCH;
+use crate::errors::BillionError;
+use crate::instructions::claim_land_buy_rewards::{parcel_rewards_per_block, pending_land_buy_rewards};
+use crate::instructions::claim_land_buy_rewards_batch::load_parcel_info;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct GetPendingRewardsMany<'info> {
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// Archive of an earlier epoch - required for parcels from that epoch
+    #[account(
+        seeds = [EpochArchive::SEED, &grid_seed(grid_id), &epoch_archive.epoch.to_le_bytes()],
+        bump = epoch_archive.bump
+    )]
+    pub epoch_archive: Option<Account<'info, EpochArchive>>,
+}
+
+/// get_pending_rewards for each of `parcel_ids`, whose ParcelInfo accounts follow in
+/// remaining_accounts in the same order. Returns the amounts in that order.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, GetPendingRewardsMany<'info>>,
+    grid_id: u64,
+    parcel_ids: Vec<u16>,
+) -> Result<Vec<u64>> {
+    require!(
+        parcel_ids.len() <= MAX_REWARD_CLAIM_BATCH as usize && ctx.remaining_accounts.len() == parcel_ids.len(),
+        BillionError::InvalidBatch
+    );
+    let epoch_archive = ctx.accounts.epoch_archive.as_deref();
+    parcel_ids
+        .iter()
+        .zip(ctx.remaining_accounts)
+        .map(|(&parcel_id, account)| {
+            let parcel_info = load_parcel_info(account, grid_id, parcel_id)?;
+            let land_buy_rewards_per_block =
+                parcel_rewards_per_block(&parcel_info, &ctx.accounts.grid_config, epoch_archive)?;
+            pending_land_buy_rewards(&parcel_info, land_buy_rewards_per_block)
+        })
+        .collect()
+}
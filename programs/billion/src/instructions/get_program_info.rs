@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::state::{grid_seed, GridConfig};
+
+/// Program semver baked in at build time
+pub const PROGRAM_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct GetProgramInfo<'info> {
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+}
+
+/// Capability information for SDKs, returned via return data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ProgramInfo {
+    pub version: String,
+    pub schema_version: u8,
+    pub feature_flags: u64,
+}
+
+pub fn handler(ctx: Context<GetProgramInfo>, _grid_id: u64) -> Result<ProgramInfo> {
+    let config = &ctx.accounts.grid_config;
+
+    Ok(ProgramInfo {
+        version: PROGRAM_VERSION.to_string(),
+        schema_version: config.schema_version,
+        feature_flags: config.feature_flags(),
+    })
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use billion_core::Rect;
+use crate::state::{grid_seed, BlockMap, GridConfig, RingUnlockSchedule};
+use crate::constants::MAX_REGION_SIDE;
+use crate::errors::BillionError;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct GetRegion<'info> {
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// BlockMap address must match the one stored in grid_config
+    #[account(
+        constraint = block_map.key() == grid_config.block_map @ BillionError::Unauthorized
+    )]
+    pub block_map: AccountLoader<'info, BlockMap>,
+
+    /// Times rings open whatever has been burned; rings unlock by burns alone while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [RingUnlockSchedule::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_unlock_schedule: UncheckedAccount<'info>,
+}
+
+/// A window of the BlockMap plus the grid state a viewport needs, returned via return data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RegionView {
+    pub x: u8,
+    pub y: u8,
+    pub width: u8,
+    pub height: u8,
+    /// Row-major parcel ids of the window, 0 meaning unclaimed and RESERVED_BLOCK reserved
+    pub blocks: Vec<u16>,
+    pub unlocked_ring: u8,
+    pub next_parcel_id: u16,
+}
+
+/// Reads a window of at most MAX_REGION_SIDE x MAX_REGION_SIDE blocks, so a renderer doesn't
+/// have to fetch the whole BlockMap
+pub fn handler(ctx: Context<GetRegion>, _grid_id: u64, x: u8, y: u8, width: u8, height: u8) -> Result<RegionView> {
+    require!(width > 0 && height > 0, BillionError::InvalidDimensions);
+    require!(width <= MAX_REGION_SIDE && height <= MAX_REGION_SIDE, BillionError::RegionTooLarge);
+
+    let rect = Rect::new(x, y, width, height);
+    let block_map = BlockMap::load(&ctx.accounts.block_map)?;
+    require!(rect.in_bounds(block_map.grid_size()), BillionError::OutOfBounds);
+
+    let config = &ctx.accounts.grid_config;
+    let schedule = RingUnlockSchedule::load(&ctx.accounts.ring_unlock_schedule)?;
+    Ok(RegionView {
+        x,
+        y,
+        width,
+        height,
+        blocks: rect.blocks().map(|(block_x, block_y)| block_map.get_block(block_x, block_y)).collect(),
+        unlocked_ring: config.unlocked_ring_at(&schedule, Clock::get()?.unix_timestamp),
+        next_parcel_id: config.next_parcel_id,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::program::MAX_RETURN_DATA;
+
+    fn view(side: u8) -> RegionView {
+        RegionView {
+            x: 0,
+            y: 0,
+            width: side,
+            height: side,
+            blocks: vec![u16::MAX; side as usize * side as usize],
+            unlocked_ring: 10,
+            next_parcel_id: u16::MAX,
+        }
+    }
+
+    #[test]
+    fn test_largest_region_fits_in_return_data() {
+        assert!(view(MAX_REGION_SIDE).try_to_vec().unwrap().len() <= MAX_RETURN_DATA);
+        assert!(view(MAX_REGION_SIDE + 1).try_to_vec().unwrap().len() > MAX_RETURN_DATA);
+    }
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use billion_core::Rect;
+use crate::errors::BillionError;
+use crate::events::{MapUpdated, EVENT_SCHEMA_VERSION};
+use crate::state::{grid_seed, GridConfig, MapDigest};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct InitMapDigest<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump,
+        has_one = authority @ BillionError::Unauthorized,
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + MapDigest::INIT_SPACE,
+        seeds = [MapDigest::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub map_digest: Account<'info, MapDigest>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates the MapDigest of a grid initialized before it existed. Its hash chains only the
+/// writes from here on, so a mirror re-fetches the BlockMap once and replays MapUpdated from
+/// this call's event.
+pub fn handler(ctx: Context<InitMapDigest>, _grid_id: u64) -> Result<()> {
+    let epoch = ctx.accounts.grid_config.epoch;
+    *ctx.accounts.map_digest = MapDigest::new(epoch, ctx.bumps.map_digest);
+
+    emit!(MapUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        epoch,
+        parcel_id: 0,
+        x: 0,
+        y: 0,
+        width: 0,
+        height: 0,
+        map_version: 0,
+        map_hash: [0; 32],
+    });
+    msg!("Map digest initialized for epoch {}", epoch);
+    Ok(())
+}
+
+/// Chains a write of `parcel_id` over `rect` into `account` and emits it. Nothing is recorded
+/// for a grid whose MapDigest hasn't been created.
+pub(crate) fn record_map_write(account: &AccountInfo, epoch: u16, parcel_id: u16, rect: Rect) -> Result<()> {
+    if account.data_is_empty() {
+        return Ok(());
+    }
+    let mut digest = MapDigest::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    digest.apply(epoch, parcel_id, rect).ok_or(BillionError::Overflow)?;
+    digest.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+    emit!(MapUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        epoch,
+        parcel_id,
+        x: rect.x,
+        y: rect.y,
+        width: rect.width,
+        height: rect.height,
+        map_version: digest.map_version,
+        map_hash: digest.map_hash,
+    });
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::BillionError;
+use crate::state::{grid_seed, GridConfig, QUEST_VAULT_SEED};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct InitQuestVault<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump,
+        has_one = authority @ BillionError::Unauthorized,
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        constraint = token_mint.key() == grid_config.token_mint @ BillionError::Unauthorized
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Quest vault - funded by plain token transfers, separate from the land-buy pool
+    #[account(
+        init,
+        payer = authority,
+        seeds = [QUEST_VAULT_SEED, grid_config.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = grid_config,
+        token::token_program = token_program,
+    )]
+    pub quest_vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitQuestVault>, _grid_id: u64) -> Result<()> {
+    let config = &mut ctx.accounts.grid_config;
+    config.quest_vault = ctx.accounts.quest_vault.key();
+
+    msg!("Quest vault initialized at {}", config.quest_vault);
+    Ok(())
+}
@@ -3,21 +3,26 @@ use anchor_spl::{
     token_interface::{Mint, TokenAccount, TokenInterface},
     associated_token::AssociatedToken,
 };
-use crate::state::{GridConfig, BlockMap, LAND_BUY_REWARD_POOL_SEED};
+use crate::state::{grid_seed, AdminRoles, GridConfig, BlockMap, RingOccupancy, MapDigest, LAND_BUY_REWARD_POOL_SEED};
+use crate::events::{ConfigInitialized, GridInitialized, MapUpdated, RingOccupancyUpdated, EVENT_SCHEMA_VERSION};
+use crate::errors::BillionError;
+use crate::instructions::get_program_info::PROGRAM_VERSION;
+use crate::utils::has_transfer_fee;
 
 #[derive(Accounts)]
+#[instruction(grid_id: u64)]
 pub struct Initialize<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
-    /// Token mint - supports both Token and Token-2022
+    /// Token mint - supports both Token and Token-2022, but not Token-2022 transfer fees
     pub token_mint: InterfaceAccount<'info, Mint>,
 
     #[account(
         init,
         payer = authority,
         space = 8 + GridConfig::INIT_SPACE,
-        seeds = [GridConfig::SEED],
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
         bump
     )]
     pub grid_config: Account<'info, GridConfig>,
@@ -39,6 +44,38 @@ pub struct Initialize<'info> {
     )]
     pub land_buy_reward_pool: InterfaceAccount<'info, TokenAccount>,
 
+    /// Every admin role starts with the authority; survives an admin_purge, so re-initializing
+    /// resets it
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + AdminRoles::INIT_SPACE,
+        seeds = [AdminRoles::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub admin_roles: Account<'info, AdminRoles>,
+
+    /// Claimed blocks per ring, all zero over the empty BlockMap; survives an admin_purge
+    /// as well, so re-initializing starts it over
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + RingOccupancy::INIT_SPACE,
+        seeds = [RingOccupancy::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub ring_occupancy: Account<'info, RingOccupancy>,
+
+    /// Digest of the BlockMap's writes, starting from none; re-initializing starts it over too
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + MapDigest::INIT_SPACE,
+        seeds = [MapDigest::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub map_digest: Account<'info, MapDigest>,
+
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
@@ -46,11 +83,27 @@ pub struct Initialize<'info> {
 
 pub fn handler(
     ctx: Context<Initialize>,
+    _grid_id: u64,
     price_per_block: u64,
     ring_thresholds: Vec<u64>,
     uri_base: String,
     land_owners_reward_share_bps: u16,
+    max_parcel_blocks: u16,
 ) -> Result<()> {
+    // Fees would leave the reward pool holding less than the rewards credited against it
+    require!(
+        !has_transfer_fee(&ctx.accounts.token_mint.to_account_info())?,
+        BillionError::TransferFeeMintUnsupported
+    );
+    // No treasury, referral or charity share exists before their setters first run
+    require!(GridConfig::valid_split_bps(land_owners_reward_share_bps, 0, 0, 0), BillionError::InvalidRewardShare);
+    // The number of thresholds sets the number of rings, which the grid must have room for
+    let grid_size = BlockMap::load(&ctx.accounts.block_map)?.grid_size();
+    let ring_count = ring_thresholds.len();
+    require!(GridConfig::valid_ring_count(ring_count, grid_size), BillionError::InvalidRingThresholds);
+    GridConfig::check_ring_thresholds(&ring_thresholds, ring_count as u8)?;
+    require!(GridConfig::valid_uri_base(&uri_base), BillionError::InvalidUriBase);
+
     let config = &mut ctx.accounts.grid_config;
 
     config.authority = ctx.accounts.authority.key();
@@ -70,15 +123,91 @@ pub fn handler(
     config.total_claimed_blocks = 0;
     config.land_owners_reward_share_bps = land_owners_reward_share_bps;
     config.land_buy_reward_pool = ctx.accounts.land_buy_reward_pool.key();
-    config._padding = [0u8; 202];
+    config.schema_version = GridConfig::SCHEMA_VERSION;
+    config.referral_bps = 0;
+    config.referral_vault = Pubkey::default();
+    config.emission_rate_per_block_per_sec = 0;
+    config.emissions_vault = Pubkey::default();
+    config.quest_vault = Pubkey::default();
+    config.epoch = 0;
+    config.exit_share_bps = 0;
+    config.reward_liability = 0;
+    config.reward_expiry_seconds = 0;
+    config.expiry_bounty_bps = 0;
+    config.reward_expiry_since = 0;
+    config.adjacency_bonus_bps = 0;
+    config.ring_unlocked_at = 0;
+    config.priority_ring = 0;
+    config.max_charity_bps = 0;
+    config.premium_price_per_period = 0;
+    config.premium_period_secs = 0;
+    config.raffle_interval_secs = 0;
+    config.max_parcel_blocks = max_parcel_blocks;
+    config.max_blocks_per_wallet = 0;
+    config.claims_paused = false;
+    config.price_per_block_lamports = 0;
+    config.total_sol_collected = 0;
+    config.sol_ring_credit_bps = 0;
+    config.allowlist_only = false;
+    config.collection_generation = 0;
+    config._padding = [0u8; 0];
+
+    *ctx.accounts.admin_roles = AdminRoles::single(ctx.accounts.authority.key(), ctx.bumps.admin_roles);
+    *ctx.accounts.ring_occupancy = RingOccupancy::new(0, RingOccupancy::FULLY_COUNTED, ctx.bumps.ring_occupancy);
+    *ctx.accounts.map_digest = MapDigest::new(0, ctx.bumps.map_digest);
 
     // BlockMap is already initialized by create_block_map instruction
     // blocks array is already zeroed from account creation
+    // GridConfig has no spare bytes, so the grid size lives in the BlockMap trailer alone.
+    // Binding the map keeps any other grid from initializing onto it.
+    let mut block_map = BlockMap::load_mut(&ctx.accounts.block_map)?;
+    block_map.bind()?;
 
     msg!(
-        "Grid initialized with price {} per block, reward share {}bps",
+        "Grid initialized at {}x{} with price {} per block, reward share {}bps",
+        grid_size,
+        grid_size,
         price_per_block,
         land_owners_reward_share_bps
     );
+
+    emit!(GridInitialized {
+        schema_version: EVENT_SCHEMA_VERSION,
+        grid_config: config.key(),
+        authority: config.authority,
+        token_mint: config.token_mint,
+        price_per_block,
+        land_owners_reward_share_bps,
+        program_version: PROGRAM_VERSION.to_string(),
+        config_schema_version: config.schema_version,
+        feature_flags: config.feature_flags(),
+    });
+    emit!(ConfigInitialized {
+        schema_version: EVENT_SCHEMA_VERSION,
+        price_per_block,
+        ring_thresholds: config.ring_thresholds.clone(),
+        uri_base: config.uri_base.clone(),
+        seeding_enabled: config.seeding_enabled,
+        collection: config.collection,
+        land_owners_reward_share_bps,
+        max_parcel_blocks,
+    });
+    emit!(RingOccupancyUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        epoch: 0,
+        claimed_blocks_per_ring: ctx.accounts.ring_occupancy.claimed_blocks_per_ring,
+        complete: true,
+    });
+    emit!(MapUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        epoch: 0,
+        parcel_id: 0,
+        x: 0,
+        y: 0,
+        width: 0,
+        height: 0,
+        map_version: 0,
+        map_hash: [0; 32],
+    });
     Ok(())
 }
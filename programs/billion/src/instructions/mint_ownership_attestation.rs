@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::{epoch_seed, grid_seed, Attestation, ParcelInfo};
+use crate::errors::BillionError;
+use crate::instructions::claim_land_buy_rewards::get_core_asset_owner;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16)]
+pub struct MintOwnershipAttestation<'info> {
+    /// Current owner of the parcel's asset
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+
+    /// The Metaplex Core asset - must match parcel_info.asset
+    /// CHECK: Validated by constraint, ownership checked in handler
+    #[account(
+        constraint = asset.key() == parcel_info.asset @ BillionError::AssetMismatch
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    /// Re-minting refreshes an existing attestation
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + Attestation::INIT_SPACE,
+        seeds = [
+            Attestation::SEED,
+            &grid_seed(grid_id),
+            &epoch_seed(parcel_info.epoch),
+            &parcel_id.to_le_bytes(),
+            owner.key().as_ref(),
+        ],
+        bump
+    )]
+    pub attestation: Account<'info, Attestation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MintOwnershipAttestation>, _grid_id: u64, parcel_id: u16) -> Result<()> {
+    let owner = get_core_asset_owner(&ctx.accounts.asset.to_account_info())?;
+    require!(owner == ctx.accounts.owner.key(), BillionError::NotOwner);
+
+    let clock = Clock::get()?;
+    let parcel_info = &ctx.accounts.parcel_info;
+    let attestation = &mut ctx.accounts.attestation;
+    attestation.owner = owner;
+    attestation.parcel_id = parcel_id;
+    attestation.epoch = parcel_info.epoch;
+    attestation.asset = parcel_info.asset;
+    attestation.block_count = parcel_info.block_count();
+    attestation.slot = clock.slot;
+    attestation.attested_at = clock.unix_timestamp;
+    attestation.bump = ctx.bumps.attestation;
+
+    msg!("Attested {} owns parcel {} at slot {}", owner, parcel_id, clock.slot);
+    Ok(())
+}
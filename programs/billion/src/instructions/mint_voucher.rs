@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use crate::state::{grid_seed, ConfigFreeze, GridConfig, Voucher};
+use crate::errors::BillionError;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, voucher_id: u64, beneficiary: Pubkey)]
+pub struct MintVoucher<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == grid_config.authority @ BillionError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Voucher::INIT_SPACE,
+        seeds = [Voucher::SEED, &grid_seed(grid_id), beneficiary.as_ref(), &voucher_id.to_le_bytes()],
+        bump
+    )]
+    pub voucher: Account<'info, Voucher>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Must still be empty: freeze_config creating it disables this instruction for good
+    /// CHECK: Seeds are verified here, only whether it exists is read
+    #[account(
+        seeds = [ConfigFreeze::SEED, &grid_seed(grid_id)],
+        bump,
+        constraint = !ConfigFreeze::is_frozen(&config_freeze) @ BillionError::ConfigFrozen
+    )]
+    pub config_freeze: UncheckedAccount<'info>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
+    ctx: Context<MintVoucher>,
+    _grid_id: u64,
+    voucher_id: u64,
+    beneficiary: Pubkey,
+    max_width: u8,
+    max_height: u8,
+    allowed_ring_min: u8,
+    allowed_ring_max: u8,
+    expires_at: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(max_width > 0 && max_height > 0, BillionError::InvalidVoucher);
+    require!(
+        allowed_ring_min >= 1 && allowed_ring_min <= allowed_ring_max,
+        BillionError::InvalidVoucher
+    );
+    require!(expires_at > now, BillionError::InvalidVoucher);
+
+    let voucher = &mut ctx.accounts.voucher;
+    voucher.beneficiary = beneficiary;
+    voucher.voucher_id = voucher_id;
+    voucher.max_width = max_width;
+    voucher.max_height = max_height;
+    voucher.allowed_ring_min = allowed_ring_min;
+    voucher.allowed_ring_max = allowed_ring_max;
+    voucher.expires_at = expires_at;
+    voucher.issued_at = now;
+    voucher.bump = ctx.bumps.voucher;
+
+    msg!(
+        "Voucher {} for {}: up to {}x{} in rings {}-{}, expires at {}",
+        voucher_id,
+        beneficiary,
+        max_width,
+        max_height,
+        allowed_ring_min,
+        allowed_ring_max,
+        expires_at
+    );
+    Ok(())
+}
@@ -4,20 +4,218 @@ pub mod create_block_map;
 pub mod initialize;
 pub mod update_config;
 pub mod claim_parcel;
+pub mod claim_parcel_pda;
 pub mod admin_mint;
 pub mod update_parcel_metadata;
 pub mod claim_land_buy_rewards;
 pub mod admin_close_parcel_info;
 pub mod admin_purge;
 pub mod admin_transfer_nft_collection_authority;
+pub mod get_grid_state;
+pub mod get_parcel;
+pub mod get_program_info;
+pub mod register_referrer;
+pub mod init_referral_vault;
+pub mod set_referral_bps;
+pub mod claim_referral_earnings;
+pub mod init_emissions_vault;
+pub mod set_emission_rate;
+pub mod stake_parcel;
+pub mod claim_staking_rewards;
+pub mod unstake_parcel;
+pub mod configure_harberger_district;
+pub mod set_valuation;
+pub mod pay_tax;
+pub mod force_buy;
+pub mod init_quest_vault;
+pub mod set_quest_root;
+pub mod claim_quest_reward;
+pub mod create_distribution;
+pub mod claim_distribution;
+pub mod sweep_distribution;
+pub mod start_new_epoch;
+pub mod set_hook;
+pub mod disable_hook;
+pub mod fractionalize_parcel;
+pub mod claim_fraction_rewards;
+pub mod deposit_fraction_shares;
+pub mod withdraw_fraction_shares;
+pub mod redeem_parcel;
+pub mod set_exit_share;
+pub mod burn_for_exit;
+pub mod set_reward_expiry;
+pub mod expire_parcel_rewards;
+pub mod set_adjacency_bonus;
+pub mod refresh_adjacency;
+pub mod mint_ownership_attestation;
+pub mod close_attestation;
+pub mod unlock_vested_parcel;
+pub mod admin_set_charities;
+pub mod get_claimer_stats;
+pub mod close_claimer_stats;
+pub mod register_parcel_name;
+pub mod release_parcel_name;
+pub mod mint_voucher;
+pub mod redeem_voucher;
+pub mod close_expired_voucher;
+pub mod set_premium_price;
+pub mod purchase_premium;
+pub mod set_raffle_interval;
+pub mod run_raffle;
+pub mod claim_raffle_block;
+pub mod claim_parcel_signed;
+pub mod claim_parcels_batch;
+pub mod claim_parcel_sol;
+pub mod set_sol_price;
+pub mod withdraw_sol_treasury;
+pub mod refresh_parcel_attributes;
+pub mod admin_set_collection_royalties;
+pub mod admin_thaw_parcel;
+pub mod claim_parcel_masked;
+pub mod admin_mint_batch;
+pub mod admin_reserve_parcel;
+pub mod admin_release_reserved_parcel;
+pub mod admin_set_rewards_excluded;
+pub mod claim_land_buy_rewards_batch;
+pub mod get_pending_rewards;
+pub mod get_pending_rewards_many;
+pub mod claim_parcel_with_rewards;
+pub mod set_reward_delegate;
+pub mod settle_rewards_for_transfer;
+pub mod fund_land_buy_rewards;
+pub mod fund_sol_rewards;
+pub mod claim_sol_rewards;
+pub mod close_sol_reward_checkpoint;
+pub mod admin_sweep_dust;
+pub mod verify_reward_solvency;
+pub mod set_ring_reward_weights;
+pub mod admin_withdraw_pool_excess;
+pub mod set_admin_roles;
+pub mod queue_config_update;
+pub mod execute_config_update;
+pub mod cancel_config_update;
+pub mod admin_advance_ring;
+pub mod create_collection;
+pub mod set_token_treasury;
+pub mod freeze_config;
+pub mod admin_purge_parcels;
+pub mod admin_reserve_blocks;
+pub mod admin_unreserve_blocks;
+pub mod get_region;
+pub mod admin_backfill_ring_occupancy;
+pub mod init_map_digest;
+pub mod set_rename_fee;
+pub mod owner_update_parcel;
+pub mod set_parcel_content;
+pub mod set_parcel_pixels;
+pub mod close_parcel_pixels;
+pub mod update_parcels_metadata_batch;
+pub mod set_referral_share;
 
 pub use create_block_map::*;
 pub use initialize::*;
 pub use update_config::*;
 pub use claim_parcel::*;
+pub use claim_parcel_pda::*;
 pub use admin_mint::*;
 pub use update_parcel_metadata::*;
 pub use claim_land_buy_rewards::*;
 pub use admin_close_parcel_info::*;
 pub use admin_purge::*;
 pub use admin_transfer_nft_collection_authority::*;
+pub use get_grid_state::*;
+pub use get_parcel::*;
+pub use get_program_info::*;
+pub use register_referrer::*;
+pub use init_referral_vault::*;
+pub use set_referral_bps::*;
+pub use claim_referral_earnings::*;
+pub use init_emissions_vault::*;
+pub use set_emission_rate::*;
+pub use stake_parcel::*;
+pub use claim_staking_rewards::*;
+pub use unstake_parcel::*;
+pub use configure_harberger_district::*;
+pub use set_valuation::*;
+pub use pay_tax::*;
+pub use force_buy::*;
+pub use init_quest_vault::*;
+pub use set_quest_root::*;
+pub use claim_quest_reward::*;
+pub use create_distribution::*;
+pub use claim_distribution::*;
+pub use sweep_distribution::*;
+pub use start_new_epoch::*;
+pub use set_hook::*;
+pub use disable_hook::*;
+pub use fractionalize_parcel::*;
+pub use claim_fraction_rewards::*;
+pub use redeem_parcel::*;
+pub use set_exit_share::*;
+pub use burn_for_exit::*;
+pub use set_reward_expiry::*;
+pub use expire_parcel_rewards::*;
+pub use set_adjacency_bonus::*;
+pub use refresh_adjacency::*;
+pub use mint_ownership_attestation::*;
+pub use close_attestation::*;
+pub use unlock_vested_parcel::*;
+pub use admin_set_charities::*;
+pub use get_claimer_stats::*;
+pub use close_claimer_stats::*;
+pub use register_parcel_name::*;
+pub use release_parcel_name::*;
+pub use mint_voucher::*;
+pub use redeem_voucher::*;
+pub use close_expired_voucher::*;
+pub use set_premium_price::*;
+pub use purchase_premium::*;
+pub use set_raffle_interval::*;
+pub use run_raffle::*;
+pub use claim_raffle_block::*;
+pub use claim_parcel_signed::*;
+pub use claim_parcels_batch::*;
+pub use claim_parcel_sol::*;
+pub use set_sol_price::*;
+pub use withdraw_sol_treasury::*;
+pub use refresh_parcel_attributes::*;
+pub use admin_set_collection_royalties::*;
+pub use admin_thaw_parcel::*;
+pub use claim_parcel_masked::*;
+pub use admin_mint_batch::*;
+pub use admin_release_reserved_parcel::*;
+pub use admin_set_rewards_excluded::*;
+pub use claim_land_buy_rewards_batch::*;
+pub use get_pending_rewards::*;
+pub use get_pending_rewards_many::*;
+pub use claim_parcel_with_rewards::*;
+pub use set_reward_delegate::*;
+pub use settle_rewards_for_transfer::*;
+pub use fund_land_buy_rewards::*;
+pub use fund_sol_rewards::*;
+pub use claim_sol_rewards::*;
+pub use close_sol_reward_checkpoint::*;
+pub use admin_sweep_dust::*;
+pub use verify_reward_solvency::*;
+pub use set_ring_reward_weights::*;
+pub use admin_withdraw_pool_excess::*;
+pub use set_admin_roles::*;
+pub use queue_config_update::*;
+pub use execute_config_update::*;
+pub use cancel_config_update::*;
+pub use admin_advance_ring::*;
+pub use create_collection::*;
+pub use set_token_treasury::*;
+pub use freeze_config::*;
+pub use admin_purge_parcels::*;
+pub use admin_reserve_blocks::*;
+pub use get_region::*;
+pub use admin_backfill_ring_occupancy::*;
+pub use init_map_digest::*;
+pub use set_rename_fee::*;
+pub use owner_update_parcel::*;
+pub use set_parcel_content::*;
+pub use set_parcel_pixels::*;
+pub use close_parcel_pixels::*;
+pub use update_parcels_metadata_batch::*;
+pub use set_referral_share::*;
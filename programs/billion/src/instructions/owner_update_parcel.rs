@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022,
+    token_interface::{Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface},
+};
+use mpl_core::instructions::UpdateV1CpiBuilder;
+use crate::state::{
+    epoch_seed, grid_seed, sanitize_display_name, GridConfig, ParcelInfo, RenameFee, RingUnlockSchedule,
+};
+use crate::constants::MAX_PARCEL_URI_LEN;
+use crate::errors::BillionError;
+use crate::events::{ParcelRenamed, EVENT_SCHEMA_VERSION};
+use crate::instructions::claim_land_buy_rewards::get_core_asset_owner;
+#[cfg(not(feature = "localnet"))]
+use crate::instructions::claim_parcel::MPL_CORE_ID;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16)]
+pub struct OwnerUpdateParcel<'info> {
+    /// Must own the parcel's asset; pays the fee
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+
+    /// Fee burned per rename; renames are disabled while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [RenameFee::SEED, &grid_seed(grid_id)], bump)]
+    pub rename_fee: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    #[account(
+        mut,
+        constraint = token_mint.key() == grid_config.token_mint @ BillionError::Unauthorized
+    )]
+    pub token_mint: InterfaceAccount<'info, InterfaceMint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Times rings open whatever has been burned; rings unlock by burns alone while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [RingUnlockSchedule::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_unlock_schedule: UncheckedAccount<'info>,
+
+    /// The Metaplex Core asset - must match parcel_info.asset
+    /// CHECK: Validated by constraint, ownership checked in handler
+    #[account(
+        mut,
+        constraint = asset.key() == parcel_info.asset @ BillionError::AssetMismatch
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    /// Core collection - must match grid_config.collection
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        constraint = collection.key() == grid_config.collection @ BillionError::InvalidCollection
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Renames the owner's parcel to `new_name` for the flat RenameFee, burned from the owner's
+/// tokens. With `new_uri_suffix`, the uri becomes uri_base, the parcel id and the suffix, so
+/// it stays on the grid's host. A parcel holding a registered name keeps it until released.
+pub fn handler(
+    ctx: Context<OwnerUpdateParcel>,
+    grid_id: u64,
+    parcel_id: u16,
+    new_name: String,
+    new_uri_suffix: Option<String>,
+) -> Result<()> {
+    let fee = RenameFee::load(&ctx.accounts.rename_fee)?.fee;
+    require!(fee > 0, BillionError::RenameDisabled);
+    let grid_config = &ctx.accounts.grid_config;
+    require!(ctx.accounts.parcel_info.epoch == grid_config.epoch, BillionError::ParcelArchived);
+    require!(!ctx.accounts.parcel_info.named, BillionError::ParcelAlreadyNamed);
+    let owner = get_core_asset_owner(&ctx.accounts.asset.to_account_info())?;
+    require!(owner == ctx.accounts.owner.key(), BillionError::NotOwner);
+
+    let name = sanitize_display_name(&new_name).ok_or(BillionError::InvalidDisplayName)?;
+    let uri = match new_uri_suffix {
+        Some(suffix) => {
+            require!(GridConfig::valid_uri_suffix(&suffix), BillionError::InvalidUriSuffix);
+            let uri = format!("{}{}{}", grid_config.uri_base, parcel_id, suffix);
+            require!(uri.len() <= MAX_PARCEL_URI_LEN as usize, BillionError::UriTooLong);
+            Some(uri)
+        }
+        None => None,
+    };
+    require!(ctx.accounts.owner_token_account.amount >= fee, BillionError::InsufficientBalance);
+
+    token_2022::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_2022::Burn {
+                mint: ctx.accounts.token_mint.to_account_info(),
+                from: ctx.accounts.owner_token_account.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        fee,
+    )?;
+
+    let mpl_core_program = ctx.accounts.mpl_core_program.to_account_info();
+    let asset = ctx.accounts.asset.to_account_info();
+    let collection = ctx.accounts.collection.to_account_info();
+    let authority = grid_config.to_account_info();
+    let payer = ctx.accounts.owner.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let bump = grid_config.bump;
+    let mut builder = UpdateV1CpiBuilder::new(&mpl_core_program);
+    builder
+        .asset(&asset)
+        .collection(Some(&collection))
+        .authority(Some(&authority))
+        .payer(&payer)
+        .system_program(&system_program)
+        .new_name(name.clone());
+    if let Some(uri) = uri.clone() {
+        builder.new_uri(uri);
+    }
+    builder.invoke_signed(&[&[GridConfig::SEED, &grid_seed(grid_id), &[bump]]])?;
+
+    // Burned fees count toward ring unlocks like a claim's burn
+    let now = Clock::get()?.unix_timestamp;
+    let schedule = RingUnlockSchedule::load(&ctx.accounts.ring_unlock_schedule)?;
+    let grid_config = &mut ctx.accounts.grid_config;
+    let previously_unlocked = grid_config.unlocked_ring_at(&schedule, now);
+    grid_config.total_burned = grid_config.total_burned.checked_add(fee).ok_or(BillionError::Overflow)?;
+    let unlocked = grid_config.unlocked_ring_at(&schedule, now);
+    grid_config.record_ring_unlock(previously_unlocked, unlocked, now);
+
+    emit!(ParcelRenamed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        parcel_id,
+        epoch: grid_config.epoch,
+        owner,
+        name: name.clone(),
+        uri: uri.unwrap_or_default(),
+        fee,
+    });
+
+    msg!("Parcel {} renamed to {} for {}", parcel_id, name, fee);
+    Ok(())
+}
@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022,
+    token_interface::{Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface},
+};
+use billion_core::Rect;
+use crate::state::{
+    epoch_seed, grid_seed, GridConfig, HarbergerDistrict, ParcelInfo, ParcelValuation, RewardDust,
+    RingRewardWeights, LAND_BUY_REWARD_POOL_SEED,
+};
+use crate::errors::BillionError;
+use crate::instructions::claim_land_buy_rewards::get_core_asset_owner;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16)]
+pub struct PayTax<'info> {
+    /// Anyone can crank a due period; the tax comes out of the owner's allowance. Only the
+    /// owner may pay ahead.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        seeds = [HarbergerDistrict::SEED, &grid_seed(grid_id)],
+        bump = harberger_district.bump
+    )]
+    pub harberger_district: Account<'info, HarbergerDistrict>,
+
+    #[account(
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+
+    #[account(
+        mut,
+        seeds = [ParcelValuation::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_valuation.bump
+    )]
+    pub parcel_valuation: Account<'info, ParcelValuation>,
+
+    /// The Metaplex Core asset - must match parcel_info.asset
+    /// CHECK: Validated by constraint, ownership checked in handler
+    #[account(
+        constraint = asset.key() == parcel_info.asset @ BillionError::AssetMismatch
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    /// Owner's token account, with the GridConfig PDA approved as delegate
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::token_program = token_program,
+        constraint = owner_token_account.owner == parcel_valuation.owner @ BillionError::NotOwner
+    )]
+    pub owner_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    /// Land buy reward pool receiving the tax
+    #[account(
+        mut,
+        seeds = [LAND_BUY_REWARD_POOL_SEED, grid_config.key().as_ref()],
+        bump,
+        constraint = land_buy_reward_pool.key() == grid_config.land_buy_reward_pool @ BillionError::InvalidRewardPool
+    )]
+    pub land_buy_reward_pool: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    #[account(
+        constraint = token_mint.key() == grid_config.token_mint @ BillionError::Unauthorized
+    )]
+    pub token_mint: InterfaceAccount<'info, InterfaceMint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Per-ring land-buy reward weights the tax is spread by; every block is unweighted while
+    /// uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [RingRewardWeights::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_reward_weights: UncheckedAccount<'info>,
+
+    /// Rounding the accumulator credit leaves behind, created on first touch
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + RewardDust::INIT_SPACE,
+        seeds = [RewardDust::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub reward_dust: Box<Account<'info, RewardDust>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Collects one period of tax and distributes it to landowners like a claim's reward share.
+/// A cranker may only collect once the paid-up period has run out, so nobody can drain the
+/// owner's allowance by paying ahead for them.
+pub fn handler(ctx: Context<PayTax>, grid_id: u64, parcel_id: u16) -> Result<()> {
+    let district = &ctx.accounts.harberger_district;
+    let parcel = &ctx.accounts.parcel_info;
+    require!(district.enabled, BillionError::HarbergerDisabled);
+    require!(parcel.epoch == ctx.accounts.grid_config.epoch, BillionError::ParcelArchived);
+    require!(
+        district.contains(Rect::new(parcel.x, parcel.y, parcel.width, parcel.height)),
+        BillionError::OutsideDistrict
+    );
+
+    // A valuation declared by a previous owner doesn't bind the current one
+    let owner = get_core_asset_owner(&ctx.accounts.asset.to_account_info())?;
+    let valuation = &ctx.accounts.parcel_valuation;
+    require!(
+        owner == valuation.owner && valuation.valuation > 0,
+        BillionError::ValuationNotSet
+    );
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        valuation.tax_paid_until <= now || ctx.accounts.payer.key() == owner,
+        BillionError::TaxNotDue
+    );
+
+    let tax = district
+        .tax_per_period(valuation.valuation)
+        .ok_or(BillionError::Overflow)?;
+    let tax_period_secs = district.tax_period_secs;
+
+    if tax > 0 {
+        let bump = ctx.accounts.grid_config.bump;
+        let grid_bytes = grid_seed(grid_id);
+        let seeds: &[&[u8]] = &[GridConfig::SEED, &grid_bytes, &[bump]];
+        let signer_seeds: &[&[&[u8]]] = &[seeds];
+
+        let cpi_accounts = token_2022::TransferChecked {
+            from: ctx.accounts.owner_token_account.to_account_info(),
+            to: ctx.accounts.land_buy_reward_pool.to_account_info(),
+            authority: ctx.accounts.grid_config.to_account_info(),
+            mint: ctx.accounts.token_mint.to_account_info(),
+        };
+        token_2022::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            ),
+            tax,
+            ctx.accounts.token_mint.decimals,
+        )?;
+
+        let weights = RingRewardWeights::load(&ctx.accounts.ring_reward_weights)?;
+        let grid_config = &mut ctx.accounts.grid_config;
+        if grid_config.total_claimed_blocks > 0 {
+            let reward_units = weights
+                .reward_units(grid_config.epoch, grid_config.total_claimed_blocks)
+                .ok_or(BillionError::Overflow)?;
+            let increment = RingRewardWeights::reward_increase(tax, reward_units).ok_or(BillionError::Overflow)?;
+            grid_config.land_buy_rewards_per_block = grid_config
+                .land_buy_rewards_per_block
+                .checked_add(increment)
+                .ok_or(BillionError::Overflow)?;
+            grid_config.credit_land_buy_rewards(tax).ok_or(BillionError::Overflow)?;
+            let reward_dust = &mut ctx.accounts.reward_dust;
+            reward_dust.bump = ctx.bumps.reward_dust;
+            reward_dust.record(tax, increment, reward_units).ok_or(BillionError::Overflow)?;
+        }
+    }
+
+    // Paying early extends from the current expiry rather than from now
+    let valuation = &mut ctx.accounts.parcel_valuation;
+    valuation.tax_paid_until = valuation
+        .tax_paid_until
+        .max(now)
+        .checked_add(tax_period_secs)
+        .ok_or(BillionError::Overflow)?;
+
+    msg!(
+        "Parcel {} paid {} tax, current until {}",
+        parcel_id, tax, valuation.tax_paid_until
+    );
+    Ok(())
+}
@@ -0,0 +1,181 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022,
+    token_interface::{Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface},
+};
+use crate::state::{
+    epoch_seed, grid_seed, GridConfig, ParcelInfo, RewardDust, RingRewardWeights, RingUnlockSchedule,
+    LAND_BUY_REWARD_POOL_SEED,
+};
+use crate::errors::BillionError;
+use crate::events::{PremiumPurchased, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16)]
+pub struct PurchasePremium<'info> {
+    /// Pays the fee and the RewardDust on first touch; need not own the parcel
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        mut,
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = payer,
+        token::token_program = token_program,
+    )]
+    pub payer_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    /// Land buy reward pool receiving the reward share of the fee
+    #[account(
+        mut,
+        seeds = [LAND_BUY_REWARD_POOL_SEED, grid_config.key().as_ref()],
+        bump,
+        constraint = land_buy_reward_pool.key() == grid_config.land_buy_reward_pool @ BillionError::InvalidRewardPool
+    )]
+    pub land_buy_reward_pool: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    #[account(
+        mut,
+        constraint = token_mint.key() == grid_config.token_mint @ BillionError::Unauthorized
+    )]
+    pub token_mint: InterfaceAccount<'info, InterfaceMint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Per-ring land-buy reward weights the reward share is spread by; every block is unweighted while
+    /// uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [RingRewardWeights::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_reward_weights: UncheckedAccount<'info>,
+
+    /// Times rings open whatever has been burned; rings unlock by burns alone while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [RingUnlockSchedule::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_unlock_schedule: UncheckedAccount<'info>,
+
+    /// Rounding the accumulator credit leaves behind, created on first touch
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + RewardDust::INIT_SPACE,
+        seeds = [RewardDust::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub reward_dust: Box<Account<'info, RewardDust>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Charges `periods` premium periods and extends the parcel's badge. The fee is split like a
+/// claim's cost: land_owners_reward_share_bps to landowners, the rest burned.
+pub fn handler(ctx: Context<PurchasePremium>, _grid_id: u64, parcel_id: u16, periods: u32) -> Result<()> {
+    let grid_config = &ctx.accounts.grid_config;
+    require!(grid_config.premium_price_per_period > 0, BillionError::PremiumDisabled);
+    require!(periods > 0, BillionError::InvalidPremiumPeriods);
+    require!(ctx.accounts.parcel_info.epoch == grid_config.epoch, BillionError::ParcelArchived);
+
+    let now = Clock::get()?.unix_timestamp;
+    let premium_until = ctx
+        .accounts
+        .parcel_info
+        .extended_premium_until(now, periods, grid_config.premium_period_secs)
+        .ok_or(BillionError::Overflow)?;
+
+    let fee = grid_config
+        .premium_price_per_period
+        .checked_mul(periods as u64)
+        .ok_or(BillionError::Overflow)?;
+    let reward_amount = fee
+        .checked_mul(grid_config.land_owners_reward_share_bps as u64)
+        .ok_or(BillionError::Overflow)?
+        / 10_000;
+    let burn_amount = fee - reward_amount;
+    require!(ctx.accounts.payer_token_account.amount >= fee, BillionError::InsufficientBalance);
+
+    if reward_amount > 0 {
+        token_2022::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token_2022::TransferChecked {
+                    from: ctx.accounts.payer_token_account.to_account_info(),
+                    to: ctx.accounts.land_buy_reward_pool.to_account_info(),
+                    authority: ctx.accounts.payer.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                },
+            ),
+            reward_amount,
+            ctx.accounts.token_mint.decimals,
+        )?;
+    }
+    if burn_amount > 0 {
+        token_2022::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token_2022::Burn {
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    from: ctx.accounts.payer_token_account.to_account_info(),
+                    authority: ctx.accounts.payer.to_account_info(),
+                },
+            ),
+            burn_amount,
+        )?;
+    }
+
+    let weights = RingRewardWeights::load(&ctx.accounts.ring_reward_weights)?;
+    let schedule = RingUnlockSchedule::load(&ctx.accounts.ring_unlock_schedule)?;
+    let grid_config = &mut ctx.accounts.grid_config;
+    if grid_config.total_claimed_blocks > 0 && reward_amount > 0 {
+        let reward_units = weights
+            .reward_units(grid_config.epoch, grid_config.total_claimed_blocks)
+            .ok_or(BillionError::Overflow)?;
+        let increment = RingRewardWeights::reward_increase(reward_amount, reward_units).ok_or(BillionError::Overflow)?;
+        grid_config.land_buy_rewards_per_block = grid_config
+            .land_buy_rewards_per_block
+            .checked_add(increment)
+            .ok_or(BillionError::Overflow)?;
+        grid_config.credit_land_buy_rewards(reward_amount).ok_or(BillionError::Overflow)?;
+        let reward_dust = &mut ctx.accounts.reward_dust;
+        reward_dust.bump = ctx.bumps.reward_dust;
+        reward_dust.record(reward_amount, increment, reward_units).ok_or(BillionError::Overflow)?;
+    }
+
+    // Burned fees count toward ring unlocks like a claim's burn
+    let previously_unlocked = grid_config.unlocked_ring_at(&schedule, now);
+    grid_config.total_burned = grid_config
+        .total_burned
+        .checked_add(burn_amount)
+        .ok_or(BillionError::Overflow)?;
+    let unlocked = grid_config.unlocked_ring_at(&schedule, now);
+    grid_config.record_ring_unlock(previously_unlocked, unlocked, now);
+
+    ctx.accounts.parcel_info.premium_until = premium_until;
+
+    emit!(PremiumPurchased {
+        schema_version: EVENT_SCHEMA_VERSION,
+        parcel_id,
+        epoch: ctx.accounts.parcel_info.epoch,
+        payer: ctx.accounts.payer.key(),
+        periods,
+        fee,
+        reward_amount,
+        burn_amount,
+        premium_until,
+    });
+
+    msg!("Parcel {} premium until {} for {}", parcel_id, premium_until, fee);
+    Ok(())
+}
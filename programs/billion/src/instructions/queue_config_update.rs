@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    grid_seed, AdminRoles, ConfigTimelock, GridConfig, TimelockedConfig, TokenTreasury, ConfigFreeze, ReferralShare,
+    RingPricing,
+};
+use crate::errors::BillionError;
+use crate::events::{ConfigUpdateQueued, EVENT_SCHEMA_VERSION};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct QueueConfigUpdate<'info> {
+    /// The config authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// Names the config authority; the main authority holds every role until it's created
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [AdminRoles::SEED, &grid_seed(grid_id)], bump)]
+    pub admin_roles: UncheckedAccount<'info>,
+
+    /// Created without a timelock on the first queued change
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ConfigTimelock::INIT_SPACE,
+        seeds = [ConfigTimelock::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub config_timelock: Account<'info, ConfigTimelock>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Treasury share the landowner share must leave room for, with the referral and charity shares
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [TokenTreasury::SEED, &grid_seed(grid_id)], bump)]
+    pub token_treasury: UncheckedAccount<'info>,
+
+    /// Direct referral share the landowner share must leave room for
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [ReferralShare::SEED, &grid_seed(grid_id)], bump)]
+    pub referral_share: UncheckedAccount<'info>,
+
+    /// Must still be empty: freeze_config creating it disables this instruction for good
+    /// CHECK: Seeds are verified here, only whether it exists is read
+    #[account(
+        seeds = [ConfigFreeze::SEED, &grid_seed(grid_id)],
+        bump,
+        constraint = !ConfigFreeze::is_frozen(&config_freeze) @ BillionError::ConfigFrozen
+    )]
+    pub config_freeze: UncheckedAccount<'info>,
+}
+
+/// Queues `change` for execute_config_update once config_timelock_secs have passed. Only
+/// one change can be queued; cancel_config_update drops it to queue another.
+pub fn handler(ctx: Context<QueueConfigUpdate>, _grid_id: u64, change: TimelockedConfig) -> Result<()> {
+    let roles = AdminRoles::load(&ctx.accounts.admin_roles, &ctx.accounts.grid_config)?;
+    require_keys_eq!(roles.config_authority, ctx.accounts.authority.key(), BillionError::Unauthorized);
+    if let Some(bps) = change.land_owners_reward_share_bps {
+        let config = &ctx.accounts.grid_config;
+        let treasury_bps = TokenTreasury::load(&ctx.accounts.token_treasury)?.share_bps;
+        let referral_bps = ReferralShare::load(&ctx.accounts.referral_share)?.with_referral_bps(config.referral_bps);
+        require!(
+            GridConfig::valid_split_bps(bps, treasury_bps, referral_bps, config.max_charity_bps),
+            BillionError::InvalidRewardShare
+        );
+    }
+    if let Some(thresholds) = &change.ring_thresholds {
+        GridConfig::check_ring_thresholds(thresholds, ctx.accounts.grid_config.ring_count())?;
+    }
+    if let Some(secs) = change.config_timelock_secs {
+        require!(secs >= 0, BillionError::InvalidConfigTimelock);
+    }
+    if let Some(multipliers) = &change.ring_price_multipliers_bps {
+        require!(
+            RingPricing::valid_multipliers(multipliers, ctx.accounts.grid_config.ring_count()),
+            BillionError::InvalidRingMultipliers
+        );
+    }
+
+    let timelock = &mut ctx.accounts.config_timelock;
+    timelock.bump = ctx.bumps.config_timelock;
+    timelock.queue(&change, Clock::get()?.unix_timestamp)?;
+
+    emit!(ConfigUpdateQueued {
+        schema_version: EVENT_SCHEMA_VERSION,
+        change_hash: timelock.pending_hash,
+        ready_at: timelock.ready_at,
+    });
+    msg!("Queued config update, ready at {}", timelock.ready_at);
+    Ok(())
+}
@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022,
+    token_interface::{Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface},
+    associated_token::AssociatedToken,
+};
+use mpl_core::instructions::TransferV1CpiBuilder;
+use crate::state::{epoch_seed, grid_seed, Fraction, GridConfig, ParcelInfo};
+use crate::errors::BillionError;
+#[cfg(not(feature = "localnet"))]
+use crate::instructions::claim_parcel::MPL_CORE_ID;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16)]
+pub struct RedeemParcel<'info> {
+    /// Must hold every share in their wallet (none deposited)
+    #[account(mut)]
+    pub redeemer: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Box<Account<'info, GridConfig>>,
+
+    #[account(
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Box<Account<'info, ParcelInfo>>,
+
+    #[account(
+        mut,
+        close = redeemer,
+        seeds = [Fraction::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = fraction.bump,
+        has_one = share_mint @ BillionError::Unauthorized,
+        has_one = asset @ BillionError::AssetMismatch,
+    )]
+    pub fraction: Box<Account<'info, Fraction>>,
+
+    #[account(mut)]
+    pub share_mint: Box<InterfaceAccount<'info, InterfaceMint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = share_mint,
+        associated_token::authority = redeemer,
+        associated_token::token_program = token_program,
+    )]
+    pub redeemer_share_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [Fraction::ESCROW_SEED, share_mint.key().as_ref()],
+        bump
+    )]
+    pub share_escrow: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [Fraction::VAULT_SEED, share_mint.key().as_ref()],
+        bump
+    )]
+    pub reward_vault: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    /// Receives whatever is left in the reward vault
+    #[account(
+        init_if_needed,
+        payer = redeemer,
+        associated_token::mint = token_mint,
+        associated_token::authority = redeemer,
+        associated_token::token_program = token_program,
+    )]
+    pub redeemer_token_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    #[account(
+        constraint = token_mint.key() == grid_config.token_mint @ BillionError::Unauthorized
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, InterfaceMint>>,
+
+    /// The escrowed Metaplex Core asset
+    /// CHECK: Validated against fraction.asset and by the Metaplex Core program
+    #[account(mut)]
+    pub asset: UncheckedAccount<'info>,
+
+    /// Core collection - must match grid_config.collection
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        mut,
+        constraint = collection.key() == grid_config.collection @ BillionError::InvalidCollection
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Burns the entire share supply and returns the asset to the redeemer, along with any
+/// rewards left in the vault. Land-buy rewards not yet harvested stay claimable by the new
+/// owner through claim_land_buy_rewards.
+pub fn handler(ctx: Context<RedeemParcel>, grid_id: u64, parcel_id: u16) -> Result<()> {
+    let share_supply = ctx.accounts.fraction.share_supply;
+    require!(
+        ctx.accounts.redeemer_share_account.amount >= share_supply,
+        BillionError::InsufficientBalance
+    );
+
+    token_2022::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_2022::Burn {
+                mint: ctx.accounts.share_mint.to_account_info(),
+                from: ctx.accounts.redeemer_share_account.to_account_info(),
+                authority: ctx.accounts.redeemer.to_account_info(),
+            },
+        ),
+        share_supply,
+    )?;
+
+    let grid_bytes = grid_seed(grid_id);
+
+    let epoch_bytes = epoch_seed(ctx.accounts.fraction.epoch);
+    let parcel_id_bytes = parcel_id.to_le_bytes();
+    let fraction_seeds: &[&[u8]] = &[Fraction::SEED, &grid_bytes, &epoch_bytes, &parcel_id_bytes, &[ctx.accounts.fraction.bump]];
+    let signer_seeds: &[&[&[u8]]] = &[fraction_seeds];
+
+    // Rounding dust and rewards harvested while nothing was deposited
+    let leftover = ctx.accounts.reward_vault.amount;
+    if leftover > 0 {
+        token_2022::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_2022::TransferChecked {
+                    from: ctx.accounts.reward_vault.to_account_info(),
+                    to: ctx.accounts.redeemer_token_account.to_account_info(),
+                    authority: ctx.accounts.fraction.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            leftover,
+            ctx.accounts.token_mint.decimals,
+        )?;
+    }
+
+    // The escrow is empty: every share was just burned from the redeemer's wallet
+    for account in [ctx.accounts.reward_vault.to_account_info(), ctx.accounts.share_escrow.to_account_info()] {
+        token_2022::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_2022::CloseAccount {
+                account,
+                destination: ctx.accounts.redeemer.to_account_info(),
+                authority: ctx.accounts.fraction.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+    }
+
+    TransferV1CpiBuilder::new(&ctx.accounts.mpl_core_program.to_account_info())
+        .asset(&ctx.accounts.asset.to_account_info())
+        .collection(Some(&ctx.accounts.collection.to_account_info()))
+        .payer(&ctx.accounts.redeemer.to_account_info())
+        .authority(Some(&ctx.accounts.fraction.to_account_info()))
+        .new_owner(&ctx.accounts.redeemer.to_account_info())
+        .system_program(Some(&ctx.accounts.system_program.to_account_info()))
+        .invoke_signed(signer_seeds)?;
+
+    msg!(
+        "Parcel {} redeemed by {}, {} shares burned, {} rewards swept",
+        parcel_id,
+        ctx.accounts.redeemer.key(),
+        share_supply,
+        leftover
+    );
+    Ok(())
+}
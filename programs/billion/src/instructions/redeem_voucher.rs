@@ -0,0 +1,233 @@
+use anchor_lang::prelude::*;
+use mpl_core::instructions::CreateV2CpiBuilder;
+use billion_core::Rect;
+use crate::state::{
+    epoch_seed, grid_seed, GridConfig, BlockMap, ParcelInfo, Voucher, RingRewardWeights, RingOccupancy, MapDigest,
+    RingUnlockSchedule,
+};
+use crate::errors::BillionError;
+use crate::instructions::claim_parcel::{validate_claim, verify_adjacency_proof};
+use crate::instructions::refresh_parcel_attributes::parcel_attributes_plugin;
+use crate::instructions::admin_backfill_ring_occupancy::record_ring_occupancy;
+use crate::instructions::init_map_digest::record_map_write;
+#[cfg(not(feature = "localnet"))]
+use crate::instructions::claim_parcel::MPL_CORE_ID;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct RedeemVoucher<'info> {
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    /// Paid for the voucher, so gets its rent back
+    /// CHECK: Only credited, and must match grid_config.authority
+    #[account(
+        mut,
+        address = grid_config.authority @ BillionError::Unauthorized
+    )]
+    pub authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// BlockMap address must match the one stored in grid_config
+    #[account(
+        mut,
+        constraint = block_map.key() == grid_config.block_map @ BillionError::Unauthorized
+    )]
+    pub block_map: AccountLoader<'info, BlockMap>,
+
+    /// Consumed by the redemption
+    #[account(
+        mut,
+        close = authority,
+        seeds = [Voucher::SEED, &grid_seed(grid_id), beneficiary.key().as_ref(), &voucher.voucher_id.to_le_bytes()],
+        bump = voucher.bump,
+        has_one = beneficiary @ BillionError::Unauthorized,
+    )]
+    pub voucher: Account<'info, Voucher>,
+
+    /// Parcel info PDA - stores asset address for lookups
+    #[account(
+        init,
+        payer = beneficiary,
+        space = 8 + ParcelInfo::INIT_SPACE,
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(grid_config.epoch), &grid_config.next_parcel_id.to_le_bytes()],
+        bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+
+    /// New Core asset - must be a signer (keypair generated client-side)
+    #[account(mut)]
+    pub asset: Signer<'info>,
+
+    /// Core collection - must match grid_config.collection
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        mut,
+        constraint = collection.key() == grid_config.collection @ BillionError::InvalidCollection
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Per-ring land-buy reward weights, which count the parcel's extra weight; every block
+    /// is unweighted while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(mut, seeds = [RingRewardWeights::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_reward_weights: UncheckedAccount<'info>,
+
+    /// Claimed blocks per ring, which count the parcel's blocks; nothing is counted until
+    /// it's created
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(mut, seeds = [RingOccupancy::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_occupancy: UncheckedAccount<'info>,
+
+    /// Rolling digest of BlockMap writes, which chains in the parcel's blocks
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(mut, seeds = [MapDigest::SEED, &grid_seed(grid_id)], bump)]
+    pub map_digest: UncheckedAccount<'info>,
+
+    /// Times rings open whatever has been burned; rings unlock by burns alone while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [RingUnlockSchedule::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_unlock_schedule: UncheckedAccount<'info>,
+}
+
+/// Claims a parcel for free under the voucher's size, ring and expiry limits, on top of the
+/// regular claim checks. As with claim_parcel, remaining_accounts carry the adjacency proof
+/// during an expansion priority window.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, RedeemVoucher<'info>>,
+    grid_id: u64,
+    x: u8,
+    y: u8,
+    width: u8,
+    height: u8,
+) -> Result<()> {
+    let grid_config = &ctx.accounts.grid_config;
+    let ring_count = grid_config.ring_count();
+    require!(
+        grid_config.collection != Pubkey::default(),
+        BillionError::CollectionNotSet
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let voucher = &ctx.accounts.voucher;
+    require!(!voucher.is_expired(now), BillionError::VoucherExpired);
+
+    let schedule = RingUnlockSchedule::load(&ctx.accounts.ring_unlock_schedule)?;
+    let unlocked_ring = grid_config.unlocked_ring_at(&schedule, now);
+    let grid_size = {
+        let block_map = BlockMap::load(&ctx.accounts.block_map)?;
+        require!(!block_map.is_frozen(), BillionError::EpochFrozen);
+        validate_claim(x, y, width, height, &block_map, grid_config, unlocked_ring)?;
+        block_map.grid_size()
+    };
+    require!(voucher.allows(x, y, width, height, grid_size, ring_count), BillionError::VoucherConstraintViolated);
+
+    let rect = Rect::new(x, y, width, height);
+    if grid_config.requires_adjacency_proof(rect, now, grid_size) {
+        let proof = ctx.remaining_accounts.get(..2).ok_or(BillionError::AdjacencyProofRequired)?;
+        verify_adjacency_proof(proof, ctx.accounts.beneficiary.key(), rect, grid_config.epoch, grid_size)?;
+    }
+
+    let num_blocks = (width as u32).checked_mul(height as u32).ok_or(BillionError::Overflow)?;
+    require!(grid_config.has_parcel_ids(1), BillionError::ParcelIdExhausted);
+    let parcel_id = grid_config.next_parcel_id;
+
+    // Nothing is paid or burned, so only the counters move
+    let grid_config = &mut ctx.accounts.grid_config;
+    grid_config.total_claimed_blocks = grid_config
+        .total_claimed_blocks
+        .checked_add(num_blocks)
+        .ok_or(BillionError::Overflow)?;
+    grid_config.next_parcel_id = grid_config
+        .next_parcel_id
+        .checked_add(1)
+        .ok_or(BillionError::Overflow)?;
+
+    let uri_base = grid_config.uri_base.clone();
+    let current_rewards_per_block = grid_config.land_buy_rewards_per_block;
+    let epoch = grid_config.epoch;
+    let collection_generation = grid_config.collection_generation;
+
+    {
+        let mut block_map = BlockMap::load_mut(&ctx.accounts.block_map)?;
+        for (block_x, block_y) in rect.blocks() {
+            block_map.set_block(block_x, block_y, parcel_id);
+        }
+    }
+
+    let bump = ctx.accounts.grid_config.bump;
+    let grid_bytes = grid_seed(grid_id);
+    let seeds: &[&[u8]] = &[GridConfig::SEED, &grid_bytes, &[bump]];
+    CreateV2CpiBuilder::new(&ctx.accounts.mpl_core_program.to_account_info())
+        .asset(&ctx.accounts.asset.to_account_info())
+        .collection(Some(&ctx.accounts.collection.to_account_info()))
+        .authority(Some(&ctx.accounts.grid_config.to_account_info()))
+        .payer(&ctx.accounts.beneficiary.to_account_info())
+        .owner(Some(&ctx.accounts.beneficiary.to_account_info()))
+        .system_program(&ctx.accounts.system_program.to_account_info())
+        .name(format!("Parcel #{}", parcel_id))
+        .uri(format!("{}{}", uri_base, parcel_id))
+        .plugins(vec![parcel_attributes_plugin(
+            ctx.accounts.grid_config.key(),
+            parcel_id,
+            x,
+            y,
+            width,
+            height,
+        )])
+        .invoke_signed(&[seeds])?;
+
+    let weights = RingRewardWeights::load(&ctx.accounts.ring_reward_weights)?;
+    let parcel_info = &mut ctx.accounts.parcel_info;
+    parcel_info.asset = ctx.accounts.asset.key();
+    parcel_info.x = x;
+    parcel_info.y = y;
+    parcel_info.width = width;
+    parcel_info.height = height;
+    parcel_info.bump = ctx.bumps.parcel_info;
+    parcel_info.last_claimed_land_buy_rewards_per_block = current_rewards_per_block;
+    parcel_info.epoch = epoch;
+    parcel_info.last_claimed_at = now;
+    parcel_info.adjacency_score = 0;
+    parcel_info.locked_until = 0;
+    parcel_info.lock_rewards = false;
+    parcel_info.named = false;
+    parcel_info.premium_until = 0;
+    parcel_info.collection_generation = collection_generation;
+    parcel_info.masked_block_count = 0;
+    parcel_info.reserved = false;
+    parcel_info.rewards_excluded = false;
+    parcel_info.paid_total = 0;
+    parcel_info.claimed_at = ParcelInfo::claim_timestamp(now);
+    parcel_info.reward_weight =
+        weights.parcel_weight(rect.blocks(), grid_size, ring_count).ok_or(BillionError::Overflow)?;
+    parcel_info._reserved = [0u8; 0];
+    RingRewardWeights::record_extra(&ctx.accounts.ring_reward_weights, epoch, parcel_info.extra_weight(num_blocks))?;
+    record_ring_occupancy(&ctx.accounts.ring_occupancy, epoch, rect.blocks(), grid_size, ring_count, true)?;
+    record_map_write(&ctx.accounts.map_digest, epoch, parcel_id, rect)?;
+
+    msg!(
+        "Voucher {} redeemed by {} for parcel {} at ({}, {}) with dimensions {}x{}",
+        ctx.accounts.voucher.voucher_id,
+        ctx.accounts.beneficiary.key(),
+        parcel_id,
+        x,
+        y,
+        width,
+        height
+    );
+    Ok(())
+}
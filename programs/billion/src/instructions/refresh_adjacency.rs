@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use billion_core::Rect;
+use crate::state::{epoch_seed, grid_seed, BlockMap, GridConfig, ParcelInfo, RESERVED_BLOCK};
+use crate::errors::BillionError;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16)]
+pub struct RefreshAdjacency<'info> {
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        constraint = block_map.key() == grid_config.block_map @ BillionError::Unauthorized
+    )]
+    pub block_map: AccountLoader<'info, BlockMap>,
+
+    #[account(
+        mut,
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+}
+
+/// Permissionless: recounts the distinct parcels bordering `parcel_id` in the current
+/// BlockMap; reserved blocks aren't parcels. Scores aren't updated on neighbor claims, so a stale score only means a
+/// smaller bonus until someone refreshes it.
+pub fn handler(ctx: Context<RefreshAdjacency>, _grid_id: u64, parcel_id: u16) -> Result<()> {
+    let parcel_info = &mut ctx.accounts.parcel_info;
+    require!(
+        parcel_info.epoch == ctx.accounts.grid_config.epoch,
+        BillionError::ParcelArchived
+    );
+
+    let rect = Rect::new(parcel_info.x, parcel_info.y, parcel_info.width, parcel_info.height);
+    let block_map = BlockMap::load(&ctx.accounts.block_map)?;
+    let mut neighbors: Vec<u16> = rect
+        .edge_neighbors(block_map.grid_size())
+        .map(|(x, y)| block_map.get_block(x, y))
+        .filter(|&id| id != 0 && id != parcel_id && id != RESERVED_BLOCK)
+        .collect();
+    neighbors.sort_unstable();
+    neighbors.dedup();
+
+    let previous = parcel_info.adjacency_score;
+    parcel_info.adjacency_score = u8::try_from(neighbors.len()).unwrap_or(u8::MAX);
+
+    msg!(
+        "Parcel {} adjacency score {} -> {}",
+        parcel_id,
+        previous,
+        parcel_info.adjacency_score
+    );
+    Ok(())
+}
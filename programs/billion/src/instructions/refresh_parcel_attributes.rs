@@ -0,0 +1,208 @@
+use anchor_lang::prelude::*;
+use mpl_core::accounts::BaseAssetV1;
+use mpl_core::fetch_plugin;
+use mpl_core::instructions::{AddPluginV1CpiBuilder, UpdatePluginV1CpiBuilder};
+use mpl_core::types::{Attribute, Attributes, Plugin, PluginAuthority, PluginAuthorityPair, PluginType};
+use crate::state::{epoch_seed, grid_seed, GridConfig, ParcelContent, ParcelInfo};
+use crate::errors::BillionError;
+#[cfg(not(feature = "localnet"))]
+use crate::instructions::claim_parcel::MPL_CORE_ID;
+
+/// Key/value attributes marketplaces show for a parcel, read straight off the asset
+pub(crate) fn parcel_attributes(parcel_id: u16, x: u8, y: u8, width: u8, height: u8) -> Attributes {
+    let attribute = |key: &str, value: String| Attribute {
+        key: key.to_string(),
+        value,
+    };
+    Attributes {
+        attribute_list: vec![
+            attribute("parcel_id", parcel_id.to_string()),
+            attribute("x", x.to_string()),
+            attribute("y", y.to_string()),
+            attribute("width", width.to_string()),
+            attribute("height", height.to_string()),
+        ],
+    }
+}
+
+/// Appends the hex `content_hash` of a parcel's art to its attributes, unless it's cleared
+pub(crate) fn with_content_hash(mut attributes: Attributes, content_hash: &[u8; 32]) -> Attributes {
+    if *content_hash != [0; 32] {
+        attributes.attribute_list.push(Attribute {
+            key: "content_hash".to_string(),
+            value: content_hash.iter().map(|b| format!("{:02x}", b)).collect(),
+        });
+    }
+    attributes
+}
+
+/// The Attributes plugin every parcel is minted with, managed by the GridConfig PDA so only
+/// the program can change it
+pub(crate) fn parcel_attributes_plugin(
+    grid_config: Pubkey,
+    parcel_id: u16,
+    x: u8,
+    y: u8,
+    width: u8,
+    height: u8,
+) -> PluginAuthorityPair {
+    PluginAuthorityPair {
+        plugin: Plugin::Attributes(parcel_attributes(parcel_id, x, y, width, height)),
+        authority: Some(PluginAuthority::Address { address: grid_config }),
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16)]
+pub struct RefreshParcelAttributes<'info> {
+    /// Anyone may crank this; they only pay for any plugin space
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+
+    /// Hash of the parcel's art, kept among the attributes; none while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(
+        seeds = [ParcelContent::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump
+    )]
+    pub parcel_content: UncheckedAccount<'info>,
+
+    /// The Metaplex Core asset - must match parcel_info.asset
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        mut,
+        constraint = asset.key() == parcel_info.asset @ BillionError::AssetMismatch
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    /// Core collection - must match grid_config.collection
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        mut,
+        constraint = collection.key() == grid_config.collection @ BillionError::InvalidCollection
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Rewrites the asset's Attributes plugin from ParcelInfo and ParcelContent, adding it to
+/// parcels minted before assets carried one. Anything that later moves or resizes a parcel
+/// calls this path so the on-chain attributes keep matching ParcelInfo.
+pub fn handler(ctx: Context<RefreshParcelAttributes>, grid_id: u64, parcel_id: u16) -> Result<()> {
+    let parcel_info = &ctx.accounts.parcel_info;
+    let content = ParcelContent::load(&ctx.accounts.parcel_content)?;
+    let attributes = with_content_hash(
+        parcel_attributes(parcel_id, parcel_info.x, parcel_info.y, parcel_info.width, parcel_info.height),
+        &content.content_hash,
+    );
+    write_parcel_attributes(
+        grid_id,
+        &ctx.accounts.grid_config,
+        &ctx.accounts.asset,
+        &ctx.accounts.collection,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.mpl_core_program,
+        &ctx.accounts.system_program,
+        attributes,
+    )?;
+
+    msg!("Refreshed attributes of parcel {}", parcel_id);
+    Ok(())
+}
+
+/// Replaces `asset`'s Attributes plugin with `attributes`, signed by the GridConfig PDA
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn write_parcel_attributes<'info>(
+    grid_id: u64,
+    grid_config: &Account<'info, GridConfig>,
+    asset: &UncheckedAccount<'info>,
+    collection: &UncheckedAccount<'info>,
+    payer: &AccountInfo<'info>,
+    mpl_core_program: &UncheckedAccount<'info>,
+    system_program: &Program<'info, System>,
+    attributes: Attributes,
+) -> Result<()> {
+    let mpl_core_program = mpl_core_program.to_account_info();
+    let asset = asset.to_account_info();
+    let collection = collection.to_account_info();
+    let grid_config_key = grid_config.key();
+    let bump = grid_config.bump;
+    let grid_config = grid_config.to_account_info();
+    let system_program = system_program.to_account_info();
+    let grid_bytes = grid_seed(grid_id);
+    let seeds: &[&[u8]] = &[GridConfig::SEED, &grid_bytes, &[bump]];
+
+    let existing = fetch_plugin::<BaseAssetV1, Attributes>(&asset, PluginType::Attributes).ok();
+    if existing.is_some() {
+        UpdatePluginV1CpiBuilder::new(&mpl_core_program)
+            .asset(&asset)
+            .collection(Some(&collection))
+            .payer(payer)
+            .authority(Some(&grid_config))
+            .system_program(&system_program)
+            .plugin(Plugin::Attributes(attributes))
+            .invoke_signed(&[seeds])?;
+    } else {
+        // The GridConfig PDA is the collection's update authority, so it may add the plugin
+        AddPluginV1CpiBuilder::new(&mpl_core_program)
+            .asset(&asset)
+            .collection(Some(&collection))
+            .payer(payer)
+            .authority(Some(&grid_config))
+            .system_program(&system_program)
+            .plugin(Plugin::Attributes(attributes))
+            .init_authority(PluginAuthority::Address { address: grid_config_key })
+            .invoke_signed(&[seeds])?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parcel_attributes() {
+        let attributes = parcel_attributes(7, 10, 20, 3, 2);
+        let pairs: Vec<(&str, &str)> = attributes
+            .attribute_list
+            .iter()
+            .map(|attribute| (attribute.key.as_str(), attribute.value.as_str()))
+            .collect();
+        assert_eq!(
+            pairs,
+            [("parcel_id", "7"), ("x", "10"), ("y", "20"), ("width", "3"), ("height", "2")]
+        );
+    }
+
+    #[test]
+    fn test_content_hash_attribute() {
+        assert_eq!(with_content_hash(parcel_attributes(7, 10, 20, 3, 2), &[0; 32]).attribute_list.len(), 5);
+
+        let mut content_hash = [0; 32];
+        content_hash[0] = 0xab;
+        content_hash[31] = 0x01;
+        let attributes = with_content_hash(parcel_attributes(7, 10, 20, 3, 2), &content_hash);
+        let last = attributes.attribute_list.last().unwrap();
+        assert_eq!(last.key, "content_hash");
+        assert_eq!(last.value, format!("ab{}01", "0".repeat(60)));
+    }
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use mpl_core::instructions::UpdateV1CpiBuilder;
+use crate::state::{epoch_seed, grid_seed, is_valid_name, normalize_name, GridConfig, NameRecord, ParcelInfo};
+use crate::errors::BillionError;
+use crate::instructions::claim_land_buy_rewards::get_core_asset_owner;
+#[cfg(not(feature = "localnet"))]
+use crate::instructions::claim_parcel::MPL_CORE_ID;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16, name: String)]
+pub struct RegisterParcelName<'info> {
+    /// Must own the parcel's asset
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        mut,
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+
+    /// init_if_needed so a taken name fails with NameTaken rather than a system error
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + NameRecord::INIT_SPACE,
+        seeds = [NameRecord::SEED, &grid_seed(grid_id), &NameRecord::name_hash(&name)],
+        bump
+    )]
+    pub name_record: Account<'info, NameRecord>,
+
+    /// The Metaplex Core asset - must match parcel_info.asset
+    /// CHECK: Validated by constraint, ownership checked in handler
+    #[account(
+        mut,
+        constraint = asset.key() == parcel_info.asset @ BillionError::AssetMismatch
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    /// Core collection - must match grid_config.collection
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        constraint = collection.key() == grid_config.collection @ BillionError::InvalidCollection
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Claims `name` for the parcel and writes it to the asset's Core metadata. A parcel holds
+/// at most one name; release it before registering another.
+pub fn handler(ctx: Context<RegisterParcelName>, grid_id: u64, parcel_id: u16, name: String) -> Result<()> {
+    let name = normalize_name(&name);
+    require!(is_valid_name(&name), BillionError::InvalidName);
+    require!(
+        ctx.accounts.name_record.registrant == Pubkey::default(),
+        BillionError::NameTaken
+    );
+    require!(!ctx.accounts.parcel_info.named, BillionError::ParcelAlreadyNamed);
+    let owner = get_core_asset_owner(&ctx.accounts.asset.to_account_info())?;
+    require!(owner == ctx.accounts.owner.key(), BillionError::NotOwner);
+
+    let bump = ctx.accounts.grid_config.bump;
+    UpdateV1CpiBuilder::new(&ctx.accounts.mpl_core_program.to_account_info())
+        .asset(&ctx.accounts.asset.to_account_info())
+        .collection(Some(&ctx.accounts.collection.to_account_info()))
+        .authority(Some(&ctx.accounts.grid_config.to_account_info()))
+        .payer(&ctx.accounts.owner.to_account_info())
+        .system_program(&ctx.accounts.system_program.to_account_info())
+        .new_name(name.clone())
+        .invoke_signed(&[&[GridConfig::SEED, &grid_seed(grid_id), &[bump]]])?;
+
+    let parcel_info = &mut ctx.accounts.parcel_info;
+    parcel_info.named = true;
+
+    let record = &mut ctx.accounts.name_record;
+    record.name = name;
+    record.parcel_id = parcel_id;
+    record.epoch = parcel_info.epoch;
+    record.asset = parcel_info.asset;
+    record.registrant = owner;
+    record.registered_at = Clock::get()?.unix_timestamp;
+    record.bump = ctx.bumps.name_record;
+
+    msg!("Parcel {} registered as {}", parcel_id, record.name);
+    Ok(())
+}
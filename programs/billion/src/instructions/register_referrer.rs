@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{grid_seed, ReferrerAccount};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct RegisterReferrer<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = referrer,
+        space = 8 + ReferrerAccount::INIT_SPACE,
+        seeds = [ReferrerAccount::SEED, &grid_seed(grid_id), referrer.key().as_ref()],
+        bump
+    )]
+    pub referrer_account: Account<'info, ReferrerAccount>,
+
+    /// Referrer who introduced this wallet, if any
+    #[account(
+        seeds = [ReferrerAccount::SEED, &grid_seed(grid_id), referred_by.referrer.as_ref()],
+        bump = referred_by.bump
+    )]
+    pub referred_by: Option<Account<'info, ReferrerAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RegisterReferrer>, _grid_id: u64) -> Result<()> {
+    let referrer = ctx.accounts.referrer.key();
+
+    // An existing referrer can never point back at a wallet that is only now registering,
+    // so self and circular links are ruled out here by construction
+    let referred_by = ctx
+        .accounts
+        .referred_by
+        .as_ref()
+        .map_or(Pubkey::default(), |referred_by| referred_by.referrer);
+
+    let referrer_account = &mut ctx.accounts.referrer_account;
+    referrer_account.referrer = referrer;
+    referrer_account.referred_by = referred_by;
+    referrer_account.accrued = 0;
+    referrer_account.total_earned = 0;
+    referrer_account.referral_count = 0;
+    referrer_account.referred_blocks = 0;
+    referrer_account.bump = ctx.bumps.referrer_account;
+    referrer_account._reserved = [0u8; 32];
+
+    msg!("Registered referrer {}", referrer);
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use mpl_core::instructions::UpdateV1CpiBuilder;
+use crate::state::{epoch_seed, grid_seed, GridConfig, NameRecord, ParcelInfo};
+use crate::errors::BillionError;
+use crate::instructions::claim_land_buy_rewards::get_core_asset_owner;
+#[cfg(not(feature = "localnet"))]
+use crate::instructions::claim_parcel::MPL_CORE_ID;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, name: String)]
+pub struct ReleaseParcelName<'info> {
+    /// The registrant, or anyone once the registrant no longer owns the asset
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        mut,
+        close = registrant,
+        seeds = [NameRecord::SEED, &grid_seed(grid_id), &NameRecord::name_hash(&name)],
+        bump = name_record.bump,
+        has_one = registrant @ BillionError::Unauthorized,
+        has_one = asset @ BillionError::AssetMismatch,
+    )]
+    pub name_record: Account<'info, NameRecord>,
+
+    /// Receives the record's rent
+    /// CHECK: Validated against name_record.registrant
+    #[account(mut)]
+    pub registrant: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(name_record.epoch), &name_record.parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+
+    /// The named Metaplex Core asset
+    /// CHECK: Validated against name_record.asset, owner parsed in handler
+    #[account(mut)]
+    pub asset: UncheckedAccount<'info>,
+
+    /// Core collection - must match grid_config.collection
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        constraint = collection.key() == grid_config.collection @ BillionError::InvalidCollection
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Frees the name and restores the asset's default "Parcel #id" name. Permissionless once
+/// the asset has left the registrant's wallet, so names don't follow a sold parcel.
+pub fn handler(ctx: Context<ReleaseParcelName>, grid_id: u64, _name: String) -> Result<()> {
+    let registrant = ctx.accounts.name_record.registrant;
+    if ctx.accounts.signer.key() != registrant {
+        let owner = get_core_asset_owner(&ctx.accounts.asset.to_account_info())?;
+        require!(owner != registrant, BillionError::NameStillHeld);
+    }
+
+    let parcel_id = ctx.accounts.name_record.parcel_id;
+    let bump = ctx.accounts.grid_config.bump;
+    UpdateV1CpiBuilder::new(&ctx.accounts.mpl_core_program.to_account_info())
+        .asset(&ctx.accounts.asset.to_account_info())
+        .collection(Some(&ctx.accounts.collection.to_account_info()))
+        .authority(Some(&ctx.accounts.grid_config.to_account_info()))
+        .payer(&ctx.accounts.signer.to_account_info())
+        .system_program(&ctx.accounts.system_program.to_account_info())
+        .new_name(format!("Parcel #{}", parcel_id))
+        .invoke_signed(&[&[GridConfig::SEED, &grid_seed(grid_id), &[bump]]])?;
+
+    ctx.accounts.parcel_info.named = false;
+
+    msg!("Released name {} from parcel {}", ctx.accounts.name_record.name, parcel_id);
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{hash::hashv, sysvar::slot_hashes};
+use anchor_spl::token_interface::TokenAccount as InterfaceTokenAccount;
+use billion_core::Rect;
+use crate::constants::{MAX_RAFFLE_DRAWS, RAFFLE_CLAIM_WINDOW_SECS};
+use crate::state::{grid_seed, GridConfig, BlockMap, Raffle, RingUnlockSchedule, LAND_BUY_REWARD_POOL_SEED};
+use crate::errors::BillionError;
+use crate::utils::get_ring;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct RunRaffle<'info> {
+    /// Anyone can trigger a draw; pays for the Raffle account on the first one
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// BlockMap address must match the one stored in grid_config
+    #[account(
+        constraint = block_map.key() == grid_config.block_map @ BillionError::Unauthorized
+    )]
+    pub block_map: AccountLoader<'info, BlockMap>,
+
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = 8 + Raffle::INIT_SPACE,
+        seeds = [Raffle::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub raffle: Account<'info, Raffle>,
+
+    /// Pays for the prize block, out of its surplus
+    #[account(
+        seeds = [LAND_BUY_REWARD_POOL_SEED, grid_config.key().as_ref()],
+        bump,
+        constraint = land_buy_reward_pool.key() == grid_config.land_buy_reward_pool @ BillionError::InvalidRewardPool
+    )]
+    pub land_buy_reward_pool: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    /// Times rings open whatever has been burned; rings unlock by burns alone while uninitialized
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [RingUnlockSchedule::SEED, &grid_seed(grid_id)], bump)]
+    pub ring_unlock_schedule: UncheckedAccount<'info>,
+
+    /// CHECK: SlotHashes sysvar, read raw for the draw's randomness
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Randomness for a draw: the newest SlotHashes entry hashed with the raffle round.
+///
+/// This is only pseudo-random. The leader producing the slot can see the hash before the
+/// draw lands and can withhold or reorder transactions to steer it, which is acceptable for
+/// a one-block prize but not for anything valuable. Everything downstream only consumes the
+/// returned 32 bytes, so a VRF (e.g. a Switchboard randomness account) can replace this
+/// function without touching the selection logic.
+fn draw_seed(slot_hashes: &AccountInfo, round: u64) -> Result<[u8; 32]> {
+    // Layout: u64 entry count, then (u64 slot, [u8; 32] hash) entries, newest first
+    let data = slot_hashes.try_borrow_data()?;
+    let newest_hash = data.get(16..48).ok_or(BillionError::NoRaffleBlock)?;
+    Ok(hashv(&[newest_hash, &round.to_le_bytes()]).to_bytes())
+}
+
+/// Draws a random unclaimed block in an unlocked ring as a prize anyone can claim for free
+/// within RAFFLE_CLAIM_WINDOW_SECS. Blocks reserved for adjacent owners during an expansion
+/// window are skipped.
+pub fn handler(ctx: Context<RunRaffle>, _grid_id: u64) -> Result<()> {
+    let grid_config = &ctx.accounts.grid_config;
+    require!(grid_config.raffle_interval_secs > 0, BillionError::RaffleDisabled);
+
+    let now = Clock::get()?.unix_timestamp;
+    let raffle = &ctx.accounts.raffle;
+    require!(!raffle.prize_open(now), BillionError::RafflePrizeActive);
+    require!(
+        raffle.drawn_at == 0 || now >= raffle.drawn_at.saturating_add(grid_config.raffle_interval_secs),
+        BillionError::RaffleTooSoon
+    );
+
+    // The prize's burn is funded by the pool, so only unowed tokens can back it
+    require!(
+        grid_config.reward_surplus(ctx.accounts.land_buy_reward_pool.amount) >= grid_config.price_per_block,
+        BillionError::RewardPoolInsufficient
+    );
+
+    let round = raffle.round.checked_add(1).ok_or(BillionError::Overflow)?;
+    let seed = draw_seed(&ctx.accounts.slot_hashes.to_account_info(), round)?;
+    let schedule = RingUnlockSchedule::load(&ctx.accounts.ring_unlock_schedule)?;
+    let unlocked_ring = grid_config.unlocked_ring_at(&schedule, now);
+    let (prize_x, prize_y) = {
+        let block_map = BlockMap::load(&ctx.accounts.block_map)?;
+        require!(!block_map.is_frozen(), BillionError::EpochFrozen);
+        let grid_size = block_map.grid_size();
+        (0..MAX_RAFFLE_DRAWS)
+            .map(|attempt| Raffle::candidate(&seed, attempt, grid_size))
+            .find(|&(x, y)| {
+                block_map.get_block(x, y) == 0
+                    && get_ring(x, y, grid_size, grid_config.ring_count()) <= unlocked_ring
+                    && !grid_config.requires_adjacency_proof(Rect::new(x, y, 1, 1), now, grid_size)
+            })
+            .ok_or(BillionError::NoRaffleBlock)?
+    };
+
+    let raffle = &mut ctx.accounts.raffle;
+    raffle.round = round;
+    raffle.prize_x = prize_x;
+    raffle.prize_y = prize_y;
+    raffle.active = true;
+    raffle.drawn_at = now;
+    raffle.expires_at = now.checked_add(RAFFLE_CLAIM_WINDOW_SECS).ok_or(BillionError::Overflow)?;
+    raffle.winner = Pubkey::default();
+    raffle.bump = ctx.bumps.raffle;
+
+    msg!(
+        "Raffle round {} drew block ({}, {}), claimable until {}",
+        round,
+        prize_x,
+        prize_y,
+        raffle.expires_at
+    );
+    Ok(())
+}
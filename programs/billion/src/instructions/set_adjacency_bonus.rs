@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_ADJACENCY_BONUS_BPS;
+use crate::errors::BillionError;
+use crate::state::{grid_seed, ConfigFreeze, GridConfig};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct SetAdjacencyBonus<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump,
+        has_one = authority @ BillionError::Unauthorized,
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// Must still be empty: freeze_config creating it disables this instruction for good
+    /// CHECK: Seeds are verified here, only whether it exists is read
+    #[account(
+        seeds = [ConfigFreeze::SEED, &grid_seed(grid_id)],
+        bump,
+        constraint = !ConfigFreeze::is_frozen(&config_freeze) @ BillionError::ConfigFrozen
+    )]
+    pub config_freeze: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<SetAdjacencyBonus>, _grid_id: u64, adjacency_bonus_bps: u16) -> Result<()> {
+    require!(
+        adjacency_bonus_bps <= MAX_ADJACENCY_BONUS_BPS,
+        BillionError::InvalidAdjacencyBonus
+    );
+
+    ctx.accounts.grid_config.adjacency_bonus_bps = adjacency_bonus_bps;
+    msg!("Updated adjacency_bonus_bps to {}", adjacency_bonus_bps);
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BillionError;
+use crate::state::{grid_seed, AdminRoles, ConfigFreeze, GridConfig};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct SetAdminRoles<'info> {
+    /// The main authority; the roles can't reassign themselves
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump,
+        has_one = authority @ BillionError::Unauthorized,
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// Created on a grid initialized before AdminRoles existed
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + AdminRoles::INIT_SPACE,
+        seeds = [AdminRoles::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub admin_roles: Account<'info, AdminRoles>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Must still be empty: freeze_config creating it disables this instruction for good
+    /// CHECK: Seeds are verified here, only whether it exists is read
+    #[account(
+        seeds = [ConfigFreeze::SEED, &grid_seed(grid_id)],
+        bump,
+        constraint = !ConfigFreeze::is_frozen(&config_freeze) @ BillionError::ConfigFrozen
+    )]
+    pub config_freeze: UncheckedAccount<'info>,
+}
+
+/// Assigns each role given; a role left as None keeps its holder, which is the main
+/// authority on a just-created AdminRoles
+pub fn handler(
+    ctx: Context<SetAdminRoles>,
+    _grid_id: u64,
+    config_authority: Option<Pubkey>,
+    metadata_authority: Option<Pubkey>,
+    treasury_authority: Option<Pubkey>,
+) -> Result<()> {
+    // A just-created account is all zeros, and no role is ever the default key
+    let roles = &mut ctx.accounts.admin_roles;
+    if roles.config_authority == Pubkey::default() {
+        **roles = AdminRoles::single(ctx.accounts.authority.key(), ctx.bumps.admin_roles);
+    }
+    for role in [config_authority, metadata_authority, treasury_authority].into_iter().flatten() {
+        require_keys_neq!(role, Pubkey::default(), BillionError::InvalidAdminRole);
+    }
+
+    if let Some(key) = config_authority {
+        roles.config_authority = key;
+        msg!("Updated config_authority to {}", key);
+    }
+    if let Some(key) = metadata_authority {
+        roles.metadata_authority = key;
+        msg!("Updated metadata_authority to {}", key);
+    }
+    if let Some(key) = treasury_authority {
+        roles.treasury_authority = key;
+        msg!("Updated treasury_authority to {}", key);
+    }
+    Ok(())
+}
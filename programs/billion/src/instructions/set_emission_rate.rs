@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BillionError;
+use crate::state::{grid_seed, GridConfig};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct SetEmissionRate<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump,
+        has_one = authority @ BillionError::Unauthorized,
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+}
+
+/// Takes effect immediately for every open stake, including time not yet claimed
+pub fn handler(ctx: Context<SetEmissionRate>, _grid_id: u64, emission_rate_per_block_per_sec: u64) -> Result<()> {
+    let config = &mut ctx.accounts.grid_config;
+
+    require!(
+        config.emissions_vault != Pubkey::default(),
+        BillionError::EmissionsDisabled
+    );
+
+    config.emission_rate_per_block_per_sec = emission_rate_per_block_per_sec;
+    msg!("Updated emission_rate_per_block_per_sec to {}", emission_rate_per_block_per_sec);
+    Ok(())
+}
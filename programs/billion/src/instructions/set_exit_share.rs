@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BillionError;
+use crate::state::{grid_seed, ConfigFreeze, GridConfig};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct SetExitShare<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump,
+        has_one = authority @ BillionError::Unauthorized,
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// Must still be empty: freeze_config creating it disables this instruction for good
+    /// CHECK: Seeds are verified here, only whether it exists is read
+    #[account(
+        seeds = [ConfigFreeze::SEED, &grid_seed(grid_id)],
+        bump,
+        constraint = !ConfigFreeze::is_frozen(&config_freeze) @ BillionError::ConfigFrozen
+    )]
+    pub config_freeze: UncheckedAccount<'info>,
+}
+
+/// `reward_liability` overrides the tracked liability when set. Deployments upgraded from a
+/// layout without it start at zero and must seed it with the total of every parcel's pending
+/// land-buy rewards before exits are enabled, or the surplus is overstated.
+pub fn handler(ctx: Context<SetExitShare>, _grid_id: u64, exit_share_bps: u16, reward_liability: Option<u64>) -> Result<()> {
+    require!(exit_share_bps <= 10_000, BillionError::InvalidExitShare);
+
+    let config = &mut ctx.accounts.grid_config;
+    config.exit_share_bps = exit_share_bps;
+    if let Some(reward_liability) = reward_liability {
+        config.reward_liability = reward_liability;
+    }
+
+    msg!(
+        "Updated exit_share_bps to {}, reward liability {}",
+        exit_share_bps,
+        config.reward_liability
+    );
+    Ok(())
+}
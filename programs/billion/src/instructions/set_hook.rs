@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use billion_core::{Rect, MAX_GRID_SIZE};
+
+use crate::errors::BillionError;
+use crate::state::{grid_seed, ConfigFreeze, GridConfig, HookConfig};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct SetHook<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump,
+        has_one = authority @ BillionError::Unauthorized,
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// Created on first configuration, replaced in place afterwards
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + HookConfig::INIT_SPACE,
+        seeds = [HookConfig::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub hook_config: Account<'info, HookConfig>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Must still be empty: freeze_config creating it disables this instruction for good
+    /// CHECK: Seeds are verified here, only whether it exists is read
+    #[account(
+        seeds = [ConfigFreeze::SEED, &grid_seed(grid_id)],
+        bump,
+        constraint = !ConfigFreeze::is_frozen(&config_freeze) @ BillionError::ConfigFrozen
+    )]
+    pub config_freeze: UncheckedAccount<'info>,
+}
+
+/// Registers (or replaces) the on-claim hook for claims overlapping the district
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
+    ctx: Context<SetHook>,
+    _grid_id: u64,
+    program_id: Pubkey,
+    guardian: Pubkey,
+    x: u8,
+    y: u8,
+    width: u8,
+    height: u8,
+    enabled: bool,
+) -> Result<()> {
+    // No BlockMap is passed, so the region is bounded by the largest grid; claims inside it
+    // are still bounds-checked against their own grid
+    require!(Rect::new(x, y, width, height).in_bounds(MAX_GRID_SIZE), BillionError::InvalidDimensions);
+    require!(
+        !enabled || program_id != Pubkey::default(),
+        BillionError::HookProgramMismatch
+    );
+
+    let hook = &mut ctx.accounts.hook_config;
+    hook.program_id = program_id;
+    hook.enabled = enabled;
+    hook.guardian = guardian;
+    hook.x = x;
+    hook.y = y;
+    hook.width = width;
+    hook.height = height;
+    hook.bump = ctx.bumps.hook_config;
+
+    msg!(
+        "Claim hook {} for {}x{} at ({}, {}), enabled={}",
+        program_id, width, height, x, y, enabled
+    );
+    Ok(())
+}
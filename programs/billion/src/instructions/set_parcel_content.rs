@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use crate::state::{epoch_seed, grid_seed, GridConfig, ParcelContent, ParcelInfo};
+use crate::errors::BillionError;
+use crate::events::{ParcelContentUpdated, EVENT_SCHEMA_VERSION};
+use crate::instructions::claim_land_buy_rewards::require_parcel_owner;
+#[cfg(not(feature = "localnet"))]
+use crate::instructions::claim_parcel::MPL_CORE_ID;
+use crate::instructions::refresh_parcel_attributes::{parcel_attributes, with_content_hash, write_parcel_attributes};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16)]
+pub struct SetParcelContent<'info> {
+    /// Must own the parcel's asset; pays for the ParcelContent and any plugin space
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + ParcelContent::INIT_SPACE,
+        seeds = [ParcelContent::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump
+    )]
+    pub parcel_content: Account<'info, ParcelContent>,
+
+    /// The Metaplex Core asset - must match parcel_info.asset
+    /// CHECK: Validated by constraint, ownership checked in handler
+    #[account(
+        mut,
+        constraint = asset.key() == parcel_info.asset @ BillionError::AssetMismatch
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    /// Core collection - must match grid_config.collection
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        mut,
+        constraint = collection.key() == grid_config.collection @ BillionError::InvalidCollection
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Records the hash of the art the owner's parcel displays and adds it to the asset's
+/// attributes, so anyone can check the image served for it. An all-zero hash clears it.
+pub fn handler(ctx: Context<SetParcelContent>, grid_id: u64, parcel_id: u16, content_hash: [u8; 32]) -> Result<()> {
+    let owner = ctx.accounts.owner.key();
+    let parcel_info = &ctx.accounts.parcel_info;
+    require_parcel_owner(parcel_info, &ctx.accounts.asset.to_account_info(), &owner, &ctx.accounts.grid_config)?;
+
+    let content = &mut ctx.accounts.parcel_content;
+    content.content_hash = content_hash;
+    content.updated_at = Clock::get()?.unix_timestamp;
+    content.bump = ctx.bumps.parcel_content;
+
+    let attributes = with_content_hash(
+        parcel_attributes(parcel_id, parcel_info.x, parcel_info.y, parcel_info.width, parcel_info.height),
+        &content_hash,
+    );
+    write_parcel_attributes(
+        grid_id,
+        &ctx.accounts.grid_config,
+        &ctx.accounts.asset,
+        &ctx.accounts.collection,
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.mpl_core_program,
+        &ctx.accounts.system_program,
+        attributes,
+    )?;
+
+    emit!(ParcelContentUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        parcel_id,
+        epoch: parcel_info.epoch,
+        owner,
+        content_hash,
+    });
+
+    msg!("Parcel {} content hash updated", parcel_id);
+    Ok(())
+}
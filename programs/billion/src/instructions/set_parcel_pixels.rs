@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::state::{epoch_seed, grid_seed, GridConfig, ParcelInfo, ParcelPixels};
+use crate::constants::MAX_PARCEL_PIXELS_LEN;
+use crate::errors::BillionError;
+use crate::events::{ParcelPixelsUpdated, EVENT_SCHEMA_VERSION};
+use crate::instructions::claim_land_buy_rewards::require_parcel_owner;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16, rgb: Vec<u8>)]
+pub struct SetParcelPixels<'info> {
+    /// Must own the parcel's asset; pays the ParcelPixels rent
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+
+    /// The Metaplex Core asset - must match parcel_info.asset
+    /// CHECK: Validated by constraint, ownership checked in handler
+    #[account(
+        constraint = asset.key() == parcel_info.asset @ BillionError::AssetMismatch
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    /// Sized by the first call; the handler holds every later write to the same length
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = ParcelPixels::space(rgb.len()),
+        seeds = [ParcelPixels::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump
+    )]
+    pub parcel_pixels: Account<'info, ParcelPixels>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Stores the colors of the owner's current-epoch parcel on-chain, three RGB bytes per block.
+/// Only parcels of up to MAX_PARCEL_PIXELS_LEN / 3 blocks qualify; calling again repaints.
+pub fn handler(ctx: Context<SetParcelPixels>, _grid_id: u64, parcel_id: u16, rgb: Vec<u8>) -> Result<()> {
+    let parcel_info = &ctx.accounts.parcel_info;
+    require!(parcel_info.epoch == ctx.accounts.grid_config.epoch, BillionError::ParcelArchived);
+    let owner = ctx.accounts.owner.key();
+    require_parcel_owner(parcel_info, &ctx.accounts.asset.to_account_info(), &owner, &ctx.accounts.grid_config)?;
+
+    let len = 3 * parcel_info.block_count() as usize;
+    require!(len <= MAX_PARCEL_PIXELS_LEN as usize, BillionError::ParcelTooLargeForPixels);
+    require!(rgb.len() == len, BillionError::InvalidPixelData);
+
+    let pixels = &mut ctx.accounts.parcel_pixels;
+    pixels.rgb = rgb;
+    pixels.bump = ctx.bumps.parcel_pixels;
+
+    emit!(ParcelPixelsUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        parcel_id,
+        epoch: parcel_info.epoch,
+        owner,
+        cleared: false,
+    });
+
+    msg!("Parcel {} pixels set", parcel_id);
+    Ok(())
+}
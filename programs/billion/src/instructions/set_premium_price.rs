@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BillionError;
+use crate::state::{grid_seed, GridConfig};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct SetPremiumPrice<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump,
+        has_one = authority @ BillionError::Unauthorized,
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+}
+
+/// A zero price disables new purchases; badges already bought run to their expiry
+pub fn handler(ctx: Context<SetPremiumPrice>, _grid_id: u64, premium_price_per_period: u64, premium_period_secs: i64) -> Result<()> {
+    require!(premium_period_secs > 0, BillionError::InvalidPremiumConfig);
+
+    let config = &mut ctx.accounts.grid_config;
+    config.premium_price_per_period = premium_price_per_period;
+    config.premium_period_secs = premium_period_secs;
+
+    msg!(
+        "Updated premium_price_per_period to {}, premium_period_secs to {}",
+        premium_price_per_period,
+        premium_period_secs
+    );
+    Ok(())
+}
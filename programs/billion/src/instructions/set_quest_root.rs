@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BillionError;
+use crate::state::{grid_seed, GridConfig, Quest};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, root: [u8; 32])]
+pub struct SetQuestRoot<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump,
+        has_one = authority @ BillionError::Unauthorized,
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Quest::INIT_SPACE,
+        seeds = [Quest::SEED, &grid_seed(grid_id), root.as_ref()],
+        bump
+    )]
+    pub quest: Account<'info, Quest>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Publishes a quest. The vault isn't reserved per quest, so fund it for every open allocation.
+pub fn handler(ctx: Context<SetQuestRoot>, _grid_id: u64, root: [u8; 32], total_allocation: u64) -> Result<()> {
+    require!(
+        ctx.accounts.grid_config.quest_vault != Pubkey::default(),
+        BillionError::QuestsDisabled
+    );
+
+    let quest = &mut ctx.accounts.quest;
+    quest.root = root;
+    quest.total_allocation = total_allocation;
+    quest.claimed_amount = 0;
+    quest.claim_count = 0;
+    quest.bump = ctx.bumps.quest;
+    quest._reserved = [0u8; 32];
+
+    msg!("Quest {} published with allocation {}", quest.key(), total_allocation);
+    Ok(())
+}
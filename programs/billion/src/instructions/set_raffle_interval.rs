@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BillionError;
+use crate::state::{grid_seed, GridConfig};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct SetRaffleInterval<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump,
+        has_one = authority @ BillionError::Unauthorized,
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+}
+
+/// Zero disables new draws; an open prize stays claimable until it expires
+pub fn handler(ctx: Context<SetRaffleInterval>, _grid_id: u64, raffle_interval_secs: i64) -> Result<()> {
+    require!(raffle_interval_secs >= 0, BillionError::InvalidRaffleInterval);
+    ctx.accounts.grid_config.raffle_interval_secs = raffle_interval_secs;
+    msg!("Updated raffle_interval_secs to {}", raffle_interval_secs);
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BillionError;
+use crate::state::{grid_seed, ConfigFreeze, GridConfig, ReferralShare, TokenTreasury};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct SetReferralBps<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump,
+        has_one = authority @ BillionError::Unauthorized,
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// Treasury share the referral share must leave room for
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [TokenTreasury::SEED, &grid_seed(grid_id)], bump)]
+    pub token_treasury: UncheckedAccount<'info>,
+
+    /// Direct referral share the referral share must leave room for
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [ReferralShare::SEED, &grid_seed(grid_id)], bump)]
+    pub referral_share: UncheckedAccount<'info>,
+
+    /// Must still be empty: freeze_config creating it disables this instruction for good
+    /// CHECK: Seeds are verified here, only whether it exists is read
+    #[account(
+        seeds = [ConfigFreeze::SEED, &grid_seed(grid_id)],
+        bump,
+        constraint = !ConfigFreeze::is_frozen(&config_freeze) @ BillionError::ConfigFrozen
+    )]
+    pub config_freeze: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<SetReferralBps>, _grid_id: u64, referral_bps: u16) -> Result<()> {
+    let config = &mut ctx.accounts.grid_config;
+
+    require!(
+        config.referral_vault != Pubkey::default(),
+        BillionError::ReferralsDisabled
+    );
+    // Referral share comes out of the burned portion, alongside the charity and treasury shares
+    let treasury_bps = TokenTreasury::load(&ctx.accounts.token_treasury)?.share_bps;
+    let referral_share = ReferralShare::load(&ctx.accounts.referral_share)?;
+    require!(
+        GridConfig::valid_split_bps(
+            config.land_owners_reward_share_bps,
+            treasury_bps,
+            referral_share.with_referral_bps(referral_bps),
+            config.max_charity_bps,
+        ),
+        BillionError::InvalidReferralShare
+    );
+
+    config.referral_bps = referral_bps;
+    msg!("Updated referral_bps to {}", referral_bps);
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use crate::state::{grid_seed, AdminRoles, ConfigFreeze, GridConfig, ReferralShare, TokenTreasury};
+use crate::constants::MAX_REFERRAL_SHARE_BPS;
+use crate::errors::BillionError;
+use crate::events::CONFIG_FIELD_REFERRAL_SHARE;
+use crate::instructions::update_config::ConfigSnapshot;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct SetReferralShare<'info> {
+    /// The config authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// Names the config authority; the main authority holds every role until it's created
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [AdminRoles::SEED, &grid_seed(grid_id)], bump)]
+    pub admin_roles: UncheckedAccount<'info>,
+
+    /// Created on the first call
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ReferralShare::INIT_SPACE,
+        seeds = [ReferralShare::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub referral_share: Account<'info, ReferralShare>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Treasury share the referral share must leave room for
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [TokenTreasury::SEED, &grid_seed(grid_id)], bump)]
+    pub token_treasury: UncheckedAccount<'info>,
+
+    /// Must still be empty: freeze_config creating it disables this instruction for good
+    /// CHECK: Seeds are verified here, only whether it exists is read
+    #[account(
+        seeds = [ConfigFreeze::SEED, &grid_seed(grid_id)],
+        bump,
+        constraint = !ConfigFreeze::is_frozen(&config_freeze) @ BillionError::ConfigFrozen
+    )]
+    pub config_freeze: UncheckedAccount<'info>,
+}
+
+/// Sets the share of each claim_parcel cost paid to the referrer's token account. The claimer
+/// picks that account, so the share is capped at MAX_REFERRAL_SHARE_BPS, and it is counted
+/// with the other shares so together they never exceed the cost.
+pub fn handler(ctx: Context<SetReferralShare>, _grid_id: u64, share_bps: u16) -> Result<()> {
+    let roles = AdminRoles::load(&ctx.accounts.admin_roles, &ctx.accounts.grid_config)?;
+    require_keys_eq!(roles.config_authority, ctx.accounts.authority.key(), BillionError::Unauthorized);
+    require!(share_bps <= MAX_REFERRAL_SHARE_BPS, BillionError::InvalidReferralShare);
+
+    let config = &ctx.accounts.grid_config;
+    let treasury_bps = TokenTreasury::load(&ctx.accounts.token_treasury)?.share_bps;
+    require!(
+        GridConfig::valid_split_bps(
+            config.land_owners_reward_share_bps,
+            treasury_bps,
+            config.referral_bps.saturating_add(share_bps),
+            config.max_charity_bps,
+        ),
+        BillionError::InvalidReferralShare
+    );
+
+    let referral_share = &mut ctx.accounts.referral_share;
+    referral_share.share_bps = share_bps;
+    referral_share.bump = ctx.bumps.referral_share;
+
+    // GridConfig itself is unchanged, so only the changed_fields bit tells indexers
+    let before = ConfigSnapshot::of(config);
+    emit!(before.updated(config, ctx.accounts.authority.key(), CONFIG_FIELD_REFERRAL_SHARE));
+
+    msg!("Referral share set to {} bps", share_bps);
+    Ok(())
+}
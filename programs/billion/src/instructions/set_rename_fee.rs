@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::{grid_seed, AdminRoles, GridConfig, RenameFee};
+use crate::errors::BillionError;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct SetRenameFee<'info> {
+    /// The metadata authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// Names the metadata authority; the main authority holds every role until it's created
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [AdminRoles::SEED, &grid_seed(grid_id)], bump)]
+    pub admin_roles: UncheckedAccount<'info>,
+
+    /// Created on the first call
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + RenameFee::INIT_SPACE,
+        seeds = [RenameFee::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub rename_fee: Account<'info, RenameFee>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Sets the tokens burned by each owner_update_parcel. A fee of 0 stops owners renaming.
+pub fn handler(ctx: Context<SetRenameFee>, _grid_id: u64, fee: u64) -> Result<()> {
+    let roles = AdminRoles::load(&ctx.accounts.admin_roles, &ctx.accounts.grid_config)?;
+    require_keys_eq!(roles.metadata_authority, ctx.accounts.authority.key(), BillionError::Unauthorized);
+
+    let rename_fee = &mut ctx.accounts.rename_fee;
+    rename_fee.fee = fee;
+    rename_fee.bump = ctx.bumps.rename_fee;
+
+    msg!("Rename fee set to {}", fee);
+    Ok(())
+}
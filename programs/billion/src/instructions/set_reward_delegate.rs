@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::state::{epoch_seed, grid_seed, GridConfig, ParcelInfo, RewardDelegate};
+use crate::errors::BillionError;
+use crate::instructions::claim_land_buy_rewards::require_parcel_owner;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16)]
+pub struct SetRewardDelegate<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+
+    /// The Metaplex Core asset - must match parcel_info.asset
+    /// CHECK: Validated by constraint, ownership checked in handler
+    #[account(
+        constraint = asset.key() == parcel_info.asset @ BillionError::AssetMismatch
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + RewardDelegate::INIT_SPACE,
+        seeds = [RewardDelegate::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump
+    )]
+    pub reward_delegate: Account<'info, RewardDelegate>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Lets `delegate` sign claim_land_buy_rewards for the parcel, with rewards still paid to the
+/// owner. The delegate only holds while the signer keeps the asset. None clears it and
+/// returns the rent.
+pub fn handler(ctx: Context<SetRewardDelegate>, _grid_id: u64, parcel_id: u16, delegate: Option<Pubkey>) -> Result<()> {
+    let owner = ctx.accounts.owner.key();
+    require_parcel_owner(
+        &ctx.accounts.parcel_info,
+        &ctx.accounts.asset.to_account_info(),
+        &owner,
+        &ctx.accounts.grid_config,
+    )?;
+
+    let Some(delegate) = delegate else {
+        ctx.accounts.reward_delegate.close(ctx.accounts.owner.to_account_info())?;
+        msg!("Cleared reward delegate of parcel {}", parcel_id);
+        return Ok(());
+    };
+    let record = &mut ctx.accounts.reward_delegate;
+    record.delegate = delegate;
+    record.owner = owner;
+    record.bump = ctx.bumps.reward_delegate;
+    msg!("Parcel {} reward delegate: {}", parcel_id, delegate);
+    Ok(())
+}
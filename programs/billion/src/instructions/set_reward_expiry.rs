@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_EXPIRY_BOUNTY_BPS;
+use crate::errors::BillionError;
+use crate::state::{grid_seed, ConfigFreeze, GridConfig};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct SetRewardExpiry<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump,
+        has_one = authority @ BillionError::Unauthorized,
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// Must still be empty: freeze_config creating it disables this instruction for good
+    /// CHECK: Seeds are verified here, only whether it exists is read
+    #[account(
+        seeds = [ConfigFreeze::SEED, &grid_seed(grid_id)],
+        bump,
+        constraint = !ConfigFreeze::is_frozen(&config_freeze) @ BillionError::ConfigFrozen
+    )]
+    pub config_freeze: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<SetRewardExpiry>, _grid_id: u64, reward_expiry_seconds: i64, expiry_bounty_bps: u16) -> Result<()> {
+    require!(
+        reward_expiry_seconds >= 0 && expiry_bounty_bps <= MAX_EXPIRY_BOUNTY_BPS,
+        BillionError::InvalidRewardExpiry
+    );
+
+    let config = &mut ctx.accounts.grid_config;
+    // Switching expiry on starts every parcel's clock now, so idle time before it doesn't count
+    if config.reward_expiry_seconds == 0 && reward_expiry_seconds > 0 {
+        config.reward_expiry_since = Clock::get()?.unix_timestamp;
+    }
+    config.reward_expiry_seconds = reward_expiry_seconds;
+    config.expiry_bounty_bps = expiry_bounty_bps;
+
+    msg!(
+        "Updated reward_expiry_seconds to {}, expiry_bounty_bps to {}",
+        reward_expiry_seconds,
+        expiry_bounty_bps
+    );
+    Ok(())
+}
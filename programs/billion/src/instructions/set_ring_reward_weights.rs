@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::RING_COUNT;
+use crate::errors::BillionError;
+use crate::state::{
+    grid_seed, ConfigFreeze, GridConfig, RingRewardWeights, MAX_RING_REWARD_WEIGHT_BPS, MIN_RING_REWARD_WEIGHT_BPS,
+};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct SetRingRewardWeights<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump,
+        has_one = authority @ BillionError::Unauthorized,
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// Created with flat weights on the first call
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + RingRewardWeights::INIT_SPACE,
+        seeds = [RingRewardWeights::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub ring_reward_weights: Account<'info, RingRewardWeights>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Must still be empty: freeze_config creating it disables this instruction for good
+    /// CHECK: Seeds are verified here, only whether it exists is read
+    #[account(
+        seeds = [ConfigFreeze::SEED, &grid_seed(grid_id)],
+        bump,
+        constraint = !ConfigFreeze::is_frozen(&config_freeze) @ BillionError::ConfigFrozen
+    )]
+    pub config_freeze: UncheckedAccount<'info>,
+}
+
+/// Sets each ring's land-buy reward weight (index 0 is the outermost ring). A parcel's
+/// weight is fixed when it's minted, so only parcels minted afterwards earn by the new
+/// weights; parcels already on the grid keep theirs.
+pub fn handler(ctx: Context<SetRingRewardWeights>, _grid_id: u64, weights_bps: [u16; RING_COUNT as usize]) -> Result<()> {
+    require!(
+        weights_bps
+            .iter()
+            .all(|bps| (MIN_RING_REWARD_WEIGHT_BPS..=MAX_RING_REWARD_WEIGHT_BPS).contains(bps)),
+        BillionError::InvalidRingRewardWeights
+    );
+
+    // A just-created account is all zeros, which no valid set of weights is
+    let weights = &mut ctx.accounts.ring_reward_weights;
+    if weights.weights_bps.iter().all(|&bps| bps == 0) {
+        **weights = RingRewardWeights::flat(ctx.bumps.ring_reward_weights);
+        weights.epoch = ctx.accounts.grid_config.epoch;
+    }
+    weights.weights_bps = weights_bps;
+
+    msg!("Updated ring_reward_weights_bps to {:?}", weights_bps);
+    Ok(())
+}
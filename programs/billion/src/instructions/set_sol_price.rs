@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BillionError;
+use crate::state::{grid_seed, ConfigFreeze, GridConfig};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct SetSolPrice<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump,
+        has_one = authority @ BillionError::Unauthorized,
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// Must still be empty: freeze_config creating it disables this instruction for good
+    /// CHECK: Seeds are verified here, only whether it exists is read
+    #[account(
+        seeds = [ConfigFreeze::SEED, &grid_seed(grid_id)],
+        bump,
+        constraint = !ConfigFreeze::is_frozen(&config_freeze) @ BillionError::ConfigFrozen
+    )]
+    pub config_freeze: UncheckedAccount<'info>,
+}
+
+/// A zero price disables claim_parcel_sol
+pub fn handler(ctx: Context<SetSolPrice>, _grid_id: u64, price_per_block_lamports: u64, sol_ring_credit_bps: u16) -> Result<()> {
+    require!(sol_ring_credit_bps <= 10_000, BillionError::InvalidSolConfig);
+
+    let config = &mut ctx.accounts.grid_config;
+    config.price_per_block_lamports = price_per_block_lamports;
+    config.sol_ring_credit_bps = sol_ring_credit_bps;
+
+    msg!(
+        "Updated price_per_block_lamports to {}, sol_ring_credit_bps to {}",
+        price_per_block_lamports,
+        sol_ring_credit_bps
+    );
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount as InterfaceTokenAccount;
+use crate::state::{grid_seed, AdminRoles, ConfigFreeze, GridConfig, ReferralShare, TokenTreasury};
+use crate::errors::BillionError;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct SetTokenTreasury<'info> {
+    /// The treasury authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// Names the treasury authority; the main authority holds every role until it's created
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [AdminRoles::SEED, &grid_seed(grid_id)], bump)]
+    pub admin_roles: UncheckedAccount<'info>,
+
+    /// Created with no share on the first call
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + TokenTreasury::INIT_SPACE,
+        seeds = [TokenTreasury::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub token_treasury: Account<'info, TokenTreasury>,
+
+    /// Receives the treasury share; any token account of the grid's mint
+    #[account(
+        constraint = treasury_token_account.mint == grid_config.token_mint @ BillionError::InvalidTreasury
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    /// Direct referral share the treasury share must leave room for
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [ReferralShare::SEED, &grid_seed(grid_id)], bump)]
+    pub referral_share: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Must still be empty: freeze_config creating it disables this instruction for good
+    /// CHECK: Seeds are verified here, only whether it exists is read
+    #[account(
+        seeds = [ConfigFreeze::SEED, &grid_seed(grid_id)],
+        bump,
+        constraint = !ConfigFreeze::is_frozen(&config_freeze) @ BillionError::ConfigFrozen
+    )]
+    pub config_freeze: UncheckedAccount<'info>,
+}
+
+/// Routes `share_bps` of every token claim to `treasury_token_account` instead of burning
+/// it. A share of 0 turns the treasury off.
+pub fn handler(ctx: Context<SetTokenTreasury>, _grid_id: u64, share_bps: u16) -> Result<()> {
+    let roles = AdminRoles::load(&ctx.accounts.admin_roles, &ctx.accounts.grid_config)?;
+    require_keys_eq!(roles.treasury_authority, ctx.accounts.authority.key(), BillionError::Unauthorized);
+    let config = &ctx.accounts.grid_config;
+    let referral_share = ReferralShare::load(&ctx.accounts.referral_share)?;
+    require!(
+        GridConfig::valid_split_bps(
+            config.land_owners_reward_share_bps,
+            share_bps,
+            referral_share.with_referral_bps(config.referral_bps),
+            config.max_charity_bps,
+        ),
+        BillionError::InvalidTreasuryShare
+    );
+
+    let treasury = &mut ctx.accounts.token_treasury;
+    treasury.treasury = ctx.accounts.treasury_token_account.key();
+    treasury.share_bps = share_bps;
+    treasury.bump = ctx.bumps.token_treasury;
+
+    msg!("Token treasury set to {} at {} bps", treasury.treasury, share_bps);
+    Ok(())
+}
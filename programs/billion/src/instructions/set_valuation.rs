@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use billion_core::Rect;
+use mpl_core::instructions::{AddPluginV1CpiBuilder, ApprovePluginAuthorityV1CpiBuilder};
+use mpl_core::types::{Plugin, PluginAuthority, PluginType, TransferDelegate};
+use crate::state::{epoch_seed, grid_seed, GridConfig, HarbergerDistrict, ParcelInfo, ParcelValuation};
+use crate::errors::BillionError;
+use crate::instructions::claim_land_buy_rewards::get_core_asset_owner;
+#[cfg(not(feature = "localnet"))]
+use crate::instructions::claim_parcel::MPL_CORE_ID;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16)]
+pub struct SetValuation<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        seeds = [HarbergerDistrict::SEED, &grid_seed(grid_id)],
+        bump = harberger_district.bump
+    )]
+    pub harberger_district: Account<'info, HarbergerDistrict>,
+
+    #[account(
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + ParcelValuation::INIT_SPACE,
+        seeds = [ParcelValuation::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump
+    )]
+    pub parcel_valuation: Account<'info, ParcelValuation>,
+
+    /// The Metaplex Core asset - must match parcel_info.asset
+    /// CHECK: Validated by constraint, ownership checked in handler
+    #[account(
+        mut,
+        constraint = asset.key() == parcel_info.asset @ BillionError::AssetMismatch
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    /// Core collection - must match grid_config.collection
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        mut,
+        constraint = collection.key() == grid_config.collection @ BillionError::InvalidCollection
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetValuation>, _grid_id: u64, parcel_id: u16, value: u64) -> Result<()> {
+    let district = &ctx.accounts.harberger_district;
+    let parcel = &ctx.accounts.parcel_info;
+    require!(district.enabled, BillionError::HarbergerDisabled);
+    require!(parcel.epoch == ctx.accounts.grid_config.epoch, BillionError::ParcelArchived);
+    require!(
+        district.contains(Rect::new(parcel.x, parcel.y, parcel.width, parcel.height)),
+        BillionError::OutsideDistrict
+    );
+    require!(value > 0, BillionError::InvalidValuation);
+
+    let owner = get_core_asset_owner(&ctx.accounts.asset.to_account_info())?;
+    require!(owner == ctx.accounts.owner.key(), BillionError::NotOwner);
+
+    let mpl_core_program = ctx.accounts.mpl_core_program.to_account_info();
+    let asset = ctx.accounts.asset.to_account_info();
+    let collection = ctx.accounts.collection.to_account_info();
+    let owner_info = ctx.accounts.owner.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let delegate = PluginAuthority::Address {
+        address: ctx.accounts.grid_config.key(),
+    };
+
+    let valuation = &mut ctx.accounts.parcel_valuation;
+    if !valuation.plugin_added {
+        // First valuation: add a TransferDelegate the GridConfig PDA can force_buy through
+        AddPluginV1CpiBuilder::new(&mpl_core_program)
+            .asset(&asset)
+            .collection(Some(&collection))
+            .payer(&owner_info)
+            .authority(Some(&owner_info))
+            .system_program(&system_program)
+            .plugin(Plugin::TransferDelegate(TransferDelegate {}))
+            .init_authority(delegate)
+            .invoke()?;
+        valuation.plugin_added = true;
+        valuation.delegate_approved_for = owner;
+    } else if valuation.delegate_approved_for != owner {
+        // Core hands the delegate back to the owner on every transfer, so a new owner re-approves
+        ApprovePluginAuthorityV1CpiBuilder::new(&mpl_core_program)
+            .asset(&asset)
+            .collection(Some(&collection))
+            .payer(&owner_info)
+            .authority(Some(&owner_info))
+            .system_program(&system_program)
+            .plugin_type(PluginType::TransferDelegate)
+            .new_authority(delegate)
+            .invoke()?;
+        valuation.delegate_approved_for = owner;
+    }
+
+    if valuation.owner != owner {
+        // A new owner starts with no tax paid; force_buy stays closed until the first pay_tax
+        valuation.owner = owner;
+        valuation.tax_paid_until = Clock::get()?.unix_timestamp;
+    }
+
+    valuation.parcel_id = parcel_id;
+    valuation.valuation = value;
+    valuation.bump = ctx.bumps.parcel_valuation;
+
+    msg!("Parcel {} valued at {}", parcel_id, value);
+    Ok(())
+}
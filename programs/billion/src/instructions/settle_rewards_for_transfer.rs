@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_interface::{Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface},
+    associated_token::AssociatedToken,
+};
+use crate::state::{epoch_seed, grid_seed, ClaimerStats, EpochArchive, GridConfig, ParcelInfo, LAND_BUY_REWARD_POOL_SEED};
+use crate::errors::BillionError;
+use crate::instructions::claim_land_buy_rewards::{require_rewards_claimable, settle_rewards_to};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16)]
+pub struct SettleRewardsForTransfer<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        mut,
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+
+    /// The Metaplex Core asset - must match parcel_info.asset
+    /// CHECK: Validated by constraint, ownership checked in handler
+    #[account(
+        constraint = asset.key() == parcel_info.asset @ BillionError::AssetMismatch
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    /// Land buy reward pool holding the tokens
+    #[account(
+        mut,
+        seeds = [LAND_BUY_REWARD_POOL_SEED, grid_config.key().as_ref()],
+        bump,
+        constraint = land_buy_reward_pool.key() == grid_config.land_buy_reward_pool @ BillionError::InvalidRewardPool
+    )]
+    pub land_buy_reward_pool: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    /// Owner's token account to receive the rewards
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    #[account(
+        constraint = token_mint.key() == grid_config.token_mint @ BillionError::Unauthorized
+    )]
+    pub token_mint: InterfaceAccount<'info, InterfaceMint>,
+
+    /// The owner's lifetime stats, created on first touch
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + ClaimerStats::INIT_SPACE,
+        seeds = [ClaimerStats::SEED, &grid_seed(grid_id), owner.key().as_ref()],
+        bump
+    )]
+    pub claimer_stats: Box<Account<'info, ClaimerStats>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    /// Archive of the parcel's epoch - required when the parcel is from an earlier epoch
+    #[account(
+        seeds = [EpochArchive::SEED, &grid_seed(grid_id), &epoch_archive.epoch.to_le_bytes()],
+        bump = epoch_archive.bump
+    )]
+    pub epoch_archive: Option<Account<'info, EpochArchive>>,
+}
+
+/// Pays the owner everything the parcel is owed and moves its checkpoint to the accumulator,
+/// so a transfer in the same transaction hands the buyer a parcel with nothing pending. Unlike
+/// claim_land_buy_rewards it succeeds with nothing owed, so sellers and marketplaces can
+/// always prepend it, and pays no adjacency bonus.
+///
+/// Settling is voluntary for transfers the owner signs. It isn't enforced with a Core Oracle
+/// plugin: Core can't compare the checkpoint with an accumulator that moves on every claim,
+/// and an owner-armed gate would also let owners block force_buy. The program's own transfers
+/// (force_buy, fractionalize_parcel) settle to the outgoing owner through the same path.
+pub fn handler(ctx: Context<SettleRewardsForTransfer>, grid_id: u64, parcel_id: u16) -> Result<()> {
+    let owner = ctx.accounts.owner.key();
+    require_rewards_claimable(
+        &ctx.accounts.parcel_info,
+        &ctx.accounts.asset.to_account_info(),
+        &owner,
+        &ctx.accounts.grid_config,
+        Clock::get()?.unix_timestamp,
+    )?;
+    let owed = settle_rewards_to(
+        &mut ctx.accounts.parcel_info,
+        grid_id,
+        &mut ctx.accounts.grid_config,
+        ctx.accounts.epoch_archive.as_deref(),
+        &ctx.accounts.land_buy_reward_pool,
+        &ctx.accounts.owner_token_account,
+        &ctx.accounts.token_mint,
+        &ctx.accounts.token_program,
+    )?;
+
+    let stats = &mut ctx.accounts.claimer_stats;
+    stats.touch(owner, ctx.bumps.claimer_stats);
+    stats.record_rewards(owed).ok_or(BillionError::Overflow)?;
+
+    msg!("Settled {} tokens for parcel {} ahead of a transfer", owed, parcel_id);
+    Ok(())
+}
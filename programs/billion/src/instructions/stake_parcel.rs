@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use mpl_core::accounts::BaseAssetV1;
+use mpl_core::fetch_plugin;
+use mpl_core::instructions::{AddPluginV1CpiBuilder, UpdatePluginV1CpiBuilder};
+use mpl_core::types::{FreezeDelegate, Plugin, PluginAuthority, PluginType};
+use crate::state::{epoch_seed, grid_seed, GridConfig, ParcelInfo, StakeAccount};
+use crate::errors::BillionError;
+use crate::instructions::claim_land_buy_rewards::get_core_asset_owner;
+#[cfg(not(feature = "localnet"))]
+use crate::instructions::claim_parcel::MPL_CORE_ID;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16)]
+pub struct StakeParcel<'info> {
+    #[account(mut)]
+    pub staker: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+
+    #[account(
+        init,
+        payer = staker,
+        space = 8 + StakeAccount::INIT_SPACE,
+        seeds = [StakeAccount::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    /// The Metaplex Core asset - must match parcel_info.asset
+    /// CHECK: Validated by constraint, ownership checked in handler
+    #[account(
+        mut,
+        constraint = asset.key() == parcel_info.asset @ BillionError::AssetMismatch
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    /// Core collection - must match grid_config.collection
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        mut,
+        constraint = collection.key() == grid_config.collection @ BillionError::InvalidCollection
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<StakeParcel>, grid_id: u64, parcel_id: u16) -> Result<()> {
+    let owner = get_core_asset_owner(&ctx.accounts.asset.to_account_info())?;
+    require!(owner == ctx.accounts.staker.key(), BillionError::NotOwner);
+    require!(
+        ctx.accounts.parcel_info.epoch == ctx.accounts.grid_config.epoch,
+        BillionError::ParcelArchived
+    );
+    require!(
+        ctx.accounts.grid_config.emissions_vault != Pubkey::default(),
+        BillionError::EmissionsDisabled
+    );
+    // Unstaking would otherwise thaw a vesting lock early
+    require!(ctx.accounts.parcel_info.locked_until == 0, BillionError::VestingNotEnded);
+
+    let vesting_plugin =
+        fetch_plugin::<BaseAssetV1, FreezeDelegate>(&ctx.accounts.asset.to_account_info(), PluginType::FreezeDelegate)
+            .ok()
+            .filter(|(authority, _, _)| {
+                *authority == PluginAuthority::Address { address: ctx.accounts.grid_config.key() }
+            });
+    if vesting_plugin.is_some() {
+        // Unlocked vested parcels still carry the program's thawed FreezeDelegate; refreeze it
+        let bump = ctx.accounts.grid_config.bump;
+        UpdatePluginV1CpiBuilder::new(&ctx.accounts.mpl_core_program.to_account_info())
+            .asset(&ctx.accounts.asset.to_account_info())
+            .collection(Some(&ctx.accounts.collection.to_account_info()))
+            .payer(&ctx.accounts.staker.to_account_info())
+            .authority(Some(&ctx.accounts.grid_config.to_account_info()))
+            .system_program(&ctx.accounts.system_program.to_account_info())
+            .plugin(Plugin::FreezeDelegate(FreezeDelegate { frozen: true }))
+            .invoke_signed(&[&[GridConfig::SEED, &grid_seed(grid_id), &[bump]]])?;
+    } else {
+        // Owner adds a FreezeDelegate owned by the GridConfig PDA, so only the program can thaw it
+        AddPluginV1CpiBuilder::new(&ctx.accounts.mpl_core_program.to_account_info())
+            .asset(&ctx.accounts.asset.to_account_info())
+            .collection(Some(&ctx.accounts.collection.to_account_info()))
+            .payer(&ctx.accounts.staker.to_account_info())
+            .authority(Some(&ctx.accounts.staker.to_account_info()))
+            .system_program(&ctx.accounts.system_program.to_account_info())
+            .plugin(Plugin::FreezeDelegate(FreezeDelegate { frozen: true }))
+            .init_authority(PluginAuthority::Address {
+                address: ctx.accounts.grid_config.key(),
+            })
+            .invoke()?;
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let stake_account = &mut ctx.accounts.stake_account;
+    stake_account.staker = ctx.accounts.staker.key();
+    stake_account.parcel_id = parcel_id;
+    stake_account.staked_at = now;
+    stake_account.last_claimed_at = now;
+    stake_account.total_claimed = 0;
+    stake_account.bump = ctx.bumps.stake_account;
+    stake_account._reserved = [0u8; 32];
+
+    msg!("Staked parcel {} ({} blocks)", parcel_id, ctx.accounts.parcel_info.block_count());
+    Ok(())
+}
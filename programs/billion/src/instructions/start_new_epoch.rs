@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BillionError;
+use crate::state::{grid_seed, BlockMap, EpochArchive, GridConfig};
+
+/// The new BlockMap is too large to create via CPI, so the client creates it first with
+/// `create_account` + `create_block_map`, exactly as for `initialize`, with the current grid size.
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct StartNewEpoch<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump,
+        has_one = authority @ BillionError::Unauthorized,
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// Current BlockMap, frozen by this instruction
+    #[account(
+        mut,
+        constraint = block_map.key() == grid_config.block_map @ BillionError::Unauthorized
+    )]
+    pub block_map: AccountLoader<'info, BlockMap>,
+
+    /// Fresh BlockMap for the new epoch
+    #[account(
+        mut,
+        constraint = new_block_map.key() != grid_config.block_map @ BillionError::Unauthorized
+    )]
+    pub new_block_map: AccountLoader<'info, BlockMap>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + EpochArchive::INIT_SPACE,
+        seeds = [EpochArchive::SEED, &grid_seed(grid_id), &grid_config.epoch.to_le_bytes()],
+        bump
+    )]
+    pub epoch_archive: Account<'info, EpochArchive>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Ends the current season: archives its counters and accumulator, freezes its BlockMap,
+/// and starts an empty grid. Parcels from the old epoch keep their land-buy rewards.
+pub fn handler(ctx: Context<StartNewEpoch>, _grid_id: u64) -> Result<()> {
+    let mut block_map = BlockMap::load_mut(&ctx.accounts.block_map)?;
+    block_map.freeze();
+    let mut new_block_map = BlockMap::load_mut(&ctx.accounts.new_block_map)?;
+    require!(!new_block_map.is_frozen(), BillionError::EpochFrozen);
+    new_block_map.bind()?;
+    // Districts, vouchers and hooks are laid out on the current grid, so it keeps its size
+    require!(
+        new_block_map.grid_size() == block_map.grid_size(),
+        BillionError::InvalidGridSize
+    );
+
+    let config = &mut ctx.accounts.grid_config;
+    let archive = &mut ctx.accounts.epoch_archive;
+    archive.epoch = config.epoch;
+    archive.block_map = config.block_map;
+    archive.next_parcel_id = config.next_parcel_id;
+    archive.total_claimed_blocks = config.total_claimed_blocks;
+    archive.land_buy_rewards_per_block = config.land_buy_rewards_per_block;
+    archive.total_burned = config.total_burned;
+    archive.archived_at = Clock::get()?.unix_timestamp;
+    archive.bump = ctx.bumps.epoch_archive;
+    archive._reserved = [0u8; 32];
+
+    config.epoch = config.epoch.checked_add(1).ok_or(BillionError::Overflow)?;
+    config.block_map = ctx.accounts.new_block_map.key();
+    config.next_parcel_id = 1; // 0 means unclaimed
+    config.total_claimed_blocks = 0;
+    config.land_buy_rewards_per_block = 0;
+
+    msg!(
+        "Archived epoch {} ({} parcels), started epoch {}",
+        archive.epoch,
+        archive.next_parcel_id - 1,
+        config.epoch
+    );
+    Ok(())
+}
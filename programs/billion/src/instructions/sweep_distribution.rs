@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022,
+    token_interface::{Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface},
+};
+use crate::state::{grid_seed, Distribution, GridConfig};
+use crate::errors::BillionError;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct SweepDistribution<'info> {
+    #[account(mut)]
+    pub distributor: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        mut,
+        has_one = distributor @ BillionError::Unauthorized,
+        has_one = mint @ BillionError::Unauthorized,
+        close = distributor,
+    )]
+    pub distribution: Account<'info, Distribution>,
+
+    #[account(
+        mut,
+        seeds = [Distribution::VAULT_SEED, distribution.key().as_ref()],
+        bump = distribution.vault_bump
+    )]
+    pub vault: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = distributor,
+        token::token_program = token_program,
+    )]
+    pub distributor_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Returns unclaimed tokens after expiry and closes the vault and distribution
+pub fn handler(ctx: Context<SweepDistribution>, grid_id: u64) -> Result<()> {
+    require!(
+        Clock::get()?.unix_timestamp >= ctx.accounts.distribution.expires_at,
+        BillionError::DistributionNotExpired
+    );
+
+    let bump = ctx.accounts.grid_config.bump;
+    let grid_bytes = grid_seed(grid_id);
+    let seeds: &[&[u8]] = &[GridConfig::SEED, &grid_bytes, &[bump]];
+    let signer_seeds: &[&[&[u8]]] = &[seeds];
+
+    let remaining = ctx.accounts.vault.amount;
+    if remaining > 0 {
+        token_2022::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_2022::TransferChecked {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.distributor_token_account.to_account_info(),
+                    authority: ctx.accounts.grid_config.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            remaining,
+            ctx.accounts.mint.decimals,
+        )?;
+    }
+
+    token_2022::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token_2022::CloseAccount {
+            account: ctx.accounts.vault.to_account_info(),
+            destination: ctx.accounts.distributor.to_account_info(),
+            authority: ctx.accounts.grid_config.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    msg!("Swept {} unclaimed tokens from distribution {}", remaining, ctx.accounts.distribution.key());
+    Ok(())
+}
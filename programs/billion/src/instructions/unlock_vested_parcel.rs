@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use mpl_core::instructions::UpdatePluginV1CpiBuilder;
+use mpl_core::types::{FreezeDelegate, Plugin};
+use crate::state::{epoch_seed, grid_seed, GridConfig, ParcelInfo};
+use crate::errors::BillionError;
+use crate::events::{VestedParcelUnlocked, EVENT_SCHEMA_VERSION};
+#[cfg(not(feature = "localnet"))]
+use crate::instructions::claim_parcel::MPL_CORE_ID;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16)]
+pub struct UnlockVestedParcel<'info> {
+    /// Anyone; pays for any asset resize
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        mut,
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+
+    /// The Metaplex Core asset - must match parcel_info.asset
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        mut,
+        constraint = asset.key() == parcel_info.asset @ BillionError::AssetMismatch
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    /// Core collection - must match grid_config.collection
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        mut,
+        constraint = collection.key() == grid_config.collection @ BillionError::InvalidCollection
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Permissionless once the vesting date has passed. The thawed FreezeDelegate stays on the
+/// asset (only the owner can remove it); stake_parcel reuses it.
+pub fn handler(ctx: Context<UnlockVestedParcel>, grid_id: u64, parcel_id: u16) -> Result<()> {
+    let locked_until = ctx.accounts.parcel_info.locked_until;
+    require!(locked_until != 0, BillionError::NotVested);
+    let now = Clock::get()?.unix_timestamp;
+    require!(now >= locked_until, BillionError::VestingNotEnded);
+
+    let bump = ctx.accounts.grid_config.bump;
+    UpdatePluginV1CpiBuilder::new(&ctx.accounts.mpl_core_program.to_account_info())
+        .asset(&ctx.accounts.asset.to_account_info())
+        .collection(Some(&ctx.accounts.collection.to_account_info()))
+        .payer(&ctx.accounts.payer.to_account_info())
+        .authority(Some(&ctx.accounts.grid_config.to_account_info()))
+        .system_program(&ctx.accounts.system_program.to_account_info())
+        .plugin(Plugin::FreezeDelegate(FreezeDelegate { frozen: false }))
+        .invoke_signed(&[&[GridConfig::SEED, &grid_seed(grid_id), &[bump]]])?;
+
+    // Held-back rewards start their expiry clock at the vesting date, not the last claim
+    let parcel_info = &mut ctx.accounts.parcel_info;
+    parcel_info.last_claimed_at = parcel_info.expiry_clock_start();
+    parcel_info.locked_until = 0;
+    parcel_info.lock_rewards = false;
+
+    msg!("Unlocked vested parcel {}", parcel_id);
+    emit!(VestedParcelUnlocked {
+        schema_version: EVENT_SCHEMA_VERSION,
+        parcel_id,
+        epoch: parcel_info.epoch,
+        asset: parcel_info.asset,
+        locked_until,
+        unlocked_at: now,
+    });
+    Ok(())
+}
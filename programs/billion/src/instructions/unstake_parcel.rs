@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_interface::{Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface},
+    associated_token::AssociatedToken,
+};
+use mpl_core::instructions::{RemovePluginV1CpiBuilder, UpdatePluginV1CpiBuilder};
+use mpl_core::types::{FreezeDelegate, Plugin, PluginType};
+use crate::state::{epoch_seed, grid_seed, GridConfig, ParcelInfo, StakeAccount, EMISSIONS_VAULT_SEED};
+use crate::errors::BillionError;
+use crate::instructions::claim_staking_rewards::{settle_staking_rewards, EmissionAccounts};
+#[cfg(not(feature = "localnet"))]
+use crate::instructions::claim_parcel::MPL_CORE_ID;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16)]
+pub struct UnstakeParcel<'info> {
+    #[account(mut)]
+    pub staker: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+
+    #[account(
+        mut,
+        seeds = [StakeAccount::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = stake_account.bump,
+        has_one = staker @ BillionError::NotOwner,
+        close = staker,
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    /// The Metaplex Core asset - must match parcel_info.asset
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        mut,
+        constraint = asset.key() == parcel_info.asset @ BillionError::AssetMismatch
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    /// Core collection - must match grid_config.collection
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        mut,
+        constraint = collection.key() == grid_config.collection @ BillionError::InvalidCollection
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    /// Emissions vault paying the final rewards
+    #[account(
+        mut,
+        seeds = [EMISSIONS_VAULT_SEED, grid_config.key().as_ref()],
+        bump,
+        constraint = emissions_vault.key() == grid_config.emissions_vault @ BillionError::EmissionsDisabled
+    )]
+    pub emissions_vault: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    /// Staker's token account to receive emissions
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = staker,
+        associated_token::token_program = token_program,
+    )]
+    pub staker_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    #[account(
+        constraint = token_mint.key() == grid_config.token_mint @ BillionError::Unauthorized
+    )]
+    pub token_mint: InterfaceAccount<'info, InterfaceMint>,
+
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<UnstakeParcel>, grid_id: u64, parcel_id: u16) -> Result<()> {
+    // Pay out everything earned so far before the stake account closes
+    let block_count = ctx.accounts.parcel_info.block_count();
+    let paid = settle_staking_rewards(
+        EmissionAccounts {
+            grid_id,
+            grid_config: &ctx.accounts.grid_config,
+            stake_account: &mut ctx.accounts.stake_account,
+            emissions_vault: &ctx.accounts.emissions_vault,
+            staker_token_account: &ctx.accounts.staker_token_account,
+            token_mint: &ctx.accounts.token_mint,
+            token_program: &ctx.accounts.token_program,
+        },
+        block_count,
+    )?;
+
+    let mpl_core_program = ctx.accounts.mpl_core_program.to_account_info();
+    let asset = ctx.accounts.asset.to_account_info();
+    let collection = ctx.accounts.collection.to_account_info();
+    let staker = ctx.accounts.staker.to_account_info();
+    let grid_config = ctx.accounts.grid_config.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+
+    // Thaw as the FreezeDelegate authority (GridConfig PDA)
+    let bump = ctx.accounts.grid_config.bump;
+    let grid_bytes = grid_seed(grid_id);
+    let seeds: &[&[u8]] = &[GridConfig::SEED, &grid_bytes, &[bump]];
+    let signer_seeds: &[&[&[u8]]] = &[seeds];
+
+    UpdatePluginV1CpiBuilder::new(&mpl_core_program)
+        .asset(&asset)
+        .collection(Some(&collection))
+        .payer(&staker)
+        .authority(Some(&grid_config))
+        .system_program(&system_program)
+        .plugin(Plugin::FreezeDelegate(FreezeDelegate { frozen: false }))
+        .invoke_signed(signer_seeds)?;
+
+    // Owner removes the thawed plugin so the asset is back to its pre-stake state
+    RemovePluginV1CpiBuilder::new(&mpl_core_program)
+        .asset(&asset)
+        .collection(Some(&collection))
+        .payer(&staker)
+        .authority(Some(&staker))
+        .system_program(&system_program)
+        .plugin_type(PluginType::FreezeDelegate)
+        .invoke()?;
+
+    msg!("Unstaked parcel {}, paid {} staking tokens", parcel_id, paid);
+    Ok(())
+}
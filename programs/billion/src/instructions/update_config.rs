@@ -1,24 +1,163 @@
 use anchor_lang::prelude::*;
-use crate::state::GridConfig;
+use crate::state::{
+    grid_seed, AdminMintAllowance, Allowlist, ClaimsPause, GridConfig, RingPricing, AdminRoles, ConfigTimelock, TokenTreasury,
+    ConfigFreeze, ReferralShare, RingUnlockSchedule,
+};
 use crate::errors::BillionError;
+use crate::events::{
+    ConfigUpdated, CONFIG_FIELD_ADMIN_MINT_ALLOWANCE, CONFIG_FIELD_ALLOWLIST_ONLY, CONFIG_FIELD_ALLOWLIST_ROOT,
+    CONFIG_FIELD_CLAIMS_ENABLED, CONFIG_FIELD_COLLECTION, CONFIG_FIELD_CURVE_DIVISOR,
+    CONFIG_FIELD_LAND_OWNERS_REWARD_SHARE, CONFIG_FIELD_MAX_BLOCKS_PER_WALLET, CONFIG_FIELD_MAX_PARCEL_BLOCKS,
+    CONFIG_FIELD_PRICE_PER_BLOCK, CONFIG_FIELD_RING_PRICE_MULTIPLIERS, CONFIG_FIELD_RING_THRESHOLDS,
+    CONFIG_FIELD_RING_UNLOCK_TIMESTAMPS, CONFIG_FIELD_SEEDING_ENABLED, CONFIG_FIELD_URI_BASE, EVENT_SCHEMA_VERSION,
+};
 
 #[derive(Accounts)]
+#[instruction(grid_id: u64)]
 pub struct UpdateConfig<'info> {
-    #[account(
-        constraint = authority.key() == grid_config.authority @ BillionError::Unauthorized
-    )]
+    /// The config authority
+    #[account(mut)]
     pub authority: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [GridConfig::SEED],
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
         bump = grid_config.bump
     )]
     pub grid_config: Account<'info, GridConfig>,
+
+    /// Created with flat multipliers on the first update_config after its introduction
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + RingPricing::INIT_SPACE,
+        seeds = [RingPricing::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub ring_pricing: Account<'info, RingPricing>,
+
+    /// Created empty on the first update_config after its introduction
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + Allowlist::INIT_SPACE,
+        seeds = [Allowlist::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub allowlist: Account<'info, Allowlist>,
+
+    /// Created empty on the first update_config after its introduction
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + AdminMintAllowance::INIT_SPACE,
+        seeds = [AdminMintAllowance::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub admin_mint_allowance: Account<'info, AdminMintAllowance>,
+
+    /// Created on the first update_config after its introduction; records when claims were
+    /// paused for admin_purge
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ClaimsPause::INIT_SPACE,
+        seeds = [ClaimsPause::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub claims_pause: Account<'info, ClaimsPause>,
+
+    /// Created empty on the first update_config after its introduction
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + RingUnlockSchedule::INIT_SPACE,
+        seeds = [RingUnlockSchedule::SEED, &grid_seed(grid_id)],
+        bump
+    )]
+    pub ring_unlock_schedule: Account<'info, RingUnlockSchedule>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Names the config authority; the main authority holds every role until it's created
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [AdminRoles::SEED, &grid_seed(grid_id)], bump)]
+    pub admin_roles: UncheckedAccount<'info>,
+
+    /// While it holds a timelock, price, ring thresholds, reward share, ring multipliers and
+    /// curve divisor go through queue_config_update instead
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [ConfigTimelock::SEED, &grid_seed(grid_id)], bump)]
+    pub config_timelock: UncheckedAccount<'info>,
+
+    /// Treasury share the landowner share must leave room for, with the referral and charity shares
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [TokenTreasury::SEED, &grid_seed(grid_id)], bump)]
+    pub token_treasury: UncheckedAccount<'info>,
+
+    /// Direct referral share the landowner share must leave room for
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [ReferralShare::SEED, &grid_seed(grid_id)], bump)]
+    pub referral_share: UncheckedAccount<'info>,
+
+    /// Must still be empty: freeze_config creating it disables this instruction for good
+    /// CHECK: Seeds are verified here, only whether it exists is read
+    #[account(
+        seeds = [ConfigFreeze::SEED, &grid_seed(grid_id)],
+        bump,
+        constraint = !ConfigFreeze::is_frozen(&config_freeze) @ BillionError::ConfigFrozen
+    )]
+    pub config_freeze: UncheckedAccount<'info>,
+}
+
+/// The ConfigUpdated fields as they stood before an update
+pub(crate) struct ConfigSnapshot {
+    price_per_block: u64,
+    land_owners_reward_share_bps: u16,
+    seeding_enabled: bool,
+    collection: Pubkey,
+    uri_base: String,
+    ring_thresholds_hash: [u8; 32],
+}
+
+impl ConfigSnapshot {
+    pub(crate) fn of(config: &GridConfig) -> Self {
+        Self {
+            price_per_block: config.price_per_block,
+            land_owners_reward_share_bps: config.land_owners_reward_share_bps,
+            seeding_enabled: config.seeding_enabled,
+            collection: config.collection,
+            uri_base: config.uri_base.clone(),
+            ring_thresholds_hash: config.ring_thresholds_hash(),
+        }
+    }
+
+    /// ConfigUpdated from this snapshot to `config`
+    pub(crate) fn updated(self, config: &GridConfig, authority: Pubkey, changed_fields: u32) -> ConfigUpdated {
+        ConfigUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            authority,
+            changed_fields,
+            old_price_per_block: self.price_per_block,
+            new_price_per_block: config.price_per_block,
+            old_land_owners_reward_share_bps: self.land_owners_reward_share_bps,
+            new_land_owners_reward_share_bps: config.land_owners_reward_share_bps,
+            old_seeding_enabled: self.seeding_enabled,
+            new_seeding_enabled: config.seeding_enabled,
+            old_collection: self.collection,
+            new_collection: config.collection,
+            old_uri_base: self.uri_base,
+            new_uri_base: config.uri_base.clone(),
+            old_ring_thresholds_hash: self.ring_thresholds_hash,
+            new_ring_thresholds_hash: config.ring_thresholds_hash(),
+        }
+    }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<UpdateConfig>,
+    _grid_id: u64,
     price_per_block: Option<u64>,
     ring_thresholds: Option<Vec<u64>>,
     uri_base: Option<String>,
@@ -26,43 +165,178 @@ pub fn handler(
     collection: Option<Pubkey>,
     land_owners_reward_share_bps: Option<u16>,
     total_burned: Option<u64>,
+    max_parcel_blocks: Option<u16>,
+    max_blocks_per_wallet: Option<u32>,
+    claims_enabled: Option<bool>,
+    ring_price_multipliers_bps: Option<Vec<u16>>,
+    curve_divisor: Option<u64>,
+    allowlist_root: Option<[u8; 32]>,
+    allowlist_only: Option<bool>,
+    admin_mint_allowance_top_up: Option<u32>,
+    force_collection: Option<bool>,
+    ring_unlock_timestamps: Option<Vec<i64>>,
 ) -> Result<()> {
+    let roles = AdminRoles::load(&ctx.accounts.admin_roles, &ctx.accounts.grid_config)?;
+    require_keys_eq!(roles.config_authority, ctx.accounts.authority.key(), BillionError::Unauthorized);
+    if ConfigTimelock::load(&ctx.accounts.config_timelock)?.timelock_secs > 0 {
+        require!(
+            price_per_block.is_none()
+                && ring_thresholds.is_none()
+                && land_owners_reward_share_bps.is_none()
+                && ring_price_multipliers_bps.is_none()
+                && curve_divisor.is_none(),
+            BillionError::ConfigTimelocked
+        );
+    }
+
+    let authority = ctx.accounts.authority.key();
+    let now = Clock::get()?.unix_timestamp;
     let config = &mut ctx.accounts.grid_config;
+    let schedule = &mut ctx.accounts.ring_unlock_schedule;
+    let previously_unlocked = config.unlocked_ring_at(schedule, now);
+    let before = ConfigSnapshot::of(config);
+    let mut changed_fields = 0;
+
+    if let Some(bps) = land_owners_reward_share_bps {
+        let treasury_bps = TokenTreasury::load(&ctx.accounts.token_treasury)?.share_bps;
+        let referral_bps = ReferralShare::load(&ctx.accounts.referral_share)?.with_referral_bps(config.referral_bps);
+        require!(
+            GridConfig::valid_split_bps(bps, treasury_bps, referral_bps, config.max_charity_bps),
+            BillionError::InvalidRewardShare
+        );
+    }
+    if let Some(thresholds) = &ring_thresholds {
+        // Another count would move claimed blocks into other rings
+        GridConfig::check_ring_thresholds(thresholds, config.ring_count())?;
+    }
+    if let Some(uri) = &uri_base {
+        require!(GridConfig::valid_uri_base(uri), BillionError::InvalidUriBase);
+    }
+    if let Some(timestamps) = &ring_unlock_timestamps {
+        require!(
+            RingUnlockSchedule::valid_timestamps(timestamps, config.ring_count()),
+            BillionError::InvalidRingUnlockSchedule
+        );
+    }
+    // Still part of the instruction so existing callers passing None keep working; rings are
+    // unlocked by hand only through admin_advance_ring, which records it
+    require!(total_burned.is_none(), BillionError::TotalBurnedOverrideRemoved);
 
     if let Some(price) = price_per_block {
+        changed_fields |= CONFIG_FIELD_PRICE_PER_BLOCK;
         config.price_per_block = price;
         msg!("Updated price_per_block to {}", price);
     }
 
     if let Some(thresholds) = ring_thresholds {
+        changed_fields |= CONFIG_FIELD_RING_THRESHOLDS;
         config.ring_thresholds = thresholds;
         msg!("Updated ring_thresholds");
     }
 
     if let Some(uri) = uri_base {
+        changed_fields |= CONFIG_FIELD_URI_BASE;
         config.uri_base = uri;
         msg!("Updated uri_base");
     }
 
     if let Some(enabled) = seeding_enabled {
+        changed_fields |= CONFIG_FIELD_SEEDING_ENABLED;
         config.seeding_enabled = enabled;
         msg!("Updated seeding_enabled to {}", enabled);
     }
 
     if let Some(coll) = collection {
-        config.collection = coll;
-        msg!("Updated collection to {}", coll);
+        changed_fields |= CONFIG_FIELD_COLLECTION;
+        // Parcels already minted stay in the old collection
+        if coll != config.collection && config.collection_locked() {
+            require!(force_collection == Some(true), BillionError::CollectionLocked);
+        }
+        config.set_collection(coll).ok_or(BillionError::Overflow)?;
+        msg!("Updated collection to {} (generation {})", coll, config.collection_generation);
     }
 
     if let Some(bps) = land_owners_reward_share_bps {
+        changed_fields |= CONFIG_FIELD_LAND_OWNERS_REWARD_SHARE;
         config.land_owners_reward_share_bps = bps;
         msg!("Updated land_owners_reward_share_bps to {}", bps);
     }
 
-    if let Some(burned) = total_burned {
-        config.total_burned = burned;
-        msg!("Updated total_burned to {}", burned);
+    if let Some(max_blocks) = max_parcel_blocks {
+        changed_fields |= CONFIG_FIELD_MAX_PARCEL_BLOCKS;
+        config.max_parcel_blocks = max_blocks;
+        msg!("Updated max_parcel_blocks to {}", max_blocks);
+    }
+
+    if let Some(max_blocks) = max_blocks_per_wallet {
+        changed_fields |= CONFIG_FIELD_MAX_BLOCKS_PER_WALLET;
+        config.max_blocks_per_wallet = max_blocks;
+        msg!("Updated max_blocks_per_wallet to {}", max_blocks);
+    }
+
+    if let Some(enabled) = claims_enabled {
+        changed_fields |= CONFIG_FIELD_CLAIMS_ENABLED;
+        config.claims_paused = !enabled;
+        msg!("Updated claims_enabled to {}", enabled);
     }
+    let claims_pause = &mut ctx.accounts.claims_pause;
+    claims_pause.bump = ctx.bumps.claims_pause;
+    claims_pause.sync(config.claims_paused, Clock::get()?.slot);
+
+    // A just-created account is all zeros, which no valid configuration is
+    let ring_pricing = &mut ctx.accounts.ring_pricing;
+    if ring_pricing.multipliers_bps.iter().all(|&bps| bps == 0) {
+        **ring_pricing = RingPricing::flat(ctx.bumps.ring_pricing);
+    }
+    if let Some(multipliers) = ring_price_multipliers_bps {
+        changed_fields |= CONFIG_FIELD_RING_PRICE_MULTIPLIERS;
+        require!(
+            RingPricing::valid_multipliers(&multipliers, config.ring_count()),
+            BillionError::InvalidRingMultipliers
+        );
+        ring_pricing.set_multipliers(&multipliers);
+        msg!("Updated ring_price_multipliers_bps");
+    }
+
+    if let Some(divisor) = curve_divisor {
+        changed_fields |= CONFIG_FIELD_CURVE_DIVISOR;
+        ring_pricing.curve_divisor = divisor;
+        msg!("Updated curve_divisor to {}", divisor);
+    }
+
+    let allowlist = &mut ctx.accounts.allowlist;
+    allowlist.bump = ctx.bumps.allowlist;
+    if let Some(root) = allowlist_root {
+        changed_fields |= CONFIG_FIELD_ALLOWLIST_ROOT;
+        allowlist.root = root;
+        msg!("Updated allowlist_root");
+    }
+
+    if let Some(enabled) = allowlist_only {
+        changed_fields |= CONFIG_FIELD_ALLOWLIST_ONLY;
+        config.allowlist_only = enabled;
+        msg!("Updated allowlist_only to {}", enabled);
+    }
+
+    let allowance = &mut ctx.accounts.admin_mint_allowance;
+    allowance.bump = ctx.bumps.admin_mint_allowance;
+    if let Some(top_up) = admin_mint_allowance_top_up {
+        changed_fields |= CONFIG_FIELD_ADMIN_MINT_ALLOWANCE;
+        allowance.remaining = allowance.remaining.checked_add(top_up).ok_or(BillionError::Overflow)?;
+        msg!("Topped up admin_mint_allowance by {} to {}", top_up, allowance.remaining);
+    }
+
+    schedule.bump = ctx.bumps.ring_unlock_schedule;
+    if let Some(timestamps) = ring_unlock_timestamps {
+        changed_fields |= CONFIG_FIELD_RING_UNLOCK_TIMESTAMPS;
+        msg!("Updated ring_unlock_timestamps to {:?}", timestamps);
+        schedule.ring_unlock_timestamps = timestamps;
+    }
+
+    // Lowered thresholds and a schedule already due can unlock rings too
+    let unlocked = config.unlocked_ring_at(schedule, now);
+    config.record_ring_unlock(previously_unlocked, unlocked, now);
 
+    emit!(before.updated(config, authority, changed_fields));
     Ok(())
 }
@@ -1,26 +1,36 @@
 use anchor_lang::prelude::*;
 use mpl_core::instructions::UpdateV1CpiBuilder;
-use crate::state::GridConfig;
+use crate::state::{epoch_seed, grid_seed, AdminRoles, GridConfig, ParcelInfo};
+use crate::constants::{MAX_PARCEL_NAME_LEN, MAX_PARCEL_URI_LEN};
 use crate::errors::BillionError;
+use crate::instructions::claim_land_buy_rewards::require_asset_in_collection;
+#[cfg(not(feature = "localnet"))]
 use crate::instructions::claim_parcel::MPL_CORE_ID;
 
 #[derive(Accounts)]
+#[instruction(grid_id: u64, parcel_id: u16)]
 pub struct UpdateParcelMetadata<'info> {
-    /// Only the grid authority can update parcel metadata
-    #[account(
-        constraint = authority.key() == grid_config.authority @ BillionError::Unauthorized
-    )]
+    /// Only the metadata authority can update parcel metadata
     pub authority: Signer<'info>,
 
     #[account(
-        seeds = [GridConfig::SEED],
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
         bump = grid_config.bump
     )]
     pub grid_config: Account<'info, GridConfig>,
 
-    /// The Core asset to update
-    /// CHECK: Validated by Metaplex Core program
-    #[account(mut)]
+    #[account(
+        seeds = [ParcelInfo::SEED, &grid_seed(grid_id), &epoch_seed(parcel_info.epoch), &parcel_id.to_le_bytes()],
+        bump = parcel_info.bump
+    )]
+    pub parcel_info: Account<'info, ParcelInfo>,
+
+    /// The Core asset to update - must match parcel_info.asset
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        mut,
+        constraint = asset.key() == parcel_info.asset @ BillionError::AssetMismatch
+    )]
     pub asset: UncheckedAccount<'info>,
 
     /// Core collection - must match grid_config.collection
@@ -30,20 +40,39 @@ pub struct UpdateParcelMetadata<'info> {
     )]
     pub collection: UncheckedAccount<'info>,
 
-    /// CHECK: Metaplex Core program
-    #[account(address = MPL_CORE_ID)]
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
     pub mpl_core_program: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Names the metadata authority; the main authority holds every role until it's created
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [AdminRoles::SEED, &grid_seed(grid_id)], bump)]
+    pub admin_roles: UncheckedAccount<'info>,
 }
 
 pub fn handler(
     ctx: Context<UpdateParcelMetadata>,
+    grid_id: u64,
+    parcel_id: u16,
     new_name: Option<String>,
     new_uri: Option<String>,
 ) -> Result<()> {
+    let roles = AdminRoles::load(&ctx.accounts.admin_roles, &ctx.accounts.grid_config)?;
+    require_keys_eq!(roles.metadata_authority, ctx.accounts.authority.key(), BillionError::Unauthorized);
+
+    if let Some(name) = &new_name {
+        require!(name.len() <= MAX_PARCEL_NAME_LEN as usize, BillionError::NameTooLong);
+    }
+    if let Some(uri) = &new_uri {
+        require!(uri.len() <= MAX_PARCEL_URI_LEN as usize, BillionError::UriTooLong);
+    }
+
     let mpl_core_program = ctx.accounts.mpl_core_program.to_account_info();
     let asset = ctx.accounts.asset.to_account_info();
+    require_asset_in_collection(&asset, &ctx.accounts.grid_config.collection)?;
     let authority = ctx.accounts.authority.to_account_info();
     let grid_config = ctx.accounts.grid_config.to_account_info();
     let collection = ctx.accounts.collection.to_account_info();
@@ -51,7 +80,8 @@ pub fn handler(
 
     // Get the grid_config bump for PDA signing (collection authority is the GridConfig PDA)
     let bump = ctx.accounts.grid_config.bump;
-    let seeds: &[&[u8]] = &[GridConfig::SEED, &[bump]];
+    let grid_bytes = grid_seed(grid_id);
+    let seeds: &[&[u8]] = &[GridConfig::SEED, &grid_bytes, &[bump]];
     let signer_seeds: &[&[&[u8]]] = &[seeds];
 
     let mut builder = UpdateV1CpiBuilder::new(&mpl_core_program);
@@ -73,7 +103,7 @@ pub fn handler(
 
     builder.invoke_signed(signer_seeds)?;
 
-    msg!("Updated parcel metadata");
+    msg!("Updated metadata of parcel {}", parcel_id);
 
     Ok(())
 }
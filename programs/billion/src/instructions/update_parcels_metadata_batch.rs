@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use mpl_core::instructions::UpdateV1CpiBuilder;
+use crate::state::{grid_seed, AdminRoles, GridConfig};
+use crate::constants::{MAX_METADATA_UPDATE_BATCH, MAX_PARCEL_URI_LEN};
+use crate::errors::BillionError;
+use crate::instructions::claim_land_buy_rewards::require_asset_in_collection;
+use crate::instructions::claim_land_buy_rewards_batch::load_parcel_info;
+#[cfg(not(feature = "localnet"))]
+use crate::instructions::claim_parcel::MPL_CORE_ID;
+
+/// Same accounts as UpdateParcelMetadata minus the asset; assets, and in derived mode their
+/// ParcelInfo accounts, come in remaining_accounts
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct UpdateParcelsMetadataBatch<'info> {
+    /// The metadata authority; pays for any growth of the assets
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    /// Names the metadata authority; the main authority holds every role until it's created
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [AdminRoles::SEED, &grid_seed(grid_id)], bump)]
+    pub admin_roles: UncheckedAccount<'info>,
+
+    /// Core collection - must match grid_config.collection
+    /// CHECK: Validated by constraint and Metaplex Core program
+    #[account(
+        constraint = collection.key() == grid_config.collection @ BillionError::InvalidCollection
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Core program (any executable program with the `localnet` feature)
+    #[cfg_attr(not(feature = "localnet"), account(address = MPL_CORE_ID))]
+    #[cfg_attr(feature = "localnet", account(executable))]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Points the uri of up to MAX_METADATA_UPDATE_BATCH assets at a uri base followed by their
+/// parcel id, as minting does. With `uri_base`, remaining_accounts are the assets in
+/// `parcel_ids` order and the caller vouches for each id. Without it, the grid's own uri_base
+/// is used and remaining_accounts are (ParcelInfo, asset) pairs: each ParcelInfo must be the
+/// PDA of its parcel id and name the asset, so no asset can get another parcel's uri.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateParcelsMetadataBatch<'info>>,
+    grid_id: u64,
+    parcel_ids: Vec<u16>,
+    uri_base: Option<String>,
+) -> Result<()> {
+    let roles = AdminRoles::load(&ctx.accounts.admin_roles, &ctx.accounts.grid_config)?;
+    require_keys_eq!(roles.metadata_authority, ctx.accounts.authority.key(), BillionError::Unauthorized);
+    require!(
+        !parcel_ids.is_empty() && parcel_ids.len() <= MAX_METADATA_UPDATE_BATCH as usize,
+        BillionError::InvalidBatch
+    );
+    if let Some(uri_base) = &uri_base {
+        require!(GridConfig::valid_uri_base(uri_base), BillionError::InvalidUriBase);
+    }
+    let derived = uri_base.is_none();
+    let accounts_per_parcel = if derived { 2 } else { 1 };
+    require!(
+        ctx.remaining_accounts.len() == accounts_per_parcel * parcel_ids.len(),
+        BillionError::InvalidBatch
+    );
+    let uri_base = uri_base.unwrap_or_else(|| ctx.accounts.grid_config.uri_base.clone());
+
+    let mpl_core_program = ctx.accounts.mpl_core_program.to_account_info();
+    let collection = ctx.accounts.collection.to_account_info();
+    let authority = ctx.accounts.authority.to_account_info();
+    let grid_config = ctx.accounts.grid_config.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let bump = ctx.accounts.grid_config.bump;
+    let grid_bytes = grid_seed(grid_id);
+    let seeds: &[&[u8]] = &[GridConfig::SEED, &grid_bytes, &[bump]];
+
+    for (&parcel_id, accounts) in parcel_ids.iter().zip(ctx.remaining_accounts.chunks(accounts_per_parcel)) {
+        let asset = &accounts[accounts_per_parcel - 1];
+        if derived {
+            let parcel_info = load_parcel_info(&accounts[0], grid_id, parcel_id)?;
+            require!(asset.key() == parcel_info.asset, BillionError::AssetMismatch);
+        }
+        require!(asset.is_writable, BillionError::InvalidBatch);
+        require_asset_in_collection(asset, &ctx.accounts.grid_config.collection)?;
+
+        let uri = format!("{}{}", uri_base, parcel_id);
+        require!(uri.len() <= MAX_PARCEL_URI_LEN as usize, BillionError::UriTooLong);
+        UpdateV1CpiBuilder::new(&mpl_core_program)
+            .asset(asset)
+            .collection(Some(&collection))
+            .authority(Some(&grid_config))
+            .payer(&authority)
+            .system_program(&system_program)
+            .new_uri(uri)
+            .invoke_signed(&[seeds])?;
+    }
+
+    msg!("Updated the uri of {} parcels", parcel_ids.len());
+    Ok(())
+}
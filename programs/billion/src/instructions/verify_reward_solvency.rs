@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount as InterfaceTokenAccount;
+use crate::state::{grid_seed, EpochArchive, GridConfig, LAND_BUY_REWARD_POOL_SEED};
+use crate::errors::BillionError;
+use crate::events::{RewardSolvencyVerified, SolvencyWarning, EVENT_SCHEMA_VERSION};
+use crate::instructions::claim_land_buy_rewards::{parcel_rewards_per_block, pending_land_buy_rewards};
+use crate::instructions::claim_land_buy_rewards_batch::load_parcel_info;
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct VerifyRewardSolvency<'info> {
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        seeds = [LAND_BUY_REWARD_POOL_SEED, grid_config.key().as_ref()],
+        bump,
+        constraint = land_buy_reward_pool.key() == grid_config.land_buy_reward_pool @ BillionError::InvalidRewardPool
+    )]
+    pub land_buy_reward_pool: InterfaceAccount<'info, InterfaceTokenAccount>,
+
+    /// Archive of an earlier epoch - required for parcels from that epoch
+    #[account(
+        seeds = [EpochArchive::SEED, &grid_seed(grid_id), &epoch_archive.epoch.to_le_bytes()],
+        bump = epoch_archive.bump
+    )]
+    pub epoch_archive: Option<Account<'info, EpochArchive>>,
+}
+
+/// Land-buy rewards pending on the parcels verified so far, against the pool, returned via
+/// return data. Exactly one of surplus and deficit is nonzero unless they balance.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SolvencyReport {
+    /// running_total plus what this batch's parcels are owed; the next batch's running_total
+    pub outstanding: u64,
+    pub pool_balance: u64,
+    pub surplus: u64,
+    pub deficit: u64,
+}
+
+/// Sums the land-buy rewards pending on each of `parcel_ids`, whose ParcelInfo accounts
+/// follow in remaining_accounts in the same order, onto `running_total` and compares the
+/// result with the reward pool. A grid too large for one transaction is verified in
+/// batches, each passing the previous report's outstanding; the caller keeps batches from
+/// overlapping. A deficit emits SolvencyWarning instead of failing.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, VerifyRewardSolvency<'info>>,
+    grid_id: u64,
+    parcel_ids: Vec<u16>,
+    running_total: u64,
+) -> Result<SolvencyReport> {
+    let accounts = ctx.remaining_accounts;
+    require!(accounts.len() == parcel_ids.len(), BillionError::InvalidBatch);
+    for (i, account) in accounts.iter().enumerate() {
+        require!(accounts[..i].iter().all(|other| other.key != account.key), BillionError::InvalidBatch);
+    }
+
+    let epoch_archive = ctx.accounts.epoch_archive.as_deref();
+    let mut outstanding = running_total;
+    for (&parcel_id, account) in parcel_ids.iter().zip(accounts) {
+        let parcel_info = load_parcel_info(account, grid_id, parcel_id)?;
+        let land_buy_rewards_per_block =
+            parcel_rewards_per_block(&parcel_info, &ctx.accounts.grid_config, epoch_archive)?;
+        let owed = pending_land_buy_rewards(&parcel_info, land_buy_rewards_per_block)?;
+        outstanding = outstanding.checked_add(owed).ok_or(BillionError::Overflow)?;
+    }
+
+    let pool_balance = ctx.accounts.land_buy_reward_pool.amount;
+    let report = SolvencyReport {
+        outstanding,
+        pool_balance,
+        surplus: pool_balance.saturating_sub(outstanding),
+        deficit: outstanding.saturating_sub(pool_balance),
+    };
+    emit!(RewardSolvencyVerified {
+        schema_version: EVENT_SCHEMA_VERSION,
+        parcels: parcel_ids.len() as u16,
+        outstanding,
+        pool_balance,
+    });
+    if report.deficit > 0 {
+        emit!(SolvencyWarning {
+            schema_version: EVENT_SCHEMA_VERSION,
+            outstanding,
+            pool_balance,
+            deficit: report.deficit,
+        });
+    }
+    Ok(report)
+}
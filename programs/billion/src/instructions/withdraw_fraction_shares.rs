@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+use crate::errors::BillionError;
+use crate::instructions::claim_fraction_rewards::FractionHolder;
+
+/// Returns `amount` deposited shares to the holder, paying out rewards earned so far
+pub fn handler(ctx: Context<FractionHolder>, grid_id: u64, parcel_id: u16, amount: u64) -> Result<()> {
+    require!(amount > 0, BillionError::InvalidShareAmount);
+    require!(amount <= ctx.accounts.position.deposited, BillionError::InsufficientBalance);
+    ctx.accounts.harvest_and_settle(grid_id, ctx.bumps.position)?;
+
+    ctx.accounts.position.deposited -= amount;
+    let fraction = &mut ctx.accounts.fraction;
+    fraction.total_deposited = fraction.total_deposited.checked_sub(amount).ok_or(BillionError::Overflow)?;
+    ctx.accounts.release_shares(grid_id, amount)?;
+
+    msg!(
+        "Withdrew {} shares of parcel {}, {} still deposited",
+        amount,
+        parcel_id,
+        ctx.accounts.position.deposited
+    );
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::BillionError;
+use crate::state::{grid_seed, GridConfig, AdminRoles, SOL_TREASURY_SEED};
+
+#[derive(Accounts)]
+#[instruction(grid_id: u64)]
+pub struct WithdrawSolTreasury<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GridConfig::SEED, &grid_seed(grid_id)],
+        bump = grid_config.bump,
+    )]
+    pub grid_config: Account<'info, GridConfig>,
+
+    #[account(
+        mut,
+        seeds = [SOL_TREASURY_SEED, grid_config.key().as_ref()],
+        bump
+    )]
+    pub sol_treasury: SystemAccount<'info>,
+
+    /// CHECK: Any account can receive lamports
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Names the treasury authority; the main authority holds every role until it's created
+    /// CHECK: Seeds are verified here, contents are deserialized by the handler when initialized
+    #[account(seeds = [AdminRoles::SEED, &grid_seed(grid_id)], bump)]
+    pub admin_roles: UncheckedAccount<'info>,
+}
+
+/// Sends `amount` lamports of SOL claim proceeds to `recipient`. The treasury keeps its
+/// rent-exempt minimum so it stays open for later claims.
+pub fn handler(ctx: Context<WithdrawSolTreasury>, _grid_id: u64, amount: u64) -> Result<()> {
+    let roles = AdminRoles::load(&ctx.accounts.admin_roles, &ctx.accounts.grid_config)?;
+    require_keys_eq!(roles.treasury_authority, ctx.accounts.authority.key(), BillionError::Unauthorized);
+
+    let treasury = &ctx.accounts.sol_treasury;
+    let available = treasury.lamports().saturating_sub(Rent::get()?.minimum_balance(0));
+    require!(amount > 0 && amount <= available, BillionError::InsufficientBalance);
+
+    let grid_config_key = ctx.accounts.grid_config.key();
+    let seeds: &[&[u8]] = &[SOL_TREASURY_SEED, grid_config_key.as_ref(), &[ctx.bumps.sol_treasury]];
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: treasury.to_account_info(),
+                to: ctx.accounts.recipient.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+    )?;
+
+    msg!("Withdrew {} lamports from the SOL treasury to {}", amount, ctx.accounts.recipient.key());
+    Ok(())
+}
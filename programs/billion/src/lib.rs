@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 
+pub mod constants;
 pub mod errors;
+pub mod events;
 pub mod state;
 pub mod utils;
 pub mod instructions;
@@ -13,22 +15,26 @@ declare_id!("BDBCR33yBuWjGJiGXoApW3qR9ajP2fGSJfzTP6SbYn6h");
 pub mod billion {
     use super::*;
 
-    pub fn create_block_map(ctx: Context<CreateBlockMap>) -> Result<()> {
-        instructions::create_block_map::handler(ctx)
+    pub fn create_block_map(ctx: Context<CreateBlockMap>, grid_size: u16) -> Result<()> {
+        instructions::create_block_map::handler(ctx, grid_size)
     }
 
     pub fn initialize(
         ctx: Context<Initialize>,
+        grid_id: u64,
         price_per_block: u64,
         ring_thresholds: Vec<u64>,
         uri_base: String,
         land_owners_reward_share_bps: u16,
+        max_parcel_blocks: u16,
     ) -> Result<()> {
-        instructions::initialize::handler(ctx, price_per_block, ring_thresholds, uri_base, land_owners_reward_share_bps)
+        instructions::initialize::handler(ctx, grid_id, price_per_block, ring_thresholds, uri_base, land_owners_reward_share_bps, max_parcel_blocks)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn update_config(
         ctx: Context<UpdateConfig>,
+        grid_id: u64,
         price_per_block: Option<u64>,
         ring_thresholds: Option<Vec<u64>>,
         uri_base: Option<String>,
@@ -36,59 +42,780 @@ pub mod billion {
         collection: Option<Pubkey>,
         land_owners_reward_share_bps: Option<u16>,
         total_burned: Option<u64>,
+        max_parcel_blocks: Option<u16>,
+        max_blocks_per_wallet: Option<u32>,
+        claims_enabled: Option<bool>,
+        ring_price_multipliers_bps: Option<Vec<u16>>,
+        curve_divisor: Option<u64>,
+        allowlist_root: Option<[u8; 32]>,
+        allowlist_only: Option<bool>,
+        admin_mint_allowance_top_up: Option<u32>,
+        force_collection: Option<bool>,
+        ring_unlock_timestamps: Option<Vec<i64>>,
     ) -> Result<()> {
-        instructions::update_config::handler(ctx, price_per_block, ring_thresholds, uri_base, seeding_enabled, collection, land_owners_reward_share_bps, total_burned)
+        instructions::update_config::handler(ctx, grid_id, price_per_block, ring_thresholds, uri_base, seeding_enabled, collection, land_owners_reward_share_bps, total_burned, max_parcel_blocks, max_blocks_per_wallet, claims_enabled, ring_price_multipliers_bps, curve_divisor, allowlist_root, allowlist_only, admin_mint_allowance_top_up, force_collection, ring_unlock_timestamps)
     }
 
-    pub fn claim_parcel(
-        ctx: Context<ClaimParcel>,
+    /// With `dry_run` set, writes a ClaimPreview to return data and fails with DryRunSuccess.
+    /// When the on-claim hook applies, remaining_accounts are the hook program followed by
+    /// the accounts passed through to it. `charity_index` selects a registered charity that
+    /// receives `charity_bps` of the cost out of the burned portion.
+    #[allow(clippy::too_many_arguments)]
+    pub fn claim_parcel<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimParcel<'info>>,
+        grid_id: u64,
         x: u8,
         y: u8,
         width: u8,
         height: u8,
+        dry_run: bool,
+        charity_index: Option<u8>,
+        charity_bps: u16,
+        allowlist_proof: Option<Vec<[u8; 32]>>,
     ) -> Result<()> {
-        instructions::claim_parcel::handler(ctx, x, y, width, height)
+        instructions::claim_parcel::handler(ctx, grid_id, x, y, width, height, dry_run, charity_index, charity_bps, allowlist_proof)
     }
 
+    pub fn claim_parcel_pda<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimParcelPda<'info>>,
+        grid_id: u64,
+        x: u8,
+        y: u8,
+        width: u8,
+        height: u8,
+    ) -> Result<()> {
+        instructions::claim_parcel_pda::handler(ctx, grid_id, x, y, width, height)
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn admin_mint(
         ctx: Context<AdminMint>,
+        grid_id: u64,
         x: u8,
         y: u8,
         width: u8,
         height: u8,
+        locked_until: Option<i64>,
+        lock_rewards: bool,
+        soulbound: bool,
+        rewards_excluded: bool,
     ) -> Result<()> {
-        instructions::admin_mint::handler(ctx, x, y, width, height)
+        instructions::admin_mint::handler(ctx, grid_id, x, y, width, height, locked_until, lock_rewards, soulbound, rewards_excluded)
     }
 
     pub fn update_parcel_metadata(
         ctx: Context<UpdateParcelMetadata>,
+        grid_id: u64,
+        parcel_id: u16,
         new_name: Option<String>,
         new_uri: Option<String>,
     ) -> Result<()> {
-        instructions::update_parcel_metadata::handler(ctx, new_name, new_uri)
+        instructions::update_parcel_metadata::handler(ctx, grid_id, parcel_id, new_name, new_uri)
+    }
+
+    /// Metadata-authority-only: point up to MAX_METADATA_UPDATE_BATCH asset uris at a uri base
+    /// and their parcel id. remaining_accounts are the assets with `uri_base`, or without it
+    /// (ParcelInfo, asset) pairs checked against `parcel_ids` and the grid's uri_base.
+    pub fn update_parcels_metadata_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateParcelsMetadataBatch<'info>>,
+        grid_id: u64,
+        parcel_ids: Vec<u16>,
+        uri_base: Option<String>,
+    ) -> Result<()> {
+        instructions::update_parcels_metadata_batch::handler(ctx, grid_id, parcel_ids, uri_base)
     }
 
     pub fn claim_land_buy_rewards(
         ctx: Context<ClaimLandBuyRewards>,
+        grid_id: u64,
         parcel_id: u16,
     ) -> Result<()> {
-        instructions::claim_land_buy_rewards::handler(ctx, parcel_id)
+        instructions::claim_land_buy_rewards::handler(ctx, grid_id, parcel_id)
     }
 
     pub fn admin_close_parcel_info(
         ctx: Context<AdminCloseParcelInfo>,
+        grid_id: u64,
         parcel_id: u16,
     ) -> Result<()> {
-        instructions::admin_close_parcel_info::handler(ctx, parcel_id)
+        instructions::admin_close_parcel_info::handler(ctx, grid_id, parcel_id)
     }
 
-    pub fn admin_purge(ctx: Context<AdminPurge>) -> Result<()> {
-        instructions::admin_purge::handler(ctx)
+    pub fn admin_purge(ctx: Context<AdminPurge>, grid_id: u64, force: bool) -> Result<()> {
+        instructions::admin_purge::handler(ctx, grid_id, force)
     }
 
     pub fn admin_transfer_nft_collection_authority(
         ctx: Context<AdminTransferNftCollectionAuthority>,
+        grid_id: u64,
+    ) -> Result<()> {
+        instructions::admin_transfer_nft_collection_authority::handler(ctx, grid_id)
+    }
+
+    pub fn get_grid_state(ctx: Context<GetGridState>, grid_id: u64) -> Result<GridState> {
+        instructions::get_grid_state::handler(ctx, grid_id)
+    }
+
+    /// To find the parcel under a block, read its id from the BlockMap first (see BlockMap)
+    pub fn get_parcel(ctx: Context<GetParcel>, grid_id: u64, parcel_id: u16) -> Result<ParcelView> {
+        instructions::get_parcel::handler(ctx, grid_id, parcel_id)
+    }
+
+    pub fn get_program_info(ctx: Context<GetProgramInfo>, grid_id: u64) -> Result<ProgramInfo> {
+        instructions::get_program_info::handler(ctx, grid_id)
+    }
+
+    pub fn register_referrer(ctx: Context<RegisterReferrer>, grid_id: u64) -> Result<()> {
+        instructions::register_referrer::handler(ctx, grid_id)
+    }
+
+    pub fn init_referral_vault(ctx: Context<InitReferralVault>, grid_id: u64) -> Result<()> {
+        instructions::init_referral_vault::handler(ctx, grid_id)
+    }
+
+    pub fn set_referral_bps(ctx: Context<SetReferralBps>, grid_id: u64, referral_bps: u16) -> Result<()> {
+        instructions::set_referral_bps::handler(ctx, grid_id, referral_bps)
+    }
+
+    pub fn claim_referral_earnings(ctx: Context<ClaimReferralEarnings>, grid_id: u64) -> Result<()> {
+        instructions::claim_referral_earnings::handler(ctx, grid_id)
+    }
+
+    pub fn init_emissions_vault(ctx: Context<InitEmissionsVault>, grid_id: u64) -> Result<()> {
+        instructions::init_emissions_vault::handler(ctx, grid_id)
+    }
+
+    pub fn set_emission_rate(ctx: Context<SetEmissionRate>, grid_id: u64, emission_rate_per_block_per_sec: u64) -> Result<()> {
+        instructions::set_emission_rate::handler(ctx, grid_id, emission_rate_per_block_per_sec)
+    }
+
+    pub fn stake_parcel(ctx: Context<StakeParcel>, grid_id: u64, parcel_id: u16) -> Result<()> {
+        instructions::stake_parcel::handler(ctx, grid_id, parcel_id)
+    }
+
+    pub fn claim_staking_rewards(ctx: Context<ClaimStakingRewards>, grid_id: u64, parcel_id: u16) -> Result<()> {
+        instructions::claim_staking_rewards::handler(ctx, grid_id, parcel_id)
+    }
+
+    pub fn unstake_parcel(ctx: Context<UnstakeParcel>, grid_id: u64, parcel_id: u16) -> Result<()> {
+        instructions::unstake_parcel::handler(ctx, grid_id, parcel_id)
+    }
+
+    /// Create or update the Harberger district. Disabled districts reject every Harberger instruction.
+    #[allow(clippy::too_many_arguments)]
+    pub fn configure_harberger_district(
+        ctx: Context<ConfigureHarbergerDistrict>,
+        grid_id: u64,
+        x: u8,
+        y: u8,
+        width: u8,
+        height: u8,
+        tax_bps: u16,
+        tax_period_secs: i64,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::configure_harberger_district::handler(ctx, grid_id, x, y, width, height, tax_bps, tax_period_secs, enabled)
+    }
+
+    pub fn set_valuation(ctx: Context<SetValuation>, grid_id: u64, parcel_id: u16, value: u64) -> Result<()> {
+        instructions::set_valuation::handler(ctx, grid_id, parcel_id, value)
+    }
+
+    pub fn pay_tax(ctx: Context<PayTax>, grid_id: u64, parcel_id: u16) -> Result<()> {
+        instructions::pay_tax::handler(ctx, grid_id, parcel_id)
+    }
+
+    pub fn force_buy(ctx: Context<ForceBuy>, grid_id: u64, parcel_id: u16) -> Result<()> {
+        instructions::force_buy::handler(ctx, grid_id, parcel_id)
+    }
+
+    pub fn init_quest_vault(ctx: Context<InitQuestVault>, grid_id: u64) -> Result<()> {
+        instructions::init_quest_vault::handler(ctx, grid_id)
+    }
+
+    pub fn set_quest_root(ctx: Context<SetQuestRoot>, grid_id: u64, root: [u8; 32], total_allocation: u64) -> Result<()> {
+        instructions::set_quest_root::handler(ctx, grid_id, root, total_allocation)
+    }
+
+    /// Pays a quest leaf `keccak256(index_le || claimer || amount_le)` proven against the quest's root
+    pub fn claim_quest_reward(
+        ctx: Context<ClaimQuestReward>,
+        grid_id: u64,
+        amount: u64,
+        index: u32,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::claim_quest_reward::handler(ctx, grid_id, amount, index, proof)
+    }
+
+    /// Airdrop `amount` of any token to current landowners, pro rata by blocks owned right now
+    pub fn create_distribution(ctx: Context<CreateDistribution>, grid_id: u64, amount: u64, expires_at: i64) -> Result<()> {
+        instructions::create_distribution::handler(ctx, grid_id, amount, expires_at)
+    }
+
+    pub fn claim_distribution(ctx: Context<ClaimDistribution>, grid_id: u64, parcel_id: u16) -> Result<()> {
+        instructions::claim_distribution::handler(ctx, grid_id, parcel_id)
+    }
+
+    pub fn sweep_distribution(ctx: Context<SweepDistribution>, grid_id: u64) -> Result<()> {
+        instructions::sweep_distribution::handler(ctx, grid_id)
+    }
+
+    /// Freeze and archive the current grid, then start a fresh one under the next epoch
+    pub fn start_new_epoch(ctx: Context<StartNewEpoch>, grid_id: u64) -> Result<()> {
+        instructions::start_new_epoch::handler(ctx, grid_id)
+    }
+
+    /// Register the on-claim hook program for claims overlapping the district. Authority only.
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_hook(
+        ctx: Context<SetHook>,
+        grid_id: u64,
+        program_id: Pubkey,
+        guardian: Pubkey,
+        x: u8,
+        y: u8,
+        width: u8,
+        height: u8,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::set_hook::handler(ctx, grid_id, program_id, guardian, x, y, width, height, enabled)
+    }
+
+    /// Disable the on-claim hook. Callable by the authority or the hook guardian.
+    pub fn disable_hook(ctx: Context<DisableHook>, grid_id: u64) -> Result<()> {
+        instructions::disable_hook::handler(ctx, grid_id)
+    }
+
+    /// Escrow a parcel under a Fraction PDA and mint `share_supply` fungible shares to its owner
+    pub fn fractionalize_parcel(ctx: Context<FractionalizeParcel>, grid_id: u64, parcel_id: u16, share_supply: u64) -> Result<()> {
+        instructions::fractionalize_parcel::handler(ctx, grid_id, parcel_id, share_supply)
+    }
+
+    /// Pay a share holder's pro-rata cut of the fractionalized parcel's land-buy rewards
+    pub fn claim_fraction_rewards(ctx: Context<FractionHolder>, grid_id: u64, parcel_id: u16) -> Result<()> {
+        instructions::claim_fraction_rewards::handler(ctx, grid_id, parcel_id)
+    }
+
+    /// Deposit shares so they earn land-buy rewards; only deposited shares are counted
+    pub fn deposit_fraction_shares(ctx: Context<FractionHolder>, grid_id: u64, parcel_id: u16, amount: u64) -> Result<()> {
+        instructions::deposit_fraction_shares::handler(ctx, grid_id, parcel_id, amount)
+    }
+
+    pub fn withdraw_fraction_shares(ctx: Context<FractionHolder>, grid_id: u64, parcel_id: u16, amount: u64) -> Result<()> {
+        instructions::withdraw_fraction_shares::handler(ctx, grid_id, parcel_id, amount)
+    }
+
+    /// Burn every share of a fractionalized parcel to take the asset back
+    pub fn redeem_parcel(ctx: Context<RedeemParcel>, grid_id: u64, parcel_id: u16) -> Result<()> {
+        instructions::redeem_parcel::handler(ctx, grid_id, parcel_id)
+    }
+
+    /// Set the share of the reward-pool surplus paid on burn_for_exit, optionally re-seeding
+    /// the tracked reward liability. Authority only.
+    pub fn set_exit_share(ctx: Context<SetExitShare>, grid_id: u64, exit_share_bps: u16, reward_liability: Option<u64>) -> Result<()> {
+        instructions::set_exit_share::handler(ctx, grid_id, exit_share_bps, reward_liability)
+    }
+
+    /// Burn a parcel, free its blocks, and pay pending rewards plus the configured share of
+    /// the reward-pool surplus
+    pub fn burn_for_exit(ctx: Context<BurnForExit>, grid_id: u64, parcel_id: u16) -> Result<()> {
+        instructions::burn_for_exit::handler(ctx, grid_id, parcel_id)
+    }
+
+    /// Set how long pending land-buy rewards survive without a claim (0 = forever) and the
+    /// bounty paid for expiring them. Authority only.
+    pub fn set_reward_expiry(ctx: Context<SetRewardExpiry>, grid_id: u64, reward_expiry_seconds: i64, expiry_bounty_bps: u16) -> Result<()> {
+        instructions::set_reward_expiry::handler(ctx, grid_id, reward_expiry_seconds, expiry_bounty_bps)
+    }
+
+    /// Permissionless crank: lapse a dormant parcel's pending land-buy rewards back to the
+    /// other landowners, paying the caller a bounty
+    pub fn expire_parcel_rewards(ctx: Context<ExpireParcelRewards>, grid_id: u64, parcel_id: u16) -> Result<()> {
+        instructions::expire_parcel_rewards::handler(ctx, grid_id, parcel_id)
+    }
+
+    /// Set the adjacency bonus paid on land-buy reward claims. Authority only.
+    pub fn set_adjacency_bonus(ctx: Context<SetAdjacencyBonus>, grid_id: u64, adjacency_bonus_bps: u16) -> Result<()> {
+        instructions::set_adjacency_bonus::handler(ctx, grid_id, adjacency_bonus_bps)
+    }
+
+    /// Permissionless crank: recount a parcel's bordering parcels for the adjacency bonus
+    pub fn refresh_adjacency(ctx: Context<RefreshAdjacency>, grid_id: u64, parcel_id: u16) -> Result<()> {
+        instructions::refresh_adjacency::handler(ctx, grid_id, parcel_id)
+    }
+
+    /// Record that the signer currently owns a parcel, in a PDA verifiers can read directly
+    pub fn mint_ownership_attestation(ctx: Context<MintOwnershipAttestation>, grid_id: u64, parcel_id: u16) -> Result<()> {
+        instructions::mint_ownership_attestation::handler(ctx, grid_id, parcel_id)
+    }
+
+    /// Permissionless: close an attestation older than 24 hours, refunding its owner
+    pub fn close_attestation(ctx: Context<CloseAttestation>, grid_id: u64) -> Result<()> {
+        instructions::close_attestation::handler(ctx, grid_id)
+    }
+
+    /// Permissionless: thaw an admin-minted parcel once its vesting date has passed
+    pub fn unlock_vested_parcel(ctx: Context<UnlockVestedParcel>, grid_id: u64, parcel_id: u16) -> Result<()> {
+        instructions::unlock_vested_parcel::handler(ctx, grid_id, parcel_id)
+    }
+
+    pub fn admin_set_charities(
+        ctx: Context<AdminSetCharities>,
+        grid_id: u64,
+        charities: Vec<Pubkey>,
+        max_charity_bps: u16,
+    ) -> Result<()> {
+        instructions::admin_set_charities::handler(ctx, grid_id, charities, max_charity_bps)
+    }
+
+    pub fn get_claimer_stats(ctx: Context<GetClaimerStats>, grid_id: u64, wallet: Pubkey) -> Result<state::ClaimerStats> {
+        instructions::get_claimer_stats::handler(ctx, grid_id, wallet)
+    }
+
+    pub fn close_claimer_stats(ctx: Context<CloseClaimerStats>, grid_id: u64) -> Result<()> {
+        instructions::close_claimer_stats::handler(ctx, grid_id)
+    }
+
+    /// Asset owner only: claim a globally unique name and write it to the Core metadata
+    pub fn register_parcel_name(ctx: Context<RegisterParcelName>, grid_id: u64, parcel_id: u16, name: String) -> Result<()> {
+        instructions::register_parcel_name::handler(ctx, grid_id, parcel_id, name)
+    }
+
+    /// Registrant, or anyone once the asset has been transferred out: free a parcel name
+    pub fn release_parcel_name(ctx: Context<ReleaseParcelName>, grid_id: u64, name: String) -> Result<()> {
+        instructions::release_parcel_name::handler(ctx, grid_id, name)
+    }
+
+    /// Authority only: issue a voucher for one free parcel within size, ring and expiry limits
+    #[allow(clippy::too_many_arguments)]
+    pub fn mint_voucher(
+        ctx: Context<MintVoucher>,
+        grid_id: u64,
+        voucher_id: u64,
+        beneficiary: Pubkey,
+        max_width: u8,
+        max_height: u8,
+        allowed_ring_min: u8,
+        allowed_ring_max: u8,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::mint_voucher::handler(
+            ctx,
+            grid_id,
+            voucher_id,
+            beneficiary,
+            max_width,
+            max_height,
+            allowed_ring_min,
+            allowed_ring_max,
+            expires_at,
+        )
+    }
+
+    /// Beneficiary only: claim a parcel for free with a voucher, which is closed
+    pub fn redeem_voucher<'info>(
+        ctx: Context<'_, '_, '_, 'info, RedeemVoucher<'info>>,
+        grid_id: u64,
+        x: u8,
+        y: u8,
+        width: u8,
+        height: u8,
+    ) -> Result<()> {
+        instructions::redeem_voucher::handler(ctx, grid_id, x, y, width, height)
+    }
+
+    /// Authority only: close an expired voucher and reclaim its rent
+    pub fn close_expired_voucher(ctx: Context<CloseExpiredVoucher>, grid_id: u64) -> Result<()> {
+        instructions::close_expired_voucher::handler(ctx, grid_id)
+    }
+
+    /// Authority only: price and length of a premium badge period (price 0 disables purchases)
+    pub fn set_premium_price(
+        ctx: Context<SetPremiumPrice>,
+        grid_id: u64,
+        premium_price_per_period: u64,
+        premium_period_secs: i64,
+    ) -> Result<()> {
+        instructions::set_premium_price::handler(ctx, grid_id, premium_price_per_period, premium_period_secs)
+    }
+
+    /// Buy `periods` of the premium map badge for a parcel, extending any active badge
+    pub fn purchase_premium(ctx: Context<PurchasePremium>, grid_id: u64, parcel_id: u16, periods: u32) -> Result<()> {
+        instructions::purchase_premium::handler(ctx, grid_id, parcel_id, periods)
+    }
+
+    /// Authority only: minimum seconds between raffle draws (0 disables raffles)
+    pub fn set_raffle_interval(ctx: Context<SetRaffleInterval>, grid_id: u64, raffle_interval_secs: i64) -> Result<()> {
+        instructions::set_raffle_interval::handler(ctx, grid_id, raffle_interval_secs)
+    }
+
+    /// Permissionless: draw a random free block once the raffle interval has passed
+    pub fn run_raffle(ctx: Context<RunRaffle>, grid_id: u64) -> Result<()> {
+        instructions::run_raffle::handler(ctx, grid_id)
+    }
+
+    /// First caller within the claim window mints the raffle prize block for free
+    pub fn claim_raffle_block(ctx: Context<ClaimRaffleBlock>, grid_id: u64) -> Result<()> {
+        instructions::claim_raffle_block::handler(ctx, grid_id)
+    }
+
+    /// Relayer-submitted claim authorized by the user's ed25519 signature over a
+    /// ClaimAuthorization, verified by the preceding ed25519 program instruction
+    #[allow(clippy::too_many_arguments)]
+    pub fn claim_parcel_signed<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimParcelSigned<'info>>,
+        grid_id: u64,
+        x: u8,
+        y: u8,
+        width: u8,
+        height: u8,
+        max_cost: u64,
+        nonce: u64,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::claim_parcel_signed::handler(ctx, grid_id, x, y, width, height, max_cost, nonce, expires_at)
+    }
+
+    /// Claims up to MAX_BATCH_RECTS disjoint rectangles for one combined payment.
+    /// remaining_accounts are each rectangle's asset signer and ParcelInfo PDA, then the hook
+    /// program and its accounts when the hook applies.
+    pub fn claim_parcels_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimParcelsBatch<'info>>,
+        grid_id: u64,
+        rects: Vec<BatchRect>,
+    ) -> Result<()> {
+        instructions::claim_parcels_batch::handler(ctx, grid_id, rects)
+    }
+
+    /// Claim paid in lamports into the SOL treasury; no burn and no landowner reward share.
+    /// remaining_accounts work as in claim_parcel.
+    pub fn claim_parcel_sol<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimParcelSol<'info>>,
+        grid_id: u64,
+        x: u8,
+        y: u8,
+        width: u8,
+        height: u8,
+    ) -> Result<()> {
+        instructions::claim_parcel_sol::handler(ctx, grid_id, x, y, width, height)
+    }
+
+    /// A zero price disables claim_parcel_sol
+    pub fn set_sol_price(
+        ctx: Context<SetSolPrice>,
+        grid_id: u64,
+        price_per_block_lamports: u64,
+        sol_ring_credit_bps: u16,
+    ) -> Result<()> {
+        instructions::set_sol_price::handler(ctx, grid_id, price_per_block_lamports, sol_ring_credit_bps)
+    }
+
+    /// Treasury-authority-only; the treasury keeps its rent-exempt minimum
+    pub fn withdraw_sol_treasury(ctx: Context<WithdrawSolTreasury>, grid_id: u64, amount: u64) -> Result<()> {
+        instructions::withdraw_sol_treasury::handler(ctx, grid_id, amount)
+    }
+
+    /// Rewrites a parcel's Core Attributes plugin from its ParcelInfo (permissionless)
+    pub fn refresh_parcel_attributes(ctx: Context<RefreshParcelAttributes>, grid_id: u64, parcel_id: u16) -> Result<()> {
+        instructions::refresh_parcel_attributes::handler(ctx, grid_id, parcel_id)
+    }
+
+    /// Authority-only; sets or removes the collection's Royalties plugin, which covers every parcel
+    pub fn admin_set_collection_royalties(
+        ctx: Context<AdminSetCollectionRoyalties>,
+        grid_id: u64,
+        royalty_bps: u16,
+        royalty_recipient: Pubkey,
+    ) -> Result<()> {
+        instructions::admin_set_collection_royalties::handler(ctx, grid_id, royalty_bps, royalty_recipient)
+    }
+
+    pub fn admin_thaw_parcel(ctx: Context<AdminThawParcel>, grid_id: u64, parcel_id: u16) -> Result<()> {
+        instructions::admin_thaw_parcel::handler(ctx, grid_id, parcel_id)
+    }
+
+    /// Claims only the cells of a bounding box set in a row-major bitmask, for
+    /// non-rectangular parcels. remaining_accounts work as in claim_parcel.
+    pub fn claim_parcel_masked<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimParcelMasked<'info>>,
+        grid_id: u64,
+        x: u8,
+        y: u8,
+        width: u8,
+        height: u8,
+        mask: Vec<u8>,
+    ) -> Result<()> {
+        instructions::claim_parcel_masked::handler(ctx, grid_id, x, y, width, height, mask)
+    }
+
+    /// Authority-only; seeds up to MAX_ADMIN_MINT_BATCH parcels in one instruction.
+    /// remaining_accounts are each entry's asset signer and ParcelInfo PDA, then the recipients.
+    pub fn admin_mint_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, AdminMintBatch<'info>>,
+        grid_id: u64,
+        entries: Vec<AdminMintEntry>,
+    ) -> Result<()> {
+        instructions::admin_mint_batch::handler(ctx, grid_id, entries)
+    }
+
+    /// Authority-only; admin_mint to the GridConfig PDA, held until admin_release_reserved_parcel
+    pub fn admin_reserve_parcel(ctx: Context<AdminMint>, grid_id: u64, x: u8, y: u8, width: u8, height: u8) -> Result<()> {
+        instructions::admin_reserve_parcel::handler(ctx, grid_id, x, y, width, height)
+    }
+
+    /// Authority-only; transfers a reserved parcel to its final owner
+    pub fn admin_release_reserved_parcel(ctx: Context<AdminReleaseReservedParcel>, grid_id: u64, parcel_id: u16) -> Result<()> {
+        instructions::admin_release_reserved_parcel::handler(ctx, grid_id, parcel_id)
+    }
+
+    /// Authority-only; excludes a parcel from land-buy rewards, or includes it again
+    pub fn admin_set_rewards_excluded(ctx: Context<AdminSetRewardsExcluded>, grid_id: u64, parcel_id: u16, excluded: bool) -> Result<()> {
+        instructions::admin_set_rewards_excluded::handler(ctx, grid_id, parcel_id, excluded)
+    }
+
+    /// remaining_accounts: a ParcelInfo and Core asset pair per entry of `parcel_ids`
+    pub fn claim_land_buy_rewards_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimLandBuyRewardsBatch<'info>>,
+        grid_id: u64,
+        parcel_ids: Vec<u16>,
+    ) -> Result<()> {
+        instructions::claim_land_buy_rewards_batch::handler(ctx, grid_id, parcel_ids)
+    }
+
+    pub fn get_pending_rewards(ctx: Context<GetPendingRewards>, grid_id: u64, parcel_id: u16) -> Result<u64> {
+        instructions::get_pending_rewards::handler(ctx, grid_id, parcel_id)
+    }
+
+    /// remaining_accounts: the ParcelInfo of each entry of `parcel_ids`
+    pub fn get_pending_rewards_many<'info>(
+        ctx: Context<'_, '_, '_, 'info, GetPendingRewardsMany<'info>>,
+        grid_id: u64,
+        parcel_ids: Vec<u16>,
+    ) -> Result<Vec<u64>> {
+        instructions::get_pending_rewards_many::handler(ctx, grid_id, parcel_ids)
+    }
+
+    /// Claims a rectangle paid first out of another owned parcel's land-buy rewards, with
+    /// the claimer's tokens covering the rest. remaining_accounts work as in claim_parcel.
+    pub fn claim_parcel_with_rewards<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimParcelWithRewards<'info>>,
+        grid_id: u64,
+        x: u8,
+        y: u8,
+        width: u8,
+        height: u8,
+        reward_parcel_id: u16,
+    ) -> Result<()> {
+        instructions::claim_parcel_with_rewards::handler(ctx, grid_id, x, y, width, height, reward_parcel_id)
+    }
+
+    /// Lets a hot wallet claim a parcel's land-buy rewards for its owner; None clears it
+    pub fn set_reward_delegate(ctx: Context<SetRewardDelegate>, grid_id: u64, parcel_id: u16, delegate: Option<Pubkey>) -> Result<()> {
+        instructions::set_reward_delegate::handler(ctx, grid_id, parcel_id, delegate)
+    }
+
+    /// Pays out a parcel's pending land-buy rewards ahead of a transfer; succeeds with nothing owed
+    pub fn settle_rewards_for_transfer(ctx: Context<SettleRewardsForTransfer>, grid_id: u64, parcel_id: u16) -> Result<()> {
+        instructions::settle_rewards_for_transfer::handler(ctx, grid_id, parcel_id)
+    }
+
+    /// Permissionless; deposits tokens into the reward pool for every landowner to claim pro-rata
+    pub fn fund_land_buy_rewards(ctx: Context<FundLandBuyRewards>, grid_id: u64, amount: u64) -> Result<()> {
+        instructions::fund_land_buy_rewards::handler(ctx, grid_id, amount)
+    }
+
+    /// Permissionless; deposits lamports for every parcel enrolled in SOL rewards to claim pro-rata
+    pub fn fund_sol_rewards(ctx: Context<FundSolRewards>, grid_id: u64, lamports: u64) -> Result<()> {
+        instructions::fund_sol_rewards::handler(ctx, grid_id, lamports)
+    }
+
+    /// Claims a parcel's SOL rewards; its first call enrolls it in the stream
+    pub fn claim_sol_rewards(ctx: Context<ClaimSolRewards>, grid_id: u64, parcel_id: u16) -> Result<()> {
+        instructions::claim_sol_rewards::handler(ctx, grid_id, parcel_id)
+    }
+
+    /// Permissionless; drops the SOL reward enrollment of a parcel that left the grid
+    pub fn close_sol_reward_checkpoint(ctx: Context<CloseSolRewardCheckpoint>, grid_id: u64, epoch: u16, parcel_id: u16) -> Result<()> {
+        instructions::close_sol_reward_checkpoint::handler(ctx, grid_id, epoch, parcel_id)
+    }
+
+    /// Treasury-authority-only; sweeps at most the whole tokens of accumulator rounding tracked in RewardDust
+    pub fn admin_sweep_dust(ctx: Context<AdminSweepDust>, grid_id: u64, amount: u64) -> Result<()> {
+        instructions::admin_sweep_dust::handler(ctx, grid_id, amount)
+    }
+
+    /// Read-only audit: remaining_accounts hold the ParcelInfo of each entry of `parcel_ids`.
+    /// Pass the previous batch's outstanding as `running_total` to verify a grid in parts.
+    pub fn verify_reward_solvency<'info>(
+        ctx: Context<'_, '_, '_, 'info, VerifyRewardSolvency<'info>>,
+        grid_id: u64,
+        parcel_ids: Vec<u16>,
+        running_total: u64,
+    ) -> Result<SolvencyReport> {
+        instructions::verify_reward_solvency::handler(ctx, grid_id, parcel_ids, running_total)
+    }
+
+    /// Authority-only; weights each ring's blocks in land-buy rewards for parcels minted afterwards
+    pub fn set_ring_reward_weights(
+        ctx: Context<SetRingRewardWeights>,
+        grid_id: u64,
+        weights_bps: [u16; constants::RING_COUNT as usize],
+    ) -> Result<()> {
+        instructions::set_ring_reward_weights::handler(ctx, grid_id, weights_bps)
+    }
+
+    /// Treasury-authority-only; withdraws tokens from the reward pool beyond what landowners are owed
+    pub fn admin_withdraw_pool_excess(ctx: Context<AdminWithdrawPoolExcess>, grid_id: u64, amount: u64) -> Result<()> {
+        instructions::admin_withdraw_pool_excess::handler(ctx, grid_id, amount)
+    }
+
+    /// Authority-only; hands the config, metadata and treasury roles to other keys
+    pub fn set_admin_roles(
+        ctx: Context<SetAdminRoles>,
+        grid_id: u64,
+        config_authority: Option<Pubkey>,
+        metadata_authority: Option<Pubkey>,
+        treasury_authority: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_admin_roles::handler(ctx, grid_id, config_authority, metadata_authority, treasury_authority)
+    }
+
+    /// Config-authority-only; queues a price, ring threshold, reward share, ring multiplier,
+    /// curve divisor or timelock change
+    pub fn queue_config_update(ctx: Context<QueueConfigUpdate>, grid_id: u64, change: state::TimelockedConfig) -> Result<()> {
+        instructions::queue_config_update::handler(ctx, grid_id, change)
+    }
+
+    /// Config-authority-only; applies the queued change once its timelock has passed
+    pub fn execute_config_update(ctx: Context<ExecuteConfigUpdate>, grid_id: u64, change: state::TimelockedConfig) -> Result<()> {
+        instructions::execute_config_update::handler(ctx, grid_id, change)
+    }
+
+    /// Config-authority-only; drops the queued config change
+    pub fn cancel_config_update(ctx: Context<CancelConfigUpdate>, grid_id: u64) -> Result<()> {
+        instructions::cancel_config_update::handler(ctx, grid_id)
+    }
+
+    /// Config-authority-only; unlocks rings up to `ring` by raising total_burned, logged loudly
+    pub fn admin_advance_ring(ctx: Context<AdminAdvanceRing>, grid_id: u64, ring: u8) -> Result<()> {
+        instructions::admin_advance_ring::handler(ctx, grid_id, ring)
+    }
+
+    /// Config-authority-only; creates the Core collection under the GridConfig PDA and sets it
+    pub fn create_collection(ctx: Context<CreateCollection>, grid_id: u64, name: String, uri: String) -> Result<()> {
+        instructions::create_collection::handler(ctx, grid_id, name, uri)
+    }
+
+    /// Treasury-authority-only; routes a share of every token claim to a treasury account
+    pub fn set_token_treasury(ctx: Context<SetTokenTreasury>, grid_id: u64, share_bps: u16) -> Result<()> {
+        instructions::set_token_treasury::handler(ctx, grid_id, share_bps)
+    }
+
+    /// Authority-only and irreversible; disables update_config, the economics setters and the
+    /// admin mint paths listed on freeze_config::handler
+    pub fn freeze_config(ctx: Context<FreezeConfig>, grid_id: u64, confirmation: u64) -> Result<()> {
+        instructions::freeze_config::handler(ctx, grid_id, confirmation)
+    }
+
+    /// Treasury-authority-only; closes ParcelInfo accounts ahead of the final admin_purge
+    pub fn admin_purge_parcels<'info>(
+        ctx: Context<'_, '_, '_, 'info, AdminPurgeParcels<'info>>,
+        grid_id: u64,
+        parcel_ids: Vec<u16>,
+        force: bool,
+    ) -> Result<()> {
+        instructions::admin_purge_parcels::handler(ctx, grid_id, parcel_ids, force)
+    }
+
+    /// Config-authority-only; marks an unclaimed rectangle reserved so it can never be claimed
+    pub fn admin_reserve_blocks(
+        ctx: Context<AdminReserveBlocks>,
+        grid_id: u64,
+        x: u8,
+        y: u8,
+        width: u8,
+        height: u8,
+    ) -> Result<()> {
+        instructions::admin_reserve_blocks::handler(ctx, grid_id, x, y, width, height)
+    }
+
+    /// Config-authority-only; returns a fully reserved rectangle to unclaimed
+    pub fn admin_unreserve_blocks(
+        ctx: Context<AdminReserveBlocks>,
+        grid_id: u64,
+        x: u8,
+        y: u8,
+        width: u8,
+        height: u8,
+    ) -> Result<()> {
+        instructions::admin_unreserve_blocks::handler(ctx, grid_id, x, y, width, height)
+    }
+
+    /// View: up to MAX_REGION_SIDE x MAX_REGION_SIDE parcel ids of the BlockMap, with the
+    /// unlocked ring and next_parcel_id
+    pub fn get_region(ctx: Context<GetRegion>, grid_id: u64, x: u8, y: u8, width: u8, height: u8) -> Result<RegionView> {
+        instructions::get_region::handler(ctx, grid_id, x, y, width, height)
+    }
+
+    /// Config-authority-only; counts the next MAX_OCCUPANCY_SCAN_BLOCKS blocks of the BlockMap
+    /// into a grid's RingOccupancy, creating it for grids initialized before it existed
+    pub fn admin_backfill_ring_occupancy(
+        ctx: Context<AdminBackfillRingOccupancy>,
+        grid_id: u64,
+        restart: bool,
+    ) -> Result<()> {
+        instructions::admin_backfill_ring_occupancy::handler(ctx, grid_id, restart)
+    }
+
+    /// Authority-only; creates the MapDigest of a grid initialized before it existed
+    pub fn init_map_digest(ctx: Context<InitMapDigest>, grid_id: u64) -> Result<()> {
+        instructions::init_map_digest::handler(ctx, grid_id)
+    }
+
+    /// Metadata-authority-only: tokens burned by each owner_update_parcel (0 disables renames)
+    pub fn set_rename_fee(ctx: Context<SetRenameFee>, grid_id: u64, fee: u64) -> Result<()> {
+        instructions::set_rename_fee::handler(ctx, grid_id, fee)
+    }
+
+    /// Asset owner only: rename the parcel's asset and optionally point its uri at uri_base,
+    /// the parcel id and `new_uri_suffix`, burning the rename fee
+    pub fn owner_update_parcel(
+        ctx: Context<OwnerUpdateParcel>,
+        grid_id: u64,
+        parcel_id: u16,
+        new_name: String,
+        new_uri_suffix: Option<String>,
     ) -> Result<()> {
-        instructions::admin_transfer_nft_collection_authority::handler(ctx)
+        instructions::owner_update_parcel::handler(ctx, grid_id, parcel_id, new_name, new_uri_suffix)
+    }
+
+    /// Asset owner only: record the hash of the parcel's art and show it in its attributes
+    /// (all zeros clears it)
+    pub fn set_parcel_content(
+        ctx: Context<SetParcelContent>,
+        grid_id: u64,
+        parcel_id: u16,
+        content_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::set_parcel_content::handler(ctx, grid_id, parcel_id, content_hash)
+    }
+
+    /// Asset owner only: store the parcel's colors on-chain, three RGB bytes per block, for
+    /// parcels of up to 64 blocks
+    pub fn set_parcel_pixels(ctx: Context<SetParcelPixels>, grid_id: u64, parcel_id: u16, rgb: Vec<u8>) -> Result<()> {
+        instructions::set_parcel_pixels::handler(ctx, grid_id, parcel_id, rgb)
+    }
+
+    /// Asset owner only: delete the parcel's pixels, refunding their rent
+    pub fn close_parcel_pixels(ctx: Context<CloseParcelPixels>, grid_id: u64, parcel_id: u16) -> Result<()> {
+        instructions::close_parcel_pixels::handler(ctx, grid_id, parcel_id)
+    }
+
+    /// Config-authority-only: basis points of each claim_parcel cost, up to
+    /// MAX_REFERRAL_SHARE_BPS, paid straight to the referrer's token account passed in
+    /// remaining_accounts (0 disables it)
+    pub fn set_referral_share(ctx: Context<SetReferralShare>, grid_id: u64, share_bps: u16) -> Result<()> {
+        instructions::set_referral_share::handler(ctx, grid_id, share_bps)
     }
 }
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::constants::ADMIN_MINT_ALLOWANCE_SEED;
+use crate::errors::BillionError;
+
+/// Promotional admin mints left once GridConfig.seeding_enabled is off, topped up through
+/// update_config. Created by the first update_config call.
+#[account]
+#[derive(InitSpace)]
+pub struct AdminMintAllowance {
+    pub remaining: u32,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AdminMintAllowance {
+    pub const SEED: &'static [u8] = ADMIN_MINT_ALLOWANCE_SEED;
+
+    /// Reads the PDA, falling back to an empty allowance while it hasn't been created
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        if account.data_is_empty() {
+            return Ok(Self { remaining: 0, bump: 0 });
+        }
+        Self::try_deserialize(&mut &account.try_borrow_data()?[..])
+    }
+
+    /// Takes `parcels` mints from the allowance, or fails with SeedingDisabled when it
+    /// doesn't cover them. Returns what's left.
+    pub fn spend(&mut self, parcels: u32) -> Result<u32> {
+        self.remaining = self.remaining.checked_sub(parcels).ok_or(BillionError::SeedingDisabled)?;
+        Ok(self.remaining)
+    }
+
+    /// Gates an admin mint of `parcels` parcels: free while seeding is enabled, otherwise
+    /// charged to the allowance stored in `account`. Returns the allowance left when it was
+    /// charged.
+    pub fn charge(account: &AccountInfo, seeding_enabled: bool, parcels: u32) -> Result<Option<u32>> {
+        if seeding_enabled {
+            return Ok(None);
+        }
+        let mut allowance = Self::load(account)?;
+        let remaining = allowance.spend(parcels)?;
+        allowance.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+        Ok(Some(remaining))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spend_stops_at_zero() {
+        let mut allowance = AdminMintAllowance { remaining: 3, bump: 255 };
+        assert_eq!(allowance.spend(2).unwrap(), 1);
+        assert_eq!(allowance.spend(2).unwrap_err(), BillionError::SeedingDisabled.into());
+        assert_eq!(allowance.remaining, 1);
+        assert_eq!(allowance.spend(1).unwrap(), 0);
+        assert_eq!(allowance.spend(1).unwrap_err(), BillionError::SeedingDisabled.into());
+    }
+}
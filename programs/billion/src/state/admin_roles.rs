@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::constants::ADMIN_ROLES_SEED;
+use crate::state::GridConfig;
+
+/// Keys holding the admin powers split off GridConfig.authority, which keeps admin_mint and
+/// the right to reassign these. Created by initialize, or by the first set_admin_roles on a
+/// grid initialized before it; until then every role is the main authority.
+#[account]
+#[derive(InitSpace)]
+pub struct AdminRoles {
+    /// Runs update_config
+    pub config_authority: Pubkey,
+    /// Runs update_parcel_metadata, update_parcels_metadata_batch and set_rename_fee
+    pub metadata_authority: Pubkey,
+    /// Runs admin_purge and moves tokens or SOL out of the program's vaults
+    pub treasury_authority: Pubkey,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AdminRoles {
+    pub const SEED: &'static [u8] = ADMIN_ROLES_SEED;
+
+    /// Every role held by `authority`
+    pub fn single(authority: Pubkey, bump: u8) -> Self {
+        Self {
+            config_authority: authority,
+            metadata_authority: authority,
+            treasury_authority: authority,
+            bump,
+        }
+    }
+
+    /// Reads the PDA, falling back to the main authority for every role while it hasn't
+    /// been created
+    pub fn load(account: &AccountInfo, grid_config: &GridConfig) -> Result<Self> {
+        if account.data_is_empty() {
+            return Ok(Self::single(grid_config.authority, 0));
+        }
+        Self::try_deserialize(&mut &account.try_borrow_data()?[..])
+    }
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+use crate::constants::ALLOWLIST_SEED;
+
+/// Merkle root of the wallets allowed to claim while GridConfig.allowlist_only is set,
+/// created by the first update_config call
+#[account]
+#[derive(InitSpace)]
+pub struct Allowlist {
+    /// Root over `allowlist_leaf(wallet)` leaves
+    pub root: [u8; 32],
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Allowlist {
+    pub const SEED: &'static [u8] = ALLOWLIST_SEED;
+}
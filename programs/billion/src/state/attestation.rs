@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::constants::{ATTESTATION_SEED, ATTESTATION_TTL_SECS};
+
+/// On-chain proof that `owner` held a parcel's asset at `slot`. Lives at
+/// `[SEED, grid_seed(grid_id), epoch_seed(epoch), parcel_id, owner]`, so verifiers can check it with a single
+/// account read; recency is theirs to judge from `slot` and `attested_at`.
+#[account]
+#[derive(InitSpace)]
+pub struct Attestation {
+    pub owner: Pubkey,
+    pub parcel_id: u16,
+    pub epoch: u16,
+    pub asset: Pubkey,
+    pub block_count: u32,
+    /// Slot the ownership was read at
+    pub slot: u64,
+    /// Unix timestamp the ownership was read at
+    pub attested_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Attestation {
+    pub const SEED: &'static [u8] = ATTESTATION_SEED;
+
+    /// Whether anyone may close the attestation at `now`
+    pub fn is_expired(&self, now: i64) -> bool {
+        now.saturating_sub(self.attested_at) >= ATTESTATION_TTL_SECS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attestation_expires_after_ttl() {
+        let attestation = Attestation {
+            owner: Pubkey::new_unique(),
+            parcel_id: 1,
+            epoch: 0,
+            asset: Pubkey::new_unique(),
+            block_count: 4,
+            slot: 10,
+            attested_at: 1_000,
+            bump: 255,
+        };
+        assert!(!attestation.is_expired(1_000));
+        assert!(!attestation.is_expired(1_000 + ATTESTATION_TTL_SECS - 1));
+        assert!(attestation.is_expired(1_000 + ATTESTATION_TTL_SECS));
+    }
+}
@@ -1,28 +1,189 @@
+use std::cell::{Ref, RefMut};
+use std::ops::{Deref, DerefMut};
+
 use anchor_lang::prelude::*;
+use billion_core::block_index;
+
+use crate::errors::BillionError;
 
-pub const GRID_SIZE: usize = 100;
-pub const TOTAL_BLOCKS: usize = GRID_SIZE * GRID_SIZE;
+pub use billion_core::{DEFAULT_GRID_SIZE, MAX_GRID_SIZE, MAX_TOTAL_BLOCKS, MIN_GRID_SIZE, RESERVED_BLOCK};
 
+/// Layout of a MAX_GRID_SIZE BlockMap. A smaller grid stores only its own `grid_size²` blocks
+/// followed by the same 8-byte trailer, so the account is read through [`BlockMap::load`]
+/// rather than AccountLoader::load, which expects the full struct.
+///
+/// `blocks[y * grid_size + x]` is the id of the parcel covering (x, y), 0 if unclaimed or
+/// RESERVED_BLOCK. The grid, the map's epoch and that id derive the parcel's ParcelInfo, which
+/// stores its asset, so finding the parcel under a coordinate takes no index of its own.
 #[account(zero_copy(unsafe))]
 #[repr(C)]
 pub struct BlockMap {
-    pub blocks: [u16; TOTAL_BLOCKS],
+    pub blocks: [u16; MAX_TOTAL_BLOCKS],
     pub bump: u8,
-    pub _padding: [u8; 7], // Align to 8 bytes
+    /// Non-zero once start_new_epoch has archived this grid; claims are rejected
+    pub frozen: u8,
+    /// Width and height of the grid; 0 (maps created before sizes could vary) means DEFAULT_GRID_SIZE
+    pub grid_size: u16,
+    /// Non-zero from create_block_map until initialize or start_new_epoch binds the map to a
+    /// grid; maps created before there could be several grids read as bound
+    pub unbound: u8,
+    pub _padding: [u8; 3], // Align to 8 bytes
 }
 
 impl BlockMap {
     pub const SEED: &'static [u8] = b"block_map";
 
-    pub const SIZE: usize = 8 + (2 * TOTAL_BLOCKS) + 1 + 7; // 20016 bytes
+    /// bump, frozen, grid_size, unbound and padding after the blocks
+    pub const TRAILER_LEN: usize = 8;
+
+    /// Size of a DEFAULT_GRID_SIZE BlockMap
+    pub const SIZE: usize = Self::size(DEFAULT_GRID_SIZE); // 20016 bytes
 
+    /// Account size of a `grid_size` x `grid_size` BlockMap
+    pub const fn size(grid_size: u16) -> usize {
+        8 + 2 * (grid_size as usize) * (grid_size as usize) + Self::TRAILER_LEN
+    }
+
+    /// Read view of an initialized BlockMap of any grid size
+    pub fn load<'a>(loader: &'a AccountLoader<'_, BlockMap>) -> Result<BlockMapData<Ref<'a, [u8]>>> {
+        let data = loader.as_ref().try_borrow_data()?;
+        BlockMapData::new(Ref::map(data, |data| &data[..]))
+    }
+
+    /// Write view of an initialized BlockMap of any grid size
+    pub fn load_mut<'a>(loader: &'a AccountLoader<'_, BlockMap>) -> Result<BlockMapData<RefMut<'a, [u8]>>> {
+        require!(loader.as_ref().is_writable, ErrorCode::AccountNotMutable);
+        let data = loader.as_ref().try_borrow_mut_data()?;
+        BlockMapData::new(RefMut::map(data, |data| &mut data[..]))
+    }
+}
+
+/// BlockMap account data (discriminator included) with its grid size read from the trailer
+pub struct BlockMapData<D> {
+    data: D,
+    grid_size: u16,
+}
+
+impl<D: Deref<Target = [u8]>> BlockMapData<D> {
+    /// Fails unless the account is exactly `BlockMap::size` of the grid size it records
+    pub fn new(data: D) -> Result<Self> {
+        require!(data.len() >= BlockMap::size(MIN_GRID_SIZE), BillionError::InvalidGridSize);
+        let trailer = data.len() - BlockMap::TRAILER_LEN;
+        let grid_size = match u16::from_le_bytes([data[trailer + 2], data[trailer + 3]]) {
+            0 => DEFAULT_GRID_SIZE,
+            grid_size => grid_size,
+        };
+        require!(
+            billion_core::grid_size_is_valid(grid_size) && data.len() == BlockMap::size(grid_size),
+            BillionError::InvalidGridSize
+        );
+        Ok(Self { data, grid_size })
+    }
+
+    pub fn grid_size(&self) -> u16 {
+        self.grid_size
+    }
+
+    /// Whether start_new_epoch has archived this grid
+    pub fn is_frozen(&self) -> bool {
+        self.data[self.trailer() + 1] != 0
+    }
+
+    /// Whether no grid has taken this map as its BlockMap yet
+    pub fn is_unbound(&self) -> bool {
+        self.data[self.trailer() + 4] != 0
+    }
+
+    /// Parcel id at (x, y), 0 meaning unclaimed and RESERVED_BLOCK reserved; (x, y) must be
+    /// inside the grid
     pub fn get_block(&self, x: u8, y: u8) -> u16 {
-        let index = (y as usize) * GRID_SIZE + (x as usize);
-        self.blocks[index]
+        let offset = self.offset(x, y);
+        u16::from_le_bytes([self.data[offset], self.data[offset + 1]])
     }
 
+    fn trailer(&self) -> usize {
+        self.data.len() - BlockMap::TRAILER_LEN
+    }
+
+    fn offset(&self, x: u8, y: u8) -> usize {
+        debug_assert!((x as u16) < self.grid_size && (y as u16) < self.grid_size);
+        8 + 2 * block_index(x, y, self.grid_size)
+    }
+}
+
+impl<D: DerefMut<Target = [u8]>> BlockMapData<D> {
     pub fn set_block(&mut self, x: u8, y: u8, parcel_id: u16) {
-        let index = (y as usize) * GRID_SIZE + (x as usize);
-        self.blocks[index] = parcel_id;
+        let offset = self.offset(x, y);
+        self.data[offset..offset + 2].copy_from_slice(&parcel_id.to_le_bytes());
+    }
+
+    pub fn freeze(&mut self) {
+        let trailer = self.trailer();
+        self.data[trailer + 1] = 1;
+    }
+
+    /// Takes an unbound map for a grid, so no other grid can claim into it
+    pub fn bind(&mut self) -> Result<()> {
+        require!(self.is_unbound(), BillionError::BlockMapInUse);
+        let trailer = self.trailer();
+        self.data[trailer + 4] = 0;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data(grid_size: u16, recorded: u16) -> Vec<u8> {
+        let mut data = vec![0u8; BlockMap::size(grid_size)];
+        let trailer = data.len() - BlockMap::TRAILER_LEN;
+        data[trailer + 2..trailer + 4].copy_from_slice(&recorded.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn test_legacy_maps_read_as_the_default_grid() {
+        let data = data(DEFAULT_GRID_SIZE, 0);
+        assert_eq!(data.len(), 20016);
+        let map = BlockMapData::new(&data[..]).unwrap();
+        assert_eq!(map.grid_size(), DEFAULT_GRID_SIZE);
+        assert!(!map.is_frozen());
+        assert!(!map.is_unbound());
+    }
+
+    #[test]
+    fn test_a_map_binds_to_one_grid_only() {
+        let mut data = data(50, 50);
+        let trailer = data.len() - BlockMap::TRAILER_LEN;
+        data[trailer + 4] = 1;
+        let mut map = BlockMapData::new(&mut data[..]).unwrap();
+        assert!(map.is_unbound());
+        map.bind().unwrap();
+        assert!(!map.is_unbound());
+        assert!(map.bind().is_err());
+        assert_eq!(data[trailer + 4], 0);
+    }
+
+    #[test]
+    fn test_small_grid_blocks_and_trailer() {
+        let mut data = data(50, 50);
+        let mut map = BlockMapData::new(&mut data[..]).unwrap();
+        map.set_block(49, 49, 7);
+        map.set_block(0, 1, 3);
+        map.freeze();
+        assert_eq!((map.get_block(49, 49), map.get_block(0, 1), map.get_block(1, 0)), (7, 3, 0));
+        assert!(map.is_frozen());
+        // Row 1 starts 50 blocks in, and the last block sits right before the trailer
+        assert_eq!(u16::from_le_bytes([data[8 + 100], data[8 + 101]]), 3);
+        assert_eq!(data[BlockMap::size(50) - 8 - 2..BlockMap::size(50) - 8], 7u16.to_le_bytes());
+    }
+
+    #[test]
+    fn test_length_must_match_the_recorded_size() {
+        assert!(BlockMapData::new(&data(50, 0)[..]).is_err());
+        assert!(BlockMapData::new(&data(50, 49)[..]).is_err());
+        assert!(BlockMapData::new(&data(MIN_GRID_SIZE - 1, MIN_GRID_SIZE - 1)[..]).is_err());
+        assert!(BlockMapData::new(&[0u8; 16][..]).is_err());
     }
 }
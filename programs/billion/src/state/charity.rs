@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+use crate::constants::{CHARITY_REGISTRY_SEED, MAX_CHARITIES};
+
+/// Admin-approved charity token accounts. A buyer picks one by index at claim time and
+/// routes up to GridConfig.max_charity_bps of the cost to it instead of the burn.
+#[account]
+#[derive(InitSpace)]
+pub struct CharityRegistry {
+    #[max_len(MAX_CHARITIES)]
+    pub charities: Vec<Pubkey>,
+    /// PDA bump seed
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; 32],
+}
+
+impl CharityRegistry {
+    pub const SEED: &'static [u8] = CHARITY_REGISTRY_SEED;
+
+    /// The charity token account at `index`, if registered
+    pub fn get(&self, index: u8) -> Option<Pubkey> {
+        self.charities.get(index as usize).copied()
+    }
+}
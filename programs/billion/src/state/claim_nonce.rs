@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::constants::CLAIM_NONCE_SEED;
+
+/// Replay protection for a user's signed claims. Nonces only move forward: consuming one
+/// invalidates it and every lower nonce, so authorizations should be submitted in order.
+#[account]
+#[derive(InitSpace)]
+pub struct ClaimNonce {
+    pub user: Pubkey,
+    /// Lowest nonce a new authorization may use
+    pub next_nonce: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ClaimNonce {
+    pub const SEED: &'static [u8] = CLAIM_NONCE_SEED;
+
+    /// Marks `nonce` used. Returns false if it (or a later nonce) already was.
+    pub fn consume(&mut self, nonce: u64) -> bool {
+        if nonce < self.next_nonce {
+            return false;
+        }
+        match nonce.checked_add(1) {
+            Some(next_nonce) => {
+                self.next_nonce = next_nonce;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nonces_are_single_use_and_monotonic() {
+        let mut nonce = ClaimNonce { user: Pubkey::new_unique(), next_nonce: 0, bump: 255 };
+        assert!(nonce.consume(0));
+        assert!(!nonce.consume(0));
+        assert!(nonce.consume(5));
+        assert!(!nonce.consume(3));
+        assert_eq!(nonce.next_nonce, 6);
+        assert!(!nonce.consume(u64::MAX));
+    }
+}
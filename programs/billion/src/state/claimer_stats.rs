@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use crate::constants::CLAIMER_STATS_SEED;
+
+/// Lifetime totals for one wallet, created on its first claim or land-buy reward claim.
+/// Counts what the wallet did through this program; parcels it later sells or receives on
+/// secondary markets are not reflected.
+#[account]
+#[derive(InitSpace)]
+pub struct ClaimerStats {
+    pub wallet: Pubkey,
+    /// Tokens paid for claims, including the burned portion
+    pub lifetime_spent: u64,
+    /// Land-buy rewards paid out to this wallet
+    pub lifetime_rewards_claimed: u64,
+    /// Unix timestamp of the first claim (0 if the wallet has only claimed rewards)
+    pub first_claim_ts: i64,
+    pub parcels_claimed: u16,
+    pub blocks_claimed: u32,
+    /// PDA bump seed
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; 32],
+}
+
+impl ClaimerStats {
+    pub const SEED: &'static [u8] = CLAIMER_STATS_SEED;
+
+    /// Fills in the wallet and bump on first touch
+    pub fn touch(&mut self, wallet: Pubkey, bump: u8) {
+        if self.wallet == Pubkey::default() {
+            self.wallet = wallet;
+            self.bump = bump;
+        }
+    }
+
+    pub fn record_claim(&mut self, spent: u64, block_count: u32, now: i64) -> Option<()> {
+        if self.first_claim_ts == 0 {
+            self.first_claim_ts = now;
+        }
+        self.lifetime_spent = self.lifetime_spent.checked_add(spent)?;
+        self.parcels_claimed = self.parcels_claimed.checked_add(1)?;
+        self.blocks_claimed = self.blocks_claimed.checked_add(block_count)?;
+        Some(())
+    }
+
+    pub fn record_rewards(&mut self, amount: u64) -> Option<()> {
+        self.lifetime_rewards_claimed = self.lifetime_rewards_claimed.checked_add(amount)?;
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_claim_timestamp_is_kept() {
+        let mut stats = ClaimerStats {
+            wallet: Pubkey::default(),
+            lifetime_spent: 0,
+            lifetime_rewards_claimed: 0,
+            first_claim_ts: 0,
+            parcels_claimed: 0,
+            blocks_claimed: 0,
+            bump: 0,
+            _reserved: [0; 32],
+        };
+        let wallet = Pubkey::new_unique();
+        stats.touch(wallet, 254);
+        stats.record_rewards(50).unwrap();
+        assert_eq!(stats.first_claim_ts, 0);
+
+        stats.record_claim(4_000_000, 4, 1_000).unwrap();
+        stats.touch(Pubkey::new_unique(), 1);
+        stats.record_claim(1_000_000, 1, 2_000).unwrap();
+        assert_eq!(stats.wallet, wallet);
+        assert_eq!(stats.bump, 254);
+        assert_eq!(stats.first_claim_ts, 1_000);
+        assert_eq!(stats.lifetime_spent, 5_000_000);
+        assert_eq!(stats.parcels_claimed, 2);
+        assert_eq!(stats.blocks_claimed, 5);
+        assert_eq!(stats.lifetime_rewards_claimed, 50);
+    }
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::constants::CLAIMS_PAUSE_SEED;
+
+/// When claims were last paused, which GridConfig has no room for. Created by the first
+/// update_config after its introduction and kept in step with claims_paused by every
+/// update_config after that.
+#[account]
+#[derive(InitSpace)]
+pub struct ClaimsPause {
+    /// Slot claims were paused at; 0 while claims are open
+    pub paused_at_slot: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ClaimsPause {
+    pub const SEED: &'static [u8] = CLAIMS_PAUSE_SEED;
+
+    /// Reads the PDA, falling back to no recorded pause while it hasn't been created
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        if account.data_is_empty() {
+            return Ok(Self { paused_at_slot: 0, bump: 0 });
+        }
+        Self::try_deserialize(&mut &account.try_borrow_data()?[..])
+    }
+
+    /// Follows `claims_paused` at `slot`. A pause already recorded keeps its slot, and one
+    /// that predates this account starts counting now.
+    pub fn sync(&mut self, claims_paused: bool, slot: u64) {
+        if !claims_paused {
+            self.paused_at_slot = 0;
+        } else if self.paused_at_slot == 0 {
+            self.paused_at_slot = slot.max(1);
+        }
+    }
+
+    /// Whether claims have been paused for at least `slots` at `slot`
+    pub fn paused_for(&self, slots: u64, slot: u64) -> bool {
+        self.paused_at_slot != 0 && slot.saturating_sub(self.paused_at_slot) >= slots
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pause_keeps_its_first_slot() {
+        let mut pause = ClaimsPause { paused_at_slot: 0, bump: 255 };
+        pause.sync(true, 100);
+        pause.sync(true, 500);
+        assert_eq!(pause.paused_at_slot, 100);
+        assert!(!pause.paused_for(1_000, 1_099));
+        assert!(pause.paused_for(1_000, 1_100));
+
+        pause.sync(false, 1_200);
+        assert!(!pause.paused_for(0, 1_200));
+        pause.sync(true, 1_300);
+        assert_eq!(pause.paused_at_slot, 1_300);
+    }
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use crate::constants::CONFIG_FREEZE_SEED;
+
+/// Marks the config as permanently frozen. Only freeze_config creates it and nothing closes
+/// or writes it afterwards, so once it exists the instructions listed on freeze_config stay
+/// disabled for good.
+#[account]
+#[derive(InitSpace)]
+pub struct ConfigFreeze {
+    /// Authority that froze the config
+    pub authority: Pubkey,
+    /// When the config was frozen
+    pub frozen_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ConfigFreeze {
+    pub const SEED: &'static [u8] = CONFIG_FREEZE_SEED;
+
+    /// Whether `account`, the ConfigFreeze PDA, has been created
+    pub fn is_frozen(account: &AccountInfo) -> bool {
+        !account.data_is_empty()
+    }
+}
+
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::constants::CONFIG_TIMELOCK_SEED;
+use crate::errors::BillionError;
+
+/// Config fields that can't change mid-mint: while a timelock is set they only change
+/// through queue_config_update and, once it has passed, execute_config_update
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TimelockedConfig {
+    pub price_per_block: Option<u64>,
+    pub ring_thresholds: Option<Vec<u64>>,
+    pub land_owners_reward_share_bps: Option<u16>,
+    /// Lowering the timelock waits out the current one
+    pub config_timelock_secs: Option<i64>,
+    /// RingPricing.multipliers_bps, one per ring
+    pub ring_price_multipliers_bps: Option<Vec<u16>>,
+    /// RingPricing.curve_divisor
+    pub curve_divisor: Option<u64>,
+}
+
+impl TimelockedConfig {
+    /// Hash of the serialized change, all that is stored while it's queued
+    pub fn hash(&self) -> Result<[u8; 32]> {
+        Ok(hashv(&[&self.try_to_vec()?]).to_bytes())
+    }
+}
+
+/// The config timelock and the change queued under it. Created by the first
+/// queue_config_update; until then there is no timelock and update_config changes every
+/// field at once.
+#[account]
+#[derive(InitSpace)]
+pub struct ConfigTimelock {
+    /// Seconds between queueing a change and executing it
+    pub timelock_secs: i64,
+    /// TimelockedConfig::hash of the queued change
+    pub pending_hash: [u8; 32],
+    /// When the queued change can execute; 0 while nothing is queued
+    pub ready_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ConfigTimelock {
+    pub const SEED: &'static [u8] = CONFIG_TIMELOCK_SEED;
+
+    /// Reads the PDA, falling back to no timelock while it hasn't been created
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        if account.data_is_empty() {
+            return Ok(Self { timelock_secs: 0, pending_hash: [0u8; 32], ready_at: 0, bump: 0 });
+        }
+        Self::try_deserialize(&mut &account.try_borrow_data()?[..])
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.ready_at != 0
+    }
+
+    /// Queues `change` to execute `timelock_secs` after `now`
+    pub fn queue(&mut self, change: &TimelockedConfig, now: i64) -> Result<()> {
+        require!(!self.is_pending(), BillionError::ConfigUpdatePending);
+        self.pending_hash = change.hash()?;
+        // A zero timelock still leaves a queued change to execute
+        self.ready_at = now.checked_add(self.timelock_secs).ok_or(BillionError::Overflow)?.max(1);
+        Ok(())
+    }
+
+    /// Takes the queued change if it is `change` and its timelock has passed at `now`
+    pub fn take(&mut self, change: &TimelockedConfig, now: i64) -> Result<()> {
+        require!(self.is_pending(), BillionError::NoPendingConfigUpdate);
+        require!(now >= self.ready_at, BillionError::ConfigUpdateNotReady);
+        require!(change.hash()? == self.pending_hash, BillionError::ConfigUpdateMismatch);
+        self.cancel()
+    }
+
+    /// Drops the queued change
+    pub fn cancel(&mut self) -> Result<()> {
+        require!(self.is_pending(), BillionError::NoPendingConfigUpdate);
+        self.pending_hash = [0u8; 32];
+        self.ready_at = 0;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timelock(timelock_secs: i64) -> ConfigTimelock {
+        ConfigTimelock { timelock_secs, pending_hash: [0u8; 32], ready_at: 0, bump: 255 }
+    }
+
+    #[test]
+    fn test_queued_change_executes_after_the_timelock() {
+        let mut lock = timelock(100);
+        let change = TimelockedConfig { price_per_block: Some(2_000_000), ..Default::default() };
+        lock.queue(&change, 1_000).unwrap();
+        assert_eq!(lock.ready_at, 1_100);
+        assert!(lock.queue(&change, 1_000).is_err());
+        assert!(lock.take(&change, 1_099).is_err());
+
+        let other = TimelockedConfig { price_per_block: Some(3_000_000), ..Default::default() };
+        assert!(lock.take(&other, 1_100).is_err());
+        lock.take(&change, 1_100).unwrap();
+        assert!(!lock.is_pending());
+        assert!(lock.take(&change, 1_100).is_err());
+    }
+
+    #[test]
+    fn test_cancel_needs_a_queued_change() {
+        let mut lock = timelock(0);
+        assert!(lock.cancel().is_err());
+        lock.queue(&TimelockedConfig::default(), 0).unwrap();
+        assert!(lock.is_pending());
+        lock.cancel().unwrap();
+        assert!(!lock.is_pending());
+    }
+}
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use crate::constants::{DISTRIBUTION_VAULT_SEED, REWARD_SCALE};
+
+/// A one-off airdrop of any token to landowners, pro rata by blocks owned when it was created.
+/// The account is a client-generated keypair, so a distributor can run any number at once.
+#[account]
+pub struct Distribution {
+    /// Wallet that funded the distribution and can sweep it after expiry
+    pub distributor: Pubkey,
+    /// Token being distributed (any SPL or Token-2022 mint)
+    pub mint: Pubkey,
+    /// Total amount pulled into the vault
+    pub amount: u64,
+    /// Tokens per block, scaled by REWARD_SCALE, fixed at creation
+    pub per_block: u128,
+    /// grid_config.epoch at creation; only parcels from this epoch can claim
+    pub epoch: u16,
+    /// grid_config.next_parcel_id at creation; only lower parcel ids can claim
+    pub snapshot_next_parcel_id: u16,
+    pub created_at: i64,
+    /// After this, claims stop and the distributor can sweep the vault
+    pub expires_at: i64,
+    /// Paid out so far
+    pub claimed_amount: u64,
+    /// Number of parcels paid
+    pub claim_count: u32,
+    /// Bump of the vault PDA
+    pub vault_bump: u8,
+    /// One bit per parcel id below `snapshot_next_parcel_id`
+    pub claimed: Vec<u8>,
+}
+
+impl Distribution {
+    pub const VAULT_SEED: &'static [u8] = DISTRIBUTION_VAULT_SEED;
+
+    /// Account size for a snapshot covering parcel ids below `next_parcel_id`
+    pub fn space(next_parcel_id: u16) -> usize {
+        8 + 32 + 32 + 8 + 16 + 2 + 2 + 8 + 8 + 8 + 4 + 1 + 4 + Self::bitmap_len(next_parcel_id)
+    }
+
+    pub fn bitmap_len(next_parcel_id: u16) -> usize {
+        (next_parcel_id as usize).div_ceil(8)
+    }
+
+    /// `per_block` for `amount` split across `total_claimed_blocks`
+    pub fn per_block_for(amount: u64, total_claimed_blocks: u32) -> Option<u128> {
+        (amount as u128)
+            .checked_mul(REWARD_SCALE)?
+            .checked_div(total_claimed_blocks as u128)
+    }
+
+    /// Unscaled payout for a parcel of `block_count` blocks
+    pub fn payout(&self, block_count: u32) -> Option<u64> {
+        let owed = (block_count as u128).checked_mul(self.per_block)? / REWARD_SCALE;
+        u64::try_from(owed).ok()
+    }
+
+    pub fn is_claimed(&self, parcel_id: u16) -> bool {
+        self.claimed[parcel_id as usize / 8] & (1 << (parcel_id % 8)) != 0
+    }
+
+    pub fn set_claimed(&mut self, parcel_id: u16) {
+        self.claimed[parcel_id as usize / 8] |= 1 << (parcel_id % 8);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_space_matches_serialized_len() {
+        let distribution = Distribution {
+            distributor: Pubkey::default(),
+            mint: Pubkey::default(),
+            amount: 0,
+            per_block: 0,
+            epoch: 0,
+            snapshot_next_parcel_id: 17,
+            created_at: 0,
+            expires_at: 0,
+            claimed_amount: 0,
+            claim_count: 0,
+            vault_bump: 0,
+            claimed: vec![0u8; Distribution::bitmap_len(17)],
+        };
+        let mut data = Vec::new();
+        distribution.try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), Distribution::space(17));
+    }
+
+    #[test]
+    fn test_payout_is_pro_rata() {
+        let per_block = Distribution::per_block_for(1_000, 30).unwrap();
+        let distribution = Distribution {
+            distributor: Pubkey::default(),
+            mint: Pubkey::default(),
+            amount: 1_000,
+            per_block,
+            epoch: 0,
+            snapshot_next_parcel_id: 3,
+            created_at: 0,
+            expires_at: 0,
+            claimed_amount: 0,
+            claim_count: 0,
+            vault_bump: 0,
+            claimed: vec![0u8; 1],
+        };
+        // 10 + 20 blocks: rounding down never pays out more than the amount
+        assert_eq!(distribution.payout(10), Some(333));
+        assert_eq!(distribution.payout(20), Some(666));
+        assert_eq!(distribution.payout(30), Some(999));
+    }
+}
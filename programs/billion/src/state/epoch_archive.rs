@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use crate::constants::EPOCH_ARCHIVE_SEED;
+
+/// Final state of a finished season, written by start_new_epoch. Parcels from the epoch
+/// claim land-buy rewards against `land_buy_rewards_per_block` here.
+#[account]
+#[derive(InitSpace)]
+pub struct EpochArchive {
+    pub epoch: u16,
+    /// The epoch's BlockMap, now frozen
+    pub block_map: Pubkey,
+    /// One past the last parcel id claimed in the epoch
+    pub next_parcel_id: u16,
+    pub total_claimed_blocks: u32,
+    /// Accumulator value when the epoch ended
+    pub land_buy_rewards_per_block: u128,
+    /// grid_config.total_burned when the epoch ended (not reset between epochs)
+    pub total_burned: u64,
+    pub archived_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; 32],
+}
+
+impl EpochArchive {
+    pub const SEED: &'static [u8] = EPOCH_ARCHIVE_SEED;
+}
@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+use crate::constants::{FRACTION_ESCROW_SEED, FRACTION_POSITION_SEED, FRACTION_SEED, FRACTION_VAULT_SEED, REWARD_SCALE};
+
+/// A parcel split into `share_supply` fungible Token-2022 shares. The Fraction PDA owns the
+/// escrowed Core asset until a holder of every share redeems it.
+#[account]
+#[derive(InitSpace)]
+pub struct Fraction {
+    pub parcel_id: u16,
+    /// Epoch of the parcel (part of the PDA seeds)
+    pub epoch: u16,
+    pub asset: Pubkey,
+    /// Share mint, a client-generated keypair with a fixed supply (mint authority revoked)
+    pub share_mint: Pubkey,
+    pub share_supply: u64,
+    /// Owner who fractionalized the parcel
+    pub fractionalized_by: Pubkey,
+    pub fractionalized_at: i64,
+    /// Shares deposited into positions; only these earn land-buy rewards
+    pub total_deposited: u64,
+    /// Land-buy rewards per deposited share, scaled by REWARD_SCALE
+    pub rewards_per_share: u128,
+    /// Harvested while nothing was deposited, folded in at the next harvest with deposits
+    pub undistributed: u64,
+    /// Lifetime land-buy rewards moved from the pool into the vault
+    pub total_harvested: u64,
+    /// PDA bump seed
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; 32],
+}
+
+impl Fraction {
+    pub const SEED: &'static [u8] = FRACTION_SEED;
+    pub const VAULT_SEED: &'static [u8] = FRACTION_VAULT_SEED;
+    pub const ESCROW_SEED: &'static [u8] = FRACTION_ESCROW_SEED;
+
+    /// Credits `amount` newly harvested rewards to deposited shares, or holds it in
+    /// `undistributed` while nothing is deposited
+    pub fn distribute(&mut self, amount: u64) -> Option<()> {
+        self.total_harvested = self.total_harvested.checked_add(amount)?;
+        let pending = self.undistributed.checked_add(amount)?;
+        if self.total_deposited == 0 {
+            self.undistributed = pending;
+            return Some(());
+        }
+        let increase = (pending as u128)
+            .checked_mul(REWARD_SCALE)?
+            .checked_div(self.total_deposited as u128)?;
+        self.rewards_per_share = self.rewards_per_share.checked_add(increase)?;
+        self.undistributed = 0;
+        Some(())
+    }
+}
+
+/// Shares a holder has deposited to earn a fraction's land-buy rewards
+#[account]
+#[derive(InitSpace)]
+pub struct FractionPosition {
+    pub share_mint: Pubkey,
+    pub holder: Pubkey,
+    pub deposited: u64,
+    /// fraction.rewards_per_share when the position was last settled
+    pub rewards_per_share_paid: u128,
+    /// Lifetime rewards paid to this position
+    pub total_claimed: u64,
+    /// PDA bump seed
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; 16],
+}
+
+impl FractionPosition {
+    pub const SEED: &'static [u8] = FRACTION_POSITION_SEED;
+
+    /// Rewards owed at `rewards_per_share` since the last settlement
+    pub fn owed(&self, rewards_per_share: u128) -> Option<u64> {
+        let delta = rewards_per_share.checked_sub(self.rewards_per_share_paid)?;
+        let owed = (self.deposited as u128).checked_mul(delta)? / REWARD_SCALE;
+        u64::try_from(owed).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fraction(total_deposited: u64) -> Fraction {
+        Fraction {
+            parcel_id: 1,
+            epoch: 0,
+            asset: Pubkey::default(),
+            share_mint: Pubkey::default(),
+            share_supply: 1_000,
+            fractionalized_by: Pubkey::default(),
+            fractionalized_at: 0,
+            total_deposited,
+            rewards_per_share: 0,
+            undistributed: 0,
+            total_harvested: 0,
+            bump: 255,
+            _reserved: [0; 32],
+        }
+    }
+
+    fn position(deposited: u64) -> FractionPosition {
+        FractionPosition {
+            share_mint: Pubkey::default(),
+            holder: Pubkey::default(),
+            deposited,
+            rewards_per_share_paid: 0,
+            total_claimed: 0,
+            bump: 255,
+            _reserved: [0; 16],
+        }
+    }
+
+    #[test]
+    fn test_distribute_splits_pro_rata() {
+        let mut fraction = fraction(1_000);
+        fraction.distribute(5_000).unwrap();
+        assert_eq!(position(250).owed(fraction.rewards_per_share), Some(1_250));
+        assert_eq!(position(750).owed(fraction.rewards_per_share), Some(3_750));
+        assert_eq!(fraction.total_harvested, 5_000);
+    }
+
+    #[test]
+    fn test_distribute_holds_rewards_until_deposits() {
+        let mut fraction = fraction(0);
+        fraction.distribute(400).unwrap();
+        assert_eq!(fraction.rewards_per_share, 0);
+        assert_eq!(fraction.undistributed, 400);
+
+        fraction.total_deposited = 100;
+        fraction.distribute(100).unwrap();
+        assert_eq!(fraction.undistributed, 0);
+        assert_eq!(position(100).owed(fraction.rewards_per_share), Some(500));
+    }
+
+    #[test]
+    fn test_owed_starts_from_last_settlement() {
+        let mut fraction = fraction(10);
+        fraction.distribute(100).unwrap();
+        let mut position = position(10);
+        position.rewards_per_share_paid = fraction.rewards_per_share;
+        assert_eq!(position.owed(fraction.rewards_per_share), Some(0));
+
+        fraction.distribute(30).unwrap();
+        assert_eq!(position.owed(fraction.rewards_per_share), Some(30));
+    }
+}
@@ -1,6 +1,19 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
 
-pub const LAND_BUY_REWARD_POOL_SEED: &[u8] = b"land_buy_reward_pool";
+use billion_core::{get_ring, get_unlocked_ring, ring_count_is_valid, Rect};
+
+use crate::constants::{
+    ADJACENCY_BONUS_MIN_NEIGHBORS, EXPANSION_PRIORITY_WINDOW_SECS, GRID_CONFIG_SEED, MAX_PARCEL_ID, MAX_URI_BASE_LEN,
+    RING_COUNT,
+};
+use crate::errors::BillionError;
+use crate::state::RingUnlockSchedule;
+
+pub use crate::constants::{
+    ASSET_SEED, EMISSIONS_VAULT_SEED, LAND_BUY_REWARD_POOL_SEED, QUEST_VAULT_SEED, REFERRAL_VAULT_SEED,
+    SOL_TREASURY_SEED,
+};
 
 #[account]
 #[derive(InitSpace)]
@@ -11,24 +24,586 @@ pub struct GridConfig {
     pub collection: Pubkey, // Metaplex Core collection address for parcel NFTs
     pub price_per_block: u64,
     pub total_burned: u64,
-    #[max_len(10)]
+    /// One per ring, so their number is the grid's ring count; there's no room left for it
+    /// as a field of its own
+    #[max_len(RING_COUNT)]
     pub ring_thresholds: Vec<u64>,
     pub next_parcel_id: u16,
-    #[max_len(128)]
+    #[max_len(MAX_URI_BASE_LEN)]
     pub uri_base: String,
     pub seeding_enabled: bool,
     pub bump: u8,
     /// Global accumulator for land buy rewards (scaled by 1e9 for precision)
     pub land_buy_rewards_per_block: u128,
-    /// Sum of all blocks in claimed parcels
+    /// Sum of all blocks in claimed parcels, less those excluded from land-buy rewards
+    /// (counted in RewardExclusions instead)
     pub total_claimed_blocks: u32,
     /// Basis points for land owner reward share (2000 = 20%)
     pub land_owners_reward_share_bps: u16,
     /// Token account holding claimable land buy rewards
     pub land_buy_reward_pool: Pubkey,
-    pub _padding: [u8; 202], // Reduced by 8 to accommodate u128
+    /// Layout version of this account (0 = created before versioning)
+    pub schema_version: u8,
+    /// Basis points of each referred claim credited to the referrer (0 = referrals off)
+    pub referral_bps: u16,
+    /// Token account holding accrued referral earnings (default until init_referral_vault)
+    pub referral_vault: Pubkey,
+    /// Staking emission per staked block per second, in token base units
+    pub emission_rate_per_block_per_sec: u64,
+    /// Token account funding staking emissions (default until init_emissions_vault)
+    pub emissions_vault: Pubkey,
+    /// Token account paying quest rewards (default until init_quest_vault)
+    pub quest_vault: Pubkey,
+    /// Current season; earlier seasons are frozen and kept in EpochArchive accounts
+    pub epoch: u16,
+    /// Basis points of a burned parcel's share of the reward-pool surplus paid by burn_for_exit (0 = off)
+    pub exit_share_bps: u16,
+    /// Land-buy rewards credited through the accumulator but not yet paid out of the pool;
+    /// anything the pool holds above this is surplus
+    pub reward_liability: u64,
+    /// Seconds without a claim after which a parcel's pending land-buy rewards lapse (0 = never)
+    pub reward_expiry_seconds: i64,
+    /// Basis points of lapsed rewards paid to whoever cranks expire_parcel_rewards
+    pub expiry_bounty_bps: u16,
+    /// When expiry was last switched on; no parcel's clock starts earlier than this
+    pub reward_expiry_since: i64,
+    /// Extra basis points of land-buy rewards paid, out of pool surplus, to parcels with
+    /// at least ADJACENCY_BONUS_MIN_NEIGHBORS neighbors (0 = off)
+    pub adjacency_bonus_bps: u16,
+    /// When the latest ring unlocked; opens the adjacent-owner expansion window
+    pub ring_unlocked_at: i64,
+    /// Lowest ring unlocked in the current expansion window (0 = no window yet)
+    pub priority_ring: u8,
+    /// Most basis points of a claim's cost a buyer may route to a registered charity
+    pub max_charity_bps: u16,
+    /// Cost of one period of the premium map badge (0 = premium disabled)
+    pub premium_price_per_period: u64,
+    /// Length of one premium period in seconds
+    pub premium_period_secs: i64,
+    /// Minimum seconds between raffle draws (0 = raffles disabled)
+    pub raffle_interval_secs: i64,
+    /// Largest width * height a claim may cover (0 = unlimited); admin_mint isn't limited
+    pub max_parcel_blocks: u16,
+    /// Most blocks one wallet may claim, counted in its ClaimerStats (0 = unlimited);
+    /// admin_mint isn't limited
+    pub max_blocks_per_wallet: u32,
+    /// Halts every public claim path; stored inverted so zeroed padding reads as enabled
+    pub claims_paused: bool,
+    /// Price per block for claim_parcel_sol (0 = SOL payments disabled)
+    pub price_per_block_lamports: u64,
+    /// Lamports paid into the SOL treasury by claim_parcel_sol, before any withdrawal
+    pub total_sol_collected: u64,
+    /// Share of price_per_block a SOL-paid block adds to total_burned toward ring unlocks, in
+    /// basis points; no tokens are actually burned for it
+    pub sol_ring_credit_bps: u16,
+    /// Restricts claims to claim_parcel with a proof against the Allowlist root
+    pub allowlist_only: bool,
+    /// Bumped by update_config whenever the collection changes; parcels record the
+    /// generation they were minted under
+    pub collection_generation: u16,
+    pub _padding: [u8; 0], // Reduced by 8 for u128, 1 for schema_version, 34 for referrals, 40 for staking, 32 for quests, 2 for epochs, 10 for exits, 18 for reward expiry, 2 for adjacency, 9 for expansion priority, 2 for charity, 16 for premium, 8 for raffles, 2 for max parcel size, 4 for the wallet limit, 1 for the claims pause, 18 for SOL payments, 1 for the allowlist, 2 for the collection generation
+}
+
+/// Feature bits reported by get_program_info
+pub const FEATURE_PDA_ASSETS: u64 = 1 << 0;
+pub const FEATURE_SEEDING: u64 = 1 << 1;
+pub const FEATURE_LAND_BUY_REWARDS: u64 = 1 << 2;
+
+/// Grid component of every grid-scoped PDA (`[SEED, grid_seed(grid_id), ...]`), ahead of any
+/// epoch component. Empty for grid 0, so a deployment from before multiple grids is grid 0
+/// and keeps its original addresses.
+pub fn grid_seed(grid_id: u64) -> Vec<u8> {
+    if grid_id == 0 {
+        Vec::new()
+    } else {
+        grid_id.to_le_bytes().to_vec()
+    }
 }
 
 impl GridConfig {
-    pub const SEED: &'static [u8] = b"grid_config";
+    pub const SEED: &'static [u8] = GRID_CONFIG_SEED;
+
+    pub const SCHEMA_VERSION: u8 = 1;
+
+    /// Records `amount` of land-buy rewards credited to landowners through the accumulator
+    pub fn credit_land_buy_rewards(&mut self, amount: u64) -> Option<()> {
+        self.reward_liability = self.reward_liability.checked_add(amount)?;
+        Some(())
+    }
+
+    /// Records `amount` of land-buy rewards paid out of the pool. Payouts round down, so the
+    /// liability can only overstate what is still owed.
+    pub fn pay_land_buy_rewards(&mut self, amount: u64) {
+        self.reward_liability = self.reward_liability.saturating_sub(amount);
+    }
+
+    /// Portion of a reward pool holding `pool_balance` that no landowner is owed
+    pub fn reward_surplus(&self, pool_balance: u64) -> u64 {
+        pool_balance.saturating_sub(self.reward_liability)
+    }
+
+    /// Exit payout for burning a parcel of `block_count` blocks: exit_share_bps of the
+    /// parcel's pro-rata share of `surplus`
+    pub fn exit_payout(&self, surplus: u64, block_count: u32) -> Option<u64> {
+        if self.total_claimed_blocks == 0 {
+            return Some(0);
+        }
+        let payout = (surplus as u128)
+            .checked_mul(block_count as u128)?
+            .checked_mul(self.exit_share_bps as u128)?
+            / (self.total_claimed_blocks as u128 * 10_000);
+        u64::try_from(payout).ok()
+    }
+
+    /// Whether rewards of a parcel last claimed at `last_claimed_at` have lapsed at `now`
+    pub fn rewards_expired(&self, last_claimed_at: i64, now: i64) -> bool {
+        if self.reward_expiry_seconds <= 0 {
+            return false;
+        }
+        let clock_start = last_claimed_at.max(self.reward_expiry_since);
+        now.saturating_sub(clock_start) > self.reward_expiry_seconds
+    }
+
+    /// Adjacency bonus on a claim of `owed` by a parcel with `adjacency_score` neighbors,
+    /// capped at the pool `surplus` so it never eats into other landowners' rewards
+    pub fn adjacency_bonus(&self, owed: u64, adjacency_score: u8, surplus: u64) -> u64 {
+        if adjacency_score < ADJACENCY_BONUS_MIN_NEIGHBORS {
+            return 0;
+        }
+        let bonus = (owed as u128) * (self.adjacency_bonus_bps as u128) / 10_000;
+        bonus.min(surplus as u128) as u64
+    }
+
+    /// Opens the expansion window when the unlocked ring rises from `previously_unlocked` to
+    /// `unlocked`. Rings unlocking while a window is open extend it rather than replace it.
+    pub fn record_ring_unlock(&mut self, previously_unlocked: u8, unlocked: u8, now: i64) {
+        if unlocked <= previously_unlocked {
+            return;
+        }
+        if !self.in_priority_window(now) {
+            self.priority_ring = previously_unlocked + 1;
+        }
+        self.ring_unlocked_at = now;
+    }
+
+    pub fn in_priority_window(&self, now: i64) -> bool {
+        self.priority_ring != 0 && now < self.ring_unlocked_at.saturating_add(EXPANSION_PRIORITY_WINDOW_SECS)
+    }
+
+    /// Whether a `width` x `height` claim fits under max_parcel_blocks
+    pub fn allows_parcel_size(&self, width: u8, height: u8) -> bool {
+        self.allows_parcel_blocks((width as u32) * (height as u32))
+    }
+
+    /// Whether a claim of `block_count` blocks fits under max_parcel_blocks
+    pub fn allows_parcel_blocks(&self, block_count: u32) -> bool {
+        self.max_parcel_blocks == 0 || block_count <= self.max_parcel_blocks as u32
+    }
+
+    /// Whether `count` more parcels can be numbered from next_parcel_id without passing
+    /// MAX_PARCEL_ID, which would run into the reserved-block marker
+    pub fn has_parcel_ids(&self, count: usize) -> bool {
+        (self.next_parcel_id as usize) + count <= (MAX_PARCEL_ID as usize) + 1
+    }
+
+    /// Whether a wallet that has claimed `blocks_claimed` blocks may claim `new_blocks` more
+    pub fn allows_wallet_blocks(&self, blocks_claimed: u32, new_blocks: u32) -> bool {
+        self.max_blocks_per_wallet == 0 || blocks_claimed.saturating_add(new_blocks) <= self.max_blocks_per_wallet
+    }
+
+    /// What a `block_count`-block SOL claim adds to total_burned
+    pub fn sol_ring_credit(&self, block_count: u32) -> Option<u64> {
+        let credit = (block_count as u128)
+            .checked_mul(self.price_per_block as u128)?
+            .checked_mul(self.sol_ring_credit_bps as u128)?
+            / 10_000;
+        u64::try_from(credit).ok()
+    }
+
+    /// Whether claiming `rect` of a `grid_size` grid at `now` is reserved for owners of an
+    /// adjacent parcel
+    pub fn requires_adjacency_proof(&self, rect: Rect, now: i64, grid_size: u16) -> bool {
+        self.in_priority_window(now)
+            && rect.blocks().any(|(x, y)| get_ring(x, y, grid_size, self.ring_count()) >= self.priority_ring)
+    }
+
+    /// Number of rings the grid is split into, one per ring threshold
+    pub fn ring_count(&self) -> u8 {
+        self.ring_thresholds.len() as u8
+    }
+
+    /// Ring unlocked by the burns so far
+    pub fn unlocked_ring(&self) -> u8 {
+        get_unlocked_ring(self.total_burned, &self.ring_thresholds, self.ring_count())
+    }
+
+    /// Ring unlocked at `now` by the burns or by `schedule`, whichever has gone further
+    pub fn unlocked_ring_at(&self, schedule: &RingUnlockSchedule, now: i64) -> u8 {
+        schedule.unlocked_ring(self.unlocked_ring(), now, self.ring_count())
+    }
+
+    /// Whether the landowner, treasury, referral and largest charity shares of a claim fit in
+    /// it together; more than all of it would leave less than nothing to burn and fail every
+    /// claim. Each setter of one share checks it against the current others.
+    pub fn valid_split_bps(reward_bps: u16, treasury_bps: u16, referral_bps: u16, max_charity_bps: u16) -> bool {
+        reward_bps as u32 + treasury_bps as u32 + referral_bps as u32 + max_charity_bps as u32 <= 10_000
+    }
+
+    /// Whether a `grid_size` grid can have `ring_count` rings; the per-ring accounts hold
+    /// RING_COUNT entries
+    pub fn valid_ring_count(ring_count: usize, grid_size: u16) -> bool {
+        ring_count <= RING_COUNT as usize && ring_count_is_valid(ring_count as u8, grid_size)
+    }
+
+    /// Checks that `thresholds` holds one threshold per ring of a `ring_count`-ring grid,
+    /// starting from 0 for ring 1 and none below the one before it. get_unlocked_ring searches
+    /// them on that ordering.
+    pub fn check_ring_thresholds(thresholds: &[u64], ring_count: u8) -> Result<()> {
+        require!(thresholds.len() == ring_count as usize, BillionError::InvalidRingThresholds);
+        require!(thresholds.first() == Some(&0), BillionError::FirstRingThresholdNotZero);
+        require!(
+            thresholds.windows(2).all(|pair| pair[0] <= pair[1]),
+            BillionError::RingThresholdsNotAscending
+        );
+        Ok(())
+    }
+
+    /// Whether `uri_base` fits the account and ends where a parcel id can be appended
+    pub fn valid_uri_base(uri_base: &str) -> bool {
+        uri_base.len() <= MAX_URI_BASE_LEN as usize && (uri_base.ends_with('/') || uri_base.ends_with('='))
+    }
+
+    /// Whether `suffix` can follow a parcel id in its uri: URL-safe characters and '/', not
+    /// starting with a digit that would read as part of another parcel's id
+    pub fn valid_uri_suffix(suffix: &str) -> bool {
+        !suffix.starts_with(|c: char| c.is_ascii_digit())
+            && suffix.bytes().all(|b| b.is_ascii_alphanumeric() || b"-._~/".contains(&b))
+    }
+
+    /// Whether parcels have been minted under the current collection, which a new one
+    /// would leave behind
+    pub fn collection_locked(&self) -> bool {
+        self.next_parcel_id > 1
+    }
+
+    /// Points the grid at `collection`, starting a new generation when it differs
+    pub fn set_collection(&mut self, collection: Pubkey) -> Option<()> {
+        if collection != self.collection {
+            self.collection_generation = self.collection_generation.checked_add(1)?;
+        }
+        self.collection = collection;
+        Some(())
+    }
+
+    /// Sha256 over the thresholds' little-endian bytes, as reported by ConfigUpdated
+    pub fn ring_thresholds_hash(&self) -> [u8; 32] {
+        let bytes: Vec<[u8; 8]> = self.ring_thresholds.iter().map(|threshold| threshold.to_le_bytes()).collect();
+        let slices: Vec<&[u8]> = bytes.iter().map(|b| b.as_slice()).collect();
+        hashv(&slices).to_bytes()
+    }
+
+    /// Optional features enabled on this deployment, derived from config fields
+    pub fn feature_flags(&self) -> u64 {
+        let mut flags = FEATURE_PDA_ASSETS;
+        if self.seeding_enabled {
+            flags |= FEATURE_SEEDING;
+        }
+        if self.land_owners_reward_share_bps > 0 {
+            flags |= FEATURE_LAND_BUY_REWARDS;
+        }
+        flags
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{DEFAULT_GRID_SIZE, MIN_GRID_SIZE};
+
+    fn config(total_claimed_blocks: u32, exit_share_bps: u16, reward_liability: u64) -> GridConfig {
+        GridConfig {
+            authority: Pubkey::default(),
+            token_mint: Pubkey::default(),
+            block_map: Pubkey::default(),
+            collection: Pubkey::default(),
+            price_per_block: 0,
+            total_burned: 0,
+            ring_thresholds: vec![0; RING_COUNT as usize],
+            next_parcel_id: 1,
+            uri_base: String::new(),
+            seeding_enabled: false,
+            bump: 255,
+            land_buy_rewards_per_block: 0,
+            total_claimed_blocks,
+            land_owners_reward_share_bps: 0,
+            land_buy_reward_pool: Pubkey::default(),
+            schema_version: GridConfig::SCHEMA_VERSION,
+            referral_bps: 0,
+            referral_vault: Pubkey::default(),
+            emission_rate_per_block_per_sec: 0,
+            emissions_vault: Pubkey::default(),
+            quest_vault: Pubkey::default(),
+            epoch: 0,
+            exit_share_bps,
+            reward_liability,
+            reward_expiry_seconds: 0,
+            expiry_bounty_bps: 0,
+            reward_expiry_since: 0,
+            adjacency_bonus_bps: 0,
+            ring_unlocked_at: 0,
+            priority_ring: 0,
+            max_charity_bps: 0,
+            premium_price_per_period: 0,
+            premium_period_secs: 0,
+            raffle_interval_secs: 0,
+            max_parcel_blocks: 0,
+            max_blocks_per_wallet: 0,
+            claims_paused: false,
+            price_per_block_lamports: 0,
+            total_sol_collected: 0,
+            sol_ring_credit_bps: 0,
+            allowlist_only: false,
+            collection_generation: 0,
+            _padding: [0; 0],
+        }
+    }
+
+    #[test]
+    fn test_reward_surplus_excludes_liability() {
+        let mut config = config(10, 0, 0);
+        config.credit_land_buy_rewards(600).unwrap();
+        assert_eq!(config.reward_surplus(1_000), 400);
+        config.pay_land_buy_rewards(700);
+        assert_eq!(config.reward_liability, 0);
+        assert_eq!(config.reward_surplus(1_000), 1_000);
+        assert_eq!(self::config(0, 0, 2_000).reward_surplus(1_000), 0);
+    }
+
+    #[test]
+    fn test_rewards_expire_after_window_since_enabled() {
+        let mut config = config(10, 0, 0);
+        assert!(!config.rewards_expired(0, i64::MAX));
+
+        config.reward_expiry_seconds = 100;
+        config.reward_expiry_since = 1_000;
+        // Parcels idle before expiry was switched on start their clock at reward_expiry_since
+        assert!(!config.rewards_expired(0, 1_100));
+        assert!(config.rewards_expired(0, 1_101));
+        assert!(!config.rewards_expired(2_000, 2_100));
+        assert!(config.rewards_expired(2_000, 2_101));
+    }
+
+    #[test]
+    fn test_adjacency_bonus_needs_neighbors_and_surplus() {
+        let mut config = config(10, 0, 0);
+        config.adjacency_bonus_bps = 2_500;
+        assert_eq!(config.adjacency_bonus(1_000, 2, u64::MAX), 0);
+        assert_eq!(config.adjacency_bonus(1_000, 3, u64::MAX), 250);
+        assert_eq!(config.adjacency_bonus(1_000, 3, 100), 100);
+        config.adjacency_bonus_bps = 0;
+        assert_eq!(config.adjacency_bonus(1_000, 8, u64::MAX), 0);
+    }
+
+    #[test]
+    fn test_expansion_window_covers_newly_unlocked_rings_for_an_hour() {
+        let mut config = config(10, 0, 0);
+        let ring_2 = Rect::new(6, 20, 1, 1);
+        let ring_1 = Rect::new(5, 20, 1, 1);
+        assert!(!config.requires_adjacency_proof(ring_2, 0, DEFAULT_GRID_SIZE));
+
+        config.record_ring_unlock(1, 1, 1_000);
+        assert!(!config.in_priority_window(1_000));
+
+        config.record_ring_unlock(1, 2, 1_000);
+        assert!(config.requires_adjacency_proof(ring_2, 1_000, DEFAULT_GRID_SIZE));
+        assert!(config.requires_adjacency_proof(Rect::new(5, 20, 2, 1), 1_000, DEFAULT_GRID_SIZE));
+        assert!(!config.requires_adjacency_proof(ring_1, 1_000, DEFAULT_GRID_SIZE));
+        assert!(config.requires_adjacency_proof(ring_2, 1_000 + EXPANSION_PRIORITY_WINDOW_SECS - 1, DEFAULT_GRID_SIZE));
+        assert!(!config.requires_adjacency_proof(ring_2, 1_000 + EXPANSION_PRIORITY_WINDOW_SECS, DEFAULT_GRID_SIZE));
+
+        // A second unlock inside the window keeps ring 2 reserved and restarts the clock
+        config.record_ring_unlock(2, 3, 2_000);
+        assert_eq!(config.priority_ring, 2);
+        assert!(config.requires_adjacency_proof(ring_2, 2_000 + EXPANSION_PRIORITY_WINDOW_SECS - 1, DEFAULT_GRID_SIZE));
+
+        // Once it has closed, the next unlock reserves only the new ring
+        config.record_ring_unlock(3, 4, 10_000);
+        assert_eq!(config.priority_ring, 4);
+        assert!(!config.requires_adjacency_proof(ring_2, 10_000, DEFAULT_GRID_SIZE));
+    }
+
+    #[test]
+    fn test_exit_payout_is_pro_rata_share_of_surplus() {
+        // 4 of 10 blocks, half of their share
+        assert_eq!(config(10, 5_000, 0).exit_payout(1_000, 4), Some(200));
+        assert_eq!(config(10, 0, 0).exit_payout(1_000, 4), Some(0));
+        assert_eq!(config(0, 5_000, 0).exit_payout(1_000, 4), Some(0));
+        assert_eq!(config(4, 10_000, 0).exit_payout(u64::MAX, 4), Some(u64::MAX));
+    }
+
+    #[test]
+    fn test_parcel_size_limit() {
+        let mut config = config(10, 0, 0);
+        assert!(config.allows_parcel_size(u8::MAX, u8::MAX));
+        config.max_parcel_blocks = 64;
+        assert!(config.allows_parcel_size(8, 8));
+        assert!(config.allows_parcel_size(64, 1));
+        assert!(!config.allows_parcel_size(10, 10));
+        assert!(!config.allows_parcel_size(65, 1));
+    }
+
+    #[test]
+    fn test_wallet_block_limit() {
+        let mut config = config(10, 0, 0);
+        assert!(config.allows_wallet_blocks(u32::MAX, u32::MAX));
+        config.max_blocks_per_wallet = 100;
+        assert!(config.allows_wallet_blocks(90, 10));
+        assert!(!config.allows_wallet_blocks(90, 11));
+        assert!(!config.allows_wallet_blocks(0, 101));
+    }
+
+    #[test]
+    fn test_parcel_ids_stop_below_the_reserved_marker() {
+        let mut config = config(0, 0, 0);
+        assert!(config.has_parcel_ids(MAX_PARCEL_ID as usize));
+        assert!(!config.has_parcel_ids(MAX_PARCEL_ID as usize + 1));
+        config.next_parcel_id = MAX_PARCEL_ID;
+        assert!(config.has_parcel_ids(1));
+        assert!(!config.has_parcel_ids(2));
+        config.next_parcel_id = crate::state::RESERVED_BLOCK;
+        assert!(!config.has_parcel_ids(1));
+    }
+
+    #[test]
+    fn test_sol_ring_credit_is_share_of_token_price() {
+        let mut config = config(10, 0, 0);
+        config.price_per_block = 1_000_000;
+        assert_eq!(config.sol_ring_credit(4), Some(0));
+        config.sol_ring_credit_bps = 5_000;
+        assert_eq!(config.sol_ring_credit(4), Some(2_000_000));
+        config.price_per_block = u64::MAX;
+        config.sol_ring_credit_bps = 10_000;
+        assert_eq!(config.sol_ring_credit(2), None);
+    }
+
+    #[test]
+    fn test_config_input_validation() {
+        assert!(GridConfig::valid_split_bps(10_000, 0, 0, 0));
+        assert!(!GridConfig::valid_split_bps(60_000, 0, 0, 0));
+        assert!(GridConfig::valid_split_bps(8_000, 2_000, 0, 0));
+        assert!(!GridConfig::valid_split_bps(8_000, 2_001, 0, 0));
+        assert!(GridConfig::valid_split_bps(3_000, 5_000, 1_000, 1_000));
+        // Each pair fits, all four don't
+        assert!(!GridConfig::valid_split_bps(3_000, 7_000, 2_000, 0));
+        assert!(!GridConfig::valid_split_bps(u16::MAX, u16::MAX, u16::MAX, u16::MAX));
+
+        let mut thresholds = vec![0u64, 0, 5, 5, 10, 20, 30, 40, 50, u64::MAX];
+        assert!(GridConfig::check_ring_thresholds(&thresholds, RING_COUNT).is_ok());
+        assert!(GridConfig::check_ring_thresholds(&thresholds[..5], 5).is_ok());
+        assert_eq!(
+            GridConfig::check_ring_thresholds(&thresholds[..2], RING_COUNT).unwrap_err(),
+            BillionError::InvalidRingThresholds.into()
+        );
+        thresholds[3] = 4;
+        assert_eq!(
+            GridConfig::check_ring_thresholds(&thresholds, RING_COUNT).unwrap_err(),
+            BillionError::RingThresholdsNotAscending.into()
+        );
+        thresholds[0] = 1;
+        assert_eq!(
+            GridConfig::check_ring_thresholds(&thresholds, RING_COUNT).unwrap_err(),
+            BillionError::FirstRingThresholdNotZero.into()
+        );
+
+        assert!(GridConfig::valid_ring_count(5, DEFAULT_GRID_SIZE));
+        assert!(GridConfig::valid_ring_count(RING_COUNT as usize, MIN_GRID_SIZE));
+        assert!(!GridConfig::valid_ring_count(RING_COUNT as usize + 1, DEFAULT_GRID_SIZE));
+        assert!(!GridConfig::valid_ring_count(0, DEFAULT_GRID_SIZE));
+
+        assert!(GridConfig::valid_uri_base("https://example.com/parcel/"));
+        assert!(GridConfig::valid_uri_base("https://example.com/parcel?id="));
+        assert!(!GridConfig::valid_uri_base("https://example.com/parcel"));
+        assert!(!GridConfig::valid_uri_base(&format!("https://example.com/{}/", "a".repeat(120))));
+
+        assert!(GridConfig::valid_uri_suffix(""));
+        assert!(GridConfig::valid_uri_suffix(".json"));
+        assert!(GridConfig::valid_uri_suffix("/v2/palace"));
+        assert!(!GridConfig::valid_uri_suffix("0"));
+        assert!(!GridConfig::valid_uri_suffix("@evil.example"));
+        assert!(!GridConfig::valid_uri_suffix("?next=https://evil.example"));
+    }
+
+    #[test]
+    fn test_ring_count_follows_the_thresholds() {
+        let mut config = config(10, 0, 0);
+        config.ring_thresholds = vec![0, 100, 200, 300, 400];
+        config.total_burned = 250;
+        assert_eq!((config.ring_count(), config.unlocked_ring()), (5, 3));
+        config.total_burned = u64::MAX;
+        assert_eq!(config.unlocked_ring(), 5);
+
+        // Ring 5 is the center of a 5-ring grid, so a window opened by its unlock covers it
+        config.record_ring_unlock(4, 5, 1_000);
+        assert!(config.requires_adjacency_proof(Rect::new(50, 50, 1, 1), 1_000, DEFAULT_GRID_SIZE));
+        assert!(!config.requires_adjacency_proof(Rect::new(50, 70, 1, 1), 1_000, DEFAULT_GRID_SIZE));
+    }
+
+    #[test]
+    fn test_ring_thresholds_hash_tracks_every_threshold() {
+        let mut config = config(10, 0, 0);
+        config.ring_thresholds = vec![0, 10];
+        let hash = config.ring_thresholds_hash();
+        config.ring_thresholds = vec![0, 11];
+        assert_ne!(config.ring_thresholds_hash(), hash);
+        config.ring_thresholds = vec![0, 10, 0];
+        assert_ne!(config.ring_thresholds_hash(), hash);
+        config.ring_thresholds = vec![0, 10];
+        assert_eq!(config.ring_thresholds_hash(), hash);
+    }
+
+    #[test]
+    fn test_collection_changes_start_a_generation() {
+        let mut config = config(0, 0, 0);
+        assert!(!config.collection_locked());
+        let collection = Pubkey::new_unique();
+        config.set_collection(collection).unwrap();
+        config.set_collection(collection).unwrap();
+        assert_eq!(config.collection_generation, 1);
+        config.set_collection(Pubkey::new_unique()).unwrap();
+        assert_eq!(config.collection_generation, 2);
+
+        config.next_parcel_id = 2;
+        assert!(config.collection_locked());
+    }
+
+    #[test]
+    fn test_grid_zero_keeps_single_grid_addresses() {
+        let program_id = Pubkey::new_unique();
+        let (legacy, _) = Pubkey::find_program_address(&[GridConfig::SEED], &program_id);
+        let (grid_0, _) = Pubkey::find_program_address(&[GridConfig::SEED, &grid_seed(0)], &program_id);
+        let (grid_1, _) = Pubkey::find_program_address(&[GridConfig::SEED, &grid_seed(1)], &program_id);
+        assert_eq!(legacy, grid_0);
+        assert_ne!(legacy, grid_1);
+    }
+
+    #[test]
+    fn test_grid_and_epoch_components_never_collide() {
+        // Grid components are 0 or 8 bytes and epoch components 0 or 2, so each pairing of a
+        // grid and an epoch seeds a different number of bytes
+        let program_id = Pubkey::new_unique();
+        let parcel_id = 7u16.to_le_bytes();
+        let address = |grid_id: u64, epoch: u16| {
+            Pubkey::find_program_address(
+                &[crate::state::ParcelInfo::SEED, &grid_seed(grid_id), &crate::state::epoch_seed(epoch), &parcel_id],
+                &program_id,
+            )
+            .0
+        };
+        let addresses = [address(0, 0), address(0, 1), address(1, 0), address(1, 1), address(2, 0)];
+        for (i, a) in addresses.iter().enumerate() {
+            for b in &addresses[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+    }
 }
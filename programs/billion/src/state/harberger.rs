@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use billion_core::{Rect, MAX_GRID_SIZE};
+use crate::constants::{HARBERGER_DISTRICT_SEED, VALUATION_SEED};
+
+/// The single self-assessed-pricing district. Parcels entirely inside the rectangle can
+/// declare a valuation, pay tax on it, and be force-bought at that valuation.
+#[account]
+#[derive(InitSpace)]
+pub struct HarbergerDistrict {
+    pub x: u8,
+    pub y: u8,
+    pub width: u8,
+    pub height: u8,
+    /// Must be set for any Harberger instruction to run
+    pub enabled: bool,
+    /// Tax per period, in basis points of the declared valuation
+    pub tax_bps: u16,
+    /// Length of one tax period in seconds
+    pub tax_period_secs: i64,
+    /// PDA bump seed
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; 32],
+}
+
+impl HarbergerDistrict {
+    pub const SEED: &'static [u8] = HARBERGER_DISTRICT_SEED;
+
+    pub fn rect(&self) -> Rect {
+        Rect::new(self.x, self.y, self.width, self.height)
+    }
+
+    /// Whether the parcel rectangle lies entirely inside the district. The bounds check only
+    /// keeps the corner arithmetic in range; parcels are checked against their own grid when
+    /// claimed.
+    pub fn contains(&self, parcel: Rect) -> bool {
+        let district = self.rect();
+        parcel.in_bounds(MAX_GRID_SIZE)
+            && district.contains(parcel.x, parcel.y)
+            && district.contains(parcel.x + parcel.width - 1, parcel.y + parcel.height - 1)
+    }
+
+    /// Tax owed for one period at `valuation`
+    pub fn tax_per_period(&self, valuation: u64) -> Option<u64> {
+        let tax = (valuation as u128).checked_mul(self.tax_bps as u128)? / 10_000;
+        u64::try_from(tax).ok()
+    }
+}
+
+/// Self-assessed valuation of a district parcel
+#[account]
+#[derive(InitSpace)]
+pub struct ParcelValuation {
+    pub parcel_id: u16,
+    /// Owner who declared `valuation`
+    pub owner: Pubkey,
+    /// Price anyone can force-buy the parcel for, in token base units
+    pub valuation: u64,
+    /// Unix timestamp taxes are paid up to; force_buy requires this to be in the future
+    pub tax_paid_until: i64,
+    /// Whether the TransferDelegate plugin has been added to the asset
+    pub plugin_added: bool,
+    /// Owner under whom the GridConfig PDA holds the transfer delegate (default if none);
+    /// Core resets the delegate to the owner on every transfer
+    pub delegate_approved_for: Pubkey,
+    /// PDA bump seed
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; 32],
+}
+
+impl ParcelValuation {
+    pub const SEED: &'static [u8] = VALUATION_SEED;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn district() -> HarbergerDistrict {
+        HarbergerDistrict {
+            x: 10,
+            y: 10,
+            width: 5,
+            height: 5,
+            enabled: true,
+            tax_bps: 100,
+            tax_period_secs: 86_400,
+            bump: 255,
+            _reserved: [0u8; 32],
+        }
+    }
+
+    #[test]
+    fn test_district_contains() {
+        let district = district();
+        assert!(district.contains(Rect::new(10, 10, 5, 5)));
+        assert!(district.contains(Rect::new(12, 13, 1, 2)));
+        assert!(!district.contains(Rect::new(9, 10, 2, 1)));
+        assert!(!district.contains(Rect::new(14, 14, 2, 1)));
+        assert!(!district.contains(Rect::new(10, 10, 0, 1)));
+    }
+
+    #[test]
+    fn test_tax_per_period() {
+        assert_eq!(district().tax_per_period(1_000_000), Some(10_000));
+        assert_eq!(district().tax_per_period(u64::MAX), Some(u64::MAX / 100));
+    }
+}
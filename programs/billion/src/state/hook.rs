@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use billion_core::Rect;
+use crate::constants::HOOK_CONFIG_SEED;
+
+/// The single on-claim hook. When enabled, every claim overlapping the district CPIs
+/// `program_id` with `OnParcelClaimed` and aborts if the hook fails.
+#[account]
+#[derive(InitSpace)]
+pub struct HookConfig {
+    /// Program invoked after a matching claim
+    pub program_id: Pubkey,
+    /// Must be set for the hook to run
+    pub enabled: bool,
+    /// May disable the hook in addition to the authority
+    pub guardian: Pubkey,
+    pub x: u8,
+    pub y: u8,
+    pub width: u8,
+    pub height: u8,
+    /// PDA bump seed
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; 32],
+}
+
+impl HookConfig {
+    pub const SEED: &'static [u8] = HOOK_CONFIG_SEED;
+
+    pub fn rect(&self) -> Rect {
+        Rect::new(self.x, self.y, self.width, self.height)
+    }
+
+    /// Whether a claim of `parcel` must invoke the hook
+    pub fn applies_to(&self, parcel: Rect) -> bool {
+        self.enabled && self.rect().intersects(&parcel)
+    }
+}
+
+/// Anchor discriminator of `on_parcel_claimed`, so hook programs can be written as a
+/// regular Anchor instruction
+pub const ON_PARCEL_CLAIMED_DISCRIMINATOR: [u8; 8] = [105, 22, 232, 177, 241, 111, 221, 153];
+
+/// Arguments passed to the hook program, after the discriminator.
+/// Accounts are the GridConfig PDA (signer, proving the call comes from this program),
+/// the claimer, the new asset, then the pass-through remaining accounts.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct OnParcelClaimed {
+    pub parcel_id: u16,
+    pub claimer: Pubkey,
+    pub x: u8,
+    pub y: u8,
+    pub width: u8,
+    pub height: u8,
+}
+
+impl OnParcelClaimed {
+    pub fn instruction_data(&self) -> Result<Vec<u8>> {
+        let mut data = ON_PARCEL_CLAIMED_DISCRIMINATOR.to_vec();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::hash::hash;
+
+    fn hook() -> HookConfig {
+        HookConfig {
+            program_id: Pubkey::new_unique(),
+            enabled: true,
+            guardian: Pubkey::new_unique(),
+            x: 10,
+            y: 10,
+            width: 5,
+            height: 5,
+            bump: 255,
+            _reserved: [0; 32],
+        }
+    }
+
+    #[test]
+    fn test_discriminator_matches_anchor() {
+        assert_eq!(
+            ON_PARCEL_CLAIMED_DISCRIMINATOR,
+            hash(b"global:on_parcel_claimed").to_bytes()[..8]
+        );
+    }
+
+    #[test]
+    fn test_applies_to_overlapping_claims_only() {
+        let mut hook = hook();
+        assert!(hook.applies_to(Rect::new(12, 12, 1, 1)));
+        assert!(hook.applies_to(Rect::new(8, 8, 3, 3)));
+        assert!(!hook.applies_to(Rect::new(15, 15, 1, 1)));
+        hook.enabled = false;
+        assert!(!hook.applies_to(Rect::new(12, 12, 1, 1)));
+    }
+
+    #[test]
+    fn test_instruction_data_layout() {
+        let claimer = Pubkey::new_unique();
+        let data = OnParcelClaimed { parcel_id: 7, claimer, x: 1, y: 2, width: 3, height: 4 }
+            .instruction_data()
+            .unwrap();
+        assert_eq!(data.len(), 8 + 2 + 32 + 4);
+        assert_eq!(&data[..8], &ON_PARCEL_CLAIMED_DISCRIMINATOR);
+        assert_eq!(&data[8..10], &7u16.to_le_bytes());
+        assert_eq!(&data[10..42], claimer.as_ref());
+        assert_eq!(&data[42..], &[1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use billion_core::Rect;
+use crate::constants::MAP_DIGEST_SEED;
+use crate::utils::next_map_hash;
+
+/// Rolling digest of the writes to the current epoch's BlockMap, which GridConfig has no room
+/// for. An off-chain mirror replays MapUpdated events through `next_map_hash` and compares
+/// its hash with this one; a mismatch means it missed an event and should re-fetch the map.
+#[account]
+#[derive(InitSpace)]
+pub struct MapDigest {
+    /// Writes to the epoch's BlockMap since the epoch started or the digest was created
+    pub map_version: u64,
+    /// Hash chained over those writes, all zeros before the first
+    pub map_hash: [u8; 32],
+    /// Epoch the digest belongs to; a new epoch's empty BlockMap starts it over
+    pub epoch: u16,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl MapDigest {
+    pub const SEED: &'static [u8] = MAP_DIGEST_SEED;
+
+    pub fn new(epoch: u16, bump: u8) -> Self {
+        Self { map_version: 0, map_hash: [0; 32], epoch, bump }
+    }
+
+    /// Chains a write of `parcel_id` over `rect` of `epoch`'s BlockMap into the digest
+    pub fn apply(&mut self, epoch: u16, parcel_id: u16, rect: Rect) -> Option<()> {
+        if self.epoch != epoch {
+            *self = Self::new(epoch, self.bump);
+        }
+        self.map_version = self.map_version.checked_add(1)?;
+        self.map_hash = next_map_hash(&self.map_hash, parcel_id, rect);
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_writes_chain_and_a_new_epoch_starts_over() {
+        let mut digest = MapDigest::new(0, 255);
+        digest.apply(0, 1, Rect::new(0, 0, 2, 2)).unwrap();
+        digest.apply(0, 0, Rect::new(0, 0, 2, 2)).unwrap();
+        let replayed = next_map_hash(&next_map_hash(&[0; 32], 1, Rect::new(0, 0, 2, 2)), 0, Rect::new(0, 0, 2, 2));
+        assert_eq!((digest.map_version, digest.map_hash), (2, replayed));
+
+        digest.apply(1, 1, Rect::new(0, 0, 2, 2)).unwrap();
+        assert_eq!((digest.epoch, digest.map_version), (1, 1));
+        assert_eq!(digest.map_hash, next_map_hash(&[0; 32], 1, Rect::new(0, 0, 2, 2)));
+    }
+}
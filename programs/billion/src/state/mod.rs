@@ -1,7 +1,81 @@
 pub mod grid_config;
 pub mod block_map;
 pub mod parcel_info;
+pub mod referrer_account;
+pub mod stake_account;
+pub mod harberger;
+pub mod quest;
+pub mod distribution;
+pub mod epoch_archive;
+pub mod hook;
+pub mod fraction;
+pub mod attestation;
+pub mod charity;
+pub mod claimer_stats;
+pub mod name_record;
+pub mod voucher;
+pub mod raffle;
+pub mod claim_nonce;
+pub mod ring_pricing;
+pub mod allowlist;
+pub mod parcel_id_pool;
+pub mod parcel_mask;
+pub mod admin_mint_allowance;
+pub mod reward_exclusions;
+pub mod reward_delegate;
+pub mod sol_rewards;
+pub mod reward_dust;
+pub mod ring_reward_weights;
+pub mod admin_roles;
+pub mod config_timelock;
+pub mod token_treasury;
+pub mod config_freeze;
+pub mod claims_pause;
+pub mod ring_occupancy;
+pub mod map_digest;
+pub mod ring_unlock_schedule;
+pub mod rename_fee;
+pub mod parcel_content;
+pub mod parcel_pixels;
+pub mod referral_share;
 
 pub use grid_config::*;
 pub use block_map::*;
 pub use parcel_info::*;
+pub use referrer_account::*;
+pub use stake_account::*;
+pub use harberger::*;
+pub use quest::*;
+pub use distribution::*;
+pub use epoch_archive::*;
+pub use hook::*;
+pub use fraction::*;
+pub use attestation::*;
+pub use charity::*;
+pub use claimer_stats::*;
+pub use name_record::*;
+pub use voucher::*;
+pub use raffle::*;
+pub use claim_nonce::*;
+pub use ring_pricing::*;
+pub use allowlist::*;
+pub use parcel_id_pool::*;
+pub use parcel_mask::*;
+pub use admin_mint_allowance::*;
+pub use reward_exclusions::*;
+pub use reward_delegate::*;
+pub use sol_rewards::*;
+pub use reward_dust::*;
+pub use ring_reward_weights::*;
+pub use admin_roles::*;
+pub use config_timelock::*;
+pub use token_treasury::*;
+pub use config_freeze::*;
+pub use claims_pause::*;
+pub use ring_occupancy::*;
+pub use map_digest::*;
+pub use ring_unlock_schedule::*;
+pub use rename_fee::*;
+pub use parcel_content::*;
+pub use parcel_pixels::*;
+pub use referral_share::*;
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use crate::constants::{MAX_PARCEL_NAME_LEN, MIN_PARCEL_NAME_LEN, NAME_RECORD_SEED};
+
+/// A globally unique parcel name. Lives at `[SEED, name_hash(name)]`, so two spellings
+/// that normalize to the same name collide.
+#[account]
+#[derive(InitSpace)]
+pub struct NameRecord {
+    /// Normalized name, also written to the asset's Core metadata
+    #[max_len(MAX_PARCEL_NAME_LEN)]
+    pub name: String,
+    pub parcel_id: u16,
+    pub epoch: u16,
+    pub asset: Pubkey,
+    /// Asset owner at registration; receives the rent back on release
+    pub registrant: Pubkey,
+    pub registered_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl NameRecord {
+    pub const SEED: &'static [u8] = NAME_RECORD_SEED;
+
+    /// PDA seed for `name`, hashed after normalization
+    pub fn name_hash(name: &str) -> [u8; 32] {
+        hash(normalize_name(name).as_bytes()).to_bytes()
+    }
+}
+
+/// Trims surrounding whitespace and lowercases ASCII letters
+pub fn normalize_name(name: &str) -> String {
+    name.trim().to_ascii_lowercase()
+}
+
+/// Whether a normalized name is 3-32 characters of a-z, 0-9, '-' or '_'
+pub fn is_valid_name(name: &str) -> bool {
+    (MIN_PARCEL_NAME_LEN as usize..=MAX_PARCEL_NAME_LEN as usize).contains(&name.len())
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-' || b == b'_')
+}
+
+/// `name` as an owner-set display name: whitespace trimmed and inner runs collapsed to one
+/// space. None unless that leaves 1-32 printable ASCII characters.
+pub fn sanitize_display_name(name: &str) -> Option<String> {
+    let name = name.split_ascii_whitespace().collect::<Vec<_>>().join(" ");
+    let valid = (1..=MAX_PARCEL_NAME_LEN as usize).contains(&name.len())
+        && name.bytes().all(|b| b.is_ascii_graphic() || b == b' ');
+    valid.then_some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spellings_of_a_name_share_a_hash() {
+        assert_eq!(NameRecord::name_hash("OnlyOne"), NameRecord::name_hash("  onlyone "));
+        assert_ne!(NameRecord::name_hash("onlyone"), NameRecord::name_hash("only-one"));
+    }
+
+    #[test]
+    fn test_name_charset_and_length() {
+        assert!(is_valid_name("abc"));
+        assert!(is_valid_name("plot_42-north"));
+        assert!(is_valid_name(&"a".repeat(32)));
+        assert!(!is_valid_name("ab"));
+        assert!(!is_valid_name(&"a".repeat(33)));
+        assert!(!is_valid_name("two words"));
+        assert!(!is_valid_name("Upper"));
+        assert!(!is_valid_name("émoji"));
+    }
+
+    #[test]
+    fn test_display_names_are_trimmed_printable_ascii() {
+        assert_eq!(sanitize_display_name("  Bob's   Pixel\tPalace "), Some("Bob's Pixel Palace".to_string()));
+        assert_eq!(sanitize_display_name(&"a".repeat(32)), Some("a".repeat(32)));
+        assert_eq!(sanitize_display_name(&"a".repeat(33)), None);
+        assert_eq!(sanitize_display_name(" \n "), None);
+        assert_eq!(sanitize_display_name("bell\u{7}"), None);
+        assert_eq!(sanitize_display_name("café"), None);
+    }
+}
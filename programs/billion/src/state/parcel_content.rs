@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use crate::constants::PARCEL_CONTENT_SEED;
+
+/// Hash of the art a parcel displays, at `[SEED, grid_seed(grid_id), epoch_seed(epoch),
+/// parcel_id]`, so renderers can check what the metadata server hands out. ParcelInfo has no
+/// room left for it.
+#[account]
+#[derive(InitSpace)]
+pub struct ParcelContent {
+    /// Hash of the displayed image; all zeros once cleared
+    pub content_hash: [u8; 32],
+    /// Unix timestamp of the last set_parcel_content
+    pub updated_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ParcelContent {
+    pub const SEED: &'static [u8] = PARCEL_CONTENT_SEED;
+
+    /// Reads the PDA, falling back to no hash while the owner hasn't set one
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        if account.data_is_empty() {
+            return Ok(Self { content_hash: [0; 32], updated_at: 0, bump: 0 });
+        }
+        Self::try_deserialize(&mut &account.try_borrow_data()?[..])
+    }
+}
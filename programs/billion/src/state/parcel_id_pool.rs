@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_FREE_PARCEL_IDS, PARCEL_ID_POOL_SEED};
+
+/// Parcel ids freed by admin_close_parcel_info, which claim_parcel hands out again before
+/// advancing GridConfig.next_parcel_id. Created by the first admin_close_parcel_info call
+/// that frees an id.
+#[account]
+#[derive(InitSpace)]
+pub struct ParcelIdPool {
+    /// Epoch the ids were freed in; ids are only reused within it
+    pub epoch: u16,
+    #[max_len(MAX_FREE_PARCEL_IDS)]
+    pub free_ids: Vec<u16>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ParcelIdPool {
+    pub const SEED: &'static [u8] = PARCEL_ID_POOL_SEED;
+
+    /// Reads the PDA, falling back to an empty pool while it hasn't been created
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        if account.data_is_empty() {
+            return Ok(Self { epoch: 0, free_ids: Vec::new(), bump: 0 });
+        }
+        Self::try_deserialize(&mut &account.try_borrow_data()?[..])
+    }
+
+    /// The freed id the next claim in `epoch` reuses, if any
+    pub fn peek(&self, epoch: u16) -> Option<u16> {
+        self.free_ids.last().copied().filter(|_| self.epoch == epoch)
+    }
+
+    /// Removes and returns the id `peek` reports
+    pub fn take(&mut self, epoch: u16) -> Option<u16> {
+        let id = self.peek(epoch)?;
+        self.free_ids.pop();
+        Some(id)
+    }
+
+    /// Adds an id freed in `epoch`, dropping ids left over from earlier epochs. Returns false
+    /// when the pool is full and the id isn't kept.
+    pub fn release(&mut self, epoch: u16, id: u16) -> bool {
+        if self.epoch != epoch {
+            self.epoch = epoch;
+            self.free_ids.clear();
+        }
+        if self.free_ids.len() >= MAX_FREE_PARCEL_IDS as usize {
+            return false;
+        }
+        self.free_ids.push(id);
+        true
+    }
+
+    /// The id claim_parcel assigns next in the grid's current epoch
+    pub fn next_parcel_id(account: &AccountInfo, epoch: u16, next_parcel_id: u16) -> Result<u16> {
+        Ok(Self::load(account)?.peek(epoch).unwrap_or(next_parcel_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool() -> ParcelIdPool {
+        ParcelIdPool { epoch: 1, free_ids: Vec::new(), bump: 255 }
+    }
+
+    #[test]
+    fn test_take_returns_the_latest_released_id() {
+        let mut pool = pool();
+        assert!(pool.release(1, 7));
+        assert!(pool.release(1, 9));
+        assert_eq!(pool.take(1), Some(9));
+        assert_eq!(pool.take(1), Some(7));
+        assert_eq!(pool.take(1), None);
+    }
+
+    #[test]
+    fn test_ids_from_another_epoch_are_not_reused() {
+        let mut pool = pool();
+        pool.release(1, 7);
+        assert_eq!(pool.peek(2), None);
+        assert_eq!(pool.take(2), None);
+
+        pool.release(2, 3);
+        assert_eq!(pool.free_ids, vec![3]);
+        assert_eq!(pool.take(2), Some(3));
+    }
+
+    #[test]
+    fn test_release_stops_at_capacity() {
+        let mut pool = pool();
+        for id in 0..MAX_FREE_PARCEL_IDS as u16 {
+            assert!(pool.release(1, id + 1));
+        }
+        assert!(!pool.release(1, u16::MAX - 1));
+        assert_eq!(pool.free_ids.len(), MAX_FREE_PARCEL_IDS as usize);
+    }
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::constants::{PARCEL_INFO_SEED, REWARD_WEIGHT_UNIT};
 
 #[account]
 #[derive(InitSpace)]
@@ -17,15 +18,209 @@ pub struct ParcelInfo {
     pub bump: u8,
     /// Snapshot of land_buy_rewards_per_block at last claim
     pub last_claimed_land_buy_rewards_per_block: u128,
+    /// Epoch the parcel was claimed in
+    pub epoch: u16,
+    /// Unix timestamp of the last land-buy reward claim (or of the parcel claim); restarts
+    /// the reward expiry clock
+    pub last_claimed_at: i64,
+    /// Distinct parcels sharing an edge, as of the last refresh_adjacency
+    pub adjacency_score: u8,
+    /// Vesting date of an admin-minted parcel; its asset stays frozen until then (0 = not locked)
+    pub locked_until: i64,
+    /// Whether land-buy rewards are also unclaimable until locked_until
+    pub lock_rewards: bool,
+    /// Whether a NameRecord currently points at this parcel
+    pub named: bool,
+    /// Premium badge is shown until this Unix timestamp (0 = never purchased)
+    pub premium_until: i64,
+    /// GridConfig.collection_generation when the asset was minted
+    pub collection_generation: u16,
+    /// Cells set in the ParcelMask of a masked parcel (0 = the parcel fills its rectangle)
+    pub masked_block_count: u16,
+    /// Whether the GridConfig PDA holds the asset until admin_release_reserved_parcel names its owner
+    pub reserved: bool,
+    /// Whether the parcel is left out of total_claimed_blocks and earns no land-buy rewards
+    pub rewards_excluded: bool,
+    /// Tokens the claimer paid for the parcel; 0 for admin mints and for parcels paid in SOL,
+    /// by voucher or by raffle
+    pub paid_total: u64,
+    /// Unix timestamp of the claim or admin mint. Seconds fit a u32 until 2106.
+    pub claimed_at: u32,
+    /// Average land-buy reward weight of the parcel's blocks when minted, in tenths of an
+    /// unweighted block (0 = unweighted, as for parcels minted before ring weights)
+    pub reward_weight: u8,
     /// Reserved for future fields
-    pub _reserved: [u8; 48], // Reduced by 8 to accommodate u128
+    pub _reserved: [u8; 0], // Reduced by 8 to accommodate u128, 2 for epoch, 8 for reward expiry, 1 for adjacency, 9 for vesting, 1 for names, 8 for premium, 2 for the collection generation, 2 for masks, 1 for reservations, 1 for reward exclusion, 12 for provenance, 1 for the reward weight
+}
+
+/// Epoch component of every per-parcel PDA (`[SEED, grid_seed(grid_id), epoch_seed(epoch),
+/// parcel_id]`). Empty for epoch 0, so parcels from before seasons keep their original addresses.
+pub fn epoch_seed(epoch: u16) -> Vec<u8> {
+    if epoch == 0 {
+        Vec::new()
+    } else {
+        epoch.to_le_bytes().to_vec()
+    }
 }
 
 impl ParcelInfo {
-    pub const SEED: &'static [u8] = b"parcel";
+    pub const SEED: &'static [u8] = PARCEL_INFO_SEED;
 
-    /// Calculate the number of blocks in this parcel
+    /// Calculate the number of blocks in this parcel: the masked cells, or the whole rectangle
     pub fn block_count(&self) -> u32 {
+        if self.masked_block_count > 0 {
+            return self.masked_block_count as u32;
+        }
         (self.width as u32) * (self.height as u32)
     }
+
+    /// `now` as stored in claimed_at
+    pub fn claim_timestamp(now: i64) -> u32 {
+        now.clamp(0, u32::MAX as i64) as u32
+    }
+
+    /// Blocks the parcel contributes to GridConfig.total_claimed_blocks, and so accrues
+    /// land-buy rewards and distributions on: none while it's excluded from rewards
+    pub fn rewarded_block_count(&self) -> u32 {
+        if self.rewards_excluded {
+            return 0;
+        }
+        self.block_count()
+    }
+
+    /// Reward weight of each block, in tenths of an unweighted block
+    pub fn weight_per_block(&self) -> u64 {
+        if self.reward_weight == 0 {
+            return REWARD_WEIGHT_UNIT as u64;
+        }
+        self.reward_weight as u64
+    }
+
+    /// The parcel's share of the land-buy reward accumulator's weighted blocks, in tenths
+    pub fn rewarded_weight(&self) -> u64 {
+        self.rewarded_block_count() as u64 * self.weight_per_block()
+    }
+
+    /// Weight of `blocks` of this parcel's blocks above (or below) the same blocks unweighted
+    pub fn extra_weight(&self, blocks: u32) -> i64 {
+        blocks as i64 * (self.weight_per_block() as i64 - REWARD_WEIGHT_UNIT as i64)
+    }
+
+    /// Whether land-buy rewards are held back by a vesting lock at `now`
+    pub fn rewards_locked(&self, now: i64) -> bool {
+        self.lock_rewards && now < self.locked_until
+    }
+
+    /// When the reward expiry clock starts: the last claim, or the end of a reward lock
+    pub fn expiry_clock_start(&self) -> i64 {
+        if self.lock_rewards {
+            self.last_claimed_at.max(self.locked_until)
+        } else {
+            self.last_claimed_at
+        }
+    }
+
+    /// Whether the premium badge is active at `now`
+    pub fn is_premium(&self, now: i64) -> bool {
+        now < self.premium_until
+    }
+
+    /// Premium expiry after buying `periods` more periods of `period_secs` at `now`. An
+    /// active badge is extended from its current expiry, a lapsed one from now.
+    pub fn extended_premium_until(&self, now: i64, periods: u32, period_secs: i64) -> Option<i64> {
+        let extension = period_secs.checked_mul(periods as i64)?;
+        self.premium_until.max(now).checked_add(extension)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_epoch_zero_keeps_legacy_address() {
+        let program_id = Pubkey::new_unique();
+        let parcel_id = 7u16.to_le_bytes();
+        let (legacy, _) = Pubkey::find_program_address(&[ParcelInfo::SEED, &parcel_id], &program_id);
+        let (epoch_0, _) =
+            Pubkey::find_program_address(&[ParcelInfo::SEED, &epoch_seed(0), &parcel_id], &program_id);
+        let (epoch_1, _) =
+            Pubkey::find_program_address(&[ParcelInfo::SEED, &epoch_seed(1), &parcel_id], &program_id);
+        assert_eq!(legacy, epoch_0);
+        assert_ne!(legacy, epoch_1);
+    }
+
+    #[test]
+    fn test_reward_lock_holds_claims_and_expiry_until_vesting() {
+        let mut parcel_info = ParcelInfo {
+            asset: Pubkey::new_unique(),
+            x: 0,
+            y: 0,
+            width: 1,
+            height: 1,
+            bump: 255,
+            last_claimed_land_buy_rewards_per_block: 0,
+            epoch: 0,
+            last_claimed_at: 100,
+            adjacency_score: 0,
+            locked_until: 1_000,
+            lock_rewards: false,
+            named: false,
+            premium_until: 0,
+            collection_generation: 0,
+            masked_block_count: 0,
+            reserved: false,
+            rewards_excluded: false,
+            paid_total: 0,
+            claimed_at: 0,
+            reward_weight: 0,
+            _reserved: [0u8; 0],
+        };
+        assert!(!parcel_info.rewards_locked(500));
+        assert_eq!(parcel_info.expiry_clock_start(), 100);
+
+        parcel_info.lock_rewards = true;
+        assert!(parcel_info.rewards_locked(999));
+        assert!(!parcel_info.rewards_locked(1_000));
+        assert_eq!(parcel_info.expiry_clock_start(), 1_000);
+    }
+
+    #[test]
+    fn test_premium_extends_from_current_expiry_while_active() {
+        let mut parcel_info = ParcelInfo {
+            asset: Pubkey::new_unique(),
+            x: 0,
+            y: 0,
+            width: 1,
+            height: 1,
+            bump: 255,
+            last_claimed_land_buy_rewards_per_block: 0,
+            epoch: 0,
+            last_claimed_at: 0,
+            adjacency_score: 0,
+            locked_until: 0,
+            lock_rewards: false,
+            named: false,
+            premium_until: 0,
+            collection_generation: 0,
+            masked_block_count: 0,
+            reserved: false,
+            rewards_excluded: false,
+            paid_total: 0,
+            claimed_at: 0,
+            reward_weight: 0,
+            _reserved: [0u8; 0],
+        };
+        assert!(!parcel_info.is_premium(0));
+        assert_eq!(parcel_info.extended_premium_until(1_000, 2, 100), Some(1_200));
+
+        parcel_info.premium_until = 1_200;
+        assert!(parcel_info.is_premium(1_199));
+        assert_eq!(parcel_info.extended_premium_until(1_100, 1, 100), Some(1_300));
+
+        // Lapsed: starts over from now
+        assert!(!parcel_info.is_premium(1_200));
+        assert_eq!(parcel_info.extended_premium_until(5_000, 1, 100), Some(5_100));
+        assert_eq!(parcel_info.extended_premium_until(0, u32::MAX, i64::MAX), None);
+    }
 }
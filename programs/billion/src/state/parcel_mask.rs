@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_MASK_BYTES, PARCEL_MASK_SEED};
+
+/// Cells of a parcel claimed with claim_parcel_masked, at
+/// `[SEED, grid_seed(grid_id), epoch_seed(epoch), parcel_id]`. Rectangular parcels have none.
+#[account]
+#[derive(InitSpace)]
+pub struct ParcelMask {
+    /// Row-major bitmask over the ParcelInfo bounding box (see `billion_core::mask_contains`)
+    #[max_len(MAX_MASK_BYTES)]
+    pub mask: Vec<u8>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ParcelMask {
+    pub const SEED: &'static [u8] = PARCEL_MASK_SEED;
+}
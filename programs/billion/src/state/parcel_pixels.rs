@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+use crate::constants::PARCEL_PIXELS_SEED;
+
+/// Raw colors of a small parcel, so the map renders without trusting the metadata server.
+/// Lives at `[SEED, grid_seed(grid_id), epoch_seed(epoch), parcel_id]`, sized to the parcel.
+#[account]
+pub struct ParcelPixels {
+    /// Three RGB bytes per block, row-major over the ParcelInfo bounding box; masked parcels
+    /// list only their cells, in the same order
+    pub rgb: Vec<u8>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ParcelPixels {
+    pub const SEED: &'static [u8] = PARCEL_PIXELS_SEED;
+
+    /// Account size, discriminator included, holding `rgb_len` bytes
+    pub const fn space(rgb_len: usize) -> usize {
+        8 + 4 + rgb_len + 1
+    }
+}
@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak::hashv;
+use crate::constants::{QUEST_CLAIMS_SEED, QUEST_SEED};
+
+/// One quest's payout tree, one PDA per Merkle root so quests can run concurrently
+#[account]
+#[derive(InitSpace)]
+pub struct Quest {
+    /// Merkle root over `quest_leaf(index, wallet, amount)` leaves
+    pub root: [u8; 32],
+    /// Most the quest can pay out in total, in token base units
+    pub total_allocation: u64,
+    /// Paid out so far
+    pub claimed_amount: u64,
+    /// Number of leaves claimed
+    pub claim_count: u32,
+    /// PDA bump seed
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; 32],
+}
+
+impl Quest {
+    pub const SEED: &'static [u8] = QUEST_SEED;
+}
+
+/// Claimed-leaf bitmap for one chunk of a quest's indices, created by the first claimer in it
+#[account]
+#[derive(InitSpace)]
+pub struct QuestClaims {
+    pub quest: Pubkey,
+    /// Covers indices `chunk * CLAIMS_PER_CHUNK .. (chunk + 1) * CLAIMS_PER_CHUNK`
+    pub chunk: u32,
+    pub bits: [u8; 256],
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl QuestClaims {
+    pub const SEED: &'static [u8] = QUEST_CLAIMS_SEED;
+
+    pub const CLAIMS_PER_CHUNK: u32 = 256 * 8;
+
+    pub fn chunk_of(index: u32) -> u32 {
+        index / Self::CLAIMS_PER_CHUNK
+    }
+
+    pub fn is_claimed(&self, index: u32) -> bool {
+        let bit = index % Self::CLAIMS_PER_CHUNK;
+        self.bits[(bit / 8) as usize] & (1 << (bit % 8)) != 0
+    }
+
+    pub fn set_claimed(&mut self, index: u32) {
+        let bit = index % Self::CLAIMS_PER_CHUNK;
+        self.bits[(bit / 8) as usize] |= 1 << (bit % 8);
+    }
+}
+
+/// Leaf hash for a quest payout: keccak256(index_le || wallet || amount_le)
+pub fn quest_leaf(index: u32, wallet: &Pubkey, amount: u64) -> [u8; 32] {
+    hashv(&[&index.to_le_bytes(), wallet.as_ref(), &amount.to_le_bytes()]).to_bytes()
+}
+
+/// Hash of two sibling nodes, sorted so proofs don't need left/right flags
+pub fn quest_node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[lo, hi]).to_bytes()
+}
+
+/// Whether `proof` walks `leaf` up to `root`
+pub fn verify_quest_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    proof.iter().fold(leaf, |node, sibling| quest_node(&node, sibling)) == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_quest_proof() {
+        let wallet = Pubkey::new_unique();
+        let leaves = [
+            quest_leaf(0, &wallet, 100),
+            quest_leaf(1, &Pubkey::new_unique(), 200),
+            quest_leaf(2, &Pubkey::new_unique(), 300),
+        ];
+        let left = quest_node(&leaves[0], &leaves[1]);
+        let root = quest_node(&left, &leaves[2]);
+
+        assert!(verify_quest_proof(&[leaves[1], leaves[2]], &root, leaves[0]));
+        assert!(verify_quest_proof(&[left], &root, leaves[2]));
+        // Amount and index are bound into the leaf
+        assert!(!verify_quest_proof(&[leaves[1], leaves[2]], &root, quest_leaf(0, &wallet, 101)));
+        assert!(!verify_quest_proof(&[leaves[1], leaves[2]], &root, quest_leaf(3, &wallet, 100)));
+    }
+
+    #[test]
+    fn test_claim_bits() {
+        let mut claims = QuestClaims {
+            quest: Pubkey::default(),
+            chunk: 1,
+            bits: [0u8; 256],
+            bump: 255,
+        };
+        let index = QuestClaims::CLAIMS_PER_CHUNK + 9;
+        assert_eq!(QuestClaims::chunk_of(index), 1);
+        assert!(!claims.is_claimed(index));
+        claims.set_claimed(index);
+        assert!(claims.is_claimed(index));
+        assert!(!claims.is_claimed(index + 1));
+    }
+}
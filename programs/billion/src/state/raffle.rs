@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use billion_core::total_blocks;
+use crate::constants::RAFFLE_SEED;
+
+/// One per grid, tracking the free-block raffle. At most one prize is open at a time; it can be
+/// claimed by anyone until `expires_at`.
+#[account]
+#[derive(InitSpace)]
+pub struct Raffle {
+    /// Number of draws so far; mixed into the randomness so consecutive draws differ
+    pub round: u64,
+    pub prize_x: u8,
+    pub prize_y: u8,
+    /// Whether the current prize is still unclaimed
+    pub active: bool,
+    /// Unix timestamp of the latest draw (0 = never drawn)
+    pub drawn_at: i64,
+    /// Prize stops being claimable at this Unix timestamp
+    pub expires_at: i64,
+    /// Claimer of the latest prize, or the default pubkey while unclaimed
+    pub winner: Pubkey,
+    /// PDA bump seed
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; 32],
+}
+
+impl Raffle {
+    pub const SEED: &'static [u8] = RAFFLE_SEED;
+
+    /// Whether the current prize can be claimed at `now`
+    pub fn prize_open(&self, now: i64) -> bool {
+        self.active && now < self.expires_at
+    }
+
+    /// The `attempt`-th candidate block of a `grid_size` grid for randomness `seed`
+    pub fn candidate(seed: &[u8; 32], attempt: u8, grid_size: u16) -> (u8, u8) {
+        let hash = hashv(&[seed, &[attempt]]).to_bytes();
+        let index = u32::from_le_bytes([hash[0], hash[1], hash[2], hash[3]]) as usize % total_blocks(grid_size);
+        ((index % grid_size as usize) as u8, (index / grid_size as usize) as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::DEFAULT_GRID_SIZE;
+
+    #[test]
+    fn test_candidates_are_in_bounds_and_vary() {
+        let seed = [7u8; 32];
+        for grid_size in [DEFAULT_GRID_SIZE, 50] {
+            let candidates: Vec<_> = (0..32).map(|attempt| Raffle::candidate(&seed, attempt, grid_size)).collect();
+            assert!(candidates.iter().all(|&(x, y)| (x as u16) < grid_size && (y as u16) < grid_size));
+            assert_ne!(candidates[0], candidates[1]);
+            assert_eq!(Raffle::candidate(&seed, 0, grid_size), candidates[0]);
+            assert_ne!(Raffle::candidate(&[8u8; 32], 0, grid_size), candidates[0]);
+        }
+    }
+
+    #[test]
+    fn test_prize_open_until_expiry_or_claim() {
+        let mut raffle = Raffle {
+            round: 1,
+            prize_x: 0,
+            prize_y: 0,
+            active: true,
+            drawn_at: 0,
+            expires_at: 100,
+            winner: Pubkey::default(),
+            bump: 255,
+            _reserved: [0u8; 32],
+        };
+        assert!(raffle.prize_open(99));
+        assert!(!raffle.prize_open(100));
+        raffle.active = false;
+        assert!(!raffle.prize_open(0));
+    }
+}
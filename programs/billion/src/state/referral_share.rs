@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::constants::REFERRAL_SHARE_SEED;
+
+/// Share of each claim_parcel cost paid straight to a referrer's token account, ahead of the
+/// landowner/referral/charity/treasury/burn split of the rest. Created by the first
+/// set_referral_share; until then claims pay no direct share. GridConfig has no room left for it.
+#[account]
+#[derive(InitSpace)]
+pub struct ReferralShare {
+    /// At most MAX_REFERRAL_SHARE_BPS, and counted with the other shares in the split check
+    pub share_bps: u16,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ReferralShare {
+    pub const SEED: &'static [u8] = REFERRAL_SHARE_SEED;
+
+    /// Reads the PDA, falling back to no direct share while it hasn't been created
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        if account.data_is_empty() {
+            return Ok(Self { share_bps: 0, bump: 0 });
+        }
+        Self::try_deserialize(&mut &account.try_borrow_data()?[..])
+    }
+
+    /// This share on top of GridConfig.referral_bps, the referral share GridConfig::valid_split_bps
+    /// checks
+    pub fn with_referral_bps(&self, referral_bps: u16) -> u16 {
+        referral_bps.saturating_add(self.share_bps)
+    }
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+use crate::constants::REFERRER_SEED;
+
+/// Registered referrer, one per wallet. Earnings accrue here and are paid out
+/// from the referral vault by claim_referral_earnings.
+#[account]
+#[derive(InitSpace)]
+pub struct ReferrerAccount {
+    /// Wallet that registered and receives earnings
+    pub referrer: Pubkey,
+    /// Referrer who introduced this wallet (default if none); blocks circular referrals
+    pub referred_by: Pubkey,
+    /// Earnings credited but not yet withdrawn
+    pub accrued: u64,
+    /// Lifetime earnings credited
+    pub total_earned: u64,
+    /// Number of referred claims
+    pub referral_count: u32,
+    /// Blocks bought through referred claims
+    pub referred_blocks: u32,
+    /// PDA bump seed
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; 32],
+}
+
+impl ReferrerAccount {
+    pub const SEED: &'static [u8] = REFERRER_SEED;
+}
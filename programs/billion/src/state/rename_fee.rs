@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+use crate::constants::RENAME_FEE_SEED;
+
+/// Flat token fee owner_update_parcel burns for each rename. Created by the first
+/// set_rename_fee; until then, and while the fee is 0, owners can't rename their parcels.
+#[account]
+#[derive(InitSpace)]
+pub struct RenameFee {
+    pub fee: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RenameFee {
+    pub const SEED: &'static [u8] = RENAME_FEE_SEED;
+
+    /// Reads the PDA, falling back to renames being disabled while it hasn't been created
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        if account.data_is_empty() {
+            return Ok(Self { fee: 0, bump: 0 });
+        }
+        Self::try_deserialize(&mut &account.try_borrow_data()?[..])
+    }
+}
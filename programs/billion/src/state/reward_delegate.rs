@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::constants::REWARD_DELEGATE_SEED;
+
+/// Wallet allowed to sign claim_land_buy_rewards for a parcel, at
+/// `[SEED, grid_seed(grid_id), epoch_seed(epoch), parcel_id]`. ParcelInfo has no room left for it.
+#[account]
+#[derive(InitSpace)]
+pub struct RewardDelegate {
+    /// Signer accepted in place of the owner
+    pub delegate: Pubkey,
+    /// Asset owner who set the delegate; once the asset changes hands the delegate lapses
+    pub owner: Pubkey,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RewardDelegate {
+    pub const SEED: &'static [u8] = REWARD_DELEGATE_SEED;
+
+    /// Whether `signer` may claim for a parcel whose asset `owner` currently holds
+    pub fn authorizes(&self, signer: &Pubkey, owner: &Pubkey) -> bool {
+        self.owner == *owner && self.delegate == *signer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delegate_lapses_when_the_owner_changes() {
+        let (delegate, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let record = RewardDelegate { delegate, owner, bump: 255 };
+        assert!(record.authorizes(&delegate, &owner));
+        assert!(!record.authorizes(&owner, &owner));
+        assert!(!record.authorizes(&delegate, &Pubkey::new_unique()));
+    }
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use crate::constants::{REWARD_DUST_SEED, REWARD_SCALE, REWARD_WEIGHT_UNIT};
+
+/// Land-buy rewards the accumulator rounds away. A claim credits landowners
+/// reward_amount * REWARD_SCALE / total_claimed_blocks per block (per weighted block once
+/// RingRewardWeights exists), rounded down, so what is left over sits in the pool owed to
+/// no one. Kept apart from GridConfig, which has no room
+/// left; created by the first claim.
+#[account]
+#[derive(InitSpace)]
+pub struct RewardDust {
+    /// Unowed rewards, scaled by REWARD_SCALE like the accumulator. Only whole tokens can be
+    /// swept: a sub-token remainder per distribution can still surface in some parcel's
+    /// rounded-down payout.
+    pub undistributed_dust: u128,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RewardDust {
+    pub const SEED: &'static [u8] = REWARD_DUST_SEED;
+
+    /// Records what crediting `reward_amount` as `reward_increase` per weighted tenth of a
+    /// block to `reward_units` tenths left over, rounded down
+    pub fn record(&mut self, reward_amount: u64, reward_increase: u128, reward_units: u128) -> Option<()> {
+        let credited = reward_increase.checked_mul(reward_units)?;
+        let dust = (reward_amount as u128)
+            .checked_mul(REWARD_SCALE * REWARD_WEIGHT_UNIT as u128)?
+            .checked_sub(credited)?
+            / REWARD_WEIGHT_UNIT as u128;
+        self.undistributed_dust = self.undistributed_dust.checked_add(dust)?;
+        Some(())
+    }
+
+    /// Whole tokens of dust that may leave the pool
+    pub fn sweepable(&self) -> u64 {
+        u64::try_from(self.undistributed_dust / REWARD_SCALE).unwrap_or(u64::MAX)
+    }
+
+    /// Takes `amount` tokens off the tracked dust, or None if less than that is sweepable
+    pub fn sweep(&mut self, amount: u64) -> Option<()> {
+        if amount > self.sweepable() {
+            return None;
+        }
+        self.undistributed_dust -= amount as u128 * REWARD_SCALE;
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sub_token_dust_is_not_sweepable() {
+        let mut dust = RewardDust { undistributed_dust: 0, bump: 255 };
+        // 1 token over 3 blocks credits 333_333_333 scaled units per block
+        dust.record(1, 333_333_333, 30).unwrap();
+        assert_eq!(dust.undistributed_dust, 1);
+        assert_eq!(dust.sweepable(), 0);
+        assert!(dust.sweep(1).is_none());
+        assert!(dust.record(1, 333_333_334, 30).is_none());
+    }
+
+    #[test]
+    fn test_pool_covers_every_claim_after_a_sweep() {
+        // (blocks, accumulator checkpoint)
+        let mut parcels: Vec<(u32, u128)> = vec![(999_983, 0), (7, 0), (3, 0)];
+        let mut dust = RewardDust { undistributed_dust: 0, bump: 255 };
+        let (mut accumulator, mut pool) = (0u128, 0u64);
+        for round in 0..5_000u64 {
+            if round % 1_000 == 999 {
+                parcels.push((1, accumulator));
+            }
+            let total_blocks: u32 = parcels.iter().map(|(blocks, _)| blocks).sum();
+            let reward_amount = 200_000 + round * 7;
+            let reward_increase = reward_amount as u128 * REWARD_SCALE / total_blocks as u128;
+            dust.record(reward_amount, reward_increase, total_blocks as u128 * 10).unwrap();
+            accumulator += reward_increase;
+            pool += reward_amount;
+        }
+
+        let swept = dust.sweepable();
+        assert!(swept > 0);
+        dust.sweep(swept).unwrap();
+        pool -= swept;
+        for (blocks, checkpoint) in parcels {
+            let owed = (blocks as u128 * (accumulator - checkpoint) / REWARD_SCALE) as u64;
+            pool = pool.checked_sub(owed).expect("pool must cover every claim");
+        }
+        assert_eq!(dust.sweepable(), 0);
+    }
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::constants::REWARD_EXCLUSIONS_SEED;
+use crate::errors::BillionError;
+
+/// Blocks of current-epoch parcels excluded from land-buy rewards. They are left out of
+/// GridConfig.total_claimed_blocks, so this holds the rest of the grid's claimed blocks.
+/// Created by the first admin_mint or admin_set_rewards_excluded call.
+#[account]
+#[derive(InitSpace)]
+pub struct RewardExclusions {
+    /// Epoch the count belongs to; it restarts from zero in a new epoch
+    pub epoch: u16,
+    pub excluded_blocks: u32,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RewardExclusions {
+    pub const SEED: &'static [u8] = REWARD_EXCLUSIONS_SEED;
+
+    /// Reads the PDA, falling back to an empty count while it hasn't been created
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        if account.data_is_empty() {
+            return Ok(Self { epoch: 0, excluded_blocks: 0, bump: 0 });
+        }
+        Self::try_deserialize(&mut &account.try_borrow_data()?[..])
+    }
+
+    /// Excluded blocks in `epoch`
+    pub fn excluded_in(&self, epoch: u16) -> u32 {
+        if self.epoch == epoch { self.excluded_blocks } else { 0 }
+    }
+
+    /// Counts `blocks` more excluded blocks in `epoch`
+    pub fn add(&mut self, epoch: u16, blocks: u32) -> Option<()> {
+        self.excluded_blocks = self.excluded_in(epoch).checked_add(blocks)?;
+        self.epoch = epoch;
+        Some(())
+    }
+
+    /// Stops counting `blocks` excluded blocks in `epoch`
+    pub fn remove(&mut self, epoch: u16, blocks: u32) -> Option<()> {
+        self.excluded_blocks = self.excluded_in(epoch).checked_sub(blocks)?;
+        self.epoch = epoch;
+        Some(())
+    }
+
+    /// Removes an excluded parcel's `blocks` from the count stored in `account`
+    pub fn release(account: &AccountInfo, epoch: u16, blocks: u32) -> Result<()> {
+        let mut exclusions = Self::load(account)?;
+        exclusions.remove(epoch, blocks).ok_or(BillionError::Overflow)?;
+        exclusions.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_restarts_in_a_new_epoch() {
+        let mut exclusions = RewardExclusions { epoch: 1, excluded_blocks: 0, bump: 255 };
+        exclusions.add(1, 4).unwrap();
+        assert_eq!(exclusions.excluded_in(1), 4);
+        assert_eq!(exclusions.excluded_in(2), 0);
+        assert!(exclusions.remove(2, 1).is_none());
+
+        exclusions.add(2, 3).unwrap();
+        assert_eq!(exclusions.excluded_in(2), 3);
+        exclusions.remove(2, 3).unwrap();
+        assert_eq!(exclusions.excluded_blocks, 0);
+    }
+}
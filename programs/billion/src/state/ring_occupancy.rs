@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+use billion_core::{block_index, get_ring, total_blocks};
+use crate::constants::{RING_COUNT, RING_OCCUPANCY_SEED};
+use crate::state::RESERVED_BLOCK;
+
+/// Claimed blocks per ring of the current epoch's BlockMap, which GridConfig has no room for.
+/// initialize creates it for a new grid; a grid from before it existed has it created by
+/// admin_backfill_ring_occupancy, and the counts are complete once that scan has covered the
+/// whole BlockMap.
+#[account]
+#[derive(InitSpace)]
+pub struct RingOccupancy {
+    /// Claimed blocks of ring i + 1 (index 0 is the outermost ring); reserved blocks don't count
+    pub claimed_blocks_per_ring: [u32; RING_COUNT as usize],
+    /// Epoch the counts belong to; they restart from zero with the next epoch's empty BlockMap
+    pub epoch: u16,
+    /// Row-major BlockMap index the counts cover up to. Blocks past it are left for the
+    /// backfill to count; FULLY_COUNTED once it has covered the whole map.
+    pub counted_to: u32,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RingOccupancy {
+    pub const SEED: &'static [u8] = RING_OCCUPANCY_SEED;
+
+    /// counted_to of counts that cover every block
+    pub const FULLY_COUNTED: u32 = u32::MAX;
+
+    /// Counts that cover `epoch`'s BlockMap only up to `counted_to`
+    pub fn new(epoch: u16, counted_to: u32, bump: u8) -> Self {
+        Self { claimed_blocks_per_ring: [0; RING_COUNT as usize], epoch, counted_to, bump }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.counted_to == Self::FULLY_COUNTED
+    }
+
+    /// Moves the counts to `epoch`. A new epoch's BlockMap starts empty, so its counts
+    /// start complete.
+    fn roll(&mut self, epoch: u16) {
+        if self.epoch != epoch {
+            *self = Self::new(epoch, Self::FULLY_COUNTED, self.bump);
+        }
+    }
+
+    /// Counts `blocks` of a `grid_size` grid of `ring_count` rings in `epoch` as newly claimed, or as freed when
+    /// `claimed` is false. Blocks the backfill hasn't reached are left for it.
+    pub fn record(
+        &mut self,
+        epoch: u16,
+        blocks: impl Iterator<Item = (u8, u8)>,
+        grid_size: u16,
+        ring_count: u8,
+        claimed: bool,
+    ) -> Option<()> {
+        self.roll(epoch);
+        for (x, y) in blocks {
+            if block_index(x, y, grid_size) as u64 >= self.counted_to as u64 {
+                continue;
+            }
+            let ring = get_ring(x, y, grid_size, ring_count);
+            let count = self.claimed_blocks_per_ring.get_mut(ring as usize - 1)?;
+            *count = if claimed { count.checked_add(1)? } else { count.checked_sub(1)? };
+        }
+        Some(())
+    }
+
+    /// Counts the claimed blocks among the next `max_blocks` of `epoch`'s BlockMap, in
+    /// row-major order from where the last scan stopped. `parcel_id_at` reads the map.
+    pub fn scan(
+        &mut self,
+        epoch: u16,
+        parcel_id_at: impl Fn(u8, u8) -> u16,
+        grid_size: u16,
+        ring_count: u8,
+        max_blocks: u32,
+    ) -> Option<()> {
+        self.roll(epoch);
+        if self.is_complete() {
+            return Some(());
+        }
+        let total = total_blocks(grid_size);
+        let end = total.min((self.counted_to as usize).saturating_add(max_blocks as usize));
+        let side = grid_size as usize;
+        for index in self.counted_to as usize..end {
+            let (x, y) = ((index % side) as u8, (index / side) as u8);
+            let parcel_id = parcel_id_at(x, y);
+            if parcel_id == 0 || parcel_id == RESERVED_BLOCK {
+                continue;
+            }
+            let ring = get_ring(x, y, grid_size, ring_count);
+            let count = self.claimed_blocks_per_ring.get_mut(ring as usize - 1)?;
+            *count = count.checked_add(1)?;
+        }
+        self.counted_to = if end == total { Self::FULLY_COUNTED } else { end as u32 };
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use billion_core::Rect;
+    use crate::state::DEFAULT_GRID_SIZE;
+
+    fn map(parcels: &[(u16, Rect)]) -> Vec<u16> {
+        let mut blocks = vec![0u16; total_blocks(DEFAULT_GRID_SIZE)];
+        for &(parcel_id, rect) in parcels {
+            for (x, y) in rect.blocks() {
+                blocks[block_index(x, y, DEFAULT_GRID_SIZE)] = parcel_id;
+            }
+        }
+        blocks
+    }
+
+    fn scan(occupancy: &mut RingOccupancy, blocks: &[u16], max_blocks: u32) {
+        let parcel_id_at = |x, y| blocks[block_index(x, y, DEFAULT_GRID_SIZE)];
+        occupancy.scan(0, parcel_id_at, DEFAULT_GRID_SIZE, RING_COUNT, max_blocks).unwrap();
+    }
+
+    #[test]
+    fn test_claims_and_frees_move_their_rings() {
+        let mut occupancy = RingOccupancy::new(0, RingOccupancy::FULLY_COUNTED, 255);
+        occupancy.record(0, Rect::new(0, 0, 2, 2).blocks(), DEFAULT_GRID_SIZE, RING_COUNT, true).unwrap();
+        occupancy.record(0, Rect::new(50, 50, 1, 1).blocks(), DEFAULT_GRID_SIZE, RING_COUNT, true).unwrap();
+        assert_eq!((occupancy.claimed_blocks_per_ring[0], occupancy.claimed_blocks_per_ring[9]), (4, 1));
+
+        occupancy.record(0, Rect::new(0, 0, 1, 2).blocks(), DEFAULT_GRID_SIZE, RING_COUNT, false).unwrap();
+        assert_eq!(occupancy.claimed_blocks_per_ring[0], 2);
+        let freed = Rect::new(50, 50, 2, 1).blocks();
+        assert!(occupancy.record(0, freed, DEFAULT_GRID_SIZE, RING_COUNT, false).is_none());
+    }
+
+    #[test]
+    fn test_a_new_epoch_starts_from_zero() {
+        let mut occupancy = RingOccupancy::new(0, 0, 255);
+        occupancy.record(1, Rect::new(0, 0, 2, 2).blocks(), DEFAULT_GRID_SIZE, RING_COUNT, true).unwrap();
+        assert_eq!((occupancy.epoch, occupancy.claimed_blocks_per_ring[0]), (1, 4));
+        assert!(occupancy.is_complete());
+    }
+
+    #[test]
+    fn test_backfill_counts_each_block_once() {
+        // A reserved road and two parcels, one of them claimed while the scan is halfway
+        let blocks = map(&[(1, Rect::new(0, 0, 2, 2)), (RESERVED_BLOCK, Rect::new(0, 50, 100, 1))]);
+        let mut occupancy = RingOccupancy::new(0, 0, 255);
+        scan(&mut occupancy, &blocks, 5_000);
+        assert_eq!(occupancy.counted_to, 5_000);
+
+        // Behind the scan a claim counts at once; ahead of it the scan will find it
+        let mut blocks = blocks;
+        for rect in [Rect::new(2, 0, 1, 1), Rect::new(99, 99, 1, 1)] {
+            blocks[block_index(rect.x, rect.y, DEFAULT_GRID_SIZE)] = 2;
+            occupancy.record(0, rect.blocks(), DEFAULT_GRID_SIZE, RING_COUNT, true).unwrap();
+        }
+        assert_eq!(occupancy.claimed_blocks_per_ring[0], 5);
+
+        scan(&mut occupancy, &blocks, 5_000);
+        assert!(occupancy.is_complete());
+        assert_eq!(occupancy.claimed_blocks_per_ring.iter().sum::<u32>(), 6);
+        scan(&mut occupancy, &blocks, 5_000);
+        assert_eq!(occupancy.claimed_blocks_per_ring.iter().sum::<u32>(), 6);
+    }
+}
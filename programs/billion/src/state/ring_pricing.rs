@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use billion_core::Rect;
+use crate::constants::{RING_COUNT, RING_PRICING_SEED};
+use crate::utils::get_ring;
+
+/// 10_000 bps: the ring costs exactly price_per_block
+pub const FLAT_RING_MULTIPLIER_BPS: u16 = 10_000;
+
+/// Per-ring price multipliers and the bonding curve, created by the first update_config call.
+/// Until it exists every block costs price_per_block.
+#[account]
+#[derive(InitSpace)]
+pub struct RingPricing {
+    /// Multiplier on price_per_block for ring i + 1 (index 0 is the outermost ring), in basis points;
+    /// flat past the grid's ring count
+    pub multipliers_bps: [u16; RING_COUNT as usize],
+    /// Claimed blocks over which the curve adds another price_per_block to the base price
+    /// (0 = no curve)
+    pub curve_divisor: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RingPricing {
+    pub const SEED: &'static [u8] = RING_PRICING_SEED;
+
+    pub fn flat(bump: u8) -> Self {
+        Self {
+            multipliers_bps: [FLAT_RING_MULTIPLIER_BPS; RING_COUNT as usize],
+            curve_divisor: 0,
+            bump,
+        }
+    }
+
+    /// Whether `multipliers` holds a nonzero multiplier for each ring of a `ring_count`-ring grid
+    pub fn valid_multipliers(multipliers: &[u16], ring_count: u8) -> bool {
+        multipliers.len() == ring_count as usize && multipliers.iter().all(|&bps| bps > 0)
+    }
+
+    /// Sets the multipliers of the grid's rings to `multipliers`; the slots past its ring count
+    /// are never priced and stay flat
+    pub fn set_multipliers(&mut self, multipliers: &[u16]) {
+        self.multipliers_bps = [FLAT_RING_MULTIPLIER_BPS; RING_COUNT as usize];
+        self.multipliers_bps[..multipliers.len()].copy_from_slice(multipliers);
+    }
+
+    /// Reads the PDA, falling back to flat pricing while it hasn't been created
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        if account.data_is_empty() {
+            return Ok(Self::flat(0));
+        }
+        Self::try_deserialize(&mut &account.try_borrow_data()?[..])
+    }
+
+    /// Base price of the next claim once `total_claimed_blocks` blocks are claimed:
+    /// price_per_block * (1 + total_claimed_blocks / curve_divisor), rounded down
+    pub fn curve_price(&self, price_per_block: u64, total_claimed_blocks: u32) -> Option<u64> {
+        if self.curve_divisor == 0 {
+            return Some(price_per_block);
+        }
+        let scaled = (self.curve_divisor as u128).checked_add(total_claimed_blocks as u128)?;
+        let price = (price_per_block as u128).checked_mul(scaled)? / self.curve_divisor as u128;
+        u64::try_from(price).ok()
+    }
+
+    /// Price of the block at (x, y) of a `grid_size` grid of `ring_count` rings, rounded down
+    pub fn block_price(&self, x: u8, y: u8, price_per_block: u64, grid_size: u16, ring_count: u8) -> Option<u64> {
+        let multiplier = *self.multipliers_bps.get(get_ring(x, y, grid_size, ring_count) as usize - 1)?;
+        let price = (price_per_block as u128).checked_mul(multiplier as u128)? / 10_000;
+        u64::try_from(price).ok()
+    }
+
+    /// Sum of the block prices in `rect`
+    pub fn rect_cost(&self, rect: Rect, price_per_block: u64, grid_size: u16, ring_count: u8) -> Option<u64> {
+        self.blocks_cost(rect.blocks(), price_per_block, grid_size, ring_count)
+    }
+
+    /// Sum of the prices of `blocks`
+    pub fn blocks_cost(
+        &self,
+        mut blocks: impl Iterator<Item = (u8, u8)>,
+        price_per_block: u64,
+        grid_size: u16,
+        ring_count: u8,
+    ) -> Option<u64> {
+        blocks.try_fold(0u64, |total, (x, y)| {
+            total.checked_add(self.block_price(x, y, price_per_block, grid_size, ring_count)?)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::DEFAULT_GRID_SIZE;
+
+    #[test]
+    fn test_flat_pricing_matches_price_per_block() {
+        let pricing = RingPricing::flat(255);
+        let cost = |rect| pricing.rect_cost(rect, 1_000_000, DEFAULT_GRID_SIZE, RING_COUNT);
+        assert_eq!(cost(Rect::new(0, 0, 3, 2)), Some(6_000_000));
+        assert_eq!(cost(Rect::new(48, 48, 4, 4)), Some(16_000_000));
+    }
+
+    #[test]
+    fn test_parcel_spanning_two_rings_sums_per_block_prices() {
+        let mut pricing = RingPricing::flat(255);
+        pricing.multipliers_bps[0] = 10_000;
+        pricing.multipliers_bps[1] = 15_000;
+        // Column 5 is ring 1, column 6 is ring 2
+        assert_eq!(get_ring(5, 20, DEFAULT_GRID_SIZE, RING_COUNT), 1);
+        assert_eq!(get_ring(6, 20, DEFAULT_GRID_SIZE, RING_COUNT), 2);
+        let cost = pricing.rect_cost(Rect::new(5, 20, 2, 2), 1_000_000, DEFAULT_GRID_SIZE, RING_COUNT);
+        assert_eq!(cost, Some(5_000_000));
+    }
+
+    #[test]
+    fn test_curve_price_ramps_with_claimed_blocks() {
+        let mut pricing = RingPricing::flat(255);
+        assert_eq!(pricing.curve_price(1_000_000, 5_000), Some(1_000_000));
+
+        pricing.curve_divisor = 1_000;
+        assert_eq!(pricing.curve_price(1_000_000, 0), Some(1_000_000));
+        assert_eq!(pricing.curve_price(1_000_000, 500), Some(1_500_000));
+        assert_eq!(pricing.curve_price(1_000_000, 2_000), Some(3_000_000));
+        assert_eq!(pricing.curve_price(u64::MAX, 1), None);
+    }
+
+    #[test]
+    fn test_multipliers_cover_just_the_grids_rings() {
+        assert!(RingPricing::valid_multipliers(&[10_000, 15_000, 20_000, 25_000, 30_000], 5));
+        assert!(!RingPricing::valid_multipliers(&[10_000; RING_COUNT as usize], 5));
+        assert!(!RingPricing::valid_multipliers(&[10_000, 0, 10_000, 10_000, 10_000], 5));
+
+        let mut pricing = RingPricing::flat(255);
+        pricing.multipliers_bps[7] = 30_000;
+        pricing.set_multipliers(&[20_000, 15_000]);
+        assert_eq!(pricing.multipliers_bps[..3], [20_000, 15_000, FLAT_RING_MULTIPLIER_BPS]);
+        assert_eq!(pricing.multipliers_bps[7], FLAT_RING_MULTIPLIER_BPS);
+    }
+
+    #[test]
+    fn test_rect_cost_overflow() {
+        let mut pricing = RingPricing::flat(255);
+        pricing.multipliers_bps[0] = u16::MAX;
+        assert_eq!(pricing.block_price(0, 0, u64::MAX, DEFAULT_GRID_SIZE, RING_COUNT), None);
+        let flat = RingPricing::flat(255);
+        assert_eq!(flat.rect_cost(Rect::new(0, 0, 2, 1), u64::MAX, DEFAULT_GRID_SIZE, RING_COUNT), None);
+    }
+}
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use crate::constants::{REWARD_SCALE, REWARD_WEIGHT_UNIT, RING_COUNT, RING_REWARD_WEIGHTS_SEED};
+use crate::errors::BillionError;
+use crate::utils::get_ring;
+
+/// 10_000 bps: a block of the ring earns what an unweighted block does
+pub const FLAT_RING_REWARD_WEIGHT_BPS: u16 = 10_000;
+/// Lightest ring weight; a parcel's weight is kept in tenths and can't round to nothing
+pub const MIN_RING_REWARD_WEIGHT_BPS: u16 = 1_000;
+/// Heaviest ring weight, the most tenths ParcelInfo.reward_weight holds
+pub const MAX_RING_REWARD_WEIGHT_BPS: u16 = 25_500;
+
+/// Per-ring land-buy reward weights, created by the first set_ring_reward_weights call. Until
+/// it exists every block is unweighted. The accumulator pays per tenth of a weighted block, so
+/// with flat weights it credits and pays exactly what it did per block. GridConfig, which has
+/// no room left, keeps counting plain blocks; the weight they add on top is counted here.
+#[account]
+#[derive(InitSpace)]
+pub struct RingRewardWeights {
+    /// Weight of ring i + 1's blocks (index 0 is the outermost ring), in basis points
+    pub weights_bps: [u16; RING_COUNT as usize],
+    /// Epoch extra_weight belongs to; it restarts from zero in a new epoch
+    pub epoch: u16,
+    /// Weight of the epoch's rewarded parcels above (or below) their blocks unweighted, in tenths
+    pub extra_weight: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RingRewardWeights {
+    pub const SEED: &'static [u8] = RING_REWARD_WEIGHTS_SEED;
+
+    pub fn flat(bump: u8) -> Self {
+        Self {
+            weights_bps: [FLAT_RING_REWARD_WEIGHT_BPS; RING_COUNT as usize],
+            epoch: 0,
+            extra_weight: 0,
+            bump,
+        }
+    }
+
+    /// Reads the PDA, falling back to flat weights while it hasn't been created
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        if account.data_is_empty() {
+            return Ok(Self::flat(0));
+        }
+        Self::try_deserialize(&mut &account.try_borrow_data()?[..])
+    }
+
+    /// Average weight of `blocks` of a `grid_size` grid of `ring_count` rings, in tenths of an unweighted block,
+    /// rounded down
+    pub fn parcel_weight(&self, blocks: impl Iterator<Item = (u8, u8)>, grid_size: u16, ring_count: u8) -> Option<u8> {
+        let (mut total_bps, mut count) = (0u64, 0u64);
+        for (x, y) in blocks {
+            let ring = get_ring(x, y, grid_size, ring_count);
+            total_bps = total_bps.checked_add(*self.weights_bps.get(ring as usize - 1)? as u64)?;
+            count += 1;
+        }
+        let tenths = total_bps.checked_div(count.checked_mul(FLAT_RING_REWARD_WEIGHT_BPS as u64 / 10)?)?;
+        u8::try_from(tenths).ok()
+    }
+
+    /// Extra weight counted in `epoch`
+    pub fn extra_in(&self, epoch: u16) -> i64 {
+        if self.epoch == epoch { self.extra_weight } else { 0 }
+    }
+
+    /// Counts `delta` more extra weight in `epoch`
+    pub fn add_extra(&mut self, epoch: u16, delta: i64) -> Option<()> {
+        self.extra_weight = self.extra_in(epoch).checked_add(delta)?;
+        self.epoch = epoch;
+        Some(())
+    }
+
+    /// Weighted blocks, in tenths, that the accumulator spreads rewards over in `epoch`
+    pub fn reward_units(&self, epoch: u16, total_claimed_blocks: u32) -> Option<u128> {
+        let unweighted = total_claimed_blocks as i128 * REWARD_WEIGHT_UNIT as i128;
+        u128::try_from(unweighted.checked_add(self.extra_in(epoch) as i128)?).ok()
+    }
+
+    /// Accumulator increase crediting `amount` over `reward_units` weighted tenths. With flat
+    /// weights this is amount * REWARD_SCALE / total_claimed_blocks.
+    pub fn reward_increase(amount: u64, reward_units: u128) -> Option<u128> {
+        (amount as u128)
+            .checked_mul(REWARD_SCALE)?
+            .checked_mul(REWARD_WEIGHT_UNIT as u128)?
+            .checked_div(reward_units)
+    }
+
+    /// Adds `delta` to the extra weight stored in `account`. A parcel only carries extra
+    /// weight once the PDA exists, so an uncreated PDA never has anything to record.
+    pub fn record_extra(account: &AccountInfo, epoch: u16, delta: i64) -> Result<()> {
+        if delta == 0 {
+            return Ok(());
+        }
+        let mut weights = Self::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+        weights.add_extra(epoch, delta).ok_or(BillionError::Overflow)?;
+        weights.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::DEFAULT_GRID_SIZE;
+
+    #[test]
+    fn test_flat_weights_keep_the_unweighted_accumulator() {
+        let weights = RingRewardWeights::flat(255);
+        let blocks = [(0, 0), (50, 50)].into_iter();
+        assert_eq!(weights.parcel_weight(blocks, DEFAULT_GRID_SIZE, RING_COUNT), Some(REWARD_WEIGHT_UNIT));
+        let units = weights.reward_units(0, 3).unwrap();
+        assert_eq!(units, 30);
+        assert_eq!(RingRewardWeights::reward_increase(200_000, units), Some(200_000 * REWARD_SCALE / 3));
+    }
+
+    #[test]
+    fn test_parcel_weight_averages_its_rings() {
+        let mut weights = RingRewardWeights::flat(255);
+        weights.weights_bps[0] = 10_000;
+        weights.weights_bps[1] = 15_000;
+        // Column 5 is ring 1, column 6 is ring 2
+        assert_eq!(get_ring(5, 20, DEFAULT_GRID_SIZE, RING_COUNT), 1);
+        assert_eq!(get_ring(6, 20, DEFAULT_GRID_SIZE, RING_COUNT), 2);
+        assert_eq!(weights.parcel_weight([(5, 20), (6, 20)].into_iter(), DEFAULT_GRID_SIZE, RING_COUNT), Some(12));
+        assert_eq!(weights.parcel_weight([(6, 20)].into_iter(), DEFAULT_GRID_SIZE, RING_COUNT), Some(15));
+        assert_eq!(weights.parcel_weight(std::iter::empty(), DEFAULT_GRID_SIZE, RING_COUNT), None);
+    }
+
+    #[test]
+    fn test_extra_weight_restarts_in_a_new_epoch() {
+        let mut weights = RingRewardWeights::flat(255);
+        weights.add_extra(1, 20).unwrap();
+        assert_eq!(weights.reward_units(1, 4), Some(60));
+        assert_eq!(weights.reward_units(2, 4), Some(40));
+        weights.add_extra(2, -5).unwrap();
+        assert_eq!(weights.reward_units(2, 4), Some(35));
+        assert_eq!(weights.reward_units(2, 0), None);
+    }
+}
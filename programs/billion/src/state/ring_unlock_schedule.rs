@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use billion_core::get_scheduled_ring;
+use crate::constants::{RING_COUNT, RING_UNLOCK_SCHEDULE_SEED};
+
+/// Times at which rings open whatever has been burned, which GridConfig has no room for.
+/// Created by the first update_config after its introduction; until then, and while it's
+/// empty, rings unlock by burns alone.
+#[account]
+#[derive(InitSpace)]
+pub struct RingUnlockSchedule {
+    /// Unix timestamp at which ring i + 1 opens; rings past the end open only by burns
+    #[max_len(RING_COUNT)]
+    pub ring_unlock_timestamps: Vec<i64>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RingUnlockSchedule {
+    pub const SEED: &'static [u8] = RING_UNLOCK_SCHEDULE_SEED;
+
+    /// Reads the PDA, falling back to no schedule while it hasn't been created
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        if account.data_is_empty() {
+            return Ok(Self { ring_unlock_timestamps: Vec::new(), bump: 0 });
+        }
+        Self::try_deserialize(&mut &account.try_borrow_data()?[..])
+    }
+
+    /// Whether `timestamps` schedules at most one time per ring of a `ring_count`-ring grid,
+    /// none before the one before it
+    pub fn valid_timestamps(timestamps: &[i64], ring_count: u8) -> bool {
+        timestamps.len() <= ring_count as usize && timestamps.windows(2).all(|pair| pair[0] <= pair[1])
+    }
+
+    /// The later of `burn_ring`, unlocked by the burns, and the ring the schedule has opened
+    /// at `now`
+    pub fn unlocked_ring(&self, burn_ring: u8, now: i64, ring_count: u8) -> u8 {
+        burn_ring.max(get_scheduled_ring(now, &self.ring_unlock_timestamps, ring_count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schedule_opens_rings_the_burns_have_not() {
+        let schedule = RingUnlockSchedule { ring_unlock_timestamps: vec![0, 100, 200, 300, 400], bump: 255 };
+        // Burns have unlocked ring 3, the schedule ring 5
+        assert_eq!(schedule.unlocked_ring(3, 400, RING_COUNT), 5);
+        // Before the schedule catches up, the burns decide
+        assert_eq!(schedule.unlocked_ring(3, 250, RING_COUNT), 3);
+        assert_eq!(schedule.unlocked_ring(8, i64::MAX, RING_COUNT), 8);
+
+        let empty = RingUnlockSchedule { ring_unlock_timestamps: Vec::new(), bump: 0 };
+        assert_eq!(empty.unlocked_ring(2, i64::MAX, RING_COUNT), 2);
+    }
+
+    #[test]
+    fn test_valid_timestamps() {
+        assert!(RingUnlockSchedule::valid_timestamps(&[], RING_COUNT));
+        assert!(RingUnlockSchedule::valid_timestamps(&[0, 100, 100], 3));
+        assert!(!RingUnlockSchedule::valid_timestamps(&[0, 100, 50], RING_COUNT));
+        assert!(!RingUnlockSchedule::valid_timestamps(&[0, 100, 200, 300], 3));
+    }
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use crate::constants::{REWARD_SCALE, SOL_REWARDS_SEED, SOL_REWARD_CHECKPOINT_SEED};
+
+/// Accumulator of the SOL reward stream, kept apart from GridConfig, which has no room left.
+/// Lamports sit in the system-owned vault at `[SOL_REWARD_VAULT_SEED, grid_config]`. Only
+/// parcels enrolled through claim_sol_rewards share in it, so deposits are spread over
+/// enrolled_blocks rather than total_claimed_blocks. Created by the first fund_sol_rewards or
+/// claim_sol_rewards call.
+#[account]
+#[derive(InitSpace)]
+pub struct SolRewards {
+    /// Lamports per enrolled block deposited so far, scaled by REWARD_SCALE
+    pub sol_rewards_per_block: u128,
+    /// Blocks of every parcel holding a SolRewardCheckpoint
+    pub enrolled_blocks: u32,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl SolRewards {
+    pub const SEED: &'static [u8] = SOL_REWARDS_SEED;
+
+    /// Spreads a deposit of `lamports` over the enrolled blocks
+    pub fn credit(&mut self, lamports: u64) -> Option<()> {
+        let increment = (lamports as u128)
+            .checked_mul(REWARD_SCALE)?
+            .checked_div(self.enrolled_blocks as u128)?;
+        self.sol_rewards_per_block = self.sol_rewards_per_block.checked_add(increment)?;
+        Some(())
+    }
+}
+
+/// A parcel's enrollment in the SOL reward stream, at `[SEED, grid_seed(grid_id), epoch_seed(epoch), parcel_id]`.
+/// ParcelInfo has no room for a second checkpoint.
+#[account]
+#[derive(InitSpace)]
+pub struct SolRewardCheckpoint {
+    /// Asset of the parcel that enrolled; a reused parcel id enrolls afresh
+    pub asset: Pubkey,
+    /// Blocks counted in SolRewards.enrolled_blocks for this parcel
+    pub block_count: u32,
+    /// Snapshot of sol_rewards_per_block at the last claim
+    pub last_claimed_sol_rewards_per_block: u128,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl SolRewardCheckpoint {
+    pub const SEED: &'static [u8] = SOL_REWARD_CHECKPOINT_SEED;
+
+    /// Lamports owed at `sol_rewards_per_block`
+    pub fn pending(&self, sol_rewards_per_block: u128) -> Option<u64> {
+        let owed = sol_rewards_per_block
+            .checked_sub(self.last_claimed_sol_rewards_per_block)?
+            .checked_mul(self.block_count as u128)?
+            / REWARD_SCALE;
+        u64::try_from(owed).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deposits_are_split_over_enrolled_blocks() {
+        let mut sol_rewards = SolRewards { sol_rewards_per_block: 0, enrolled_blocks: 4, bump: 255 };
+        sol_rewards.credit(1_000).unwrap();
+        let checkpoint = SolRewardCheckpoint {
+            asset: Pubkey::new_unique(),
+            block_count: 3,
+            last_claimed_sol_rewards_per_block: 0,
+            bump: 255,
+        };
+        assert_eq!(checkpoint.pending(sol_rewards.sol_rewards_per_block), Some(750));
+
+        sol_rewards.enrolled_blocks = 0;
+        assert_eq!(sol_rewards.credit(1_000), None);
+    }
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use crate::constants::STAKE_SEED;
+
+/// A staked parcel. Exists only while the parcel's asset is frozen by the program.
+#[account]
+#[derive(InitSpace)]
+pub struct StakeAccount {
+    /// Asset owner at stake time; the only wallet that can claim or unstake
+    pub staker: Pubkey,
+    /// Parcel being staked
+    pub parcel_id: u16,
+    /// Unix timestamp the parcel was staked
+    pub staked_at: i64,
+    /// Unix timestamp emissions were last settled up to
+    pub last_claimed_at: i64,
+    /// Lifetime emissions paid out for this stake
+    pub total_claimed: u64,
+    /// PDA bump seed
+    pub bump: u8,
+    /// Reserved for future fields
+    pub _reserved: [u8; 32],
+}
+
+impl StakeAccount {
+    pub const SEED: &'static [u8] = STAKE_SEED;
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::constants::TOKEN_TREASURY_SEED;
+
+/// Protocol treasury taking a share of every token claim, carved out of the burn. Created by
+/// the first set_token_treasury; until then claims pay nothing to it.
+#[account]
+#[derive(InitSpace)]
+pub struct TokenTreasury {
+    /// Token account of the grid's mint receiving the share; owned outside the program
+    pub treasury: Pubkey,
+    /// Portion of each claim's total_cost sent to `treasury`. Together with the landowner,
+    /// referral and charity shares it never exceeds 10_000.
+    pub share_bps: u16,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl TokenTreasury {
+    pub const SEED: &'static [u8] = TOKEN_TREASURY_SEED;
+
+    /// Reads the PDA, falling back to no treasury share while it hasn't been created
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        if account.data_is_empty() {
+            return Ok(Self { treasury: Pubkey::default(), share_bps: 0, bump: 0 });
+        }
+        Self::try_deserialize(&mut &account.try_borrow_data()?[..])
+    }
+
+    /// Treasury share of a claim costing `total_cost`, rounded down so the burn keeps the dust
+    pub fn share(&self, total_cost: u64) -> Option<u64> {
+        u64::try_from(total_cost as u128 * self.share_bps as u128 / 10_000).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn treasury(share_bps: u16) -> TokenTreasury {
+        TokenTreasury { treasury: Pubkey::new_unique(), share_bps, bump: 255 }
+    }
+
+    #[test]
+    fn test_three_way_split_leaves_the_rounding_to_the_burn() {
+        // 20% to landowners, 15% to the treasury, the rest burned
+        let total_cost = 1_000_003u64;
+        let reward = total_cost * 2_000 / 10_000;
+        let share = treasury(1_500).share(total_cost).unwrap();
+        assert_eq!((reward, share), (200_000, 150_000));
+        assert_eq!(total_cost - reward - share, 650_003);
+
+        assert_eq!(treasury(1_500).share(6), Some(0));
+        assert_eq!(treasury(0).share(u64::MAX), Some(0));
+        assert_eq!(treasury(10_000).share(u64::MAX), Some(u64::MAX));
+    }
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use billion_core::Rect;
+use crate::constants::VOUCHER_SEED;
+use crate::utils::get_ring;
+
+/// Authority-issued right for `beneficiary` to claim one parcel for free. Lives at
+/// `[SEED, beneficiary, voucher_id]` and is closed when redeemed or, once expired, by the
+/// authority.
+#[account]
+#[derive(InitSpace)]
+pub struct Voucher {
+    pub beneficiary: Pubkey,
+    /// Chosen by the authority; distinguishes vouchers issued to the same beneficiary
+    pub voucher_id: u64,
+    pub max_width: u8,
+    pub max_height: u8,
+    /// Every block of the redeemed parcel must lie in rings allowed_ring_min..=allowed_ring_max
+    /// (1 is the outermost ring, 10 the center)
+    pub allowed_ring_min: u8,
+    pub allowed_ring_max: u8,
+    /// Unix timestamp after which the voucher can no longer be redeemed
+    pub expires_at: i64,
+    pub issued_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Voucher {
+    pub const SEED: &'static [u8] = VOUCHER_SEED;
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.expires_at
+    }
+
+    /// Whether a `width` x `height` parcel at (x, y) of a `grid_size` grid of `ring_count`
+    /// rings fits the voucher's size and ring limits. Expects the parcel to have passed the regular bounds check.
+    pub fn allows(&self, x: u8, y: u8, width: u8, height: u8, grid_size: u16, ring_count: u8) -> bool {
+        if width > self.max_width || height > self.max_height {
+            return false;
+        }
+        let allowed_rings = self.allowed_ring_min..=self.allowed_ring_max;
+        Rect::new(x, y, width, height)
+            .blocks()
+            .all(|(x, y)| allowed_rings.contains(&get_ring(x, y, grid_size, ring_count)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::RING_COUNT;
+    use crate::state::DEFAULT_GRID_SIZE;
+
+    fn voucher(max_width: u8, max_height: u8, allowed_ring_min: u8, allowed_ring_max: u8) -> Voucher {
+        Voucher {
+            beneficiary: Pubkey::new_unique(),
+            voucher_id: 1,
+            max_width,
+            max_height,
+            allowed_ring_min,
+            allowed_ring_max,
+            expires_at: 1_000,
+            issued_at: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_voucher_enforces_size_limits() {
+        let center = (DEFAULT_GRID_SIZE / 2) as u8;
+        let v = voucher(2, 3, 0, u8::MAX);
+        assert!(v.allows(center, center, 2, 3, DEFAULT_GRID_SIZE, RING_COUNT));
+        assert!(v.allows(center, center, 1, 1, DEFAULT_GRID_SIZE, RING_COUNT));
+        assert!(!v.allows(center, center, 3, 1, DEFAULT_GRID_SIZE, RING_COUNT));
+        assert!(!v.allows(center, center, 1, 4, DEFAULT_GRID_SIZE, RING_COUNT));
+    }
+
+    #[test]
+    fn test_voucher_requires_every_block_in_allowed_rings() {
+        let center = (DEFAULT_GRID_SIZE / 2) as u8;
+        let ring = get_ring(center, center, DEFAULT_GRID_SIZE, RING_COUNT);
+        let v = voucher(u8::MAX, u8::MAX, ring, ring);
+        assert!(v.allows(center, center, 1, 1, DEFAULT_GRID_SIZE, RING_COUNT));
+        // A parcel reaching into the outermost ring leaves the allowed band
+        assert!(!v.allows(0, 0, 1, 1, DEFAULT_GRID_SIZE, RING_COUNT));
+        assert!(!v.allows(center, center, (DEFAULT_GRID_SIZE / 2) as u8, 1, DEFAULT_GRID_SIZE, RING_COUNT));
+
+        let corner = get_ring(0, 0, DEFAULT_GRID_SIZE, RING_COUNT);
+        let outer = voucher(u8::MAX, u8::MAX, corner, corner);
+        assert!(outer.allows(0, 0, 1, 1, DEFAULT_GRID_SIZE, RING_COUNT));
+        assert!(!outer.allows(center, center, 1, 1, DEFAULT_GRID_SIZE, RING_COUNT));
+    }
+
+    #[test]
+    fn test_voucher_expiry() {
+        let v = voucher(1, 1, 0, 0);
+        assert!(!v.is_expired(999));
+        assert!(v.is_expired(1_000));
+    }
+}
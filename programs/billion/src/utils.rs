@@ -1,67 +1,168 @@
-use crate::state::GRID_SIZE;
-
-/// Calculate which ring a block belongs to (1-10)
-/// Ring 1 is outermost (corners), Ring 10 is center
-/// Outer rings unlock first, center unlocks last
-pub fn get_ring(x: u8, y: u8) -> u8 {
-    let center = (GRID_SIZE / 2) as i16; // 50
-    let dx = ((x as i16) - center).unsigned_abs() as u8;
-    let dy = ((y as i16) - center).unsigned_abs() as u8;
-    let distance = dx.max(dy);
-
-    // Ring 10 = center (0-4), Ring 1 = corners (45-50)
-    // Inverted: 11 - ((distance / 5) + 1), clamped to 1-10
-    let raw_ring = (distance / 5) + 1;
-    (11 - raw_ring.min(10)).max(1)
+//! Ring and grid math lives in the `billion-core` crate so off-chain clients share it.
+pub use billion_core::{block_index, get_ring, get_unlocked_ring, rect_block_count, rect_in_bounds};
+
+use anchor_lang::prelude::{AccountInfo, Pubkey, Result};
+use billion_core::Rect;
+use anchor_lang::solana_program::keccak::hashv;
+use anchor_spl::token_2022::spl_token_2022::extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions};
+use anchor_spl::token_2022::spl_token_2022::state::Mint;
+use crate::state::verify_quest_proof;
+
+/// Allowlist leaf hash for `wallet`: keccak256(wallet)
+pub fn allowlist_leaf(wallet: &Pubkey) -> [u8; 32] {
+    hashv(&[wallet.as_ref()]).to_bytes()
 }
 
-/// Calculate which ring is unlocked based on total burned
-pub fn get_unlocked_ring(total_burned: u64, thresholds: &[u64]) -> u8 {
-    for (i, &threshold) in thresholds.iter().enumerate().rev() {
-        if total_burned >= threshold {
-            return (i + 1) as u8;
-        }
-    }
-    1 // Ring 1 always unlocked
+/// Whether `proof` walks `wallet`'s leaf up to the allowlist `root`. Sibling nodes are hashed
+/// as sorted pairs, the same tree shape quests use.
+pub fn verify_allowlist_proof(proof: &[[u8; 32]], root: &[u8; 32], wallet: &Pubkey) -> bool {
+    verify_quest_proof(proof, root, allowlist_leaf(wallet))
+}
+
+/// Map hash after writing `parcel_id` over `rect` of the BlockMap:
+/// keccak256(prev_hash || parcel_id || x || y || width || height), the id little-endian. A
+/// free writes parcel id 0, a reservation RESERVED_BLOCK.
+pub fn next_map_hash(prev_hash: &[u8; 32], parcel_id: u16, rect: Rect) -> [u8; 32] {
+    let write = [rect.x, rect.y, rect.width, rect.height];
+    hashv(&[prev_hash, &parcel_id.to_le_bytes(), &write]).to_bytes()
+}
+
+/// Whether `mint` has the Token-2022 TransferFee extension. Transfers into the reward pool
+/// would then land short of what the accumulator credits. Legacy SPL Token mints have none.
+pub fn has_transfer_fee(mint: &AccountInfo) -> Result<bool> {
+    let data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&data)?;
+    Ok(mint.get_extension_types()?.contains(&ExtensionType::TransferFeeConfig))
+}
+
+/// First block of the `width` x `height` rectangle at (x, y), in row-major order, whose ring
+/// of `ring_count` isn't unlocked once `total_burned` tokens are burned. The rectangle must be
+/// in bounds.
+#[allow(clippy::too_many_arguments)]
+pub fn first_locked_block(
+    x: u8,
+    y: u8,
+    width: u8,
+    height: u8,
+    total_burned: u64,
+    thresholds: &[u64],
+    grid_size: u16,
+    ring_count: u8,
+) -> Option<(u8, u8)> {
+    let unlocked_ring = get_unlocked_ring(total_burned, thresholds, ring_count);
+    first_locked_of(Rect::new(x, y, width, height).blocks(), unlocked_ring, grid_size, ring_count)
+}
+
+/// First of `blocks`, such as a masked parcel's cells, in a ring of `ring_count` past
+/// `unlocked_ring`
+pub fn first_locked_of(
+    mut blocks: impl Iterator<Item = (u8, u8)>,
+    unlocked_ring: u8,
+    grid_size: u16,
+    ring_count: u8,
+) -> Option<(u8, u8)> {
+    blocks.find(|&(x, y)| get_ring(x, y, grid_size, ring_count) > unlocked_ring)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use anchor_spl::token_2022::spl_token_2022::extension::mint_close_authority::MintCloseAuthority;
+    use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+    use anchor_spl::token_2022::spl_token_2022::extension::{BaseStateWithExtensionsMut, StateWithExtensionsMut};
+    use crate::constants::RING_COUNT;
+    use crate::state::{quest_node, DEFAULT_GRID_SIZE};
+
+    /// Initialized mint account data carrying `extensions`
+    fn mint_data(extensions: &[ExtensionType]) -> Vec<u8> {
+        let len = ExtensionType::try_calculate_account_len::<Mint>(extensions).unwrap();
+        let mut data = vec![0u8; len];
+        let mut mint = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+        for extension in extensions {
+            match extension {
+                ExtensionType::TransferFeeConfig => {
+                    mint.init_extension::<TransferFeeConfig>(true).unwrap();
+                }
+                ExtensionType::MintCloseAuthority => {
+                    mint.init_extension::<MintCloseAuthority>(true).unwrap();
+                }
+                _ => unimplemented!(),
+            }
+        }
+        mint.base = Mint { decimals: 6, is_initialized: true, ..Mint::default() };
+        mint.pack_base();
+        if !extensions.is_empty() {
+            mint.init_account_type().unwrap();
+        }
+        data
+    }
+
+    fn mint_has_transfer_fee(extensions: &[ExtensionType]) -> bool {
+        let key = Pubkey::new_unique();
+        let owner = anchor_spl::token_2022::ID;
+        let mut lamports = 0;
+        let mut data = mint_data(extensions);
+        let account = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &owner, false, 0);
+        has_transfer_fee(&account).unwrap()
+    }
 
     #[test]
-    fn test_get_ring_center() {
-        // Center area (distance 0-4 from center) = Ring 10 (unlocks last)
-        assert_eq!(get_ring(50, 50), 10);
-        assert_eq!(get_ring(48, 52), 10);
-        assert_eq!(get_ring(54, 46), 10);
+    fn test_has_transfer_fee() {
+        assert!(!mint_has_transfer_fee(&[]));
+        assert!(!mint_has_transfer_fee(&[ExtensionType::MintCloseAuthority]));
+        assert!(mint_has_transfer_fee(&[ExtensionType::TransferFeeConfig]));
+        assert!(mint_has_transfer_fee(&[ExtensionType::MintCloseAuthority, ExtensionType::TransferFeeConfig]));
     }
 
     #[test]
-    fn test_get_ring_edges() {
-        // Corners/edges (distance 45-50 from center) = Ring 1 (unlocks first)
-        assert_eq!(get_ring(0, 0), 1);
-        assert_eq!(get_ring(99, 99), 1);
-        assert_eq!(get_ring(0, 99), 1);
-        assert_eq!(get_ring(99, 0), 1);
+    fn test_verify_allowlist_proof() {
+        let wallets = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+        let leaves = wallets.map(|wallet| allowlist_leaf(&wallet));
+        let left = quest_node(&leaves[0], &leaves[1]);
+        let root = quest_node(&left, &leaves[2]);
+
+        assert!(verify_allowlist_proof(&[leaves[1], leaves[2]], &root, &wallets[0]));
+        assert!(verify_allowlist_proof(&[leaves[0], leaves[2]], &root, &wallets[1]));
+        assert!(verify_allowlist_proof(&[left], &root, &wallets[2]));
+        // Another wallet can't reuse a member's proof
+        assert!(!verify_allowlist_proof(&[leaves[1], leaves[2]], &root, &Pubkey::new_unique()));
+        assert!(!verify_allowlist_proof(&[], &root, &wallets[0]));
     }
 
     #[test]
-    fn test_get_ring_boundaries() {
-        // Ring boundaries based on distance from center
-        assert_eq!(get_ring(50, 54), 10); // distance 4 → Ring 10
-        assert_eq!(get_ring(50, 55), 9);  // distance 5 → Ring 9
-        assert_eq!(get_ring(50, 59), 9);  // distance 9 → Ring 9
-        assert_eq!(get_ring(50, 60), 8);  // distance 10 → Ring 8
+    fn test_first_locked_block() {
+        let thresholds = [0, 100, 200];
+        // (6, 6) is the outermost corner of ring 2
+        assert_eq!(first_locked_block(0, 0, 5, 5, 0, &thresholds, DEFAULT_GRID_SIZE, RING_COUNT), None);
+        assert_eq!(first_locked_block(4, 5, 3, 3, 0, &thresholds, DEFAULT_GRID_SIZE, RING_COUNT), Some((6, 6)));
+        assert_eq!(first_locked_block(4, 5, 3, 3, 100, &thresholds, DEFAULT_GRID_SIZE, RING_COUNT), None);
+        // (11, 11) is in ring 3; its ring-2 neighbors in the rectangle pass
+        let locked = first_locked_block(10, 10, 2, 2, 100, &thresholds, DEFAULT_GRID_SIZE, RING_COUNT);
+        assert_eq!(locked, Some((11, 11)));
     }
 
     #[test]
-    fn test_get_unlocked_ring() {
-        let thresholds = vec![0, 100, 200, 300, 400, 500, 600, 700, 800, 900];
-        assert_eq!(get_unlocked_ring(0, &thresholds), 1);
-        assert_eq!(get_unlocked_ring(99, &thresholds), 1);
-        assert_eq!(get_unlocked_ring(100, &thresholds), 2);
-        assert_eq!(get_unlocked_ring(500, &thresholds), 6);
-        assert_eq!(get_unlocked_ring(1000, &thresholds), 10);
+    fn test_first_locked_of_skips_unlisted_blocks() {
+        let corners = [(0, 0), (99, 99)].into_iter();
+        assert_eq!(first_locked_of(corners, 1, DEFAULT_GRID_SIZE, RING_COUNT), None);
+        let blocks = [(0, 0), (6, 6), (7, 7)].into_iter();
+        assert_eq!(first_locked_of(blocks, 1, DEFAULT_GRID_SIZE, RING_COUNT), Some((6, 6)));
+    }
+
+    #[test]
+    fn test_next_map_hash_is_deterministic() {
+        let start = [0u8; 32];
+        let first = next_map_hash(&start, 1, Rect::new(0, 0, 2, 2));
+        assert_eq!(first, next_map_hash(&start, 1, Rect::new(0, 0, 2, 2)));
+        assert_eq!(first, hashv(&[&[0u8; 32], &[1, 0, 0, 0, 2, 2]]).to_bytes());
+
+        // Every input moves the hash, and so does the order of the writes
+        assert_ne!(first, next_map_hash(&start, 2, Rect::new(0, 0, 2, 2)));
+        assert_ne!(first, next_map_hash(&start, 1, Rect::new(0, 0, 2, 1)));
+        assert_ne!(first, next_map_hash(&[1u8; 32], 1, Rect::new(0, 0, 2, 2)));
+        let (a, b) = ((1, Rect::new(0, 0, 1, 1)), (2, Rect::new(1, 0, 1, 1)));
+        let ab = next_map_hash(&next_map_hash(&start, a.0, a.1), b.0, b.1);
+        let ba = next_map_hash(&next_map_hash(&start, b.0, b.1), a.0, a.1);
+        assert_ne!(ab, ba);
     }
 }
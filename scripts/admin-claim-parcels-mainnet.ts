@@ -13,6 +13,8 @@ import * as readline from "readline";
 // Set to true for dry run (no transactions, just show what would happen)
 const DRY_RUN = false;
 
+const GRID_ID = new anchor.BN(0);
+
 // Worker directory for wrangler commands
 const WORKER_DIR = path.resolve(__dirname, "../../worker");
 
@@ -242,7 +244,7 @@ async function main() {
   if (!gridConfig.seedingEnabled) {
     console.error("Seeding is disabled. Enable it first with update_config.");
     console.log("\nTo enable seeding, run:");
-    console.log("  program.methods.updateConfig(null, null, null, true, null, null, null)");
+    console.log("  program.methods.updateConfig(GRID_ID, null, null, null, true, null, null, null)");
     process.exit(1);
   }
 
@@ -290,7 +292,7 @@ async function main() {
 
       // Call admin_mint
       const tx = await program.methods
-        .adminMint(parcel.x, parcel.y, parcel.width, parcel.height)
+        .adminMint(GRID_ID, parcel.x, parcel.y, parcel.width, parcel.height)
         .accountsPartial({
           authority: authority.publicKey,
           recipient: recipient,
@@ -0,0 +1,77 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { Billion } from "../target/types/billion";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+
+// Migration for grids initialized before RingOccupancy existed: the first call creates the
+// account with every count at zero, and each call counts the next MAX_OCCUPANCY_SCAN_BLOCKS
+// blocks of the BlockMap. Claims made while it runs count their blocks only where the scan
+// has already been, so the counts are exact once it reports complete. Grids initialized
+// since then start with complete counts and never need this.
+
+const GRID_ID = new BN(0);
+
+async function main() {
+  const restart = process.argv.includes("--restart");
+
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.Billion as Program<Billion>;
+  const authority = provider.wallet;
+
+  console.log("===========================================");
+  console.log("  Backfill Ring Occupancy");
+  console.log("===========================================\n");
+
+  console.log("Program ID:", program.programId.toBase58());
+  console.log("Authority:", authority.publicKey.toBase58());
+
+  // Grid 0 keeps the original single-grid PDA seeds
+  const [gridConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("grid_config")],
+    program.programId
+  );
+  const [ringOccupancyPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("ring_occupancy")],
+    program.programId
+  );
+  const [adminRolesPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("admin_roles")],
+    program.programId
+  );
+  const config = await program.account.gridConfig.fetch(gridConfigPda);
+
+  // Only the first call may restart; the rest continue from where it stopped
+  let first = restart;
+  for (;;) {
+    // eslint-disable-next-line @typescript-eslint/no-explicit-any
+    const sig = await (program.methods as any)
+      .adminBackfillRingOccupancy(GRID_ID, first)
+      .accounts({
+        authority: authority.publicKey,
+        gridConfig: gridConfigPda,
+        blockMap: config.blockMap,
+        ringOccupancy: ringOccupancyPda,
+        adminRoles: adminRolesPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    first = false;
+
+    const occupancy = await program.account.ringOccupancy.fetch(ringOccupancyPda);
+    const complete = occupancy.countedTo === 0xffffffff;
+    console.log("TX:", sig, complete ? "(complete)" : `(counted to block ${occupancy.countedTo})`);
+    if (complete) {
+      console.log("\nClaimed blocks per ring:", occupancy.claimedBlocksPerRing.join(", "));
+      break;
+    }
+  }
+
+  console.log("\n✅ Ring occupancy backfilled!");
+}
+
+main().catch((err) => {
+  console.error("Error:", err);
+  process.exit(1);
+});
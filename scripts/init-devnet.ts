@@ -24,8 +24,11 @@ const MPL_CORE_PROGRAM_ID = new PublicKey(
   "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
 );
 
-// Grid constants (must match Rust)
-const BLOCK_MAP_SIZE = 8 + 2 * 10000 + 1 + 7; // 20016 bytes
+// Grid constants (must match Rust): GRID_SIZE=50 launches a 50x50 grid
+const GRID_SIZE = Number(process.env.GRID_SIZE ?? 100);
+const BLOCK_MAP_SIZE = 8 + 2 * GRID_SIZE * GRID_SIZE + 8; // 20016 bytes at 100x100
+// Grid 0 keeps the original single-grid PDA seeds
+const GRID_ID = new BN(0);
 
 // ============================================
 // Cost tracking helper
@@ -189,7 +192,7 @@ async function main() {
   });
 
   const createBlockMapIx = await program.methods
-    .createBlockMap()
+    .createBlockMap(GRID_SIZE)
     .accounts({
       payer: authority.publicKey,
       blockMap: blockMapKeypair.publicKey,
@@ -244,6 +247,7 @@ async function main() {
   const sig2 = await trackCost(provider, "Initialize Grid Config", async () => {
     const sig = await program.methods
       .initialize(
+        GRID_ID,
         pricePerBlock,
         ringThresholds,
         uriBase,
@@ -317,7 +321,7 @@ async function main() {
     "Update Config with Collection",
     async () => {
       const sig = await program.methods
-        .updateConfig(null, null, null, null, collectionPubkey, null)
+        .updateConfig(GRID_ID, null, null, null, null, collectionPubkey, null)
         .accountsPartial({
           authority: authority.publicKey,
           gridConfig: gridConfigPda,
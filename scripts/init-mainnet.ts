@@ -25,8 +25,11 @@ const EXISTING_COLLECTION = new PublicKey(
   "43wJ2YA1qdKfYv1GUUnCvT8b2ksZtNEx1Ns6suJa5c1C"
 );
 
-// Grid constants (must match Rust)
-const BLOCK_MAP_SIZE = 8 + 2 * 10000 + 1 + 7; // 20016 bytes
+// Grid constants (must match Rust): GRID_SIZE=50 launches a 50x50 grid
+const GRID_SIZE = Number(process.env.GRID_SIZE ?? 100);
+const BLOCK_MAP_SIZE = 8 + 2 * GRID_SIZE * GRID_SIZE + 8; // 20016 bytes at 100x100
+// Grid 0 keeps the original single-grid PDA seeds
+const GRID_ID = new BN(0);
 
 // ============================================
 // Cost tracking helper
@@ -166,7 +169,7 @@ async function main() {
   });
 
   const createBlockMapIx = await program.methods
-    .createBlockMap()
+    .createBlockMap(GRID_SIZE)
     .accounts({
       payer: authority.publicKey,
       blockMap: blockMapKeypair.publicKey,
@@ -217,6 +220,7 @@ async function main() {
   await trackCost(provider, "Initialize Grid Config", async () => {
     const sig = await program.methods
       .initialize(
+        GRID_ID,
         pricePerBlock,
         ringThresholds,
         uriBase,
@@ -269,6 +273,7 @@ async function main() {
   await trackCost(provider, "Update Config with Collection", async () => {
     const sig = await program.methods
       .updateConfig(
+        GRID_ID,
         null,
         null,
         null,
@@ -3,8 +3,8 @@ import { Program } from "@coral-xyz/anchor";
 import { Billion } from "../target/types/billion";
 import { Connection, PublicKey } from "@solana/web3.js";
 
-const GRID_SIZE = 100;
-const TOTAL_BLOCKS = GRID_SIZE * GRID_SIZE;
+// Maps created before grid sizes could vary leave the stored size at 0
+const DEFAULT_GRID_SIZE = 100;
 
 // Base62 charset: 1-9 (indices 1-9), A-Z (indices 10-35), a-z (indices 36-61)
 // 0 is reserved for empty cells
@@ -76,9 +76,12 @@ async function main() {
   console.log("BlockMap account size:", blockMapAccountInfo.data.length, "bytes");
 
   // Parse BlockMap data
-  // Layout: 8 bytes discriminator + 10000 u16s (20000 bytes) + 1 byte bump + 7 bytes padding
+  // Layout: 8 bytes discriminator + GRID_SIZE² u16s + 1 byte bump + 1 byte frozen + u16 grid size + 4 bytes padding
   const data = blockMapAccountInfo.data;
   const DISCRIMINATOR_SIZE = 8;
+  const GRID_SIZE = data.readUInt16LE(data.length - 6) || DEFAULT_GRID_SIZE;
+  const TOTAL_BLOCKS = GRID_SIZE * GRID_SIZE;
+  console.log(`Grid: ${GRID_SIZE}x${GRID_SIZE}`);
 
   // Read the u16 array (little-endian)
   const blocks: number[] = [];
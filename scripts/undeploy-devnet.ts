@@ -9,6 +9,8 @@ import {
 } from "@solana/spl-token";
 import { execFileSync } from "child_process";
 
+const GRID_ID = new BN(0);
+
 // Helper functions for PDA derivation
 function deriveGridConfig(programId: PublicKey): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
@@ -120,31 +122,43 @@ async function main() {
   // ============================================
   // Step 1: Close all ParcelInfo PDAs
   // ============================================
+  // Claims must have been paused for PURGE_PAUSE_SLOTS; --force purges even while
+  // landowners are still owed rewards
+  const force = process.argv.includes("--force");
+
   console.log("\n--- Step 1: Closing ParcelInfo PDAs ---");
 
+  const openParcels: { parcelId: number; pda: PublicKey }[] = [];
   for (let parcelId = 1; parcelId <= parcelCount; parcelId++) {
     const [parcelInfoPda] = deriveParcelInfo(program.programId, parcelId);
-
-    // Check if account exists
     const accountInfo = await provider.connection.getAccountInfo(parcelInfoPda);
     if (!accountInfo) {
       console.log(`  Parcel ${parcelId}: Already closed or doesn't exist`);
       continue;
     }
+    openParcels.push({ parcelId, pda: parcelInfoPda });
+  }
 
+  // admin_purge_parcels closes up to MAX_PURGE_PARCEL_BATCH accounts per call
+  const batchSize = 24;
+  for (let start = 0; start < openParcels.length; start += batchSize) {
+    const batch = openParcels.slice(start, start + batchSize);
+    const ids = batch.map(({ parcelId }) => parcelId);
     try {
       const sig = await program.methods
-        .adminCloseParcelInfo(parcelId)
+        .adminPurgeParcels(GRID_ID, ids, force)
         .accountsPartial({
           authority: authority.publicKey,
           gridConfig: gridConfigPda,
-          parcelInfo: parcelInfoPda,
         })
+        .remainingAccounts(
+          batch.map(({ pda }) => ({ pubkey: pda, isWritable: true, isSigner: false }))
+        )
         .rpc();
 
-      console.log(`  Parcel ${parcelId}: Closed (${sig.slice(0, 8)}...)`);
+      console.log(`  Parcels ${ids[0]}-${ids[ids.length - 1]}: Closed (${sig.slice(0, 8)}...)`);
     } catch (error: any) {
-      console.error(`  Parcel ${parcelId}: Failed - ${error.message}`);
+      console.error(`  Parcels ${ids[0]}-${ids[ids.length - 1]}: Failed - ${error.message}`);
     }
   }
 
@@ -188,7 +202,7 @@ async function main() {
 
   try {
     const sig = await program.methods
-      .adminPurge()
+      .adminPurge(GRID_ID, force)
       .accountsPartial({
         authority: authority.publicKey,
         gridConfig: gridConfigPda,
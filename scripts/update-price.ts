@@ -3,6 +3,8 @@ import { Program, BN } from "@coral-xyz/anchor";
 import { Billion } from "../target/types/billion";
 import { PublicKey } from "@solana/web3.js";
 
+const GRID_ID = new BN(0);
+
 async function main() {
   // Get new price from command line args
   const newPriceArg = process.argv[2];
@@ -44,7 +46,7 @@ async function main() {
   console.log("\nUpdating price...");
   // eslint-disable-next-line @typescript-eslint/no-explicit-any
   const sig = await (program.methods as any)
-    .updateConfig(newPrice, null, null, null, null, null)
+    .updateConfig(GRID_ID, newPrice, null, null, null, null, null)
     .accounts({
       authority: authority.publicKey,
       gridConfig: gridConfigPda,
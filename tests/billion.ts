@@ -11,6 +11,8 @@ import {
 import {
   TOKEN_2022_PROGRAM_ID,
   createMint,
+  approve,
+  getAccount,
   getOrCreateAssociatedTokenAccount,
   mintTo,
 } from "@solana/spl-token";
@@ -22,7 +24,10 @@ const MPL_CORE_PROGRAM_ID = new PublicKey(
 
 // Grid constants (must match Rust)
 const GRID_SIZE = 100;
-const BLOCK_MAP_SIZE = 8 + (2 * 10000) + 1 + 7; // 20016 bytes (discriminator + blocks + bump + padding)
+// 20016 bytes (discriminator + blocks + bump, frozen, grid size, padding)
+const BLOCK_MAP_SIZE = 8 + (2 * GRID_SIZE * GRID_SIZE) + 8;
+// Grid 0 derives its PDAs exactly as the single-grid layout did
+const GRID_ID = new BN(0);
 
 // Helper functions for PDA derivation
 function deriveGridConfig(programId: PublicKey): [PublicKey, number] {
@@ -48,6 +53,62 @@ function deriveParcelInfo(parcelId: number, programId: PublicKey): [PublicKey, n
   );
 }
 
+function deriveReferrerAccount(referrer: PublicKey, programId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("referrer"), referrer.toBuffer()],
+    programId
+  );
+}
+
+function deriveReferralVault(gridConfig: PublicKey, programId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("referral_vault"), gridConfig.toBuffer()],
+    programId
+  );
+}
+
+function deriveStakeAccount(parcelId: number, programId: PublicKey): [PublicKey, number] {
+  const parcelIdBuffer = Buffer.alloc(2);
+  parcelIdBuffer.writeUInt16LE(parcelId);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("stake"), parcelIdBuffer],
+    programId
+  );
+}
+
+function deriveEmissionsVault(gridConfig: PublicKey, programId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("emissions_vault"), gridConfig.toBuffer()],
+    programId
+  );
+}
+
+function deriveHarbergerDistrict(programId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("harberger_district")], programId);
+}
+
+function deriveHookConfig(programId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("hook_config")], programId);
+}
+
+function deriveParcelValuation(parcelId: number, programId: PublicKey): [PublicKey, number] {
+  const parcelIdBuffer = Buffer.alloc(2);
+  parcelIdBuffer.writeUInt16LE(parcelId);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("valuation"), parcelIdBuffer],
+    programId
+  );
+}
+
+function deriveAsset(gridConfig: PublicKey, parcelId: number, programId: PublicKey): [PublicKey, number] {
+  const parcelIdBuffer = Buffer.alloc(2);
+  parcelIdBuffer.writeUInt16LE(parcelId);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("asset"), gridConfig.toBuffer(), parcelIdBuffer],
+    programId
+  );
+}
+
 // Helper to calculate ring (must match Rust logic)
 // Ring 1 = outer (corners, unlocks first), Ring 10 = center (unlocks last)
 function getRing(x: number, y: number): number {
@@ -176,6 +237,11 @@ describe("billion", () => {
       tokenProgram: TOKEN_2022_PROGRAM_ID,
       associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
+      hookConfig: deriveHookConfig(program.programId)[0],
+      referrerAccount: null,
+      referralVault: null,
+      charityRegistry: null,
+      charityTokenAccount: null,
     };
   }
 
@@ -309,7 +375,7 @@ describe("billion", () => {
 
       // Step 2: Initialize the BlockMap via program instruction
       const createBlockMapIx = await program.methods
-        .createBlockMap()
+        .createBlockMap(GRID_SIZE)
         .accounts({
           payer: authority.publicKey,
           blockMap: blockMapKeypair.publicKey,
@@ -324,7 +390,7 @@ describe("billion", () => {
 
       // Step 3: Initialize the grid config
       await program.methods
-        .initialize(pricePerBlock, ringThresholds, uriBase, landOwnersRewardShareBps)
+        .initialize(GRID_ID, pricePerBlock, ringThresholds, uriBase, landOwnersRewardShareBps)
         .accounts({
           authority: authority.publicKey,
           tokenMint,
@@ -379,7 +445,7 @@ describe("billion", () => {
 
       // Update config with collection
       await program.methods
-        .updateConfig(newPrice, newThresholds, newUri, true, collectionPubkey, null)
+        .updateConfig(GRID_ID, newPrice, newThresholds, newUri, true, collectionPubkey, null)
         .accounts({
           authority: authority.publicKey,
           gridConfig: gridConfigPda,
@@ -394,7 +460,7 @@ describe("billion", () => {
 
       // Reset to original values for subsequent tests (keeping collection set)
       await program.methods
-        .updateConfig(pricePerBlock, ringThresholds, uriBase, true, null, null)
+        .updateConfig(GRID_ID, pricePerBlock, ringThresholds, uriBase, true, null, null)
         .accounts({
           authority: authority.publicKey,
           gridConfig: gridConfigPda,
@@ -411,7 +477,7 @@ describe("billion", () => {
 
       // Mint at outer edge (ring 1) - x=5, y=5 (distance 45 from center)
       await program.methods
-        .adminMint(5, 5, 1, 1)
+        .adminMint(GRID_ID, 5, 5, 1, 1, null, false, false, false)
         .accounts(accounts)
         .signers([asset])
         .rpc();
@@ -437,7 +503,7 @@ describe("billion", () => {
 
       // Claim at outer edge - x=4, y=4 (ring 1, distance 46 from center)
       await program.methods
-        .claimParcel(4, 4, 1, 1)
+        .claimParcel(GRID_ID, 4, 4, 1, 1, false, null, 0)
         .accounts(accounts)
         .signers([user.keypair, asset])
         .rpc();
@@ -466,7 +532,7 @@ describe("billion", () => {
 
       // Claim 3x2 at x=0, y=0 (ring 1 - outer corner)
       await program.methods
-        .claimParcel(0, 0, 3, 2)
+        .claimParcel(GRID_ID, 0, 0, 3, 2, false, null, 0)
         .accounts(accounts)
         .signers([user.keypair, asset])
         .rpc();
@@ -499,7 +565,7 @@ describe("billion", () => {
       const accounts1 = await buildClaimAccounts(user1.keypair, user1.tokenAccount, asset1);
 
       await program.methods
-        .claimParcel(97, 97, 1, 1)
+        .claimParcel(GRID_ID, 97, 97, 1, 1, false, null, 0)
         .accounts(accounts1)
         .signers([user1.keypair, asset1])
         .rpc();
@@ -510,7 +576,7 @@ describe("billion", () => {
       const accounts2 = await buildClaimAccounts(user2.keypair, user2.tokenAccount, asset2);
 
       await program.methods
-        .claimParcel(98, 97, 1, 1)
+        .claimParcel(GRID_ID, 98, 97, 1, 1, false, null, 0)
         .accounts(accounts2)
         .signers([user2.keypair, asset2])
         .rpc();
@@ -521,6 +587,44 @@ describe("billion", () => {
       expect(blockMap.blocks[97 * 100 + 98]).to.equal(parcelId2);
       expect(parcelId2).to.equal(parcelId1 + 1);
     });
+
+    it("7. Claim a parcel with a PDA asset (no asset keypair)", async () => {
+      const user = await createTestUser(100_000_000);
+
+      const parcelId = await getNextParcelId();
+      const [assetPda] = deriveAsset(gridConfigPda, parcelId, program.programId);
+      const [parcelInfoPda] = deriveParcelInfo(parcelId, program.programId);
+
+      await program.methods
+        .claimParcelPda(GRID_ID, 99, 20, 1, 1)
+        .accounts({
+          claimer: user.keypair.publicKey,
+          gridConfig: gridConfigPda,
+          blockMap: blockMapPubkey,
+          tokenMint,
+          claimerTokenAccount: user.tokenAccount,
+          landBuyRewardPool: landBuyRewardPoolPda,
+          parcelInfo: parcelInfoPda,
+          asset: assetPda,
+          collection: collectionPubkey,
+          mplCoreProgram: MPL_CORE_PROGRAM_ID,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          hookConfig: deriveHookConfig(program.programId)[0],
+        })
+        .signers([user.keypair])
+        .rpc();
+
+      const parcelInfo = await program.account.parcelInfo.fetch(parcelInfoPda);
+      expect(parcelInfo.asset.toString()).to.equal(assetPda.toString());
+
+      const assetAccount = await provider.connection.getAccountInfo(assetPda);
+      expect(assetAccount.owner.toString()).to.equal(MPL_CORE_PROGRAM_ID.toString());
+
+      const blockMap = await program.account.blockMap.fetch(blockMapPubkey);
+      expect(blockMap.blocks[20 * 100 + 99]).to.equal(parcelId);
+    });
   });
 
   // ============================================
@@ -537,7 +641,7 @@ describe("billion", () => {
       const accounts = await buildClaimAccounts(user.keypair, user.tokenAccount, testAsset);
 
       await program.methods
-        .claimParcel(3, 3, 1, 1)  // Ring 1 position (outer corner, distance 47 from center)
+        .claimParcel(GRID_ID, 3, 3, 1, 1, false, null, 0)  // Ring 1 position (outer corner, distance 47 from center)
         .accounts(accounts)
         .signers([user.keypair, testAsset])
         .rpc();
@@ -545,7 +649,7 @@ describe("billion", () => {
 
     it("1. Authority can update parcel name", async () => {
       await program.methods
-        .updateParcelMetadata("New Parcel Name", null)
+        .updateParcelMetadata(GRID_ID, "New Parcel Name", null)
         .accounts({
           authority: authority.publicKey,
           gridConfig: gridConfigPda,
@@ -562,7 +666,7 @@ describe("billion", () => {
 
     it("2. Authority can update parcel URI", async () => {
       await program.methods
-        .updateParcelMetadata(null, "https://newuri.com/parcel/1")
+        .updateParcelMetadata(GRID_ID, null, "https://newuri.com/parcel/1")
         .accounts({
           authority: authority.publicKey,
           gridConfig: gridConfigPda,
@@ -576,7 +680,7 @@ describe("billion", () => {
 
     it("3. Authority can update both name and URI", async () => {
       await program.methods
-        .updateParcelMetadata("Updated Name", "https://updated.com/parcel/1")
+        .updateParcelMetadata(GRID_ID, "Updated Name", "https://updated.com/parcel/1")
         .accounts({
           authority: authority.publicKey,
           gridConfig: gridConfigPda,
@@ -594,7 +698,7 @@ describe("billion", () => {
 
       try {
         await program.methods
-          .updateParcelMetadata("Hacked Name", null)
+          .updateParcelMetadata(GRID_ID, "Hacked Name", null)
           .accounts({
             authority: nonAdmin.publicKey,
             gridConfig: gridConfigPda,
@@ -624,7 +728,7 @@ describe("billion", () => {
       const accounts1 = await buildClaimAccounts(user.keypair, user.tokenAccount, asset1);
 
       await program.methods
-        .claimParcel(2, 2, 1, 1)
+        .claimParcel(GRID_ID, 2, 2, 1, 1, false, null, 0)
         .accounts(accounts1)
         .signers([user.keypair, asset1])
         .rpc();
@@ -635,7 +739,7 @@ describe("billion", () => {
 
       try {
         await program.methods
-          .claimParcel(2, 2, 1, 1)
+          .claimParcel(GRID_ID, 2, 2, 1, 1, false, null, 0)
           .accounts(accounts2)
           .signers([user.keypair, asset2])
           .rpc();
@@ -656,7 +760,7 @@ describe("billion", () => {
 
       try {
         await program.methods
-          .claimParcel(50, 50, 1, 1)
+          .claimParcel(GRID_ID, 50, 50, 1, 1, false, null, 0)
           .accounts(accounts)
           .signers([user.keypair, asset])
           .rpc();
@@ -674,7 +778,7 @@ describe("billion", () => {
 
       try {
         await program.methods
-          .claimParcel(99, 50, 2, 1)
+          .claimParcel(GRID_ID, 99, 50, 2, 1, false, null, 0)
           .accounts(accounts)
           .signers([user.keypair, asset])
           .rpc();
@@ -692,7 +796,7 @@ describe("billion", () => {
 
       try {
         await program.methods
-          .claimParcel(50, 50, 0, 1)
+          .claimParcel(GRID_ID, 50, 50, 0, 1, false, null, 0)
           .accounts(accounts)
           .signers([user.keypair, asset])
           .rpc();
@@ -710,7 +814,7 @@ describe("billion", () => {
 
       try {
         await program.methods
-          .claimParcel(50, 50, 1, 0)
+          .claimParcel(GRID_ID, 50, 50, 1, 0, false, null, 0)
           .accounts(accounts)
           .signers([user.keypair, asset])
           .rpc();
@@ -729,7 +833,7 @@ describe("billion", () => {
 
       try {
         await program.methods
-          .claimParcel(0, 10, 1, 1)  // Ring 1: distance 50 from center (unique position)
+          .claimParcel(GRID_ID, 0, 10, 1, 1, false, null, 0)  // Ring 1: distance 50 from center (unique position)
           .accounts(accounts)
           .signers([user.keypair, asset])
           .rpc();
@@ -746,7 +850,7 @@ describe("billion", () => {
 
       try {
         await program.methods
-          .updateConfig(new BN(5_000_000), null, null, null, null, null)
+          .updateConfig(GRID_ID, new BN(5_000_000), null, null, null, null, null)
           .accounts({
             authority: nonAdmin.publicKey,
             gridConfig: gridConfigPda,
@@ -763,7 +867,7 @@ describe("billion", () => {
     it("7. SeedingDisabled - Admin mint after disabling seeding", async () => {
       // Disable seeding
       await program.methods
-        .updateConfig(null, null, null, false, null, null)
+        .updateConfig(GRID_ID, null, null, null, false, null, null)
         .accounts({
           authority: authority.publicKey,
           gridConfig: gridConfigPda,
@@ -778,7 +882,7 @@ describe("billion", () => {
 
       try {
         await program.methods
-          .adminMint(6, 6, 1, 1)  // Ring 1 outer position
+          .adminMint(GRID_ID, 6, 6, 1, 1, null, false, false, false)  // Ring 1 outer position
           .accounts(accounts)
           .signers([asset])
           .rpc();
@@ -790,7 +894,7 @@ describe("billion", () => {
 
       // Re-enable seeding for subsequent tests
       await program.methods
-        .updateConfig(null, null, null, true, null, null)
+        .updateConfig(GRID_ID, null, null, null, true, null, null)
         .accounts({
           authority: authority.publicKey,
           gridConfig: gridConfigPda,
@@ -827,7 +931,7 @@ describe("billion", () => {
       // So let's try a different outer corner that hasn't been claimed
       try {
         await program.methods
-          .claimParcel(99, 0, 1, 1)  // Different corner, also ring 1
+          .claimParcel(GRID_ID, 99, 0, 1, 1, false, null, 0)  // Different corner, also ring 1
           .accounts(accounts)
           .signers([user.keypair, asset])
           .rpc();
@@ -846,7 +950,7 @@ describe("billion", () => {
 
       // This should succeed because (99,99) is ring 1 (outer) - unlocked
       await program.methods
-        .claimParcel(99, 98, 1, 1)  // Near corner, ring 1
+        .claimParcel(GRID_ID, 99, 98, 1, 1, false, null, 0)  // Near corner, ring 1
         .accounts(accounts)
         .signers([user.keypair, asset])
         .rpc();
@@ -868,7 +972,7 @@ describe("billion", () => {
       const accounts = await buildClaimAccounts(user.keypair, user.tokenAccount, asset);
 
       await program.methods
-        .claimParcel(5, 0, 1, 1) // x=5, y=0: dx=45, dy=50, distance=50 -> ring 1
+        .claimParcel(GRID_ID, 5, 0, 1, 1, false, null, 0) // x=5, y=0: dx=45, dy=50, distance=50 -> ring 1
         .accounts(accounts)
         .signers([user.keypair, asset])
         .rpc();
@@ -886,7 +990,7 @@ describe("billion", () => {
       const accounts1 = await buildClaimAccounts(user.keypair, user.tokenAccount, asset1);
 
       await program.methods
-        .claimParcel(95, 0, 2, 2) // Claims (95,0), (96,0), (95,1), (96,1) - all ring 1
+        .claimParcel(GRID_ID, 95, 0, 2, 2, false, null, 0) // Claims (95,0), (96,0), (95,1), (96,1) - all ring 1
         .accounts(accounts1)
         .signers([user.keypair, asset1])
         .rpc();
@@ -898,7 +1002,7 @@ describe("billion", () => {
       try {
         // This overlaps with the previous claim at (96, 1)
         await program.methods
-          .claimParcel(96, 1, 2, 2)
+          .claimParcel(GRID_ID, 96, 1, 2, 2, false, null, 0)
           .accounts(accounts2)
           .signers([user.keypair, asset2])
           .rpc();
@@ -916,7 +1020,7 @@ describe("billion", () => {
       // Update only price
       const newPrice = new BN(3_000_000);
       await program.methods
-        .updateConfig(newPrice, null, null, null, null, null)
+        .updateConfig(GRID_ID, newPrice, null, null, null, null, null)
         .accounts({
           authority: authority.publicKey,
           gridConfig: gridConfigPda,
@@ -936,7 +1040,7 @@ describe("billion", () => {
 
       // Restore original price
       await program.methods
-        .updateConfig(pricePerBlock, null, null, null, null, null)
+        .updateConfig(GRID_ID, pricePerBlock, null, null, null, null, null)
         .accounts({
           authority: authority.publicKey,
           gridConfig: gridConfigPda,
@@ -957,7 +1061,7 @@ describe("billion", () => {
 
       // Admin mint should succeed despite ring being locked
       await program.methods
-        .adminMint(50, 51, 1, 1)
+        .adminMint(GRID_ID, 50, 51, 1, 1, null, false, false, false)
         .accounts(accounts)
         .signers([asset])
         .rpc();
@@ -979,7 +1083,7 @@ describe("billion", () => {
 
       // Claim 2x2 = 4 blocks in ring 1 (outer area)
       await program.methods
-        .claimParcel(93, 0, 2, 2)
+        .claimParcel(GRID_ID, 93, 0, 2, 2, false, null, 0)
         .accounts(accounts)
         .signers([user.keypair, asset])
         .rpc();
@@ -1019,7 +1123,7 @@ describe("billion", () => {
       try {
         // (0, 99) is ring 1 (outer), but height=2 goes to y=100 which is out of bounds
         await program.methods
-          .claimParcel(0, 99, 1, 2)
+          .claimParcel(GRID_ID, 0, 99, 1, 2, false, null, 0)
           .accounts(accounts)
           .signers([user.keypair, asset])
           .rpc();
@@ -1040,7 +1144,7 @@ describe("billion", () => {
 
       try {
         await program.methods
-          .adminMint(7, 7, 1, 1)  // Ring 1 outer position
+          .adminMint(GRID_ID, 7, 7, 1, 1, null, false, false, false)  // Ring 1 outer position
           .accounts({
             authority: nonAdmin.publicKey,
             recipient: recipient.publicKey,
@@ -1073,7 +1177,7 @@ describe("billion", () => {
 
       // Claim 4x2 = 8 blocks in ring 1 area (outer corner - unique position)
       await program.methods
-        .claimParcel(80, 0, 4, 2)
+        .claimParcel(GRID_ID, 80, 0, 4, 2, false, null, 0)
         .accounts(accounts)
         .signers([user.keypair, asset])
         .rpc();
@@ -1107,7 +1211,7 @@ describe("billion", () => {
 
       // Transfer collection authority
       await program.methods
-        .adminTransferNftCollectionAuthority()
+        .adminTransferNftCollectionAuthority(GRID_ID)
         .accounts({
           authority: authority.publicKey,
           gridConfig: gridConfigPda,
@@ -1137,7 +1241,7 @@ describe("billion", () => {
 
       try {
         await program.methods
-          .adminTransferNftCollectionAuthority()
+          .adminTransferNftCollectionAuthority(GRID_ID)
           .accounts({
             authority: nonAdmin.publicKey,
             gridConfig: gridConfigPda,
@@ -1160,7 +1264,7 @@ describe("billion", () => {
 
       try {
         await program.methods
-          .adminTransferNftCollectionAuthority()
+          .adminTransferNftCollectionAuthority(GRID_ID)
           .accounts({
             authority: authority.publicKey,
             gridConfig: gridConfigPda,
@@ -1205,7 +1309,7 @@ describe("billion", () => {
 
       // Update grid config to use new collection
       await program.methods
-        .updateConfig(null, null, null, null, testCollectionPubkey, null)
+        .updateConfig(GRID_ID, null, null, null, null, testCollectionPubkey, null)
         .accounts({
           authority: authority.publicKey,
           gridConfig: gridConfigPda,
@@ -1217,7 +1321,7 @@ describe("billion", () => {
       await airdropSol(tempAuthority.publicKey);
 
       await program.methods
-        .adminTransferNftCollectionAuthority()
+        .adminTransferNftCollectionAuthority(GRID_ID)
         .accounts({
           authority: authority.publicKey,
           gridConfig: gridConfigPda,
@@ -1234,7 +1338,7 @@ describe("billion", () => {
 
       // Restore original collection for other tests
       await program.methods
-        .updateConfig(null, null, null, null, collectionPubkey, null)
+        .updateConfig(GRID_ID, null, null, null, null, collectionPubkey, null)
         .accounts({
           authority: authority.publicKey,
           gridConfig: gridConfigPda,
@@ -1242,4 +1346,558 @@ describe("billion", () => {
         .rpc();
     });
   });
+
+  // ============================================
+  // VIEW INSTRUCTION TESTS
+  // ============================================
+  describe("View Instructions", () => {
+    it("1. get_grid_state returns decoded grid state", async () => {
+      const config = await program.account.gridConfig.fetch(gridConfigPda);
+
+      const state = await program.methods
+        .getGridState(GRID_ID)
+        .accounts({ gridConfig: gridConfigPda })
+        .view();
+
+      expect(state.pricePerBlock.toString()).to.equal(config.pricePerBlock.toString());
+      expect(state.totalBurned.toString()).to.equal(config.totalBurned.toString());
+      expect(state.totalClaimedBlocks).to.equal(config.totalClaimedBlocks);
+      expect(state.nextParcelId).to.equal(config.nextParcelId);
+      expect(state.seedingEnabled).to.equal(config.seedingEnabled);
+      expect(state.unlockedRing).to.be.at.least(1);
+    });
+
+    it("2. get_parcel returns parcel info and current owner", async () => {
+      const user = await createTestUser(100_000_000);
+      const parcelId = await getNextParcelId();
+      const asset = Keypair.generate();
+      const accounts = await buildClaimAccounts(user.keypair, user.tokenAccount, asset);
+
+      await program.methods
+        .claimParcel(GRID_ID, 99, 21, 1, 1, false, null, 0)
+        .accounts(accounts)
+        .signers([user.keypair, asset])
+        .rpc();
+
+      const [parcelInfoPda] = deriveParcelInfo(parcelId, program.programId);
+      const parcel = await program.methods
+        .getParcel(GRID_ID, parcelId)
+        .accounts({ parcelInfo: parcelInfoPda, asset: asset.publicKey })
+        .view();
+
+      expect(parcel.parcelId).to.equal(parcelId);
+      expect(parcel.asset.toString()).to.equal(asset.publicKey.toString());
+      expect(parcel.owner.toString()).to.equal(user.keypair.publicKey.toString());
+      expect(parcel.x).to.equal(99);
+      expect(parcel.y).to.equal(21);
+      expect(parcel.blockCount).to.equal(1);
+    });
+
+    it("3. get_program_info reports version, schema and feature flags", async () => {
+      const info = await program.methods
+        .getProgramInfo(GRID_ID)
+        .accounts({ gridConfig: gridConfigPda })
+        .view();
+
+      expect(info.version).to.match(/^\d+\.\d+\.\d+/);
+      expect(info.schemaVersion).to.equal(1);
+      // PDA assets are always supported
+      expect(info.featureFlags.toNumber() & 1).to.equal(1);
+    });
+
+    it("4. claim_parcel dry run returns a preview and persists nothing", async () => {
+      const user = await createTestUser(100_000_000);
+      const parcelId = await getNextParcelId();
+      const configBefore = await program.account.gridConfig.fetch(gridConfigPda);
+      const asset = Keypair.generate();
+      const accounts = await buildClaimAccounts(user.keypair, user.tokenAccount, asset);
+
+      const tx = await program.methods
+        .claimParcel(GRID_ID, 99, 22, 2, 1, true, null, 0)
+        .accounts(accounts)
+        .transaction();
+      tx.feePayer = user.keypair.publicKey;
+      tx.recentBlockhash = (await provider.connection.getLatestBlockhash()).blockhash;
+      const simulation = await provider.connection.simulateTransaction(tx, [user.keypair, asset]);
+
+      // Always fails with DryRunSuccess (6017) so nothing is written
+      expect(JSON.stringify(simulation.value.err)).to.contain("6017");
+      const [data] = simulation.value.returnData.data;
+      const preview = Buffer.from(data, "base64");
+      // ClaimPreview starts with parcel_id: u16, block_count: u32, total_cost: u64
+      expect(preview.readUInt16LE(0)).to.equal(parcelId);
+      expect(preview.readUInt32LE(2)).to.equal(2);
+      expect(preview.readBigUInt64LE(6).toString()).to.equal(
+        configBefore.pricePerBlock.muln(2).toString()
+      );
+
+      const configAfter = await program.account.gridConfig.fetch(gridConfigPda);
+      expect(configAfter.nextParcelId).to.equal(configBefore.nextParcelId);
+      expect(configAfter.totalBurned.toString()).to.equal(configBefore.totalBurned.toString());
+    });
+  });
+
+  // ============================================
+  // REFERRAL TESTS
+  // ============================================
+  describe("Referrals", () => {
+    const referralBps = 500; // 5%
+    let referrer: { keypair: Keypair; tokenAccount: PublicKey };
+    let referrerAccountPda: PublicKey;
+    let referralVaultPda: PublicKey;
+
+    before(async () => {
+      referrer = await createTestUser(0);
+      [referrerAccountPda] = deriveReferrerAccount(referrer.keypair.publicKey, program.programId);
+      [referralVaultPda] = deriveReferralVault(gridConfigPda, program.programId);
+    });
+
+    it("1. Admin initializes the referral vault and sets referral_bps", async () => {
+      await program.methods
+        .initReferralVault(GRID_ID)
+        .accounts({
+          authority: authority.publicKey,
+          gridConfig: gridConfigPda,
+          tokenMint,
+          referralVault: referralVaultPda,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      await program.methods
+        .setReferralBps(GRID_ID, referralBps)
+        .accounts({ authority: authority.publicKey, gridConfig: gridConfigPda })
+        .rpc();
+
+      const config = await program.account.gridConfig.fetch(gridConfigPda);
+      expect(config.referralBps).to.equal(referralBps);
+      expect(config.referralVault.toString()).to.equal(referralVaultPda.toString());
+    });
+
+    it("2. Referred claim credits the referrer's accrued balance", async () => {
+      await program.methods
+        .registerReferrer(GRID_ID)
+        .accounts({
+          referrer: referrer.keypair.publicKey,
+          referrerAccount: referrerAccountPda,
+          referredBy: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([referrer.keypair])
+        .rpc();
+
+      const buyer = await createTestUser(100_000_000);
+      const asset = Keypair.generate();
+      const accounts = await buildClaimAccounts(buyer.keypair, buyer.tokenAccount, asset);
+
+      await program.methods
+        .claimParcel(GRID_ID, 99, 23, 2, 1, false, null, 0)
+        .accounts({ ...accounts, referrerAccount: referrerAccountPda, referralVault: referralVaultPda })
+        .signers([buyer.keypair, asset])
+        .rpc();
+
+      const config = await program.account.gridConfig.fetch(gridConfigPda);
+      const expected = config.pricePerBlock.muln(2).muln(referralBps).divn(10000);
+      const account = await program.account.referrerAccount.fetch(referrerAccountPda);
+      expect(account.accrued.toString()).to.equal(expected.toString());
+      expect(account.totalEarned.toString()).to.equal(expected.toString());
+      expect(account.referralCount).to.equal(1);
+      expect(account.referredBlocks).to.equal(2);
+    });
+
+    it("3. SelfReferral - Referrer cannot refer their own claim", async () => {
+      const asset = Keypair.generate();
+      const accounts = await buildClaimAccounts(referrer.keypair, referrer.tokenAccount, asset);
+
+      try {
+        await program.methods
+          .claimParcel(GRID_ID, 99, 25, 1, 1, false, null, 0)
+          .accounts({ ...accounts, referrerAccount: referrerAccountPda, referralVault: referralVaultPda })
+          .signers([referrer.keypair, asset])
+          .rpc();
+        expect.fail("Expected SelfReferral error");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("SelfReferral");
+      }
+    });
+
+    it("4. Referrer withdraws accrued earnings", async () => {
+      const before = await program.account.referrerAccount.fetch(referrerAccountPda);
+
+      await program.methods
+        .claimReferralEarnings(GRID_ID)
+        .accounts({
+          referrer: referrer.keypair.publicKey,
+          gridConfig: gridConfigPda,
+          referrerAccount: referrerAccountPda,
+          referralVault: referralVaultPda,
+          referrerTokenAccount: referrer.tokenAccount,
+          tokenMint,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
+        })
+        .signers([referrer.keypair])
+        .rpc();
+
+      const balance = await getAccount(
+        provider.connection,
+        referrer.tokenAccount,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      expect(balance.amount.toString()).to.equal(before.accrued.toString());
+      const after = await program.account.referrerAccount.fetch(referrerAccountPda);
+      expect(after.accrued.toNumber()).to.equal(0);
+      expect(after.totalEarned.toString()).to.equal(before.totalEarned.toString());
+    });
+  });
+
+  // ============================================
+  // STAKING TESTS
+  // ============================================
+  describe("Staking", () => {
+    const emissionRate = new BN(10); // per block per second
+    let staker: { keypair: Keypair; tokenAccount: PublicKey };
+    let stakedParcelId: number;
+    let stakedAsset: Keypair;
+    let emissionsVaultPda: PublicKey;
+
+    function stakingAccounts() {
+      const [parcelInfoPda] = deriveParcelInfo(stakedParcelId, program.programId);
+      const [stakeAccountPda] = deriveStakeAccount(stakedParcelId, program.programId);
+      return {
+        staker: staker.keypair.publicKey,
+        gridConfig: gridConfigPda,
+        parcelInfo: parcelInfoPda,
+        stakeAccount: stakeAccountPda,
+        asset: stakedAsset.publicKey,
+        collection: collectionPubkey,
+        emissionsVault: emissionsVaultPda,
+        stakerTokenAccount: staker.tokenAccount,
+        tokenMint,
+        mplCoreProgram: MPL_CORE_PROGRAM_ID,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      };
+    }
+
+    before(async () => {
+      [emissionsVaultPda] = deriveEmissionsVault(gridConfigPda, program.programId);
+
+      await program.methods
+        .initEmissionsVault(GRID_ID)
+        .accounts({
+          authority: authority.publicKey,
+          gridConfig: gridConfigPda,
+          tokenMint,
+          emissionsVault: emissionsVaultPda,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      await program.methods
+        .setEmissionRate(GRID_ID, emissionRate)
+        .accounts({ authority: authority.publicKey, gridConfig: gridConfigPda })
+        .rpc();
+      await mintTo(
+        provider.connection,
+        authority.payer,
+        tokenMint,
+        emissionsVaultPda,
+        authority.payer,
+        1_000_000_000,
+        [],
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      staker = await createTestUser(100_000_000);
+      stakedParcelId = await getNextParcelId();
+      stakedAsset = Keypair.generate();
+      const accounts = await buildClaimAccounts(staker.keypair, staker.tokenAccount, stakedAsset);
+      await program.methods
+        .claimParcel(GRID_ID, 99, 27, 1, 1, false, null, 0)
+        .accounts(accounts)
+        .signers([staker.keypair, stakedAsset])
+        .rpc();
+    });
+
+    it("1. Owner stakes a parcel", async () => {
+      await program.methods
+        .stakeParcel(GRID_ID, stakedParcelId)
+        .accounts(stakingAccounts())
+        .signers([staker.keypair])
+        .rpc();
+
+      const stake = await program.account.stakeAccount.fetch(stakingAccounts().stakeAccount);
+      expect(stake.staker.toString()).to.equal(staker.keypair.publicKey.toString());
+      expect(stake.parcelId).to.equal(stakedParcelId);
+      expect(stake.stakedAt.toNumber()).to.be.greaterThan(0);
+    });
+
+    it("2. Staked parcel accrues emissions over time", async () => {
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+      const before = await getAccount(provider.connection, staker.tokenAccount, undefined, TOKEN_2022_PROGRAM_ID);
+
+      await program.methods
+        .claimStakingRewards(GRID_ID, stakedParcelId)
+        .accounts(stakingAccounts())
+        .signers([staker.keypair])
+        .rpc();
+
+      const after = await getAccount(provider.connection, staker.tokenAccount, undefined, TOKEN_2022_PROGRAM_ID);
+      expect(after.amount > before.amount).to.equal(true);
+    });
+
+    it("3. Unstake thaws the asset and closes the stake account", async () => {
+      await program.methods
+        .unstakeParcel(GRID_ID, stakedParcelId)
+        .accounts(stakingAccounts())
+        .signers([staker.keypair])
+        .rpc();
+
+      const stake = await provider.connection.getAccountInfo(stakingAccounts().stakeAccount);
+      expect(stake).to.be.null;
+    });
+  });
+
+  // ============================================
+  // HARBERGER DISTRICT TESTS
+  // ============================================
+  describe("Harberger District", () => {
+    const valuation = new BN(10_000_000);
+    let owner: { keypair: Keypair; tokenAccount: PublicKey };
+    let buyer: { keypair: Keypair; tokenAccount: PublicKey };
+    let parcelId: number;
+    let asset: Keypair;
+    let districtPda: PublicKey;
+
+    function valuationAccounts() {
+      const [parcelInfoPda] = deriveParcelInfo(parcelId, program.programId);
+      const [parcelValuationPda] = deriveParcelValuation(parcelId, program.programId);
+      return {
+        gridConfig: gridConfigPda,
+        harbergerDistrict: districtPda,
+        parcelInfo: parcelInfoPda,
+        parcelValuation: parcelValuationPda,
+        asset: asset.publicKey,
+      };
+    }
+
+    before(async () => {
+      [districtPda] = deriveHarbergerDistrict(program.programId);
+
+      // Column x=99, rows 28..30
+      await program.methods
+        .configureHarbergerDistrict(GRID_ID, 99, 28, 1, 3, 100, new BN(86_400), true)
+        .accounts({
+          authority: authority.publicKey,
+          gridConfig: gridConfigPda,
+          harbergerDistrict: districtPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      owner = await createTestUser(100_000_000);
+      buyer = await createTestUser(50_000_000);
+      parcelId = await getNextParcelId();
+      asset = Keypair.generate();
+      const accounts = await buildClaimAccounts(owner.keypair, owner.tokenAccount, asset);
+      await program.methods
+        .claimParcel(GRID_ID, 99, 28, 1, 1, false, null, 0)
+        .accounts(accounts)
+        .signers([owner.keypair, asset])
+        .rpc();
+      await approve(
+        provider.connection,
+        authority.payer,
+        owner.tokenAccount,
+        gridConfigPda,
+        owner.keypair,
+        BigInt("18446744073709551615"),
+        [],
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+    });
+
+    it("1. Owner declares a valuation", async () => {
+      await program.methods
+        .setValuation(GRID_ID, parcelId, valuation)
+        .accounts({
+          ...valuationAccounts(),
+          owner: owner.keypair.publicKey,
+          collection: collectionPubkey,
+          mplCoreProgram: MPL_CORE_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([owner.keypair])
+        .rpc();
+
+      const state = await program.account.parcelValuation.fetch(valuationAccounts().parcelValuation);
+      expect(state.valuation.toString()).to.equal(valuation.toString());
+      expect(state.owner.toString()).to.equal(owner.keypair.publicKey.toString());
+    });
+
+    it("2. Anyone can crank pay_tax from the owner's allowance", async () => {
+      const before = await getAccount(provider.connection, owner.tokenAccount, undefined, TOKEN_2022_PROGRAM_ID);
+      await program.methods
+        .payTax(GRID_ID, parcelId)
+        .accounts({
+          ...valuationAccounts(),
+          payer: authority.publicKey,
+          ownerTokenAccount: owner.tokenAccount,
+          landBuyRewardPool: landBuyRewardPoolPda,
+          tokenMint,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc();
+
+      const after = await getAccount(provider.connection, owner.tokenAccount, undefined, TOKEN_2022_PROGRAM_ID);
+      expect((before.amount - after.amount).toString()).to.equal("100000");
+    });
+
+    it("3. Force-buy pays the owner and transfers the asset", async () => {
+      const [stakeAccountPda] = deriveStakeAccount(parcelId, program.programId);
+      const before = await getAccount(provider.connection, owner.tokenAccount, undefined, TOKEN_2022_PROGRAM_ID);
+      await program.methods
+        .forceBuy(GRID_ID, parcelId)
+        .accounts({
+          ...valuationAccounts(),
+          buyer: buyer.keypair.publicKey,
+          stakeAccount: stakeAccountPda,
+          collection: collectionPubkey,
+          previousOwner: owner.keypair.publicKey,
+          buyerTokenAccount: buyer.tokenAccount,
+          previousOwnerTokenAccount: owner.tokenAccount,
+          tokenMint,
+          mplCoreProgram: MPL_CORE_PROGRAM_ID,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyer.keypair])
+        .rpc();
+
+      const after = await getAccount(provider.connection, owner.tokenAccount, undefined, TOKEN_2022_PROGRAM_ID);
+      // The price, plus whatever land-buy rewards the parcel had pending
+      expect(after.amount - before.amount >= BigInt(valuation.toString())).to.be.true;
+      const assetInfo = await provider.connection.getAccountInfo(asset.publicKey);
+      expect(new PublicKey(assetInfo!.data.subarray(1, 33)).toString()).to.equal(buyer.keypair.publicKey.toString());
+    });
+  });
+
+  describe("Claim Hook", () => {
+    const guardian = Keypair.generate();
+    let hookConfigPda: PublicKey;
+
+    before(async () => {
+      [hookConfigPda] = deriveHookConfig(program.programId);
+
+      // Column x=99, row 31 only; the system program rejects the hook call
+      await program.methods
+        .setHook(GRID_ID, SystemProgram.programId, guardian.publicKey, 99, 31, 1, 1, true)
+        .accounts({
+          authority: authority.publicKey,
+          gridConfig: gridConfigPda,
+          hookConfig: hookConfigPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    });
+
+    it("1. Claim in the hook district without the hook program is rejected", async () => {
+      const user = await createTestUser(10_000_000);
+      const asset = Keypair.generate();
+      const accounts = await buildClaimAccounts(user.keypair, user.tokenAccount, asset);
+
+      try {
+        await program.methods
+          .claimParcel(GRID_ID, 99, 31, 1, 1, false, null, 0)
+          .accounts(accounts)
+          .signers([user.keypair, asset])
+          .rpc();
+        expect.fail("Expected HookProgramMismatch error");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("HookProgramMismatch");
+      }
+    });
+
+    it("2. Failing hook aborts the claim", async () => {
+      const user = await createTestUser(10_000_000);
+      const asset = Keypair.generate();
+      const accounts = await buildClaimAccounts(user.keypair, user.tokenAccount, asset);
+
+      let failed = false;
+      try {
+        await program.methods
+          .claimParcel(GRID_ID, 99, 31, 1, 1, false, null, 0)
+          .accounts(accounts)
+          .remainingAccounts([{ pubkey: SystemProgram.programId, isWritable: false, isSigner: false }])
+          .signers([user.keypair, asset])
+          .rpc();
+      } catch {
+        failed = true;
+      }
+      expect(failed).to.equal(true);
+      const blockMap = await program.account.blockMap.fetch(blockMapPubkey);
+      expect(blockMap.blocks[31 * 100 + 99]).to.equal(0);
+    });
+
+    it("3. Guardian disables the hook and claims go through", async () => {
+      await program.methods
+        .disableHook(GRID_ID)
+        .accounts({ signer: guardian.publicKey, gridConfig: gridConfigPda, hookConfig: hookConfigPda })
+        .signers([guardian])
+        .rpc();
+
+      const hook = await program.account.hookConfig.fetch(hookConfigPda);
+      expect(hook.enabled).to.equal(false);
+
+      const user = await createTestUser(10_000_000);
+      const asset = Keypair.generate();
+      const accounts = await buildClaimAccounts(user.keypair, user.tokenAccount, asset);
+      await program.methods
+        .claimParcel(GRID_ID, 99, 31, 1, 1, false, null, 0)
+        .accounts(accounts)
+        .signers([user.keypair, asset])
+        .rpc();
+    });
+  });
+
+  // ============================================
+  // IDL CONSTANT TESTS
+  // ============================================
+  describe("IDL Constants", () => {
+    function idlConst(name: string): string {
+      const constant = program.idl.constants.find((c) => c.name === name);
+      expect(constant, `missing IDL constant ${name}`).to.not.be.undefined;
+      return constant.value;
+    }
+
+    function idlSeed(name: string): string {
+      // Byte string constants are rendered as a Rust debug array, e.g. [103, 114, ...]
+      return Buffer.from(JSON.parse(idlConst(name))).toString();
+    }
+
+    it("1. Seeds match the strings used for PDA derivation", () => {
+      expect(idlSeed("gridConfigSeed")).to.equal("grid_config");
+      expect(idlSeed("parcelInfoSeed")).to.equal("parcel");
+      expect(idlSeed("landBuyRewardPoolSeed")).to.equal("land_buy_reward_pool");
+      expect(idlSeed("assetSeed")).to.equal("asset");
+    });
+
+    it("2. Grid size, ring count, reward scale, and limits are exported", () => {
+      expect(Number(idlConst("gridWidth"))).to.equal(GRID_SIZE);
+      expect(Number(idlConst("ringCount"))).to.equal(10);
+      expect(idlConst("rewardScale")).to.equal("1000000000");
+      expect(Number(idlConst("maxParcelBlocks"))).to.equal(GRID_SIZE * GRID_SIZE);
+      expect(Number(idlConst("maxUriBaseLen"))).to.equal(128);
+      expect(Number(idlConst("maxParcelNameLen"))).to.equal(32);
+      expect(Number(idlConst("maxParcelUriLen"))).to.equal(200);
+      expect(Number(idlConst("eventSchemaVersion"))).to.equal(1);
+    });
+  });
 });
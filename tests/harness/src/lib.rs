@@ -0,0 +1,589 @@
+//! Rust-native fixtures for the billion program, built on solana-program-test.
+//!
+//! The program binary is loaded from `target/deploy/billion.so` (run `anchor build` first)
+//! and Metaplex Core from `tests/fixtures/mpl_core.so`, dumped with:
+//! `solana program dump -um CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d tests/fixtures/mpl_core.so`
+//!
+//! Instructions are built with `billion-client`, so these tests double as its integration coverage.
+
+use std::collections::HashSet;
+
+use anchor_lang::Event;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use billion::errors::BillionError;
+use billion::instructions::claim_parcel::MPL_CORE_ID;
+use billion::state::{
+    BlockMap, Fraction, GridConfig, ParcelInfo, ParcelValuation, ReferrerAccount, StakeAccount, DEFAULT_GRID_SIZE,
+};
+use billion_client::{self as client, GridAccounts, InitializeArgs, UpdateConfigArgs};
+use solana_program_test::{BanksClientError, ProgramTest, ProgramTestContext};
+use spl_token_2022::extension::ExtensionType;
+use solana_sdk::{
+    clock::Clock,
+    instruction::{Instruction, InstructionError},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+    system_instruction,
+    transaction::{Transaction, TransactionError},
+};
+
+pub use billion_client::{find_grid_config, find_parcel_info, find_reward_pool, Rect};
+
+pub const TOKEN_DECIMALS: u8 = 6;
+
+/// Wallet with a funded Token-2022 associated token account
+pub struct TestUser {
+    pub keypair: Keypair,
+    pub token_account: Pubkey,
+}
+
+/// A parcel created by the fixture
+#[derive(Clone, Copy, Debug)]
+pub struct ClaimedParcel {
+    pub parcel_id: u16,
+    pub asset: Pubkey,
+    pub epoch: u16,
+}
+
+/// Builder for a fully initialized grid: mint, BlockMap, GridConfig, reward pool, and Core collection
+pub struct GridFixtureBuilder {
+    args: InitializeArgs,
+    /// Creates the mint with a Token-2022 TransferFee extension charging this many basis points
+    transfer_fee_bps: Option<u16>,
+    grid_size: u16,
+    grid_id: u64,
+}
+
+impl Default for GridFixtureBuilder {
+    fn default() -> Self {
+        Self {
+            args: InitializeArgs {
+                price_per_block: 1_000_000,
+                ring_thresholds: vec![
+                    0,
+                    10_000_000,
+                    50_000_000,
+                    100_000_000,
+                    200_000_000,
+                    400_000_000,
+                    600_000_000,
+                    800_000_000,
+                    900_000_000,
+                    1_000_000_000,
+                ],
+                uri_base: "https://example.com/parcel/".to_string(),
+                land_owners_reward_share_bps: 2000,
+                max_parcel_blocks: 0,
+            },
+            transfer_fee_bps: None,
+            grid_size: DEFAULT_GRID_SIZE,
+            grid_id: 0,
+        }
+    }
+}
+
+impl GridFixtureBuilder {
+    pub fn price_per_block(mut self, price_per_block: u64) -> Self {
+        self.args.price_per_block = price_per_block;
+        self
+    }
+
+    /// Thresholds for the first rings; the rings after them never unlock
+    pub fn ring_thresholds(mut self, mut ring_thresholds: Vec<u64>) -> Self {
+        ring_thresholds.resize(billion::constants::RING_COUNT as usize, u64::MAX);
+        self.args.ring_thresholds = ring_thresholds;
+        self
+    }
+
+    /// Splits the grid into `ring_count` rings, keeping that many of the thresholds set so far
+    pub fn ring_count(mut self, ring_count: u8) -> Self {
+        self.args.ring_thresholds.resize(ring_count as usize, u64::MAX);
+        self
+    }
+
+    pub fn uri_base(mut self, uri_base: &str) -> Self {
+        self.args.uri_base = uri_base.to_string();
+        self
+    }
+
+    pub fn land_owners_reward_share_bps(mut self, bps: u16) -> Self {
+        self.args.land_owners_reward_share_bps = bps;
+        self
+    }
+
+    pub fn max_parcel_blocks(mut self, max_parcel_blocks: u16) -> Self {
+        self.args.max_parcel_blocks = max_parcel_blocks;
+        self
+    }
+
+    pub fn transfer_fee_bps(mut self, bps: u16) -> Self {
+        self.transfer_fee_bps = Some(bps);
+        self
+    }
+
+    /// Width and height of the grid's BlockMap, and of every epoch's after it
+    pub fn grid_size(mut self, grid_size: u16) -> Self {
+        self.grid_size = grid_size;
+        self
+    }
+
+    /// Grid the fixture initializes and builds every instruction for
+    pub fn grid_id(mut self, grid_id: u64) -> Self {
+        self.grid_id = grid_id;
+        self
+    }
+
+    /// Arguments `build` passes to initialize
+    pub fn initialize_args(&self) -> InitializeArgs {
+        self.args.clone()
+    }
+
+    pub async fn build(self) -> GridFixture {
+        self.try_build().await.unwrap()
+    }
+
+    /// Like `build`, but returns initialize's error instead of panicking
+    pub async fn try_build(self) -> Result<GridFixture, BanksClientError> {
+        let mut program_test = ProgramTest::new("billion", billion::ID, None);
+        program_test.add_program("mpl_core", MPL_CORE_ID, None);
+        let ctx = program_test.start_with_context().await;
+
+        let authority = ctx.payer.pubkey();
+        let mut fixture = GridFixture {
+            ctx,
+            grid: GridAccounts::new(self.grid_id, authority, Pubkey::default(), Pubkey::default(), Pubkey::default()),
+            grid_size: self.grid_size,
+            sent: HashSet::new(),
+        };
+
+        fixture.create_mint(self.transfer_fee_bps).await;
+        fixture.create_block_map().await;
+        fixture.send(&[client::initialize(&fixture.grid, self.args)], &[]).await?;
+        let collection = Keypair::new();
+        let create_collection = client::create_collection(
+            self.grid_id,
+            &authority,
+            &collection.pubkey(),
+            "Test Parcels".to_string(),
+            "https://example.com/collection.json".to_string(),
+        );
+        fixture.send(&[create_collection], &[&collection]).await.unwrap();
+        fixture.grid.collection = collection.pubkey();
+        Ok(fixture)
+    }
+}
+
+pub struct GridFixture {
+    pub ctx: ProgramTestContext,
+    pub grid: GridAccounts,
+    pub grid_size: u16,
+    /// Signatures already processed, so a repeated instruction gets a fresh blockhash
+    /// instead of being rejected as a duplicate transaction
+    sent: HashSet<Signature>,
+}
+
+impl GridFixture {
+    pub fn builder() -> GridFixtureBuilder {
+        GridFixtureBuilder::default()
+    }
+
+    pub fn authority(&self) -> Pubkey {
+        self.ctx.payer.pubkey()
+    }
+
+    pub fn reward_pool(&self) -> Pubkey {
+        self.grid.land_buy_reward_pool
+    }
+
+    /// Sign with the fixture payer plus `signers` and process the transaction
+    pub async fn send(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<(), BanksClientError> {
+        let tx = self.transaction(instructions, signers).await?;
+        self.ctx.banks_client.process_transaction(tx).await
+    }
+
+    /// Like `send`, returning the events of type `E` the transaction emitted, in order
+    pub async fn send_for_events<E: Event>(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<Vec<E>, BanksClientError> {
+        let tx = self.transaction(instructions, signers).await?;
+        let processed = self.ctx.banks_client.process_transaction_with_metadata(tx).await?;
+        processed.result.map_err(BanksClientError::TransactionError)?;
+        let logs = processed.metadata.map(|metadata| metadata.log_messages).unwrap_or_default();
+        Ok(logs
+            .iter()
+            .filter_map(|log| log.strip_prefix("Program data: "))
+            .filter_map(|data| STANDARD.decode(data).ok())
+            .filter(|data| data.starts_with(&E::DISCRIMINATOR))
+            .map(|data| E::deserialize(&mut &data[8..]).unwrap())
+            .collect())
+    }
+
+    /// Sign `instructions` with the fixture payer plus `signers`, on a fresh blockhash if the
+    /// same transaction was already sent
+    async fn transaction(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<Transaction, BanksClientError> {
+        let mut blockhash = self.ctx.banks_client.get_latest_blockhash().await?;
+        let mut all_signers: Vec<&Keypair> = vec![&self.ctx.payer];
+        all_signers.extend_from_slice(signers);
+        let mut tx = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.ctx.payer.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+        if self.sent.contains(&tx.signatures[0]) {
+            blockhash = self.ctx.get_new_latest_blockhash().await?;
+            tx.sign(&all_signers, blockhash);
+        }
+        self.sent.insert(tx.signatures[0]);
+        Ok(tx)
+    }
+
+    /// Simulate `instructions` signed by the payer plus `signers`; returns the outcome and raw return data
+    pub async fn simulate(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> (Result<(), TransactionError>, Vec<u8>) {
+        let blockhash = self.ctx.banks_client.get_latest_blockhash().await.unwrap();
+        let mut all_signers: Vec<&Keypair> = vec![&self.ctx.payer];
+        all_signers.extend_from_slice(signers);
+        let tx = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.ctx.payer.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+        let simulation = self.ctx.banks_client.simulate_transaction(tx).await.unwrap();
+        let return_data = simulation
+            .simulation_details
+            .and_then(|details| details.return_data)
+            .map(|return_data| return_data.data)
+            .unwrap_or_default();
+        (simulation.result.unwrap_or(Ok(())), return_data)
+    }
+
+    /// Simulate a view instruction and return its raw return data
+    pub async fn view(&mut self, instruction: Instruction) -> Vec<u8> {
+        let (result, return_data) = self.simulate(&[instruction], &[]).await;
+        result.unwrap();
+        return_data
+    }
+
+    async fn create_mint(&mut self, transfer_fee_bps: Option<u16>) {
+        let mint = Keypair::new();
+        let rent = self.ctx.banks_client.get_rent().await.unwrap();
+        let extensions: &[ExtensionType] = match transfer_fee_bps {
+            Some(_) => &[ExtensionType::TransferFeeConfig],
+            None => &[],
+        };
+        let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(extensions).unwrap();
+        let mut instructions = vec![system_instruction::create_account(
+            &self.authority(),
+            &mint.pubkey(),
+            rent.minimum_balance(space),
+            space as u64,
+            &spl_token_2022::id(),
+        )];
+        if let Some(bps) = transfer_fee_bps {
+            instructions.push(
+                spl_token_2022::extension::transfer_fee::instruction::initialize_transfer_fee_config(
+                    &spl_token_2022::id(),
+                    &mint.pubkey(),
+                    Some(&self.authority()),
+                    Some(&self.authority()),
+                    bps,
+                    u64::MAX,
+                )
+                .unwrap(),
+            );
+        }
+        instructions.push(
+            spl_token_2022::instruction::initialize_mint2(
+                &spl_token_2022::id(),
+                &mint.pubkey(),
+                &self.authority(),
+                None,
+                TOKEN_DECIMALS,
+            )
+            .unwrap(),
+        );
+        self.send(&instructions, &[&mint]).await.unwrap();
+        self.grid.token_mint = mint.pubkey();
+    }
+
+    /// Pre-create the >10KB BlockMap account top-level, then initialize it
+    async fn create_block_map(&mut self) {
+        self.grid.block_map = self.new_block_map().await;
+    }
+
+    /// Create and initialize a BlockMap of the fixture's grid size that no grid has bound yet
+    pub async fn new_block_map(&mut self) -> Pubkey {
+        let block_map = Keypair::new();
+        let rent = self.ctx.banks_client.get_rent().await.unwrap();
+        let instructions = client::create_sized_block_map(
+            &self.authority(),
+            &block_map.pubkey(),
+            rent.minimum_balance(BlockMap::size(self.grid_size)),
+            self.grid_size,
+        );
+        self.send(&instructions, &[&block_map]).await.unwrap();
+        block_map.pubkey()
+    }
+
+    /// Archive the current epoch onto a fresh BlockMap and point `grid` at the new epoch
+    pub async fn start_new_epoch(&mut self) -> Result<(), BanksClientError> {
+        let new_block_map = self.new_block_map().await;
+        let ix = client::start_new_epoch(&self.grid, &new_block_map);
+        self.send(&[ix], &[]).await?;
+        self.grid.block_map = new_block_map;
+        self.grid.epoch += 1;
+        Ok(())
+    }
+
+    /// Initialize grid `grid_id` alongside the fixture's, with the default arguments, the same
+    /// mint, a fresh BlockMap, and its own collection. `grid` is left pointing at the fixture's grid.
+    pub async fn add_grid(&mut self, grid_id: u64) -> Result<GridAccounts, BanksClientError> {
+        let block_map = self.new_block_map().await;
+        let mut grid = GridAccounts::new(grid_id, self.authority(), block_map, self.grid.token_mint, Pubkey::default());
+        self.send(&[client::initialize(&grid, GridFixtureBuilder::default().args)], &[]).await?;
+        let collection = Keypair::new();
+        let create_collection = client::create_collection(
+            grid_id,
+            &self.authority(),
+            &collection.pubkey(),
+            "Test Parcels".to_string(),
+            "https://example.com/collection.json".to_string(),
+        );
+        self.send(&[create_collection], &[&collection]).await?;
+        grid.collection = collection.pubkey();
+        Ok(grid)
+    }
+
+    /// Create a Core collection outside the program whose update authority is the GridConfig
+    /// PDA and point `grid` at it; update_config must still be called to switch the program over
+    pub async fn create_collection(&mut self) {
+        let collection = Keypair::new();
+        let ix = mpl_core::instructions::CreateCollectionV2Builder::new()
+            .collection(collection.pubkey())
+            .update_authority(Some(self.grid.grid_config))
+            .payer(self.authority())
+            .name("Test Parcels".to_string())
+            .uri("https://example.com/collection.json".to_string())
+            .instruction();
+        self.send(&[ix], &[&collection]).await.unwrap();
+        self.grid.collection = collection.pubkey();
+    }
+
+    /// Create a wallet with SOL for fees and `token_amount` tokens in its ATA
+    pub async fn create_user(&mut self, token_amount: u64) -> TestUser {
+        let keypair = Keypair::new();
+        let token_account = client::find_token_account(&keypair.pubkey(), &self.grid.token_mint);
+        let mut instructions = vec![
+            system_instruction::transfer(&self.authority(), &keypair.pubkey(), 1_000_000_000),
+            spl_associated_token_account::instruction::create_associated_token_account(
+                &self.authority(),
+                &keypair.pubkey(),
+                &self.grid.token_mint,
+                &spl_token_2022::id(),
+            ),
+        ];
+        if token_amount > 0 {
+            instructions.push(
+                spl_token_2022::instruction::mint_to(
+                    &spl_token_2022::id(),
+                    &self.grid.token_mint,
+                    &token_account,
+                    &self.authority(),
+                    &[],
+                    token_amount,
+                )
+                .unwrap(),
+            );
+        }
+        self.send(&instructions, &[]).await.unwrap();
+        TestUser { keypair, token_account }
+    }
+
+    /// Create an empty Token-2022 account of `owner` at a fresh address, not its ATA
+    pub async fn create_token_account(&mut self, owner: &Pubkey) -> Pubkey {
+        let account = Keypair::new();
+        let rent = self.ctx.banks_client.get_rent().await.unwrap();
+        let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Account>(&[]).unwrap();
+        let instructions = [
+            system_instruction::create_account(
+                &self.authority(),
+                &account.pubkey(),
+                rent.minimum_balance(space),
+                space as u64,
+                &spl_token_2022::id(),
+            ),
+            spl_token_2022::instruction::initialize_account3(
+                &spl_token_2022::id(),
+                &account.pubkey(),
+                &self.grid.token_mint,
+                owner,
+            )
+            .unwrap(),
+        ];
+        self.send(&instructions, &[&account]).await.unwrap();
+        account.pubkey()
+    }
+
+    /// Claim `rect` for `user` with a fresh asset keypair
+    pub async fn claim(&mut self, user: &TestUser, rect: Rect) -> Result<ClaimedParcel, BanksClientError> {
+        self.claim_referred(user, rect, None).await
+    }
+
+    /// Claim `rect` for `user`, crediting `referrer` if given
+    pub async fn claim_referred(
+        &mut self,
+        user: &TestUser,
+        rect: Rect,
+        referrer: Option<&Pubkey>,
+    ) -> Result<ClaimedParcel, BanksClientError> {
+        let parcel_id = self.next_claim_parcel_id().await;
+        let asset = Keypair::new();
+        let ix = client::claim_parcel(&self.grid, &user.keypair.pubkey(), &asset.pubkey(), parcel_id, rect, referrer);
+        self.send(&[ix], &[&user.keypair, &asset]).await?;
+        Ok(ClaimedParcel { parcel_id, asset: asset.pubkey(), epoch: self.grid.epoch })
+    }
+
+    /// Claim land buy rewards for `parcel` with `user` as signer and destination
+    pub async fn claim_rewards(&mut self, user: &TestUser, parcel: ClaimedParcel) -> Result<(), BanksClientError> {
+        let ix = client::claim_land_buy_rewards_in_epoch(
+            &self.grid,
+            &user.keypair.pubkey(),
+            &parcel.asset,
+            parcel.epoch,
+            parcel.parcel_id,
+        );
+        self.send(&[ix], &[&user.keypair]).await
+    }
+
+    pub async fn grid_config(&mut self) -> GridConfig {
+        client::decode_grid_config(&self.account_data(self.grid.grid_config).await).unwrap()
+    }
+
+    /// The id claim_parcel assigns next: the last freed id in the pool, else next_parcel_id
+    pub async fn next_claim_parcel_id(&mut self) -> u16 {
+        let config = self.grid_config().await;
+        let pool = self.ctx.banks_client.get_account(client::find_parcel_id_pool(self.grid.grid_id)).await.unwrap();
+        pool.and_then(|account| client::decode_parcel_id_pool(&account.data).ok())
+            .and_then(|pool| pool.peek(config.epoch))
+            .unwrap_or(config.next_parcel_id)
+    }
+
+    pub async fn parcel_info(&mut self, parcel_id: u16) -> ParcelInfo {
+        let parcel_info = client::find_parcel_info_in_epoch(self.grid.grid_id, self.grid.epoch, parcel_id);
+        client::decode_parcel_info(&self.account_data(parcel_info).await).unwrap()
+    }
+
+    pub async fn referrer_account(&mut self, referrer: &Pubkey) -> ReferrerAccount {
+        let referrer_account = client::find_referrer_account(self.grid.grid_id, referrer);
+        client::decode_referrer_account(&self.account_data(referrer_account).await).unwrap()
+    }
+
+    pub async fn stake_account(&mut self, parcel_id: u16) -> StakeAccount {
+        let stake_account = client::find_stake_account(self.grid.grid_id, parcel_id);
+        client::decode_stake_account(&self.account_data(stake_account).await).unwrap()
+    }
+
+    pub async fn parcel_valuation(&mut self, parcel_id: u16) -> ParcelValuation {
+        let parcel_valuation = client::find_parcel_valuation(self.grid.grid_id, parcel_id);
+        client::decode_parcel_valuation(&self.account_data(parcel_valuation).await).unwrap()
+    }
+
+    /// Current owner of a Core asset (BaseAssetV1 owner at offset 1)
+    pub async fn asset_owner(&mut self, asset: Pubkey) -> Pubkey {
+        let data = self.account_data(asset).await;
+        Pubkey::try_from(&data[1..33]).unwrap()
+    }
+
+    pub async fn fraction(&mut self, parcel: ClaimedParcel) -> Fraction {
+        let fraction = client::find_fraction(self.grid.grid_id, parcel.epoch, parcel.parcel_id);
+        client::decode_fraction(&self.account_data(fraction).await).unwrap()
+    }
+
+    /// Move the Clock sysvar's unix_timestamp forward by `seconds`
+    pub async fn advance_clock(&mut self, seconds: i64) {
+        let mut clock: Clock = self.ctx.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp += seconds;
+        self.ctx.set_sysvar(&clock);
+    }
+
+    /// Move the Clock sysvar's slot forward by `slots`
+    pub async fn advance_slots(&mut self, slots: u64) {
+        let mut clock: Clock = self.ctx.banks_client.get_sysvar().await.unwrap();
+        clock.slot += slots;
+        self.ctx.set_sysvar(&clock);
+    }
+
+    /// Pause claims and wait out PURGE_PAUSE_SLOTS so admin_purge may run
+    pub async fn pause_for_purge(&mut self) {
+        let pause = client::update_config(
+            self.grid.grid_id,
+            &self.authority(),
+            UpdateConfigArgs { claims_enabled: Some(false), ..Default::default() },
+        );
+        self.send(&[pause], &[]).await.unwrap();
+        self.advance_slots(billion::constants::PURGE_PAUSE_SLOTS).await;
+    }
+
+    /// Mint `amount` tokens straight into `token_account`
+    pub async fn mint_tokens(&mut self, token_account: Pubkey, amount: u64) {
+        let ix = spl_token_2022::instruction::mint_to(
+            &spl_token_2022::id(),
+            &self.grid.token_mint,
+            &token_account,
+            &self.authority(),
+            &[],
+            amount,
+        )
+        .unwrap();
+        self.send(&[ix], &[]).await.unwrap();
+    }
+
+    pub async fn block_map_data(&mut self) -> Vec<u8> {
+        self.account_data(self.grid.block_map).await
+    }
+
+    /// Parcel id stored in the BlockMap at (x, y)
+    pub async fn block(&mut self, x: u8, y: u8) -> u16 {
+        let blocks = client::decode_block_map(&self.block_map_data().await).unwrap();
+        blocks[billion_core::block_index(x, y, self.grid_size)]
+    }
+
+    pub async fn token_balance(&mut self, token_account: Pubkey) -> u64 {
+        let data = self.account_data(token_account).await;
+        let state =
+            spl_token_2022::extension::StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data).unwrap();
+        state.base.amount
+    }
+
+    pub async fn account_data(&mut self, address: Pubkey) -> Vec<u8> {
+        self.ctx.banks_client.get_account(address).await.unwrap().unwrap().data
+    }
+}
+
+/// Assert that `result` failed with the given program error
+pub fn assert_billion_error<T: std::fmt::Debug>(result: Result<T, BanksClientError>, expected: BillionError) {
+    let code = u32::from(expected);
+    match result {
+        Err(BanksClientError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::Custom(actual),
+        ))) => assert_eq!(actual, code, "expected {:?}", expected),
+        other => panic!("expected {:?} ({}), got {:?}", expected, code, other),
+    }
+}
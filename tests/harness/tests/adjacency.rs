@@ -0,0 +1,62 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, ClaimedParcel, GridFixture, Rect, TestUser};
+
+/// A 1-block parcel claimed into an empty grid (its 200_000 reward share is pool surplus),
+/// then three bordering parcels that leave it 366_666 pending
+async fn adjacency_fixture(adjacency_bonus_bps: u16) -> (GridFixture, TestUser, ClaimedParcel) {
+    let mut fixture = GridFixture::builder().build().await;
+    let authority = fixture.authority();
+    let ix = client::set_adjacency_bonus(fixture.grid.grid_id, &authority, adjacency_bonus_bps);
+    fixture.send(&[ix], &[]).await.unwrap();
+    let owner = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&owner, Rect::new(5, 1, 1, 1)).await.unwrap();
+    for rect in [Rect::new(4, 1, 1, 1), Rect::new(6, 1, 1, 1), Rect::new(5, 0, 1, 1)] {
+        fixture.claim(&owner, rect).await.unwrap();
+    }
+    (fixture, owner, parcel)
+}
+
+async fn refresh(fixture: &mut GridFixture, parcel: ClaimedParcel) -> u8 {
+    let ix = client::refresh_adjacency(&fixture.grid, parcel.parcel_id);
+    fixture.send(&[ix], &[]).await.unwrap();
+    fixture.parcel_info(parcel.parcel_id).await.adjacency_score
+}
+
+async fn claimed(fixture: &mut GridFixture, owner: &TestUser, parcel: ClaimedParcel) -> u64 {
+    let before = fixture.token_balance(owner.token_account).await;
+    fixture.claim_rewards(owner, parcel).await.unwrap();
+    fixture.token_balance(owner.token_account).await - before
+}
+
+#[tokio::test]
+async fn refreshed_score_earns_the_bonus() {
+    let (mut fixture, owner, parcel) = adjacency_fixture(5_000).await;
+    assert_eq!(refresh(&mut fixture, parcel).await, 3);
+    assert_eq!(claimed(&mut fixture, &owner, parcel).await, 366_666 + 183_333);
+}
+
+#[tokio::test]
+async fn stale_score_pays_no_bonus_until_refreshed() {
+    let (mut fixture, owner, parcel) = adjacency_fixture(5_000).await;
+    assert_eq!(fixture.parcel_info(parcel.parcel_id).await.adjacency_score, 0);
+    assert_eq!(claimed(&mut fixture, &owner, parcel).await, 366_666);
+
+    fixture.claim(&owner, Rect::new(5, 2, 1, 1)).await.unwrap();
+    assert_eq!(refresh(&mut fixture, parcel).await, 4);
+    // 200_000 over the 4 blocks claimed before it, plus half of that as bonus
+    assert_eq!(claimed(&mut fixture, &owner, parcel).await, 50_000 + 25_000);
+}
+
+#[tokio::test]
+async fn bonus_is_capped_at_pool_surplus() {
+    let (mut fixture, owner, parcel) = adjacency_fixture(10_000).await;
+    refresh(&mut fixture, parcel).await;
+    assert_eq!(claimed(&mut fixture, &owner, parcel).await, 366_666 + 200_000);
+
+    let authority = fixture.authority();
+    assert_billion_error(
+        fixture.send(&[client::set_adjacency_bonus(fixture.grid.grid_id, &authority, 10_001)], &[]).await,
+        BillionError::InvalidAdjacencyBonus,
+    );
+}
@@ -0,0 +1,84 @@
+use billion::constants::MAX_ADMIN_MINT_BATCH;
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, GridFixture, Rect};
+use solana_program_test::BanksClientError;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+/// Seeds `parcels` (recipient and rectangle) in one admin_mint_batch transaction
+async fn mint_batch(fixture: &mut GridFixture, parcels: &[(Pubkey, Rect)]) -> Result<Vec<Keypair>, BanksClientError> {
+    let first_parcel_id = fixture.grid_config().await.next_parcel_id;
+    let assets: Vec<Keypair> = parcels.iter().map(|_| Keypair::new()).collect();
+    let entries: Vec<_> = assets
+        .iter()
+        .zip(parcels)
+        .map(|(asset, &(recipient, rect))| (asset.pubkey(), recipient, rect))
+        .collect();
+    let ix = client::admin_mint_batch(&fixture.grid, first_parcel_id, &entries);
+    let signers: Vec<&Keypair> = assets.iter().collect();
+    fixture.send(&[ix], &signers).await.map(|_| assets)
+}
+
+#[tokio::test]
+async fn full_batch_fits_in_one_transaction() {
+    let mut fixture = GridFixture::builder().build().await;
+    let first = fixture.grid_config().await.next_parcel_id;
+    let (treasury, artist) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+    // Locked rings are fine for seeding, as with admin_mint
+    let parcels = [
+        (treasury, Rect::new(0, 0, 2, 2)),
+        (artist, Rect::new(10, 0, 1, 3)),
+        (treasury, Rect::new(50, 50, 2, 2)),
+        (artist, Rect::new(0, 10, 1, 1)),
+        (treasury, Rect::new(99, 99, 1, 1)),
+    ];
+    assert_eq!(parcels.len(), MAX_ADMIN_MINT_BATCH as usize);
+    let assets = mint_batch(&mut fixture, &parcels).await.unwrap();
+
+    let config = fixture.grid_config().await;
+    assert_eq!(config.next_parcel_id, first + 5);
+    assert_eq!(config.total_claimed_blocks, 13);
+    for ((parcel_id, (recipient, rect)), asset) in (first..).zip(parcels).zip(&assets) {
+        let info = fixture.parcel_info(parcel_id).await;
+        assert_eq!((info.x, info.y, info.width, info.height), (rect.x, rect.y, rect.width, rect.height));
+        assert_eq!(info.asset, asset.pubkey());
+        assert_eq!(info.paid_total, 0);
+        assert!(info.claimed_at > 0);
+        assert_eq!(fixture.asset_owner(asset.pubkey()).await, recipient);
+        assert_eq!(fixture.block(rect.x, rect.y).await, parcel_id);
+    }
+}
+
+#[tokio::test]
+async fn overlapping_entries_revert_the_whole_batch() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+    fixture.claim(&user, Rect::new(20, 0, 1, 1)).await.unwrap();
+    let first = fixture.grid_config().await.next_parcel_id;
+    let recipient = Pubkey::new_unique();
+
+    let result = mint_batch(&mut fixture, &[(recipient, Rect::new(0, 0, 2, 2)), (recipient, Rect::new(1, 1, 2, 2))]).await;
+    assert_billion_error(result, BillionError::BlockAlreadyClaimed);
+    let result = mint_batch(&mut fixture, &[(recipient, Rect::new(0, 0, 1, 1)), (recipient, Rect::new(19, 0, 2, 1))]).await;
+    assert_billion_error(result, BillionError::BlockAlreadyClaimed);
+
+    assert_eq!(fixture.grid_config().await.next_parcel_id, first);
+    assert_eq!(fixture.block(0, 0).await, 0);
+}
+
+#[tokio::test]
+async fn batch_respects_seeding_gate() {
+    let mut fixture = GridFixture::builder().build().await;
+    let disable = client::update_config(
+        fixture.grid.grid_id,
+        &fixture.authority(),
+        client::UpdateConfigArgs { seeding_enabled: Some(false), ..Default::default() },
+    );
+    fixture.send(&[disable], &[]).await.unwrap();
+
+    let result = mint_batch(&mut fixture, &[(Pubkey::new_unique(), Rect::new(0, 0, 1, 1))]).await;
+    assert_billion_error(result, BillionError::SeedingDisabled);
+    assert_billion_error(mint_batch(&mut fixture, &[]).await, BillionError::InvalidBatch);
+}
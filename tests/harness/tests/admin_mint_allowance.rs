@@ -0,0 +1,74 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, GridFixture, Rect};
+use solana_program_test::BanksClientError;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+/// Disables seeding and tops the admin mint allowance up by `top_up`
+async fn airdrop_fixture(top_up: u32) -> GridFixture {
+    let mut fixture = GridFixture::builder().build().await;
+    let ix = client::update_config(
+        fixture.grid.grid_id,
+        &fixture.authority(),
+        client::UpdateConfigArgs {
+            seeding_enabled: Some(false),
+            admin_mint_allowance_top_up: Some(top_up),
+            ..Default::default()
+        },
+    );
+    fixture.send(&[ix], &[]).await.unwrap();
+    fixture
+}
+
+async fn admin_mint(fixture: &mut GridFixture, rect: Rect) -> Result<(), BanksClientError> {
+    let parcel_id = fixture.grid_config().await.next_parcel_id;
+    let asset = Keypair::new();
+    let ix = client::admin_mint(&fixture.grid, &Pubkey::new_unique(), &asset.pubkey(), parcel_id, rect);
+    fixture.send(&[ix], &[&asset]).await
+}
+
+async fn remaining(fixture: &mut GridFixture) -> u32 {
+    let data = fixture.account_data(client::find_admin_mint_allowance(fixture.grid.grid_id)).await;
+    client::decode_admin_mint_allowance(&data).unwrap().remaining
+}
+
+#[tokio::test]
+async fn allowance_covers_admin_mints_after_seeding() {
+    let mut fixture = airdrop_fixture(2).await;
+    admin_mint(&mut fixture, Rect::new(0, 0, 1, 1)).await.unwrap();
+    assert_eq!(remaining(&mut fixture).await, 1);
+    admin_mint(&mut fixture, Rect::new(1, 0, 1, 1)).await.unwrap();
+    assert_eq!(remaining(&mut fixture).await, 0);
+
+    assert_billion_error(admin_mint(&mut fixture, Rect::new(2, 0, 1, 1)).await, BillionError::SeedingDisabled);
+    assert_eq!(fixture.block(2, 0).await, 0);
+}
+
+#[tokio::test]
+async fn batch_needs_allowance_for_every_entry() {
+    let mut fixture = airdrop_fixture(1).await;
+    let first_parcel_id = fixture.grid_config().await.next_parcel_id;
+    let assets = [Keypair::new(), Keypair::new()];
+    let recipient = Pubkey::new_unique();
+    let entries = [
+        (assets[0].pubkey(), recipient, Rect::new(0, 0, 1, 1)),
+        (assets[1].pubkey(), recipient, Rect::new(1, 0, 1, 1)),
+    ];
+    let ix = client::admin_mint_batch(&fixture.grid, first_parcel_id, &entries);
+    assert_billion_error(fixture.send(&[ix], &[&assets[0], &assets[1]]).await, BillionError::SeedingDisabled);
+    assert_eq!(remaining(&mut fixture).await, 1);
+}
+
+#[tokio::test]
+async fn allowance_is_unused_while_seeding() {
+    let mut fixture = GridFixture::builder().build().await;
+    let ix = client::update_config(
+        fixture.grid.grid_id,
+        &fixture.authority(),
+        client::UpdateConfigArgs { admin_mint_allowance_top_up: Some(3), ..Default::default() },
+    );
+    fixture.send(&[ix], &[]).await.unwrap();
+    admin_mint(&mut fixture, Rect::new(0, 0, 1, 1)).await.unwrap();
+    assert_eq!(remaining(&mut fixture).await, 3);
+}
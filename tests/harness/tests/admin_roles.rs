@@ -0,0 +1,81 @@
+use billion::errors::BillionError;
+use billion_client::{self as client, GridAccounts};
+use billion_test_harness::{assert_billion_error, GridFixture, Rect};
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn every_role_starts_with_the_authority() {
+    let mut fixture = GridFixture::builder().build().await;
+    let roles = client::decode_admin_roles(&fixture.account_data(client::find_admin_roles(fixture.grid.grid_id)).await).unwrap();
+    let authority = fixture.authority();
+    assert_eq!(
+        (roles.config_authority, roles.metadata_authority, roles.treasury_authority),
+        (authority, authority, authority)
+    );
+}
+
+#[tokio::test]
+async fn update_config_needs_the_config_authority() {
+    let mut fixture = GridFixture::builder().build().await;
+    let config = fixture.create_user(0).await;
+    let hand_over = client::set_admin_roles(fixture.grid.grid_id, &fixture.authority(), Some(config.keypair.pubkey()), None, None);
+    fixture.send(&[hand_over], &[]).await.unwrap();
+
+    let pause = |authority| {
+        client::update_config(fixture.grid.grid_id, authority, client::UpdateConfigArgs { claims_enabled: Some(false), ..Default::default() })
+    };
+    assert_billion_error(fixture.send(&[pause(&fixture.authority())], &[]).await, BillionError::Unauthorized);
+    fixture.send(&[pause(&config.keypair.pubkey())], &[&config.keypair]).await.unwrap();
+    assert!(fixture.grid_config().await.claims_paused);
+
+    // Only the main authority reassigns roles
+    let take_over = client::set_admin_roles(fixture.grid.grid_id, &config.keypair.pubkey(), None, Some(config.keypair.pubkey()), None);
+    assert_billion_error(fixture.send(&[take_over], &[&config.keypair]).await, BillionError::Unauthorized);
+}
+
+#[tokio::test]
+async fn metadata_updates_need_the_metadata_authority() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&user, Rect::new(0, 0, 1, 1)).await.unwrap();
+    let metadata = fixture.create_user(0).await;
+    let hand_over = client::set_admin_roles(fixture.grid.grid_id, &fixture.authority(), None, Some(metadata.keypair.pubkey()), None);
+    fixture.send(&[hand_over], &[]).await.unwrap();
+
+    let rename = |grid: &GridAccounts| {
+        client::update_parcel_metadata(grid, &parcel.asset, parcel.parcel_id, Some("Plaza".to_string()), None)
+    };
+    assert_billion_error(fixture.send(&[rename(&fixture.grid)], &[]).await, BillionError::Unauthorized);
+    let grid = GridAccounts { authority: metadata.keypair.pubkey(), ..fixture.grid };
+    fixture.send(&[rename(&grid)], &[&metadata.keypair]).await.unwrap();
+}
+
+#[tokio::test]
+async fn withdrawals_need_the_treasury_authority() {
+    let mut fixture = GridFixture::builder().build().await;
+    let treasury = fixture.create_user(0).await;
+    let hand_over = client::set_admin_roles(fixture.grid.grid_id, &fixture.authority(), None, None, Some(treasury.keypair.pubkey()));
+    fixture.send(&[hand_over], &[]).await.unwrap();
+    let pool = fixture.reward_pool();
+    fixture.mint_tokens(pool, 50_000).await;
+    let create = spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+        &fixture.authority(),
+        &fixture.authority(),
+        &fixture.grid.token_mint,
+        &spl_token_2022::id(),
+    );
+    fixture.send(&[create], &[]).await.unwrap();
+
+    let withdraw = client::admin_withdraw_pool_excess(&fixture.grid, 50_000);
+    assert_billion_error(fixture.send(&[withdraw], &[]).await, BillionError::Unauthorized);
+    let grid = GridAccounts { authority: treasury.keypair.pubkey(), ..fixture.grid };
+    let withdraw = client::admin_withdraw_pool_excess(&grid, 50_000);
+    fixture.send(&[withdraw], &[&treasury.keypair]).await.unwrap();
+    assert_eq!(fixture.token_balance(treasury.token_account).await, 50_000);
+
+    // The main authority keeps admin_mint
+    let (asset, parcel_id) = (Keypair::new(), fixture.grid_config().await.next_parcel_id);
+    let rect = Rect::new(0, 0, 1, 1);
+    let mint = client::admin_mint(&fixture.grid, &fixture.authority(), &asset.pubkey(), parcel_id, rect);
+    fixture.send(&[mint], &[&asset]).await.unwrap();
+}
@@ -0,0 +1,32 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, GridFixture};
+use solana_sdk::signature::Signer;
+
+#[tokio::test]
+async fn advancing_a_ring_raises_total_burned_to_its_threshold() {
+    let mut fixture = GridFixture::builder().build().await;
+    let advance = client::admin_advance_ring(fixture.grid.grid_id, &fixture.authority(), 4);
+    fixture.send(&[advance], &[]).await.unwrap();
+
+    let config = fixture.grid_config().await;
+    assert_eq!(config.total_burned, 100_000_000);
+    assert_eq!(config.unlocked_ring(), 4);
+    assert_eq!(config.priority_ring, 2);
+}
+
+#[tokio::test]
+async fn rings_only_move_forward() {
+    let mut fixture = GridFixture::builder().build().await;
+    fixture.send(&[client::admin_advance_ring(fixture.grid.grid_id, &fixture.authority(), 3)], &[]).await.unwrap();
+
+    let back = client::admin_advance_ring(fixture.grid.grid_id, &fixture.authority(), 2);
+    assert_billion_error(fixture.send(&[back], &[]).await, BillionError::RingAlreadyUnlocked);
+    let past_the_edge = client::admin_advance_ring(fixture.grid.grid_id, &fixture.authority(), 11);
+    assert_billion_error(fixture.send(&[past_the_edge], &[]).await, BillionError::InvalidRing);
+
+    let stranger = fixture.create_user(0).await;
+    let advance = client::admin_advance_ring(fixture.grid.grid_id, &stranger.keypair.pubkey(), 5);
+    assert_billion_error(fixture.send(&[advance], &[&stranger.keypair]).await, BillionError::Unauthorized);
+    assert_eq!(fixture.grid_config().await.total_burned, 50_000_000);
+}
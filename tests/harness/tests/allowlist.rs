@@ -0,0 +1,91 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, GridFixture, Rect, TestUser};
+use solana_program_test::BanksClientError;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+async fn open_allowlist(fixture: &mut GridFixture, tree: &client::AllowlistTree) {
+    let ix = client::update_config(
+        fixture.grid.grid_id,
+        &fixture.authority(),
+        client::UpdateConfigArgs {
+            allowlist_root: Some(tree.root()),
+            allowlist_only: Some(true),
+            ..Default::default()
+        },
+    );
+    fixture.send(&[ix], &[]).await.unwrap();
+}
+
+async fn claim_with_proof(
+    fixture: &mut GridFixture,
+    user: &TestUser,
+    rect: Rect,
+    proof: Vec<[u8; 32]>,
+) -> Result<(), BanksClientError> {
+    let parcel_id = fixture.grid_config().await.next_parcel_id;
+    let asset = Keypair::new();
+    let ix = client::claim_parcel_allowlisted(&fixture.grid, &user.keypair.pubkey(), &asset.pubkey(), parcel_id, rect, proof);
+    fixture.send(&[ix], &[&user.keypair, &asset]).await
+}
+
+#[tokio::test]
+async fn only_listed_wallets_claim_while_allowlist_only() {
+    let mut fixture = GridFixture::builder().build().await;
+    let listed = fixture.create_user(100_000_000).await;
+    let other = fixture.create_user(100_000_000).await;
+    let tree = client::AllowlistTree::new(&[listed.keypair.pubkey(), Pubkey::new_unique()]);
+    open_allowlist(&mut fixture, &tree).await;
+
+    let proof = tree.proof(&listed.keypair.pubkey()).unwrap();
+    claim_with_proof(&mut fixture, &listed, Rect::new(0, 0, 1, 1), proof.clone()).await.unwrap();
+    assert_eq!(fixture.block(0, 0).await, 1);
+
+    // A listed wallet's proof doesn't work for anyone else, and no proof doesn't work at all
+    let result = claim_with_proof(&mut fixture, &other, Rect::new(1, 0, 1, 1), proof).await;
+    assert_billion_error(result, BillionError::NotAllowlisted);
+    assert_billion_error(fixture.claim(&listed, Rect::new(1, 0, 1, 1)).await, BillionError::NotAllowlisted);
+}
+
+#[tokio::test]
+async fn paths_without_a_proof_are_closed_while_allowlist_only() {
+    let mut fixture = GridFixture::builder().build().await;
+    let listed = fixture.create_user(100_000_000).await;
+    let tree = client::AllowlistTree::new(&[listed.keypair.pubkey()]);
+    open_allowlist(&mut fixture, &tree).await;
+
+    let parcel_id = fixture.grid_config().await.next_parcel_id;
+    let pda_claim = client::claim_parcel_pda(&fixture.grid, &listed.keypair.pubkey(), parcel_id, Rect::new(0, 0, 1, 1), None);
+    assert_billion_error(fixture.send(&[pda_claim], &[&listed.keypair]).await, BillionError::NotAllowlisted);
+
+    let asset = Keypair::new();
+    let batch = client::claim_parcels_batch(
+        &fixture.grid,
+        &listed.keypair.pubkey(),
+        parcel_id,
+        &[(asset.pubkey(), Rect::new(0, 0, 1, 1))],
+    );
+    assert_billion_error(fixture.send(&[batch], &[&listed.keypair, &asset]).await, BillionError::NotAllowlisted);
+}
+
+#[tokio::test]
+async fn proof_is_ignored_once_the_allowlist_closes() {
+    let mut fixture = GridFixture::builder().build().await;
+    let listed = fixture.create_user(100_000_000).await;
+    let other = fixture.create_user(100_000_000).await;
+    let tree = client::AllowlistTree::new(&[listed.keypair.pubkey()]);
+    open_allowlist(&mut fixture, &tree).await;
+
+    let close = client::update_config(
+        fixture.grid.grid_id,
+        &fixture.authority(),
+        client::UpdateConfigArgs { allowlist_only: Some(false), ..Default::default() },
+    );
+    fixture.send(&[close], &[]).await.unwrap();
+
+    fixture.claim(&other, Rect::new(0, 0, 1, 1)).await.unwrap();
+    claim_with_proof(&mut fixture, &other, Rect::new(1, 0, 1, 1), vec![[7; 32]]).await.unwrap();
+}
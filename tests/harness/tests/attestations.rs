@@ -0,0 +1,56 @@
+use billion::constants::ATTESTATION_TTL_SECS;
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, GridFixture, Rect};
+use solana_sdk::signature::Signer;
+
+#[tokio::test]
+async fn owner_mints_attestation_at_deterministic_pda() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 2, 3)).await.unwrap();
+    let owner_key = owner.keypair.pubkey();
+
+    let ix = client::mint_ownership_attestation(fixture.grid.grid_id, &owner_key, &parcel.asset, parcel.epoch, parcel.parcel_id);
+    fixture.send(&[ix], &[&owner.keypair]).await.unwrap();
+
+    let address = client::find_attestation(fixture.grid.grid_id, parcel.epoch, parcel.parcel_id, &owner_key);
+    let attestation = client::decode_attestation(&fixture.account_data(address).await).unwrap();
+    assert_eq!(attestation.owner, owner_key);
+    assert_eq!(attestation.parcel_id, parcel.parcel_id);
+    assert_eq!(attestation.asset, parcel.asset);
+    assert_eq!(attestation.block_count, 6);
+}
+
+#[tokio::test]
+async fn non_owner_cannot_mint_attestation() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let other = fixture.create_user(0).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+
+    let ix = client::mint_ownership_attestation(fixture.grid.grid_id, &other.keypair.pubkey(), &parcel.asset, parcel.epoch, parcel.parcel_id);
+    assert_billion_error(fixture.send(&[ix], &[&other.keypair]).await, BillionError::NotOwner);
+}
+
+#[tokio::test]
+async fn anyone_closes_attestation_after_ttl() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    let owner_key = owner.keypair.pubkey();
+    let ix = client::mint_ownership_attestation(fixture.grid.grid_id, &owner_key, &parcel.asset, parcel.epoch, parcel.parcel_id);
+    fixture.send(&[ix], &[&owner.keypair]).await.unwrap();
+
+    // Sent by the fixture authority, not the owner
+    let close = client::close_attestation(fixture.grid.grid_id, &owner_key, parcel.epoch, parcel.parcel_id);
+    assert_billion_error(fixture.send(&[close.clone()], &[]).await, BillionError::AttestationNotExpired);
+
+    fixture.advance_clock(ATTESTATION_TTL_SECS).await;
+    let before = fixture.ctx.banks_client.get_balance(owner_key).await.unwrap();
+    fixture.send(&[close], &[]).await.unwrap();
+    assert!(fixture.ctx.banks_client.get_balance(owner_key).await.unwrap() > before);
+
+    let address = client::find_attestation(fixture.grid.grid_id, parcel.epoch, parcel.parcel_id, &owner_key);
+    assert!(fixture.ctx.banks_client.get_account(address).await.unwrap().is_none());
+}
@@ -0,0 +1,68 @@
+use billion_client as client;
+use billion_test_harness::{ClaimedParcel, GridFixture, Rect};
+use mpl_core::types::PluginAuthority;
+use mpl_core::{Asset, BaseAuthority};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+async fn attributes(fixture: &mut GridFixture, parcel: ClaimedParcel) -> Vec<(String, String)> {
+    let asset = Asset::deserialize(&fixture.account_data(parcel.asset).await).unwrap();
+    let plugin = asset.plugin_list.attributes.expect("asset has an Attributes plugin");
+    let grid_config = PluginAuthority::Address { address: fixture.grid.grid_config };
+    assert_eq!(plugin.base.authority, BaseAuthority::from(grid_config));
+    plugin
+        .attributes
+        .attribute_list
+        .into_iter()
+        .map(|attribute| (attribute.key, attribute.value))
+        .collect()
+}
+
+fn expected(parcel_id: u16, rect: Rect) -> Vec<(String, String)> {
+    [
+        ("parcel_id", parcel_id),
+        ("x", rect.x as u16),
+        ("y", rect.y as u16),
+        ("width", rect.width as u16),
+        ("height", rect.height as u16),
+    ]
+    .into_iter()
+    .map(|(key, value)| (key.to_string(), value.to_string()))
+    .collect()
+}
+
+#[tokio::test]
+async fn claimed_asset_carries_its_coordinates() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+    let rect = Rect::new(2, 3, 3, 2);
+
+    let parcel = fixture.claim(&user, rect).await.unwrap();
+    assert_eq!(attributes(&mut fixture, parcel).await, expected(parcel.parcel_id, rect));
+}
+
+#[tokio::test]
+async fn admin_minted_asset_carries_its_coordinates() {
+    let mut fixture = GridFixture::builder().build().await;
+    let asset = Keypair::new();
+    let parcel_id = fixture.grid_config().await.next_parcel_id;
+    let rect = Rect::new(0, 0, 4, 4);
+    let ix = client::admin_mint(&fixture.grid, &Pubkey::new_unique(), &asset.pubkey(), parcel_id, rect);
+    fixture.send(&[ix], &[&asset]).await.unwrap();
+
+    let parcel = ClaimedParcel { parcel_id, asset: asset.pubkey(), epoch: 0 };
+    assert_eq!(attributes(&mut fixture, parcel).await, expected(parcel_id, rect));
+}
+
+#[tokio::test]
+async fn anyone_can_refresh_attributes_from_parcel_info() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+    let cranker = fixture.create_user(0).await;
+    let rect = Rect::new(0, 0, 2, 2);
+    let parcel = fixture.claim(&user, rect).await.unwrap();
+
+    let ix = client::refresh_parcel_attributes(&fixture.grid, &cranker.keypair.pubkey(), &parcel.asset, parcel.parcel_id);
+    fixture.send(&[ix], &[&cranker.keypair]).await.unwrap();
+    assert_eq!(attributes(&mut fixture, parcel).await, expected(parcel.parcel_id, rect));
+}
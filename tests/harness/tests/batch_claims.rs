@@ -0,0 +1,95 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, GridFixture, Rect, TestUser};
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+
+const PRICE: u64 = 1_000_000;
+
+async fn claim_batch(fixture: &mut GridFixture, user: &TestUser, rects: &[Rect]) -> Result<Vec<Keypair>, BanksClientError> {
+    let first_parcel_id = fixture.grid_config().await.next_parcel_id;
+    let assets: Vec<Keypair> = rects.iter().map(|_| Keypair::new()).collect();
+    let parcels: Vec<_> = assets.iter().map(|asset| asset.pubkey()).zip(rects.iter().copied()).collect();
+    let ix = client::claim_parcels_batch(&fixture.grid, &user.keypair.pubkey(), first_parcel_id, &parcels);
+    let mut signers: Vec<&Keypair> = vec![&user.keypair];
+    signers.extend(assets.iter());
+    fixture.send(&[ix], &signers).await.map(|_| assets)
+}
+
+#[tokio::test]
+async fn batch_claims_each_rectangle_as_its_own_parcel() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+    let first = fixture.grid_config().await.next_parcel_id;
+
+    let rects = [Rect::new(0, 0, 2, 2), Rect::new(10, 0, 1, 3), Rect::new(0, 10, 1, 1)];
+    let assets = claim_batch(&mut fixture, &user, &rects).await.unwrap();
+
+    let config = fixture.grid_config().await;
+    assert_eq!(config.next_parcel_id, first + 3);
+    assert_eq!(config.total_claimed_blocks, 8);
+    assert_eq!(fixture.token_balance(user.token_account).await, 100_000_000 - 8 * PRICE);
+    for ((parcel_id, rect), asset) in (first..).zip(rects).zip(&assets) {
+        let info = fixture.parcel_info(parcel_id).await;
+        assert_eq!((info.x, info.y, info.width, info.height), (rect.x, rect.y, rect.width, rect.height));
+        assert_eq!(info.asset, asset.pubkey());
+        assert_eq!(fixture.asset_owner(asset.pubkey()).await, user.keypair.pubkey());
+        assert_eq!(fixture.block(rect.x, rect.y).await, parcel_id);
+    }
+}
+
+#[tokio::test]
+async fn batch_rewards_go_to_landowners_from_before_the_batch() {
+    let mut fixture = GridFixture::builder().build().await;
+    let early = fixture.create_user(100_000_000).await;
+    let buyer = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&early, Rect::new(98, 98, 2, 2)).await.unwrap();
+
+    claim_batch(&mut fixture, &buyer, &[Rect::new(0, 0, 1, 1), Rect::new(5, 5, 1, 1)]).await.unwrap();
+
+    // Both blocks' 200_000 reward shares are owed to the 4 blocks that existed before
+    let before = fixture.token_balance(early.token_account).await;
+    fixture.claim_rewards(&early, parcel).await.unwrap();
+    assert_eq!(fixture.token_balance(early.token_account).await - before, 400_000);
+}
+
+#[tokio::test]
+async fn overlapping_rectangles_revert_the_whole_batch() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+    let first = fixture.grid_config().await.next_parcel_id;
+
+    let result = claim_batch(&mut fixture, &user, &[Rect::new(0, 0, 2, 2), Rect::new(1, 1, 2, 2)]).await;
+    assert_billion_error(result, BillionError::BlockAlreadyClaimed);
+
+    assert_eq!(fixture.grid_config().await.next_parcel_id, first);
+    assert_eq!(fixture.block(0, 0).await, 0);
+    assert_eq!(fixture.token_balance(user.token_account).await, 100_000_000);
+}
+
+#[tokio::test]
+async fn batch_size_is_limited() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+
+    assert_billion_error(claim_batch(&mut fixture, &user, &[]).await, BillionError::InvalidBatch);
+    let five: Vec<Rect> = (0..5).map(|i| Rect::new(i * 2, 0, 1, 1)).collect();
+    assert_billion_error(claim_batch(&mut fixture, &user, &five).await, BillionError::InvalidBatch);
+}
+
+#[tokio::test]
+async fn batch_prices_every_rectangle_at_the_same_curve_price() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+    let set_curve = client::update_config(
+        fixture.grid.grid_id,
+        &fixture.authority(),
+        client::UpdateConfigArgs { curve_divisor: Some(2), ..Default::default() },
+    );
+    fixture.send(&[set_curve], &[]).await.unwrap();
+    fixture.claim(&user, Rect::new(0, 0, 1, 2)).await.unwrap();
+
+    // 2 claimed blocks double the price for the whole batch, not just its first rectangle
+    claim_batch(&mut fixture, &user, &[Rect::new(10, 0, 2, 2), Rect::new(0, 10, 1, 1)]).await.unwrap();
+    assert_eq!(fixture.token_balance(user.token_account).await, 100_000_000 - 2 * PRICE - 5 * 2 * PRICE);
+}
@@ -0,0 +1,92 @@
+use billion::errors::BillionError;
+use billion_client::{self as client, CharityChoice};
+use billion_test_harness::{assert_billion_error, GridFixture, Rect, TestUser};
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+
+/// Registers `count` charities (each a fresh user's token account) with a 10% cap
+async fn charity_fixture(count: usize) -> (GridFixture, Vec<TestUser>) {
+    let mut fixture = GridFixture::builder().build().await;
+    let mut charities = Vec::new();
+    for _ in 0..count {
+        charities.push(fixture.create_user(0).await);
+    }
+    let accounts = charities.iter().map(|charity| charity.token_account).collect();
+    let ix = client::admin_set_charities(fixture.grid.grid_id, &fixture.authority(), accounts, 1_000);
+    fixture.send(&[ix], &[]).await.unwrap();
+    (fixture, charities)
+}
+
+async fn claim_with_charity(
+    fixture: &mut GridFixture,
+    user: &TestUser,
+    rect: Rect,
+    charity: CharityChoice,
+) -> Result<(), BanksClientError> {
+    let parcel_id = fixture.grid_config().await.next_parcel_id;
+    let asset = Keypair::new();
+    let ix = client::claim_parcel_with_charity(
+        &fixture.grid,
+        &user.keypair.pubkey(),
+        &asset.pubkey(),
+        parcel_id,
+        rect,
+        None,
+        Some(charity),
+    );
+    fixture.send(&[ix], &[&user.keypair, &asset]).await
+}
+
+#[tokio::test]
+async fn charity_share_comes_out_of_the_burn() {
+    let (mut fixture, charities) = charity_fixture(2).await;
+    let buyer = fixture.create_user(100_000_000).await;
+    let charity = CharityChoice { index: 1, bps: 1_000, token_account: charities[1].token_account };
+
+    // 4 blocks: 4_000_000 cost, 800_000 to rewards, 400_000 to charity, the rest burned
+    claim_with_charity(&mut fixture, &buyer, Rect::new(0, 0, 2, 2), charity).await.unwrap();
+    assert_eq!(fixture.token_balance(charities[1].token_account).await, 400_000);
+    assert_eq!(fixture.token_balance(charities[0].token_account).await, 0);
+    assert_eq!(fixture.token_balance(buyer.token_account).await, 96_000_000);
+    assert_eq!(fixture.grid_config().await.total_burned, 2_800_000);
+}
+
+#[tokio::test]
+async fn unknown_index_fails_before_any_transfer() {
+    let (mut fixture, charities) = charity_fixture(1).await;
+    let buyer = fixture.create_user(100_000_000).await;
+    let charity = CharityChoice { index: 1, bps: 500, token_account: charities[0].token_account };
+
+    let result = claim_with_charity(&mut fixture, &buyer, Rect::new(0, 0, 1, 1), charity).await;
+    assert_billion_error(result, BillionError::InvalidCharity);
+    assert_eq!(fixture.token_balance(buyer.token_account).await, 100_000_000);
+}
+
+#[tokio::test]
+async fn token_account_must_match_the_registry() {
+    let (mut fixture, _) = charity_fixture(1).await;
+    let buyer = fixture.create_user(100_000_000).await;
+    let impostor = fixture.create_user(0).await;
+    let charity = CharityChoice { index: 0, bps: 500, token_account: impostor.token_account };
+
+    let result = claim_with_charity(&mut fixture, &buyer, Rect::new(0, 0, 1, 1), charity).await;
+    assert_billion_error(result, BillionError::InvalidCharity);
+}
+
+#[tokio::test]
+async fn charity_share_is_capped_by_config() {
+    let (mut fixture, charities) = charity_fixture(1).await;
+    let buyer = fixture.create_user(100_000_000).await;
+    let charity = CharityChoice { index: 0, bps: 1_001, token_account: charities[0].token_account };
+
+    let result = claim_with_charity(&mut fixture, &buyer, Rect::new(0, 0, 1, 1), charity).await;
+    assert_billion_error(result, BillionError::InvalidCharityShare);
+}
+
+#[tokio::test]
+async fn registry_holds_at_most_eight_charities() {
+    let mut fixture = GridFixture::builder().build().await;
+    let charities = (0..9).map(|_| Keypair::new().pubkey()).collect();
+    let ix = client::admin_set_charities(fixture.grid.grid_id, &fixture.authority(), charities, 1_000);
+    assert_billion_error(fixture.send(&[ix], &[]).await, BillionError::TooManyCharities);
+}
@@ -0,0 +1,273 @@
+use anchor_lang::AnchorDeserialize;
+use billion::errors::BillionError;
+use billion::instructions::ClaimPreview;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, ClaimedParcel, GridFixture, Rect};
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::TransactionError,
+};
+
+#[tokio::test]
+async fn claim_parcel_happy_path() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+
+    let parcel = fixture.claim(&user, Rect::new(0, 0, 3, 2)).await.unwrap();
+
+    let config = fixture.grid_config().await;
+    assert_eq!(parcel.parcel_id, 1);
+    assert_eq!(config.next_parcel_id, 2);
+    assert_eq!(config.total_claimed_blocks, 6);
+    // 20% of the 6 token cost goes to the reward pool, the rest is burned
+    assert_eq!(config.total_burned, 4_800_000);
+    assert_eq!(fixture.token_balance(user.token_account).await, 94_000_000);
+    assert_eq!(fixture.token_balance(fixture.reward_pool()).await, 1_200_000);
+
+    for (x, y) in [(0, 0), (2, 0), (0, 1), (2, 1)] {
+        assert_eq!(fixture.block(x, y).await, parcel.parcel_id);
+    }
+
+    let parcel_info = fixture.parcel_info(parcel.parcel_id).await;
+    assert_eq!(parcel_info.asset, parcel.asset);
+    assert_eq!(parcel_info.block_count(), 6);
+}
+
+#[tokio::test]
+async fn claim_parcel_ring_locked() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+
+    let result = fixture.claim(&user, Rect::new(50, 50, 1, 1)).await;
+    assert_billion_error(result, BillionError::RingLocked);
+}
+
+#[tokio::test]
+async fn claim_parcel_overlap() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+
+    fixture.claim(&user, Rect::new(95, 0, 2, 2)).await.unwrap();
+    let result = fixture.claim(&user, Rect::new(96, 1, 2, 2)).await;
+    assert_billion_error(result, BillionError::BlockAlreadyClaimed);
+}
+
+#[tokio::test]
+async fn claim_parcel_insufficient_balance() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(500_000).await;
+
+    let result = fixture.claim(&user, Rect::new(0, 10, 1, 1)).await;
+    assert_billion_error(result, BillionError::InsufficientBalance);
+}
+
+#[tokio::test]
+async fn claim_parcel_dry_run_persists_nothing() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+    fixture.claim(&user, Rect::new(0, 0, 1, 1)).await.unwrap();
+
+    let asset = Keypair::new();
+    let ix = client::claim_parcel_dry_run(&fixture.grid, &user.keypair.pubkey(), &asset.pubkey(), 2, Rect::new(0, 1, 2, 1), None);
+    let (result, return_data) = fixture.simulate(&[ix], &[&user.keypair, &asset]).await;
+    assert_eq!(
+        result,
+        Err(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(u32::from(BillionError::DryRunSuccess))
+        ))
+    );
+
+    let preview = ClaimPreview::deserialize(&mut &return_data[..]).unwrap();
+    assert_eq!(preview.parcel_id, 2);
+    assert_eq!(preview.total_cost, 2_000_000);
+    assert_eq!(preview.total_claimed_blocks, 3);
+    // 400_000 reward tokens shared by the single existing block
+    assert_eq!(preview.land_buy_rewards_per_block, 400_000 * 1_000_000_000);
+
+    // The real claim lands exactly on the preview
+    let parcel = fixture.claim(&user, Rect::new(0, 1, 2, 1)).await.unwrap();
+    let config = fixture.grid_config().await;
+    assert_eq!(parcel.parcel_id, preview.parcel_id);
+    assert_eq!(config.total_burned, preview.total_burned);
+    assert_eq!(config.land_buy_rewards_per_block, preview.land_buy_rewards_per_block);
+}
+
+#[tokio::test]
+async fn claim_parcel_respects_max_parcel_blocks() {
+    let mut fixture = GridFixture::builder().max_parcel_blocks(64).build().await;
+    let user = fixture.create_user(1_000_000_000).await;
+
+    let result = fixture.claim(&user, Rect::new(0, 0, 10, 10)).await;
+    assert_billion_error(result, BillionError::ParcelTooLarge);
+
+    let parcel = fixture.claim(&user, Rect::new(0, 0, 8, 8)).await.unwrap();
+    assert_eq!(fixture.parcel_info(parcel.parcel_id).await.block_count(), 64);
+}
+
+#[tokio::test]
+async fn update_config_lifts_max_parcel_blocks() {
+    let mut fixture = GridFixture::builder().max_parcel_blocks(64).build().await;
+    let user = fixture.create_user(1_000_000_000).await;
+
+    let lift = client::update_config(
+        fixture.grid.grid_id,
+        &fixture.authority(),
+        client::UpdateConfigArgs { max_parcel_blocks: Some(0), ..Default::default() },
+    );
+    fixture.send(&[lift], &[]).await.unwrap();
+    assert_eq!(fixture.grid_config().await.max_parcel_blocks, 0);
+    fixture.claim(&user, Rect::new(0, 0, 10, 10)).await.unwrap();
+}
+
+#[tokio::test]
+async fn admin_mint_ignores_max_parcel_blocks() {
+    let mut fixture = GridFixture::builder().max_parcel_blocks(64).build().await;
+    let recipient = Pubkey::new_unique();
+    let asset = Keypair::new();
+    let parcel_id = fixture.grid_config().await.next_parcel_id;
+    let ix = client::admin_mint(&fixture.grid, &recipient, &asset.pubkey(), parcel_id, Rect::new(0, 0, 10, 10));
+    fixture.send(&[ix], &[&asset]).await.unwrap();
+    assert_eq!(fixture.parcel_info(parcel_id).await.block_count(), 100);
+}
+
+#[tokio::test]
+async fn paused_claims_still_pay_rewards() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let buyer = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    fixture.claim(&buyer, Rect::new(1, 0, 1, 1)).await.unwrap();
+
+    let set_claims_enabled = |enabled| {
+        client::update_config(
+            fixture.grid.grid_id,
+            &fixture.authority(),
+            client::UpdateConfigArgs { claims_enabled: Some(enabled), ..Default::default() },
+        )
+    };
+    let pause = set_claims_enabled(false);
+    let resume = set_claims_enabled(true);
+    fixture.send(&[pause], &[]).await.unwrap();
+    assert!(fixture.grid_config().await.claims_paused);
+
+    let result = fixture.claim(&buyer, Rect::new(2, 0, 1, 1)).await;
+    assert_billion_error(result, BillionError::ClaimsPaused);
+    fixture.claim_rewards(&owner, parcel).await.unwrap();
+
+    fixture.send(&[resume], &[]).await.unwrap();
+    fixture.claim(&buyer, Rect::new(2, 0, 1, 1)).await.unwrap();
+}
+
+#[tokio::test]
+async fn ring_multipliers_price_each_block_by_its_ring() {
+    let mut fixture = GridFixture::builder().ring_thresholds(vec![0, 0]).build().await;
+    let user = fixture.create_user(100_000_000).await;
+    let mut multipliers = vec![10_000u16; 10];
+    multipliers[1] = 15_000;
+    let set_multipliers = client::update_config(
+        fixture.grid.grid_id,
+        &fixture.authority(),
+        client::UpdateConfigArgs { ring_price_multipliers_bps: Some(multipliers), ..Default::default() },
+    );
+    fixture.send(&[set_multipliers], &[]).await.unwrap();
+
+    // Column 5 is ring 1 at 1_000_000 per block, column 6 is ring 2 at 1_500_000
+    fixture.claim(&user, Rect::new(5, 20, 2, 2)).await.unwrap();
+    assert_eq!(fixture.token_balance(user.token_account).await, 100_000_000 - 5_000_000);
+    assert_eq!(fixture.grid_config().await.total_claimed_blocks, 4);
+}
+
+#[tokio::test]
+async fn ring_multipliers_need_a_nonzero_entry_per_ring() {
+    let mut fixture = GridFixture::builder().build().await;
+    let set_multipliers = |multipliers: Vec<u16>| {
+        client::update_config(
+            fixture.grid.grid_id,
+            &fixture.authority(),
+            client::UpdateConfigArgs { ring_price_multipliers_bps: Some(multipliers), ..Default::default() },
+        )
+    };
+    let too_few = set_multipliers(vec![10_000; 9]);
+    let mut with_zero = vec![10_000; 10];
+    with_zero[3] = 0;
+    let with_zero = set_multipliers(with_zero);
+
+    assert_billion_error(fixture.send(&[too_few], &[]).await, BillionError::InvalidRingMultipliers);
+    assert_billion_error(fixture.send(&[with_zero], &[]).await, BillionError::InvalidRingMultipliers);
+}
+
+#[tokio::test]
+async fn ring_multipliers_take_one_entry_per_ring_of_the_grid() {
+    let mut fixture = GridFixture::builder().ring_thresholds(vec![0, 0]).ring_count(5).build().await;
+    let user = fixture.create_user(100_000_000).await;
+    let set_multipliers = |multipliers: Vec<u16>| {
+        client::update_config(
+            fixture.grid.grid_id,
+            &fixture.authority(),
+            client::UpdateConfigArgs { ring_price_multipliers_bps: Some(multipliers), ..Default::default() },
+        )
+    };
+    let every_slot = set_multipliers(vec![10_000; 10]);
+    let per_ring = set_multipliers(vec![10_000, 15_000, 10_000, 10_000, 10_000]);
+
+    assert_billion_error(fixture.send(&[every_slot], &[]).await, BillionError::InvalidRingMultipliers);
+    fixture.send(&[per_ring], &[]).await.unwrap();
+    // Column 15 is ring 2 of the five, at 1_500_000 per block
+    fixture.claim(&user, Rect::new(15, 50, 1, 1)).await.unwrap();
+    assert_eq!(fixture.token_balance(user.token_account).await, 100_000_000 - 1_500_000);
+}
+
+#[tokio::test]
+async fn bonding_curve_raises_the_price_with_claimed_blocks() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+    let set_curve = client::update_config(
+        fixture.grid.grid_id,
+        &fixture.authority(),
+        client::UpdateConfigArgs { curve_divisor: Some(4), ..Default::default() },
+    );
+    fixture.send(&[set_curve], &[]).await.unwrap();
+
+    // All four blocks are priced before any of them counts toward the curve
+    fixture.claim(&user, Rect::new(0, 0, 2, 2)).await.unwrap();
+    assert_eq!(fixture.token_balance(user.token_account).await, 100_000_000 - 4_000_000);
+
+    // 4 claimed blocks over a divisor of 4 double the price
+    fixture.claim(&user, Rect::new(0, 2, 2, 1)).await.unwrap();
+    assert_eq!(fixture.token_balance(user.token_account).await, 100_000_000 - 8_000_000);
+}
+
+#[tokio::test]
+async fn gift_claim_mints_to_the_recipient_and_charges_the_claimer() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let buyer = fixture.create_user(100_000_000).await;
+    let recipient = fixture.create_user(0).await;
+    fixture.claim(&owner, Rect::new(98, 98, 1, 1)).await.unwrap();
+
+    let parcel_id = fixture.grid_config().await.next_parcel_id;
+    let asset = Keypair::new();
+    let ix = client::claim_parcel_gift(
+        &fixture.grid,
+        &buyer.keypair.pubkey(),
+        &recipient.keypair.pubkey(),
+        &asset.pubkey(),
+        parcel_id,
+        Rect::new(0, 0, 2, 1),
+    );
+    fixture.send(&[ix], &[&buyer.keypair, &asset]).await.unwrap();
+
+    assert_eq!(fixture.asset_owner(asset.pubkey()).await, recipient.keypair.pubkey());
+    assert_eq!(fixture.token_balance(buyer.token_account).await, 100_000_000 - 2_000_000);
+    assert_eq!(fixture.token_balance(recipient.token_account).await, 0);
+
+    // The recipient owns the asset, so the gifted parcel's rewards are theirs to claim
+    fixture.claim(&buyer, Rect::new(0, 1, 1, 1)).await.unwrap();
+    let gift = ClaimedParcel { parcel_id, asset: asset.pubkey(), epoch: 0 };
+    fixture.claim_rewards(&recipient, gift).await.unwrap();
+    assert!(fixture.token_balance(recipient.token_account).await > 0);
+    assert_billion_error(fixture.claim_rewards(&buyer, gift).await, BillionError::NotOwner);
+}
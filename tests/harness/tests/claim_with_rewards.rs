@@ -0,0 +1,86 @@
+use anchor_lang::AnchorDeserialize;
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, ClaimedParcel, GridFixture, Rect, TestUser};
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+
+async fn claim_with_rewards(
+    fixture: &mut GridFixture,
+    user: &TestUser,
+    rect: Rect,
+    reward_parcel: ClaimedParcel,
+) -> Result<ClaimedParcel, BanksClientError> {
+    let parcel_id = fixture.next_claim_parcel_id().await;
+    let asset = Keypair::new();
+    let ix = client::claim_parcel_with_rewards(
+        &fixture.grid,
+        &user.keypair.pubkey(),
+        &asset.pubkey(),
+        parcel_id,
+        rect,
+        reward_parcel.parcel_id,
+        &reward_parcel.asset,
+    );
+    fixture.send(&[ix], &[&user.keypair, &asset]).await?;
+    Ok(ClaimedParcel { parcel_id, asset: asset.pubkey(), epoch: fixture.grid.epoch })
+}
+
+async fn pending(fixture: &mut GridFixture, parcel: ClaimedParcel) -> u64 {
+    let ix = client::get_pending_rewards(&fixture.grid, parcel.epoch, parcel.parcel_id);
+    u64::deserialize(&mut &fixture.view(ix).await[..]).unwrap()
+}
+
+#[tokio::test]
+async fn rewards_pay_part_of_the_claim() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let other = fixture.create_user(100_000_000).await;
+    let reward_parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    fixture.claim(&other, Rect::new(1, 0, 1, 1)).await.unwrap();
+    assert_eq!(pending(&mut fixture, reward_parcel).await, 200_000);
+
+    let before = fixture.token_balance(owner.token_account).await;
+    let parcel = claim_with_rewards(&mut fixture, &owner, Rect::new(5, 0, 1, 1), reward_parcel).await.unwrap();
+    assert_eq!(before - fixture.token_balance(owner.token_account).await, 800_000);
+    assert_eq!(fixture.block(5, 0).await, parcel.parcel_id);
+
+    // The purchase's reward share went to the two earlier parcels, none to the new one
+    assert_eq!(pending(&mut fixture, reward_parcel).await, 100_000);
+    assert_eq!(pending(&mut fixture, parcel).await, 0);
+    assert_eq!(fixture.grid_config().await.total_claimed_blocks, 3);
+}
+
+#[tokio::test]
+async fn rewards_above_the_cost_stay_with_the_claimer() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let other = fixture.create_user(100_000_000).await;
+    let reward_parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    // 10 blocks credit 2_000_000 to the only earlier parcel
+    fixture.claim(&other, Rect::new(1, 0, 10, 1)).await.unwrap();
+
+    let before = fixture.token_balance(owner.token_account).await;
+    claim_with_rewards(&mut fixture, &owner, Rect::new(0, 5, 1, 1), reward_parcel).await.unwrap();
+    assert_eq!(fixture.token_balance(owner.token_account).await - before, 1_000_000);
+}
+
+#[tokio::test]
+async fn rewards_need_an_owned_parcel_with_something_pending() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let other = fixture.create_user(100_000_000).await;
+    let mine = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    assert_billion_error(
+        claim_with_rewards(&mut fixture, &owner, Rect::new(5, 0, 1, 1), mine).await,
+        BillionError::NothingToClaim,
+    );
+
+    let theirs = fixture.claim(&other, Rect::new(1, 0, 1, 1)).await.unwrap();
+    fixture.claim(&other, Rect::new(2, 0, 1, 1)).await.unwrap();
+    assert_billion_error(
+        claim_with_rewards(&mut fixture, &owner, Rect::new(5, 0, 1, 1), theirs).await,
+        BillionError::NotOwner,
+    );
+    assert_eq!(fixture.block(5, 0).await, 0);
+}
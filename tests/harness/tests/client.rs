@@ -0,0 +1,78 @@
+use anchor_lang::AnchorDeserialize;
+use billion::instructions::{GridState, ParcelView};
+use billion_client as client;
+use billion_test_harness::{GridFixture, Rect};
+use solana_sdk::signature::Signer;
+
+#[tokio::test]
+async fn client_builders_round_trip() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+
+    // Decoded GridConfig resolves the same addresses the fixture set up
+    let config = fixture.grid_config().await;
+    assert_eq!(client::GridAccounts::from_config(fixture.grid.grid_id, &config), fixture.grid);
+
+    // PDA asset path needs no extra signer
+    let ix = client::claim_parcel_pda(&fixture.grid, &user.keypair.pubkey(), 1, Rect::new(0, 0, 2, 1), None);
+    fixture.send(&[ix], &[&user.keypair]).await.unwrap();
+
+    let asset = client::find_asset(&fixture.grid.grid_config, 1);
+    let parcel_info = fixture.parcel_info(1).await;
+    assert_eq!(parcel_info.asset, asset);
+
+    let blocks = client::decode_block_map(&fixture.block_map_data().await).unwrap();
+    assert_eq!(blocks[billion_core::block_index(0, 0, fixture.grid_size)], 1);
+    assert_eq!(blocks[billion_core::block_index(1, 0, fixture.grid_size)], 1);
+    assert_eq!(blocks.iter().filter(|&&b| b != 0).count(), 2);
+
+    let state = GridState::deserialize(&mut &fixture.view(client::get_grid_state(fixture.grid.grid_id)).await[..]).unwrap();
+    assert_eq!(state.next_parcel_id, 2);
+    assert_eq!(state.total_claimed_blocks, 2);
+
+    let parcel = ParcelView::deserialize(&mut &fixture.view(client::get_parcel(fixture.grid.grid_id, 0, 1, &asset)).await[..]).unwrap();
+    assert_eq!(parcel.owner, user.keypair.pubkey());
+    assert_eq!(parcel.block_count, 2);
+    assert_eq!(parcel.paid_total, 2_000_000);
+    assert_eq!(parcel.claimed_at, parcel_info.claimed_at);
+}
+
+#[tokio::test]
+async fn block_map_view_matches_program_writes() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+    let first = fixture.claim(&user, Rect::new(0, 0, 3, 2)).await.unwrap();
+    let second = fixture.claim(&user, Rect::new(97, 98, 3, 2)).await.unwrap();
+
+    let view = client::BlockMapView::from_account_data(&fixture.block_map_data().await).unwrap();
+    for (x, y) in Rect::new(0, 0, 3, 2).blocks() {
+        assert_eq!(view.get(x, y), Some(first.parcel_id));
+        assert_eq!(view.get(x, y).unwrap_or(0), fixture.block(x, y).await);
+    }
+    assert_eq!(view.get(96, 99), None);
+    assert!(view
+        .iter_region(Rect::new(97, 98, 3, 2))
+        .all(|(_, _, parcel_id)| parcel_id == second.parcel_id));
+
+    let config = fixture.grid_config().await;
+    assert_eq!(view.claimed_blocks(), config.total_claimed_blocks);
+    assert_eq!(view.occupancy_by_ring(config.ring_count())[0], 12);
+    assert!(!view.find_free_rects(3, 2).contains(&Rect::new(1, 0, 3, 2)));
+    assert_eq!(client::BlockMapView::from_snapshot(&view.to_snapshot()).unwrap(), view);
+}
+
+#[tokio::test]
+async fn parcel_under_a_coordinate_resolves_through_the_block_map() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&user, Rect::new(10, 20, 3, 3)).await.unwrap();
+
+    let view = client::BlockMapView::from_account_data(&fixture.block_map_data().await).unwrap();
+    let (grid_id, epoch) = (fixture.grid.grid_id, fixture.grid_config().await.epoch);
+    // Any block of the parcel leads to it, not just the top-left corner
+    let address = view.parcel_info_at(grid_id, epoch, 12, 21).unwrap();
+    let parcel_info = client::decode_parcel_info(&fixture.account_data(address).await).unwrap();
+    assert_eq!(parcel_info.asset, parcel.asset);
+
+    assert_eq!(view.parcel_info_at(grid_id, epoch, 13, 21), None);
+}
@@ -0,0 +1,148 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, ClaimedParcel, GridFixture, Rect};
+use mpl_core::accounts::BaseCollectionV1;
+use mpl_core::instructions::{CreateCollectionV2Builder, CreateV2Builder};
+use solana_sdk::signature::{Keypair, Signer};
+
+async fn switch_collection(fixture: &mut GridFixture) {
+    fixture.create_collection().await;
+    let ix = client::update_config(
+        fixture.grid.grid_id,
+        &fixture.authority(),
+        client::UpdateConfigArgs {
+            collection: Some(fixture.grid.collection),
+            force_collection: Some(true),
+            ..Default::default()
+        },
+    );
+    fixture.send(&[ix], &[]).await.unwrap();
+}
+
+#[tokio::test]
+async fn the_program_creates_its_own_collection() {
+    let mut fixture = GridFixture::builder().build().await;
+    let config = fixture.grid_config().await;
+    assert_eq!(config.collection, fixture.grid.collection);
+    let collection = BaseCollectionV1::from_bytes(&fixture.account_data(config.collection).await).unwrap();
+    assert_eq!(collection.update_authority, fixture.grid.grid_config);
+
+    // Still replaceable before the first mint
+    let (replacement, authority) = (Keypair::new(), fixture.authority());
+    let create = |collection: &Keypair| {
+        let uri = "https://example.com/collection.json".to_string();
+        client::create_collection(fixture.grid.grid_id, &authority, &collection.pubkey(), "Test Parcels".to_string(), uri)
+    };
+    fixture.send(&[create(&replacement)], &[&replacement]).await.unwrap();
+    assert_eq!(fixture.grid_config().await.collection, replacement.pubkey());
+    fixture.grid.collection = replacement.pubkey();
+
+    let user = fixture.create_user(100_000_000).await;
+    fixture.claim(&user, Rect::new(0, 0, 1, 1)).await.unwrap();
+    let late = Keypair::new();
+    assert_billion_error(fixture.send(&[create(&late)], &[&late]).await, BillionError::CollectionLocked);
+}
+
+#[tokio::test]
+async fn minted_parcels_pin_the_collection_without_force() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+    fixture.claim(&user, Rect::new(0, 0, 1, 1)).await.unwrap();
+
+    let original = fixture.grid.collection;
+    fixture.create_collection().await;
+    let ix = client::update_config(
+        fixture.grid.grid_id,
+        &fixture.authority(),
+        client::UpdateConfigArgs { collection: Some(fixture.grid.collection), ..Default::default() },
+    );
+    assert_billion_error(fixture.send(&[ix], &[]).await, BillionError::CollectionLocked);
+    assert_eq!(fixture.grid_config().await.collection, original);
+}
+
+#[tokio::test]
+async fn parcels_record_the_collection_generation_they_were_minted_under() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+    // Setting the collection at build time was the first change
+    assert_eq!(fixture.grid_config().await.collection_generation, 1);
+    let old = fixture.claim(&user, Rect::new(0, 0, 1, 1)).await.unwrap();
+    assert_eq!(fixture.parcel_info(old.parcel_id).await.collection_generation, 1);
+
+    // Re-setting the same collection isn't a change
+    let same = client::update_config(
+        fixture.grid.grid_id,
+        &fixture.authority(),
+        client::UpdateConfigArgs { collection: Some(fixture.grid.collection), ..Default::default() },
+    );
+    fixture.send(&[same], &[]).await.unwrap();
+    assert_eq!(fixture.grid_config().await.collection_generation, 1);
+
+    switch_collection(&mut fixture).await;
+    assert_eq!(fixture.grid_config().await.collection_generation, 2);
+    let new = fixture.claim(&user, Rect::new(1, 0, 1, 1)).await.unwrap();
+    assert_eq!(fixture.parcel_info(new.parcel_id).await.collection_generation, 2);
+
+    // Both still earn: the old parcel is checked against nothing newer than its generation
+    let buyer = fixture.create_user(100_000_000).await;
+    fixture.claim(&buyer, Rect::new(2, 0, 1, 1)).await.unwrap();
+    fixture.claim_rewards(&user, old).await.unwrap();
+    fixture.claim_rewards(&user, new).await.unwrap();
+}
+
+#[tokio::test]
+async fn metadata_updates_require_the_parcels_asset() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&user, Rect::new(0, 0, 1, 1)).await.unwrap();
+    let asset = Keypair::new();
+    let ix = CreateV2Builder::new()
+        .asset(asset.pubkey())
+        .payer(fixture.authority())
+        .name("Stray".to_string())
+        .uri("https://example.com/stray.json".to_string())
+        .instruction();
+    fixture.send(&[ix], &[&asset]).await.unwrap();
+
+    let name = Some("Parcel #1".to_string());
+    let ix = client::update_parcel_metadata(&fixture.grid, &asset.pubkey(), parcel.parcel_id, name.clone(), None);
+    assert_billion_error(fixture.send(&[ix], &[]).await, BillionError::AssetMismatch);
+    let ix = client::update_parcel_metadata(&fixture.grid, &parcel.asset, parcel.parcel_id, name, None);
+    fixture.send(&[ix], &[]).await.unwrap();
+}
+
+#[tokio::test]
+async fn rewards_refuse_an_asset_from_another_collection() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&user, Rect::new(0, 0, 1, 1)).await.unwrap();
+    let buyer = fixture.create_user(100_000_000).await;
+    fixture.claim(&buyer, Rect::new(1, 0, 1, 1)).await.unwrap();
+
+    // A rogue asset the claimer really owns, in a collection the grid never used
+    let (collection, rogue) = (Keypair::new(), Keypair::new());
+    let create_collection = CreateCollectionV2Builder::new()
+        .collection(collection.pubkey())
+        .payer(fixture.authority())
+        .name("Rogue Parcels".to_string())
+        .uri("https://example.com/rogue.json".to_string())
+        .instruction();
+    let create_asset = CreateV2Builder::new()
+        .asset(rogue.pubkey())
+        .collection(Some(collection.pubkey()))
+        .payer(fixture.authority())
+        .owner(Some(user.keypair.pubkey()))
+        .name("Parcel #1".to_string())
+        .uri("https://example.com/rogue/1.json".to_string())
+        .instruction();
+    fixture.send(&[create_collection, create_asset], &[&collection, &rogue]).await.unwrap();
+
+    // Repoint the ParcelInfo at it, as a bad admin write would
+    let parcel_info = client::find_parcel_info(fixture.grid.grid_id, parcel.parcel_id);
+    let mut account = fixture.ctx.banks_client.get_account(parcel_info).await.unwrap().unwrap();
+    account.data[8..40].copy_from_slice(rogue.pubkey().as_ref());
+    fixture.ctx.set_account(&parcel_info, &account.into());
+
+    let result = fixture.claim_rewards(&user, ClaimedParcel { asset: rogue.pubkey(), ..parcel }).await;
+    assert_billion_error(result, BillionError::InvalidCoreAsset);
+}
@@ -0,0 +1,181 @@
+use billion::constants::FREEZE_CONFIG_CONFIRMATION;
+use billion::errors::BillionError;
+use billion_client::{self as client, HookArgs, TimelockedConfig, UpdateConfigArgs};
+use billion_test_harness::{assert_billion_error, GridFixture, Rect};
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::system_program;
+
+async fn freeze(fixture: &mut GridFixture) {
+    let ix = client::freeze_config(fixture.grid.grid_id, &fixture.authority(), FREEZE_CONFIG_CONFIRMATION);
+    fixture.send(&[ix], &[]).await.unwrap();
+}
+
+#[tokio::test]
+async fn freezing_needs_the_authority_and_the_confirmation() {
+    let mut fixture = GridFixture::builder().build().await;
+
+    let typo = client::freeze_config(fixture.grid.grid_id, &fixture.authority(), FREEZE_CONFIG_CONFIRMATION + 1);
+    assert_billion_error(fixture.send(&[typo], &[]).await, BillionError::InvalidFreezeConfirmation);
+    let stranger = fixture.create_user(0).await;
+    let ix = client::freeze_config(fixture.grid.grid_id, &stranger.keypair.pubkey(), FREEZE_CONFIG_CONFIRMATION);
+    assert_billion_error(fixture.send(&[ix], &[&stranger.keypair]).await, BillionError::Unauthorized);
+
+    // Nothing is frozen by the failed attempts
+    let update = client::update_config(
+        fixture.grid.grid_id,
+        &fixture.authority(),
+        UpdateConfigArgs { price_per_block: Some(2_000_000), ..Default::default() },
+    );
+    fixture.send(&[update], &[]).await.unwrap();
+}
+
+#[tokio::test]
+async fn a_frozen_config_rejects_admin_changes() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 2, 2)).await.unwrap();
+    // Queued before the freeze, so only the freeze stops it executing
+    let change = TimelockedConfig { price_per_block: Some(2_000_000), ..Default::default() };
+    let queue = client::queue_config_update(fixture.grid.grid_id, &fixture.authority(), change.clone());
+    fixture.send(&[queue], &[]).await.unwrap();
+    freeze(&mut fixture).await;
+
+    let authority = fixture.authority();
+    let reprice = UpdateConfigArgs { price_per_block: Some(3_000_000), ..Default::default() };
+    let frozen = [
+        client::update_config(fixture.grid.grid_id, &authority, reprice),
+        client::execute_config_update(fixture.grid.grid_id, &authority, change),
+        client::queue_config_update(fixture.grid.grid_id, &authority, TimelockedConfig::default()),
+        client::admin_advance_ring(fixture.grid.grid_id, &authority, 2),
+        client::admin_close_parcel_info(&fixture.grid, parcel.epoch, parcel.parcel_id),
+        client::admin_purge(&fixture.grid, true),
+        client::admin_reserve_blocks(&fixture.grid, Rect::new(10, 0, 2, 1)),
+    ];
+    for ix in frozen {
+        assert_billion_error(fixture.send(&[ix], &[]).await, BillionError::ConfigFrozen);
+    }
+
+    let asset = Keypair::new();
+    let mint = client::admin_mint(&fixture.grid, &owner.keypair.pubkey(), &asset.pubkey(), 2, Rect::new(5, 5, 1, 1));
+    assert_billion_error(fixture.send(&[mint], &[&asset]).await, BillionError::ConfigFrozen);
+    let collection = Keypair::new();
+    let (name, uri) = ("Grid".to_string(), "https://example.com/collection.json".to_string());
+    let create = client::create_collection(fixture.grid.grid_id, &authority, &collection.pubkey(), name, uri);
+    assert_billion_error(fixture.send(&[create], &[&collection]).await, BillionError::ConfigFrozen);
+
+    assert_eq!(fixture.grid_config().await.price_per_block, 1_000_000);
+}
+
+#[tokio::test]
+async fn owners_keep_using_a_frozen_grid() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 2, 2)).await.unwrap();
+    freeze(&mut fixture).await;
+
+    let buyer = fixture.create_user(100_000_000).await;
+    fixture.claim(&buyer, Rect::new(4, 4, 1, 1)).await.unwrap();
+    fixture.claim_rewards(&owner, parcel).await.unwrap();
+    let rename =
+        client::update_parcel_metadata(&fixture.grid, &parcel.asset, parcel.parcel_id, Some("Plaza".to_string()), None);
+    fixture.send(&[rename], &[]).await.unwrap();
+}
+
+#[tokio::test]
+async fn the_freeze_cannot_be_undone() {
+    let mut fixture = GridFixture::builder().build().await;
+    freeze(&mut fixture).await;
+    let address = client::find_config_freeze(fixture.grid.grid_id);
+    let frozen = client::decode_config_freeze(&fixture.account_data(address).await).unwrap();
+    assert_eq!(frozen.authority, fixture.authority());
+
+    // freeze_config only ever creates the PDA, so a second call can't rewrite it
+    fixture.advance_clock(60).await;
+    let again = client::freeze_config(fixture.grid.grid_id, &fixture.authority(), FREEZE_CONFIG_CONFIRMATION);
+    assert!(fixture.send(&[again], &[]).await.is_err());
+    let after = client::decode_config_freeze(&fixture.account_data(address).await).unwrap();
+    assert_eq!((after.authority, after.frozen_at), (frozen.authority, frozen.frozen_at));
+
+    // Every admin path that could clear it on the way out is frozen too
+    let purge = client::admin_purge(&fixture.grid, true);
+    assert_billion_error(fixture.send(&[purge], &[]).await, BillionError::ConfigFrozen);
+    let grid_config = fixture.account_data(fixture.grid.grid_config).await;
+    assert!(!grid_config.is_empty());
+}
+
+#[tokio::test]
+async fn no_admin_path_unfreezes_the_config() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    freeze(&mut fixture).await;
+    let before = fixture.grid_config().await;
+
+    let authority = fixture.authority();
+    let updates = [
+        UpdateConfigArgs { claims_enabled: Some(false), ..Default::default() },
+        UpdateConfigArgs { seeding_enabled: Some(true), ..Default::default() },
+        UpdateConfigArgs { land_owners_reward_share_bps: Some(0), ..Default::default() },
+        UpdateConfigArgs { uri_base: Some("https://new.example/".to_string()), ..Default::default() },
+        UpdateConfigArgs { admin_mint_allowance_top_up: Some(5), ..Default::default() },
+    ];
+    for args in updates {
+        let ix = client::update_config(fixture.grid.grid_id, &authority, args);
+        assert_billion_error(fixture.send(&[ix], &[]).await, BillionError::ConfigFrozen);
+    }
+    let purge = client::admin_purge_parcels(&fixture.grid, &[(parcel.epoch, parcel.parcel_id)], true);
+    assert_billion_error(fixture.send(&[purge], &[]).await, BillionError::ConfigFrozen);
+    let asset = Keypair::new();
+    let entries = [(asset.pubkey(), owner.keypair.pubkey(), Rect::new(5, 5, 1, 1))];
+    let batch = client::admin_mint_batch(&fixture.grid, before.next_parcel_id, &entries);
+    assert_billion_error(fixture.send(&[batch], &[&asset]).await, BillionError::ConfigFrozen);
+
+    // The rejected calls left the config and the freeze exactly as they were
+    let after = fixture.grid_config().await;
+    assert_eq!(
+        (after.claims_paused, after.seeding_enabled, after.land_owners_reward_share_bps, after.next_parcel_id),
+        (before.claims_paused, before.seeding_enabled, before.land_owners_reward_share_bps, before.next_parcel_id),
+    );
+    let update = client::update_config(
+        fixture.grid.grid_id,
+        &authority,
+        UpdateConfigArgs { price_per_block: Some(2_000_000), ..Default::default() },
+    );
+    assert_billion_error(fixture.send(&[update], &[]).await, BillionError::ConfigFrozen);
+}
+
+#[tokio::test]
+async fn a_frozen_config_rejects_economics_and_voucher_changes() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    fixture.send(&[client::init_referral_vault(&fixture.grid)], &[]).await.unwrap();
+    freeze(&mut fixture).await;
+
+    let (grid_id, authority) = (fixture.grid.grid_id, fixture.authority());
+    let district = Rect::new(0, 0, 10, 10);
+    let hook = HookArgs { program_id: system_program::ID, guardian: authority, district, enabled: true };
+    let frozen = [
+        client::set_token_treasury(&fixture.grid, &owner.token_account, 1_000),
+        client::set_referral_bps(grid_id, &authority, 500),
+        client::set_referral_share(grid_id, &authority, 500),
+        client::admin_set_charities(grid_id, &authority, vec![owner.token_account], 1_000),
+        client::set_ring_reward_weights(grid_id, &authority, [5_000; 10]),
+        client::set_reward_expiry(grid_id, &authority, 86_400, 100),
+        client::set_exit_share(grid_id, &authority, 5_000, None),
+        client::set_adjacency_bonus(grid_id, &authority, 1_000),
+        client::set_sol_price(grid_id, &authority, 1_000_000, 0),
+        client::set_hook(grid_id, &authority, hook),
+        client::set_admin_roles(grid_id, &authority, Some(owner.keypair.pubkey()), None, None),
+        client::mint_voucher(&fixture.grid, 1, &owner.keypair.pubkey(), 2, 2, 1, 1, i64::MAX),
+    ];
+    for ix in frozen {
+        assert_billion_error(fixture.send(&[ix], &[]).await, BillionError::ConfigFrozen);
+    }
+
+    let config = fixture.grid_config().await;
+    assert_eq!((config.referral_bps, config.max_charity_bps, config.adjacency_bonus_bps), (0, 0, 0));
+    let treasury = client::find_token_treasury(grid_id);
+    assert!(fixture.ctx.banks_client.get_account(treasury).await.unwrap().is_none());
+    let voucher = client::find_voucher(grid_id, &owner.keypair.pubkey(), 1);
+    assert!(fixture.ctx.banks_client.get_account(voucher).await.unwrap().is_none());
+}
@@ -0,0 +1,123 @@
+use billion::errors::BillionError;
+use billion_client::{self as client, TimelockedConfig};
+use billion_test_harness::{assert_billion_error, GridFixture, Rect};
+
+const DAY: i64 = 86_400;
+
+async fn set_timelock(fixture: &mut GridFixture, secs: i64) {
+    // Without a timelock the first change executes straight away
+    let change = TimelockedConfig { config_timelock_secs: Some(secs), ..Default::default() };
+    let queue = client::queue_config_update(fixture.grid.grid_id, &fixture.authority(), change.clone());
+    let execute = client::execute_config_update(fixture.grid.grid_id, &fixture.authority(), change);
+    fixture.send(&[queue, execute], &[]).await.unwrap();
+}
+
+async fn with_timelock(secs: i64) -> GridFixture {
+    let mut fixture = GridFixture::builder().build().await;
+    set_timelock(&mut fixture, secs).await;
+    fixture
+}
+
+/// Queues `change` and executes it once the timelock has passed
+async fn queue_and_execute(fixture: &mut GridFixture, change: TimelockedConfig) {
+    let queue = client::queue_config_update(fixture.grid.grid_id, &fixture.authority(), change.clone());
+    fixture.send(&[queue], &[]).await.unwrap();
+    fixture.advance_clock(DAY).await;
+    let execute = client::execute_config_update(fixture.grid.grid_id, &fixture.authority(), change);
+    fixture.send(&[execute], &[]).await.unwrap();
+}
+
+#[tokio::test]
+async fn price_changes_wait_out_the_timelock() {
+    let mut fixture = with_timelock(DAY).await;
+    let reprice = client::update_config(
+        fixture.grid.grid_id,
+        &fixture.authority(),
+        client::UpdateConfigArgs { price_per_block: Some(2_000_000), ..Default::default() },
+    );
+    assert_billion_error(fixture.send(&[reprice], &[]).await, BillionError::ConfigTimelocked);
+
+    let change = TimelockedConfig { price_per_block: Some(2_000_000), ..Default::default() };
+    let queue = client::queue_config_update(fixture.grid.grid_id, &fixture.authority(), change.clone());
+    fixture.send(&[queue], &[]).await.unwrap();
+    let execute = client::execute_config_update(fixture.grid.grid_id, &fixture.authority(), change.clone());
+    assert_billion_error(fixture.send(&[execute], &[]).await, BillionError::ConfigUpdateNotReady);
+
+    fixture.advance_clock(DAY).await;
+    let other = TimelockedConfig { price_per_block: Some(1), ..Default::default() };
+    let execute = client::execute_config_update(fixture.grid.grid_id, &fixture.authority(), other);
+    assert_billion_error(fixture.send(&[execute], &[]).await, BillionError::ConfigUpdateMismatch);
+    let execute = client::execute_config_update(fixture.grid.grid_id, &fixture.authority(), change);
+    fixture.send(&[execute], &[]).await.unwrap();
+    assert_eq!(fixture.grid_config().await.price_per_block, 2_000_000);
+}
+
+#[tokio::test]
+async fn instant_fields_skip_the_timelock() {
+    let mut fixture = with_timelock(DAY).await;
+    let pause = client::update_config(
+        fixture.grid.grid_id,
+        &fixture.authority(),
+        client::UpdateConfigArgs { claims_enabled: Some(false), ..Default::default() },
+    );
+    fixture.send(&[pause], &[]).await.unwrap();
+    assert!(fixture.grid_config().await.claims_paused);
+}
+
+#[tokio::test]
+async fn cancelled_changes_never_execute() {
+    let mut fixture = with_timelock(DAY).await;
+    let change = TimelockedConfig { land_owners_reward_share_bps: Some(0), ..Default::default() };
+    let queue = client::queue_config_update(fixture.grid.grid_id, &fixture.authority(), change.clone());
+    fixture.send(&[queue], &[]).await.unwrap();
+    let another = TimelockedConfig { land_owners_reward_share_bps: Some(1), ..Default::default() };
+    let queue = client::queue_config_update(fixture.grid.grid_id, &fixture.authority(), another);
+    assert_billion_error(fixture.send(&[queue], &[]).await, BillionError::ConfigUpdatePending);
+
+    fixture.send(&[client::cancel_config_update(fixture.grid.grid_id, &fixture.authority())], &[]).await.unwrap();
+    fixture.advance_clock(DAY).await;
+    let execute = client::execute_config_update(fixture.grid.grid_id, &fixture.authority(), change);
+    assert_billion_error(fixture.send(&[execute], &[]).await, BillionError::NoPendingConfigUpdate);
+    let lock = client::decode_config_timelock(&fixture.account_data(client::find_config_timelock(fixture.grid.grid_id)).await).unwrap();
+    assert_eq!(lock.timelock_secs, DAY);
+}
+
+#[tokio::test]
+async fn ring_multipliers_wait_out_the_timelock() {
+    let mut fixture = GridFixture::builder().ring_thresholds(vec![0, 0]).build().await;
+    set_timelock(&mut fixture, DAY).await;
+    let user = fixture.create_user(100_000_000).await;
+    let mut multipliers = vec![10_000u16; 10];
+    multipliers[1] = 15_000;
+    let reprice = client::update_config(
+        fixture.grid.grid_id,
+        &fixture.authority(),
+        client::UpdateConfigArgs { ring_price_multipliers_bps: Some(multipliers.clone()), ..Default::default() },
+    );
+    assert_billion_error(fixture.send(&[reprice], &[]).await, BillionError::ConfigTimelocked);
+
+    let change = TimelockedConfig { ring_price_multipliers_bps: Some(multipliers), ..Default::default() };
+    queue_and_execute(&mut fixture, change).await;
+    // Column 5 is ring 1 at 1_000_000 per block, column 6 is ring 2 at 1_500_000
+    fixture.claim(&user, Rect::new(5, 20, 2, 2)).await.unwrap();
+    assert_eq!(fixture.token_balance(user.token_account).await, 100_000_000 - 5_000_000);
+}
+
+#[tokio::test]
+async fn curve_divisor_waits_out_the_timelock() {
+    let mut fixture = with_timelock(DAY).await;
+    let user = fixture.create_user(100_000_000).await;
+    let steepen = client::update_config(
+        fixture.grid.grid_id,
+        &fixture.authority(),
+        client::UpdateConfigArgs { curve_divisor: Some(1), ..Default::default() },
+    );
+    assert_billion_error(fixture.send(&[steepen], &[]).await, BillionError::ConfigTimelocked);
+    fixture.claim(&user, Rect::new(0, 0, 2, 2)).await.unwrap();
+    assert_eq!(fixture.token_balance(user.token_account).await, 100_000_000 - 4_000_000);
+
+    queue_and_execute(&mut fixture, TimelockedConfig { curve_divisor: Some(4), ..Default::default() }).await;
+    // 4 claimed blocks over a divisor of 4 double the price
+    fixture.claim(&user, Rect::new(0, 2, 2, 1)).await.unwrap();
+    assert_eq!(fixture.token_balance(user.token_account).await, 100_000_000 - 8_000_000);
+}
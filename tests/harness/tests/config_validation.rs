@@ -0,0 +1,93 @@
+use billion::errors::BillionError;
+use billion_client::{self as client, TimelockedConfig, UpdateConfigArgs};
+use billion_test_harness::{assert_billion_error, GridFixture};
+
+#[tokio::test]
+async fn update_config_rejects_unusable_values() {
+    let mut fixture = GridFixture::builder().build().await;
+    let cases = [
+        (
+            UpdateConfigArgs { land_owners_reward_share_bps: Some(60_000), ..Default::default() },
+            BillionError::InvalidRewardShare,
+        ),
+        (
+            UpdateConfigArgs { ring_thresholds: Some(vec![0, 0]), ..Default::default() },
+            BillionError::InvalidRingThresholds,
+        ),
+        (
+            UpdateConfigArgs { ring_thresholds: Some((0..10).rev().collect()), ..Default::default() },
+            BillionError::FirstRingThresholdNotZero,
+        ),
+        (
+            UpdateConfigArgs { ring_thresholds: Some(vec![0, 2, 1, 3, 4, 5, 6, 7, 8, 9]), ..Default::default() },
+            BillionError::RingThresholdsNotAscending,
+        ),
+        (
+            UpdateConfigArgs { uri_base: Some("https://example.com/parcel".to_string()), ..Default::default() },
+            BillionError::InvalidUriBase,
+        ),
+    ];
+    for (args, error) in cases {
+        let update = client::update_config(fixture.grid.grid_id, &fixture.authority(), args);
+        assert_billion_error(fixture.send(&[update], &[]).await, error);
+    }
+}
+
+#[tokio::test]
+async fn the_reward_share_leaves_room_for_referrals_and_charities() {
+    let mut fixture = GridFixture::builder().build().await;
+    let authority = fixture.authority();
+    let grid_id = fixture.grid.grid_id;
+    let charity = fixture.create_user(0).await;
+    let setup = [
+        client::init_referral_vault(&fixture.grid),
+        client::set_referral_bps(grid_id, &authority, 2_000),
+        client::admin_set_charities(grid_id, &authority, vec![charity.token_account], 2_000),
+    ];
+    fixture.send(&setup, &[]).await.unwrap();
+
+    let raise = |bps| UpdateConfigArgs { land_owners_reward_share_bps: Some(bps), ..Default::default() };
+    let update = client::update_config(grid_id, &authority, raise(6_001));
+    assert_billion_error(fixture.send(&[update], &[]).await, BillionError::InvalidRewardShare);
+    let change = TimelockedConfig { land_owners_reward_share_bps: Some(6_001), ..Default::default() };
+    let queue = client::queue_config_update(grid_id, &authority, change);
+    assert_billion_error(fixture.send(&[queue], &[]).await, BillionError::InvalidRewardShare);
+
+    // Fits when queued, but the referral share grows before it executes
+    let change = TimelockedConfig { land_owners_reward_share_bps: Some(5_000), ..Default::default() };
+    fixture.send(&[client::queue_config_update(grid_id, &authority, change.clone())], &[]).await.unwrap();
+    fixture.send(&[client::set_referral_bps(grid_id, &authority, 3_500)], &[]).await.unwrap();
+    let execute = client::execute_config_update(grid_id, &authority, change);
+    assert_billion_error(fixture.send(&[execute], &[]).await, BillionError::InvalidRewardShare);
+
+    let update = client::update_config(grid_id, &authority, raise(4_500));
+    fixture.send(&[update], &[]).await.unwrap();
+    assert_eq!(fixture.grid_config().await.land_owners_reward_share_bps, 4_500);
+}
+
+#[tokio::test]
+async fn total_burned_is_not_set_through_update_config() {
+    let mut fixture = GridFixture::builder().build().await;
+    let raise = client::update_config(
+        fixture.grid.grid_id,
+        &fixture.authority(),
+        UpdateConfigArgs { total_burned: Some(50_000_000), ..Default::default() },
+    );
+    assert_billion_error(fixture.send(&[raise], &[]).await, BillionError::TotalBurnedOverrideRemoved);
+    assert_eq!(fixture.grid_config().await.total_burned, 0);
+}
+
+#[tokio::test]
+async fn initialize_rejects_misordered_ring_thresholds() {
+    let cases = [
+        (vec![10_000_000, 50_000_000], BillionError::FirstRingThresholdNotZero),
+        (vec![0, 50_000_000, 10_000_000], BillionError::RingThresholdsNotAscending),
+    ];
+    for (thresholds, error) in cases {
+        let result = GridFixture::builder().ring_thresholds(thresholds).try_build().await.map(|_| ());
+        assert_billion_error(result, error);
+    }
+    // Rings sharing a threshold unlock together
+    let mut fixture = GridFixture::builder().ring_thresholds(vec![0, 0, 0]).build().await;
+    assert_eq!(fixture.grid_config().await.unlocked_ring(), 3);
+}
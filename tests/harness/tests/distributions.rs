@@ -0,0 +1,105 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, ClaimedParcel, GridFixture, Rect, TestUser};
+use solana_sdk::{
+    clock::Clock,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+const TOKEN_2022: Pubkey = spl_token_2022::ID;
+const WINDOW: i64 = 1_000;
+
+/// Grid with a 1-block and a 3-block parcel, and a 1_000-token distribution over them that
+/// expires in `WINDOW` seconds. Uses the grid's own mint for simplicity; any mint works.
+async fn distribution_fixture() -> (GridFixture, Vec<(TestUser, ClaimedParcel)>, TestUser, Keypair) {
+    let mut fixture = GridFixture::builder().build().await;
+    let small = fixture.create_user(100_000_000).await;
+    let large = fixture.create_user(100_000_000).await;
+    let small_parcel = fixture.claim(&small, Rect::new(0, 0, 1, 1)).await.unwrap();
+    let large_parcel = fixture.claim(&large, Rect::new(1, 0, 3, 1)).await.unwrap();
+
+    let distributor = fixture.create_user(1_000).await;
+    let distribution = Keypair::new();
+    let clock: Clock = fixture.ctx.banks_client.get_sysvar().await.unwrap();
+    let ix = client::create_distribution(
+        fixture.grid.grid_id,
+        &distributor.keypair.pubkey(),
+        &distribution.pubkey(),
+        &fixture.grid.token_mint,
+        &TOKEN_2022,
+        1_000,
+        clock.unix_timestamp + WINDOW,
+    );
+    fixture.send(&[ix], &[&distributor.keypair, &distribution]).await.unwrap();
+    (fixture, vec![(small, small_parcel), (large, large_parcel)], distributor, distribution)
+}
+
+fn claim_ix(fixture: &GridFixture, distribution: &Keypair, owner: &TestUser, parcel: ClaimedParcel) -> Instruction {
+    client::claim_distribution(
+        fixture.grid.grid_id,
+        &fixture.ctx.payer.pubkey(),
+        &distribution.pubkey(),
+        &fixture.grid.token_mint,
+        &TOKEN_2022,
+        &owner.keypair.pubkey(),
+        &parcel.asset,
+        0,
+        parcel.parcel_id,
+    )
+}
+
+#[tokio::test]
+async fn landowners_share_pro_rata_once() {
+    let (mut fixture, owners, _, distribution) = distribution_fixture().await;
+
+    for ((owner, parcel), expected) in owners.iter().zip([250, 750]) {
+        let before = fixture.token_balance(owner.token_account).await;
+        let ix = claim_ix(&fixture, &distribution, owner, *parcel);
+        fixture.send(&[ix], &[]).await.unwrap();
+        assert_eq!(fixture.token_balance(owner.token_account).await - before, expected);
+    }
+
+    let (owner, parcel) = &owners[0];
+    let ix = claim_ix(&fixture, &distribution, owner, *parcel);
+    assert_billion_error(fixture.send(&[ix], &[]).await, BillionError::DistributionAlreadyClaimed);
+}
+
+#[tokio::test]
+async fn parcels_claimed_after_snapshot_are_excluded() {
+    let (mut fixture, _, _, distribution) = distribution_fixture().await;
+    let late = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&late, Rect::new(4, 0, 1, 1)).await.unwrap();
+
+    let ix = claim_ix(&fixture, &distribution, &late, parcel);
+    assert_billion_error(fixture.send(&[ix], &[]).await, BillionError::ParcelNotInSnapshot);
+}
+
+#[tokio::test]
+async fn distributor_sweeps_unclaimed_after_expiry() {
+    let (mut fixture, owners, distributor, distribution) = distribution_fixture().await;
+    let (owner, parcel) = &owners[0];
+    let ix = claim_ix(&fixture, &distribution, owner, *parcel);
+    fixture.send(&[ix], &[]).await.unwrap();
+
+    let sweep = client::sweep_distribution(
+        fixture.grid.grid_id,
+        &distributor.keypair.pubkey(),
+        &distribution.pubkey(),
+        &fixture.grid.token_mint,
+        &TOKEN_2022,
+    );
+    let ix = sweep.clone();
+    assert_billion_error(fixture.send(&[ix], &[&distributor.keypair]).await, BillionError::DistributionNotExpired);
+
+    fixture.advance_clock(WINDOW).await;
+    let (owner, parcel) = &owners[1];
+    let ix = claim_ix(&fixture, &distribution, owner, *parcel);
+    assert_billion_error(fixture.send(&[ix], &[]).await, BillionError::DistributionExpired);
+
+    fixture.send(&[sweep], &[&distributor.keypair]).await.unwrap();
+    assert_eq!(fixture.token_balance(distributor.token_account).await, 750);
+    let closed = fixture.ctx.banks_client.get_account(distribution.pubkey()).await.unwrap();
+    assert!(closed.is_none());
+}
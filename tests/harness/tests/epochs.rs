@@ -0,0 +1,89 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, GridFixture, Rect};
+use solana_sdk::signature::Signer;
+
+#[tokio::test]
+async fn new_epoch_resets_grid_and_archives_counters() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+    fixture.claim(&user, Rect::new(0, 0, 2, 2)).await.unwrap();
+    let before = fixture.grid_config().await;
+    let old_block_map = fixture.grid.block_map;
+
+    fixture.start_new_epoch().await.unwrap();
+
+    let config = fixture.grid_config().await;
+    assert_eq!(config.epoch, 1);
+    assert_eq!(config.next_parcel_id, 1);
+    assert_eq!(config.total_claimed_blocks, 0);
+    assert_eq!(config.land_buy_rewards_per_block, 0);
+    assert_eq!(config.total_burned, before.total_burned);
+
+    let archive = client::decode_epoch_archive(&fixture.account_data(client::find_epoch_archive(fixture.grid.grid_id, 0)).await).unwrap();
+    assert_eq!(archive.block_map, old_block_map);
+    assert_eq!(archive.next_parcel_id, before.next_parcel_id);
+    assert_eq!(archive.total_claimed_blocks, 4);
+    assert_eq!(archive.land_buy_rewards_per_block, before.land_buy_rewards_per_block);
+
+    // The same blocks and parcel id are free again in the new epoch, under a new ParcelInfo
+    let parcel = fixture.claim(&user, Rect::new(0, 0, 2, 2)).await.unwrap();
+    assert_eq!(parcel.parcel_id, 1);
+    assert_eq!(parcel.epoch, 1);
+    assert_eq!(fixture.parcel_info(1).await.epoch, 1);
+    assert!(fixture.ctx.banks_client.get_account(client::find_parcel_info(fixture.grid.grid_id, 1)).await.unwrap().is_some());
+}
+
+#[tokio::test]
+async fn old_block_map_is_frozen() {
+    let mut fixture = GridFixture::builder().build().await;
+    let old_block_map = fixture.grid.block_map;
+    fixture.start_new_epoch().await.unwrap();
+
+    // frozen follows the blocks array and bump
+    let frozen_offset = billion::state::BlockMap::SIZE - billion::state::BlockMap::TRAILER_LEN + 1;
+    assert_eq!(fixture.account_data(old_block_map).await[frozen_offset], 1);
+    assert_eq!(fixture.block_map_data().await[frozen_offset], 0);
+}
+
+#[tokio::test]
+async fn archived_parcels_claim_rewards_against_archived_accumulator() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    let buyer = fixture.create_user(100_000_000).await;
+    fixture.claim(&buyer, Rect::new(1, 0, 1, 1)).await.unwrap();
+
+    fixture.start_new_epoch().await.unwrap();
+    // New-epoch purchases don't accrue to archived parcels
+    fixture.claim(&buyer, Rect::new(0, 0, 3, 3)).await.unwrap();
+
+    let archive = client::decode_epoch_archive(&fixture.account_data(client::find_epoch_archive(fixture.grid.grid_id, 0)).await).unwrap();
+    let before = fixture.token_balance(owner.token_account).await;
+    fixture.claim_rewards(&owner, parcel).await.unwrap();
+    let expected = (archive.land_buy_rewards_per_block / billion::constants::REWARD_SCALE) as u64;
+    assert_eq!(fixture.token_balance(owner.token_account).await - before, expected);
+
+    // Without the archive the claim is refused rather than paid from the new accumulator
+    let mut stale = fixture.grid;
+    stale.epoch = 0;
+    let ix = client::claim_land_buy_rewards(&stale, &owner.keypair.pubkey(), &parcel.asset, parcel.parcel_id);
+    assert_billion_error(fixture.send(&[ix], &[&owner.keypair]).await, BillionError::EpochArchiveRequired);
+}
+
+#[tokio::test]
+async fn archived_parcels_cannot_be_staked() {
+    let mut fixture = GridFixture::builder().build().await;
+    let authority = fixture.authority();
+    let init = client::init_emissions_vault(&fixture.grid);
+    fixture.send(&[init, client::set_emission_rate(fixture.grid.grid_id, &authority, 1)], &[]).await.unwrap();
+    let owner = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+
+    fixture.start_new_epoch().await.unwrap();
+
+    let mut grid = fixture.grid;
+    grid.epoch = parcel.epoch;
+    let ix = client::stake_parcel(&grid, &owner.keypair.pubkey(), &parcel.asset, parcel.parcel_id);
+    assert_billion_error(fixture.send(&[ix], &[&owner.keypair]).await, BillionError::ParcelArchived);
+}
@@ -0,0 +1,78 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, ClaimedParcel, GridFixture, Rect, TestUser};
+use solana_sdk::signature::Signer;
+
+/// A 2x2 parcel claimed into an empty grid (its 800_000 reward share is surplus), then a
+/// 1-block claim that leaves it 200_000 of pending rewards
+async fn exit_fixture() -> (GridFixture, TestUser, ClaimedParcel) {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let buyer = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 2, 2)).await.unwrap();
+    fixture.claim(&buyer, Rect::new(99, 0, 1, 1)).await.unwrap();
+    (fixture, owner, parcel)
+}
+
+async fn burn(fixture: &mut GridFixture, owner: &TestUser, parcel: ClaimedParcel) -> u64 {
+    let before = fixture.token_balance(owner.token_account).await;
+    let ix = client::burn_for_exit(&fixture.grid, &owner.keypair.pubkey(), &parcel.asset, parcel.parcel_id);
+    fixture.send(&[ix], &[&owner.keypair]).await.unwrap();
+    fixture.token_balance(owner.token_account).await - before
+}
+
+#[tokio::test]
+async fn default_exit_share_pays_only_pending_rewards() {
+    let (mut fixture, owner, parcel) = exit_fixture().await;
+    assert_eq!(fixture.grid_config().await.reward_liability, 200_000);
+
+    assert_eq!(burn(&mut fixture, &owner, parcel).await, 200_000);
+
+    let config = fixture.grid_config().await;
+    assert_eq!(config.total_claimed_blocks, 1);
+    assert_eq!(config.reward_liability, 0);
+    let pool = fixture.reward_pool();
+    assert_eq!(fixture.token_balance(pool).await, 800_000);
+}
+
+#[tokio::test]
+async fn exit_share_pays_pro_rata_share_of_surplus() {
+    let (mut fixture, owner, parcel) = exit_fixture().await;
+    let authority = fixture.authority();
+    fixture.send(&[client::set_exit_share(fixture.grid.grid_id, &authority, 5_000, None)], &[]).await.unwrap();
+
+    // 200_000 pending, plus half of 4/5 of the 800_000 surplus
+    assert_eq!(burn(&mut fixture, &owner, parcel).await, 200_000 + 320_000);
+}
+
+#[tokio::test]
+async fn burn_frees_blocks_and_closes_parcel_info() {
+    let (mut fixture, owner, parcel) = exit_fixture().await;
+    burn(&mut fixture, &owner, parcel).await;
+
+    let parcel_info = client::find_parcel_info(fixture.grid.grid_id, parcel.parcel_id);
+    assert!(fixture.ctx.banks_client.get_account(parcel_info).await.unwrap().is_none());
+    // Core leaves a one-byte tombstone behind a burned asset
+    let asset = fixture.ctx.banks_client.get_account(parcel.asset).await.unwrap();
+    assert!(asset.map(|a| a.data.len()).unwrap_or(0) <= 1);
+    assert_eq!(fixture.block(1, 1).await, 0);
+
+    let next = fixture.claim(&owner, Rect::new(0, 0, 2, 2)).await.unwrap();
+    assert_eq!(fixture.block(1, 1).await, next.parcel_id);
+}
+
+#[tokio::test]
+async fn exit_share_is_bounded_and_authority_only() {
+    let (mut fixture, owner, _) = exit_fixture().await;
+    let authority = fixture.authority();
+    assert_billion_error(
+        fixture.send(&[client::set_exit_share(fixture.grid.grid_id, &authority, 10_001, None)], &[]).await,
+        BillionError::InvalidExitShare,
+    );
+    assert_billion_error(
+        fixture
+            .send(&[client::set_exit_share(fixture.grid.grid_id, &owner.keypair.pubkey(), 5_000, None)], &[&owner.keypair])
+            .await,
+        BillionError::Unauthorized,
+    );
+}
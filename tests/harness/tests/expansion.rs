@@ -0,0 +1,95 @@
+use billion::constants::EXPANSION_PRIORITY_WINDOW_SECS;
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, ClaimedParcel, GridFixture, Rect, TestUser};
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+
+/// (6, 20) is the first ring-2 cell east of the ring-1 cell (5, 20)
+const RING_2_NEXT_TO_OWNER: Rect = Rect::new(6, 20, 1, 1);
+const RING_2_ELSEWHERE: Rect = Rect::new(6, 30, 1, 1);
+
+/// Ring 2 unlocks with the first claim's 800_000 burn: the owner's ring-1 parcel at (5, 20)
+async fn expansion_fixture() -> (GridFixture, TestUser, ClaimedParcel) {
+    let mut fixture = GridFixture::builder().ring_thresholds(vec![0, 800_000]).build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&owner, Rect::new(5, 20, 1, 1)).await.unwrap();
+    assert_eq!(fixture.grid_config().await.priority_ring, 2);
+    (fixture, owner, parcel)
+}
+
+async fn claim_with_proof(
+    fixture: &mut GridFixture,
+    user: &TestUser,
+    rect: Rect,
+    proof: ClaimedParcel,
+) -> Result<(), BanksClientError> {
+    let parcel_id = fixture.grid_config().await.next_parcel_id;
+    let asset = Keypair::new();
+    let ix = client::claim_parcel(&fixture.grid, &user.keypair.pubkey(), &asset.pubkey(), parcel_id, rect, None);
+    let ix = client::with_adjacency_proof(ix, fixture.grid.grid_id, proof.epoch, proof.parcel_id, &proof.asset);
+    fixture.send(&[ix], &[&user.keypair, &asset]).await
+}
+
+#[tokio::test]
+async fn adjacent_owner_claims_during_window() {
+    let (mut fixture, owner, parcel) = expansion_fixture().await;
+    claim_with_proof(&mut fixture, &owner, RING_2_NEXT_TO_OWNER, parcel).await.unwrap();
+    assert_ne!(fixture.block(6, 20).await, 0);
+
+    // A wider claim only needs one of its cells to border the proof parcel
+    claim_with_proof(&mut fixture, &owner, Rect::new(5, 21, 3, 1), parcel).await.unwrap();
+}
+
+#[tokio::test]
+async fn newly_unlocked_cells_need_a_proof() {
+    let (mut fixture, owner, _) = expansion_fixture().await;
+    let outsider = fixture.create_user(100_000_000).await;
+    assert_billion_error(fixture.claim(&outsider, RING_2_NEXT_TO_OWNER).await, BillionError::AdjacencyProofRequired);
+    assert_billion_error(fixture.claim(&owner, RING_2_ELSEWHERE).await, BillionError::AdjacencyProofRequired);
+
+    // Cells in rings that were already open stay public
+    fixture.claim(&outsider, Rect::new(4, 40, 1, 1)).await.unwrap();
+}
+
+#[tokio::test]
+async fn proof_must_be_an_adjacent_parcel_of_the_claimer() {
+    let (mut fixture, owner, parcel) = expansion_fixture().await;
+    let outsider = fixture.create_user(100_000_000).await;
+
+    // Someone else's parcel
+    assert_billion_error(
+        claim_with_proof(&mut fixture, &outsider, RING_2_NEXT_TO_OWNER, parcel).await,
+        BillionError::InvalidAdjacencyProof,
+    );
+    // The claimer's parcel, but not bordering the claim
+    assert_billion_error(
+        claim_with_proof(&mut fixture, &owner, RING_2_ELSEWHERE, parcel).await,
+        BillionError::InvalidAdjacencyProof,
+    );
+    // Diagonal contact doesn't count as sharing an edge
+    assert_billion_error(
+        claim_with_proof(&mut fixture, &owner, Rect::new(6, 21, 1, 1), parcel).await,
+        BillionError::InvalidAdjacencyProof,
+    );
+
+    // An asset that doesn't belong to the ParcelInfo
+    let mut forged = parcel;
+    forged.asset = fixture.claim(&outsider, Rect::new(4, 40, 1, 1)).await.unwrap().asset;
+    assert_billion_error(
+        claim_with_proof(&mut fixture, &outsider, RING_2_NEXT_TO_OWNER, forged).await,
+        BillionError::InvalidAdjacencyProof,
+    );
+}
+
+#[tokio::test]
+async fn window_closes_after_an_hour() {
+    let (mut fixture, _, _) = expansion_fixture().await;
+    let outsider = fixture.create_user(100_000_000).await;
+
+    fixture.advance_clock(EXPANSION_PRIORITY_WINDOW_SECS - 1).await;
+    assert_billion_error(fixture.claim(&outsider, RING_2_NEXT_TO_OWNER).await, BillionError::AdjacencyProofRequired);
+
+    fixture.advance_clock(1).await;
+    fixture.claim(&outsider, RING_2_NEXT_TO_OWNER).await.unwrap();
+}
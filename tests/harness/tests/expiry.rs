@@ -0,0 +1,74 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, ClaimedParcel, GridFixture, Rect, TestUser};
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::Signer;
+
+const EXPIRY: i64 = 1_000;
+
+/// Two 1-block parcels and a 2-block claim: 400_000 pending on the first, 200_000 on the second
+async fn expiry_fixture() -> (GridFixture, TestUser, ClaimedParcel, ClaimedParcel) {
+    let mut fixture = GridFixture::builder().build().await;
+    let authority = fixture.authority();
+    fixture.send(&[client::set_reward_expiry(fixture.grid.grid_id, &authority, EXPIRY, 500)], &[]).await.unwrap();
+    let owner = fixture.create_user(100_000_000).await;
+    let dormant = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    let active = fixture.claim(&owner, Rect::new(1, 0, 1, 1)).await.unwrap();
+    fixture.claim(&owner, Rect::new(10, 0, 2, 1)).await.unwrap();
+    (fixture, owner, dormant, active)
+}
+
+async fn expire(
+    fixture: &mut GridFixture,
+    cranker: &TestUser,
+    parcel: ClaimedParcel,
+) -> Result<(), BanksClientError> {
+    let ix = client::expire_parcel_rewards(&fixture.grid, &cranker.keypair.pubkey(), parcel.parcel_id);
+    fixture.send(&[ix], &[&cranker.keypair]).await
+}
+
+#[tokio::test]
+async fn lapsed_rewards_go_to_other_landowners_and_cranker() {
+    let (mut fixture, owner, dormant, active) = expiry_fixture().await;
+    let cranker = fixture.create_user(0).await;
+    fixture.advance_clock(EXPIRY + 1).await;
+
+    expire(&mut fixture, &cranker, dormant).await.unwrap();
+    // 5% bounty of the 400_000 owed; the other 380_000 spread over the remaining 3 blocks
+    assert_eq!(fixture.token_balance(cranker.token_account).await, 20_000);
+
+    let before = fixture.token_balance(owner.token_account).await;
+    fixture.claim_rewards(&owner, active).await.unwrap();
+    assert_eq!(fixture.token_balance(owner.token_account).await - before, 200_000 + 126_666);
+
+    // Nothing left to lapse, and the dormant parcel doesn't earn its own lapsed rewards back
+    assert_billion_error(expire(&mut fixture, &cranker, dormant).await, BillionError::NothingToClaim);
+    assert_billion_error(fixture.claim_rewards(&owner, dormant).await, BillionError::NothingToClaim);
+}
+
+#[tokio::test]
+async fn claiming_resets_the_expiry_clock() {
+    let (mut fixture, owner, dormant, _) = expiry_fixture().await;
+    let cranker = fixture.create_user(0).await;
+    fixture.advance_clock(EXPIRY - 10).await;
+    assert_billion_error(expire(&mut fixture, &cranker, dormant).await, BillionError::RewardsNotExpired);
+
+    fixture.claim_rewards(&owner, dormant).await.unwrap();
+    fixture.advance_clock(20).await;
+    assert_billion_error(expire(&mut fixture, &cranker, dormant).await, BillionError::RewardsNotExpired);
+}
+
+#[tokio::test]
+async fn zero_expiry_disables_the_crank() {
+    let (mut fixture, _, dormant, _) = expiry_fixture().await;
+    let authority = fixture.authority();
+    fixture.send(&[client::set_reward_expiry(fixture.grid.grid_id, &authority, 0, 500)], &[]).await.unwrap();
+    let cranker = fixture.create_user(0).await;
+    fixture.advance_clock(EXPIRY * 10).await;
+    assert_billion_error(expire(&mut fixture, &cranker, dormant).await, BillionError::RewardExpiryDisabled);
+
+    assert_billion_error(
+        fixture.send(&[client::set_reward_expiry(fixture.grid.grid_id, &authority, EXPIRY, 1_001)], &[]).await,
+        BillionError::InvalidRewardExpiry,
+    );
+}
@@ -0,0 +1,127 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, ClaimedParcel, GridFixture, Rect, TestUser};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+const SHARES: u64 = 1_000;
+
+/// Grid with a 2x2 parcel fractionalized into SHARES shares, all held by its owner
+async fn fraction_fixture() -> (GridFixture, TestUser, ClaimedParcel) {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 2, 2)).await.unwrap();
+    let share_mint = Keypair::new();
+    let ix = client::fractionalize_parcel(
+        &fixture.grid,
+        &owner.keypair.pubkey(),
+        &share_mint.pubkey(),
+        &parcel.asset,
+        parcel.parcel_id,
+        SHARES,
+    );
+    fixture.send(&[ix], &[&owner.keypair, &share_mint]).await.unwrap();
+    (fixture, owner, parcel)
+}
+
+/// Give `to` an ATA for the share mint and move `amount` shares into it from `from`
+async fn send_shares(fixture: &mut GridFixture, share_mint: &Pubkey, from: &TestUser, to: &TestUser, amount: u64) {
+    let create = spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+        &fixture.authority(),
+        &to.keypair.pubkey(),
+        share_mint,
+        &spl_token_2022::id(),
+    );
+    let transfer = spl_token_2022::instruction::transfer_checked(
+        &spl_token_2022::id(),
+        &client::find_token_account(&from.keypair.pubkey(), share_mint),
+        share_mint,
+        &client::find_token_account(&to.keypair.pubkey(), share_mint),
+        &from.keypair.pubkey(),
+        &[],
+        amount,
+        0,
+    )
+    .unwrap();
+    fixture.send(&[create, transfer], &[&from.keypair]).await.unwrap();
+}
+
+#[tokio::test]
+async fn fractionalize_escrows_asset_and_mints_shares() {
+    let (mut fixture, owner, parcel) = fraction_fixture().await;
+    let fraction = fixture.fraction(parcel).await;
+
+    let fraction_pda = client::find_fraction(fixture.grid.grid_id, parcel.epoch, parcel.parcel_id);
+    assert_eq!(fixture.asset_owner(parcel.asset).await, fraction_pda);
+    let owner_shares = client::find_token_account(&owner.keypair.pubkey(), &fraction.share_mint);
+    assert_eq!(fixture.token_balance(owner_shares).await, SHARES);
+
+    // The escrowed asset no longer answers to its former owner
+    assert_billion_error(fixture.claim_rewards(&owner, parcel).await, BillionError::NotOwner);
+}
+
+#[tokio::test]
+async fn rewards_split_pro_rata_between_deposited_shares() {
+    let (mut fixture, owner, parcel) = fraction_fixture().await;
+    let fraction = fixture.fraction(parcel).await;
+    let holder = fixture.create_user(0).await;
+    send_shares(&mut fixture, &fraction.share_mint, &owner, &holder, 400).await;
+
+    for (user, amount) in [(&owner, 600), (&holder, 400)] {
+        let ix = client::deposit_fraction_shares(&fixture.grid, &user.keypair.pubkey(), &fraction, amount);
+        fixture.send(&[ix], &[&user.keypair]).await.unwrap();
+    }
+
+    // The fractionalized parcel holds every claimed block, so it earns the whole landowner share
+    let buyer = fixture.create_user(100_000_000).await;
+    fixture.claim(&buyer, Rect::new(10, 0, 5, 1)).await.unwrap();
+
+    let owner_before = fixture.token_balance(owner.token_account).await;
+    let holder_before = fixture.token_balance(holder.token_account).await;
+    for user in [&owner, &holder] {
+        let ix = client::claim_fraction_rewards(&fixture.grid, &user.keypair.pubkey(), &fraction);
+        fixture.send(&[ix], &[&user.keypair]).await.unwrap();
+    }
+
+    let harvested = fixture.fraction(parcel).await.total_harvested;
+    assert!(harvested > 0);
+    assert_eq!(fixture.token_balance(owner.token_account).await - owner_before, harvested * 600 / SHARES);
+    assert_eq!(fixture.token_balance(holder.token_account).await - holder_before, harvested * 400 / SHARES);
+
+    let ix = client::claim_fraction_rewards(&fixture.grid, &holder.keypair.pubkey(), &fraction);
+    assert_billion_error(fixture.send(&[ix], &[&holder.keypair]).await, BillionError::NothingToClaim);
+}
+
+#[tokio::test]
+async fn redeem_requires_every_share() {
+    let (mut fixture, owner, parcel) = fraction_fixture().await;
+    let fraction = fixture.fraction(parcel).await;
+
+    let deposit = client::deposit_fraction_shares(&fixture.grid, &owner.keypair.pubkey(), &fraction, 1);
+    fixture.send(&[deposit], &[&owner.keypair]).await.unwrap();
+    let redeem = client::redeem_parcel(&fixture.grid, &owner.keypair.pubkey(), &fraction);
+    assert_billion_error(
+        fixture.send(&[redeem.clone()], &[&owner.keypair]).await,
+        BillionError::InsufficientBalance,
+    );
+
+    let withdraw = client::withdraw_fraction_shares(&fixture.grid, &owner.keypair.pubkey(), &fraction, 1);
+    fixture.send(&[withdraw, redeem], &[&owner.keypair]).await.unwrap();
+
+    assert_eq!(fixture.asset_owner(parcel.asset).await, owner.keypair.pubkey());
+    let fraction_pda = client::find_fraction(fixture.grid.grid_id, parcel.epoch, parcel.parcel_id);
+    assert!(fixture.ctx.banks_client.get_account(fraction_pda).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn withdraw_is_bounded_by_the_deposit() {
+    let (mut fixture, owner, parcel) = fraction_fixture().await;
+    let fraction = fixture.fraction(parcel).await;
+
+    let deposit = client::deposit_fraction_shares(&fixture.grid, &owner.keypair.pubkey(), &fraction, 10);
+    fixture.send(&[deposit], &[&owner.keypair]).await.unwrap();
+    let withdraw = client::withdraw_fraction_shares(&fixture.grid, &owner.keypair.pubkey(), &fraction, 11);
+    assert_billion_error(fixture.send(&[withdraw], &[&owner.keypair]).await, BillionError::InsufficientBalance);
+}
@@ -0,0 +1,95 @@
+use billion::errors::BillionError;
+use billion::state::{BlockMap, DEFAULT_GRID_SIZE, MIN_GRID_SIZE};
+use billion_client::{self as client, UpdateConfigArgs};
+use billion_test_harness::{assert_billion_error, GridFixture, Rect};
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn a_small_grid_bounds_claims_and_rings_by_its_own_size() {
+    let mut fixture = GridFixture::builder().grid_size(50).build().await;
+    let data = fixture.block_map_data().await;
+    assert_eq!(data.len(), BlockMap::size(50));
+    assert_eq!(client::decode_block_map_grid_size(&data).unwrap(), 50);
+
+    let owner = fixture.create_user(100_000_000).await;
+    let corner = fixture.claim(&owner, Rect::new(48, 48, 2, 2)).await.unwrap();
+    assert_eq!(fixture.block(49, 49).await, corner.parcel_id);
+
+    // Column 50 would be inside a 100x100 grid
+    let result = fixture.claim(&owner, Rect::new(49, 0, 2, 1)).await;
+    assert_billion_error(result, BillionError::OutOfBounds);
+    // The center of the small grid is its innermost, still locked ring
+    let result = fixture.claim(&owner, Rect::new(25, 25, 1, 1)).await;
+    assert_billion_error(result, BillionError::RingLocked);
+}
+
+#[tokio::test]
+async fn block_map_length_must_match_its_grid_size() {
+    let mut fixture = GridFixture::builder().build().await;
+    let rent = fixture.ctx.banks_client.get_rent().await.unwrap();
+
+    // A default-sized account can't hold a 50x50 grid, nor can any grid go below the minimum
+    for grid_size in [50, MIN_GRID_SIZE - 1] {
+        let block_map = Keypair::new();
+        let lamports = rent.minimum_balance(BlockMap::SIZE);
+        let [create_account, _] = client::create_block_map(&fixture.authority(), &block_map.pubkey(), lamports);
+        let [_, init] = client::create_sized_block_map(&fixture.authority(), &block_map.pubkey(), lamports, grid_size);
+        let result = fixture.send(&[create_account, init], &[&block_map]).await;
+        assert_billion_error(result, BillionError::InvalidGridSize);
+    }
+}
+
+#[tokio::test]
+async fn new_epochs_keep_the_grid_size() {
+    let mut fixture = GridFixture::builder().build().await;
+    let rent = fixture.ctx.banks_client.get_rent().await.unwrap();
+    let block_map = Keypair::new();
+    let instructions = client::create_sized_block_map(
+        &fixture.authority(),
+        &block_map.pubkey(),
+        rent.minimum_balance(BlockMap::size(50)),
+        50,
+    );
+    fixture.send(&instructions, &[&block_map]).await.unwrap();
+
+    let ix = client::start_new_epoch(&fixture.grid, &block_map.pubkey());
+    assert_billion_error(fixture.send(&[ix], &[]).await, BillionError::InvalidGridSize);
+
+    fixture.start_new_epoch().await.unwrap();
+    let data = fixture.block_map_data().await;
+    assert_eq!(client::decode_block_map_grid_size(&data).unwrap(), DEFAULT_GRID_SIZE);
+}
+
+#[tokio::test]
+async fn a_grid_has_one_ring_per_threshold() {
+    let mut fixture = GridFixture::builder().grid_size(50).ring_count(5).build().await;
+    assert_eq!(fixture.grid_config().await.ring_count(), 5);
+
+    // Five rings of a 50x50 grid are 5 blocks wide: (5, 5) is in ring 1, (6, 6) in ring 2
+    let owner = fixture.create_user(100_000_000).await;
+    fixture.claim(&owner, Rect::new(5, 5, 1, 1)).await.unwrap();
+    assert_billion_error(fixture.claim(&owner, Rect::new(6, 6, 1, 1)).await, BillionError::RingLocked);
+
+    // Ring 5 is the center, and the last one
+    let grid_id = fixture.grid.grid_id;
+    let beyond = client::admin_advance_ring(grid_id, &fixture.authority(), 6);
+    assert_billion_error(fixture.send(&[beyond], &[]).await, BillionError::InvalidRing);
+    fixture.send(&[client::admin_advance_ring(grid_id, &fixture.authority(), 5)], &[]).await.unwrap();
+    fixture.claim(&owner, Rect::new(25, 25, 1, 1)).await.unwrap();
+
+    // Another count would move claimed blocks between rings
+    let args = UpdateConfigArgs { ring_thresholds: Some(vec![0; 10]), ..Default::default() };
+    let update = client::update_config(grid_id, &fixture.authority(), args);
+    assert_billion_error(fixture.send(&[update], &[]).await, BillionError::InvalidRingThresholds);
+}
+
+#[tokio::test]
+async fn a_grid_can_only_have_as_many_rings_as_it_fits() {
+    // Past RING_COUNT the per-ring accounts have no room, and none is no grid at all
+    for ring_count in [0, 11] {
+        let result = GridFixture::builder().ring_count(ring_count).try_build().await.map(|_| ());
+        assert_billion_error(result, BillionError::InvalidRingThresholds);
+    }
+    let mut fixture = GridFixture::builder().grid_size(MIN_GRID_SIZE).ring_count(10).build().await;
+    assert_eq!(fixture.grid_config().await.ring_count(), 10);
+}
@@ -0,0 +1,92 @@
+use billion::errors::BillionError;
+use billion::state::{GridConfig, ParcelInfo};
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, GridFixture, Rect};
+use solana_sdk::{pubkey::Pubkey, signature::{Keypair, Signer}};
+
+#[tokio::test]
+async fn grid_zero_keeps_the_single_grid_addresses() {
+    let mut fixture = GridFixture::builder().build().await;
+    let (grid_config, _) = Pubkey::find_program_address(&[GridConfig::SEED], &billion::ID);
+    assert_eq!(fixture.grid.grid_config, grid_config);
+
+    let user = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&user, Rect::new(0, 0, 2, 2)).await.unwrap();
+    let (parcel_info, _) =
+        Pubkey::find_program_address(&[ParcelInfo::SEED, &parcel.parcel_id.to_le_bytes()], &billion::ID);
+    assert_eq!(fixture.parcel_info(parcel.parcel_id).await.asset, parcel.asset);
+    assert!(fixture.ctx.banks_client.get_account(parcel_info).await.unwrap().is_some());
+}
+
+#[tokio::test]
+async fn two_grids_claim_the_same_cells_independently() {
+    let mut fixture = GridFixture::builder().build().await;
+    let second = fixture.add_grid(1).await.unwrap();
+    assert_ne!(second.grid_config, fixture.grid.grid_config);
+    assert_ne!(second.land_buy_reward_pool, fixture.grid.land_buy_reward_pool);
+
+    let user = fixture.create_user(100_000_000).await;
+    let first = fixture.claim(&user, Rect::new(0, 0, 2, 2)).await.unwrap();
+
+    // Parcel 1 of grid 1 covers the same cells and lives at its own ParcelInfo
+    let asset = Keypair::new();
+    let ix = client::claim_parcel(&second, &user.keypair.pubkey(), &asset.pubkey(), 1, Rect::new(0, 0, 2, 2), None);
+    fixture.send(&[ix], &[&user.keypair, &asset]).await.unwrap();
+    assert_eq!(first.parcel_id, 1);
+    assert_ne!(client::find_parcel_info(1, 1), client::find_parcel_info(0, 1));
+
+    let second_blocks = client::decode_block_map(&fixture.account_data(second.block_map).await).unwrap();
+    assert_eq!(second_blocks[billion_core::block_index(1, 1, fixture.grid_size)], 1);
+    assert_eq!(fixture.block(1, 1).await, first.parcel_id);
+    assert_eq!(fixture.grid_config().await.total_claimed_blocks, 4);
+    let second_config = client::decode_grid_config(&fixture.account_data(second.grid_config).await).unwrap();
+    assert_eq!(second_config.total_claimed_blocks, 4);
+}
+
+#[tokio::test]
+async fn a_later_grid_runs_on_its_own_pdas() {
+    let mut fixture = GridFixture::builder().grid_id(7).build().await;
+    assert_eq!(fixture.grid.grid_config, client::find_grid_config(7));
+    assert_ne!(fixture.grid.grid_config, client::find_grid_config(0));
+
+    let user = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&user, Rect::new(0, 0, 2, 2)).await.unwrap();
+    fixture.claim_rewards(&user, parcel).await.unwrap();
+    assert_eq!(fixture.parcel_info(parcel.parcel_id).await.asset, parcel.asset);
+    let legacy = client::find_parcel_info(0, parcel.parcel_id);
+    assert!(fixture.ctx.banks_client.get_account(legacy).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn a_grid_cannot_claim_into_another_grids_block_map() {
+    let mut fixture = GridFixture::builder().build().await;
+    let mut second = fixture.add_grid(1).await.unwrap();
+    let user = fixture.create_user(100_000_000).await;
+
+    second.block_map = fixture.grid.block_map;
+    let asset = Keypair::new();
+    let ix = client::claim_parcel(&second, &user.keypair.pubkey(), &asset.pubkey(), 1, Rect::new(0, 0, 2, 2), None);
+    assert_billion_error(fixture.send(&[ix], &[&user.keypair, &asset]).await, BillionError::Unauthorized);
+    assert_eq!(fixture.block(0, 0).await, 0);
+}
+
+#[tokio::test]
+async fn a_block_map_binds_to_one_grid() {
+    let mut fixture = GridFixture::builder().build().await;
+    let taken = fixture.grid.block_map;
+
+    // Neither a new grid nor another grid's next epoch can adopt a map already in use
+    let grid = client::GridAccounts::new(1, fixture.authority(), taken, fixture.grid.token_mint, Pubkey::default());
+    let initialize = client::initialize(&grid, GridFixture::builder().initialize_args());
+    assert_billion_error(fixture.send(&[initialize], &[]).await, BillionError::BlockMapInUse);
+
+    let second = fixture.add_grid(1).await.unwrap();
+    let ix = client::start_new_epoch(&second, &taken);
+    assert_billion_error(fixture.send(&[ix], &[]).await, BillionError::BlockMapInUse);
+
+    // A map bound by one grid's new epoch is spent for every other grid too
+    let fresh = fixture.new_block_map().await;
+    fixture.send(&[client::start_new_epoch(&second, &fresh)], &[]).await.unwrap();
+    let ix = client::start_new_epoch(&fixture.grid, &fresh);
+    assert_billion_error(fixture.send(&[ix], &[]).await, BillionError::BlockMapInUse);
+}
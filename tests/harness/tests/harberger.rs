@@ -0,0 +1,151 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, ClaimedParcel, GridFixture, Rect, TestUser};
+use solana_sdk::instruction::Instruction;
+use solana_sdk::signature::Signer;
+
+const DAY: i64 = 86_400;
+
+/// Grid with an enabled 10x10 district at the origin taxing 1% per day, and a 2x2 parcel
+/// inside it valued at `valuation` whose owner has approved the GridConfig PDA for taxes
+async fn district_fixture(valuation: u64) -> (GridFixture, TestUser, ClaimedParcel) {
+    let mut fixture = GridFixture::builder().build().await;
+    let authority = fixture.authority();
+    let configure = client::configure_harberger_district(
+        fixture.grid.grid_id,
+        &authority,
+        client::HarbergerDistrictArgs {
+            rect: Rect::new(0, 0, 10, 10),
+            tax_bps: 100,
+            tax_period_secs: DAY,
+            enabled: true,
+        },
+    );
+    fixture.send(&[configure], &[]).await.unwrap();
+
+    let owner = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 2, 2)).await.unwrap();
+    let set = client::set_valuation(&fixture.grid, &owner.keypair.pubkey(), &parcel.asset, parcel.parcel_id, valuation);
+    let approve = spl_token_2022::instruction::approve(
+        &spl_token_2022::id(),
+        &owner.token_account,
+        &fixture.grid.grid_config,
+        &owner.keypair.pubkey(),
+        &[],
+        u64::MAX,
+    )
+    .unwrap();
+    fixture.send(&[set, approve], &[&owner.keypair]).await.unwrap();
+    (fixture, owner, parcel)
+}
+
+async fn pay_tax(fixture: &mut GridFixture, owner: &TestUser, parcel: ClaimedParcel) {
+    let payer = fixture.ctx.payer.pubkey();
+    let ix = client::pay_tax(&fixture.grid, &payer, &owner.keypair.pubkey(), &parcel.asset, parcel.parcel_id);
+    fixture.send(&[ix], &[]).await.unwrap();
+}
+
+fn prepay_tax(fixture: &GridFixture, owner: &TestUser, parcel: ClaimedParcel) -> Instruction {
+    let owner_key = owner.keypair.pubkey();
+    client::pay_tax(&fixture.grid, &owner_key, &owner_key, &parcel.asset, parcel.parcel_id)
+}
+
+#[tokio::test]
+async fn pay_tax_funds_landowners_and_extends_coverage() {
+    let (mut fixture, owner, parcel) = district_fixture(10_000_000).await;
+    let before = fixture.token_balance(owner.token_account).await;
+    let per_block_before = fixture.grid_config().await.land_buy_rewards_per_block;
+
+    pay_tax(&mut fixture, &owner, parcel).await;
+    let paid_until = fixture.parcel_valuation(parcel.parcel_id).await.tax_paid_until;
+    let ix = prepay_tax(&fixture, &owner, parcel);
+    fixture.send(&[ix], &[&owner.keypair]).await.unwrap();
+
+    // Two periods at 1% of 10_000_000
+    assert_eq!(before - fixture.token_balance(owner.token_account).await, 200_000);
+    assert!(fixture.grid_config().await.land_buy_rewards_per_block > per_block_before);
+    assert_eq!(fixture.parcel_valuation(parcel.parcel_id).await.tax_paid_until, paid_until + DAY);
+}
+
+#[tokio::test]
+async fn only_the_owner_pays_ahead() {
+    let (mut fixture, owner, parcel) = district_fixture(10_000_000).await;
+    pay_tax(&mut fixture, &owner, parcel).await;
+    let balance = fixture.token_balance(owner.token_account).await;
+
+    // A cranker can't pull further periods out of the owner's allowance
+    let payer = fixture.ctx.payer.pubkey();
+    let ix = client::pay_tax(&fixture.grid, &payer, &owner.keypair.pubkey(), &parcel.asset, parcel.parcel_id);
+    assert_billion_error(fixture.send(&[ix], &[]).await, BillionError::TaxNotDue);
+    assert_eq!(fixture.token_balance(owner.token_account).await, balance);
+
+    // Once the period runs out the crank collects again
+    fixture.advance_clock(DAY + 1).await;
+    pay_tax(&mut fixture, &owner, parcel).await;
+    assert_eq!(fixture.token_balance(owner.token_account).await, balance - 100_000);
+}
+
+#[tokio::test]
+async fn force_buy_requires_current_taxes() {
+    let (mut fixture, owner, parcel) = district_fixture(10_000_000).await;
+    let buyer = fixture.create_user(50_000_000).await;
+    let force_buy = |fixture: &GridFixture| {
+        client::force_buy(
+            &fixture.grid,
+            &buyer.keypair.pubkey(),
+            &owner.keypair.pubkey(),
+            &parcel.asset,
+            parcel.parcel_id,
+        )
+    };
+
+    let ix = force_buy(&fixture);
+    assert_billion_error(fixture.send(&[ix], &[&buyer.keypair]).await, BillionError::TaxNotCurrent);
+
+    pay_tax(&mut fixture, &owner, parcel).await;
+    fixture.advance_clock(DAY + 1).await;
+    let ix = force_buy(&fixture);
+    assert_billion_error(fixture.send(&[ix], &[&buyer.keypair]).await, BillionError::TaxNotCurrent);
+}
+
+#[tokio::test]
+async fn force_buy_pays_owner_and_transfers_asset() {
+    let (mut fixture, owner, parcel) = district_fixture(10_000_000).await;
+    pay_tax(&mut fixture, &owner, parcel).await;
+
+    let buyer = fixture.create_user(50_000_000).await;
+    let owner_before = fixture.token_balance(owner.token_account).await;
+    let ix = client::force_buy(
+        &fixture.grid,
+        &buyer.keypair.pubkey(),
+        &owner.keypair.pubkey(),
+        &parcel.asset,
+        parcel.parcel_id,
+    );
+    fixture.send(&[ix], &[&buyer.keypair]).await.unwrap();
+
+    // The price plus the 100_000 tax the parcel earned as the grid's only landowner
+    assert_eq!(fixture.token_balance(owner.token_account).await - owner_before, 10_100_000);
+    assert_eq!(fixture.token_balance(buyer.token_account).await, 40_000_000);
+    assert_eq!(fixture.asset_owner(parcel.asset).await, buyer.keypair.pubkey());
+    assert_billion_error(fixture.claim_rewards(&buyer, parcel).await, BillionError::NothingToClaim);
+
+    // The new owner isn't bound by the old valuation until they declare their own
+    let valuation = fixture.parcel_valuation(parcel.parcel_id).await;
+    assert_eq!(valuation.owner, buyer.keypair.pubkey());
+    assert_eq!(valuation.valuation, 0);
+
+    let ix = client::set_valuation(&fixture.grid, &buyer.keypair.pubkey(), &parcel.asset, parcel.parcel_id, 20_000_000);
+    fixture.send(&[ix], &[&buyer.keypair]).await.unwrap();
+    assert_eq!(fixture.parcel_valuation(parcel.parcel_id).await.delegate_approved_for, buyer.keypair.pubkey());
+}
+
+#[tokio::test]
+async fn parcels_outside_district_are_unaffected() {
+    let (mut fixture, _, _) = district_fixture(10_000_000).await;
+    let user = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&user, Rect::new(9, 0, 2, 1)).await.unwrap();
+
+    let ix = client::set_valuation(&fixture.grid, &user.keypair.pubkey(), &parcel.asset, parcel.parcel_id, 1_000);
+    assert_billion_error(fixture.send(&[ix], &[&user.keypair]).await, BillionError::OutsideDistrict);
+}
@@ -0,0 +1,111 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, GridFixture, Rect, TestUser};
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program,
+};
+
+/// Grid whose hook targets the system program over the 10x10 district at the origin.
+/// The system program rejects `on_parcel_claimed`, standing in for a failing hook.
+async fn hook_fixture(guardian: &Pubkey) -> (GridFixture, TestUser) {
+    let mut fixture = GridFixture::builder().build().await;
+    let authority = fixture.authority();
+    let set = client::set_hook(
+        fixture.grid.grid_id,
+        &authority,
+        client::HookArgs {
+            program_id: system_program::ID,
+            guardian: *guardian,
+            district: Rect::new(0, 0, 10, 10),
+            enabled: true,
+        },
+    );
+    fixture.send(&[set], &[]).await.unwrap();
+    let user = fixture.create_user(100_000_000).await;
+    (fixture, user)
+}
+
+async fn claim_ix(fixture: &mut GridFixture, user: &TestUser, asset: &Keypair, rect: Rect) -> Instruction {
+    let parcel_id = fixture.grid_config().await.next_parcel_id;
+    client::claim_parcel(&fixture.grid, &user.keypair.pubkey(), &asset.pubkey(), parcel_id, rect, None)
+}
+
+#[tokio::test]
+async fn failing_hook_aborts_the_claim() {
+    let (mut fixture, user) = hook_fixture(&Pubkey::new_unique()).await;
+    let asset = Keypair::new();
+    let ix = claim_ix(&mut fixture, &user, &asset, Rect::new(2, 2, 1, 1)).await;
+    let ix = client::with_hook_accounts(ix, &system_program::ID, &[]);
+
+    assert!(fixture.send(&[ix], &[&user.keypair, &asset]).await.is_err());
+    assert_eq!(fixture.block(2, 2).await, 0);
+    assert_eq!(fixture.grid_config().await.next_parcel_id, 1);
+}
+
+#[tokio::test]
+async fn hook_program_must_be_passed() {
+    let (mut fixture, user) = hook_fixture(&Pubkey::new_unique()).await;
+    let asset = Keypair::new();
+    let ix = claim_ix(&mut fixture, &user, &asset, Rect::new(2, 2, 1, 1)).await;
+    assert_billion_error(
+        fixture.send(&[ix.clone()], &[&user.keypair, &asset]).await,
+        BillionError::HookProgramMismatch,
+    );
+
+    let wrong = client::with_hook_accounts(ix, &spl_token_2022::id(), &[]);
+    assert_billion_error(
+        fixture.send(&[wrong], &[&user.keypair, &asset]).await,
+        BillionError::HookProgramMismatch,
+    );
+}
+
+#[tokio::test]
+async fn hook_skips_claims_outside_the_district() {
+    let (mut fixture, user) = hook_fixture(&Pubkey::new_unique()).await;
+    let parcel = fixture.claim(&user, Rect::new(10, 0, 2, 2)).await.unwrap();
+    assert_eq!(fixture.block(10, 0).await, parcel.parcel_id);
+}
+
+#[tokio::test]
+async fn hook_accounts_are_bounded() {
+    let (mut fixture, user) = hook_fixture(&Pubkey::new_unique()).await;
+    let asset = Keypair::new();
+    let ix = claim_ix(&mut fixture, &user, &asset, Rect::new(2, 2, 1, 1)).await;
+    let extra: Vec<_> = (0..=billion::constants::MAX_HOOK_ACCOUNTS)
+        .map(|_| solana_sdk::instruction::AccountMeta::new_readonly(Pubkey::new_unique(), false))
+        .collect();
+    let ix = client::with_hook_accounts(ix, &system_program::ID, &extra);
+    assert_billion_error(
+        fixture.send(&[ix], &[&user.keypair, &asset]).await,
+        BillionError::TooManyHookAccounts,
+    );
+}
+
+#[tokio::test]
+async fn guardian_can_disable_the_hook() {
+    let guardian = Keypair::new();
+    let (mut fixture, user) = hook_fixture(&guardian.pubkey()).await;
+
+    let stranger = Keypair::new();
+    let ix = client::disable_hook(fixture.grid.grid_id, &stranger.pubkey());
+    assert_billion_error(fixture.send(&[ix], &[&stranger]).await, BillionError::Unauthorized);
+
+    let ix = client::disable_hook(fixture.grid.grid_id, &guardian.pubkey());
+    fixture.send(&[ix], &[&guardian]).await.unwrap();
+    let hook = client::decode_hook_config(&fixture.account_data(client::find_hook_config(fixture.grid.grid_id)).await).unwrap();
+    assert!(!hook.enabled);
+
+    let parcel = fixture.claim(&user, Rect::new(2, 2, 1, 1)).await.unwrap();
+    assert_eq!(fixture.block(2, 2).await, parcel.parcel_id);
+}
+
+#[tokio::test]
+async fn authority_can_disable_the_hook() {
+    let (mut fixture, user) = hook_fixture(&Pubkey::new_unique()).await;
+    let authority = fixture.authority();
+    fixture.send(&[client::disable_hook(fixture.grid.grid_id, &authority)], &[]).await.unwrap();
+    fixture.claim(&user, Rect::new(2, 2, 1, 1)).await.unwrap();
+}
@@ -0,0 +1,59 @@
+use billion::errors::BillionError;
+use billion::state::{MapDigest, RESERVED_BLOCK};
+use billion::utils::next_map_hash;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, GridFixture, Rect};
+use solana_sdk::signature::Signer;
+
+async fn digest(fixture: &mut GridFixture) -> MapDigest {
+    let data = fixture.account_data(client::find_map_digest(fixture.grid.grid_id)).await;
+    client::decode_map_digest(&data).unwrap()
+}
+
+#[tokio::test]
+async fn every_map_write_chains_into_the_hash() {
+    let mut fixture = GridFixture::builder().build().await;
+    let start = digest(&mut fixture).await;
+    assert_eq!((start.map_version, start.map_hash), (0, [0; 32]));
+
+    let user = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&user, Rect::new(0, 0, 2, 2)).await.unwrap();
+    let road = Rect::new(0, 2, 10, 1);
+    fixture.send(&[client::admin_reserve_blocks(&fixture.grid, road)], &[]).await.unwrap();
+    let exit = client::burn_for_exit(&fixture.grid, &user.keypair.pubkey(), &parcel.asset, parcel.parcel_id);
+    fixture.send(&[exit], &[&user.keypair]).await.unwrap();
+
+    // A mirror replaying the claim, the reservation and the exit lands on the same hash
+    let mut replayed = next_map_hash(&[0; 32], parcel.parcel_id, Rect::new(0, 0, 2, 2));
+    replayed = next_map_hash(&replayed, RESERVED_BLOCK, road);
+    replayed = next_map_hash(&replayed, 0, Rect::new(0, 0, 2, 2));
+    let end = digest(&mut fixture).await;
+    assert_eq!((end.map_version, end.map_hash), (3, replayed));
+}
+
+#[tokio::test]
+async fn writes_chain_in_order() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+    let first = fixture.claim(&user, Rect::new(0, 0, 1, 1)).await.unwrap();
+    let second = fixture.claim(&user, Rect::new(1, 0, 1, 1)).await.unwrap();
+
+    let (a, b) = ((first.parcel_id, Rect::new(0, 0, 1, 1)), (second.parcel_id, Rect::new(1, 0, 1, 1)));
+    let end = digest(&mut fixture).await;
+    assert_eq!(end.map_version, 2);
+    assert_eq!(end.map_hash, next_map_hash(&next_map_hash(&[0; 32], a.0, a.1), b.0, b.1));
+    // A mirror that applied the same writes the other way round doesn't match
+    assert_ne!(end.map_hash, next_map_hash(&next_map_hash(&[0; 32], b.0, b.1), a.0, a.1));
+}
+
+#[tokio::test]
+async fn only_the_authority_creates_a_digest() {
+    let mut fixture = GridFixture::builder().build().await;
+    let stranger = fixture.create_user(0).await;
+    let grid = client::GridAccounts { authority: stranger.keypair.pubkey(), ..fixture.grid };
+    let ix = client::init_map_digest(&grid);
+    assert_billion_error(fixture.send(&[ix], &[&stranger.keypair]).await, BillionError::Unauthorized);
+
+    // initialize already created this grid's digest
+    assert!(fixture.send(&[client::init_map_digest(&fixture.grid)], &[]).await.is_err());
+}
@@ -0,0 +1,85 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, ClaimedParcel, GridFixture, Rect, TestUser};
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+
+/// X . .
+/// X X X
+const L_SHAPE: u8 = 0b0011_1001;
+
+/// Claim the cells of `rect` set in `mask` for `user`
+async fn claim_masked(
+    fixture: &mut GridFixture,
+    user: &TestUser,
+    rect: Rect,
+    mask: Vec<u8>,
+) -> Result<ClaimedParcel, BanksClientError> {
+    let parcel_id = fixture.next_claim_parcel_id().await;
+    let asset = Keypair::new();
+    let ix = client::claim_parcel_masked(&fixture.grid, &user.keypair.pubkey(), &asset.pubkey(), parcel_id, rect, mask);
+    fixture.send(&[ix], &[&user.keypair, &asset]).await?;
+    Ok(ClaimedParcel { parcel_id, asset: asset.pubkey(), epoch: fixture.grid.epoch })
+}
+
+#[tokio::test]
+async fn masked_claim_takes_and_charges_only_masked_cells() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+    let parcel = claim_masked(&mut fixture, &user, Rect::new(0, 0, 3, 2), vec![L_SHAPE]).await.unwrap();
+
+    assert_eq!(fixture.token_balance(user.token_account).await, 96_000_000);
+    assert_eq!(fixture.block(0, 0).await, parcel.parcel_id);
+    assert_eq!(fixture.block(1, 0).await, 0);
+    assert_eq!(fixture.block(2, 0).await, 0);
+    assert_eq!(fixture.block(2, 1).await, parcel.parcel_id);
+
+    let info = fixture.parcel_info(parcel.parcel_id).await;
+    assert_eq!((info.x, info.y, info.width, info.height), (0, 0, 3, 2));
+    assert_eq!(info.block_count(), 4);
+    assert_eq!(fixture.grid_config().await.total_claimed_blocks, 4);
+    let mask = fixture.account_data(client::find_parcel_mask_in_epoch(fixture.grid.grid_id, fixture.grid.epoch, parcel.parcel_id)).await;
+    assert_eq!(client::decode_parcel_mask(&mask).unwrap().mask, vec![L_SHAPE]);
+}
+
+#[tokio::test]
+async fn holes_stay_claimable_and_rewards_follow_masked_blocks() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let buyer = fixture.create_user(100_000_000).await;
+    let parcel = claim_masked(&mut fixture, &owner, Rect::new(0, 0, 3, 2), vec![L_SHAPE]).await.unwrap();
+
+    // The hole's 200_000 reward share is spread over the L's 4 blocks
+    let hole = fixture.claim(&buyer, Rect::new(1, 0, 1, 1)).await.unwrap();
+    let before = fixture.token_balance(owner.token_account).await;
+    fixture.claim_rewards(&owner, parcel).await.unwrap();
+    assert_eq!(fixture.token_balance(owner.token_account).await - before, 200_000);
+
+    // Burning the L leaves the parcel in its hole alone
+    let ix = client::burn_for_exit(&fixture.grid, &owner.keypair.pubkey(), &parcel.asset, parcel.parcel_id);
+    fixture.send(&[ix], &[&owner.keypair]).await.unwrap();
+    assert_eq!(fixture.block(0, 0).await, 0);
+    assert_eq!(fixture.block(1, 0).await, hole.parcel_id);
+    assert_eq!(fixture.grid_config().await.total_claimed_blocks, 1);
+}
+
+#[tokio::test]
+async fn invalid_masks_are_rejected() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+
+    // Empty
+    let result = claim_masked(&mut fixture, &user, Rect::new(0, 0, 3, 2), vec![0]).await;
+    assert_billion_error(result, BillionError::InvalidMask);
+    // Longer than the box
+    let result = claim_masked(&mut fixture, &user, Rect::new(0, 0, 3, 2), vec![L_SHAPE, 1]).await;
+    assert_billion_error(result, BillionError::InvalidMask);
+    // Bits past the box's last cell
+    let result = claim_masked(&mut fixture, &user, Rect::new(0, 0, 3, 2), vec![0b0100_0001]).await;
+    assert_billion_error(result, BillionError::InvalidMask);
+    // Box wider than MAX_MASK_SIDE
+    let result = claim_masked(&mut fixture, &user, Rect::new(0, 0, 17, 1), vec![0xff, 0xff, 1]).await;
+    assert_billion_error(result, BillionError::InvalidMask);
+
+    assert_eq!(fixture.grid_config().await.total_claimed_blocks, 0);
+}
@@ -0,0 +1,78 @@
+use billion::errors::BillionError;
+use billion_client::{self as client, UpdateConfigArgs};
+use billion_test_harness::{assert_billion_error, ClaimedParcel, GridFixture, Rect};
+use mpl_core::accounts::BaseAssetV1;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+
+const NEW_HOST: &str = "https://new.example/parcel/";
+
+async fn claimed_parcels(fixture: &mut GridFixture, count: u8) -> Vec<ClaimedParcel> {
+    let owner = fixture.create_user(100_000_000).await;
+    let mut parcels = Vec::new();
+    for x in 0..count {
+        parcels.push(fixture.claim(&owner, Rect::new(x, 0, 1, 1)).await.unwrap());
+    }
+    parcels
+}
+
+async fn uri(fixture: &mut GridFixture, parcel: ClaimedParcel) -> String {
+    BaseAssetV1::from_bytes(&fixture.account_data(parcel.asset).await).unwrap().uri
+}
+
+fn entries(parcels: &[ClaimedParcel]) -> Vec<(u16, Pubkey)> {
+    parcels.iter().map(|parcel| (parcel.parcel_id, parcel.asset)).collect()
+}
+
+#[tokio::test]
+async fn derived_uris_follow_the_grid_uri_base() {
+    let mut fixture = GridFixture::builder().build().await;
+    let parcels = claimed_parcels(&mut fixture, 3).await;
+    let args = UpdateConfigArgs { uri_base: Some(NEW_HOST.to_string()), ..Default::default() };
+    let move_host = client::update_config(fixture.grid.grid_id, &fixture.authority(), args);
+    fixture.send(&[move_host], &[]).await.unwrap();
+
+    let ix = client::update_parcels_metadata_batch(&fixture.grid, &fixture.authority(), &entries(&parcels), None);
+    fixture.send(&[ix], &[]).await.unwrap();
+    for parcel in parcels {
+        assert_eq!(uri(&mut fixture, parcel).await, format!("{}{}", NEW_HOST, parcel.parcel_id));
+    }
+}
+
+#[tokio::test]
+async fn a_given_uri_base_rewrites_just_the_assets() {
+    let mut fixture = GridFixture::builder().build().await;
+    let parcels = claimed_parcels(&mut fixture, 2).await;
+
+    let batch = entries(&parcels);
+    let ix = client::update_parcels_metadata_batch(&fixture.grid, &fixture.authority(), &batch, Some(NEW_HOST));
+    fixture.send(&[ix], &[]).await.unwrap();
+    for parcel in parcels {
+        assert_eq!(uri(&mut fixture, parcel).await, format!("{}{}", NEW_HOST, parcel.parcel_id));
+    }
+    let bad_base = client::update_parcels_metadata_batch(&fixture.grid, &fixture.authority(), &batch, Some("nohost"));
+    assert_billion_error(fixture.send(&[bad_base], &[]).await, BillionError::InvalidUriBase);
+}
+
+#[tokio::test]
+async fn derived_mode_rejects_assets_of_other_parcels() {
+    let mut fixture = GridFixture::builder().build().await;
+    let parcels = claimed_parcels(&mut fixture, 2).await;
+
+    let swapped = [(parcels[0].parcel_id, parcels[1].asset), (parcels[1].parcel_id, parcels[0].asset)];
+    let ix = client::update_parcels_metadata_batch(&fixture.grid, &fixture.authority(), &swapped, None);
+    assert_billion_error(fixture.send(&[ix], &[]).await, BillionError::AssetMismatch);
+}
+
+#[tokio::test]
+async fn only_the_metadata_authority_runs_bounded_batches() {
+    let mut fixture = GridFixture::builder().build().await;
+    let parcels = claimed_parcels(&mut fixture, 1).await;
+    let stranger = fixture.create_user(0).await;
+
+    let ix = client::update_parcels_metadata_batch(&fixture.grid, &stranger.keypair.pubkey(), &entries(&parcels), None);
+    assert_billion_error(fixture.send(&[ix], &[&stranger.keypair]).await, BillionError::Unauthorized);
+
+    let empty = client::update_parcels_metadata_batch(&fixture.grid, &fixture.authority(), &[], None);
+    assert_billion_error(fixture.send(&[empty], &[]).await, BillionError::InvalidBatch);
+}
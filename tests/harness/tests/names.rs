@@ -0,0 +1,121 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, ClaimedParcel, GridFixture, Rect, TestUser};
+use mpl_core::accounts::BaseAssetV1;
+use mpl_core::instructions::TransferV1Builder;
+use solana_sdk::signature::Signer;
+
+async fn asset_name(fixture: &mut GridFixture, parcel: ClaimedParcel) -> String {
+    BaseAssetV1::from_bytes(&fixture.account_data(parcel.asset).await).unwrap().name
+}
+
+async fn register(
+    fixture: &mut GridFixture,
+    owner: &TestUser,
+    parcel: ClaimedParcel,
+    name: &str,
+) -> Result<(), solana_program_test::BanksClientError> {
+    let ix = client::register_parcel_name(
+        &fixture.grid,
+        &owner.keypair.pubkey(),
+        &parcel.asset,
+        parcel.epoch,
+        parcel.parcel_id,
+        name,
+    );
+    fixture.send(&[ix], &[&owner.keypair]).await
+}
+
+#[tokio::test]
+async fn owner_registers_normalized_name() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+
+    register(&mut fixture, &owner, parcel, "OnlyOne").await.unwrap();
+    assert_eq!(asset_name(&mut fixture, parcel).await, "onlyone");
+    assert!(fixture.parcel_info(parcel.parcel_id).await.named);
+
+    let record = client::decode_name_record(&fixture.account_data(client::find_name_record(fixture.grid.grid_id, "onlyone")).await).unwrap();
+    assert_eq!(record.parcel_id, parcel.parcel_id);
+    assert_eq!(record.registrant, owner.keypair.pubkey());
+
+    // One name per parcel
+    assert_billion_error(register(&mut fixture, &owner, parcel, "another").await, BillionError::ParcelAlreadyNamed);
+}
+
+#[tokio::test]
+async fn taken_and_invalid_names_are_rejected() {
+    let mut fixture = GridFixture::builder().build().await;
+    let first = fixture.create_user(100_000_000).await;
+    let second = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&first, Rect::new(0, 0, 1, 1)).await.unwrap();
+    let other = fixture.claim(&second, Rect::new(1, 0, 1, 1)).await.unwrap();
+    register(&mut fixture, &first, parcel, "onlyone").await.unwrap();
+
+    assert_billion_error(register(&mut fixture, &second, other, "ONLYONE").await, BillionError::NameTaken);
+    assert_billion_error(register(&mut fixture, &second, other, "no").await, BillionError::InvalidName);
+    assert_billion_error(register(&mut fixture, &second, other, "two words").await, BillionError::InvalidName);
+    // Non-owners can't name someone else's parcel
+    assert_billion_error(register(&mut fixture, &first, other, "mine").await, BillionError::NotOwner);
+}
+
+#[tokio::test]
+async fn name_is_releasable_by_anyone_after_transfer_out() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let buyer = fixture.create_user(0).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    register(&mut fixture, &owner, parcel, "onlyone").await.unwrap();
+    let owner_key = owner.keypair.pubkey();
+
+    let release = client::release_parcel_name(
+        &fixture.grid,
+        &buyer.keypair.pubkey(),
+        &owner_key,
+        &parcel.asset,
+        parcel.epoch,
+        parcel.parcel_id,
+        "onlyone",
+    );
+    assert_billion_error(fixture.send(&[release.clone()], &[&buyer.keypair]).await, BillionError::NameStillHeld);
+
+    let transfer = TransferV1Builder::new()
+        .asset(parcel.asset)
+        .collection(Some(fixture.grid.collection))
+        .payer(owner_key)
+        .new_owner(buyer.keypair.pubkey())
+        .instruction();
+    fixture.send(&[transfer], &[&owner.keypair]).await.unwrap();
+
+    fixture.send(&[release], &[&buyer.keypair]).await.unwrap();
+    assert_eq!(asset_name(&mut fixture, parcel).await, format!("Parcel #{}", parcel.parcel_id));
+    assert!(!fixture.parcel_info(parcel.parcel_id).await.named);
+    let record = client::find_name_record(fixture.grid.grid_id, "onlyone");
+    assert!(fixture.ctx.banks_client.get_account(record).await.unwrap().is_none());
+
+    // The new owner can now claim the name for themselves
+    register(&mut fixture, &buyer, parcel, "onlyone").await.unwrap();
+}
+
+#[tokio::test]
+async fn registrant_releases_explicitly() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    register(&mut fixture, &owner, parcel, "onlyone").await.unwrap();
+
+    let owner_key = owner.keypair.pubkey();
+    let release = client::release_parcel_name(
+        &fixture.grid,
+        &owner_key,
+        &owner_key,
+        &parcel.asset,
+        parcel.epoch,
+        parcel.parcel_id,
+        "onlyone",
+    );
+    fixture.send(&[release], &[&owner.keypair]).await.unwrap();
+    register(&mut fixture, &owner, parcel, "renamed").await.unwrap();
+    assert_eq!(asset_name(&mut fixture, parcel).await, "renamed");
+}
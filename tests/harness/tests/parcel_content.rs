@@ -0,0 +1,73 @@
+use billion::errors::BillionError;
+use billion::events::ParcelContentUpdated;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, ClaimedParcel, GridFixture, Rect, TestUser};
+use mpl_core::Asset;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::Signer;
+
+const HASH: [u8; 32] = [0xab; 32];
+
+async fn set_content(
+    fixture: &mut GridFixture,
+    owner: &TestUser,
+    parcel: ClaimedParcel,
+    content_hash: [u8; 32],
+) -> Result<Vec<ParcelContentUpdated>, BanksClientError> {
+    let owner_key = owner.keypair.pubkey();
+    let ix = client::set_parcel_content(&fixture.grid, &owner_key, &parcel.asset, parcel.parcel_id, content_hash);
+    fixture.send_for_events(&[ix], &[&owner.keypair]).await
+}
+
+async fn content_hash_attribute(fixture: &mut GridFixture, parcel: ClaimedParcel) -> Option<String> {
+    let asset = Asset::deserialize(&fixture.account_data(parcel.asset).await).unwrap();
+    let plugin = asset.plugin_list.attributes.expect("asset has an Attributes plugin");
+    plugin
+        .attributes
+        .attribute_list
+        .into_iter()
+        .find(|attribute| attribute.key == "content_hash")
+        .map(|attribute| attribute.value)
+}
+
+#[tokio::test]
+async fn owner_sets_and_clears_the_content_hash() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+
+    let events = set_content(&mut fixture, &owner, parcel, HASH).await.unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!((events[0].parcel_id, events[0].owner), (parcel.parcel_id, owner.keypair.pubkey()));
+    assert_eq!(events[0].content_hash, HASH);
+    let address = client::find_parcel_content_in_epoch(fixture.grid.grid_id, parcel.epoch, parcel.parcel_id);
+    let content = client::decode_parcel_content(&fixture.account_data(address).await).unwrap();
+    assert_eq!(content.content_hash, HASH);
+    assert_eq!(content_hash_attribute(&mut fixture, parcel).await, Some("ab".repeat(32)));
+
+    let events = set_content(&mut fixture, &owner, parcel, [0; 32]).await.unwrap();
+    assert_eq!(events[0].content_hash, [0; 32]);
+    assert_eq!(content_hash_attribute(&mut fixture, parcel).await, None);
+}
+
+#[tokio::test]
+async fn only_the_owner_sets_the_content_hash() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let stranger = fixture.create_user(0).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+
+    assert_billion_error(set_content(&mut fixture, &stranger, parcel, HASH).await, BillionError::NotOwner);
+}
+
+#[tokio::test]
+async fn refreshing_attributes_keeps_the_content_hash() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    set_content(&mut fixture, &owner, parcel, HASH).await.unwrap();
+
+    let ix = client::refresh_parcel_attributes(&fixture.grid, &owner.keypair.pubkey(), &parcel.asset, parcel.parcel_id);
+    fixture.send(&[ix], &[&owner.keypair]).await.unwrap();
+    assert_eq!(content_hash_attribute(&mut fixture, parcel).await, Some("ab".repeat(32)));
+}
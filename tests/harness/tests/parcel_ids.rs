@@ -0,0 +1,93 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, GridFixture, Rect};
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn closed_parcel_id_is_reused_by_the_next_claim() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+    let closed = fixture.claim(&user, Rect::new(0, 0, 2, 2)).await.unwrap();
+    fixture.claim(&user, Rect::new(10, 0, 1, 1)).await.unwrap();
+    let next_parcel_id = fixture.grid_config().await.next_parcel_id;
+
+    let close = client::admin_close_parcel_info(&fixture.grid, fixture.grid.epoch, closed.parcel_id);
+    fixture.send(&[close], &[]).await.unwrap();
+    assert_eq!(fixture.block(0, 0).await, 0);
+    assert_eq!(fixture.grid_config().await.total_claimed_blocks, 1);
+    assert_eq!(fixture.next_claim_parcel_id().await, closed.parcel_id);
+
+    let reused = fixture.claim(&user, Rect::new(0, 10, 1, 2)).await.unwrap();
+    assert_eq!(reused.parcel_id, closed.parcel_id);
+    assert_eq!(fixture.block(0, 10).await, closed.parcel_id);
+    assert_eq!(fixture.block(0, 11).await, closed.parcel_id);
+    assert_eq!(fixture.block(0, 0).await, 0);
+    let info = fixture.parcel_info(reused.parcel_id).await;
+    assert_eq!((info.x, info.y, info.width, info.height), (0, 10, 1, 2));
+    assert_eq!(info.asset, reused.asset);
+
+    // The pool is drained, so the counter resumes
+    assert_eq!(fixture.grid_config().await.next_parcel_id, next_parcel_id);
+    let fresh = fixture.claim(&user, Rect::new(20, 0, 1, 1)).await.unwrap();
+    assert_eq!(fresh.parcel_id, next_parcel_id);
+}
+
+#[tokio::test]
+async fn staked_parcel_cannot_be_closed() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&user, Rect::new(0, 0, 1, 1)).await.unwrap();
+    let stake = client::stake_parcel(&fixture.grid, &user.keypair.pubkey(), &parcel.asset, parcel.parcel_id);
+    fixture.send(&[stake], &[&user.keypair]).await.unwrap();
+
+    let close = client::admin_close_parcel_info(&fixture.grid, fixture.grid.epoch, parcel.parcel_id);
+    assert_billion_error(fixture.send(&[close], &[]).await, BillionError::ParcelStaked);
+    assert_eq!(fixture.block(0, 0).await, parcel.parcel_id);
+}
+
+#[tokio::test]
+async fn burned_parcel_id_is_reused_by_the_next_claim() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+    let burned = fixture.claim(&user, Rect::new(0, 0, 2, 2)).await.unwrap();
+    fixture.claim(&user, Rect::new(10, 0, 1, 1)).await.unwrap();
+
+    let burn = client::burn_for_exit(&fixture.grid, &user.keypair.pubkey(), &burned.asset, burned.parcel_id);
+    fixture.send(&[burn], &[&user.keypair]).await.unwrap();
+    assert_eq!(fixture.next_claim_parcel_id().await, burned.parcel_id);
+
+    let reused = fixture.claim(&user, Rect::new(0, 10, 1, 1)).await.unwrap();
+    assert_eq!(reused.parcel_id, burned.parcel_id);
+    assert_eq!(fixture.block(0, 10).await, burned.parcel_id);
+    assert_eq!(fixture.parcel_info(reused.parcel_id).await.asset, reused.asset);
+}
+
+#[tokio::test]
+async fn closing_forfeits_pending_rewards() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+    let closed = fixture.claim(&user, Rect::new(0, 0, 2, 2)).await.unwrap();
+    fixture.claim(&user, Rect::new(10, 0, 1, 1)).await.unwrap();
+    assert!(fixture.grid_config().await.reward_liability > 0);
+
+    let close = client::admin_close_parcel_info(&fixture.grid, fixture.grid.epoch, closed.parcel_id);
+    fixture.send(&[close], &[]).await.unwrap();
+    // Only the remaining 1-block parcel is owed, and it has accrued nothing since its claim
+    assert_eq!(fixture.grid_config().await.reward_liability, 0);
+}
+
+#[tokio::test]
+async fn fractionalized_parcel_cannot_be_closed() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&user, Rect::new(0, 0, 1, 1)).await.unwrap();
+    let share_mint = Keypair::new();
+    let user_key = user.keypair.pubkey();
+    let fractionalize =
+        client::fractionalize_parcel(&fixture.grid, &user_key, &share_mint.pubkey(), &parcel.asset, parcel.parcel_id, 100);
+    fixture.send(&[fractionalize], &[&user.keypair, &share_mint]).await.unwrap();
+
+    let close = client::admin_close_parcel_info(&fixture.grid, fixture.grid.epoch, parcel.parcel_id);
+    assert_billion_error(fixture.send(&[close], &[]).await, BillionError::ParcelFractionalized);
+    assert_eq!(fixture.block(0, 0).await, parcel.parcel_id);
+}
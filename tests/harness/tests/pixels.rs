@@ -0,0 +1,76 @@
+use billion::errors::BillionError;
+use billion::events::ParcelPixelsUpdated;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, ClaimedParcel, GridFixture, Rect, TestUser};
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::Signer;
+
+async fn set_pixels(
+    fixture: &mut GridFixture,
+    owner: &TestUser,
+    parcel: ClaimedParcel,
+    rgb: &[u8],
+) -> Result<Vec<ParcelPixelsUpdated>, BanksClientError> {
+    let ix = client::set_parcel_pixels(&fixture.grid, &owner.keypair.pubkey(), &parcel.asset, parcel.parcel_id, rgb);
+    fixture.send_for_events(&[ix], &[&owner.keypair]).await
+}
+
+async fn close_pixels(
+    fixture: &mut GridFixture,
+    owner: &TestUser,
+    parcel: ClaimedParcel,
+) -> Result<Vec<ParcelPixelsUpdated>, BanksClientError> {
+    let owner_key = owner.keypair.pubkey();
+    let ix = client::close_parcel_pixels(&fixture.grid, &owner_key, &parcel.asset, parcel.epoch, parcel.parcel_id);
+    fixture.send_for_events(&[ix], &[&owner.keypair]).await
+}
+
+#[tokio::test]
+async fn owner_sets_repaints_and_closes_pixels() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let owner_key = owner.keypair.pubkey();
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 2, 1)).await.unwrap();
+    let address = client::find_parcel_pixels_in_epoch(fixture.grid.grid_id, parcel.epoch, parcel.parcel_id);
+
+    let events = set_pixels(&mut fixture, &owner, parcel, &[255, 0, 0, 0, 0, 255]).await.unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!((events[0].parcel_id, events[0].owner, events[0].cleared), (parcel.parcel_id, owner_key, false));
+    let pixels = client::decode_parcel_pixels(&fixture.account_data(address).await).unwrap();
+    assert_eq!(pixels.rgb, [255, 0, 0, 0, 0, 255]);
+
+    set_pixels(&mut fixture, &owner, parcel, &[1, 2, 3, 4, 5, 6]).await.unwrap();
+    let pixels = client::decode_parcel_pixels(&fixture.account_data(address).await).unwrap();
+    assert_eq!(pixels.rgb, [1, 2, 3, 4, 5, 6]);
+
+    let before = fixture.ctx.banks_client.get_balance(owner_key).await.unwrap();
+    let events = close_pixels(&mut fixture, &owner, parcel).await.unwrap();
+    assert!(events[0].cleared);
+    assert!(fixture.ctx.banks_client.get_balance(owner_key).await.unwrap() > before);
+    assert!(fixture.ctx.banks_client.get_account(address).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn pixels_hold_three_bytes_per_block_of_small_parcels() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(1_000_000_000).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 2, 1)).await.unwrap();
+    assert_billion_error(set_pixels(&mut fixture, &owner, parcel, &[0; 3]).await, BillionError::InvalidPixelData);
+    assert_billion_error(set_pixels(&mut fixture, &owner, parcel, &[0; 9]).await, BillionError::InvalidPixelData);
+
+    let large = fixture.claim(&owner, Rect::new(0, 2, 9, 8)).await.unwrap();
+    let result = set_pixels(&mut fixture, &owner, large, &[0; 3 * 72]).await;
+    assert_billion_error(result, BillionError::ParcelTooLargeForPixels);
+}
+
+#[tokio::test]
+async fn only_the_owner_touches_pixels() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let stranger = fixture.create_user(0).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+
+    assert_billion_error(set_pixels(&mut fixture, &stranger, parcel, &[0; 3]).await, BillionError::NotOwner);
+    set_pixels(&mut fixture, &owner, parcel, &[0; 3]).await.unwrap();
+    assert_billion_error(close_pixels(&mut fixture, &stranger, parcel).await, BillionError::NotOwner);
+}
@@ -0,0 +1,34 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, GridFixture, Rect};
+
+#[tokio::test]
+async fn only_tokens_beyond_the_liability_leave_the_pool() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    fixture.claim(&owner, Rect::new(1, 0, 1, 1)).await.unwrap();
+    // 200_000 of the 400_000 in the pool is owed to the first parcel; 50_000 more arrives by mistake
+    let pool = fixture.reward_pool();
+    fixture.mint_tokens(pool, 50_000).await;
+
+    let create = spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+        &fixture.authority(),
+        &fixture.authority(),
+        &fixture.grid.token_mint,
+        &spl_token_2022::id(),
+    );
+    fixture.send(&[create], &[]).await.unwrap();
+    let too_much = client::admin_withdraw_pool_excess(&fixture.grid, 250_001);
+    assert_billion_error(fixture.send(&[too_much], &[]).await, BillionError::InsufficientBalance);
+
+    let withdraw = client::admin_withdraw_pool_excess(&fixture.grid, 250_000);
+    fixture.send(&[withdraw], &[]).await.unwrap();
+    assert_eq!(fixture.token_balance(pool).await, 200_000);
+    let authority_account = client::find_token_account(&fixture.authority(), &fixture.grid.token_mint);
+    assert_eq!(fixture.token_balance(authority_account).await, 250_000);
+
+    // The owed rewards are still paid in full
+    fixture.claim_rewards(&owner, parcel).await.unwrap();
+    assert_eq!(fixture.token_balance(pool).await, 0);
+}
@@ -0,0 +1,76 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, GridFixture, Rect};
+use solana_sdk::{clock::Clock, signature::Signer};
+
+const PRICE: u64 = 1_000_000;
+const PERIOD_SECS: i64 = 30 * 86_400;
+
+async fn premium_fixture() -> GridFixture {
+    let mut fixture = GridFixture::builder().build().await;
+    let ix = client::set_premium_price(fixture.grid.grid_id, &fixture.authority(), PRICE, PERIOD_SECS);
+    fixture.send(&[ix], &[]).await.unwrap();
+    fixture
+}
+
+#[tokio::test]
+async fn premium_charges_the_caller_and_splits_the_fee() {
+    let mut fixture = premium_fixture().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    let sponsor = fixture.create_user(10_000_000).await;
+
+    let config = fixture.grid_config().await;
+    let pool = fixture.reward_pool();
+    let pool_before = fixture.token_balance(pool).await;
+    let ix = client::purchase_premium(&fixture.grid, &sponsor.keypair.pubkey(), parcel.parcel_id, 3);
+    fixture.send(&[ix], &[&sponsor.keypair]).await.unwrap();
+
+    let fee = 3 * PRICE;
+    let reward = fee * config.land_owners_reward_share_bps as u64 / 10_000;
+    assert_eq!(fixture.token_balance(sponsor.token_account).await, 10_000_000 - fee);
+    assert_eq!(fixture.token_balance(pool).await, pool_before + reward);
+    assert_eq!(fixture.grid_config().await.total_burned, config.total_burned + fee - reward);
+
+    let clock: Clock = fixture.ctx.banks_client.get_sysvar().await.unwrap();
+    let parcel_info = fixture.parcel_info(parcel.parcel_id).await;
+    assert_eq!(parcel_info.premium_until, clock.unix_timestamp + 3 * PERIOD_SECS);
+    assert!(parcel_info.is_premium(clock.unix_timestamp));
+}
+
+#[tokio::test]
+async fn renewal_extends_from_current_expiry() {
+    let mut fixture = premium_fixture().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    let ix = client::purchase_premium(&fixture.grid, &owner.keypair.pubkey(), parcel.parcel_id, 1);
+    fixture.send(&[ix.clone()], &[&owner.keypair]).await.unwrap();
+    let first_expiry = fixture.parcel_info(parcel.parcel_id).await.premium_until;
+
+    fixture.advance_clock(PERIOD_SECS / 2).await;
+    fixture.send(&[ix.clone()], &[&owner.keypair]).await.unwrap();
+    assert_eq!(fixture.parcel_info(parcel.parcel_id).await.premium_until, first_expiry + PERIOD_SECS);
+
+    // Once lapsed, a purchase starts from now
+    fixture.advance_clock(3 * PERIOD_SECS).await;
+    fixture.send(&[ix], &[&owner.keypair]).await.unwrap();
+    let clock: Clock = fixture.ctx.banks_client.get_sysvar().await.unwrap();
+    assert_eq!(fixture.parcel_info(parcel.parcel_id).await.premium_until, clock.unix_timestamp + PERIOD_SECS);
+}
+
+#[tokio::test]
+async fn premium_requires_a_price_and_periods() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    let ix = client::purchase_premium(&fixture.grid, &owner.keypair.pubkey(), parcel.parcel_id, 1);
+    assert_billion_error(fixture.send(&[ix], &[&owner.keypair]).await, BillionError::PremiumDisabled);
+
+    let ix = client::set_premium_price(fixture.grid.grid_id, &fixture.authority(), PRICE, 0);
+    assert_billion_error(fixture.send(&[ix], &[]).await, BillionError::InvalidPremiumConfig);
+
+    let ix = client::set_premium_price(fixture.grid.grid_id, &fixture.authority(), PRICE, PERIOD_SECS);
+    fixture.send(&[ix], &[]).await.unwrap();
+    let ix = client::purchase_premium(&fixture.grid, &owner.keypair.pubkey(), parcel.parcel_id, 0);
+    assert_billion_error(fixture.send(&[ix], &[&owner.keypair]).await, BillionError::InvalidPremiumPeriods);
+}
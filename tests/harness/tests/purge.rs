@@ -0,0 +1,102 @@
+use billion::constants::PURGE_PAUSE_SLOTS;
+use billion::errors::BillionError;
+use billion::state::BlockMap;
+use billion_client::{self as client, UpdateConfigArgs};
+use billion_test_harness::{assert_billion_error, GridFixture, Rect};
+use solana_sdk::{instruction::Instruction, system_instruction};
+
+fn set_claims_enabled(fixture: &GridFixture, enabled: bool) -> Instruction {
+    let args = UpdateConfigArgs { claims_enabled: Some(enabled), ..Default::default() };
+    client::update_config(fixture.grid.grid_id, &fixture.authority(), args)
+}
+
+#[tokio::test]
+async fn purge_waits_out_the_claims_pause() {
+    let mut fixture = GridFixture::builder().build().await;
+    let purge = client::admin_purge(&fixture.grid, false);
+    assert_billion_error(fixture.send(&[purge.clone()], &[]).await, BillionError::PurgeWindowOpen);
+
+    fixture.send(&[set_claims_enabled(&fixture, false)], &[]).await.unwrap();
+    fixture.advance_slots(PURGE_PAUSE_SLOTS - 1).await;
+    assert_billion_error(fixture.send(&[purge.clone()], &[]).await, BillionError::PurgeWindowOpen);
+
+    // Reopening claims restarts the window
+    fixture.send(&[set_claims_enabled(&fixture, true)], &[]).await.unwrap();
+    fixture.send(&[set_claims_enabled(&fixture, false)], &[]).await.unwrap();
+    fixture.advance_slots(1).await;
+    assert_billion_error(fixture.send(&[purge.clone()], &[]).await, BillionError::PurgeWindowOpen);
+
+    fixture.advance_slots(PURGE_PAUSE_SLOTS).await;
+    fixture.send(&[purge], &[]).await.unwrap();
+    assert!(fixture.ctx.banks_client.get_account(fixture.grid.grid_config).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn purge_needs_force_while_rewards_are_owed() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let buyer = fixture.create_user(100_000_000).await;
+    fixture.claim(&owner, Rect::new(0, 0, 2, 2)).await.unwrap();
+    fixture.claim(&buyer, Rect::new(4, 4, 1, 1)).await.unwrap();
+    assert!(fixture.grid_config().await.reward_liability > 0);
+    fixture.pause_for_purge().await;
+
+    let purge = client::admin_purge(&fixture.grid, false);
+    assert_billion_error(fixture.send(&[purge], &[]).await, BillionError::OutstandingRewards);
+
+    let pool = fixture.token_balance(fixture.reward_pool()).await;
+    let authority_tokens = client::find_token_account(&fixture.authority(), &fixture.grid.token_mint);
+    let before = fixture.token_balance(authority_tokens).await;
+    fixture.send(&[client::admin_purge(&fixture.grid, true)], &[]).await.unwrap();
+    assert_eq!(fixture.token_balance(authority_tokens).await, before + pool);
+}
+
+#[tokio::test]
+async fn parcels_are_purged_in_batches_before_the_grid() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let mut parcels = Vec::new();
+    for x in 0..3 {
+        parcels.push(fixture.claim(&owner, Rect::new(x * 2, 0, 1, 1)).await.unwrap());
+    }
+    let ids: Vec<(u16, u16)> = parcels.iter().map(|parcel| (parcel.epoch, parcel.parcel_id)).collect();
+
+    let early = client::admin_purge_parcels(&fixture.grid, &ids, true);
+    assert_billion_error(fixture.send(&[early], &[]).await, BillionError::PurgeWindowOpen);
+    fixture.pause_for_purge().await;
+
+    // Ids past next_parcel_id aren't parcels of this grid
+    let unknown = client::admin_purge_parcels(&fixture.grid, &[(parcels[0].epoch, 4)], true);
+    assert_billion_error(fixture.send(&[unknown], &[]).await, BillionError::InvalidBatch);
+
+    fixture.send(&[client::admin_purge_parcels(&fixture.grid, &ids[..2], true)], &[]).await.unwrap();
+    fixture.send(&[client::admin_purge_parcels(&fixture.grid, &ids[2..], true)], &[]).await.unwrap();
+    for parcel in &parcels {
+        let address = client::find_parcel_info_in_epoch(fixture.grid.grid_id, parcel.epoch, parcel.parcel_id);
+        assert!(fixture.ctx.banks_client.get_account(address).await.unwrap().is_none());
+    }
+
+    // Closed accounts can't be closed twice, and GridConfig is still there to finish on
+    let again = client::admin_purge_parcels(&fixture.grid, &ids[..1], true);
+    assert_billion_error(fixture.send(&[again], &[]).await, BillionError::InvalidBatch);
+    fixture.send(&[client::admin_purge(&fixture.grid, true)], &[]).await.unwrap();
+    assert!(fixture.ctx.banks_client.get_account(fixture.grid.grid_config).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn a_purged_block_map_cannot_be_reinitialized() {
+    let mut fixture = GridFixture::builder().build().await;
+    fixture.pause_for_purge().await;
+    let block_map = fixture.grid.block_map;
+    let rent = fixture.ctx.banks_client.get_rent().await.unwrap().minimum_balance(BlockMap::SIZE);
+    let [_, init] = client::create_block_map(&fixture.authority(), &block_map, rent);
+
+    // Topping the purged account back up in the same transaction still leaves nothing to initialize
+    let top_up = system_instruction::transfer(&fixture.authority(), &block_map, rent);
+    let purge = client::admin_purge(&fixture.grid, false);
+    assert!(fixture.send(&[purge.clone(), top_up, init.clone()], &[]).await.is_err());
+
+    fixture.send(&[purge], &[]).await.unwrap();
+    assert!(fixture.send(&[init], &[]).await.is_err());
+    assert!(fixture.ctx.banks_client.get_account(block_map).await.unwrap().is_none());
+}
@@ -0,0 +1,90 @@
+use billion::errors::BillionError;
+use billion_client::{self as client, QuestTree};
+use billion_test_harness::{assert_billion_error, GridFixture, TestUser};
+use solana_sdk::signature::Signer;
+
+/// Grid with a funded quest vault and a published quest paying `amounts` to fresh users
+async fn quest_fixture(amounts: &[u64], total_allocation: u64) -> (GridFixture, Vec<TestUser>, QuestTree) {
+    let mut fixture = GridFixture::builder().build().await;
+    let mut winners = Vec::new();
+    for _ in amounts {
+        winners.push(fixture.create_user(0).await);
+    }
+    let tree = QuestTree::new(
+        &winners
+            .iter()
+            .zip(amounts)
+            .map(|(user, amount)| (user.keypair.pubkey(), *amount))
+            .collect::<Vec<_>>(),
+    );
+
+    let authority = fixture.authority();
+    let init = client::init_quest_vault(&fixture.grid);
+    let publish = client::set_quest_root(fixture.grid.grid_id, &authority, tree.root(), total_allocation);
+    fixture.send(&[init, publish], &[]).await.unwrap();
+    fixture.mint_tokens(fixture.grid.quest_vault, 1_000_000).await;
+    (fixture, winners, tree)
+}
+
+#[tokio::test]
+async fn winners_claim_once_with_proof() {
+    let (mut fixture, winners, tree) = quest_fixture(&[100, 200, 300], 600).await;
+
+    for (index, (user, amount)) in winners.iter().zip([100, 200, 300]).enumerate() {
+        let index = index as u32;
+        let ix = client::claim_quest_reward(
+            &fixture.grid,
+            &user.keypair.pubkey(),
+            &tree.root(),
+            amount,
+            index,
+            tree.proof(index),
+        );
+        fixture.send(&[ix], &[&user.keypair]).await.unwrap();
+        assert_eq!(fixture.token_balance(user.token_account).await, amount);
+    }
+
+    let user = &winners[0];
+    let ix = client::claim_quest_reward(&fixture.grid, &user.keypair.pubkey(), &tree.root(), 100, 0, tree.proof(0));
+    assert_billion_error(fixture.send(&[ix], &[&user.keypair]).await, BillionError::QuestAlreadyClaimed);
+}
+
+#[tokio::test]
+async fn wrong_amount_or_wallet_is_rejected() {
+    let (mut fixture, winners, tree) = quest_fixture(&[100, 200], 300).await;
+
+    let user = &winners[0];
+    let ix = client::claim_quest_reward(&fixture.grid, &user.keypair.pubkey(), &tree.root(), 150, 0, tree.proof(0));
+    assert_billion_error(fixture.send(&[ix], &[&user.keypair]).await, BillionError::InvalidQuestProof);
+
+    let thief = &winners[1];
+    let ix = client::claim_quest_reward(&fixture.grid, &thief.keypair.pubkey(), &tree.root(), 100, 0, tree.proof(0));
+    assert_billion_error(fixture.send(&[ix], &[&thief.keypair]).await, BillionError::InvalidQuestProof);
+}
+
+#[tokio::test]
+async fn payouts_are_capped_at_the_allocation() {
+    let (mut fixture, winners, tree) = quest_fixture(&[100, 200], 250).await;
+
+    let first = &winners[0];
+    let ix = client::claim_quest_reward(&fixture.grid, &first.keypair.pubkey(), &tree.root(), 100, 0, tree.proof(0));
+    fixture.send(&[ix], &[&first.keypair]).await.unwrap();
+
+    let second = &winners[1];
+    let ix = client::claim_quest_reward(&fixture.grid, &second.keypair.pubkey(), &tree.root(), 200, 1, tree.proof(1));
+    assert_billion_error(fixture.send(&[ix], &[&second.keypair]).await, BillionError::QuestAllocationExceeded);
+}
+
+#[tokio::test]
+async fn concurrent_quests_have_separate_claims() {
+    let (mut fixture, winners, first) = quest_fixture(&[100], 100).await;
+    let user = &winners[0];
+    let second = QuestTree::new(&[(user.keypair.pubkey(), 50)]);
+    let authority = fixture.authority();
+    fixture.send(&[client::set_quest_root(fixture.grid.grid_id, &authority, second.root(), 50)], &[]).await.unwrap();
+
+    let a = client::claim_quest_reward(&fixture.grid, &user.keypair.pubkey(), &first.root(), 100, 0, first.proof(0));
+    let b = client::claim_quest_reward(&fixture.grid, &user.keypair.pubkey(), &second.root(), 50, 0, second.proof(0));
+    fixture.send(&[a, b], &[&user.keypair]).await.unwrap();
+    assert_eq!(fixture.token_balance(user.token_account).await, 150);
+}
@@ -0,0 +1,89 @@
+use billion::constants::RAFFLE_CLAIM_WINDOW_SECS;
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, GridFixture, Rect, TestUser};
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+
+const INTERVAL_SECS: i64 = 7 * 86_400;
+
+/// Every ring unlocked, raffles weekly, and a first 5x5 claim whose reward share sits in the
+/// pool as surplus because no landowner existed yet
+async fn raffle_fixture() -> (GridFixture, TestUser) {
+    let mut fixture = GridFixture::builder().ring_thresholds(vec![0; 10]).build().await;
+    let buyer = fixture.create_user(100_000_000).await;
+    fixture.claim(&buyer, Rect::new(0, 0, 5, 5)).await.unwrap();
+    let ix = client::set_raffle_interval(fixture.grid.grid_id, &fixture.authority(), INTERVAL_SECS);
+    fixture.send(&[ix], &[]).await.unwrap();
+    let player = fixture.create_user(0).await;
+    (fixture, player)
+}
+
+async fn run_raffle(fixture: &mut GridFixture, caller: &TestUser) -> Result<(), BanksClientError> {
+    let ix = client::run_raffle(&fixture.grid, &caller.keypair.pubkey());
+    fixture.send(&[ix], &[&caller.keypair]).await
+}
+
+async fn claim_prize(fixture: &mut GridFixture, claimer: &TestUser) -> Result<Keypair, BanksClientError> {
+    let parcel_id = fixture.grid_config().await.next_parcel_id;
+    let asset = Keypair::new();
+    let ix = client::claim_raffle_block(&fixture.grid, &claimer.keypair.pubkey(), &asset.pubkey(), parcel_id);
+    fixture.send(&[ix], &[&claimer.keypair, &asset]).await.map(|_| asset)
+}
+
+#[tokio::test]
+async fn raffle_prize_is_minted_free_and_paid_from_surplus() {
+    let (mut fixture, player) = raffle_fixture().await;
+    run_raffle(&mut fixture, &player).await.unwrap();
+    let raffle = client::decode_raffle(&fixture.account_data(client::find_raffle(fixture.grid.grid_id)).await).unwrap();
+    assert!(raffle.active);
+    assert_eq!(fixture.block(raffle.prize_x, raffle.prize_y).await, 0);
+
+    let config = fixture.grid_config().await;
+    let pool = fixture.reward_pool();
+    let pool_before = fixture.token_balance(pool).await;
+    let parcel_id = config.next_parcel_id;
+    let asset = claim_prize(&mut fixture, &player).await.unwrap();
+
+    assert_eq!(fixture.asset_owner(asset.pubkey()).await, player.keypair.pubkey());
+    assert_eq!(fixture.block(raffle.prize_x, raffle.prize_y).await, parcel_id);
+    assert_eq!(fixture.token_balance(pool).await, pool_before - config.price_per_block);
+    assert_eq!(fixture.grid_config().await.total_burned, config.total_burned + config.price_per_block);
+    assert_eq!(fixture.token_balance(player.token_account).await, 0);
+
+    let raffle = client::decode_raffle(&fixture.account_data(client::find_raffle(fixture.grid.grid_id)).await).unwrap();
+    assert_eq!(raffle.winner, player.keypair.pubkey());
+    assert_billion_error(claim_prize(&mut fixture, &player).await, BillionError::NoRafflePrize);
+}
+
+#[tokio::test]
+async fn raffle_is_rate_limited() {
+    let (mut fixture, player) = raffle_fixture().await;
+    run_raffle(&mut fixture, &player).await.unwrap();
+    assert_billion_error(run_raffle(&mut fixture, &player).await, BillionError::RafflePrizeActive);
+
+    claim_prize(&mut fixture, &player).await.unwrap();
+    assert_billion_error(run_raffle(&mut fixture, &player).await, BillionError::RaffleTooSoon);
+
+    fixture.advance_clock(INTERVAL_SECS).await;
+    run_raffle(&mut fixture, &player).await.unwrap();
+}
+
+#[tokio::test]
+async fn unclaimed_prize_expires() {
+    let (mut fixture, player) = raffle_fixture().await;
+    run_raffle(&mut fixture, &player).await.unwrap();
+    fixture.advance_clock(RAFFLE_CLAIM_WINDOW_SECS).await;
+    assert_billion_error(claim_prize(&mut fixture, &player).await, BillionError::NoRafflePrize);
+}
+
+#[tokio::test]
+async fn raffle_needs_interval_and_surplus() {
+    let mut fixture = GridFixture::builder().build().await;
+    let player = fixture.create_user(0).await;
+    assert_billion_error(run_raffle(&mut fixture, &player).await, BillionError::RaffleDisabled);
+
+    let ix = client::set_raffle_interval(fixture.grid.grid_id, &fixture.authority(), INTERVAL_SECS);
+    fixture.send(&[ix], &[]).await.unwrap();
+    assert_billion_error(run_raffle(&mut fixture, &player).await, BillionError::RewardPoolInsufficient);
+}
@@ -0,0 +1,171 @@
+use billion::constants::MAX_REFERRAL_SHARE_BPS;
+use billion::errors::BillionError;
+use billion::events::{ConfigUpdated, ReferralSharePaid, CONFIG_FIELD_REFERRAL_SHARE};
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, GridFixture, Rect, TestUser};
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+
+async fn setup_referrals(fixture: &mut GridFixture, referral_bps: u16) {
+    let authority = fixture.authority();
+    let init = client::init_referral_vault(&fixture.grid);
+    let set_bps = client::set_referral_bps(fixture.grid.grid_id, &authority, referral_bps);
+    fixture.send(&[init, set_bps], &[]).await.unwrap();
+}
+
+async fn register(fixture: &mut GridFixture, user: &TestUser, referred_by: Option<&TestUser>) {
+    let referred_by = referred_by.map(|r| r.keypair.pubkey());
+    let ix = client::register_referrer(fixture.grid.grid_id, &user.keypair.pubkey(), referred_by.as_ref());
+    fixture.send(&[ix], &[&user.keypair]).await.unwrap();
+}
+
+/// Claim `rect` for `buyer`, passing `referrer` as the token account paid the direct share
+async fn claim_with_share(
+    fixture: &mut GridFixture,
+    buyer: &TestUser,
+    rect: Rect,
+    referrer: &TestUser,
+) -> Result<Vec<ReferralSharePaid>, BanksClientError> {
+    let parcel_id = fixture.next_claim_parcel_id().await;
+    let asset = Keypair::new();
+    let ix = client::claim_parcel(&fixture.grid, &buyer.keypair.pubkey(), &asset.pubkey(), parcel_id, rect, None);
+    let ix = client::with_referrer_token_account(ix, &referrer.token_account);
+    fixture.send_for_events(&[ix], &[&buyer.keypair, &asset]).await
+}
+
+#[tokio::test]
+async fn referred_claim_accrues_and_withdraws() {
+    let mut fixture = GridFixture::builder().build().await;
+    setup_referrals(&mut fixture, 500).await;
+    let referrer = fixture.create_user(0).await;
+    let buyer = fixture.create_user(100_000_000).await;
+    register(&mut fixture, &referrer, None).await;
+
+    let referrer_key = referrer.keypair.pubkey();
+    fixture.claim_referred(&buyer, Rect::new(0, 0, 2, 2), Some(&referrer_key)).await.unwrap();
+
+    // 5% of 4 tokens is credited, 20% goes to landowners, the rest is burned
+    let account = fixture.referrer_account(&referrer_key).await;
+    assert_eq!(account.accrued, 200_000);
+    assert_eq!(account.total_earned, 200_000);
+    assert_eq!(account.referral_count, 1);
+    assert_eq!(account.referred_blocks, 4);
+    assert_eq!(fixture.grid_config().await.total_burned, 3_000_000);
+    assert_eq!(fixture.token_balance(fixture.grid.referral_vault).await, 200_000);
+
+    let ix = client::claim_referral_earnings(&fixture.grid, &referrer_key);
+    fixture.send(&[ix], &[&referrer.keypair]).await.unwrap();
+    assert_eq!(fixture.token_balance(referrer.token_account).await, 200_000);
+    let account = fixture.referrer_account(&referrer_key).await;
+    assert_eq!(account.accrued, 0);
+    assert_eq!(account.total_earned, 200_000);
+
+    let ix = client::claim_referral_earnings(&fixture.grid, &referrer_key);
+    assert_billion_error(fixture.send(&[ix], &[&referrer.keypair]).await, BillionError::NothingToClaim);
+}
+
+#[tokio::test]
+async fn self_and_circular_referrals_rejected() {
+    let mut fixture = GridFixture::builder().build().await;
+    setup_referrals(&mut fixture, 500).await;
+    let alice = fixture.create_user(100_000_000).await;
+    let bob = fixture.create_user(100_000_000).await;
+    register(&mut fixture, &alice, None).await;
+    // Alice introduced Bob
+    register(&mut fixture, &bob, Some(&alice)).await;
+
+    let alice_key = alice.keypair.pubkey();
+    let bob_key = bob.keypair.pubkey();
+    let result = fixture.claim_referred(&alice, Rect::new(0, 0, 1, 1), Some(&alice_key)).await;
+    assert_billion_error(result, BillionError::SelfReferral);
+
+    let result = fixture.claim_referred(&alice, Rect::new(0, 0, 1, 1), Some(&bob_key)).await;
+    assert_billion_error(result, BillionError::CircularReferral);
+
+    // The forward direction is fine
+    fixture.claim_referred(&bob, Rect::new(0, 0, 1, 1), Some(&alice_key)).await.unwrap();
+}
+
+#[tokio::test]
+async fn referral_share_bounded_by_landowner_share() {
+    let mut fixture = GridFixture::builder().land_owners_reward_share_bps(2000).build().await;
+    let authority = fixture.authority();
+
+    let result = fixture.send(&[client::set_referral_bps(fixture.grid.grid_id, &authority, 100)], &[]).await;
+    assert_billion_error(result, BillionError::ReferralsDisabled);
+
+    fixture.send(&[client::init_referral_vault(&fixture.grid)], &[]).await.unwrap();
+    let result = fixture.send(&[client::set_referral_bps(fixture.grid.grid_id, &authority, 8001)], &[]).await;
+    assert_billion_error(result, BillionError::InvalidReferralShare);
+}
+
+#[tokio::test]
+async fn direct_share_is_paid_before_the_split() {
+    let mut fixture = GridFixture::builder().build().await;
+    let set_share = client::set_referral_share(fixture.grid.grid_id, &fixture.authority(), 1_000);
+    fixture.send(&[set_share], &[]).await.unwrap();
+    let referrer = fixture.create_user(0).await;
+    let buyer = fixture.create_user(100_000_000).await;
+
+    let events = claim_with_share(&mut fixture, &buyer, Rect::new(0, 0, 2, 2), &referrer).await.unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].referrer, referrer.keypair.pubkey());
+    assert_eq!(events[0].referrer_token_account, referrer.token_account);
+    assert_eq!(events[0].amount, 400_000);
+
+    // 10% of 4 tokens goes to the referrer, then 20% of the rest to landowners and the rest is burned
+    assert_eq!(fixture.token_balance(referrer.token_account).await, 400_000);
+    assert_eq!(fixture.token_balance(buyer.token_account).await, 96_000_000);
+    assert_eq!(fixture.token_balance(fixture.reward_pool()).await, 720_000);
+    assert_eq!(fixture.grid_config().await.total_burned, 2_880_000);
+}
+
+#[tokio::test]
+async fn direct_share_rejects_the_claimers_own_account() {
+    let mut fixture = GridFixture::builder().build().await;
+    let set_share = client::set_referral_share(fixture.grid.grid_id, &fixture.authority(), 1_000);
+    fixture.send(&[set_share], &[]).await.unwrap();
+    let buyer = fixture.create_user(100_000_000).await;
+
+    let result = claim_with_share(&mut fixture, &buyer, Rect::new(0, 0, 1, 1), &buyer).await;
+    assert_billion_error(result, BillionError::SelfReferral);
+}
+
+#[tokio::test]
+async fn no_direct_share_is_paid_while_unset() {
+    let mut fixture = GridFixture::builder().build().await;
+    let referrer = fixture.create_user(0).await;
+    let buyer = fixture.create_user(100_000_000).await;
+
+    let events = claim_with_share(&mut fixture, &buyer, Rect::new(0, 0, 2, 2), &referrer).await.unwrap();
+    assert!(events.is_empty());
+    assert_eq!(fixture.token_balance(referrer.token_account).await, 0);
+    assert_eq!(fixture.grid_config().await.total_burned, 3_200_000);
+
+    let too_large = client::set_referral_share(fixture.grid.grid_id, &fixture.authority(), MAX_REFERRAL_SHARE_BPS + 1);
+    assert_billion_error(fixture.send(&[too_large], &[]).await, BillionError::InvalidReferralShare);
+}
+
+#[tokio::test]
+async fn direct_share_is_set_by_the_config_authority_within_the_split() {
+    let mut fixture = GridFixture::builder().build().await;
+    let grid_id = fixture.grid.grid_id;
+    let config = fixture.create_user(0).await;
+    let hand_over = client::set_admin_roles(grid_id, &fixture.authority(), Some(config.keypair.pubkey()), None, None);
+    fixture.send(&[hand_over], &[]).await.unwrap();
+    let ix = client::set_referral_share(grid_id, &fixture.authority(), 500);
+    assert_billion_error(fixture.send(&[ix], &[]).await, BillionError::Unauthorized);
+
+    // 2000 bps to landowners and 7500 to referrers leave 500 for the direct share
+    setup_referrals(&mut fixture, 7_500).await;
+    let ix = client::set_referral_share(grid_id, &config.keypair.pubkey(), 501);
+    assert_billion_error(fixture.send(&[ix], &[&config.keypair]).await, BillionError::InvalidReferralShare);
+    let ix = client::set_referral_share(grid_id, &config.keypair.pubkey(), 500);
+    let events: Vec<ConfigUpdated> = fixture.send_for_events(&[ix], &[&config.keypair]).await.unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!((events[0].authority, events[0].changed_fields), (config.keypair.pubkey(), CONFIG_FIELD_REFERRAL_SHARE));
+
+    // Counted from then on by the other shares' setters
+    let raise = client::set_referral_bps(grid_id, &fixture.authority(), 7_501);
+    assert_billion_error(fixture.send(&[raise], &[]).await, BillionError::InvalidReferralShare);
+}
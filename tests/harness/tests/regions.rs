@@ -0,0 +1,55 @@
+use anchor_lang::AnchorDeserialize;
+use billion::constants::MAX_REGION_SIDE;
+use billion::errors::BillionError;
+use billion::instructions::RegionView;
+use billion::state::RESERVED_BLOCK;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, GridFixture, Rect};
+
+async fn region(fixture: &mut GridFixture, rect: Rect) -> RegionView {
+    let ix = client::get_region(&fixture.grid, rect);
+    RegionView::deserialize(&mut &fixture.view(ix).await[..]).unwrap()
+}
+
+#[tokio::test]
+async fn a_region_reads_the_window_and_grid_state() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+    let first = fixture.claim(&user, Rect::new(0, 0, 2, 2)).await.unwrap();
+    let second = fixture.claim(&user, Rect::new(3, 0, 1, 1)).await.unwrap();
+    fixture.send(&[client::admin_reserve_blocks(&fixture.grid, Rect::new(0, 2, 4, 1))], &[]).await.unwrap();
+
+    let view = region(&mut fixture, Rect::new(1, 0, 3, 3)).await;
+    assert_eq!((view.x, view.y, view.width, view.height), (1, 0, 3, 3));
+    let (a, b, road) = (first.parcel_id, second.parcel_id, RESERVED_BLOCK);
+    assert_eq!(view.blocks, vec![a, 0, b, a, 0, 0, road, road, road]);
+    assert_eq!(view.unlocked_ring, 1);
+    assert_eq!(view.next_parcel_id, second.parcel_id + 1);
+}
+
+#[tokio::test]
+async fn a_region_needs_no_signer_and_writes_nothing() {
+    let mut fixture = GridFixture::builder().build().await;
+    let ix = client::get_region(&fixture.grid, Rect::new(0, 0, MAX_REGION_SIDE, MAX_REGION_SIDE));
+    assert!(ix.accounts.iter().all(|meta| !meta.is_signer && !meta.is_writable));
+
+    let before = fixture.block_map_data().await;
+    let view = region(&mut fixture, Rect::new(78, 78, MAX_REGION_SIDE, MAX_REGION_SIDE)).await;
+    assert_eq!(view.blocks.len(), MAX_REGION_SIDE as usize * MAX_REGION_SIDE as usize);
+    assert!(view.blocks.iter().all(|&parcel_id| parcel_id == 0));
+    assert_eq!(fixture.block_map_data().await, before);
+}
+
+#[tokio::test]
+async fn a_region_must_be_small_and_on_the_grid() {
+    let mut fixture = GridFixture::builder().build().await;
+    let cases = [
+        (Rect::new(0, 0, MAX_REGION_SIDE + 1, 1), BillionError::RegionTooLarge),
+        (Rect::new(0, 0, 1, 0), BillionError::InvalidDimensions),
+        (Rect::new(90, 0, 11, 1), BillionError::OutOfBounds),
+    ];
+    for (rect, error) in cases {
+        let ix = client::get_region(&fixture.grid, rect);
+        assert_billion_error(fixture.send(&[ix], &[]).await, error);
+    }
+}
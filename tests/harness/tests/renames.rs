@@ -0,0 +1,95 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, ClaimedParcel, GridFixture, Rect, TestUser};
+use mpl_core::accounts::BaseAssetV1;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::Signer;
+
+const RENAME_FEE: u64 = 2_000_000;
+
+async fn rename(
+    fixture: &mut GridFixture,
+    owner: &TestUser,
+    parcel: ClaimedParcel,
+    name: &str,
+    uri_suffix: Option<&str>,
+) -> Result<(), BanksClientError> {
+    let owner_key = owner.keypair.pubkey();
+    let ix = client::owner_update_parcel(&fixture.grid, &owner_key, &parcel.asset, parcel.parcel_id, name, uri_suffix);
+    fixture.send(&[ix], &[&owner.keypair]).await
+}
+
+async fn renaming_fixture() -> (GridFixture, TestUser, ClaimedParcel) {
+    let mut fixture = GridFixture::builder().build().await;
+    let set_fee = client::set_rename_fee(fixture.grid.grid_id, &fixture.authority(), RENAME_FEE);
+    fixture.send(&[set_fee], &[]).await.unwrap();
+    let owner = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    (fixture, owner, parcel)
+}
+
+#[tokio::test]
+async fn owner_renames_for_a_burned_fee() {
+    let (mut fixture, owner, parcel) = renaming_fixture().await;
+    let balance = fixture.token_balance(owner.token_account).await;
+    let burned = fixture.grid_config().await.total_burned;
+
+    rename(&mut fixture, &owner, parcel, "  Bob's   Pixel Palace ", Some(".json")).await.unwrap();
+    let asset = BaseAssetV1::from_bytes(&fixture.account_data(parcel.asset).await).unwrap();
+    assert_eq!(asset.name, "Bob's Pixel Palace");
+    assert_eq!(asset.uri, "https://example.com/parcel/1.json");
+    assert_eq!(fixture.token_balance(owner.token_account).await, balance - RENAME_FEE);
+    assert_eq!(fixture.grid_config().await.total_burned, burned + RENAME_FEE);
+
+    // Without a suffix the uri stays as it was
+    rename(&mut fixture, &owner, parcel, "Palace", None).await.unwrap();
+    let asset = BaseAssetV1::from_bytes(&fixture.account_data(parcel.asset).await).unwrap();
+    assert_eq!((asset.name.as_str(), asset.uri.as_str()), ("Palace", "https://example.com/parcel/1.json"));
+}
+
+#[tokio::test]
+async fn renames_are_disabled_until_a_fee_is_set() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    assert_billion_error(rename(&mut fixture, &owner, parcel, "Palace", None).await, BillionError::RenameDisabled);
+
+    // Only the metadata authority sets the fee
+    let stranger = fixture.create_user(0).await;
+    let set_fee = client::set_rename_fee(fixture.grid.grid_id, &stranger.keypair.pubkey(), RENAME_FEE);
+    assert_billion_error(fixture.send(&[set_fee], &[&stranger.keypair]).await, BillionError::Unauthorized);
+}
+
+#[tokio::test]
+async fn names_and_uris_stay_within_bounds() {
+    let (mut fixture, owner, parcel) = renaming_fixture().await;
+    let cases = [
+        ("a".repeat(33), None, BillionError::InvalidDisplayName),
+        ("   ".to_string(), None, BillionError::InvalidDisplayName),
+        ("Palace".to_string(), Some("2"), BillionError::InvalidUriSuffix),
+        ("Palace".to_string(), Some("@evil.example"), BillionError::InvalidUriSuffix),
+    ];
+    for (name, uri_suffix, error) in cases {
+        assert_billion_error(rename(&mut fixture, &owner, parcel, &name, uri_suffix).await, error);
+    }
+}
+
+#[tokio::test]
+async fn only_the_owner_renames_an_unregistered_parcel() {
+    let (mut fixture, owner, parcel) = renaming_fixture().await;
+    let stranger = fixture.create_user(100_000_000).await;
+    assert_billion_error(rename(&mut fixture, &stranger, parcel, "Mine", None).await, BillionError::NotOwner);
+
+    // A registered name stays on the asset until it's released
+    let register = client::register_parcel_name(
+        &fixture.grid,
+        &owner.keypair.pubkey(),
+        &parcel.asset,
+        parcel.epoch,
+        parcel.parcel_id,
+        "palace",
+    );
+    fixture.send(&[register], &[&owner.keypair]).await.unwrap();
+    let result = rename(&mut fixture, &owner, parcel, "Palace", None).await;
+    assert_billion_error(result, BillionError::ParcelAlreadyNamed);
+}
@@ -0,0 +1,50 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, ClaimedParcel, GridFixture, Rect};
+use solana_sdk::signature::{Keypair, Signer};
+
+/// Reserves a 1x1 parcel at the origin
+async fn reserve(fixture: &mut GridFixture) -> ClaimedParcel {
+    let parcel_id = fixture.grid_config().await.next_parcel_id;
+    let asset = Keypair::new();
+    let ix = client::admin_reserve_parcel(&fixture.grid, &asset.pubkey(), parcel_id, Rect::new(0, 0, 1, 1));
+    fixture.send(&[ix], &[&asset]).await.unwrap();
+    ClaimedParcel { parcel_id, asset: asset.pubkey(), epoch: fixture.grid.epoch }
+}
+
+#[tokio::test]
+async fn reserved_parcel_is_released_to_its_final_owner() {
+    let mut fixture = GridFixture::builder().build().await;
+    let parcel = reserve(&mut fixture).await;
+    assert_eq!(fixture.asset_owner(parcel.asset).await, fixture.grid.grid_config);
+    assert!(fixture.parcel_info(parcel.parcel_id).await.reserved);
+    assert_eq!(fixture.block(0, 0).await, parcel.parcel_id);
+
+    // Rewards accrue while the parcel is held
+    let buyer = fixture.create_user(100_000_000).await;
+    fixture.claim(&buyer, Rect::new(1, 0, 1, 1)).await.unwrap();
+
+    let owner = fixture.create_user(0).await;
+    let release =
+        client::admin_release_reserved_parcel(&fixture.grid, &parcel.asset, parcel.parcel_id, &owner.keypair.pubkey());
+    fixture.send(&[release], &[]).await.unwrap();
+    assert_eq!(fixture.asset_owner(parcel.asset).await, owner.keypair.pubkey());
+    assert!(!fixture.parcel_info(parcel.parcel_id).await.reserved);
+
+    fixture.claim_rewards(&owner, parcel).await.unwrap();
+    assert_eq!(fixture.token_balance(owner.token_account).await, 200_000);
+}
+
+#[tokio::test]
+async fn only_reserved_parcels_can_be_released() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(0).await;
+    let parcel_id = fixture.grid_config().await.next_parcel_id;
+    let asset = Keypair::new();
+    let mint = client::admin_mint(&fixture.grid, &owner.keypair.pubkey(), &asset.pubkey(), parcel_id, Rect::new(0, 0, 1, 1));
+    fixture.send(&[mint], &[&asset]).await.unwrap();
+
+    let release = client::admin_release_reserved_parcel(&fixture.grid, &asset.pubkey(), parcel_id, &fixture.authority());
+    assert_billion_error(fixture.send(&[release], &[]).await, BillionError::ParcelNotReserved);
+    assert_eq!(fixture.asset_owner(asset.pubkey()).await, owner.keypair.pubkey());
+}
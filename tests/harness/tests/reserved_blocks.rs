@@ -0,0 +1,93 @@
+use billion::errors::BillionError;
+use billion::state::RESERVED_BLOCK;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, GridFixture, Rect};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+/// A road along the top edge, from (0, 0) to (9, 0)
+const ROAD: Rect = Rect::new(0, 0, 10, 1);
+
+async fn road_fixture() -> GridFixture {
+    let mut fixture = GridFixture::builder().build().await;
+    fixture.send(&[client::admin_reserve_blocks(&fixture.grid, ROAD)], &[]).await.unwrap();
+    fixture
+}
+
+#[tokio::test]
+async fn reserved_blocks_cannot_be_claimed_or_minted() {
+    let mut fixture = road_fixture().await;
+    assert_eq!(fixture.block(0, 0).await, RESERVED_BLOCK);
+    assert_eq!(fixture.block(9, 0).await, RESERVED_BLOCK);
+    assert_eq!(fixture.block(10, 0).await, 0);
+
+    let user = fixture.create_user(100_000_000).await;
+    let result = fixture.claim(&user, Rect::new(8, 0, 3, 2)).await;
+    assert_billion_error(result, BillionError::BlockAlreadyClaimed);
+
+    let asset = Keypair::new();
+    let parcel_id = fixture.grid_config().await.next_parcel_id;
+    let mint = client::admin_mint(&fixture.grid, &Pubkey::new_unique(), &asset.pubkey(), parcel_id, Rect::new(5, 0, 1, 1));
+    assert_billion_error(fixture.send(&[mint], &[&asset]).await, BillionError::BlockAlreadyClaimed);
+
+    // Reserving over a claimed or reserved block fails as well
+    fixture.claim(&user, Rect::new(10, 0, 1, 1)).await.unwrap();
+    let overlap = client::admin_reserve_blocks(&fixture.grid, Rect::new(10, 0, 2, 1));
+    assert_billion_error(fixture.send(&[overlap], &[]).await, BillionError::BlockAlreadyClaimed);
+    let again = client::admin_reserve_blocks(&fixture.grid, Rect::new(0, 0, 1, 1));
+    assert_billion_error(fixture.send(&[again], &[]).await, BillionError::BlockAlreadyClaimed);
+}
+
+#[tokio::test]
+async fn reserved_blocks_carry_no_reward_weight() {
+    let mut fixture = road_fixture().await;
+    assert_eq!(fixture.grid_config().await.total_claimed_blocks, 0);
+
+    // The first claim's reward share has no landowners to go to, the second's all goes to
+    // the first parcel, as if the road weren't there
+    let user = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&user, Rect::new(0, 1, 2, 1)).await.unwrap();
+    fixture.claim(&user, Rect::new(2, 1, 2, 1)).await.unwrap();
+    assert_eq!(fixture.grid_config().await.total_claimed_blocks, 4);
+
+    let before = fixture.token_balance(user.token_account).await;
+    fixture.claim_rewards(&user, parcel).await.unwrap();
+    assert_eq!(fixture.token_balance(user.token_account).await - before, 400_000);
+
+    // The road doesn't make a parcel next to it a neighbor of anything
+    fixture.send(&[client::refresh_adjacency(&fixture.grid, parcel.parcel_id)], &[]).await.unwrap();
+    assert_eq!(fixture.parcel_info(parcel.parcel_id).await.adjacency_score, 1);
+}
+
+#[tokio::test]
+async fn unreserving_returns_the_blocks_to_the_market() {
+    let mut fixture = road_fixture().await;
+
+    // Only a fully reserved rectangle can be released
+    let past_the_road = client::admin_unreserve_blocks(&fixture.grid, Rect::new(8, 0, 3, 1));
+    assert_billion_error(fixture.send(&[past_the_road], &[]).await, BillionError::BlocksNotReserved);
+
+    let part = Rect::new(0, 0, 4, 1);
+    fixture.send(&[client::admin_unreserve_blocks(&fixture.grid, part)], &[]).await.unwrap();
+    assert_eq!(fixture.block(3, 0).await, 0);
+    assert_eq!(fixture.block(4, 0).await, RESERVED_BLOCK);
+
+    let user = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&user, part).await.unwrap();
+    assert_eq!(fixture.block(0, 0).await, parcel.parcel_id);
+    assert_eq!(fixture.grid_config().await.total_claimed_blocks, 4);
+}
+
+#[tokio::test]
+async fn only_the_config_authority_reserves_blocks() {
+    let mut fixture = GridFixture::builder().build().await;
+    let stranger = fixture.create_user(0).await;
+    let grid = client::GridAccounts { authority: stranger.keypair.pubkey(), ..fixture.grid };
+
+    let reserve = client::admin_reserve_blocks(&grid, ROAD);
+    assert_billion_error(fixture.send(&[reserve], &[&stranger.keypair]).await, BillionError::Unauthorized);
+    fixture.send(&[client::admin_reserve_blocks(&fixture.grid, ROAD)], &[]).await.unwrap();
+    let unreserve = client::admin_unreserve_blocks(&grid, ROAD);
+    assert_billion_error(fixture.send(&[unreserve], &[&stranger.keypair]).await, BillionError::Unauthorized);
+    assert_eq!(fixture.block(0, 0).await, RESERVED_BLOCK);
+}
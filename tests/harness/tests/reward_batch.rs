@@ -0,0 +1,72 @@
+use anchor_lang::AnchorDeserialize;
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, ClaimedParcel, GridFixture, Rect, TestUser};
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::Signer;
+
+async fn claim_batch(fixture: &mut GridFixture, user: &TestUser, parcels: &[ClaimedParcel]) -> Result<(), BanksClientError> {
+    let parcels: Vec<_> = parcels.iter().map(|parcel| (parcel.parcel_id, parcel.asset)).collect();
+    let ix = client::claim_land_buy_rewards_batch(&fixture.grid, &user.keypair.pubkey(), &parcels);
+    fixture.send(&[ix], &[&user.keypair]).await
+}
+
+#[tokio::test]
+async fn batch_pays_every_parcel_in_one_transfer() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let first = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    let second = fixture.claim(&owner, Rect::new(1, 0, 1, 1)).await.unwrap();
+    // The newest parcel has nothing to claim yet and is skipped
+    let third = fixture.claim(&owner, Rect::new(2, 0, 1, 1)).await.unwrap();
+
+    let before = fixture.token_balance(owner.token_account).await;
+    claim_batch(&mut fixture, &owner, &[first, second, third]).await.unwrap();
+    assert_eq!(fixture.token_balance(owner.token_account).await - before, 400_000);
+    assert_eq!(fixture.grid_config().await.reward_liability, 0);
+
+    assert_billion_error(claim_batch(&mut fixture, &owner, &[first, second]).await, BillionError::NothingToClaim);
+    assert_billion_error(fixture.claim_rewards(&owner, first).await, BillionError::NothingToClaim);
+}
+
+#[tokio::test]
+async fn batch_fails_on_a_parcel_the_claimer_does_not_own() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let other = fixture.create_user(100_000_000).await;
+    let mine = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    let theirs = fixture.claim(&other, Rect::new(1, 0, 1, 1)).await.unwrap();
+    fixture.claim(&other, Rect::new(2, 0, 1, 1)).await.unwrap();
+
+    assert_billion_error(claim_batch(&mut fixture, &owner, &[mine, theirs]).await, BillionError::NotOwner);
+    // Nothing was settled, so the owner's parcel still claims on its own
+    fixture.claim_rewards(&owner, mine).await.unwrap();
+}
+
+#[tokio::test]
+async fn batch_size_is_bounded() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    assert_billion_error(claim_batch(&mut fixture, &owner, &[]).await, BillionError::InvalidBatch);
+}
+
+#[tokio::test]
+async fn pending_views_match_what_claims_pay() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let first = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    let second = fixture.claim(&owner, Rect::new(1, 0, 2, 1)).await.unwrap();
+    fixture.claim(&owner, Rect::new(3, 0, 1, 1)).await.unwrap();
+
+    let ix = client::get_pending_rewards(&fixture.grid, first.epoch, first.parcel_id);
+    let pending = u64::deserialize(&mut &fixture.view(ix).await[..]).unwrap();
+    let ix = client::get_pending_rewards_many(&fixture.grid, &[first.parcel_id, second.parcel_id]);
+    let many = Vec::<u64>::deserialize(&mut &fixture.view(ix).await[..]).unwrap();
+    assert_eq!(many[0], pending);
+
+    let before = fixture.token_balance(owner.token_account).await;
+    claim_batch(&mut fixture, &owner, &[first, second]).await.unwrap();
+    assert_eq!(fixture.token_balance(owner.token_account).await - before, many.iter().sum::<u64>());
+    let ix = client::get_pending_rewards(&fixture.grid, first.epoch, first.parcel_id);
+    assert_eq!(u64::deserialize(&mut &fixture.view(ix).await[..]).unwrap(), 0);
+}
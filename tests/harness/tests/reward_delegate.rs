@@ -0,0 +1,134 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, ClaimedParcel, GridFixture, Rect, TestUser};
+use mpl_core::instructions::TransferV1Builder;
+use solana_program_test::BanksClientError;
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+
+async fn claim_as(
+    fixture: &mut GridFixture,
+    claimer: &TestUser,
+    owner: &TestUser,
+    parcel: ClaimedParcel,
+) -> Result<(), BanksClientError> {
+    let ix = client::claim_land_buy_rewards_for_owner(
+        &fixture.grid,
+        &claimer.keypair.pubkey(),
+        &owner.keypair.pubkey(),
+        &parcel.asset,
+        parcel.epoch,
+        parcel.parcel_id,
+    );
+    fixture.send(&[ix], &[&claimer.keypair]).await
+}
+
+async fn set_delegate(fixture: &mut GridFixture, owner: &TestUser, parcel: ClaimedParcel, delegate: Option<&TestUser>) {
+    let delegate = delegate.map(|delegate| delegate.keypair.pubkey());
+    let ix =
+        client::set_reward_delegate(&fixture.grid, &owner.keypair.pubkey(), &parcel.asset, parcel.parcel_id, delegate);
+    fixture.send(&[ix], &[&owner.keypair]).await.unwrap();
+}
+
+#[tokio::test]
+async fn delegate_claims_into_the_owners_account() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let hot = fixture.create_user(0).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    fixture.claim(&owner, Rect::new(1, 0, 1, 1)).await.unwrap();
+    set_delegate(&mut fixture, &owner, parcel, Some(&hot)).await;
+
+    let before = fixture.token_balance(owner.token_account).await;
+    claim_as(&mut fixture, &hot, &owner, parcel).await.unwrap();
+    assert_eq!(fixture.token_balance(owner.token_account).await - before, 200_000);
+    assert_eq!(fixture.token_balance(hot.token_account).await, 0);
+
+    let stranger = fixture.create_user(0).await;
+    fixture.claim(&owner, Rect::new(2, 0, 1, 1)).await.unwrap();
+    assert_billion_error(claim_as(&mut fixture, &stranger, &owner, parcel).await, BillionError::NotRewardDelegate);
+}
+
+#[tokio::test]
+async fn cleared_delegate_can_no_longer_claim() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let hot = fixture.create_user(0).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    fixture.claim(&owner, Rect::new(1, 0, 1, 1)).await.unwrap();
+    set_delegate(&mut fixture, &owner, parcel, Some(&hot)).await;
+    set_delegate(&mut fixture, &owner, parcel, None).await;
+
+    assert!(claim_as(&mut fixture, &hot, &owner, parcel).await.is_err());
+    fixture.claim_rewards(&owner, parcel).await.unwrap();
+}
+
+#[tokio::test]
+async fn transferring_the_asset_drops_the_delegate() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let buyer = fixture.create_user(0).await;
+    let hot = fixture.create_user(0).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    fixture.claim(&owner, Rect::new(1, 0, 1, 1)).await.unwrap();
+    set_delegate(&mut fixture, &owner, parcel, Some(&hot)).await;
+
+    let transfer = TransferV1Builder::new()
+        .asset(parcel.asset)
+        .collection(Some(fixture.grid.collection))
+        .payer(owner.keypair.pubkey())
+        .new_owner(buyer.keypair.pubkey())
+        .instruction();
+    fixture.send(&[transfer], &[&owner.keypair]).await.unwrap();
+
+    assert_billion_error(claim_as(&mut fixture, &hot, &buyer, parcel).await, BillionError::NotRewardDelegate);
+    assert_billion_error(claim_as(&mut fixture, &hot, &owner, parcel).await, BillionError::NotOwner);
+    fixture.claim_rewards(&buyer, parcel).await.unwrap();
+}
+
+#[tokio::test]
+async fn only_the_owner_sets_a_delegate() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let other = fixture.create_user(0).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+
+    let ix = client::set_reward_delegate(
+        &fixture.grid,
+        &other.keypair.pubkey(),
+        &parcel.asset,
+        parcel.parcel_id,
+        Some(other.keypair.pubkey()),
+    );
+    assert_billion_error(fixture.send(&[ix], &[&other.keypair]).await, BillionError::NotOwner);
+}
+
+#[tokio::test]
+async fn rewards_can_go_to_any_account_of_the_owner() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let other = fixture.create_user(0).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    fixture.claim(&owner, Rect::new(1, 0, 1, 1)).await.unwrap();
+    let auxiliary = fixture.create_token_account(&owner.keypair.pubkey()).await;
+    let foreign = fixture.create_token_account(&other.keypair.pubkey()).await;
+
+    let claim_to = |destination: &Pubkey| {
+        client::claim_land_buy_rewards_to(
+            &fixture.grid,
+            &owner.keypair.pubkey(),
+            &owner.keypair.pubkey(),
+            &parcel.asset,
+            parcel.epoch,
+            parcel.parcel_id,
+            destination,
+        )
+    };
+    let (to_foreign, to_auxiliary) = (claim_to(&foreign), claim_to(&auxiliary));
+    assert_billion_error(
+        fixture.send(&[to_foreign], &[&owner.keypair]).await,
+        BillionError::InvalidRewardDestination,
+    );
+    fixture.send(&[to_auxiliary], &[&owner.keypair]).await.unwrap();
+    assert_eq!(fixture.token_balance(auxiliary).await, 200_000);
+    assert_eq!(fixture.token_balance(foreign).await, 0);
+}
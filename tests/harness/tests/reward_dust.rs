@@ -0,0 +1,46 @@
+use billion::constants::REWARD_SCALE;
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, GridFixture, Rect};
+
+#[tokio::test]
+async fn rounding_is_tracked_and_every_claim_is_still_paid() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let mut parcels = Vec::new();
+    for x in 0..4 {
+        parcels.push(fixture.claim(&owner, Rect::new(x, 0, 1, 1)).await.unwrap());
+    }
+    // Only the last claim's 200_000 doesn't split evenly: 3 blocks get 66_666_666_666_666
+    // scaled units each, leaving 2
+    let address = client::find_reward_dust(fixture.grid.grid_id);
+    let dust = client::decode_reward_dust(&fixture.account_data(address).await).unwrap();
+    assert_eq!(dust.undistributed_dust, 2);
+    assert_eq!(dust.sweepable(), 0);
+    let sweep = client::admin_sweep_dust(&fixture.grid, 1);
+    assert_billion_error(fixture.send(&[sweep], &[]).await, BillionError::InsufficientBalance);
+
+    for parcel in &parcels[..3] {
+        fixture.claim_rewards(&owner, *parcel).await.unwrap();
+    }
+    // The last parcel was claimed after every credit
+    assert_billion_error(fixture.claim_rewards(&owner, parcels[3]).await, BillionError::NothingToClaim);
+    // The first claim's share had no landowners and stays as surplus; rounded-down payouts
+    // leave 2 more tokens that are still counted as owed
+    assert_eq!(fixture.token_balance(fixture.reward_pool()).await, 200_002);
+    assert_eq!(fixture.grid_config().await.reward_liability, 2);
+
+    // Stand in for the long history it takes for rounding to add up to whole tokens
+    let mut account = fixture.ctx.banks_client.get_account(address).await.unwrap().unwrap();
+    account.data[8..24].copy_from_slice(&(2 * REWARD_SCALE + 2).to_le_bytes());
+    fixture.ctx.set_account(&address, &account.into());
+
+    let sweep = client::admin_sweep_dust(&fixture.grid, 2);
+    fixture.send(&[sweep], &[]).await.unwrap();
+    let dust = client::decode_reward_dust(&fixture.account_data(address).await).unwrap();
+    assert_eq!(dust.undistributed_dust, 2);
+    let pool = fixture.token_balance(fixture.reward_pool()).await;
+    let liability = fixture.grid_config().await.reward_liability;
+    assert_eq!((pool, liability), (200_000, 0));
+    assert!(pool >= liability);
+}
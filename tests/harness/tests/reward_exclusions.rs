@@ -0,0 +1,81 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, ClaimedParcel, GridFixture, Rect, TestUser};
+use solana_sdk::signature::{Keypair, Signer};
+
+/// Admin-mints `rect` to `owner`, optionally excluded from land-buy rewards
+async fn seed(fixture: &mut GridFixture, owner: &TestUser, rect: Rect, excluded: bool) -> ClaimedParcel {
+    let parcel_id = fixture.grid_config().await.next_parcel_id;
+    let asset = Keypair::new();
+    let recipient = owner.keypair.pubkey();
+    let ix = if excluded {
+        client::admin_mint_rewards_excluded(&fixture.grid, &recipient, &asset.pubkey(), parcel_id, rect)
+    } else {
+        client::admin_mint(&fixture.grid, &recipient, &asset.pubkey(), parcel_id, rect)
+    };
+    fixture.send(&[ix], &[&asset]).await.unwrap();
+    ClaimedParcel { parcel_id, asset: asset.pubkey(), epoch: fixture.grid.epoch }
+}
+
+async fn excluded_blocks(fixture: &mut GridFixture) -> u32 {
+    let data = fixture.account_data(client::find_reward_exclusions(fixture.grid.grid_id)).await;
+    client::decode_reward_exclusions(&data).unwrap().excluded_blocks
+}
+
+#[tokio::test]
+async fn excluded_parcel_earns_nothing() {
+    let mut fixture = GridFixture::builder().build().await;
+    let promo_owner = fixture.create_user(0).await;
+    let owner = fixture.create_user(0).await;
+    let promo = seed(&mut fixture, &promo_owner, Rect::new(0, 0, 2, 2), true).await;
+    let seeded = seed(&mut fixture, &owner, Rect::new(0, 5, 1, 1), false).await;
+    assert!(fixture.parcel_info(promo.parcel_id).await.rewards_excluded);
+    assert_eq!(fixture.grid_config().await.total_claimed_blocks, 1);
+    assert_eq!(excluded_blocks(&mut fixture).await, 4);
+
+    // The whole land-buy reward goes to the one rewarded block
+    let buyer = fixture.create_user(100_000_000).await;
+    fixture.claim(&buyer, Rect::new(3, 0, 1, 1)).await.unwrap();
+    assert_billion_error(fixture.claim_rewards(&promo_owner, promo).await, BillionError::NothingToClaim);
+    fixture.claim_rewards(&owner, seeded).await.unwrap();
+    assert_eq!(fixture.token_balance(owner.token_account).await, 200_000);
+}
+
+#[tokio::test]
+async fn toggling_exclusion_settles_the_accumulator() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(0).await;
+    let parcel = seed(&mut fixture, &owner, Rect::new(0, 0, 1, 1), false).await;
+    let buyer = fixture.create_user(100_000_000).await;
+    fixture.claim(&buyer, Rect::new(1, 0, 1, 1)).await.unwrap();
+    assert_eq!(fixture.grid_config().await.reward_liability, 200_000);
+
+    // Pending rewards lapse into surplus when the parcel is excluded
+    let exclude = client::admin_set_rewards_excluded(&fixture.grid, parcel.parcel_id, true);
+    fixture.send(&[exclude], &[]).await.unwrap();
+    let config = fixture.grid_config().await;
+    assert_eq!((config.reward_liability, config.total_claimed_blocks), (0, 1));
+    assert_billion_error(fixture.claim_rewards(&owner, parcel).await, BillionError::NothingToClaim);
+
+    // Rewards credited while excluded aren't owed after it's included again
+    fixture.claim(&buyer, Rect::new(2, 0, 1, 1)).await.unwrap();
+    let include = client::admin_set_rewards_excluded(&fixture.grid, parcel.parcel_id, false);
+    fixture.send(&[include], &[]).await.unwrap();
+    assert_eq!(fixture.grid_config().await.total_claimed_blocks, 3);
+    assert_eq!(excluded_blocks(&mut fixture).await, 0);
+    assert_billion_error(fixture.claim_rewards(&owner, parcel).await, BillionError::NothingToClaim);
+
+    fixture.claim(&buyer, Rect::new(3, 0, 1, 1)).await.unwrap();
+    fixture.claim_rewards(&owner, parcel).await.unwrap();
+    assert_eq!(fixture.token_balance(owner.token_account).await, 66_666);
+}
+
+#[tokio::test]
+async fn only_the_authority_can_toggle_exclusion() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(0).await;
+    let parcel = seed(&mut fixture, &owner, Rect::new(0, 0, 1, 1), false).await;
+    let grid = client::GridAccounts { authority: owner.keypair.pubkey(), ..fixture.grid };
+    let exclude = client::admin_set_rewards_excluded(&grid, parcel.parcel_id, true);
+    assert_billion_error(fixture.send(&[exclude], &[&owner.keypair]).await, BillionError::Unauthorized);
+}
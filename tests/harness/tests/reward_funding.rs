@@ -0,0 +1,40 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, GridFixture, Rect};
+use solana_sdk::signature::Signer;
+
+#[tokio::test]
+async fn funding_is_claimable_pro_rata() {
+    let mut fixture = GridFixture::builder().build().await;
+    let small = fixture.create_user(100_000_000).await;
+    let large = fixture.create_user(100_000_000).await;
+    let donor = fixture.create_user(4_000_000).await;
+    let small_parcel = fixture.claim(&small, Rect::new(0, 0, 1, 1)).await.unwrap();
+    let large_parcel = fixture.claim(&large, Rect::new(10, 0, 3, 1)).await.unwrap();
+    // Settle what the second claim credited, so only the deposit is pending
+    fixture.claim_rewards(&small, small_parcel).await.unwrap();
+
+    let fund = client::fund_land_buy_rewards(&fixture.grid, &donor.keypair.pubkey(), 4_000_000);
+    fixture.send(&[fund], &[&donor.keypair]).await.unwrap();
+    assert_eq!(fixture.token_balance(donor.token_account).await, 0);
+
+    let before = fixture.token_balance(small.token_account).await;
+    fixture.claim_rewards(&small, small_parcel).await.unwrap();
+    assert_eq!(fixture.token_balance(small.token_account).await - before, 1_000_000);
+    let before = fixture.token_balance(large.token_account).await;
+    fixture.claim_rewards(&large, large_parcel).await.unwrap();
+    assert_eq!(fixture.token_balance(large.token_account).await - before, 3_000_000);
+}
+
+#[tokio::test]
+async fn funding_needs_claimed_blocks_and_an_amount() {
+    let mut fixture = GridFixture::builder().build().await;
+    let donor = fixture.create_user(1_000_000).await;
+
+    let fund = client::fund_land_buy_rewards(&fixture.grid, &donor.keypair.pubkey(), 1_000_000);
+    assert_billion_error(fixture.send(&[fund], &[&donor.keypair]).await, BillionError::NoRewardRecipients);
+
+    fixture.claim(&donor, Rect::new(0, 0, 1, 1)).await.unwrap();
+    let fund = client::fund_land_buy_rewards(&fixture.grid, &donor.keypair.pubkey(), 0);
+    assert_billion_error(fixture.send(&[fund], &[&donor.keypair]).await, BillionError::InvalidRewardFunding);
+}
@@ -0,0 +1,57 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, GridFixture, Rect};
+
+#[tokio::test]
+async fn heavier_rings_earn_a_larger_share() {
+    let mut fixture = GridFixture::builder().ring_thresholds(vec![0, 0]).build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let mut weights = [10_000u16; 10];
+    weights[1] = 20_000;
+    let set_weights = client::set_ring_reward_weights(fixture.grid.grid_id, &fixture.authority(), weights);
+    fixture.send(&[set_weights], &[]).await.unwrap();
+
+    // Column 5 is ring 1, column 6 is ring 2
+    let outer = fixture.claim(&owner, Rect::new(5, 20, 1, 1)).await.unwrap();
+    let inner = fixture.claim(&owner, Rect::new(6, 20, 1, 1)).await.unwrap();
+    assert_eq!(fixture.parcel_info(inner.parcel_id).await.reward_weight, 20);
+    fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+
+    // The second claim's 200_000 all goes to the outer block; the third's splits 1:2
+    let before = fixture.token_balance(owner.token_account).await;
+    fixture.claim_rewards(&owner, outer).await.unwrap();
+    assert_eq!(fixture.token_balance(owner.token_account).await - before, 266_666);
+    fixture.claim_rewards(&owner, inner).await.unwrap();
+    assert_eq!(fixture.token_balance(owner.token_account).await - before, 399_999);
+}
+
+#[tokio::test]
+async fn parcels_keep_the_weight_they_were_minted_with() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let unweighted = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+
+    let set_weights = client::set_ring_reward_weights(fixture.grid.grid_id, &fixture.authority(), [5_000; 10]);
+    fixture.send(&[set_weights], &[]).await.unwrap();
+    let weighted = fixture.claim(&owner, Rect::new(1, 0, 1, 1)).await.unwrap();
+    assert_eq!(fixture.parcel_info(unweighted.parcel_id).await.reward_weight, 0);
+    assert_eq!(fixture.parcel_info(weighted.parcel_id).await.reward_weight, 5);
+    fixture.claim(&owner, Rect::new(2, 0, 1, 1)).await.unwrap();
+
+    // 200_000 from the second claim, then 2/3 of the third's
+    let before = fixture.token_balance(owner.token_account).await;
+    fixture.claim_rewards(&owner, unweighted).await.unwrap();
+    assert_eq!(fixture.token_balance(owner.token_account).await - before, 333_333);
+}
+
+#[tokio::test]
+async fn weights_stay_within_bounds() {
+    let mut fixture = GridFixture::builder().build().await;
+    let mut too_light = [10_000u16; 10];
+    too_light[0] = 999;
+    let set_weights = client::set_ring_reward_weights(fixture.grid.grid_id, &fixture.authority(), too_light);
+    assert_billion_error(fixture.send(&[set_weights], &[]).await, BillionError::InvalidRingRewardWeights);
+
+    let set_weights = client::set_ring_reward_weights(fixture.grid.grid_id, &fixture.authority(), [25_501; 10]);
+    assert_billion_error(fixture.send(&[set_weights], &[]).await, BillionError::InvalidRingRewardWeights);
+}
@@ -0,0 +1,45 @@
+use billion::errors::BillionError;
+use billion_test_harness::{assert_billion_error, GridFixture, Rect};
+
+#[tokio::test]
+async fn claim_rewards_after_later_purchase() {
+    let mut fixture = GridFixture::builder().build().await;
+    let early = fixture.create_user(100_000_000).await;
+    let late = fixture.create_user(100_000_000).await;
+
+    let parcel = fixture.claim(&early, Rect::new(0, 0, 2, 2)).await.unwrap();
+    fixture.claim(&late, Rect::new(99, 0, 1, 1)).await.unwrap();
+
+    let before = fixture.token_balance(early.token_account).await;
+    fixture.claim_rewards(&early, parcel).await.unwrap();
+
+    // The 1-block purchase sends 200_000 to the pool, all owed to the 4 existing blocks
+    assert_eq!(fixture.token_balance(early.token_account).await - before, 200_000);
+
+    let result = fixture.claim_rewards(&early, parcel).await;
+    assert_billion_error(result, BillionError::NothingToClaim);
+}
+
+#[tokio::test]
+async fn claim_rewards_not_owner() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let other = fixture.create_user(100_000_000).await;
+
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    fixture.claim(&other, Rect::new(99, 99, 1, 1)).await.unwrap();
+
+    let result = fixture.claim_rewards(&other, parcel).await;
+    assert_billion_error(result, BillionError::NotOwner);
+}
+
+#[tokio::test]
+async fn transfer_fee_mint_is_rejected_at_initialize() {
+    // The pool would receive less than every reward credited against it
+    let result = GridFixture::builder().transfer_fee_bps(100).try_build().await.map(|_| ());
+    assert_billion_error(result, BillionError::TransferFeeMintUnsupported);
+
+    // Zero-fee mints are refused too: the fee authority can raise it later
+    let result = GridFixture::builder().transfer_fee_bps(0).try_build().await.map(|_| ());
+    assert_billion_error(result, BillionError::TransferFeeMintUnsupported);
+}
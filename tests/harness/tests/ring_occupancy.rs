@@ -0,0 +1,78 @@
+use billion::errors::BillionError;
+use billion::state::RingOccupancy;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, GridFixture, Rect};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+async fn occupancy(fixture: &mut GridFixture) -> RingOccupancy {
+    let data = fixture.account_data(client::find_ring_occupancy(fixture.grid.grid_id)).await;
+    client::decode_ring_occupancy(&data).unwrap()
+}
+
+async fn backfill(fixture: &mut GridFixture, restart: bool) {
+    let ix = client::admin_backfill_ring_occupancy(&fixture.grid, restart);
+    fixture.send(&[ix], &[]).await.unwrap();
+}
+
+#[tokio::test]
+async fn claims_mints_and_exits_move_the_counts() {
+    let mut fixture = GridFixture::builder().build().await;
+    let start = occupancy(&mut fixture).await;
+    assert_eq!(start.claimed_blocks_per_ring, [0; 10]);
+    assert!(start.is_complete());
+
+    let user = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&user, Rect::new(0, 0, 2, 2)).await.unwrap();
+    let asset = Keypair::new();
+    let parcel_id = fixture.grid_config().await.next_parcel_id;
+    let mint = client::admin_mint(&fixture.grid, &Pubkey::new_unique(), &asset.pubkey(), parcel_id, Rect::new(50, 50, 1, 1));
+    fixture.send(&[mint], &[&asset]).await.unwrap();
+    // Reserved blocks aren't claimed
+    fixture.send(&[client::admin_reserve_blocks(&fixture.grid, Rect::new(0, 2, 10, 1))], &[]).await.unwrap();
+    assert_eq!(occupancy(&mut fixture).await.claimed_blocks_per_ring, [4, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+
+    let exit = client::burn_for_exit(&fixture.grid, &user.keypair.pubkey(), &parcel.asset, parcel.parcel_id);
+    fixture.send(&[exit], &[&user.keypair]).await.unwrap();
+    assert_eq!(occupancy(&mut fixture).await.claimed_blocks_per_ring, [0, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+}
+
+#[tokio::test]
+async fn a_backfill_recounts_the_block_map_in_chunks() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+    fixture.claim(&user, Rect::new(0, 0, 2, 2)).await.unwrap();
+
+    // Starting over drops the counts; the first chunk covers the top 20 rows
+    backfill(&mut fixture, true).await;
+    let partial = occupancy(&mut fixture).await;
+    assert_eq!((partial.counted_to, partial.claimed_blocks_per_ring[0]), (2_000, 4));
+    assert!(!partial.is_complete());
+
+    // A claim behind the scan counts at once, one ahead of it waits for the scan
+    fixture.claim(&user, Rect::new(2, 0, 1, 1)).await.unwrap();
+    fixture.claim(&user, Rect::new(0, 99, 1, 1)).await.unwrap();
+    assert_eq!(occupancy(&mut fixture).await.claimed_blocks_per_ring[0], 5);
+
+    for _ in 0..4 {
+        backfill(&mut fixture, false).await;
+    }
+    let done = occupancy(&mut fixture).await;
+    assert!(done.is_complete());
+    assert_eq!(done.claimed_blocks_per_ring, [6, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+    // Once complete, further calls change nothing
+    backfill(&mut fixture, false).await;
+    assert_eq!(occupancy(&mut fixture).await.claimed_blocks_per_ring, done.claimed_blocks_per_ring);
+}
+
+#[tokio::test]
+async fn only_the_config_authority_backfills() {
+    let mut fixture = GridFixture::builder().build().await;
+    let stranger = fixture.create_user(0).await;
+    let grid = client::GridAccounts { authority: stranger.keypair.pubkey(), ..fixture.grid };
+
+    let ix = client::admin_backfill_ring_occupancy(&grid, true);
+    assert_billion_error(fixture.send(&[ix], &[&stranger.keypair]).await, BillionError::Unauthorized);
+    assert!(occupancy(&mut fixture).await.is_complete());
+}
@@ -0,0 +1,46 @@
+use billion::events::RingUnlocked;
+use billion_client as client;
+use billion_test_harness::{GridFixture, Rect, TestUser};
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+
+async fn claim_for_events(
+    fixture: &mut GridFixture,
+    user: &TestUser,
+    rect: Rect,
+) -> Result<Vec<RingUnlocked>, BanksClientError> {
+    let parcel_id = fixture.next_claim_parcel_id().await;
+    let asset = Keypair::new();
+    let ix = client::claim_parcel(&fixture.grid, &user.keypair.pubkey(), &asset.pubkey(), parcel_id, rect, None);
+    fixture.send_for_events(&[ix], &[&user.keypair, &asset]).await
+}
+
+#[tokio::test]
+async fn a_claim_crossing_a_threshold_announces_the_ring() {
+    // Each outer-ring block burns 800_000
+    let mut fixture = GridFixture::builder().ring_thresholds(vec![0, 1_600_000]).build().await;
+    let user = fixture.create_user(100_000_000).await;
+    assert!(claim_for_events(&mut fixture, &user, Rect::new(0, 0, 1, 1)).await.unwrap().is_empty());
+
+    let events = claim_for_events(&mut fixture, &user, Rect::new(1, 0, 1, 1)).await.unwrap();
+    assert_eq!(events.len(), 1);
+    let event = &events[0];
+    assert_eq!((event.new_ring, event.rings_crossed), (2, 1));
+    assert_eq!((event.total_burned, event.triggering_parcel_id), (1_600_000, 2));
+
+    // Ring 2 is already open; the next burn crosses nothing
+    assert!(claim_for_events(&mut fixture, &user, Rect::new(2, 0, 1, 1)).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn a_claim_crossing_two_thresholds_announces_the_last() {
+    let mut fixture = GridFixture::builder().ring_thresholds(vec![0, 800_000, 1_600_000]).build().await;
+    let user = fixture.create_user(100_000_000).await;
+
+    let events = claim_for_events(&mut fixture, &user, Rect::new(0, 0, 2, 1)).await.unwrap();
+    assert_eq!(events.len(), 1);
+    let event = &events[0];
+    assert_eq!((event.new_ring, event.rings_crossed), (3, 2));
+    assert_eq!((event.total_burned, event.triggering_parcel_id), (1_600_000, 1));
+    assert_eq!(fixture.grid_config().await.unlocked_ring(), 3);
+}
@@ -0,0 +1,71 @@
+use anchor_lang::AnchorDeserialize;
+use billion::constants::EXPANSION_PRIORITY_WINDOW_SECS;
+use billion::errors::BillionError;
+use billion::instructions::GridState;
+use billion_client::{self as client, UpdateConfigArgs};
+use billion_test_harness::{assert_billion_error, GridFixture, Rect};
+use solana_program_test::BanksClientError;
+use solana_sdk::clock::Clock;
+
+async fn set_schedule(fixture: &mut GridFixture, timestamps: Vec<i64>) -> Result<(), BanksClientError> {
+    let args = UpdateConfigArgs { ring_unlock_timestamps: Some(timestamps), ..Default::default() };
+    let ix = client::update_config(fixture.grid.grid_id, &fixture.authority(), args);
+    fixture.send(&[ix], &[]).await
+}
+
+async fn unlocked_ring(fixture: &mut GridFixture) -> u8 {
+    let state = fixture.view(client::get_grid_state(fixture.grid.grid_id)).await;
+    GridState::deserialize(&mut &state[..]).unwrap().unlocked_ring
+}
+
+async fn now(fixture: &mut GridFixture) -> i64 {
+    let clock: Clock = fixture.ctx.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp
+}
+
+#[tokio::test]
+async fn the_schedule_opens_rings_the_burns_have_not() {
+    let mut fixture = GridFixture::builder().build().await;
+    fixture.send(&[client::admin_advance_ring(fixture.grid.grid_id, &fixture.authority(), 3)], &[]).await.unwrap();
+    let advanced_at = fixture.grid_config().await.ring_unlocked_at;
+    fixture.advance_clock(EXPANSION_PRIORITY_WINDOW_SECS).await;
+    let user = fixture.create_user(100_000_000).await;
+
+    // Burns have unlocked ring 3; the schedule opens rings 4 and 5 in 1000 seconds
+    let start = now(&mut fixture).await;
+    set_schedule(&mut fixture, vec![start, start, start, start + 1_000, start + 1_000]).await.unwrap();
+    assert_eq!(unlocked_ring(&mut fixture).await, 3);
+    assert_billion_error(fixture.claim(&user, Rect::new(25, 25, 1, 1)).await, BillionError::RingLocked);
+
+    fixture.advance_clock(1_000).await;
+    assert_eq!(unlocked_ring(&mut fixture).await, 5);
+    assert_eq!(fixture.grid_config().await.unlocked_ring(), 3);
+    // Ring 5 is open, and opening on schedule starts no expansion window
+    fixture.claim(&user, Rect::new(25, 25, 1, 1)).await.unwrap();
+    assert_billion_error(fixture.claim(&user, Rect::new(30, 30, 1, 1)).await, BillionError::RingLocked);
+    assert_eq!(fixture.grid_config().await.ring_unlocked_at, advanced_at);
+}
+
+#[tokio::test]
+async fn clearing_the_schedule_leaves_rings_to_the_burns() {
+    let mut fixture = GridFixture::builder().build().await;
+    set_schedule(&mut fixture, vec![0; 6]).await.unwrap();
+    assert_eq!(unlocked_ring(&mut fixture).await, 6);
+
+    set_schedule(&mut fixture, Vec::new()).await.unwrap();
+    assert_eq!(unlocked_ring(&mut fixture).await, 1);
+    let data = fixture.account_data(client::find_ring_unlock_schedule(fixture.grid.grid_id)).await;
+    assert!(client::decode_ring_unlock_schedule(&data).unwrap().ring_unlock_timestamps.is_empty());
+}
+
+#[tokio::test]
+async fn a_schedule_must_be_non_decreasing_and_fit_the_rings() {
+    let mut fixture = GridFixture::builder().ring_count(5).build().await;
+    let backwards = set_schedule(&mut fixture, vec![0, 200, 100]).await;
+    assert_billion_error(backwards, BillionError::InvalidRingUnlockSchedule);
+    let too_many = set_schedule(&mut fixture, vec![0; 6]).await;
+    assert_billion_error(too_many, BillionError::InvalidRingUnlockSchedule);
+
+    set_schedule(&mut fixture, vec![0; 5]).await.unwrap();
+    assert_eq!(unlocked_ring(&mut fixture).await, 5);
+}
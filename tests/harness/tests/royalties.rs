@@ -0,0 +1,40 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, GridFixture};
+use mpl_core::types::Royalties;
+use mpl_core::Collection;
+use solana_sdk::pubkey::Pubkey;
+
+async fn collection_royalties(fixture: &mut GridFixture) -> Option<Royalties> {
+    let collection = Collection::deserialize(&fixture.account_data(fixture.grid.collection).await).unwrap();
+    collection.plugin_list.royalties.map(|plugin| plugin.royalties)
+}
+
+#[tokio::test]
+async fn authority_sets_updates_and_removes_collection_royalties() {
+    let mut fixture = GridFixture::builder().build().await;
+    let recipient = Pubkey::new_unique();
+    assert_eq!(collection_royalties(&mut fixture).await, None);
+
+    let set = client::admin_set_collection_royalties(&fixture.grid, 500, &recipient);
+    fixture.send(&[set], &[]).await.unwrap();
+    let royalties = collection_royalties(&mut fixture).await.unwrap();
+    assert_eq!(royalties.basis_points, 500);
+    assert_eq!(royalties.creators[0].address, recipient);
+    assert_eq!(royalties.creators[0].percentage, 100);
+
+    let raise = client::admin_set_collection_royalties(&fixture.grid, 750, &recipient);
+    fixture.send(&[raise], &[]).await.unwrap();
+    assert_eq!(collection_royalties(&mut fixture).await.unwrap().basis_points, 750);
+
+    let remove = client::admin_set_collection_royalties(&fixture.grid, 0, &recipient);
+    fixture.send(&[remove], &[]).await.unwrap();
+    assert_eq!(collection_royalties(&mut fixture).await, None);
+}
+
+#[tokio::test]
+async fn royalties_above_100_percent_are_rejected() {
+    let mut fixture = GridFixture::builder().build().await;
+    let ix = client::admin_set_collection_royalties(&fixture.grid, 10_001, &Pubkey::new_unique());
+    assert_billion_error(fixture.send(&[ix], &[]).await, BillionError::InvalidRoyalties);
+}
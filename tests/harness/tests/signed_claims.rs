@@ -0,0 +1,124 @@
+use billion::errors::BillionError;
+use billion_client::{self as client, ClaimAuthorization};
+use billion_test_harness::{assert_billion_error, GridFixture, Rect, TestUser};
+use solana_program_test::BanksClientError;
+use solana_sdk::{
+    clock::Clock,
+    signature::{Keypair, Signer},
+};
+
+const PRICE: u64 = 1_000_000;
+
+/// A funded user who approved the GridConfig PDA as delegate, and a relayer with no tokens
+async fn signed_fixture() -> (GridFixture, TestUser, TestUser) {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(100_000_000).await;
+    let approve = spl_token_2022::instruction::approve(
+        &spl_token_2022::id(),
+        &user.token_account,
+        &fixture.grid.grid_config,
+        &user.keypair.pubkey(),
+        &[],
+        u64::MAX,
+    )
+    .unwrap();
+    fixture.send(&[approve], &[&user.keypair]).await.unwrap();
+    let relayer = fixture.create_user(0).await;
+    (fixture, user, relayer)
+}
+
+async fn authorization(fixture: &mut GridFixture, user: &TestUser, rect: Rect, nonce: u64) -> ClaimAuthorization {
+    let clock: Clock = fixture.ctx.banks_client.get_sysvar().await.unwrap();
+    ClaimAuthorization {
+        grid_config: fixture.grid.grid_config,
+        user: user.keypair.pubkey(),
+        x: rect.x,
+        y: rect.y,
+        width: rect.width,
+        height: rect.height,
+        max_cost: rect.width as u64 * rect.height as u64 * PRICE,
+        nonce,
+        expires_at: clock.unix_timestamp + 600,
+    }
+}
+
+/// Signs `signed` as `user` but submits `submitted`, so tests can tamper with what's relayed
+async fn relay(
+    fixture: &mut GridFixture,
+    user: &TestUser,
+    relayer: &TestUser,
+    signed: &ClaimAuthorization,
+    submitted: &ClaimAuthorization,
+) -> Result<Keypair, BanksClientError> {
+    let message = signed.message();
+    let signature = user.keypair.sign_message(&message);
+    let verify = client::ed25519_verify(&user.keypair.pubkey(), signature.as_ref().try_into().unwrap(), &message);
+    let parcel_id = fixture.grid_config().await.next_parcel_id;
+    let asset = Keypair::new();
+    let claim = client::claim_parcel_signed(&fixture.grid, &relayer.keypair.pubkey(), submitted, &asset.pubkey(), parcel_id);
+    fixture.send(&[verify, claim], &[&relayer.keypair, &asset]).await.map(|_| asset)
+}
+
+#[tokio::test]
+async fn relayer_claims_for_the_signing_user() {
+    let (mut fixture, user, relayer) = signed_fixture().await;
+    let auth = authorization(&mut fixture, &user, Rect::new(0, 0, 2, 2), 0).await;
+    let asset = relay(&mut fixture, &user, &relayer, &auth, &auth).await.unwrap();
+
+    assert_eq!(fixture.asset_owner(asset.pubkey()).await, user.keypair.pubkey());
+    assert_eq!(fixture.token_balance(user.token_account).await, 100_000_000 - 4 * PRICE);
+    let nonce = client::decode_claim_nonce(&fixture.account_data(client::find_claim_nonce(fixture.grid.grid_id, &user.keypair.pubkey())).await)
+        .unwrap();
+    assert_eq!(nonce.next_nonce, 1);
+}
+
+#[tokio::test]
+async fn replayed_authorization_fails() {
+    let (mut fixture, user, relayer) = signed_fixture().await;
+    let auth = authorization(&mut fixture, &user, Rect::new(0, 0, 1, 1), 3).await;
+    relay(&mut fixture, &user, &relayer, &auth, &auth).await.unwrap();
+
+    let replay = ClaimAuthorization { x: 5, ..auth };
+    let result = relay(&mut fixture, &user, &relayer, &replay, &replay).await;
+    assert_billion_error(result, BillionError::NonceAlreadyUsed);
+}
+
+#[tokio::test]
+async fn relayer_cannot_alter_the_signed_claim() {
+    let (mut fixture, user, relayer) = signed_fixture().await;
+    let auth = authorization(&mut fixture, &user, Rect::new(0, 0, 1, 1), 0).await;
+
+    let moved = ClaimAuthorization { x: 4, ..auth.clone() };
+    assert_billion_error(relay(&mut fixture, &user, &relayer, &auth, &moved).await, BillionError::InvalidClaimSignature);
+
+    let bigger = ClaimAuthorization { width: 3, max_cost: 3 * PRICE, ..auth.clone() };
+    assert_billion_error(relay(&mut fixture, &user, &relayer, &auth, &bigger).await, BillionError::InvalidClaimSignature);
+    assert_eq!(fixture.token_balance(user.token_account).await, 100_000_000);
+}
+
+#[tokio::test]
+async fn expired_or_underpriced_authorization_fails_before_payment() {
+    let (mut fixture, user, relayer) = signed_fixture().await;
+    let auth = authorization(&mut fixture, &user, Rect::new(0, 0, 2, 2), 0).await;
+
+    let cheap = ClaimAuthorization { max_cost: 4 * PRICE - 1, ..auth.clone() };
+    assert_billion_error(relay(&mut fixture, &user, &relayer, &cheap, &cheap).await, BillionError::MaxCostExceeded);
+
+    fixture.advance_clock(601).await;
+    assert_billion_error(
+        relay(&mut fixture, &user, &relayer, &auth, &auth).await,
+        BillionError::ClaimAuthorizationExpired,
+    );
+    assert_eq!(fixture.token_balance(user.token_account).await, 100_000_000);
+}
+
+#[tokio::test]
+async fn claim_without_signature_instruction_fails() {
+    let (mut fixture, user, relayer) = signed_fixture().await;
+    let auth = authorization(&mut fixture, &user, Rect::new(0, 0, 1, 1), 0).await;
+    let parcel_id = fixture.grid_config().await.next_parcel_id;
+    let asset = Keypair::new();
+    let claim = client::claim_parcel_signed(&fixture.grid, &relayer.keypair.pubkey(), &auth, &asset.pubkey(), parcel_id);
+    let result = fixture.send(&[claim], &[&relayer.keypair, &asset]).await;
+    assert_billion_error(result, BillionError::InvalidClaimSignature);
+}
@@ -0,0 +1,80 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, GridFixture, Rect, TestUser};
+use solana_program_test::BanksClientError;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+const LAMPORTS_PER_BLOCK: u64 = 10_000_000;
+
+async fn set_sol_price(fixture: &mut GridFixture, price_per_block_lamports: u64, sol_ring_credit_bps: u16) {
+    let ix = client::set_sol_price(fixture.grid.grid_id, &fixture.authority(), price_per_block_lamports, sol_ring_credit_bps);
+    fixture.send(&[ix], &[]).await.unwrap();
+}
+
+async fn claim_with_sol(fixture: &mut GridFixture, user: &TestUser, rect: Rect) -> Result<Keypair, BanksClientError> {
+    let parcel_id = fixture.grid_config().await.next_parcel_id;
+    let asset = Keypair::new();
+    let ix = client::claim_parcel_sol(&fixture.grid, &user.keypair.pubkey(), &asset.pubkey(), parcel_id, rect);
+    fixture.send(&[ix], &[&user.keypair, &asset]).await.map(|_| asset)
+}
+
+#[tokio::test]
+async fn sol_claim_pays_the_treasury_without_touching_tokens() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(0).await;
+    set_sol_price(&mut fixture, LAMPORTS_PER_BLOCK, 5_000).await;
+
+    let asset = claim_with_sol(&mut fixture, &user, Rect::new(0, 0, 2, 2)).await.unwrap();
+
+    assert_eq!(fixture.asset_owner(asset.pubkey()).await, user.keypair.pubkey());
+    let treasury = client::find_sol_treasury(&fixture.grid.grid_config);
+    assert_eq!(fixture.ctx.banks_client.get_balance(treasury).await.unwrap(), 4 * LAMPORTS_PER_BLOCK);
+    assert_eq!(fixture.token_balance(fixture.reward_pool()).await, 0);
+
+    let config = fixture.grid_config().await;
+    assert_eq!(config.total_sol_collected, 4 * LAMPORTS_PER_BLOCK);
+    assert_eq!(config.total_claimed_blocks, 4);
+    // Half the 1_000_000 token price per block counts toward rings
+    assert_eq!(config.total_burned, 2_000_000);
+}
+
+#[tokio::test]
+async fn zero_lamport_price_disables_sol_claims() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(0).await;
+
+    let result = claim_with_sol(&mut fixture, &user, Rect::new(0, 0, 1, 1)).await;
+    assert_billion_error(result, BillionError::SolPaymentsDisabled);
+
+    set_sol_price(&mut fixture, LAMPORTS_PER_BLOCK, 0).await;
+    set_sol_price(&mut fixture, 0, 0).await;
+    let result = claim_with_sol(&mut fixture, &user, Rect::new(0, 0, 1, 1)).await;
+    assert_billion_error(result, BillionError::SolPaymentsDisabled);
+}
+
+#[tokio::test]
+async fn authority_withdraws_treasury_above_rent() {
+    let mut fixture = GridFixture::builder().build().await;
+    let user = fixture.create_user(0).await;
+    set_sol_price(&mut fixture, LAMPORTS_PER_BLOCK, 0).await;
+    claim_with_sol(&mut fixture, &user, Rect::new(0, 0, 1, 1)).await.unwrap();
+
+    let recipient = Pubkey::new_unique();
+    let treasury = client::find_sol_treasury(&fixture.grid.grid_config);
+    let all = client::withdraw_sol_treasury(fixture.grid.grid_id, &fixture.authority(), &recipient, LAMPORTS_PER_BLOCK);
+    assert_billion_error(fixture.send(&[all], &[]).await, BillionError::InsufficientBalance);
+
+    let rent = fixture.ctx.banks_client.get_rent().await.unwrap().minimum_balance(0);
+    let available = LAMPORTS_PER_BLOCK - rent;
+    let withdraw = client::withdraw_sol_treasury(fixture.grid.grid_id, &fixture.authority(), &recipient, available);
+    fixture.send(&[withdraw], &[]).await.unwrap();
+    assert_eq!(fixture.ctx.banks_client.get_balance(recipient).await.unwrap(), available);
+    assert_eq!(fixture.ctx.banks_client.get_balance(treasury).await.unwrap(), rent);
+
+    let stranger = fixture.create_user(0).await;
+    let steal = client::withdraw_sol_treasury(fixture.grid.grid_id, &stranger.keypair.pubkey(), &stranger.keypair.pubkey(), 1);
+    assert_billion_error(fixture.send(&[steal], &[&stranger.keypair]).await, BillionError::Unauthorized);
+}
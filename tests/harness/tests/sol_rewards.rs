@@ -0,0 +1,102 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, ClaimedParcel, GridFixture, Rect, TestUser};
+use solana_program_test::BanksClientError;
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+
+async fn claim_sol(fixture: &mut GridFixture, user: &TestUser, parcel: ClaimedParcel) -> Result<(), BanksClientError> {
+    let ix = client::claim_sol_rewards(&fixture.grid, &user.keypair.pubkey(), &parcel.asset, parcel.parcel_id);
+    fixture.send(&[ix], &[&user.keypair]).await
+}
+
+async fn fund_sol(fixture: &mut GridFixture, lamports: u64) -> Result<(), BanksClientError> {
+    let ix = client::fund_sol_rewards(&fixture.grid, &fixture.authority(), lamports);
+    fixture.send(&[ix], &[]).await
+}
+
+async fn lamports(fixture: &mut GridFixture, address: Pubkey) -> u64 {
+    fixture.ctx.banks_client.get_balance(address).await.unwrap()
+}
+
+async fn sol_rewards(fixture: &mut GridFixture) -> billion::state::SolRewards {
+    let data = fixture.account_data(client::find_sol_rewards(fixture.grid.grid_id)).await;
+    client::decode_sol_rewards(&data).unwrap()
+}
+
+#[tokio::test]
+async fn deposits_are_claimed_pro_rata_by_enrolled_parcels() {
+    let mut fixture = GridFixture::builder().build().await;
+    let small = fixture.create_user(100_000_000).await;
+    let large = fixture.create_user(100_000_000).await;
+    let small_parcel = fixture.claim(&small, Rect::new(0, 0, 1, 1)).await.unwrap();
+    let large_parcel = fixture.claim(&large, Rect::new(10, 0, 3, 1)).await.unwrap();
+    claim_sol(&mut fixture, &small, small_parcel).await.unwrap();
+    claim_sol(&mut fixture, &large, large_parcel).await.unwrap();
+    assert_eq!(sol_rewards(&mut fixture).await.enrolled_blocks, 4);
+
+    let pool_before = fixture.token_balance(fixture.reward_pool()).await;
+    fund_sol(&mut fixture, 4_000_000).await.unwrap();
+
+    let before = lamports(&mut fixture, small.keypair.pubkey()).await;
+    claim_sol(&mut fixture, &small, small_parcel).await.unwrap();
+    assert_eq!(lamports(&mut fixture, small.keypair.pubkey()).await - before, 1_000_000);
+    let before = lamports(&mut fixture, large.keypair.pubkey()).await;
+    claim_sol(&mut fixture, &large, large_parcel).await.unwrap();
+    assert_eq!(lamports(&mut fixture, large.keypair.pubkey()).await - before, 3_000_000);
+
+    // The token stream is untouched
+    assert_eq!(fixture.token_balance(fixture.reward_pool()).await, pool_before);
+    assert_billion_error(claim_sol(&mut fixture, &small, small_parcel).await, BillionError::NothingToClaim);
+}
+
+#[tokio::test]
+async fn parcels_earn_only_deposits_after_enrolling() {
+    let mut fixture = GridFixture::builder().build().await;
+    let early = fixture.create_user(100_000_000).await;
+    let late = fixture.create_user(100_000_000).await;
+    let early_parcel = fixture.claim(&early, Rect::new(0, 0, 1, 1)).await.unwrap();
+    let late_parcel = fixture.claim(&late, Rect::new(1, 0, 1, 1)).await.unwrap();
+    assert_billion_error(fund_sol(&mut fixture, 1_000_000).await, BillionError::NoRewardRecipients);
+
+    claim_sol(&mut fixture, &early, early_parcel).await.unwrap();
+    fund_sol(&mut fixture, 1_000_000).await.unwrap();
+    claim_sol(&mut fixture, &late, late_parcel).await.unwrap();
+    assert_billion_error(claim_sol(&mut fixture, &late, late_parcel).await, BillionError::NothingToClaim);
+
+    let before = lamports(&mut fixture, early.keypair.pubkey()).await;
+    claim_sol(&mut fixture, &early, early_parcel).await.unwrap();
+    assert_eq!(lamports(&mut fixture, early.keypair.pubkey()).await - before, 1_000_000);
+}
+
+#[tokio::test]
+async fn closed_parcels_leave_the_stream() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 2, 1)).await.unwrap();
+    claim_sol(&mut fixture, &owner, parcel).await.unwrap();
+
+    let close = client::close_sol_reward_checkpoint(fixture.grid.grid_id, &fixture.authority(), parcel.epoch, parcel.parcel_id);
+    assert_billion_error(fixture.send(&[close], &[]).await, BillionError::ParcelStillOpen);
+
+    let close_parcel = client::admin_close_parcel_info(&fixture.grid, parcel.epoch, parcel.parcel_id);
+    fixture.send(&[close_parcel], &[]).await.unwrap();
+    let close = client::close_sol_reward_checkpoint(fixture.grid.grid_id, &fixture.authority(), parcel.epoch, parcel.parcel_id);
+    fixture.send(&[close], &[]).await.unwrap();
+    assert_eq!(sol_rewards(&mut fixture).await.enrolled_blocks, 0);
+}
+
+#[tokio::test]
+async fn purge_drains_the_sol_reward_vault() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    claim_sol(&mut fixture, &owner, parcel).await.unwrap();
+    fund_sol(&mut fixture, 5_000_000).await.unwrap();
+
+    let vault = client::find_sol_reward_vault(&fixture.grid.grid_config);
+    assert!(lamports(&mut fixture, vault).await > 5_000_000);
+    fixture.pause_for_purge().await;
+    let purge = client::admin_purge(&fixture.grid, true);
+    fixture.send(&[purge], &[]).await.unwrap();
+    assert_eq!(lamports(&mut fixture, vault).await, 0);
+}
@@ -0,0 +1,50 @@
+use anchor_lang::AnchorDeserialize;
+use billion::errors::BillionError;
+use billion::instructions::SolvencyReport;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, GridFixture, Rect};
+
+async fn verify(fixture: &mut GridFixture, parcel_ids: &[u16], running_total: u64) -> SolvencyReport {
+    let ix = client::verify_reward_solvency(&fixture.grid, parcel_ids, running_total);
+    SolvencyReport::deserialize(&mut &fixture.view(ix).await[..]).unwrap()
+}
+
+#[tokio::test]
+async fn batches_add_up_to_the_pending_rewards() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let first = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    let second = fixture.claim(&owner, Rect::new(1, 0, 1, 1)).await.unwrap();
+    let third = fixture.claim(&owner, Rect::new(2, 0, 1, 1)).await.unwrap();
+
+    let report = verify(&mut fixture, &[first.parcel_id], 0).await;
+    assert_eq!(report.outstanding, 300_000);
+    let report = verify(&mut fixture, &[second.parcel_id, third.parcel_id], report.outstanding).await;
+    // The first claim's share had no landowners and is surplus
+    assert_eq!(
+        report,
+        SolvencyReport { outstanding: 400_000, pool_balance: 600_000, surplus: 200_000, deficit: 0 }
+    );
+}
+
+#[tokio::test]
+async fn a_deficit_is_reported_without_failing() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let first = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    fixture.claim(&owner, Rect::new(1, 0, 1, 1)).await.unwrap();
+
+    let report = verify(&mut fixture, &[first.parcel_id], 1_000_000).await;
+    assert_eq!(report.outstanding, 1_200_000);
+    assert_eq!((report.surplus, report.deficit), (0, 800_000));
+}
+
+#[tokio::test]
+async fn a_parcel_is_counted_once_per_batch() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let first = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+
+    let ix = client::verify_reward_solvency(&fixture.grid, &[first.parcel_id, first.parcel_id], 0);
+    assert_billion_error(fixture.send(&[ix], &[]).await, BillionError::InvalidBatch);
+}
@@ -0,0 +1,69 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, ClaimedParcel, GridFixture, Rect, TestUser};
+use mpl_core::instructions::TransferV1Builder;
+use solana_sdk::{
+    instruction::Instruction,
+    signature::{Keypair, Signer},
+};
+
+/// Admin-mints a soulbound 1x1 parcel at the origin to a new user
+async fn soulbound_fixture() -> (GridFixture, TestUser, ClaimedParcel) {
+    let mut fixture = GridFixture::builder().build().await;
+    let recipient = fixture.create_user(0).await;
+    let parcel_id = fixture.grid_config().await.next_parcel_id;
+    let asset = Keypair::new();
+    let ix = client::admin_mint_soulbound(
+        &fixture.grid,
+        &recipient.keypair.pubkey(),
+        &asset.pubkey(),
+        parcel_id,
+        Rect::new(0, 0, 1, 1),
+    );
+    fixture.send(&[ix], &[&asset]).await.unwrap();
+    let parcel = ClaimedParcel { parcel_id, asset: asset.pubkey(), epoch: fixture.grid.epoch };
+    (fixture, recipient, parcel)
+}
+
+/// Owner hands the asset to the fixture authority
+fn transfer_to_authority(fixture: &GridFixture, owner: &TestUser, parcel: ClaimedParcel) -> Instruction {
+    TransferV1Builder::new()
+        .asset(parcel.asset)
+        .collection(Some(fixture.grid.collection))
+        .payer(owner.keypair.pubkey())
+        .new_owner(fixture.authority())
+        .instruction()
+}
+
+#[tokio::test]
+async fn soulbound_parcel_is_frozen_until_thawed() {
+    let (mut fixture, recipient, parcel) = soulbound_fixture().await;
+
+    let ix = transfer_to_authority(&fixture, &recipient, parcel);
+    assert!(fixture.send(&[ix], &[&recipient.keypair]).await.is_err());
+
+    let thaw = client::admin_thaw_parcel(&fixture.grid, &parcel.asset, parcel.parcel_id);
+    fixture.send(&[thaw], &[]).await.unwrap();
+
+    let ix = transfer_to_authority(&fixture, &recipient, parcel);
+    fixture.send(&[ix], &[&recipient.keypair]).await.unwrap();
+    assert_eq!(fixture.asset_owner(parcel.asset).await, fixture.authority());
+}
+
+#[tokio::test]
+async fn soulbound_parcel_still_claims_rewards() {
+    let (mut fixture, recipient, parcel) = soulbound_fixture().await;
+    let buyer = fixture.create_user(100_000_000).await;
+    fixture.claim(&buyer, Rect::new(1, 0, 1, 1)).await.unwrap();
+
+    fixture.claim_rewards(&recipient, parcel).await.unwrap();
+    assert!(fixture.token_balance(recipient.token_account).await > 0);
+}
+
+#[tokio::test]
+async fn only_the_authority_can_thaw() {
+    let (mut fixture, recipient, parcel) = soulbound_fixture().await;
+    let grid = client::GridAccounts { authority: recipient.keypair.pubkey(), ..fixture.grid };
+    let thaw = client::admin_thaw_parcel(&grid, &parcel.asset, parcel.parcel_id);
+    assert_billion_error(fixture.send(&[thaw], &[&recipient.keypair]).await, BillionError::Unauthorized);
+}
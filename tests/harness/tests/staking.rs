@@ -0,0 +1,87 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, ClaimedParcel, GridFixture, Rect, TestUser};
+use solana_sdk::signature::Signer;
+
+/// Grid with an emissions vault holding `funding` tokens at `rate` per block per second,
+/// and a staked 2x2 parcel
+async fn staked_fixture(rate: u64, funding: u64) -> (GridFixture, TestUser, ClaimedParcel) {
+    let mut fixture = GridFixture::builder().build().await;
+    let authority = fixture.authority();
+    let init = client::init_emissions_vault(&fixture.grid);
+    let set_rate = client::set_emission_rate(fixture.grid.grid_id, &authority, rate);
+    fixture.send(&[init, set_rate], &[]).await.unwrap();
+    if funding > 0 {
+        fixture.mint_tokens(fixture.grid.emissions_vault, funding).await;
+    }
+
+    let user = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&user, Rect::new(0, 0, 2, 2)).await.unwrap();
+    let ix = client::stake_parcel(&fixture.grid, &user.keypair.pubkey(), &parcel.asset, parcel.parcel_id);
+    fixture.send(&[ix], &[&user.keypair]).await.unwrap();
+    (fixture, user, parcel)
+}
+
+#[tokio::test]
+async fn staking_accrues_per_block_per_second() {
+    let (mut fixture, user, parcel) = staked_fixture(10, 1_000_000_000).await;
+    let before = fixture.token_balance(user.token_account).await;
+
+    fixture.advance_clock(100).await;
+    let ix = client::claim_staking_rewards(&fixture.grid, &user.keypair.pubkey(), parcel.parcel_id);
+    fixture.send(&[ix], &[&user.keypair]).await.unwrap();
+
+    // 4 blocks * 10 per second * 100 seconds
+    assert_eq!(fixture.token_balance(user.token_account).await - before, 4_000);
+    assert_eq!(fixture.stake_account(parcel.parcel_id).await.total_claimed, 4_000);
+}
+
+#[tokio::test]
+async fn empty_vault_stops_emissions_without_failing() {
+    let (mut fixture, user, parcel) = staked_fixture(10, 1_000).await;
+    let before = fixture.token_balance(user.token_account).await;
+
+    fixture.advance_clock(100).await;
+    let ix = client::claim_staking_rewards(&fixture.grid, &user.keypair.pubkey(), parcel.parcel_id);
+    fixture.send(&[ix], &[&user.keypair]).await.unwrap();
+    assert_eq!(fixture.token_balance(user.token_account).await - before, 1_000);
+
+    fixture.advance_clock(100).await;
+    let ix = client::claim_staking_rewards(&fixture.grid, &user.keypair.pubkey(), parcel.parcel_id);
+    fixture.send(&[ix], &[&user.keypair]).await.unwrap();
+    assert_eq!(fixture.token_balance(user.token_account).await - before, 1_000);
+}
+
+#[tokio::test]
+async fn unstake_pays_out_and_land_rewards_still_claimable() {
+    let (mut fixture, user, parcel) = staked_fixture(10, 1_000_000_000).await;
+
+    // Another purchase while staked funds land-buy rewards for the staked parcel
+    let buyer = fixture.create_user(100_000_000).await;
+    fixture.claim(&buyer, Rect::new(0, 10, 1, 1)).await.unwrap();
+    fixture.claim_rewards(&user, parcel).await.unwrap();
+
+    fixture.advance_clock(50).await;
+    let before = fixture.token_balance(user.token_account).await;
+    let ix = client::unstake_parcel(&fixture.grid, &user.keypair.pubkey(), &parcel.asset, parcel.parcel_id);
+    fixture.send(&[ix], &[&user.keypair]).await.unwrap();
+    assert_eq!(fixture.token_balance(user.token_account).await - before, 2_000);
+
+    let stake = fixture.ctx.banks_client.get_account(client::find_stake_account(fixture.grid.grid_id, parcel.parcel_id)).await.unwrap();
+    assert!(stake.is_none());
+}
+
+#[tokio::test]
+async fn only_owner_can_stake() {
+    let mut fixture = GridFixture::builder().build().await;
+    let authority = fixture.authority();
+    let init = client::init_emissions_vault(&fixture.grid);
+    fixture.send(&[init, client::set_emission_rate(fixture.grid.grid_id, &authority, 1)], &[]).await.unwrap();
+
+    let owner = fixture.create_user(100_000_000).await;
+    let other = fixture.create_user(0).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+
+    let ix = client::stake_parcel(&fixture.grid, &other.keypair.pubkey(), &parcel.asset, parcel.parcel_id);
+    assert_billion_error(fixture.send(&[ix], &[&other.keypair]).await, BillionError::NotOwner);
+}
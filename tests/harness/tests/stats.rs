@@ -0,0 +1,92 @@
+use anchor_lang::AnchorDeserialize;
+use billion::errors::BillionError;
+use billion::state::ClaimerStats;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, GridFixture, Rect};
+use solana_sdk::signature::Signer;
+
+#[tokio::test]
+async fn claims_and_reward_claims_update_lifetime_stats() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let buyer = fixture.create_user(100_000_000).await;
+    let owner_key = owner.keypair.pubkey();
+
+    let first = fixture.claim(&owner, Rect::new(0, 0, 2, 2)).await.unwrap();
+    fixture.claim(&owner, Rect::new(2, 0, 1, 1)).await.unwrap();
+    // The 2x2 earns 50_000 per block from the second claim and 40_000 per block from this one
+    fixture.claim(&buyer, Rect::new(3, 0, 1, 1)).await.unwrap();
+    fixture.claim_rewards(&owner, first).await.unwrap();
+
+    let stats = client::decode_claimer_stats(&fixture.account_data(client::find_claimer_stats(fixture.grid.grid_id, &owner_key)).await).unwrap();
+    assert_eq!(stats.wallet, owner_key);
+    assert_eq!(stats.lifetime_spent, 5_000_000);
+    assert_eq!(stats.parcels_claimed, 2);
+    assert_eq!(stats.blocks_claimed, 5);
+    assert_eq!(stats.lifetime_rewards_claimed, 360_000);
+    assert!(stats.first_claim_ts > 0);
+
+    let view = ClaimerStats::deserialize(&mut &fixture.view(client::get_claimer_stats(fixture.grid.grid_id, &owner_key)).await[..]).unwrap();
+    assert_eq!(view.lifetime_spent, stats.lifetime_spent);
+    assert_eq!(view.first_claim_ts, stats.first_claim_ts);
+}
+
+#[tokio::test]
+async fn wallet_closes_its_stats_for_rent() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let owner_key = owner.keypair.pubkey();
+    fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+
+    // Only the wallet itself can close
+    let other = fixture.create_user(0).await;
+    let mut ix = client::close_claimer_stats(fixture.grid.grid_id, &other.keypair.pubkey());
+    ix.accounts[1].pubkey = client::find_claimer_stats(fixture.grid.grid_id, &owner_key);
+    assert!(fixture.send(&[ix], &[&other.keypair]).await.is_err());
+
+    let before = fixture.ctx.banks_client.get_balance(owner_key).await.unwrap();
+    fixture.send(&[client::close_claimer_stats(fixture.grid.grid_id, &owner_key)], &[&owner.keypair]).await.unwrap();
+    assert!(fixture.ctx.banks_client.get_balance(owner_key).await.unwrap() > before);
+    let stats = client::find_claimer_stats(fixture.grid.grid_id, &owner_key);
+    assert!(fixture.ctx.banks_client.get_account(stats).await.unwrap().is_none());
+}
+
+async fn set_wallet_limit(fixture: &mut GridFixture, max_blocks_per_wallet: u32) {
+    let ix = client::update_config(
+        fixture.grid.grid_id,
+        &fixture.authority(),
+        client::UpdateConfigArgs { max_blocks_per_wallet: Some(max_blocks_per_wallet), ..Default::default() },
+    );
+    fixture.send(&[ix], &[]).await.unwrap();
+}
+
+#[tokio::test]
+async fn wallet_block_limit_caps_lifetime_claims() {
+    let mut fixture = GridFixture::builder().build().await;
+    let whale = fixture.create_user(200_000_000).await;
+    set_wallet_limit(&mut fixture, 100).await;
+
+    fixture.claim(&whale, Rect::new(0, 0, 18, 5)).await.unwrap();
+    let result = fixture.claim(&whale, Rect::new(20, 0, 11, 1)).await;
+    assert_billion_error(result, BillionError::WalletBlockLimitExceeded);
+    fixture.claim(&whale, Rect::new(20, 0, 10, 1)).await.unwrap();
+
+    // Lifting the limit lets the wallet continue; the stats kept counting throughout
+    set_wallet_limit(&mut fixture, 0).await;
+    fixture.claim(&whale, Rect::new(20, 1, 1, 1)).await.unwrap();
+    let stats = client::decode_claimer_stats(&fixture.account_data(client::find_claimer_stats(fixture.grid.grid_id, &whale.keypair.pubkey())).await)
+        .unwrap();
+    assert_eq!(stats.blocks_claimed, 101);
+}
+
+#[tokio::test]
+async fn stats_cannot_be_closed_while_the_wallet_limit_is_set() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    set_wallet_limit(&mut fixture, 100).await;
+
+    let close = client::close_claimer_stats(fixture.grid.grid_id, &owner.keypair.pubkey());
+    let result = fixture.send(&[close], &[&owner.keypair]).await;
+    assert_billion_error(result, BillionError::WalletLimitActive);
+}
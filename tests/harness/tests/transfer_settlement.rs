@@ -0,0 +1,56 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, GridFixture, Rect};
+use mpl_core::instructions::TransferV1Builder;
+use solana_sdk::signature::Signer;
+
+#[tokio::test]
+async fn seller_keeps_rewards_settled_in_the_transfer_transaction() {
+    let mut fixture = GridFixture::builder().build().await;
+    let seller = fixture.create_user(100_000_000).await;
+    let buyer = fixture.create_user(0).await;
+    let parcel = fixture.claim(&seller, Rect::new(0, 0, 1, 1)).await.unwrap();
+    fixture.claim(&seller, Rect::new(1, 0, 1, 1)).await.unwrap();
+
+    let before = fixture.token_balance(seller.token_account).await;
+    let settle =
+        client::settle_rewards_for_transfer(&fixture.grid, &seller.keypair.pubkey(), &parcel.asset, parcel.parcel_id);
+    let transfer = TransferV1Builder::new()
+        .asset(parcel.asset)
+        .collection(Some(fixture.grid.collection))
+        .payer(seller.keypair.pubkey())
+        .new_owner(buyer.keypair.pubkey())
+        .instruction();
+    fixture.send(&[settle, transfer], &[&seller.keypair]).await.unwrap();
+
+    assert_eq!(fixture.token_balance(seller.token_account).await - before, 200_000);
+    assert_eq!(fixture.asset_owner(parcel.asset).await, buyer.keypair.pubkey());
+    assert_billion_error(fixture.claim_rewards(&buyer, parcel).await, BillionError::NothingToClaim);
+}
+
+#[tokio::test]
+async fn settling_with_nothing_owed_succeeds() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+
+    let before = fixture.token_balance(owner.token_account).await;
+    let settle =
+        client::settle_rewards_for_transfer(&fixture.grid, &owner.keypair.pubkey(), &parcel.asset, parcel.parcel_id);
+    fixture.send(&[settle], &[&owner.keypair]).await.unwrap();
+    assert_eq!(fixture.token_balance(owner.token_account).await, before);
+}
+
+#[tokio::test]
+async fn only_the_owner_settles() {
+    let mut fixture = GridFixture::builder().build().await;
+    let owner = fixture.create_user(100_000_000).await;
+    let other = fixture.create_user(0).await;
+    let parcel = fixture.claim(&owner, Rect::new(0, 0, 1, 1)).await.unwrap();
+    fixture.claim(&owner, Rect::new(1, 0, 1, 1)).await.unwrap();
+
+    let settle =
+        client::settle_rewards_for_transfer(&fixture.grid, &other.keypair.pubkey(), &parcel.asset, parcel.parcel_id);
+    assert_billion_error(fixture.send(&[settle], &[&other.keypair]).await, BillionError::NotOwner);
+    fixture.claim_rewards(&owner, parcel).await.unwrap();
+}
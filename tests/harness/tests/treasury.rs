@@ -0,0 +1,86 @@
+use billion::errors::BillionError;
+use billion_client::{self as client, UpdateConfigArgs};
+use billion_test_harness::{assert_billion_error, GridFixture, Rect, TestUser};
+
+/// Points the treasury share at a fresh user's token account
+async fn treasury_fixture(price_per_block: u64, share_bps: u16) -> (GridFixture, TestUser) {
+    let mut fixture = GridFixture::builder().price_per_block(price_per_block).build().await;
+    let treasury = fixture.create_user(0).await;
+    let ix = client::set_token_treasury(&fixture.grid, &treasury.token_account, share_bps);
+    fixture.send(&[ix], &[]).await.unwrap();
+    fixture.grid.treasury = Some(treasury.token_account);
+    (fixture, treasury)
+}
+
+#[tokio::test]
+async fn treasury_share_comes_out_of_the_burn() {
+    let (mut fixture, treasury) = treasury_fixture(1_000_003, 1_500).await;
+    let buyer = fixture.create_user(100_000_000).await;
+
+    // 1_000_003 cost: 200_000 to rewards, 150_000 to the treasury, the rest (dust included) burned
+    fixture.claim(&buyer, Rect::new(0, 0, 1, 1)).await.unwrap();
+    assert_eq!(fixture.token_balance(treasury.token_account).await, 150_000);
+    assert_eq!(fixture.token_balance(fixture.reward_pool()).await, 200_000);
+    assert_eq!(fixture.token_balance(buyer.token_account).await, 100_000_000 - 1_000_003);
+    assert_eq!(fixture.grid_config().await.total_burned, 650_003);
+}
+
+#[tokio::test]
+async fn claims_must_pay_the_configured_treasury() {
+    let (mut fixture, _treasury) = treasury_fixture(1_000_000, 1_500).await;
+    let buyer = fixture.create_user(100_000_000).await;
+
+    fixture.grid.treasury = None;
+    assert_billion_error(fixture.claim(&buyer, Rect::new(0, 0, 1, 1)).await, BillionError::InvalidTreasury);
+
+    fixture.grid.treasury = Some(buyer.token_account);
+    assert_billion_error(fixture.claim(&buyer, Rect::new(0, 0, 1, 1)).await, BillionError::InvalidTreasury);
+}
+
+#[tokio::test]
+async fn treasury_and_landowner_shares_stay_within_the_cost() {
+    let (mut fixture, treasury) = treasury_fixture(1_000_000, 1_500).await;
+
+    // The landowner share is 2000 bps
+    let ix = client::set_token_treasury(&fixture.grid, &treasury.token_account, 8_001);
+    assert_billion_error(fixture.send(&[ix], &[]).await, BillionError::InvalidTreasuryShare);
+
+    let raise = client::update_config(
+        fixture.grid.grid_id,
+        &fixture.authority(),
+        UpdateConfigArgs { land_owners_reward_share_bps: Some(8_600), ..Default::default() },
+    );
+    assert_billion_error(fixture.send(&[raise], &[]).await, BillionError::InvalidRewardShare);
+
+    let ix = client::set_token_treasury(&fixture.grid, &treasury.token_account, 8_000);
+    fixture.send(&[ix], &[]).await.unwrap();
+}
+
+#[tokio::test]
+async fn every_share_setter_leaves_something_to_burn() {
+    // 2000 bps to landowners and 7000 to the treasury: referral and charity shares have 1000 left
+    let (mut fixture, treasury) = treasury_fixture(1_000_000, 7_000).await;
+    let (grid_id, authority) = (fixture.grid.grid_id, fixture.authority());
+    fixture.send(&[client::init_referral_vault(&fixture.grid)], &[]).await.unwrap();
+
+    let referral = client::set_referral_bps(grid_id, &authority, 2_000);
+    assert_billion_error(fixture.send(&[referral], &[]).await, BillionError::InvalidReferralShare);
+    let referral = client::set_referral_bps(grid_id, &authority, 600);
+    fixture.send(&[referral], &[]).await.unwrap();
+
+    let charity = fixture.create_user(0).await;
+    let charities = vec![charity.token_account];
+    let set_charities = |max_bps| client::admin_set_charities(grid_id, &authority, charities.clone(), max_bps);
+    let too_much = set_charities(401);
+    assert_billion_error(fixture.send(&[too_much], &[]).await, BillionError::InvalidCharityShare);
+    let fits = set_charities(400);
+    fixture.send(&[fits], &[]).await.unwrap();
+
+    // Everything is spoken for, so the treasury can't grow either
+    let ix = client::set_token_treasury(&fixture.grid, &treasury.token_account, 7_001);
+    assert_billion_error(fixture.send(&[ix], &[]).await, BillionError::InvalidTreasuryShare);
+
+    let buyer = fixture.create_user(100_000_000).await;
+    fixture.claim(&buyer, Rect::new(0, 0, 1, 1)).await.unwrap();
+    assert_eq!(fixture.token_balance(treasury.token_account).await, 700_000);
+}
@@ -0,0 +1,118 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, ClaimedParcel, GridFixture, Rect, TestUser};
+use mpl_core::instructions::TransferV1Builder;
+use solana_sdk::{
+    clock::Clock,
+    instruction::Instruction,
+    signature::{Keypair, Signer},
+};
+
+const LOCK_SECS: i64 = 30 * 86_400;
+
+/// Admin-mints a 1x1 parcel at the origin to a new user, locked for LOCK_SECS
+async fn vested_fixture(lock_rewards: bool) -> (GridFixture, TestUser, ClaimedParcel) {
+    let mut fixture = GridFixture::builder().build().await;
+    let recipient = fixture.create_user(0).await;
+    let clock: Clock = fixture.ctx.banks_client.get_sysvar().await.unwrap();
+    let parcel_id = fixture.grid_config().await.next_parcel_id;
+    let asset = Keypair::new();
+    let ix = client::admin_mint_with_lock(
+        &fixture.grid,
+        &recipient.keypair.pubkey(),
+        &asset.pubkey(),
+        parcel_id,
+        Rect::new(0, 0, 1, 1),
+        Some(clock.unix_timestamp + LOCK_SECS),
+        lock_rewards,
+    );
+    fixture.send(&[ix], &[&asset]).await.unwrap();
+    let parcel = ClaimedParcel { parcel_id, asset: asset.pubkey(), epoch: fixture.grid.epoch };
+    (fixture, recipient, parcel)
+}
+
+/// Accrues land-buy rewards to every claimed block
+async fn buy_elsewhere(fixture: &mut GridFixture) {
+    let buyer = fixture.create_user(100_000_000).await;
+    fixture.claim(&buyer, Rect::new(1, 0, 1, 1)).await.unwrap();
+}
+
+/// Owner hands the asset to the fixture authority
+fn transfer_to_authority(fixture: &GridFixture, owner: &TestUser, parcel: ClaimedParcel) -> Instruction {
+    TransferV1Builder::new()
+        .asset(parcel.asset)
+        .collection(Some(fixture.grid.collection))
+        .payer(owner.keypair.pubkey())
+        .new_owner(fixture.authority())
+        .instruction()
+}
+
+#[tokio::test]
+async fn vested_parcel_is_frozen_until_unlocked() {
+    let (mut fixture, recipient, parcel) = vested_fixture(false).await;
+    let parcel_info = fixture.parcel_info(parcel.parcel_id).await;
+    assert!(parcel_info.locked_until > 0);
+    assert!(!parcel_info.lock_rewards);
+
+    let ix = transfer_to_authority(&fixture, &recipient, parcel);
+    assert!(fixture.send(&[ix], &[&recipient.keypair]).await.is_err());
+
+    let unlock = client::unlock_vested_parcel(&fixture.grid, &fixture.authority(), &parcel.asset, parcel.parcel_id);
+    assert_billion_error(fixture.send(&[unlock.clone()], &[]).await, BillionError::VestingNotEnded);
+
+    fixture.advance_clock(LOCK_SECS).await;
+    fixture.send(&[unlock.clone()], &[]).await.unwrap();
+    assert_eq!(fixture.parcel_info(parcel.parcel_id).await.locked_until, 0);
+    assert_billion_error(fixture.send(&[unlock], &[]).await, BillionError::NotVested);
+
+    let ix = transfer_to_authority(&fixture, &recipient, parcel);
+    fixture.send(&[ix], &[&recipient.keypair]).await.unwrap();
+    assert_eq!(fixture.asset_owner(parcel.asset).await, fixture.authority());
+}
+
+#[tokio::test]
+async fn reward_lock_holds_back_claims_until_unlock() {
+    let (mut fixture, recipient, parcel) = vested_fixture(true).await;
+    buy_elsewhere(&mut fixture).await;
+    assert_billion_error(fixture.claim_rewards(&recipient, parcel).await, BillionError::RewardsLocked);
+
+    fixture.advance_clock(LOCK_SECS).await;
+    let unlock = client::unlock_vested_parcel(&fixture.grid, &fixture.authority(), &parcel.asset, parcel.parcel_id);
+    fixture.send(&[unlock], &[]).await.unwrap();
+
+    fixture.claim_rewards(&recipient, parcel).await.unwrap();
+    assert!(fixture.token_balance(recipient.token_account).await > 0);
+}
+
+#[tokio::test]
+async fn vested_parcel_without_reward_lock_claims_rewards() {
+    let (mut fixture, recipient, parcel) = vested_fixture(false).await;
+    buy_elsewhere(&mut fixture).await;
+    fixture.claim_rewards(&recipient, parcel).await.unwrap();
+    assert!(fixture.token_balance(recipient.token_account).await > 0);
+}
+
+#[tokio::test]
+async fn vested_parcel_cannot_be_staked() {
+    let (mut fixture, recipient, parcel) = vested_fixture(false).await;
+    let ix = client::stake_parcel(&fixture.grid, &recipient.keypair.pubkey(), &parcel.asset, parcel.parcel_id);
+    assert_billion_error(fixture.send(&[ix], &[&recipient.keypair]).await, BillionError::VestingNotEnded);
+}
+
+#[tokio::test]
+async fn lock_date_must_be_in_the_future() {
+    let mut fixture = GridFixture::builder().build().await;
+    let recipient = fixture.create_user(0).await;
+    let parcel_id = fixture.grid_config().await.next_parcel_id;
+    let asset = Keypair::new();
+    let ix = client::admin_mint_with_lock(
+        &fixture.grid,
+        &recipient.keypair.pubkey(),
+        &asset.pubkey(),
+        parcel_id,
+        Rect::new(0, 0, 1, 1),
+        Some(0),
+        false,
+    );
+    assert_billion_error(fixture.send(&[ix], &[&asset]).await, BillionError::InvalidVestingDate);
+}
@@ -0,0 +1,134 @@
+use billion::errors::BillionError;
+use billion_client as client;
+use billion_test_harness::{assert_billion_error, GridFixture, Rect, TestUser};
+use solana_program_test::BanksClientError;
+use solana_sdk::{
+    clock::Clock,
+    signature::{Keypair, Signer},
+};
+
+const VOUCHER_ID: u64 = 1;
+const TTL_SECS: i64 = 86_400;
+
+/// Issues VOUCHER_ID to a new user for a parcel of up to 2x2 in the outermost ring, with
+/// the next ring already unlocked so only the voucher keeps claims out of it
+async fn voucher_fixture() -> (GridFixture, TestUser) {
+    let mut fixture = GridFixture::builder().ring_thresholds(vec![0, 0]).build().await;
+    let beneficiary = fixture.create_user(0).await;
+    let clock: Clock = fixture.ctx.banks_client.get_sysvar().await.unwrap();
+    let ix = client::mint_voucher(
+        &fixture.grid,
+        VOUCHER_ID,
+        &beneficiary.keypair.pubkey(),
+        2,
+        2,
+        1,
+        1,
+        clock.unix_timestamp + TTL_SECS,
+    );
+    fixture.send(&[ix], &[]).await.unwrap();
+    (fixture, beneficiary)
+}
+
+async fn redeem(fixture: &mut GridFixture, beneficiary: &TestUser, rect: Rect) -> Result<Keypair, BanksClientError> {
+    let parcel_id = fixture.grid_config().await.next_parcel_id;
+    let asset = Keypair::new();
+    let ix = client::redeem_voucher(
+        &fixture.grid,
+        &beneficiary.keypair.pubkey(),
+        VOUCHER_ID,
+        &asset.pubkey(),
+        parcel_id,
+        rect,
+    );
+    fixture.send(&[ix], &[&beneficiary.keypair, &asset]).await.map(|_| asset)
+}
+
+#[tokio::test]
+async fn voucher_mints_a_free_parcel_and_closes() {
+    let (mut fixture, beneficiary) = voucher_fixture().await;
+    let voucher = client::find_voucher(fixture.grid.grid_id, &beneficiary.keypair.pubkey(), VOUCHER_ID);
+    let voucher_data = client::decode_voucher(&fixture.account_data(voucher).await).unwrap();
+    assert_eq!(voucher_data.max_width, 2);
+
+    let burned_before = fixture.grid_config().await.total_burned;
+    let asset = redeem(&mut fixture, &beneficiary, Rect::new(0, 0, 2, 2)).await.unwrap();
+
+    assert_eq!(fixture.asset_owner(asset.pubkey()).await, beneficiary.keypair.pubkey());
+    let config = fixture.grid_config().await;
+    assert_eq!(config.total_burned, burned_before);
+    assert_eq!(config.total_claimed_blocks, 4);
+    assert!(fixture.ctx.banks_client.get_account(voucher).await.unwrap().is_none());
+
+    // Consumed: a second redemption finds no voucher
+    assert!(redeem(&mut fixture, &beneficiary, Rect::new(4, 0, 1, 1)).await.is_err());
+}
+
+#[tokio::test]
+async fn voucher_enforces_size_and_ring_limits() {
+    let (mut fixture, beneficiary) = voucher_fixture().await;
+    let result = redeem(&mut fixture, &beneficiary, Rect::new(0, 0, 3, 1)).await;
+    assert_billion_error(result, BillionError::VoucherConstraintViolated);
+
+    // (6, 6) is in ring 2
+    let result = redeem(&mut fixture, &beneficiary, Rect::new(5, 5, 2, 2)).await;
+    assert_billion_error(result, BillionError::VoucherConstraintViolated);
+
+    redeem(&mut fixture, &beneficiary, Rect::new(0, 0, 1, 2)).await.unwrap();
+}
+
+#[tokio::test]
+async fn voucher_still_runs_claim_validation() {
+    let (mut fixture, beneficiary) = voucher_fixture().await;
+    let buyer = fixture.create_user(100_000_000).await;
+    fixture.claim(&buyer, Rect::new(0, 0, 1, 1)).await.unwrap();
+
+    let result = redeem(&mut fixture, &beneficiary, Rect::new(0, 0, 2, 2)).await;
+    assert_billion_error(result, BillionError::BlockAlreadyClaimed);
+}
+
+#[tokio::test]
+async fn only_the_beneficiary_can_redeem() {
+    let (mut fixture, beneficiary) = voucher_fixture().await;
+    let other = fixture.create_user(0).await;
+    let parcel_id = fixture.grid_config().await.next_parcel_id;
+    let asset = Keypair::new();
+    let mut ix = client::redeem_voucher(
+        &fixture.grid,
+        &other.keypair.pubkey(),
+        VOUCHER_ID,
+        &asset.pubkey(),
+        parcel_id,
+        Rect::new(0, 0, 1, 1),
+    );
+    // Point at the real voucher; its seeds don't match the signer
+    ix.accounts[4].pubkey = client::find_voucher(fixture.grid.grid_id, &beneficiary.keypair.pubkey(), VOUCHER_ID);
+    assert!(fixture.send(&[ix], &[&other.keypair, &asset]).await.is_err());
+}
+
+#[tokio::test]
+async fn expired_voucher_is_closed_by_the_authority() {
+    let (mut fixture, beneficiary) = voucher_fixture().await;
+    let close = client::close_expired_voucher(&fixture.grid, &beneficiary.keypair.pubkey(), VOUCHER_ID);
+    assert_billion_error(fixture.send(&[close.clone()], &[]).await, BillionError::VoucherNotExpired);
+
+    fixture.advance_clock(TTL_SECS).await;
+    let result = redeem(&mut fixture, &beneficiary, Rect::new(0, 0, 1, 1)).await;
+    assert_billion_error(result, BillionError::VoucherExpired);
+
+    fixture.send(&[close], &[]).await.unwrap();
+    let voucher = client::find_voucher(fixture.grid.grid_id, &beneficiary.keypair.pubkey(), VOUCHER_ID);
+    assert!(fixture.ctx.banks_client.get_account(voucher).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn voucher_limits_must_be_consistent() {
+    let mut fixture = GridFixture::builder().build().await;
+    let beneficiary = Keypair::new().pubkey();
+    let clock: Clock = fixture.ctx.banks_client.get_sysvar().await.unwrap();
+    let ix = client::mint_voucher(&fixture.grid, VOUCHER_ID, &beneficiary, 1, 1, 3, 2, clock.unix_timestamp + TTL_SECS);
+    assert_billion_error(fixture.send(&[ix], &[]).await, BillionError::InvalidVoucher);
+
+    let ix = client::mint_voucher(&fixture.grid, VOUCHER_ID, &beneficiary, 1, 1, 1, 1, clock.unix_timestamp);
+    assert_billion_error(fixture.send(&[ix], &[]).await, BillionError::InvalidVoucher);
+}